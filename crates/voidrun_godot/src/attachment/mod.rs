@@ -3,12 +3,21 @@
 //! Architecture: ADR-007 (TSCN Prefabs + Dynamic Attachment) + ADR-004 (NonSend main thread systems)
 //! - attach_prefabs_main_thread: Changed<Attachment> → load TSCN → attach (main thread only)
 //! - detach_prefabs_main_thread: Query<DetachAttachment> → queue_free (main thread only)
+//! - sync_armor_attachments_main_thread: Changed<EquippedArmor> → prefab каждого слота брони
+//!
+//! Нет сокета / prefab не грузится → `ExpectationFailed` (debug overlay), а не тихий пропуск.
+//! Weapon prefab при attach сверяется с контрактом (`%BulletSpawn` / `WeaponPlacement/Hitbox`).
 
 use bevy::prelude::*;
 use godot::prelude::*;
-use godot::classes::{PackedScene, Node3D};
-use voidrun_simulation::{Attachment, DetachAttachment};
+use godot::classes::Node3D;
+use voidrun_simulation::{
+    ArmorSlot, Attachment, AttachmentType, BridgeError, DetachAttachment, EquippedArmor, ExpectationFailed,
+    ItemDefinitions, WeaponStats,
+};
 use voidrun_simulation::logger;
+use crate::shared::lookup::{load_prefab, require_node, require_visual};
+use crate::shared::prefab_contract::{validate_prefab, weapon_requirements};
 use crate::shared::{VisualRegistry, AttachmentRegistry};
 
 /// Attach prefabs для новых Attachment компонентов
@@ -16,11 +25,25 @@ use crate::shared::{VisualRegistry, AttachmentRegistry};
 /// NAMING: `_main_thread` суффикс = Godot API calls (NonSend resources)
 pub fn attach_prefabs_main_thread(
     query: Query<(Entity, &Attachment), Changed<Attachment>>,
+    weapons: Query<&WeaponStats>,
     visuals: NonSend<VisualRegistry>,
     mut attachments: NonSendMut<AttachmentRegistry>,
+    mut failures: EventWriter<ExpectationFailed>,
 ) {
     for (entity, attachment) in query.iter() {
-        attach_single_prefab(entity, attachment, &visuals, &mut attachments);
+        if let Err(error) = attach_single_prefab(entity, attachment, &visuals, &mut attachments) {
+            failures.write(error.expectation(Some(entity), "attach_prefabs_main_thread"));
+            continue;
+        }
+
+        // Контракт weapon prefab'а (тип — из WeaponStats актора)
+        if attachment.attachment_type != AttachmentType::Weapon {
+            continue;
+        }
+        let key = (entity, attachment.attachment_point.clone());
+        if let (Some(prefab), Ok(stats)) = (attachments.attachments.get(&key), weapons.get(entity)) {
+            validate_prefab(prefab, &attachment.prefab_path, &weapon_requirements(stats.is_ranged()));
+        }
     }
 }
 
@@ -50,15 +73,61 @@ pub fn detach_prefabs_main_thread(
     }
 }
 
+/// Sync prefab'ов брони по слотам (Helmet / Chest / Legs / Boots)
+///
+/// Один Attachment компонент на entity не вмещает 4 части — слоты синхронизируются напрямую:
+/// prefab из ItemDefinition на `ArmorSlot::attachment_point`, пустой слот / без prefab → detach.
+///
+/// NAMING: `_main_thread` суффикс = Godot API calls (NonSend resources)
+pub fn sync_armor_attachments_main_thread(
+    query: Query<(Entity, &EquippedArmor), Changed<EquippedArmor>>,
+    definitions: Res<ItemDefinitions>,
+    visuals: NonSend<VisualRegistry>,
+    mut attachments: NonSendMut<AttachmentRegistry>,
+    mut failures: EventWriter<ExpectationFailed>,
+) {
+    for (entity, armor) in query.iter() {
+        for slot in ArmorSlot::ALL {
+            let prefab_path = armor
+                .get(slot)
+                .and_then(|piece| definitions.get(&piece.definition_id))
+                .and_then(|def| def.prefab_path.clone())
+                .unwrap_or_default();
+
+            // Тот же prefab уже висит — не переинстанцируем (Changed срабатывает на любой слот)
+            let key = (entity, slot.attachment_point().to_string());
+            let attached_path = attachments
+                .attachments
+                .get(&key)
+                .map(|node| node.get_scene_file_path().to_string())
+                .unwrap_or_default();
+            if attached_path == prefab_path {
+                continue;
+            }
+
+            let attachment = Attachment {
+                prefab_path,
+                attachment_point: slot.attachment_point().to_string(),
+                attachment_type: AttachmentType::Armor,
+            };
+            if let Err(error) = attach_single_prefab(entity, &attachment, &visuals, &mut attachments) {
+                failures.write(error.expectation(Some(entity), "sync_armor_attachments_main_thread"));
+            }
+        }
+    }
+}
+
 // === Helper functions ===
 
 /// Attach single prefab to entity
+///
+/// Err — нет визуала / сокета / prefab'а (старый prefab при этом не трогается).
 fn attach_single_prefab(
     entity: Entity,
     attachment: &Attachment,
     visuals: &VisualRegistry,
     attachments: &mut AttachmentRegistry,
-) {
+) -> Result<(), BridgeError> {
     // SPECIAL CASE: Empty prefab_path → detach existing prefab
     if attachment.prefab_path.is_empty() {
         let key = (entity, attachment.attachment_point.clone());
@@ -71,26 +140,19 @@ fn attach_single_prefab(
             ));
             attached_node.queue_free();
         }
-        return;
+        return Ok(());
     }
 
     // 1. Найти host node
-    let Some(host_node) = visuals.visuals.get(&entity) else {
-        logger::log_error(&format!("attach_prefab: entity {:?} not in VisualRegistry", entity));
-        return;
-    };
+    let host_node = require_visual(visuals, entity)?;
 
     // 2. Найти attachment point
-    let Some(mut attachment_point_node) = find_node_by_path(host_node, &attachment.attachment_point) else {
-        logger::log_error(&format!(
-            "attach_prefab: attachment point '{}' not found in entity {:?}",
-            attachment.attachment_point,
-            entity
-        ));
-        return;
-    };
+    let mut attachment_point_node = require_node::<Node3D>(&host_node, &attachment.attachment_point)?;
+
+    // 3. Load TSCN prefab
+    let prefab_scene = load_prefab(&attachment.prefab_path)?;
 
-    // 3. Detach old prefab if exists (перед attach нового)
+    // 4. Detach old prefab if exists (перед attach нового)
     let key = (entity, attachment.attachment_point.clone());
     if let Some(mut old_node) = attachments.attachments.remove(&key) {
         logger::log(&format!(
@@ -100,19 +162,6 @@ fn attach_single_prefab(
         old_node.queue_free();
     }
 
-    // 4. Load TSCN prefab
-    let prefab_scene = match load_packed_scene(&attachment.prefab_path) {
-        Some(scene) => scene,
-        None => {
-            logger::log_error(&format!(
-                "attach_prefab: failed to load prefab '{}' for entity {:?}",
-                attachment.prefab_path,
-                entity
-            ));
-            return;
-        }
-    };
-
     // 5. Instantiate prefab
     let prefab_instance = prefab_scene.instantiate_as::<Node3D>();
 
@@ -120,7 +169,6 @@ fn attach_single_prefab(
     attachment_point_node.add_child(&prefab_instance);
 
     // 7. Register in AttachmentRegistry
-    let key = (entity, attachment.attachment_point.clone());
     attachments.attachments.insert(key, prefab_instance);
 
     logger::log(&format!(
@@ -129,28 +177,5 @@ fn attach_single_prefab(
         entity,
         attachment.attachment_point
     ));
-}
-
-/// Load PackedScene from Godot resource path
-fn load_packed_scene(path: &str) -> Option<Gd<PackedScene>> {
-    let mut resource_loader = godot::classes::ResourceLoader::singleton();
-
-    match resource_loader.load(path) {
-        Some(resource) => resource.try_cast::<PackedScene>().ok(),
-        None => {
-            godot_error!("load_packed_scene: failed to load '{}'", path);
-            None
-        }
-    }
-}
-
-/// Find child node by path (e.g. "RightHand/WeaponAttachment")
-fn find_node_by_path(root: &Gd<Node3D>, path: &str) -> Option<Gd<Node3D>> {
-    let node_path = NodePath::from(path);
-
-    if root.has_node(&node_path) {
-        Some(root.get_node_as::<Node3D>(&node_path))
-    } else {
-        None
-    }
+    Ok(())
 }
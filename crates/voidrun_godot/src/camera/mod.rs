@@ -16,12 +16,19 @@
 //! **Mouse Look (FPS only):**
 //! - Horizontal (yaw Y) → rotate Actor body
 //! - Vertical (pitch X) → rotate CameraPivot (clamped -30°/+89°)
+//! - В ADS чувствительность × AdsConfig::sensitivity_at (прицел оружия)
+//!
+//! **Recoil kick (FPS only):**
+//! - RecoilState.pending_kick (ECS, паттерн оружия) → pitch CameraPivot + yaw Actor body
+//! - В ADS подброс слабее (AimMode::recoil_multiplier)
 pub mod rts_camera;
 
 use bevy::prelude::*;
 use godot::classes::{Camera3D, Input, input};
 use godot::prelude::*;
 use voidrun_simulation::camera::{ActiveCamera, CameraMode};
+use voidrun_simulation::combat::{AdsConfig, RecoilState, WeaponStats};
+use voidrun_simulation::shooting::AimMode;
 use voidrun_simulation::player::Player;
 use voidrun_simulation::PrefabPath;
 use voidrun_simulation::logger;
@@ -60,7 +67,7 @@ pub fn setup_player_camera(
         // Create Camera3D as child of CameraPivot
         let mut camera = Camera3D::new_alloc();
         camera.set_name("PlayerCamera");
-        camera.set_fov(90.0);
+        camera.set_fov(AdsConfig::HIP_FIRE_FOV); // ADS зум — update_ads_fov_main_thread
         camera.set_current(true); // Make active
 
         camera_pivot.add_child(&camera.upcast::<godot::classes::Node>());
@@ -163,11 +170,16 @@ pub fn camera_toggle_system(
     }
 }
 
+/// Pitch limits CameraPivot (mouse look + recoil kick)
+const PITCH_DOWN_LIMIT: f32 = -80.0_f32.to_radians();
+const PITCH_UP_LIMIT: f32 = 89.0_f32.to_radians();
+
 /// Player mouse look system - rotate camera по mouse motion (FPS only)
 ///
 /// # Rotation
 /// - Horizontal (yaw Y) → rotate Actor body
 /// - Vertical (pitch X) → rotate CameraPivot (clamped -30°/+89°)
+/// - ADS: чувствительность × AdsConfig::sensitivity_at (оптика — медленнее поворот)
 ///
 /// # Pitch Limits
 /// - Up: +89° (почти вертикаль вверх, не ровно 90° для stability)
@@ -177,13 +189,19 @@ pub fn camera_toggle_system(
 /// - Update (обрабатываем mouse motion events)
 pub fn player_mouse_look(
     mut mouse_events: EventReader<MouseLookEvent>,
-    player_query: Query<(Entity, &ActiveCamera), With<Player>>,
+    player_query: Query<(Entity, &ActiveCamera, Option<&AimMode>, Option<&WeaponStats>), With<Player>>,
     visuals: NonSend<VisualRegistry>,
 ) {
-    let Ok((player_entity, active_camera)) = player_query.get_single() else {
+    let Ok((player_entity, active_camera, aim_mode, weapon)) = player_query.get_single() else {
         return;
     };
 
+    // Прицел оружия в ADS замедляет поворот (на transitions — частично)
+    let sensitivity_multiplier = match (aim_mode, weapon) {
+        (Some(aim_mode), Some(weapon)) => weapon.ads.sensitivity_at(aim_mode.ads_weight()),
+        _ => 1.0,
+    };
+
     // Only в FPS mode
     if active_camera.mode != CameraMode::FirstPerson {
         return;
@@ -195,11 +213,12 @@ pub fn player_mouse_look(
 
     for event in mouse_events.read() {
         const MOUSE_SENSITIVITY: f32 = 0.002; // Радианы за pixel (стандарт FPS)
+        let sensitivity = MOUSE_SENSITIVITY * sensitivity_multiplier;
 
         // Yaw (Y axis) - rotate player body
         let mut player_node_mut = player_node.clone();
         let mut player_rot = player_node_mut.get_rotation();
-        player_rot.y -= event.delta_x * MOUSE_SENSITIVITY;
+        player_rot.y -= event.delta_x * sensitivity;
         player_node_mut.set_rotation(player_rot);
 
         // Pitch (X axis) - rotate CameraPivot (clamped)
@@ -209,13 +228,53 @@ pub fn player_mouse_look(
         };
 
         let mut camera_rot = camera_pivot.get_rotation();
-        camera_rot.x -= event.delta_y * MOUSE_SENSITIVITY;
+        camera_rot.x -= event.delta_y * sensitivity;
 
         // Clamp pitch: -30° (down to chest) / +89° (up almost vertical)
-        const PITCH_DOWN_LIMIT: f32 = -80.0_f32.to_radians();
-        const PITCH_UP_LIMIT: f32 = 89.0_f32.to_radians();
         camera_rot.x = camera_rot.x.clamp(PITCH_DOWN_LIMIT, PITCH_UP_LIMIT);
 
         camera_pivot.set_rotation(camera_rot);
     }
 }
+
+/// Recoil kick system - подброс камеры от выстрелов (FPS only)
+///
+/// # Rotation
+/// - RecoilState.pending_kick.x (градусы) → pitch CameraPivot вверх (clamped как mouse look)
+/// - RecoilState.pending_kick.y (градусы) → yaw Actor body вправо
+/// - ADS: × AimMode::recoil_multiplier (упор оружия в плечо)
+///
+/// Подброс забирается всегда (в RTS режиме тоже) — не копится до возврата в FPS.
+///
+/// # Schedule
+/// - Update (после player_mouse_look, до ADS positioning — руки следуют за камерой)
+pub fn apply_recoil_kick_main_thread(
+    mut player_query: Query<(Entity, &ActiveCamera, &mut RecoilState, Option<&AimMode>), With<Player>>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    let Ok((player_entity, active_camera, mut recoil, aim_mode)) = player_query.get_single_mut() else {
+        return;
+    };
+
+    let kick = recoil.take_kick() * aim_mode.map_or(1.0, AimMode::recoil_multiplier);
+    if kick == Vec2::ZERO || active_camera.mode != CameraMode::FirstPerson {
+        return;
+    }
+
+    let Some(player_node) = visuals.visuals.get(&player_entity) else {
+        return;
+    };
+
+    let mut player_node_mut = player_node.clone();
+    let mut player_rot = player_node_mut.get_rotation();
+    player_rot.y -= kick.y.to_radians();
+    player_node_mut.set_rotation(player_rot);
+
+    let Some(mut camera_pivot) = player_node_mut.try_get_node_as::<godot::classes::Node3D>("%CameraPivot") else {
+        return;
+    };
+
+    let mut camera_rot = camera_pivot.get_rotation();
+    camera_rot.x = (camera_rot.x + kick.x.to_radians()).clamp(PITCH_DOWN_LIMIT, PITCH_UP_LIMIT);
+    camera_pivot.set_rotation(camera_rot);
+}
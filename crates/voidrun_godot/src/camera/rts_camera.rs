@@ -2,7 +2,9 @@ use godot::prelude::*;
 use godot::classes::{Node3D, Camera3D, InputEvent, InputEventMouseMotion, InputEventMouseButton, Input, input};
 use godot::global::{MouseButton, Key};
 use voidrun_simulation::logger;
-/// RTS-style camera: WASD movement, mouse drag orbit, scroll zoom
+/// RTS-style camera: WASD movement, mouse drag orbit (MMB), scroll zoom
+///
+/// LMB / RMB свободны — выделение юнитов и приказы (`rts_selection`).
 ///
 /// Hierarchy:
 ///   RTSCamera3D (root, moves horizontally)
@@ -36,7 +38,7 @@ pub struct RTSCamera3D {
     max_zoom: f32,
 
     // Input state
-    is_rotating: bool,  // Is RMB pressed for rotation?
+    is_rotating: bool,  // Is MMB pressed for rotation?
 }
 
 #[godot_api]
@@ -88,7 +90,7 @@ impl INode3D for RTSCamera3D {
             return; // Camera ещё не создана
         }
 
-        // Mouse drag rotation (RMB)
+        // Mouse drag rotation (MMB)
         if let Ok(motion) = event.clone().try_cast::<InputEventMouseMotion>() {
             if self.is_rotating {
                 let relative = motion.get_relative();
@@ -106,10 +108,10 @@ impl INode3D for RTSCamera3D {
             }
         }
 
-        // Mouse buttons (RMB for rotation, wheel for zoom)
+        // Mouse buttons (MMB for rotation, wheel for zoom)
         if let Ok(button) = event.clone().try_cast::<InputEventMouseButton>() {
             match button.get_button_index() {
-                MouseButton::RIGHT => {
+                MouseButton::MIDDLE => {
                     if button.is_pressed() {
                         self.is_rotating = true;
                         Input::singleton().set_mouse_mode(input::MouseMode::CAPTURED);
@@ -0,0 +1,58 @@
+//! Blackboard perception writer (Godot layer → ECS Blackboard).
+//!
+//! Один раз за кадр собирает данные о цели (позиция, скорость, knockdown / downed, cooldown),
+//! decision системы читают Blackboard вместо повторных Query/Godot lookups.
+
+use bevy::prelude::*;
+use voidrun_simulation::ai::{AIState, Blackboard, ThreatTable};
+use voidrun_simulation::combat::{ActorMotion, Downed, KnockdownState, WeaponStats};
+
+use crate::shared::VisualRegistry;
+
+/// System: обновить Blackboard AI акторов (Update, после track_actor_motion, перед decision).
+///
+/// Blackboard добавляет ECS (`insert_ai_blackboards`), здесь только заполнение.
+pub fn update_ai_blackboards_main_thread(
+    mut boards: Query<(Entity, &AIState, &WeaponStats, &mut Blackboard, Option<&ThreatTable>)>,
+    motions: Query<&ActorMotion>,
+    knockdowns: Query<&KnockdownState>,
+    downed: Query<(), With<Downed>>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    let velocity_of = |entity: Entity| {
+        motions.get(entity).map_or(Vec3::ZERO, |motion| motion.velocity)
+    };
+
+    for (entity, state, weapon, mut board, threat_table) in boards.iter_mut() {
+        board.self_velocity = velocity_of(entity);
+        board.weapon_ready = weapon.can_attack();
+
+        let AIState::Combat { target } = state else {
+            board.target = None;
+            board.target_velocity = Vec3::ZERO;
+            board.target_knockdown = None;
+            board.target_downed = false;
+            board.target_threat = 0.0;
+            continue;
+        };
+
+        // Новая цель → старая позиция не актуальна
+        if board.target != Some(*target) {
+            board.target = Some(*target);
+            board.last_target_position = None;
+        }
+
+        if let Some(target_node) = visuals.visuals.get(target) {
+            let pos = target_node.get_global_position();
+            board.last_target_position = Some(Vec3::new(pos.x, pos.y, pos.z));
+        }
+
+        board.target_velocity = velocity_of(*target);
+        board.target_threat = threat_table.map_or(0.0, |table| table.threat_of(*target));
+        board.target_knockdown = knockdowns
+            .get(*target)
+            .ok()
+            .map(|knockdown| (knockdown.phase, knockdown.timer));
+        board.target_downed = downed.contains(*target);
+    }
+}
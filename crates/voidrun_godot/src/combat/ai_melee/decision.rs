@@ -110,3 +110,30 @@ pub(super) fn execute_decision(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn option(action_type: ActionType, priority: f32) -> ActionOption {
+        ActionOption { action_type, priority, reason: "test" }
+    }
+
+    #[test]
+    fn test_highest_priority_wins() {
+        let attacker = Entity::from_raw(7);
+        let options = vec![
+            option(ActionType::Wait, 0.0),
+            option(ActionType::Attack { target: attacker }, 0.3),
+            option(ActionType::Parry { attacker, delay: 0.2 }, 0.8),
+        ];
+
+        let decision = choose_best_action(options, &CurrentAction::Idle);
+        assert!(matches!(decision, ActionType::Parry { attacker: a, .. } if a == attacker));
+    }
+
+    #[test]
+    fn test_no_options_waits() {
+        assert!(matches!(choose_best_action(Vec::new(), &CurrentAction::Idle), ActionType::Wait));
+    }
+}
@@ -86,6 +86,7 @@ pub(super) fn evaluate_available_actions(
     incoming_attacker: Entity,
     incoming_attack_type: AttackType,
     incoming_windup_remaining: f32,
+    parry_priority_bonus: f32,
     visuals: &NonSend<VisualRegistry>,
 ) -> Vec<ActionOption> {
     let mut options = Vec::new();
@@ -107,6 +108,7 @@ pub(super) fn evaluate_available_actions(
             incoming_attacker,
             incoming_attack_type,
             incoming_windup_remaining,
+            parry_priority_bonus,
             attacks,
             visuals,
         ) {
@@ -163,6 +165,12 @@ fn can_parry(current_action: &CurrentAction) -> bool {
     }
 }
 
+/// Задержка перед парированием: парирование заканчивает windup к моменту удара (± margin)
+fn parry_delay(windup_remaining: f32, margin: f32) -> f32 {
+    const PARRY_WINDUP: f32 = 0.1;
+    (windup_remaining - PARRY_WINDUP + margin).max(0.0)
+}
+
 /// Evaluate attack action option.
 ///
 /// Returns ActionOption with priority based on AI behavior.
@@ -190,6 +198,7 @@ fn evaluate_parry_option(
     attacker: Entity,
     attack_type: AttackType,
     windup_remaining: f32,
+    parry_priority_bonus: f32,
     attacks: &Query<&MeleeAttackState>,
     visuals: &NonSend<VisualRegistry>,
 ) -> Option<ActionOption> {
@@ -258,16 +267,18 @@ fn evaluate_parry_option(
     }
 
     // 6. Calculate delay for parry timing
-    let parry_windup = 0.1;
     let margin = rand::thread_rng().gen_range(-0.05..0.05); // ±50ms error
-    let delay = (windup_remaining - parry_windup + margin).max(0.0);
+    let delay = parry_delay(windup_remaining, margin);
 
     // 7. Determine priority based on AI behavior
     // TODO: When AIBehavior is implemented, use actual behavior
     // For now: use 50/50 random strategy (50% aggressive, 50% defensive)
     let defensive_strategy = rand::thread_rng().gen_bool(0.5);
 
-    let priority = if defensive_strategy { 0.8 } else { 0.6 };
+    let base_priority = if defensive_strategy { 0.8 } else { 0.6 };
+
+    // Difficulty scaling (AIConfig::parry_priority_bonus)
+    let priority = (base_priority + parry_priority_bonus).clamp(0.0, 1.0);
 
     logger::log(&format!(
         "🛡️ AI: Parry option available (defender: {:?}, attacker: {:?}, distance: {:.2}m, priority: {:.2})",
@@ -280,3 +291,47 @@ fn evaluate_parry_option(
         reason: "incoming attack detected",
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_can_attack_requires_stamina() {
+        let weapon = WeaponStats::melee_sword();
+
+        assert!(can_attack(&Stamina::new(100.0), &weapon, &CurrentAction::Idle));
+
+        let mut tired = Stamina::new(100.0);
+        tired.current = 10.0;
+        assert!(!can_attack(&tired, &weapon, &CurrentAction::Idle));
+    }
+
+    #[test]
+    fn test_windup_interruptible_only_early() {
+        let weapon = WeaponStats::melee_sword();
+        let stamina = Stamina::new(100.0);
+        let early = CurrentAction::AttackWindup { interruptible: true, progress: 0.2 };
+        let late = CurrentAction::AttackWindup { interruptible: false, progress: 0.8 };
+
+        assert!(can_attack(&stamina, &weapon, &early));
+        assert!(can_parry(&early));
+        assert!(!can_attack(&stamina, &weapon, &late));
+        assert!(!can_parry(&late));
+    }
+
+    #[test]
+    fn test_committed_actions_block_parry() {
+        assert!(!can_parry(&CurrentAction::AttackActive));
+        assert!(!can_parry(&CurrentAction::ParryWindup));
+        assert!(!can_parry(&CurrentAction::Staggered));
+        assert!(can_parry(&CurrentAction::AttackRecovery));
+    }
+
+    #[test]
+    fn test_parry_delay() {
+        assert!((parry_delay(0.5, 0.0) - 0.4).abs() < 1e-6);
+        // Поздняя реакция — парируем сразу
+        assert_eq!(parry_delay(0.05, -0.05), 0.0);
+    }
+}
@@ -29,10 +29,10 @@
 
 use bevy::prelude::*;
 use rand::Rng;
-use voidrun_simulation::ai::{AIState, GodotAIEvent};
+use voidrun_simulation::ai::{AIConfig, AIState, Blackboard, GodotAIEvent};
 use voidrun_simulation::combat::{
     AttackType, MeleeAttackIntent, MeleeAttackState, MeleeAttackType, ParryDelayTimer,
-    ParryState, StaggerState, WeaponStats,
+    FlinchState, KnockdownState, ParryState, StaggerState, WeaponStats, target_in_reach_at_hit,
 };
 use voidrun_simulation::{Stamina, Actor};
 use voidrun_simulation::player::Player;
@@ -45,10 +45,14 @@ use crate::shared::los_helpers::check_line_of_sight;
 mod evaluation;
 mod decision;
 mod validation;
+mod prediction;
+mod blackboard;
 
 // Re-export key functions
 use evaluation::{evaluate_available_actions, get_current_action};
 use decision::{choose_best_action, execute_decision};
+pub use prediction::track_actor_motion_main_thread;
+pub use blackboard::update_ai_blackboards_main_thread;
 
 // ============================================================================
 // Components
@@ -177,7 +181,7 @@ pub(super) struct ActionOption {
 /// - **Can start new attack after AttackRecovery** (cooldown permitting)
 pub fn ai_melee_combat_decision_main_thread(
     mut telegraph_events: EventReader<GodotAIEvent>,
-    ai_query: Query<(Entity, &AIState, &WeaponStats, &Stamina, &Actor), (Without<StaggerState>, Without<Player>)>,
+    ai_query: Query<(Entity, &AIState, &WeaponStats, &Stamina, &Actor, &Blackboard, Option<&AIConfig>), (Without<StaggerState>, Without<FlinchState>, Without<KnockdownState>, Without<Player>)>,
     actor_query: Query<&Actor>,
     attacks: Query<&MeleeAttackState>,
     parries: Query<&ParryState>,
@@ -244,7 +248,7 @@ pub fn ai_melee_combat_decision_main_thread(
     // ========================================================================
     // STEP 2: Process all AI in Combat state (O(n) with O(1) HashMap lookup)
     // ========================================================================
-    for (entity, ai_state, weapon, stamina, actor) in ai_query.iter() {
+    for (entity, ai_state, weapon, stamina, actor, blackboard, ai_config) in ai_query.iter() {
         // Only process AI in Combat state
         let AIState::Combat { target } = ai_state else {
             continue;
@@ -263,6 +267,7 @@ pub fn ai_melee_combat_decision_main_thread(
                 ai_state,
                 weapon,
                 stamina,
+                ai_config.map(|config| config.parry_priority_bonus).unwrap_or(0.0),
                 &attacks,
                 &parries,
                 &delay_timers,
@@ -287,6 +292,7 @@ pub fn ai_melee_combat_decision_main_thread(
                 actor,
                 weapon,
                 stamina,
+                blackboard,
                 &actor_query,
                 &attacks,
                 &parries,
@@ -315,6 +321,7 @@ fn react_to_incoming_attack(
     ai_state: &AIState,
     weapon: &WeaponStats,
     stamina: &Stamina,
+    parry_priority_bonus: f32,
     attacks: &Query<&MeleeAttackState>,
     parries: &Query<&ParryState>,
     delay_timers: &Query<&ParryDelayTimer>,
@@ -345,6 +352,7 @@ fn react_to_incoming_attack(
         attacker,
         attack_type,
         windup_remaining,
+        parry_priority_bonus,
         visuals,
     );
 
@@ -372,12 +380,18 @@ fn react_to_incoming_attack(
 /// - **Wait for opening** (defensive, wait for opponent to attack first)
 ///
 /// Randomized decision based on strategy.
+///
+/// Knockdown awareness: target лежит → всегда добивание (Execution),
+/// target встаёт → не бьём в get-up (ждём окончания).
+///
+/// Данные о цели (позиция, скорости, knockdown, cooldown) — из Blackboard.
 fn proactive_attack_decision(
     entity: Entity,
     target: Entity,
     entity_actor: &Actor,
     weapon: &WeaponStats,
     stamina: &Stamina,
+    blackboard: &Blackboard,
     actor_query: &Query<&Actor>,
     attacks: &Query<&MeleeAttackState>,
     parries: &Query<&ParryState>,
@@ -418,6 +432,19 @@ fn proactive_attack_decision(
         return;
     }
 
+    // 2.5. Knockdown: встающего не трогаем, лежачего добиваем
+    if let Some(getup_remaining) = blackboard.target_getting_up() {
+        commands.entity(entity).insert(WaitingForOpening {
+            timer: getup_remaining,
+        });
+        logger::log(&format!(
+            "🧍 PROACTIVE: entity {:?} backs off, target {:?} is getting up",
+            entity, target
+        ));
+        return;
+    }
+    let finisher = blackboard.target_is_down();
+
     // 3. Line-of-Sight Check: Не атаковать если LOS blocked
     // NOTE: movement_system.rs обработает LOS clearing через NavigationAgent
     match check_line_of_sight(entity, target, visuals, scene_root) {
@@ -442,18 +469,32 @@ fn proactive_attack_decision(
         }
     }
 
+    // 3.5. Intercept: будет ли цель в досягаемости к моменту удара (не по текущей дистанции)
+    let (Some(attacker_node), Some(target_pos)) = (visuals.visuals.get(&entity), blackboard.last_target_position) else {
+        return;
+    };
+    if !target_in_reach_at_hit(
+        prediction::to_vec3(attacker_node.get_global_position()),
+        blackboard.self_velocity,
+        target_pos,
+        blackboard.target_velocity,
+        weapon,
+    ) {
+        return;
+    }
+
     // 4. Check if can attack (stamina, cooldown)
     const ATTACK_COST: f32 = 30.0;
     if stamina.current < ATTACK_COST {
         return;
     }
 
-    if !weapon.can_attack() {
+    if !blackboard.weapon_ready {
         return;
     }
 
-    // 5. Random decision: Attack (60%) vs Wait for Opening (40%)
-    let should_attack = rand::thread_rng().gen_bool(0.6);
+    // 5. Random decision: Attack (60%) vs Wait for Opening (40%), лежачего добиваем всегда
+    let should_attack = finisher || rand::thread_rng().gen_bool(0.6);
 
     if should_attack {
         // ========================================
@@ -461,7 +502,11 @@ fn proactive_attack_decision(
         // ========================================
         attack_intent_events.write(MeleeAttackIntent {
             attacker: entity,
-            attack_type: MeleeAttackType::Normal,
+            attack_type: if finisher {
+                MeleeAttackType::Execution
+            } else {
+                MeleeAttackType::Normal
+            },
         });
 
         logger::log(&format!(
@@ -0,0 +1,43 @@
+//! Target motion tracking (сэмплы позиций для melee intercept).
+//!
+//! Математика перехвата — в `voidrun_simulation::combat` (`ActorMotion`, `melee_intercept_point`,
+//! `target_in_reach_at_hit`). Здесь только чтение позиций Godot nodes.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use voidrun_simulation::Actor;
+use voidrun_simulation::combat::ActorMotion;
+
+use crate::shared::VisualRegistry;
+
+/// Godot position → ECS Vec3
+pub(crate) fn to_vec3(position: Vector3) -> Vec3 {
+    Vec3::new(position.x, position.y, position.z)
+}
+
+/// System: обновить ActorMotion всех акторов с визуалом (Update, перед AI decision).
+///
+/// Первый кадр — только запоминаем позицию (скорость 0).
+pub fn track_actor_motion_main_thread(
+    mut tracked: Query<(Entity, &mut ActorMotion)>,
+    untracked: Query<Entity, (With<Actor>, Without<ActorMotion>)>,
+    visuals: NonSend<VisualRegistry>,
+    time: Res<crate::shared::GodotDeltaTime>,
+    mut commands: Commands,
+) {
+    for entity in untracked.iter() {
+        let Some(node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        commands.entity(entity).insert(ActorMotion::at(to_vec3(node.get_global_position())));
+    }
+
+    for (entity, mut motion) in tracked.iter_mut() {
+        let Some(node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        motion.record(to_vec3(node.get_global_position()), time.0);
+    }
+}
@@ -15,16 +15,38 @@ pub(super) fn is_facing_attacker(
     defender_node: &Gd<godot::classes::CharacterBody3D>,
     attacker_node: &Gd<godot::classes::CharacterBody3D>,
 ) -> bool {
-    let defender_pos = defender_node.get_global_position();
-    let attacker_pos = attacker_node.get_global_position();
-
-    let to_attacker = (attacker_pos - defender_pos).normalized();
-
     // Godot forward = -Z axis (Transform basis column C)
     let defender_forward = -defender_node.get_global_transform().basis.col_c();
 
-    let dot = to_attacker.dot(defender_forward);
+    is_in_front(
+        defender_node.get_global_position(),
+        defender_forward,
+        attacker_node.get_global_position(),
+    )
+}
+
+/// Facing check без nodes: attacker в переднем конусе defender'а (dot > 0.5)
+pub(super) fn is_in_front(defender_pos: Vector3, defender_forward: Vector3, attacker_pos: Vector3) -> bool {
+    let to_attacker = (attacker_pos - defender_pos).normalized();
 
     // dot > 0.5 means ~60° cone in front
-    dot > 0.5
+    to_attacker.dot(defender_forward) > 0.5
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORWARD: Vector3 = Vector3::new(0.0, 0.0, -1.0);
+
+    #[test]
+    fn test_attacker_in_front() {
+        assert!(is_in_front(Vector3::ZERO, FORWARD, Vector3::new(0.5, 0.0, -2.0)));
+    }
+
+    #[test]
+    fn test_attacker_behind_or_side() {
+        assert!(!is_in_front(Vector3::ZERO, FORWARD, Vector3::new(0.0, 0.0, 2.0)));
+        assert!(!is_in_front(Vector3::ZERO, FORWARD, Vector3::new(2.0, 0.0, -0.5)));
+    }
 }
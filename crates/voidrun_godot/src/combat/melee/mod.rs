@@ -33,8 +33,8 @@ use voidrun_simulation::logger;
 use bevy::prelude::*;
 use godot::prelude::*;
 use voidrun_simulation::combat::{
-    MeleeAttackIntent, MeleeAttackStarted, MeleeAttackState, AttackPhase,
-    WeaponStats, ParryState,
+    MeleeAttackIntent, MeleeAttackStarted, MeleeAttackState, MeleeAttackType, MeleeTimings, AttackPhase,
+    WeaponStats, ParryState, BASH_DAMAGE, BASH_RANGE, SHIELD_BASH_DAMAGE, SHIELD_BASH_RANGE,
 };
 use voidrun_simulation::*;
 use voidrun_simulation::combat::{AttackType};
@@ -43,6 +43,7 @@ use crate::shared::VisualRegistry;
 use crate::shared::actor_utils::{actors_facing_each_other, angles};
 
 use crate::shared::{AttachmentRegistry};
+use crate::shared::lookup::require_node;
 
 /// System: Process melee attack intents (Godot tactical validation).
 ///
@@ -73,13 +74,14 @@ pub fn process_melee_attack_intents_main_thread(
             continue;
         };
 
-        // Validation passed → generate MeleeAttackStarted
+        // Validation passed → generate MeleeAttackStarted (Bash / ShieldBash — фиксированные тайминги)
+        let timings = MeleeTimings::for_attack(&intent.attack_type, weapon);
         started_events.write(MeleeAttackStarted {
             attacker: intent.attacker,
             attack_type: intent.attack_type.clone(),
-            windup_duration: weapon.windup_duration,
-            attack_duration: weapon.attack_duration,
-            recovery_duration: weapon.recovery_duration,
+            windup_duration: timings.windup,
+            attack_duration: timings.attack_duration,
+            recovery_duration: timings.recovery,
         });
 
         logger::log(&format!(
@@ -103,6 +105,10 @@ pub fn process_melee_attack_intents_main_thread(
 /// Dynamically adjusts AnimationPlayer speed_scale to match weapon timings.
 /// This allows different weapon types to have different attack speeds without
 /// creating separate animation files.
+///
+/// **Bash (удар прикладом) / ShieldBash (толчок щитом):** одна анимация ("melee_bash" /
+/// "shield_bash") на всю атаку (старт в Windup), hitbox оружия не трогаем — у ranged prefab'а
+/// его нет, а щит бьёт не оружием (цели ищет poll_melee_hitboxes_main_thread).
 pub fn execute_melee_attacks_main_thread(
     query: Query<(Entity, &MeleeAttackState), Changed<MeleeAttackState>>,
     visuals: NonSend<VisualRegistry>,
@@ -115,6 +121,11 @@ pub fn execute_melee_attacks_main_thread(
             continue;
         };
 
+        if let Some(profile) = BashProfile::for_attack(&attack_state.attack_type) {
+            execute_bash_animation(entity, attack_state, &profile, &visuals);
+            continue;
+        }
+
         // Get weapon attachment (for hitbox control)
         let Some(weapon_attachment) = attachments.attachments.get(&(entity, "%RightHandAttachment".to_string())) else {
             logger::log(&format!(
@@ -221,6 +232,81 @@ pub fn execute_melee_attacks_main_thread(
     }
 }
 
+/// Атаки без hitbox оружия (Bash / ShieldBash): своя анимация + конус поиска целей
+struct BashProfile {
+    animation: &'static str,
+    /// Длительность всей атаки (под неё подгоняется speed_scale анимации)
+    total_duration: f32,
+    range: f32,
+    /// cos половины угла конуса (см. `angles`)
+    cone: f32,
+    damage: u32,
+    label: &'static str,
+}
+
+impl BashProfile {
+    fn for_attack(attack_type: &MeleeAttackType) -> Option<Self> {
+        match attack_type {
+            MeleeAttackType::Bash => Some(Self {
+                animation: "melee_bash",
+                total_duration: MeleeTimings::BASH.total(),
+                range: BASH_RANGE,
+                cone: angles::MODERATE_45_DEG,
+                damage: BASH_DAMAGE,
+                label: "Bash",
+            }),
+            // Щит шире приклада — конус 60°
+            MeleeAttackType::ShieldBash => Some(Self {
+                animation: "shield_bash",
+                total_duration: MeleeTimings::SHIELD_BASH.total(),
+                range: SHIELD_BASH_RANGE,
+                cone: angles::WIDE_60_DEG,
+                damage: SHIELD_BASH_DAMAGE,
+                label: "Shield bash",
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Bash / ShieldBash: одна анимация на всю атаку (Windup → Idle), speed подгоняется под MeleeTimings
+fn execute_bash_animation(
+    entity: Entity,
+    attack_state: &MeleeAttackState,
+    profile: &BashProfile,
+    visuals: &VisualRegistry,
+) {
+    let Some(attacker_node) = visuals.visuals.get(&entity) else {
+        return;
+    };
+    let Some(mut player) = attacker_node
+        .try_get_node_as::<godot::classes::AnimationPlayer>("MeleeSwingAnimationPlayer")
+    else {
+        return;
+    };
+
+    match &attack_state.phase {
+        AttackPhase::Windup { .. } => {
+            let anim_length = get_animation_length(&mut player, profile.animation);
+            let speed_scale = anim_length / profile.total_duration;
+
+            player.set_speed_scale(speed_scale);
+            player.play_ex().name(profile.animation).done();
+
+            logger::log(&format!(
+                "▶️ Godot: Playing '{}' (entity: {:?}, speed: {:.2}x)",
+                profile.animation, entity, speed_scale
+            ));
+        }
+        AttackPhase::Idle => {
+            player.set_speed_scale(1.0);
+            player.play_ex().name("RESET").done();
+        }
+        // Active / Recovery — анимация уже идёт с Windup
+        _ => {}
+    }
+}
+
 /// System: Poll melee hitbox overlaps during Active phase
 ///
 /// Checks Area3D.get_overlapping_bodies() every frame during Active phase.
@@ -228,11 +314,14 @@ pub fn execute_melee_attacks_main_thread(
 ///
 /// **Anti-spam:** Uses `hit_entities` to track all entities hit this attack.
 /// **CHANGED:** Multi-target support (cleave damage), no single target restriction.
+/// **Bash / ShieldBash:** hitbox нет — цели в конусе перед атакующим (см. `BashProfile`).
 pub fn poll_melee_hitboxes_main_thread(
     mut query: Query<(Entity, &mut MeleeAttackState)>,
+    actors: Query<(), With<Actor>>,
     visuals: NonSend<VisualRegistry>,
     attachments: NonSend<AttachmentRegistry>,
     mut melee_hit_events: EventWriter<voidrun_simulation::combat::MeleeHit>,
+    mut failures: EventWriter<ExpectationFailed>,
 ) {
     for (attacker, mut attack_state) in query.iter_mut() {
         // Only check during ActiveHitbox phase (NOT ActiveParryWindow!)
@@ -240,17 +329,23 @@ pub fn poll_melee_hitboxes_main_thread(
             continue;
         };
 
+        if let Some(profile) = BashProfile::for_attack(&attack_state.attack_type) {
+            poll_bash_targets(attacker, &mut attack_state, &profile, &actors, &visuals, &mut melee_hit_events);
+            continue;
+        }
+
         // Get weapon attachment
         let Some(weapon_attachment) = attachments.attachments.get(&(attacker, "%RightHandAttachment".to_string())) else {
             continue;
         };
 
-        // Get hitbox
-        let Some(weapon_placement) = weapon_attachment.try_get_node_as::<Node3D>("WeaponPlacement") else {
-            continue;
-        };
-        let Some(hitbox) = weapon_placement.try_get_node_as::<godot::classes::Area3D>("Hitbox") else {
-            continue;
+        // Get hitbox (melee prefab обязан иметь WeaponPlacement/Hitbox)
+        let hitbox = match require_node::<godot::classes::Area3D>(weapon_attachment, "WeaponPlacement/Hitbox") {
+            Ok(hitbox) => hitbox,
+            Err(error) => {
+                failures.write(error.expectation(Some(attacker), "poll_melee_hitboxes_main_thread"));
+                continue;
+            }
         };
 
         // Poll overlapping bodies
@@ -324,6 +419,62 @@ pub fn poll_melee_hitboxes_main_thread(
     }
 }
 
+/// Bash / ShieldBash: акторы в `profile.range` и в конусе перед атакующим → MeleeHit(`profile.damage`)
+fn poll_bash_targets(
+    attacker: Entity,
+    attack_state: &mut MeleeAttackState,
+    profile: &BashProfile,
+    actors: &Query<(), With<Actor>>,
+    visuals: &VisualRegistry,
+    melee_hit_events: &mut EventWriter<voidrun_simulation::combat::MeleeHit>,
+) {
+    let Some(attacker_node) = visuals.visuals.get(&attacker) else {
+        return;
+    };
+    let attacker_pos = attacker_node.get_global_position();
+    // Godot actors face -Z
+    let forward = -attacker_node.get_global_transform().basis.col_c();
+
+    for (&target_entity, target_node) in visuals.visuals.iter() {
+        if target_entity == attacker
+            || attack_state.hit_entities.contains(&target_entity)
+            || actors.get(target_entity).is_err()
+        {
+            continue;
+        }
+
+        let target_pos = target_node.get_global_position();
+        let offset = target_pos - attacker_pos;
+        if offset.length() > profile.range {
+            continue;
+        }
+
+        let direction = offset.normalized();
+        if forward.dot(direction) < profile.cone {
+            continue;
+        }
+
+        let impact_point = bevy::prelude::Vec3::new(target_pos.x, target_pos.y + 0.8, target_pos.z);
+        let impact_normal = bevy::prelude::Vec3::new(direction.x, direction.y, direction.z);
+
+        melee_hit_events.write(voidrun_simulation::combat::MeleeHit {
+            attacker,
+            target: target_entity,
+            damage: profile.damage,
+            was_blocked: false,
+            was_parried: false,
+            impact_point,
+            impact_normal,
+        });
+        attack_state.hit_entities.push(target_entity);
+
+        logger::log(&format!(
+            "💥 Godot: {} hit (attacker: {:?}, target: {:?})",
+            profile.label, attacker, target_entity
+        ));
+    }
+}
+
 /// Get animation length from AnimationPlayer.
 ///
 /// Returns the length of the specified animation in seconds.
@@ -473,6 +624,93 @@ pub fn execute_stagger_animations_main_thread(
 
 
 
+/// System: Execute flinch animations (FlinchTriggered events from ECS).
+///
+/// - Light: plays "flinch_light" on FlinchAnimationPlayer (upper-body, поверх текущей анимации)
+/// - Heavy: interrupt attack (MeleeSwingAnimationPlayer → RESET) + "flinch_heavy"
+/// - Knockdown: interrupt attack + "knockdown" (подъём — `execute_knockdown_getup_main_thread`)
+///
+/// Prefab без FlinchAnimationPlayer → только interrupt (для heavy), light игнорируется.
+pub fn execute_flinch_animations_main_thread(
+    mut flinch_events: EventReader<voidrun_simulation::combat::FlinchTriggered>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    use voidrun_simulation::combat::FlinchKind;
+
+    for event in flinch_events.read() {
+        let Some(node) = visuals.visuals.get(&event.entity) else {
+            continue;
+        };
+
+        let anim_name = match event.kind {
+            FlinchKind::Light => "flinch_light",
+            FlinchKind::Heavy | FlinchKind::Knockdown => {
+                // Heavy flinch / knockdown прерывает атаку (ECS уже снял MeleeAttackState)
+                if let Some(mut swing_player) = node
+                    .try_get_node_as::<godot::classes::AnimationPlayer>("MeleeSwingAnimationPlayer")
+                {
+                    swing_player.set_speed_scale(1.0);
+                    swing_player.play_ex().name("RESET").done();
+                }
+
+                if event.kind == FlinchKind::Knockdown {
+                    "knockdown"
+                } else {
+                    "flinch_heavy"
+                }
+            }
+        };
+
+        let Some(mut anim_player) = node
+            .try_get_node_as::<godot::classes::AnimationPlayer>("FlinchAnimationPlayer")
+        else {
+            continue;
+        };
+
+        if !anim_player.has_animation(anim_name) {
+            continue;
+        }
+
+        anim_player.set_speed_scale(1.0);
+        anim_player.play_ex().name(anim_name).done();
+
+        logger::log(&format!(
+            "😣 Godot: Playing '{}' (entity: {:?}, source: {:?})",
+            anim_name, event.entity, event.source
+        ));
+    }
+}
+
+/// System: Execute get-up animation (KnockdownGetUp events from ECS).
+///
+/// Plays "get_up" on FlinchAnimationPlayer (та же нода, что и "knockdown").
+/// Prefab без анимации → тихо пропускаем (ECS всё равно держит GettingUp окно).
+pub fn execute_knockdown_getup_main_thread(
+    mut getup_events: EventReader<voidrun_simulation::combat::KnockdownGetUp>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    for event in getup_events.read() {
+        let Some(node) = visuals.visuals.get(&event.entity) else {
+            continue;
+        };
+
+        let Some(mut anim_player) = node
+            .try_get_node_as::<godot::classes::AnimationPlayer>("FlinchAnimationPlayer")
+        else {
+            continue;
+        };
+
+        if !anim_player.has_animation("get_up") {
+            continue;
+        }
+
+        anim_player.set_speed_scale(1.0);
+        anim_player.play_ex().name("get_up").done();
+
+        logger::log(&format!("🧍 Godot: Playing 'get_up' (entity: {:?})", event.entity));
+    }
+}
+
 // ============================================================================
 // Systems: Melee Windup Detection (Tactical Layer)
 // ============================================================================
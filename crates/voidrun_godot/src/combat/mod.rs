@@ -6,6 +6,7 @@
 //! - **melee**: Melee attack execution (animations, hitboxes, parry)
 //! - **ai_melee**: AI combat decision-making (unified attack/parry decisions)
 //! - **ranged**: Ranged combat (targeting, firing, projectile physics)
+//! - **thrown**: Метательное оружие (бросок по дуге → MeleeHit + WorldItem)
 //!
 //! # Design Rationale
 //!
@@ -19,10 +20,12 @@
 //! - `melee/`: Melee attack execution (Godot tactical layer)
 //! - `ai_melee/`: AI unified combat decision system
 //! - `ranged/`: Ranged weapon targeting, firing, projectile physics
+//! - `thrown`: Thrown weapons (ThrowableLaunched → GodotThrownItem → MeleeHit / ThrowableLanded)
 
 pub mod melee;
 pub mod ai_melee;
 pub mod ranged;
+pub mod thrown;
 
 // Re-export melee systems
 pub use melee::{
@@ -31,11 +34,16 @@ pub use melee::{
     poll_melee_hitboxes_main_thread,
     execute_parry_animations_main_thread,
     execute_stagger_animations_main_thread,
+    execute_flinch_animations_main_thread,
+    execute_knockdown_getup_main_thread,
     detect_melee_windups_main_thread,
 };
 
 // Re-export AI combat decision system
-pub use ai_melee::ai_melee_combat_decision_main_thread;
+pub use ai_melee::{
+    ai_melee_combat_decision_main_thread, track_actor_motion_main_thread,
+    update_ai_blackboards_main_thread,
+};
 
 // Re-export ranged combat systems
 pub use ranged::{
@@ -48,4 +56,8 @@ pub use ranged::{
     // Projectiles
     projectile_collision_system_main_thread,
     projectile_shield_collision_main_thread,
+    projectile_near_miss_detection_main_thread,
 };
+
+// Re-export thrown weapon systems
+pub use thrown::{spawn_thrown_items_main_thread, process_thrown_item_impacts_main_thread};
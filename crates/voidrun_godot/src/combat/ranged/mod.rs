@@ -16,4 +16,5 @@ pub use ranged_attack::{process_ranged_attack_intents_main_thread, weapon_fire_m
 pub use projectile::{
     projectile_collision_system_main_thread,
     projectile_shield_collision_main_thread,
+    projectile_near_miss_detection_main_thread,
 };
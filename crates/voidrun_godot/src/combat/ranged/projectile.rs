@@ -2,7 +2,7 @@
 
 use bevy::prelude::*;
 use voidrun_simulation::*;
-use voidrun_simulation::combat::{AttackType, MeleeAttackState, WeaponStats};
+use voidrun_simulation::combat::{AttackType, MeleeAttackState, ProjectileBallistics, WeaponStats};
 use voidrun_simulation::ai::{GodotAIEvent, SpottedEnemies};
 use crate::shared::VisualRegistry;
 use crate::shared::actor_utils::{actors_facing_each_other, angles};
@@ -16,7 +16,13 @@ use voidrun_simulation::logger;
 ///
 /// Reads collision info from GodotProjectile nodes.
 /// Generates ProjectileHit events для ECS damage processing.
-/// Despawns projectiles after processing.
+///
+/// Пробитие / рикошет (ProjectileBallistics из WeaponStats):
+/// - Актор: ProjectileHit; мощности хватает на его сопротивление → пуля летит дальше
+///   (урон × PENETRATION_DAMAGE_FACTOR, второй цели достаётся меньше)
+/// - Стена вскользь (угол ≤ ricochet_angle) → рикошет с seeded разбросом (урон × RICOCHET_DAMAGE_FACTOR)
+/// - Тонкая стена (meta penetration_resistance) → пробитие
+/// - Иначе пуля despawn'ится
 ///
 /// **Frequency:** Every frame (60 Hz)
 pub fn projectile_collision_system_main_thread(
@@ -24,6 +30,8 @@ pub fn projectile_collision_system_main_thread(
     visuals: NonSend<VisualRegistry>,
     mut projectile_hit_events: EventWriter<voidrun_simulation::combat::ProjectileHit>,
 ) {
+    use godot::prelude::Vector3;
+
     // Cleanup destroyed projectiles first
     registry.cleanup_destroyed();
 
@@ -36,12 +44,48 @@ pub fn projectile_collision_system_main_thread(
             continue;  // No collision yet
         };
 
-        // Reverse lookup: InstanceId → Entity
+        // Reverse lookup: InstanceId → Entity (не actor → стена / окружение)
         let Some(&target_entity) = visuals.node_to_entity.get(&collision_info.target_instance_id) else {
-            logger::log(&format!(
-                "⚠️ Projectile collision with unknown entity (InstanceId: {:?})",
-                collision_info.target_instance_id
-            ));
+            let (ballistics, direction, ricochets, seed) = {
+                let bound = projectile.bind();
+                (bound.ballistics, bound.direction, bound.ricochets, bound.ricochet_seed)
+            };
+            let direction_bevy = bevy::prelude::Vec3::new(direction.x, direction.y, direction.z);
+            let normal = collision_info.impact_normal;
+            let normal_bevy = bevy::prelude::Vec3::new(normal.x, normal.y, normal.z);
+
+            if ballistics.should_ricochet(direction_bevy, normal_bevy, ricochets) {
+                let bounced = ProjectileBallistics::ricochet_direction(
+                    direction_bevy,
+                    normal_bevy,
+                    seed.wrapping_add(ricochets as u64),
+                );
+                {
+                    let mut bound = projectile.bind_mut();
+                    bound.direction = Vector3::new(bounced.x, bounced.y, bounced.z);
+                    bound.damage = ProjectileBallistics::reduced_damage(bound.damage, ProjectileBallistics::RICOCHET_DAMAGE_FACTOR);
+                    bound.ricochets += 1;
+                    bound.penetrations += 1;
+                    bound.collision_info = None;
+                }
+                projectile.set_global_position(collision_info.impact_point + normal * 0.05);
+
+                logger::log(&format!(
+                    "↗️ Projectile ricochet at {:?} (normal: {:?}, new dir: {:?})",
+                    collision_info.impact_point, normal, bounced
+                ));
+                continue;
+            }
+
+            if pass_through(&mut *projectile, &collision_info) {
+                logger::log(&format!(
+                    "🧱 Projectile penetrated wall at {:?}",
+                    collision_info.impact_point
+                ));
+                continue;
+            }
+
+            // Стена остановила пулю
             to_remove.push(*instance_id);
             projectile.queue_free();
             continue;
@@ -60,7 +104,10 @@ pub fn projectile_collision_system_main_thread(
         };
 
         // ✅ Generate ProjectileHit event (Godot → ECS) with impact data
-        let damage = projectile.bind().damage;
+        let (damage, penetrations, distance) = {
+            let bound = projectile.bind();
+            (bound.damage, bound.penetrations, bound.distance_travelled)
+        };
         let impact_point = bevy::prelude::Vec3::new(
             collision_info.impact_point.x,
             collision_info.impact_point.y,
@@ -78,13 +125,20 @@ pub fn projectile_collision_system_main_thread(
             damage,
             impact_point,
             impact_normal,
+            penetrations,
+            distance,
         });
 
         logger::log(&format!(
-            "💥 Projectile hit! Shooter: {:?} → Target: {:?}, Damage: {} at {:?} (normal: {:?})",
-            shooter, target_entity, damage, impact_point, impact_normal
+            "💥 Projectile hit! Shooter: {:?} → Target: {:?}, Damage: {} at {:?} (normal: {:?}, penetrations: {}, distance: {:.1}m)",
+            shooter, target_entity, damage, impact_point, impact_normal, penetrations, distance
         ));
 
+        // Пробили тело → летит ко второй цели
+        if pass_through(&mut *projectile, &collision_info) {
+            continue;
+        }
+
         // Despawn projectile
         to_remove.push(*instance_id);
         projectile.queue_free();
@@ -96,6 +150,24 @@ pub fn projectile_collision_system_main_thread(
     }
 }
 
+/// Helper: пробить преграду (мощность ≥ сопротивления) → пуля летит дальше с уменьшенным уроном
+fn pass_through(
+    projectile: &mut godot::prelude::Gd<crate::projectiles::GodotProjectile>,
+    collision_info: &crate::projectiles::projectile::ProjectileCollisionInfo,
+) -> bool {
+    let mut bound = projectile.bind_mut();
+    let Some(left) = ProjectileBallistics::penetrate(bound.penetration_left, collision_info.resistance) else {
+        return false;
+    };
+
+    bound.penetration_left = left;
+    bound.damage = ProjectileBallistics::reduced_damage(bound.damage, ProjectileBallistics::PENETRATION_DAMAGE_FACTOR);
+    bound.penetrations += 1;
+    bound.passed_through.push(collision_info.target_instance_id);
+    bound.collision_info = None;
+    true
+}
+
 /// System: Projectile → Shield collision detection (Godot tactical layer)
 ///
 /// Architecture (Hybrid approach):
@@ -105,6 +177,7 @@ pub fn projectile_collision_system_main_thread(
 ///
 /// **Self-shield bypass:** shooter == target check (own projectiles don't hit own shield)
 /// **Depleted shield bypass:** energy <= 0 → projectile passes through (checked in ECS)
+/// **Directional shield bypass:** попадание вне фронтальной дуги (`ShieldCoverage::covers`) → насквозь
 /// **VFX feedback:** Ripple effect on shield mesh (shader uniforms updated in shield_vfx_system.rs)
 pub fn projectile_shield_collision_main_thread(
     mut registry: NonSendMut<crate::projectiles::GodotProjectileRegistry>,
@@ -157,6 +230,25 @@ pub fn projectile_shield_collision_main_thread(
             continue;
         }
 
+        // ✅ Directional shield bypass: угол попадания вне дуги → projectile continues (в тело)
+        if let Some(target_node) = visuals.visuals.get(&target_entity) {
+            // Godot actors face -Z
+            let forward = -target_node.get_global_transform().basis.col_c();
+            let to_impact = collision_info.impact_point - target_node.get_global_position();
+            let covered = target_shield.coverage.covers(
+                bevy::prelude::Vec3::new(forward.x, forward.y, forward.z),
+                bevy::prelude::Vec3::new(to_impact.x, to_impact.y, to_impact.z),
+            );
+            if !covered {
+                logger::log(&format!(
+                    "🛡️ Directional shield bypass: target={:?} (impact outside {:?})",
+                    target_entity, target_shield.coverage
+                ));
+                projectile.bind_mut().shield_collision_info = None;
+                continue;
+            }
+        }
+
         // ✅ Generate ProjectileShieldHit event (Godot → ECS)
         let damage = projectile.bind().damage;
         let impact_point = bevy::prelude::Vec3::new(
@@ -195,6 +287,80 @@ pub fn projectile_shield_collision_main_thread(
     }
 }
 
+/// Near-miss радиус (метры от центра актора)
+const NEAR_MISS_RADIUS: f32 = 2.0;
+
+/// Высота "центра масс" актора над origin node (метры)
+const NEAR_MISS_ACTOR_CENTER_HEIGHT: f32 = 1.0;
+
+/// System: Projectile near-miss detection (Godot → ECS suppression)
+///
+/// Пуля "пролетела мимо" актора, если на этом кадре она пересекла плоскость,
+/// перпендикулярную полёту и проходящую через центр актора
+/// (актор был впереди на `previous_position` и позади сейчас),
+/// и перпендикулярная дистанция до траектории < NEAR_MISS_RADIUS.
+///
+/// Пересечение плоскости случается один раз → одно событие на пару (projectile, actor).
+/// Попадания сюда не доходят: projectile_collision_system удаляет пулю раньше.
+///
+/// **Frequency:** Every frame (60 Hz)
+pub fn projectile_near_miss_detection_main_thread(
+    registry: NonSend<crate::projectiles::GodotProjectileRegistry>,
+    visuals: NonSend<VisualRegistry>,
+    mut near_miss_events: EventWriter<voidrun_simulation::combat::ProjectileNearMiss>,
+) {
+    use godot::prelude::Vector3;
+
+    for projectile in registry.projectiles.values() {
+        if !projectile.is_instance_valid() {
+            continue;
+        }
+
+        let (shooter, direction, previous_position) = {
+            let bound = projectile.bind();
+            (bound.shooter, bound.direction, bound.previous_position)
+        };
+        let current_position = projectile.get_global_position();
+
+        for (&entity, actor_node) in visuals.visuals.iter() {
+            if entity == shooter {
+                continue;
+            }
+
+            let actor_center = actor_node.get_global_position()
+                + Vector3::UP * NEAR_MISS_ACTOR_CENTER_HEIGHT;
+
+            // Актор был впереди пули и теперь позади?
+            let was_ahead = (actor_center - previous_position).dot(direction) > 0.0;
+            let is_behind = (actor_center - current_position).dot(direction) <= 0.0;
+            if !(was_ahead && is_behind) {
+                continue;
+            }
+
+            // Перпендикулярная дистанция от центра актора до траектории
+            let to_actor = actor_center - current_position;
+            let along = to_actor.dot(direction);
+            let distance = (to_actor - direction * along).length();
+
+            if distance >= NEAR_MISS_RADIUS {
+                continue;
+            }
+
+            near_miss_events.write(voidrun_simulation::combat::ProjectileNearMiss {
+                shooter,
+                target: entity,
+                distance,
+                radius: NEAR_MISS_RADIUS,
+            });
+
+            logger::log(&format!(
+                "💨 Projectile near-miss: shooter={:?} → {:?} ({:.2}m)",
+                shooter, entity, distance
+            ));
+        }
+    }
+}
+
 // ============================================================================
 // Systems: Melee Windup Detection (Tactical Layer)
 // ============================================================================
@@ -4,7 +4,10 @@ use bevy::prelude::*;
 use godot::prelude::*;
 use godot::classes::{Node3D, Node, SphereMesh, StandardMaterial3D, Mesh, Material, CollisionShape3D, SphereShape3D};
 use voidrun_simulation::*;
-use voidrun_simulation::combat::{WeaponFired, WeaponFireIntent};
+use voidrun_simulation::combat::{WeaponFired, WeaponFireIntent, Suppressed, Blinded, AimSkill, ProjectileBallistics, RecoilState};
+use voidrun_simulation::shooting::AimMode;
+use crate::shared::lookup::{require_node, require_visual};
+use crate::shared::los_helpers::eye_position;
 use crate::shared::VisualRegistry;
 use voidrun_simulation::logger;
 // ============================================================================
@@ -17,6 +20,8 @@ use voidrun_simulation::logger;
 /// - ECS отправил WeaponFireIntent (strategic: "хочу стрелять")
 /// - Godot проверяет tactical constraints (distance, line of sight)
 /// - Если OK → генерирует WeaponFired для spawn projectile
+/// - Заклинившее оружие (`EquippedItem::jammed`) — intent отбрасывается
+/// - Перегретое энергооружие (`WeaponHeat::is_overheated`) — intent отбрасывается
 ///
 /// **Note:** Target switching обрабатывается отдельной системой `update_combat_targets_main_thread`
 ///
@@ -24,11 +29,26 @@ use voidrun_simulation::logger;
 pub fn process_ranged_attack_intents_main_thread(
     mut intent_events: EventReader<WeaponFireIntent>,
     actors: Query<&Actor>,
+    equipped: Query<&EquippedWeapons>,
+    weapon_stats: Query<&WeaponStats>,
     visuals: NonSend<VisualRegistry>,
     scene_root: NonSend<crate::shared::SceneRoot>,
     mut fire_events: EventWriter<WeaponFired>,
 ) {
     for intent in intent_events.read() {
+        // Заклинившее оружие не стреляет (ClearJamIntent → Channeling(ClearJam))
+        let jammed = equipped
+            .get(intent.shooter)
+            .is_ok_and(|weapons| weapons.get_active_weapon().is_some_and(|weapon| weapon.jammed));
+        if jammed {
+            continue;
+        }
+
+        // Перегрето — ждём остывания (update_weapon_heat)
+        if weapon_stats.get(intent.shooter).is_ok_and(|stats| stats.heat.is_overheated()) {
+            continue;
+        }
+
         // Получаем shooter node
         let Some(shooter_node) = visuals.visuals.get(&intent.shooter).cloned() else {
             logger::log(&format!(
@@ -38,7 +58,7 @@ pub fn process_ranged_attack_intents_main_thread(
             continue;
         };
 
-        // Player FPS shooting (no target) → skip validation, emit WeaponFired immediately
+        // Player FPS shooting (no target): оружие уже проверено в ECS (fire_block) → emit WeaponFired immediately
         let Some(target_entity) = intent.target else {
             fire_events.write(WeaponFired {
                 shooter: intent.shooter,
@@ -50,6 +70,8 @@ pub fn process_ranged_attack_intents_main_thread(
                     Vec3::new(pos.x, pos.y, pos.z)
                 },
                 hearing_range: intent.hearing_range,
+                ballistics: intent.ballistics,
+                fire_mode: intent.fire_mode,
             });
             continue;
         };
@@ -84,9 +106,9 @@ pub fn process_ranged_attack_intents_main_thread(
             continue;
         }
 
-        // ✅ Line-of-Sight Check: raycast от shooter к target (eye-level Y+0.8)
-        let shooter_eye = shooter_pos + Vector3::new(0.0, 0.8, 0.0);
-        let target_eye = target_pos + Vector3::new(0.0, 0.8, 0.0);
+        // ✅ Line-of-Sight Check: raycast от shooter к target (глаза, со сдвигом наклона)
+        let shooter_eye = eye_position(&shooter_node);
+        let target_eye = eye_position(&target_node);
 
         let world = scene_root.node.get_world_3d();
         let Some(mut world) = world else {
@@ -196,6 +218,8 @@ pub fn process_ranged_attack_intents_main_thread(
             speed: intent.speed,
             shooter_position: Vec3::new(shooter_pos.x, shooter_pos.y, shooter_pos.z),  // Godot Vector3 → Bevy Vec3
             hearing_range: intent.hearing_range,  // Радиус слышимости из оружия
+            ballistics: intent.ballistics,
+            fire_mode: intent.fire_mode,
         });
 
         logger::log(&format!(
@@ -206,25 +230,39 @@ pub fn process_ranged_attack_intents_main_thread(
 }
 
 /// System: Process WeaponFired events → spawn Godot projectile
-/// Создаёт GodotProjectile (полностью Godot-managed, НЕ в ECS)
+/// Создаёт GodotProjectile (полностью Godot-managed, НЕ в ECS), FireMode::Shotgun — `pellets` штук
+/// с детерминированным разлётом (`FireMode::pellet_offset`)
 /// Direction рассчитывается из weapon bone rotation (+Z forward axis)
 ///
 /// ВАЖНО: Fallback direction использует Godot Transform из VisualRegistry!
 pub fn weapon_fire_main_thread(
     mut fire_events: EventReader<WeaponFired>,
+    aim_skills: Query<&AimSkill>,
+    aim_modes: Query<&AimMode>,
+    recoil_states: Query<&RecoilState>,
+    suppressed_query: Query<&Suppressed>,
+    blinded_query: Query<&Blinded>,
     visuals: NonSend<VisualRegistry>,
     scene_root: NonSend<crate::shared::SceneRoot>,
     mut registry: NonSendMut<crate::projectiles::GodotProjectileRegistry>,
+    mut failures: EventWriter<ExpectationFailed>,
+    tick: Res<SimulationTick>,
 ) {
     for event in fire_events.read() {
         // Находим actor node
-        let Some(actor_node) = visuals.visuals.get(&event.shooter) else {
-            logger::log(&format!("Actor {:?} visual not found", event.shooter));
-            continue;
+        let actor_node = match require_visual(&visuals, event.shooter) {
+            Ok(actor_node) => actor_node,
+            Err(error) => {
+                failures.write(error.expectation(Some(event.shooter), "weapon_fire_main_thread"));
+                continue;
+            }
         };
 
         // 1. Находим BulletSpawn node для spawn_position (Golden Path helper)
-        let (spawn_position, weapon_node) = find_bullet_spawn_position(actor_node);
+        let (spawn_position, weapon_node, degraded) = find_bullet_spawn_position(&actor_node);
+        if let Some(error) = degraded {
+            failures.write(error.expectation(Some(event.shooter), "weapon_fire_main_thread"));
+        }
 
         // 2. Рассчитываем direction из weapon bone rotation
         let direction = if let Some(weapon) = weapon_node {
@@ -250,20 +288,69 @@ pub fn weapon_fire_main_thread(
             }
         };
 
-        // 3. Создаём GodotProjectile (полностью Godot-managed)
-        spawn_godot_projectile(
-            event.shooter,
-            spawn_position,
-            direction,
-            event.speed,
-            event.damage,
-            &scene_root.node,
-            &mut registry,
-        );
+        // 2.5. AimSkill: AI целится в цель с упреждением (tracking), а не по weapon bone
+        let aim_skill = aim_skills.get(event.shooter).ok();
+        let direction = match (aim_skill, event.target) {
+            (Some(skill), Some(target_entity)) => visuals
+                .visuals
+                .get(&target_entity)
+                .map(|target_node| {
+                    calculate_lead_direction(spawn_position, target_node, event.speed, skill.tracking)
+                })
+                .unwrap_or(direction),
+            _ => direction,
+        };
+
+        // 2.6. Spread: AimSkill (AI) / AimMode (player: hip fire ↔ ADS) + bloom отдачи + Suppression
+        //      + ослепление (стрельба вслепую) — случайный разброс в конусе
+        let spread_degrees = aim_skill.map(|skill| skill.spread_degrees).unwrap_or(0.0)
+            + aim_modes
+                .get(event.shooter)
+                .map(|aim_mode| aim_mode.spread_degrees())
+                .unwrap_or(0.0)
+            + recoil_states
+                .get(event.shooter)
+                .map(|recoil| recoil.bloom)
+                .unwrap_or(0.0)
+            + suppressed_query
+                .get(event.shooter)
+                .map(|suppressed| suppressed.extra_spread_degrees())
+                .unwrap_or(0.0)
+            + blinded_query
+                .get(event.shooter)
+                .map(|blinded| blinded.extra_spread_degrees())
+                .unwrap_or(0.0);
+        let direction = apply_aim_spread(direction, spread_degrees);
+
+        // 3. Создаём GodotProjectile (полностью Godot-managed), Shotgun — по одной на дробину
+        // Seed выстрела: тик + стрелок (тот же выстрел в реплее разлетается и рикошетит так же)
+        let shot_seed = (tick.get() << 32) ^ event.shooter.to_bits();
+        let pellets = event.fire_mode.pellets();
+
+        for pellet in 0..pellets {
+            let (deviation, azimuth) = event.fire_mode.pellet_offset(shot_seed, pellet);
+            let pellet_direction = if deviation > 0.0 {
+                deflect_direction(direction, deviation, azimuth)
+            } else {
+                direction
+            };
+
+            spawn_godot_projectile(
+                event.shooter,
+                spawn_position,
+                pellet_direction,
+                event.speed,
+                event.damage,
+                event.ballistics,
+                shot_seed ^ pellet as u64,
+                &scene_root.node,
+                &mut registry,
+            );
+        }
 
         logger::log(&format!(
-            "Spawned projectile: shooter={:?} → target={:?} at {:?} dir={:?} dmg={}",
-            event.shooter, event.target, spawn_position, direction, event.damage
+            "Spawned projectile: shooter={:?} → target={:?} at {:?} dir={:?} dmg={} pellets={}",
+            event.shooter, event.target, spawn_position, direction, event.damage, pellets
         ));
     }
 }
@@ -272,21 +359,94 @@ pub fn weapon_fire_main_thread(
 // Helpers: Bullet Spawn Position + Projectile Creation
 // ============================================================================
 
+/// Helper: направление выстрела с упреждением движущейся цели
+///
+/// `tracking` (0.0-1.0) масштабирует упреждение: 0.0 = в текущую позицию цели,
+/// 1.0 = в точку, где цель будет через время полёта пули (линейная экстраполяция).
+fn calculate_lead_direction(
+    spawn_position: Vector3,
+    target_node: &Gd<Node3D>,
+    projectile_speed: f32,
+    tracking: f32,
+) -> Vector3 {
+    // Прицел на уровень глаз (как в LOS check: выглянувшая из-за укрытия цель — в голову)
+    let target_center = eye_position(target_node);
+
+    let target_velocity = target_node
+        .clone()
+        .try_cast::<godot::classes::CharacterBody3D>()
+        .map(|body| body.get_velocity())
+        .unwrap_or(Vector3::ZERO);
+
+    lead_direction(spawn_position, target_center, target_velocity, projectile_speed, tracking)
+}
+
+/// Упреждение без Godot nodes: позиция и скорость цели заданы явно
+pub(crate) fn lead_direction(
+    spawn_position: Vector3,
+    target_center: Vector3,
+    target_velocity: Vector3,
+    projectile_speed: f32,
+    tracking: f32,
+) -> Vector3 {
+    let time_to_hit = if projectile_speed > 0.0 {
+        (target_center - spawn_position).length() / projectile_speed
+    } else {
+        0.0
+    };
+
+    let aim_point = target_center + target_velocity * time_to_hit * tracking.clamp(0.0, 1.0);
+    (aim_point - spawn_position).normalized()
+}
+
+/// Helper: случайно отклонить direction в пределах конуса `spread_degrees`
+///
+/// Угол от оси — равномерно в [0, spread], азимут — равномерно в [0, TAU].
+fn apply_aim_spread(direction: Vector3, spread_degrees: f32) -> Vector3 {
+    use rand::Rng;
+
+    if spread_degrees <= 0.0 {
+        return direction;
+    }
+
+    let mut rng = rand::thread_rng();
+    let deviation = rng.gen_range(0.0..spread_degrees).to_radians();
+    let azimuth = rng.gen_range(0.0..std::f32::consts::TAU);
+
+    deflect_direction(direction, deviation, azimuth)
+}
+
+/// Отклонить direction на угол `deviation` (радианы) в сторону `azimuth` (детерминированно)
+pub(crate) fn deflect_direction(direction: Vector3, deviation: f32, azimuth: f32) -> Vector3 {
+    // Ортонормированный базис вокруг direction (UP вырожден для вертикального выстрела)
+    let reference = if direction.cross(Vector3::UP).length_squared() > 1e-4 {
+        Vector3::UP
+    } else {
+        Vector3::RIGHT
+    };
+    let side = direction.cross(reference).normalized();
+    let up = side.cross(direction).normalized();
+
+    let offset = side * azimuth.cos() + up * azimuth.sin();
+    (direction * deviation.cos() + offset * deviation.sin()).normalized()
+}
+
 /// Helper: Find bullet spawn position (BulletSpawn → weapon root → RightHand → actor)
 ///
-/// Returns: (spawn_position, weapon_node_for_direction)
-fn find_bullet_spawn_position(actor_node: &Gd<Node3D>) -> (Vector3, Option<Gd<Node3D>>) {
+/// Returns: (spawn_position, weapon_node_for_direction, сломанный сетап — если пришлось откатиться)
+fn find_bullet_spawn_position(actor_node: &Gd<Node3D>) -> (Vector3, Option<Gd<Node3D>>, Option<BridgeError>) {
     // Try 1: RightHandAttachment (attachment point)
-    let Some(weapon_attachment) = actor_node.try_get_node_as::<Node3D>("%RightHandAttachment") else {
-        // Fallback 1: RightHand
-        if let Some(right_hand) = actor_node.try_get_node_as::<Node3D>("RightHand") {
-            logger::log("⚠️ WeaponAttachment not found, using RightHand");
-            return (right_hand.get_global_position(), Some(right_hand));
-        }
+    let weapon_attachment = match require_node::<Node3D>(actor_node, "%RightHandAttachment") {
+        Ok(weapon_attachment) => weapon_attachment,
+        Err(error) => {
+            // Fallback 1: RightHand
+            if let Some(right_hand) = actor_node.try_get_node_as::<Node3D>("RightHand") {
+                return (right_hand.get_global_position(), Some(right_hand), Some(error));
+            }
 
-        // Fallback 2: Actor position
-        logger::log("⚠️ RightHand not found, using actor position");
-        return (actor_node.get_global_position(), None);
+            // Fallback 2: Actor position
+            return (actor_node.get_global_position(), None, Some(error));
+        }
     };
 
     // Try 2: Get weapon prefab (first child of attachment)
@@ -298,24 +458,22 @@ fn find_bullet_spawn_position(actor_node: &Gd<Node3D>) -> (Vector3, Option<Gd<No
 
     let Some(weapon_prefab) = weapon_prefab else {
         logger::log("⚠️ No weapon attached to RightHandAttachment");
-        return (weapon_attachment.get_global_position(), Some(weapon_attachment));
+        return (weapon_attachment.get_global_position(), Some(weapon_attachment), None);
     };
 
     // Try 3: Find BulletSpawn via unique name
-    if let Some(bullet_spawn_node) = weapon_prefab.get_node_or_null("%BulletSpawn") {
-        if let Ok(bullet_spawn) = bullet_spawn_node.try_cast::<Node3D>() {
-            return (bullet_spawn.get_global_position(), Some(bullet_spawn));
-        }
-    }
+    let missing_spawn = match require_node::<Node3D>(&weapon_prefab, "%BulletSpawn") {
+        Ok(bullet_spawn) => return (bullet_spawn.get_global_position(), Some(bullet_spawn), None),
+        Err(error) => error,
+    };
 
     // Try 4: Legacy fallback - recursive search
     if let Some(bullet_spawn) = find_node_recursive(&weapon_attachment, "BulletSpawn") {
-        return (bullet_spawn.get_global_position(), Some(bullet_spawn));
+        return (bullet_spawn.get_global_position(), Some(bullet_spawn), None);
     }
 
-    // Fallback 5: Weapon root position
-    logger::log("⚠️ BulletSpawn not found (add unique_name_in_owner to weapon prefab)");
-    (weapon_prefab.get_global_position(), Some(weapon_prefab))
+    // Fallback 5: Weapon root position (add unique_name_in_owner to weapon prefab)
+    (weapon_prefab.get_global_position(), Some(weapon_prefab), Some(missing_spawn))
 }
 
 /// Helper: рекурсивный поиск node по имени
@@ -343,6 +501,8 @@ fn spawn_godot_projectile(
     direction: Vector3,
     speed: f32,
     damage: u32,
+    ballistics: ProjectileBallistics,
+    ricochet_seed: u64,
     scene_root: &Gd<Node3D>,
     registry: &mut crate::projectiles::GodotProjectileRegistry,
 ) {
@@ -375,6 +535,8 @@ fn spawn_godot_projectile(
         speed,
         damage as i64,
     );
+    projectile.bind_mut().previous_position = position; // Near-miss: без ложного пролёта от (0,0,0)
+    projectile.bind_mut().set_ballistics(ballistics, ricochet_seed);
 
     // 3. SphereMesh визуал (красная пуля)
     let mut mesh_instance = godot::classes::MeshInstance3D::new_alloc();
@@ -404,3 +566,50 @@ fn spawn_godot_projectile(
     // 6. Добавляем в сцену (Godot автоматически вызовет _physics_process)
     scene_root.clone().upcast::<Node>().add_child(&projectile.upcast::<Node>());
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lead_direction_without_tracking_aims_at_target() {
+        let direction = lead_direction(Vector3::ZERO, Vector3::new(0.0, 0.0, -10.0), Vector3::new(5.0, 0.0, 0.0), 20.0, 0.0);
+
+        assert!((direction - Vector3::new(0.0, 0.0, -1.0)).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_lead_direction_full_tracking_leads_moving_target() {
+        // 10m при 20 м/с = 0.5с → цель сместится на 2.5m по X
+        let direction = lead_direction(Vector3::ZERO, Vector3::new(0.0, 0.0, -10.0), Vector3::new(5.0, 0.0, 0.0), 20.0, 1.0);
+        let expected = Vector3::new(2.5, 0.0, -10.0).normalized();
+
+        assert!((direction - expected).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_deflect_direction_keeps_requested_angle() {
+        let forward = Vector3::new(0.0, 0.0, -1.0);
+        let deviation = 5.0_f32.to_radians();
+
+        for azimuth in [0.0, 1.0, 2.5, 4.0] {
+            let deflected = deflect_direction(forward, deviation, azimuth);
+            assert!((deflected.length() - 1.0).abs() < 1e-5);
+            assert!((deflected.dot(forward).acos() - deviation).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_deflect_vertical_direction_is_stable() {
+        let deflected = deflect_direction(Vector3::UP, 0.1, 0.0);
+
+        assert!(deflected.is_finite());
+        assert!((deflected.dot(Vector3::UP).acos() - 0.1).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_zero_spread_returns_direction() {
+        let direction = Vector3::new(0.0, 0.0, -1.0);
+        assert_eq!(apply_aim_spread(direction, 0.0), direction);
+    }
+}
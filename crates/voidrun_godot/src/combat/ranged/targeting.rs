@@ -4,6 +4,7 @@ use bevy::prelude::*;
 use godot::prelude::*;
 use godot::classes::Node3D;
 use voidrun_simulation::*;
+use crate::shared::los_helpers::eye_position;
 use crate::shared::VisualRegistry;
 use voidrun_simulation::logger;
 // ============================================================================
@@ -13,10 +14,12 @@ use voidrun_simulation::logger;
 /// System: Dynamic target switching (SlowUpdate schedule, 0.3 Hz)
 ///
 /// Для ВСЕХ акторов в AIState::Combat:
-/// - Проверяет ближайшего ВИДИМОГО врага из SpottedEnemies (VisionCone + LOS raycast)
-/// - Если ближайший враг ≠ текущий target → переключает target
+/// - Собирает ВИДИМЫХ врагов из SpottedEnemies (VisionCone + LOS raycast)
+/// - С ThreatTable: цель с максимальной угрозой (hysteresis — без дёрганья между равными)
+/// - Без ThreatTable: ближайший видимый враг
+/// - Если выбранный ≠ текущий target → переключает target
 ///
-/// **Результат:** AI всегда атакует ближайшего видимого врага (dynamic target prioritization)
+/// **Результат:** AI атакует самого опасного видимого врага (dynamic target prioritization)
 ///
 /// **Schedule:** SlowUpdate (0.3 Hz = ~3 раза в секунду)
 /// - Экономия CPU (не нужно каждый frame)
@@ -25,7 +28,7 @@ use voidrun_simulation::logger;
 ///
 /// ВАЖНО: НЕ зависит от WeaponFireIntent events (отдельная система)
 pub fn update_combat_targets_main_thread(
-    mut actors: Query<(Entity, &Actor, &mut ai::AIState, &ai::SpottedEnemies), With<Actor>>,
+    mut actors: Query<(Entity, &Actor, &mut ai::AIState, &ai::SpottedEnemies, Option<&ai::ThreatTable>), With<Actor>>,
     all_actors: Query<&Actor>,
     visuals: NonSend<VisualRegistry>,
     scene_root: NonSend<crate::shared::SceneRoot>,
@@ -42,7 +45,7 @@ pub fn update_combat_targets_main_thread(
         return;
     };
 
-    for (entity, actor, mut ai_state, spotted_enemies) in actors.iter_mut() {
+    for (entity, actor, mut ai_state, spotted_enemies, threat_table) in actors.iter_mut() {
         // Обрабатываем только Combat state
         let ai::AIState::Combat { target: current_target } = ai_state.as_ref() else {
             continue;
@@ -54,10 +57,10 @@ pub fn update_combat_targets_main_thread(
         };
 
         let shooter_pos = shooter_node.get_global_position();
-        let shooter_eye = shooter_pos + Vector3::new(0.0, 0.8, 0.0); // Eye level
+        let shooter_eye = eye_position(shooter_node); // Eye level (+ наклон)
 
-        // Ищем БЛИЖАЙШЕГО ВИДИМОГО врага из SpottedEnemies
-        let mut closest_visible_enemy: Option<(Entity, f32)> = None;
+        // Собираем ВИДИМЫХ врагов из SpottedEnemies (порядок обнаружения + дистанция)
+        let mut visible_enemies: Vec<(Entity, f32)> = Vec::new();
 
         for &enemy_entity in &spotted_enemies.enemies {
             // Проверяем что враг жив (есть в actors)
@@ -78,8 +81,8 @@ pub fn update_combat_targets_main_thread(
             let enemy_pos = enemy_node.get_global_position();
             let distance_to_enemy = (enemy_pos - shooter_pos).length();
 
-            // ✅ LOS CHECK: raycast от shooter к enemy (eye-level)
-            let enemy_eye = enemy_pos + Vector3::new(0.0, 0.8, 0.0);
+            // ✅ LOS CHECK: raycast от shooter к enemy (eye-level, выглянувший из-за угла виден)
+            let enemy_eye = eye_position(enemy_node);
 
             let query_params = godot::classes::PhysicsRayQueryParameters3D::create(shooter_eye, enemy_eye);
             let Some(mut query) = query_params else {
@@ -114,18 +117,15 @@ pub fn update_combat_targets_main_thread(
                 continue;
             }
 
-            // ✅ ВРАГ ВИДИМ! Обновляем ближайшего
-            if let Some((_, current_min_dist)) = closest_visible_enemy {
-                if distance_to_enemy < current_min_dist {
-                    closest_visible_enemy = Some((enemy_entity, distance_to_enemy));
-                }
-            } else {
-                closest_visible_enemy = Some((enemy_entity, distance_to_enemy));
-            }
+            // ✅ ВРАГ ВИДИМ!
+            visible_enemies.push((enemy_entity, distance_to_enemy));
         }
 
-        // Если нашли ближайшего видимого и он НЕ равен текущему target → переключаем
-        if let Some((closest_entity, closest_distance)) = closest_visible_enemy {
+        // Выбор: по угрозе (ThreatTable) или ближайший
+        let chosen = choose_visible_target(&visible_enemies, threat_table, *current_target);
+
+        // Если выбранный видимый враг НЕ равен текущему target → переключаем
+        if let Some((closest_entity, closest_distance)) = chosen {
             if closest_entity != *current_target {
                 // ✅ ЗАМЕНЯЕМ TARGET в AIState::Combat
                 if let ai::AIState::Combat { ref mut target } = ai_state.as_mut() {
@@ -133,7 +133,8 @@ pub fn update_combat_targets_main_thread(
                     *target = closest_entity;
 
                     logger::log(&format!(
-                        "🎯 TARGET SWITCH (closest visible): {:?} switches from {:?} to {:?} at {:.1}m",
+                        "🎯 TARGET SWITCH ({}): {:?} switches from {:?} to {:?} at {:.1}m",
+                        if threat_table.is_some() { "threat" } else { "closest visible" },
                         entity, old_target, closest_entity, closest_distance
                     ));
                 }
@@ -143,15 +144,36 @@ pub fn update_combat_targets_main_thread(
 
 }
 
+/// Выбор цели среди видимых врагов `(entity, distance)` (без Godot — чистая логика)
+///
+/// - С ThreatTable: максимальная угроза (hysteresis удерживает `current`)
+/// - Без ThreatTable: ближайший
+pub fn choose_visible_target(
+    visible_enemies: &[(Entity, f32)],
+    threat_table: Option<&ai::ThreatTable>,
+    current: Entity,
+) -> Option<(Entity, f32)> {
+    match threat_table {
+        Some(table) => table
+            .select_target(visible_enemies.iter().map(|&(e, _)| e), Some(current))
+            .and_then(|chosen| visible_enemies.iter().copied().find(|&(e, _)| e == chosen)),
+        None => visible_enemies
+            .iter()
+            .copied()
+            .min_by(|a, b| a.1.total_cmp(&b.1)),
+    }
+}
+
 /// System: Aim weapon at target (RightHand rotation)
 /// Если актёр в Combat state → поворачиваем руку к target
+/// Melee оружие → к точке перехвата из Blackboard (где цель будет в момент удара)
 ///
 /// ВАЖНО: Использует Godot Transform из VisualRegistry (не ECS Transform!)
 pub fn weapon_aim_main_thread(
-    actors: Query<(Entity, &ai::AIState), With<Actor>>,
+    actors: Query<(Entity, &ai::AIState, Option<&WeaponStats>, Option<&ai::Blackboard>), With<Actor>>,
     visuals: NonSend<VisualRegistry>,
 ) {
-    for (entity, state) in actors.iter() {
+    for (entity, state, weapon, blackboard) in actors.iter() {
         // Целимся только в Combat state
         if let ai::AIState::Combat { target } = state {
             // Получаем actor node (shooter)
@@ -165,7 +187,14 @@ pub fn weapon_aim_main_thread(
             };
 
             // Godot positions (tactical layer — authoritative для aim)
-            let target_pos = target_node.get_global_position();
+            let intercept = weapon
+                .filter(|weapon| weapon.attack_radius > 0.0)
+                .zip(blackboard.filter(|board| board.target == Some(*target)))
+                .and_then(|(weapon, board)| board.target_intercept_point(weapon));
+            let target_pos = intercept.map_or_else(
+                || target_node.get_global_position(),
+                |point| Vector3::new(point.x, point.y, point.z),
+            );
             let actor_pos = actor_node.get_global_position();
             let to_target = target_pos - actor_pos;
 
@@ -181,3 +210,35 @@ pub fn weapon_aim_main_thread(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_closest_visible_without_threat_table() {
+        let (current, near, far) = (Entity::from_raw(1), Entity::from_raw(2), Entity::from_raw(3));
+        let visible = [(far, 12.0), (near, 4.0), (current, 8.0)];
+
+        assert_eq!(choose_visible_target(&visible, None, current), Some((near, 4.0)));
+    }
+
+    #[test]
+    fn test_threat_table_keeps_current_within_margin() {
+        let (current, rival) = (Entity::from_raw(1), Entity::from_raw(2));
+        let mut table = ai::ThreatTable::default();
+        table.add(current, 10.0);
+        table.add(rival, 11.0);
+        let visible = [(rival, 3.0), (current, 9.0)];
+
+        assert_eq!(choose_visible_target(&visible, Some(&table), current), Some((current, 9.0)));
+
+        table.add(rival, 10.0);
+        assert_eq!(choose_visible_target(&visible, Some(&table), current), Some((rival, 3.0)));
+    }
+
+    #[test]
+    fn test_no_visible_enemies() {
+        assert_eq!(choose_visible_target(&[], None, Entity::from_raw(1)), None);
+    }
+}
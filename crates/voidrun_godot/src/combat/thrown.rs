@@ -0,0 +1,140 @@
+//! Thrown weapons — ThrowableLaunched → GodotThrownItem по дуге → MeleeHit + ThrowableLanded.
+//!
+//! Architecture: ADR-005 (Godot владеет полётом), ADR-004 (NonSend, _main_thread naming)
+//! - Попадание в актора = MeleeHit (урон melee-класса: броня, knockdown, flinch — как у удара)
+//! - Любой удар (актор / стена / пол) или истёкший lifetime → ThrowableLanded → ECS WorldItem
+
+use bevy::prelude::*;
+use godot::classes::{BoxMesh, CollisionShape3D, IArea3D, Material, Mesh, MeshInstance3D, Shape3D, SphereShape3D, StandardMaterial3D};
+use godot::prelude::*;
+use voidrun_simulation::combat::MeleeHit;
+use voidrun_simulation::logger;
+use voidrun_simulation::{ThrowableLanded, ThrowableLaunched};
+
+use crate::projectiles::{GodotThrownItem, ThrownItemRegistry};
+use crate::shared::collision::{COLLISION_LAYER_ACTORS, COLLISION_LAYER_ENVIRONMENT, COLLISION_LAYER_PROJECTILES};
+use crate::shared::{SceneRoot, VisualRegistry};
+
+/// Высота вылета над origin актора (метры, уровень плеча)
+const THROW_HEIGHT: f32 = 1.5;
+
+/// Насколько впереди актора появляется предмет (метры)
+const THROW_FORWARD_OFFSET: f32 = 0.5;
+
+/// Подброс вверх относительно горизонтали (доля forward → дуга)
+const THROW_LIFT: f32 = 0.2;
+
+/// System: ThrowableLaunched → GodotThrownItem из руки по направлению взгляда
+pub fn spawn_thrown_items_main_thread(
+    mut launched_events: EventReader<ThrowableLaunched>,
+    visuals: NonSend<VisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut registry: NonSendMut<ThrownItemRegistry>,
+) {
+    for launched in launched_events.read() {
+        let Some(actor_node) = visuals.visuals.get(&launched.thrower) else {
+            continue;
+        };
+
+        // Godot actors face -Z
+        let forward = -actor_node.get_global_transform().basis.col_c();
+        let forward = Vector3::new(forward.x, 0.0, forward.z).normalized();
+        let direction = (forward + Vector3::UP * THROW_LIFT).normalized();
+        let position = actor_node.get_global_position() + Vector3::UP * THROW_HEIGHT + forward * THROW_FORWARD_OFFSET;
+
+        let mut thrown = Gd::<GodotThrownItem>::from_init_fn(|base| <GodotThrownItem as IArea3D>::init(base));
+        thrown.set_collision_layer(COLLISION_LAYER_PROJECTILES);
+        // Щиты не останавливают (melee-класс), только тела и окружение
+        thrown.set_collision_mask(COLLISION_LAYER_ACTORS | COLLISION_LAYER_ENVIRONMENT);
+        {
+            let mut bound = thrown.bind_mut();
+            bound.thrower = launched.thrower;
+            bound.item = Some(launched.item.clone());
+            bound.damage = launched.damage;
+            bound.velocity = direction * launched.speed;
+        }
+
+        // Визуал: вытянутый серый брусок (клинок / древко)
+        let mut mesh_instance = MeshInstance3D::new_alloc();
+        let mut blade = BoxMesh::new_gd();
+        blade.set_size(Vector3::new(0.04, 0.02, 0.3));
+        mesh_instance.set_mesh(&blade.upcast::<Mesh>());
+        let mut material = StandardMaterial3D::new_gd();
+        material.set_albedo(Color::from_rgb(0.75, 0.75, 0.8));
+        mesh_instance.set_surface_override_material(0, &material.upcast::<Material>());
+        thrown.add_child(&mesh_instance.upcast::<Node>());
+
+        let mut collision = CollisionShape3D::new_alloc();
+        let mut shape = SphereShape3D::new_gd();
+        shape.set_radius(0.12);
+        collision.set_shape(&shape.upcast::<Shape3D>());
+        thrown.add_child(&collision.upcast::<Node>());
+
+        registry.register(thrown.clone());
+        scene_root.node.clone().upcast::<Node>().add_child(&thrown.clone().upcast::<Node>());
+        thrown.set_global_position(position);
+
+        logger::log(&format!(
+            "🗡️ Thrown {:?} by {:?} (speed {:.1} m/s)",
+            launched.item.definition_id, launched.thrower, launched.speed
+        ));
+    }
+}
+
+/// System: удар брошенного предмета → MeleeHit (актор) + ThrowableLanded, node удаляется
+pub fn process_thrown_item_impacts_main_thread(
+    mut registry: NonSendMut<ThrownItemRegistry>,
+    visuals: NonSend<VisualRegistry>,
+    mut melee_hit_events: EventWriter<MeleeHit>,
+    mut landed_events: EventWriter<ThrowableLanded>,
+) {
+    registry.cleanup_destroyed();
+
+    let mut finished = Vec::new();
+    for (&instance_id, thrown) in registry.items.iter_mut() {
+        let Some(impact) = thrown.bind().impact.clone() else {
+            continue;
+        };
+        let (thrower, damage, item) = {
+            let mut bound = thrown.bind_mut();
+            (bound.thrower, bound.damage, bound.item.take())
+        };
+
+        let target = impact
+            .target_instance_id
+            .and_then(|id| visuals.node_to_entity.get(&id).copied())
+            .filter(|&target| target != thrower);
+
+        // Попал в актора → предмет падает к его ногам
+        let mut landing = impact.position;
+        if let Some(target) = target {
+            melee_hit_events.write(MeleeHit {
+                attacker: thrower,
+                target,
+                damage,
+                was_blocked: false,
+                was_parried: false,
+                impact_point: Vec3::new(impact.position.x, impact.position.y, impact.position.z),
+                impact_normal: Vec3::new(impact.direction.x, impact.direction.y, impact.direction.z),
+            });
+            if let Some(target_node) = visuals.visuals.get(&target) {
+                landing = target_node.get_global_position();
+            }
+            logger::log(&format!("🗡️ Thrown hit: {:?} → {:?} ({} dmg)", thrower, target, damage));
+        }
+
+        if let Some(item) = item {
+            landed_events.write(ThrowableLanded {
+                item,
+                position: Vec3::new(landing.x, landing.y, landing.z),
+            });
+        }
+
+        thrown.queue_free();
+        finished.push(instance_id);
+    }
+
+    for instance_id in finished {
+        registry.items.remove(&instance_id);
+    }
+}
@@ -0,0 +1,57 @@
+//! Companion orders input — [F5-F8] → ECS CompanionCommandIntent.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - [F5] за мной, [F6] стоять здесь, [F8] ко мне (выйти из боя)
+//! - [F7] атаковать: raycast камеры (actors + environment) → актор под прицелом
+//!
+//! Приказы, привязка к лидеру, движение — в ECS (`voidrun_simulation::companions`).
+
+use bevy::prelude::*;
+use voidrun_simulation::companions::{CompanionCommand, CompanionCommandIntent};
+use voidrun_simulation::player::Player;
+
+use crate::input::PlayerInputEvent;
+use crate::scan::raycast_actor;
+use crate::shared::{SceneRoot, VisualRegistry};
+
+/// Дальность выбора цели для приказа атаки (метры)
+const ATTACK_ORDER_RANGE: f32 = 60.0;
+
+/// System: [F5-F8] → CompanionCommandIntent (нет спутников / цели — ECS игнорирует)
+pub fn player_companion_command_input_main_thread(
+    mut input_events: EventReader<PlayerInputEvent>,
+    player: Query<Entity, With<Player>>,
+    visuals: NonSend<VisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut command_intents: EventWriter<CompanionCommandIntent>,
+) {
+    let Ok(player_entity) = player.single() else {
+        input_events.clear();
+        return;
+    };
+
+    for input in input_events.read() {
+        let command = if input.companion_follow {
+            CompanionCommand::Follow
+        } else if input.companion_hold {
+            CompanionCommand::HoldPosition
+        } else if input.companion_regroup {
+            CompanionCommand::Regroup
+        } else if input.companion_attack {
+            let Some(target) = raycast_actor(&visuals, &scene_root, ATTACK_ORDER_RANGE) else {
+                continue;
+            };
+            if target == player_entity {
+                continue;
+            }
+            CompanionCommand::AttackTarget { target }
+        } else {
+            continue;
+        };
+
+        command_intents.write(CompanionCommandIntent {
+            leader: player_entity,
+            command,
+        });
+    }
+}
@@ -0,0 +1,107 @@
+//! Impact decals — ECS ImpactDecal → Godot Decal на полу.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - Added<ImpactDecal> → Decal (процедурная текстура, кэш на вид)
+//! - RemovedComponents<ImpactDecal> → queue_free
+//!
+//! Сколько декалей живёт — решает ECS (`battlefield::ArtifactBudget`), здесь только визуал.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{decal::DecalTexture, image::Format as ImageFormat, Decal, Image, ImageTexture, Texture2D};
+use voidrun_simulation::battlefield::{DecalKind, ImpactDecal};
+use std::collections::HashMap;
+
+use crate::shared::SceneRoot;
+
+/// Размер текстуры декали (px)
+const DECAL_TEXTURE_SIZE: i32 = 64;
+/// Глубина проекции (Y декали)
+const DECAL_DEPTH: f32 = 0.5;
+
+/// Registry: ImpactDecal entity → Godot Decal (+ кэш текстур по виду)
+///
+/// NonSend resource — main thread only (Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct ImpactDecalRegistry {
+    pub decals: HashMap<Entity, Gd<Decal>>,
+    textures: HashMap<DecalKind, Gd<Texture2D>>,
+}
+
+/// System: Added<ImpactDecal> → Decal node
+pub fn spawn_impact_decals_main_thread(
+    decals: Query<(Entity, &ImpactDecal), Added<ImpactDecal>>,
+    mut registry: NonSendMut<ImpactDecalRegistry>,
+    scene_root: NonSend<SceneRoot>,
+) {
+    for (entity, impact) in decals.iter() {
+        let texture = match registry.textures.get(&impact.kind) {
+            Some(texture) => texture.clone(),
+            None => {
+                let Some(texture) = create_decal_texture(impact.kind) else {
+                    continue;
+                };
+                registry.textures.insert(impact.kind, texture.clone());
+                texture
+            }
+        };
+
+        let mut decal = Decal::new_alloc();
+        decal.set_texture(DecalTexture::ALBEDO, &texture);
+        let size = match impact.kind {
+            DecalKind::Blood => 0.8,
+            DecalKind::Scorch => 2.5,
+        };
+        decal.set_size(Vector3::new(size, DECAL_DEPTH, size));
+
+        scene_root.node.clone().upcast::<Node>().add_child(&decal.clone().upcast::<Node>());
+        decal.set_global_position(Vector3::new(impact.position.x, impact.position.y, impact.position.z));
+
+        registry.decals.insert(entity, decal);
+    }
+}
+
+/// System: ImpactDecal despawned (бюджет) → удалить Decal
+pub fn despawn_impact_decals_main_thread(
+    mut removed: RemovedComponents<ImpactDecal>,
+    mut registry: NonSendMut<ImpactDecalRegistry>,
+) {
+    for entity in removed.read() {
+        if let Some(mut decal) = registry.decals.remove(&entity) {
+            decal.queue_free();
+        }
+    }
+}
+
+/// Процедурная текстура: кровь — неровная клякса, подпалина — тёмное пятно с мягким краем
+fn create_decal_texture(kind: DecalKind) -> Option<Gd<Texture2D>> {
+    let mut image = Image::create_empty(DECAL_TEXTURE_SIZE, DECAL_TEXTURE_SIZE, false, ImageFormat::RGBA8)?;
+    image.fill(Color::from_rgba(0.0, 0.0, 0.0, 0.0));
+
+    let center = DECAL_TEXTURE_SIZE as f32 * 0.5;
+    for y in 0..DECAL_TEXTURE_SIZE {
+        for x in 0..DECAL_TEXTURE_SIZE {
+            let dx = x as f32 + 0.5 - center;
+            let dy = y as f32 + 0.5 - center;
+            let distance = (dx * dx + dy * dy).sqrt() / center;
+            let angle = dy.atan2(dx);
+
+            let color = match kind {
+                DecalKind::Blood => {
+                    // Неровный край: радиус гуляет по углу
+                    let edge = 0.6 + 0.15 * (angle * 5.0).sin() + 0.1 * (angle * 11.0).cos();
+                    (distance <= edge).then(|| Color::from_rgba(0.35, 0.02, 0.02, 0.85))
+                }
+                DecalKind::Scorch => {
+                    let alpha = (1.0 - distance).clamp(0.0, 1.0) * 0.8;
+                    (alpha > 0.0).then(|| Color::from_rgba(0.05, 0.04, 0.03, alpha))
+                }
+            };
+            if let Some(color) = color {
+                image.set_pixel(x, y, color);
+            }
+        }
+    }
+
+    Some(ImageTexture::create_from_image(&image)?.upcast::<Texture2D>())
+}
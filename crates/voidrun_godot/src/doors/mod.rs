@@ -0,0 +1,202 @@
+//! Breachable doors — Godot ноды группы `breachable_doors` ↔ ECS Door.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - Новая нода в группе → Door + Interactable entity (позиция, нормаль -Z, meta `locked` / `integrity` / `key`)
+//! - DoorToggled ([F]) → полотно скрыто, коллизия выключена (и обратно)
+//! - DoorBreached → queue_free полотна (проход + обзор свободны)
+//! - NavigationLink3D через проём (navmesh запекается с закрытым полотном):
+//!   заперта → выключен, закрыта → дороже (AI откроет по пути), открыта / выбита → обычная цена
+//!
+//! Геометрия двери — в уровне (Godot authoritative), ECS хранит только правила breach.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{CollisionShape3D, NavigationLink3D};
+use voidrun_simulation::doors::{Door, DoorBreached, DoorKicked, DoorState};
+use voidrun_simulation::interaction::{DoorToggled, Interactable, InteractionKind};
+use voidrun_simulation::logger;
+use std::collections::{HashMap, HashSet};
+
+use crate::interaction::InteractableNodeRegistry;
+use crate::shared::SceneRoot;
+
+/// Группа Godot для выбиваемых дверей
+pub const BREACHABLE_DOOR_GROUP: &str = "breachable_doors";
+
+/// Прочность двери без meta `integrity`
+const DEFAULT_DOOR_INTEGRITY: u32 = 100;
+
+/// Полудлина NavigationLink3D по нормали двери (метры, концы — по обе стороны полотна)
+const NAV_LINK_HALF_LENGTH: f32 = 0.9;
+
+/// Цена прохода через закрытую дверь (AI предпочитает открытые маршруты)
+const CLOSED_DOOR_TRAVEL_COST: f32 = 4.0;
+
+/// Registry: Door entity ↔ Godot нода
+///
+/// NonSend resource — main thread only (Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct DoorNodeRegistry {
+    pub doors: HashMap<Entity, Gd<Node3D>>,
+    /// Навигационные связи через проём (живут дольше выбитого полотна)
+    pub nav_links: HashMap<Entity, Gd<NavigationLink3D>>,
+    /// Уже зарегистрированные ноды (не спавним Door повторно)
+    pub registered: HashSet<InstanceId>,
+}
+
+/// System: новые ноды группы `breachable_doors` → Door entities ([F] открывает незапертые)
+pub fn register_breachable_doors_main_thread(
+    mut registry: NonSendMut<DoorNodeRegistry>,
+    mut interactables: NonSendMut<InteractableNodeRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut commands: Commands,
+) {
+    let Some(mut tree) = scene_root.node.get_tree() else {
+        return;
+    };
+
+    for node in tree.get_nodes_in_group(BREACHABLE_DOOR_GROUP).iter_shared() {
+        let Ok(door_node) = node.try_cast::<Node3D>() else {
+            continue;
+        };
+        if !registry.registered.insert(door_node.instance_id()) {
+            continue;
+        }
+
+        let transform = door_node.get_global_transform();
+        let position = transform.origin;
+        let facing = -transform.basis.col_c();
+
+        let locked = door_node.has_meta("locked")
+            && door_node.get_meta("locked").try_to::<bool>().unwrap_or(false);
+        let integrity = if door_node.has_meta("integrity") {
+            door_node
+                .get_meta("integrity")
+                .try_to::<i64>()
+                .map(|value| value.max(1) as u32)
+                .unwrap_or(DEFAULT_DOOR_INTEGRITY)
+        } else {
+            DEFAULT_DOOR_INTEGRITY
+        };
+
+        let key = if door_node.has_meta("key") {
+            door_node
+                .get_meta("key")
+                .try_to::<GString>()
+                .map(|value| value.to_string())
+                .unwrap_or_default()
+        } else {
+            String::new()
+        };
+
+        let door_position = Vec3::new(position.x, position.y, position.z);
+        let mut door = Door::new(door_position, Vec3::new(facing.x, facing.y, facing.z), integrity, locked);
+        if !key.is_empty() {
+            door = door.with_key(key.as_str());
+        }
+        let nav_link = create_nav_link(&scene_root, position, facing, &door);
+
+        let entity = commands
+            .spawn((door, Interactable::new(InteractionKind::Door, door_position)))
+            .id();
+        interactables.insert(entity, door_node.clone());
+        registry.doors.insert(entity, door_node);
+        registry.nav_links.insert(entity, nav_link);
+
+        logger::log(&format!(
+            "🚪 Door {:?} registered at {:?} (locked: {}, integrity: {}, key: {})",
+            entity,
+            position,
+            locked,
+            integrity,
+            if key.is_empty() { "-" } else { key.as_str() }
+        ));
+    }
+}
+
+/// System: DoorToggled → полотно открыто/закрыто, DoorKicked → лог удара, DoorBreached → полотно убирается
+pub fn sync_door_breaches_main_thread(
+    mut toggled: EventReader<DoorToggled>,
+    mut kicked: EventReader<DoorKicked>,
+    mut breached: EventReader<DoorBreached>,
+    mut registry: NonSendMut<DoorNodeRegistry>,
+    mut interactables: NonSendMut<InteractableNodeRegistry>,
+) {
+    for event in toggled.read() {
+        let Some(door_node) = registry.doors.get_mut(&event.door) else {
+            continue;
+        };
+
+        set_door_open(door_node, event.open);
+        logger::log(&format!(
+            "🚪 Door {:?} {} by {:?}",
+            event.door,
+            if event.open { "opened" } else { "closed" },
+            event.actor
+        ));
+    }
+
+    for event in kicked.read() {
+        logger::log(&format!(
+            "🦶 Door {:?} kicked by {:?} (integrity {})",
+            event.door, event.breacher, event.integrity
+        ));
+    }
+
+    for event in breached.read() {
+        interactables.remove(event.door);
+        let Some(mut door_node) = registry.doors.remove(&event.door) else {
+            continue;
+        };
+
+        door_node.queue_free();
+    }
+}
+
+/// System: Changed<Door> → NavigationLink3D проёма (заперта / закрыта / открыта)
+pub fn sync_door_nav_links_main_thread(
+    doors: Query<(Entity, &Door), Changed<Door>>,
+    mut registry: NonSendMut<DoorNodeRegistry>,
+) {
+    for (entity, door) in doors.iter() {
+        if let Some(link) = registry.nav_links.get_mut(&entity) {
+            apply_nav_link_state(link, door);
+        }
+    }
+}
+
+/// NavigationLink3D поперёк проёма (двунаправленный, в корне сцены — переживает queue_free полотна)
+fn create_nav_link(scene_root: &SceneRoot, position: Vector3, facing: Vector3, door: &Door) -> Gd<NavigationLink3D> {
+    let normal = Vector3::new(facing.x, 0.0, facing.z).try_normalized().unwrap_or(Vector3::BACK);
+
+    let mut link = NavigationLink3D::new_alloc();
+    link.set_name("DoorNavLink");
+    link.set_bidirectional(true);
+    link.set_start_position(normal * NAV_LINK_HALF_LENGTH);
+    link.set_end_position(-normal * NAV_LINK_HALF_LENGTH);
+    apply_nav_link_state(&mut link, door);
+
+    scene_root.node.clone().upcast::<Node>().add_child(&link.clone().upcast::<Node>());
+    link.set_global_position(position);
+    link
+}
+
+fn apply_nav_link_state(link: &mut Gd<NavigationLink3D>, door: &Door) {
+    link.set_enabled(door.is_pathable());
+    link.set_travel_cost(if door.state == DoorState::Closed { CLOSED_DOOR_TRAVEL_COST } else { 1.0 });
+}
+
+/// Открытая дверь: полотно скрыто, коллизии выключены (проход + обзор свободны)
+fn set_door_open(door_node: &mut Gd<Node3D>, open: bool) {
+    door_node.set_visible(!open);
+    set_collision_disabled_recursive(&door_node.clone().upcast::<Node>(), open);
+}
+
+fn set_collision_disabled_recursive(node: &Gd<Node>, disabled: bool) {
+    for child in node.get_children().iter_shared() {
+        if let Ok(mut shape) = child.clone().try_cast::<CollisionShape3D>() {
+            shape.set_deferred("disabled", &disabled.to_variant());
+        }
+        set_collision_disabled_recursive(&child, disabled);
+    }
+}
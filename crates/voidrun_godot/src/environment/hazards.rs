@@ -0,0 +1,127 @@
+//! Hazard zones — Godot Area3D группы `hazard_zones` ↔ ECS HazardZone.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - Новая нода в группе → HazardZone entity (вид из meta `hazard`: radiation / fire / toxic_gas,
+//!   урон — meta `damage_per_second`, по умолчанию — из вида)
+//! - Poll-based (как vacuum): пары (актор, зона) vs прошлый кадр → HazardZoneEntered / Exited
+//! - Дочерние NavigationRegion3D зоны получают `travel_cost` (дороже вакуума для огня)
+//!
+//! Урон, статус-эффекты и выбор точек AI — в ECS (voidrun_simulation::environment).
+
+use bevy::prelude::*;
+use godot::classes::Area3D;
+use godot::prelude::*;
+use voidrun_simulation::environment::{HazardKind, HazardZone, HazardZoneEntered, HazardZoneExited};
+use voidrun_simulation::logger;
+use std::collections::{HashMap, HashSet};
+
+use crate::shared::{SceneRoot, VisualRegistry};
+use super::{set_zone_travel_cost, zone_bounds};
+
+/// Группа Godot для зон опасности
+pub const HAZARD_ZONE_GROUP: &str = "hazard_zones";
+
+/// Стоимость прохода navmesh на 1 DPS зоны (обычный navmesh = 1.0)
+const HAZARD_TRAVEL_COST_PER_DPS: f32 = 1.0;
+
+/// Registry: HazardZone entity ↔ Godot Area3D + пары (актор, зона) на прошлом кадре
+///
+/// NonSend resource — main thread only (Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct HazardZoneRegistry {
+    pub zones: HashMap<Entity, Gd<Area3D>>,
+    /// Уже зарегистрированные ноды (не спавним HazardZone повторно)
+    pub registered: HashSet<InstanceId>,
+    pub occupants: HashSet<(Entity, Entity)>,
+}
+
+/// System: новые ноды группы `hazard_zones` → HazardZone entities
+pub fn register_hazard_zones_main_thread(
+    mut registry: NonSendMut<HazardZoneRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut commands: Commands,
+) {
+    let Some(mut tree) = scene_root.node.get_tree() else {
+        return;
+    };
+
+    for node in tree.get_nodes_in_group(HAZARD_ZONE_GROUP).iter_shared() {
+        let Ok(area) = node.try_cast::<Area3D>() else {
+            continue;
+        };
+        if !registry.registered.insert(area.instance_id()) {
+            continue;
+        }
+
+        let kind_name = if area.has_meta("hazard") {
+            area.get_meta("hazard").try_to::<GString>().map(|name| name.to_string()).unwrap_or_default()
+        } else {
+            String::new()
+        };
+        let Some(kind) = HazardKind::from_name(&kind_name) else {
+            logger::log_error(&format!(
+                "Hazard zone {}: неизвестный meta `hazard` \"{}\" — зона игнорируется",
+                area.get_name(),
+                kind_name
+            ));
+            continue;
+        };
+        let Some((center, half_extents)) = zone_bounds(&area) else {
+            logger::log_error(&format!(
+                "Hazard zone {} без BoxShape3D — зона игнорируется",
+                area.get_name()
+            ));
+            continue;
+        };
+
+        let mut zone = HazardZone::new(kind, center, half_extents);
+        if area.has_meta("damage_per_second") {
+            if let Ok(dps) = area.get_meta("damage_per_second").try_to::<f64>() {
+                zone.damage_per_second = (dps as f32).max(0.0);
+            }
+        }
+
+        // Navmesh внутри зоны дороже → пути обходят опасность, если есть обход
+        set_zone_travel_cost(&area, 1.0 + zone.damage_per_second * HAZARD_TRAVEL_COST_PER_DPS);
+
+        let entity = commands.spawn(zone).id();
+        registry.zones.insert(entity, area);
+
+        logger::log(&format!(
+            "☢️ Hazard zone {:?} ({:?}, {:.1} dps) registered at {:?}",
+            entity, zone.kind, zone.damage_per_second, zone.center
+        ));
+    }
+}
+
+/// System: overlaps зон опасности → HazardZoneEntered / HazardZoneExited
+///
+/// В отличие от вакуума, события — на каждую зону (урон и статусы суммируются).
+pub fn detect_hazard_zones_main_thread(
+    mut registry: NonSendMut<HazardZoneRegistry>,
+    visuals: NonSend<VisualRegistry>,
+    mut entered_events: EventWriter<HazardZoneEntered>,
+    mut exited_events: EventWriter<HazardZoneExited>,
+) {
+    let mut current = HashSet::new();
+    for (&zone, area) in registry.zones.iter() {
+        if !area.is_instance_valid() {
+            continue;
+        }
+
+        for body in area.get_overlapping_bodies().iter_shared() {
+            if let Some(&entity) = visuals.node_to_entity.get(&body.instance_id()) {
+                current.insert((entity, zone));
+            }
+        }
+    }
+
+    for &(entity, zone) in current.difference(&registry.occupants) {
+        entered_events.write(HazardZoneEntered { entity, zone });
+    }
+    for &(entity, zone) in registry.occupants.difference(&current) {
+        exited_events.write(HazardZoneExited { entity, zone });
+    }
+
+    registry.occupants = current;
+}
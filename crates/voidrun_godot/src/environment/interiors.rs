@@ -0,0 +1,100 @@
+//! Interior volumes — Godot Area3D группы `interior_volumes` ↔ ECS InteriorVolume.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - Новая нода в группе → InteriorVolume entity (AABB из BoxShape3D дочернего CollisionShape3D)
+//! - Poll-based (как vacuum): акторы внутри объёмов vs прошлый кадр → InteriorEntered / Exited
+//!
+//! Sheltered и погодные модификаторы — в ECS (voidrun_simulation::environment).
+
+use bevy::prelude::*;
+use godot::classes::Area3D;
+use godot::prelude::*;
+use voidrun_simulation::environment::{InteriorEntered, InteriorExited, InteriorVolume};
+use voidrun_simulation::logger;
+use std::collections::{HashMap, HashSet};
+
+use crate::shared::{SceneRoot, VisualRegistry};
+use super::zone_bounds;
+
+/// Группа Godot для помещений (тег из импорта уровня)
+pub const INTERIOR_VOLUME_GROUP: &str = "interior_volumes";
+
+/// Registry: InteriorVolume entity ↔ Godot Area3D + акторы внутри на прошлом кадре
+///
+/// NonSend resource — main thread only (Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct InteriorVolumeRegistry {
+    pub volumes: HashMap<Entity, Gd<Area3D>>,
+    /// Уже зарегистрированные ноды (не спавним InteriorVolume повторно)
+    pub registered: HashSet<InstanceId>,
+    pub occupants: HashSet<Entity>,
+}
+
+/// System: новые ноды группы `interior_volumes` → InteriorVolume entities
+pub fn register_interior_volumes_main_thread(
+    mut registry: NonSendMut<InteriorVolumeRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut commands: Commands,
+) {
+    let Some(mut tree) = scene_root.node.get_tree() else {
+        return;
+    };
+
+    for node in tree.get_nodes_in_group(INTERIOR_VOLUME_GROUP).iter_shared() {
+        let Ok(area) = node.try_cast::<Area3D>() else {
+            continue;
+        };
+        if !registry.registered.insert(area.instance_id()) {
+            continue;
+        }
+
+        let Some((center, half_extents)) = zone_bounds(&area) else {
+            logger::log_error(&format!(
+                "Interior volume {} без BoxShape3D — объём игнорируется",
+                area.get_name()
+            ));
+            continue;
+        };
+
+        let volume = InteriorVolume::new(center, half_extents);
+        let entity = commands.spawn(volume).id();
+        registry.volumes.insert(entity, area);
+
+        logger::log(&format!(
+            "🏠 Interior volume {:?} registered at {:?} (half extents {:?})",
+            entity, volume.center, volume.half_extents
+        ));
+    }
+}
+
+/// System: overlaps помещений → InteriorEntered / InteriorExited
+///
+/// Смежные объёмы считаются одним помещением (переход между ними — без событий).
+pub fn detect_interior_volumes_main_thread(
+    mut registry: NonSendMut<InteriorVolumeRegistry>,
+    visuals: NonSend<VisualRegistry>,
+    mut entered_events: EventWriter<InteriorEntered>,
+    mut exited_events: EventWriter<InteriorExited>,
+) {
+    let mut current = HashSet::new();
+    for area in registry.volumes.values() {
+        if !area.is_instance_valid() {
+            continue;
+        }
+
+        for body in area.get_overlapping_bodies().iter_shared() {
+            if let Some(&entity) = visuals.node_to_entity.get(&body.instance_id()) {
+                current.insert(entity);
+            }
+        }
+    }
+
+    for &entity in current.difference(&registry.occupants) {
+        entered_events.write(InteriorEntered { entity });
+    }
+    for &entity in registry.occupants.difference(&current) {
+        exited_events.write(InteriorExited { entity });
+    }
+
+    registry.occupants = current;
+}
@@ -0,0 +1,55 @@
+//! Environment zones — Godot Area3D уровня ↔ ECS (вакуум, зоны опасности, помещения).
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - `vacuum`: группа `vacuum_zones` → VacuumZone + VacuumZoneEntered / Exited
+//! - `hazards`: группа `hazard_zones` → HazardZone + HazardZoneEntered / Exited
+//! - `interiors`: группа `interior_volumes` → InteriorVolume + InteriorEntered / Exited (Sheltered)
+//!
+//! Геометрия зон — BoxShape3D дочернего CollisionShape3D. Дочерние NavigationRegion3D
+//! получают `travel_cost` — NavigationAgent3D обходит опасные участки, если есть путь.
+//! Урон, кислород, статус-эффекты, погода и выбор точек AI — в ECS (voidrun_simulation::environment).
+
+use bevy::prelude::*;
+use godot::classes::{Area3D, BoxShape3D, CollisionShape3D, NavigationRegion3D};
+use godot::prelude::*;
+
+pub mod hazards;
+pub mod interiors;
+pub mod vacuum;
+
+pub use hazards::*;
+pub use interiors::*;
+pub use vacuum::*;
+
+/// Стоимость прохода navmesh внутри зоны (обычный navmesh = 1.0) для дочерних NavigationRegion3D
+fn set_zone_travel_cost(area: &Gd<Area3D>, travel_cost: f32) {
+    for child in area.get_children().iter_shared() {
+        if let Ok(mut region) = child.try_cast::<NavigationRegion3D>() {
+            region.set_travel_cost(travel_cost);
+        }
+    }
+}
+
+/// AABB зоны (центр, половины размеров): первый CollisionShape3D с BoxShape3D (с учётом масштаба)
+fn zone_bounds(area: &Gd<Area3D>) -> Option<(Vec3, Vec3)> {
+    for child in area.get_children().iter_shared() {
+        let Ok(collision) = child.try_cast::<CollisionShape3D>() else {
+            continue;
+        };
+        let Some(Ok(box_shape)) = collision.get_shape().map(|shape| shape.try_cast::<BoxShape3D>()) else {
+            continue;
+        };
+
+        let transform = collision.get_global_transform();
+        let scale = transform.basis.get_scale();
+        let size = box_shape.get_size();
+        let origin = transform.origin;
+
+        return Some((
+            Vec3::new(origin.x, origin.y, origin.z),
+            Vec3::new(size.x * scale.x, size.y * scale.y, size.z * scale.z) * 0.5,
+        ));
+    }
+
+    None
+}
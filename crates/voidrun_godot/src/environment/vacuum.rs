@@ -0,0 +1,108 @@
+//! Vacuum zones — Godot Area3D группы `vacuum_zones` ↔ ECS VacuumZone.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - Новая нода в группе → VacuumZone entity (AABB из BoxShape3D дочернего CollisionShape3D)
+//! - Poll-based (как ladders): акторы внутри зон vs прошлый кадр → VacuumZoneEntered / Exited
+//! - Дочерние NavigationRegion3D зоны получают `travel_cost` — NavigationAgent3D
+//!   выбирает маршрут через отсеки с воздухом, если он есть
+//!
+//! Кислород, удушье и выбор точек патруля — в ECS (voidrun_simulation::environment).
+
+use bevy::prelude::*;
+use godot::classes::Area3D;
+use godot::prelude::*;
+use voidrun_simulation::environment::{VacuumZone, VacuumZoneEntered, VacuumZoneExited};
+use voidrun_simulation::logger;
+use std::collections::{HashMap, HashSet};
+
+use crate::shared::{SceneRoot, VisualRegistry};
+use super::{set_zone_travel_cost, zone_bounds};
+
+/// Группа Godot для вакуумных зон
+pub const VACUUM_ZONE_GROUP: &str = "vacuum_zones";
+
+/// Стоимость прохода navmesh внутри вакуума (обычный navmesh = 1.0)
+const VACUUM_TRAVEL_COST: f32 = 8.0;
+
+/// Registry: VacuumZone entity ↔ Godot Area3D + акторы внутри на прошлом кадре
+///
+/// NonSend resource — main thread only (Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct VacuumZoneRegistry {
+    pub zones: HashMap<Entity, Gd<Area3D>>,
+    /// Уже зарегистрированные ноды (не спавним VacuumZone повторно)
+    pub registered: HashSet<InstanceId>,
+    pub occupants: HashSet<Entity>,
+}
+
+/// System: новые ноды группы `vacuum_zones` → VacuumZone entities
+pub fn register_vacuum_zones_main_thread(
+    mut registry: NonSendMut<VacuumZoneRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut commands: Commands,
+) {
+    let Some(mut tree) = scene_root.node.get_tree() else {
+        return;
+    };
+
+    for node in tree.get_nodes_in_group(VACUUM_ZONE_GROUP).iter_shared() {
+        let Ok(area) = node.try_cast::<Area3D>() else {
+            continue;
+        };
+        if !registry.registered.insert(area.instance_id()) {
+            continue;
+        }
+
+        let Some((center, half_extents)) = zone_bounds(&area) else {
+            logger::log_error(&format!(
+                "Vacuum zone {} без BoxShape3D — зона игнорируется",
+                area.get_name()
+            ));
+            continue;
+        };
+
+        // Navmesh внутри вакуума дороже → пути идут через отсеки с воздухом
+        set_zone_travel_cost(&area, VACUUM_TRAVEL_COST);
+
+        let zone = VacuumZone::new(center, half_extents);
+        let entity = commands.spawn(zone).id();
+        registry.zones.insert(entity, area);
+
+        logger::log(&format!(
+            "🫧 Vacuum zone {:?} registered at {:?} (half extents {:?})",
+            entity, zone.center, zone.half_extents
+        ));
+    }
+}
+
+/// System: overlaps вакуумных зон → VacuumZoneEntered / VacuumZoneExited
+///
+/// Несколько пересекающихся зон считаются одной (переход между ними — без событий).
+pub fn detect_vacuum_zones_main_thread(
+    mut registry: NonSendMut<VacuumZoneRegistry>,
+    visuals: NonSend<VisualRegistry>,
+    mut entered_events: EventWriter<VacuumZoneEntered>,
+    mut exited_events: EventWriter<VacuumZoneExited>,
+) {
+    let mut current = HashSet::new();
+    for area in registry.zones.values() {
+        if !area.is_instance_valid() {
+            continue;
+        }
+
+        for body in area.get_overlapping_bodies().iter_shared() {
+            if let Some(&entity) = visuals.node_to_entity.get(&body.instance_id()) {
+                current.insert(entity);
+            }
+        }
+    }
+
+    for &entity in current.difference(&registry.occupants) {
+        entered_events.write(VacuumZoneEntered { entity });
+    }
+    for &entity in registry.occupants.difference(&current) {
+        exited_events.write(VacuumZoneExited { entity });
+    }
+
+    registry.occupants = current;
+}
@@ -0,0 +1,160 @@
+//! Gear condition VFX — прочность оружия/брони → материал attached prefab'а.
+//!
+//! Architecture: ADR-007 (attached prefabs) + ADR-004 (NonSend, _main_thread naming)
+//! - Changed<EquippedWeapons> / Changed<EquippedArmor> / Changed<Attachment> → ConditionTier (pristine / worn / damaged)
+//! - StandardMaterial3D: albedo темнеет к ржавчине, растёт roughness, падает metallic
+//! - ShaderMaterial: uniform `wear` (0.0 / 0.5 / 1.0) — шейдер сам решает как рисовать износ
+//!
+//! Исходные материалы хранятся в meta MeshInstance3D — тир всегда считается от оригинала.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{Material, MeshInstance3D, ShaderMaterial, StandardMaterial3D};
+use voidrun_simulation::{Attachment, ConditionTier, EquippedArmor, EquippedWeapons, ItemDefinitions};
+use voidrun_simulation::logger;
+
+use crate::shared::AttachmentRegistry;
+
+/// Meta на корне prefab'а: последний применённый тир (повторно не переприменяем)
+const APPLIED_TIER_META: &str = "condition_tier";
+
+/// Meta на MeshInstance3D: исходный материал поверхности (`condition_base_<surface>`)
+const BASE_MATERIAL_META_PREFIX: &str = "condition_base_";
+
+/// Цвет, к которому тянется albedo при износе (грязь / ржавчина)
+const WEAR_COLOR: Color = Color::from_rgb(0.42, 0.24, 0.12);
+
+/// Параметры материала для тира
+struct ConditionLook {
+    /// Uniform `wear` для ShaderMaterial (0.0 = как новое)
+    wear: f32,
+    /// Доля смешивания albedo с WEAR_COLOR
+    tint: f32,
+    /// Добавка к roughness
+    roughness_bonus: f32,
+    /// Множитель metallic
+    metallic_factor: f32,
+}
+
+impl ConditionLook {
+    fn for_tier(tier: ConditionTier) -> Self {
+        match tier {
+            ConditionTier::Pristine => Self { wear: 0.0, tint: 0.0, roughness_bonus: 0.0, metallic_factor: 1.0 },
+            ConditionTier::Worn => Self { wear: 0.5, tint: 0.25, roughness_bonus: 0.25, metallic_factor: 0.7 },
+            ConditionTier::Damaged => Self { wear: 1.0, tint: 0.5, roughness_bonus: 0.5, metallic_factor: 0.35 },
+        }
+    }
+}
+
+/// System: прочность снаряжения → материалы attached prefab'ов
+///
+/// Запускается ПОСЛЕ attach_prefabs_main_thread (новый prefab сразу получает тир).
+pub fn apply_gear_condition_main_thread(
+    actors: Query<
+        (Entity, Option<&EquippedWeapons>, Option<&EquippedArmor>),
+        Or<(Changed<EquippedWeapons>, Changed<EquippedArmor>, Changed<Attachment>)>,
+    >,
+    definitions: Res<ItemDefinitions>,
+    attachments: NonSend<AttachmentRegistry>,
+) {
+    for (entity, weapons, armor) in actors.iter() {
+        if let Some(weapon) = weapons.and_then(|weapons| weapons.get_active_weapon()) {
+            let attachment_point = definitions
+                .get(&weapon.definition_id)
+                .and_then(|def| def.attachment_point.clone())
+                .unwrap_or_default();
+
+            if let Some(prefab) = attachments.attachments.get(&(entity, attachment_point)) {
+                apply_condition(entity, prefab, weapon.condition());
+            }
+        }
+
+        for piece in armor.into_iter().flat_map(|armor| armor.iter()) {
+            if let Some(prefab) = attachments.attachments.get(&(entity, piece.slot.attachment_point().to_string())) {
+                apply_condition(entity, prefab, piece.condition());
+            }
+        }
+    }
+}
+
+/// Применить тир к prefab'у (no-op, если тир уже применён)
+fn apply_condition(entity: Entity, prefab: &Gd<Node3D>, tier: ConditionTier) {
+    if !prefab.is_instance_valid() {
+        return;
+    }
+
+    let tier_name = format!("{:?}", tier);
+    let mut prefab = prefab.clone();
+    if prefab.has_meta(APPLIED_TIER_META)
+        && prefab.get_meta(APPLIED_TIER_META).try_to::<GString>().ok() == Some(GString::from(tier_name.as_str()))
+    {
+        return;
+    }
+
+    let look = ConditionLook::for_tier(tier);
+    apply_look_recursive(&prefab.clone().upcast::<Node>(), &look);
+    prefab.set_meta(APPLIED_TIER_META, &GString::from(tier_name.as_str()).to_variant());
+
+    logger::log(&format!("🔧 Gear condition {:?} → {:?} ({})", entity, tier, prefab.get_name()));
+}
+
+fn apply_look_recursive(node: &Gd<Node>, look: &ConditionLook) {
+    if let Ok(mut mesh_instance) = node.clone().try_cast::<MeshInstance3D>() {
+        for surface in 0..mesh_instance.get_surface_override_material_count() {
+            let Some(base) = base_material(&mut mesh_instance, surface) else {
+                continue;
+            };
+            if let Some(material) = worn_material(&base, look) {
+                mesh_instance.set_surface_override_material(surface, &material);
+            }
+        }
+    }
+
+    for child in node.get_children().iter_shared() {
+        apply_look_recursive(&child, look);
+    }
+}
+
+/// Исходный материал поверхности (запоминается в meta при первом применении)
+fn base_material(mesh_instance: &mut Gd<MeshInstance3D>, surface: i32) -> Option<Gd<Material>> {
+    let meta_key = format!("{}{}", BASE_MATERIAL_META_PREFIX, surface);
+    if mesh_instance.has_meta(meta_key.as_str()) {
+        return mesh_instance.get_meta(meta_key.as_str()).try_to::<Gd<Material>>().ok();
+    }
+
+    let base = mesh_instance.get_active_material(surface)?;
+    mesh_instance.set_meta(meta_key.as_str(), &base.to_variant());
+    Some(base)
+}
+
+/// Копия материала с параметрами тира (оригинал не трогаем — он может быть shared)
+fn worn_material(base: &Gd<Material>, look: &ConditionLook) -> Option<Gd<Material>> {
+    let duplicated = base.duplicate()?.try_cast::<Material>().ok()?;
+
+    let duplicated = match duplicated.try_cast::<ShaderMaterial>() {
+        Ok(mut shader_mat) => {
+            shader_mat.set_shader_parameter("wear", &Variant::from(look.wear));
+            return Some(shader_mat.upcast::<Material>());
+        }
+        Err(material) => material,
+    };
+
+    match duplicated.try_cast::<StandardMaterial3D>() {
+        Ok(mut standard) => {
+            let albedo = standard.get_albedo();
+            standard.set_albedo(Color::from_rgba(
+                albedo.r + (WEAR_COLOR.r - albedo.r) * look.tint,
+                albedo.g + (WEAR_COLOR.g - albedo.g) * look.tint,
+                albedo.b + (WEAR_COLOR.b - albedo.b) * look.tint,
+                albedo.a,
+            ));
+            let roughness = standard.get_roughness();
+            standard.set_roughness((roughness + look.roughness_bonus).min(1.0));
+            let metallic = standard.get_metallic();
+            standard.set_metallic(metallic * look.metallic_factor);
+            Some(standard.upcast::<Material>())
+        }
+        // Прочие материалы (ORM, canvas) — без вариаций
+        Err(_) => None,
+    }
+}
@@ -102,25 +102,77 @@ impl INode for PlayerInputController {
             "input_backward",
         );
 
-        // Sprint (Shift) - unlimited пока (используем is_action_pressed для continuous state)
+        // Sprint (Shift) - held (используем is_action_pressed для continuous state)
         let sprint = input.is_action_pressed("input_sprint");
 
+        // Hold breath (Shift в ADS) - held, та же клавиша: в ADS спринт запрещён
+        let hold_breath = input.is_action_pressed("input_sprint");
+
         // Jump (Space) - just_pressed через input map
         let jump = input.is_action_just_pressed("input_jump");
+        let jump_held = input.is_action_pressed("input_jump");
 
         // Primary action (LMB) - just_pressed через input map
         let primary_action = input.is_action_just_pressed("primary_action");
+        let primary_held = input.is_action_pressed("primary_action");
 
         // Secondary action (RMB) - just_pressed через input map
         let secondary_action = input.is_action_just_pressed("secondary_action");
 
+        // Quick melee (MMB) - just_pressed через input map
+        let quick_melee = input.is_action_just_pressed("input_quick_melee");
+
+        // Shield bash (C) - just_pressed через input map
+        let shield_bash = input.is_action_just_pressed("input_shield_bash");
+
+        // Throw (G) - just_pressed через input map
+        let throw = input.is_action_just_pressed("input_throw");
+
+        // Crouch (Ctrl) / Prone (Z) - just_pressed toggle стойки
+        let crouch = input.is_action_just_pressed("input_crouch");
+        let prone = input.is_action_just_pressed("input_prone");
+
+        // Interact (F) - just_pressed через input map
+        let interact = input.is_action_just_pressed("input_interact");
+
+        // Breach (B) - just_pressed через input map
+        let breach = input.is_action_just_pressed("input_breach");
+
+        // Scan (T) - held (канал скана идёт, пока клавиша зажата)
+        let scan = input.is_action_pressed("input_scan");
+
+        // Lean (Q / E) - held, обе зажаты → прямо
+        let lean = input.get_axis("input_lean_left", "input_lean_right");
+
+        // Companion orders (F5-F8) - just_pressed
+        let companion_follow = input.is_action_just_pressed("input_companion_follow");
+        let companion_hold = input.is_action_just_pressed("input_companion_hold");
+        let companion_attack = input.is_action_just_pressed("input_companion_attack");
+        let companion_regroup = input.is_action_just_pressed("input_companion_regroup");
+
         // Создаём PlayerInputEvent
         let input_event = PlayerInputEvent {
             move_direction: Vec2::new(move_direction.x, move_direction.y),
             sprint,
+            hold_breath,
             jump,
+            jump_held,
             primary_action,
+            primary_held,
             secondary_action,
+            quick_melee,
+            shield_bash,
+            throw,
+            crouch,
+            prone,
+            interact,
+            breach,
+            scan,
+            lean,
+            companion_follow,
+            companion_hold,
+            companion_attack,
+            companion_regroup,
         };
 
         // Emit event через SimulationBridge
@@ -152,12 +204,26 @@ impl INode for PlayerInputController {
         if input.is_action_just_pressed("input_jump")
             || input.is_action_just_pressed("primary_action")
             || input.is_action_just_pressed("secondary_action")
+            || input.is_action_just_pressed("input_quick_melee")
+            || input.is_action_just_pressed("input_shield_bash")
+            || input.is_action_just_pressed("input_throw")
+            || input.is_action_just_pressed("input_interact")
+            || input.is_action_just_pressed("input_breach")
+            || input.is_action_pressed("input_scan")
+            || input.is_action_pressed("input_lean_left")
+            || input.is_action_pressed("input_lean_right")
+            || input.is_action_just_pressed("input_companion_follow")
+            || input.is_action_just_pressed("input_companion_hold")
+            || input.is_action_just_pressed("input_companion_attack")
+            || input.is_action_just_pressed("input_companion_regroup")
             || input.is_action_just_pressed("debug_toggle")
             || input.is_action_pressed("input_forward")
             || input.is_action_pressed("input_backward")
             || input.is_action_pressed("input_left")
             || input.is_action_pressed("input_right")
             || input.is_action_pressed("input_sprint")
+            || input.is_action_just_pressed("input_crouch")
+            || input.is_action_just_pressed("input_prone")
             || input.is_action_just_pressed("slot1")
             || input.is_action_just_pressed("slot2")
             || input.is_action_just_pressed("slot3")
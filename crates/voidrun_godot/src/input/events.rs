@@ -14,10 +14,15 @@ use bevy::math::Vec2;
 ///
 /// # Fields
 /// - `move_direction`: WASD input (normalized, Vec2::ZERO если нет движения)
-/// - `sprint`: Shift key (unlimited sprint, stamina не тратится пока)
+/// - `sprint`: Shift key (held → SprintIntent, тратит stamina)
+/// - `hold_breath`: Shift key (held в ADS → HoldBreathIntent, тратит stamina)
 /// - `jump`: Space key (just_pressed)
+/// - `jump_held`: Space key (held → jetpack в воздухе)
 /// - `attack`: LMB (just_pressed)
+/// - `primary_held`: LMB (held → очередь / автоматический огонь)
 /// - `parry`: RMB (just_pressed)
+/// - `lean`: Q / E (held → LeanIntent, наклон из-за укрытия)
+/// - `companion_*`: F5-F8 (just_pressed → CompanionCommandIntent, приказы спутникам)
 ///
 /// # Примечание
 /// Mouse look пока НЕ включён (камера будет позже)
@@ -38,21 +43,74 @@ pub struct PlayerInputEvent {
     /// - W+D diagonal: `Vec2(0.707, -0.707)` (normalized)
     pub move_direction: Vec2,
 
-    /// Sprint key (Shift) - пока unlimited (stamina не тратится)
+    /// Sprint key (Shift) - held, тратит stamina (0 → Exhausted lockout)
     pub sprint: bool,
 
+    /// Hold breath (Shift) - held, та же клавиша, что спринт (в ADS спринт запрещён)
+    /// - Полный ADS → HoldBreathIntent: покачивание оружия гаснет, stamina тратится
+    pub hold_breath: bool,
+
     /// Jump key (Space) - just_pressed
     pub jump: bool,
 
+    /// Jump key (Space) - held, в воздухе / невесомости → JetpackIntent
+    pub jump_held: bool,
+
     /// Primary action (LMB) - just_pressed
     /// - Melee weapon: attack
     /// - Ranged weapon: fire
     pub primary_action: bool,
 
+    /// Primary action (LMB) - held
+    /// - Ranged weapon с FireMode::Burst / FireMode::Auto: спуск зажат → TriggerIntent
+    pub primary_held: bool,
+
     /// Secondary action (RMB) - just_pressed
     /// - Melee weapon: parry
     /// - Ranged weapon: toggle ADS
     pub secondary_action: bool,
+
+    /// Quick melee (MMB) - just_pressed
+    /// - Ranged weapon: удар прикладом (QuickMeleeIntent)
+    pub quick_melee: bool,
+
+    /// Shield bash (C) - just_pressed
+    /// - Толчок активным энергощитом (ShieldBashIntent), с любым оружием в руках
+    pub shield_bash: bool,
+
+    /// Throw key (G) - just_pressed
+    /// - Метательное оружие из consumable слота → ThrowIntent
+    pub throw: bool,
+
+    /// Crouch key (Ctrl) - just_pressed, toggle Stance::Crouched
+    pub crouch: bool,
+
+    /// Prone key (Z) - just_pressed, toggle Stance::Prone
+    pub prone: bool,
+
+    /// Interact key (F) - just_pressed
+    /// - Луч камеры на interactable → InteractIntent (дверь / предмет / рубильник)
+    /// - Рядом тревожная панель → взлом (HackAlarmPanelIntent)
+    pub interact: bool,
+
+    /// Breach key (B) - just_pressed
+    /// - Рядом закрытая дверь → выбить (BreachDoorIntent)
+    pub breach: bool,
+
+    /// Scan key (T) - held
+    /// - Луч камеры на актора → ScanIntent, отпущена → CancelScanIntent
+    pub scan: bool,
+
+    /// Lean keys (Q / E) - held, -1.0 (влево) … 1.0 (вправо), 0 — прямо
+    /// - Изменение → LeanIntent (наклон из-за укрытия)
+    pub lean: f32,
+
+    /// Companion keys (F5 / F6 / F7 / F8) - just_pressed
+    /// - Приказ спутникам: за мной / стоять здесь / атаковать цель под прицелом / ко мне
+    pub companion_follow: bool,
+    pub companion_hold: bool,
+    pub companion_attack: bool,
+    pub companion_regroup: bool,
 }
 
 /// Camera toggle event - переключение между FPS и RTS camera
@@ -16,15 +16,32 @@
 use bevy::prelude::*;
 use godot::prelude::*;
 use voidrun_simulation::camera::{ActiveCamera, CameraMode};
-use voidrun_simulation::movement::JumpIntent;
+use voidrun_simulation::movement::{
+    ClimbingState, GravityState, Jetpack, JetpackIntent, JetpackThrusting, JumpIntent, LadderExited, LeanIntent,
+    LeanState, MantleIntent, MantleState, Shoved, Sprint, SprintIntent, Sprinting, Stance,
+};
 use voidrun_simulation::player::Player;
-use voidrun_simulation::shooting::ToggleADSIntent;
-use voidrun_simulation::combat::{MeleeAttackIntent, MeleeAttackState, ParryIntent, ParryState, WeaponStats, WeaponFireIntent};
+use voidrun_simulation::shooting::{AimMode, HoldBreathIntent, ToggleADSIntent};
+use voidrun_simulation::combat::{
+    ClearJamIntent, Downed, Exhausted, MeleeAttackIntent, MeleeAttackState, ParryIntent, ParryState, QuickMeleeIntent, WeaponStats,
+    ReviveIntent, ShieldBashIntent, TriggerIntent,
+};
+use voidrun_simulation::{EquippedWeapons, ThrowIntent};
+use voidrun_simulation::doors::BreachDoorIntent;
+use voidrun_simulation::objective::{CarryingObjective, DropObjectiveIntent, PickUpObjectiveIntent};
+use voidrun_simulation::security::HackAlarmPanelIntent;
+use voidrun_simulation::world_events::OpenSupplyDropIntent;
 use voidrun_simulation::logger;
 
 use super::events::PlayerInputEvent;
 use crate::shared::VisualRegistry;
 
+/// Доля скорости ходьбы для шага в сторону на лестнице
+const LADDER_STEP_FACTOR: f32 = 0.5;
+
+/// Длительность тяги толчка вверх в невесомости (Space, секунды)
+const ZERO_G_KICK_SECS: f32 = 0.5;
+
 /// Player movement system - НАПРЯМУЮ устанавливает velocity CharacterBody3D
 ///
 /// # Архитектура
@@ -34,8 +51,22 @@ use crate::shared::VisualRegistry;
 ///
 /// # Movement
 /// - WASD → CharacterBody3D.velocity (FPS-style direct control)
-/// - Sprint → speed multiplier (6.0 vs 3.0 м/с)
+/// - Shift + движение → SprintIntent (ECS тратит stamina, 0 → Exhausted lockout)
+/// - Sprinting → speed × Sprint::speed_multiplier (3.0 → 6.0 м/с по умолчанию)
+/// - Exhausted → speed × Exhausted::movement_penalty
+/// - CarryingObjective → speed × CarryingObjective::speed_multiplier
+/// - Encumbered (перегруз inventory) → speed × Encumbered::speed_multiplier, без спринта
+/// - Ctrl / Z → toggle Stance::Crouched / Stance::Prone (speed × Stance::speed_multiplier, без спринта)
+/// - ADS (AimMode) → speed × AimMode::movement_multiplier, без спринта
 /// - Space → JumpIntent event (обрабатывается gravity system)
+/// - Space лицом к препятствию по пояс → MantleIntent (raycast `find_mantle_target`, только стоя)
+/// - MantleState → input движения игнорируется (позицию ведёт apply_mantle_positions_main_thread)
+/// - Shoved (ударная волна) → input игнорируется до конца отброса (ведёт gravity system)
+/// - ClimbingState (лестница) → W/S = подъём/спуск, A/D = шаг в сторону, S на полу = шаг назад,
+///   Space → LadderExited (отпустить лестницу), без спринта
+/// - Невесомость (GravityState::is_zero_g) → WASD = импульс двигателей (инерция сохраняется),
+///   Space = толчок вверх, без спринта и прыжка
+/// - Space зажат в воздухе / в невесомости (есть Jetpack) → JetpackIntent (тягу применяет gravity system)
 ///
 /// # Camera-Relative Movement (FPS mode)
 /// - FPS mode: WASD относительно Actor body rotation (yaw Y)
@@ -50,13 +81,46 @@ use crate::shared::VisualRegistry;
 pub fn process_player_input(
     mut input_events: EventReader<PlayerInputEvent>,
     mut jump_events: EventWriter<JumpIntent>,
-    player_query: Query<(Entity, Option<&ActiveCamera>), With<Player>>,
+    mut sprint_events: EventWriter<SprintIntent>,
+    mut mantle_events: EventWriter<MantleIntent>,
+    mut ladder_exit_events: EventWriter<LadderExited>,
+    mut jetpack_events: EventWriter<JetpackIntent>,
+    mut player_query: Query<
+        (
+            Entity,
+            Option<&ActiveCamera>,
+            Option<&Stance>,
+            Option<&Sprint>,
+            Has<Sprinting>,
+            Option<&Exhausted>,
+            Option<&CarryingObjective>,
+            Option<&voidrun_simulation::Encumbered>,
+            Option<&AimMode>,
+            Has<MantleState>,
+            Option<&mut ClimbingState>,
+            Option<&GravityState>,
+            Has<Jetpack>,
+            Has<JetpackThrusting>,
+            (Has<Shoved>, Has<Downed>),
+        ),
+        With<Player>,
+    >,
     visuals: NonSend<VisualRegistry>,
+    scene_root: NonSend<crate::shared::SceneRoot>,
+    time: Res<Time>,
+    mut commands: Commands,
 ) {
     // Guard: нет player entity
-    let Ok((player_entity, active_camera)) = player_query.get_single() else {
+    let Ok((player_entity, active_camera, stance, sprint, sprinting, exhausted, carrying, encumbered, aim_mode, mantling, mut climbing, gravity, has_jetpack, jetpack_thrusting, (shoved, downed))) = player_query.get_single_mut() else {
         return;
     };
+    let zero_g = gravity.is_some_and(|gravity| gravity.is_zero_g());
+
+    // Перелезаем / отброшены → input движения игнорируется до конца MantleState / Shoved
+    if mantling || shoved {
+        input_events.clear();
+        return;
+    }
 
     // Get Godot CharacterBody3D node
     let Some(player_node_3d) = visuals.visuals.get(&player_entity) else {
@@ -75,10 +139,120 @@ pub fn process_player_input(
         .map(|c| c.mode == CameraMode::FirstPerson)
         .unwrap_or(false);
 
+    let mut current_stance = stance.copied().unwrap_or_default();
+    let mut sprint_requested = sprinting;
+    let mut jetpack_requested = jetpack_thrusting;
+
     for input in input_events.read() {
+        // Ctrl / Z → toggle стойки (пишем только при смене; Downed — только ползком)
+        let mut desired_stance = current_stance;
+        if input.crouch && !downed {
+            desired_stance = desired_stance.toggled(Stance::Crouched);
+        }
+        if input.prone && !downed {
+            desired_stance = desired_stance.toggled(Stance::Prone);
+        }
+        if desired_stance != current_stance {
+            commands.entity(player_entity).insert(desired_stance);
+            current_stance = desired_stance;
+        }
+
+        let is_moving = !input.move_direction.is_nan() && input.move_direction.length_squared() > 0.01;
+
+        // Space зажат в воздухе / в невесомости → JetpackIntent (только при смене; ECS проверяет топливо)
+        if has_jetpack {
+            let airborne = zero_g || !player_body.is_on_floor();
+            let wants_thrust = input.jump_held && airborne && climbing.is_none() && !downed;
+            if wants_thrust != jetpack_requested {
+                jetpack_events.write(JetpackIntent {
+                    entity: player_entity,
+                    active: wants_thrust,
+                });
+                jetpack_requested = wants_thrust;
+            }
+        }
+
+        // На лестнице: W/S → ClimbingState.direction (вертикаль ведёт гравитационная система)
+        if let Some(climbing) = climbing.as_deref_mut() {
+            climbing.direction = if is_moving { -input.move_direction.y } else { 0.0 };
+
+            // Сход с лестницы: A/D в сторону, внизу (на полу) S — шаг назад
+            let step_back = if player_body.is_on_floor() && climbing.direction < 0.0 {
+                input.move_direction.y
+            } else {
+                0.0
+            };
+            let local = godot::prelude::Vector3::new(input.move_direction.x, 0.0, step_back);
+            let direction = player_node_3d.get_global_transform().basis * local;
+            let speed = 3.0 * LADDER_STEP_FACTOR;
+            player_body.set_velocity(godot::prelude::Vector3::new(
+                direction.x * speed,
+                climbing.vertical_velocity(),
+                direction.z * speed,
+            ));
+
+            if input.jump {
+                ladder_exit_events.write(LadderExited {
+                    entity: player_entity,
+                });
+            }
+            continue;
+        }
+
+        // Невесомость: WASD → импульс двигателей по осям тела, Space → толчок вверх
+        if zero_g {
+            let local = if is_moving {
+                godot::prelude::Vector3::new(input.move_direction.x, 0.0, input.move_direction.y)
+            } else {
+                godot::prelude::Vector3::ZERO
+            };
+            let direction = player_node_3d.get_global_transform().basis * local;
+            let mut thrust = Vec3::new(direction.x, direction.y, direction.z);
+            let mut thrust_secs = time.delta_secs();
+            if input.jump {
+                thrust = Vec3::Y;
+                thrust_secs = ZERO_G_KICK_SECS;
+            }
+
+            let current = player_body.get_velocity();
+            let drift = GravityState::drift_velocity(Vec3::new(current.x, current.y, current.z), thrust, thrust_secs);
+            player_body.set_velocity(godot::prelude::Vector3::new(drift.x, drift.y, drift.z));
+            continue;
+        }
+
+        // Shift → SprintIntent (только при смене; ECS проверяет stamina/Exhausted/Encumbered/ActionLock)
+        let wants_sprint = input.sprint
+            && is_moving
+            && current_stance.can_sprint()
+            && exhausted.is_none()
+            && encumbered.is_none()
+            && aim_mode.is_none_or(|aim_mode| aim_mode.allows_sprint());
+        if wants_sprint != sprint_requested {
+            sprint_events.write(SprintIntent {
+                entity: player_entity,
+                active: wants_sprint,
+            });
+            sprint_requested = wants_sprint;
+        }
+
         // WASD movement - НАПРЯМУЮ velocity
-        if !input.move_direction.is_nan() && input.move_direction.length_squared() > 0.01 {
-            let speed = if input.sprint { 6.0 } else { 3.0 }; // unlimited sprint
+        if is_moving {
+            let sprint_multiplier = if sprinting {
+                sprint.copied().unwrap_or_default().speed_multiplier
+            } else {
+                1.0
+            };
+            let exhaustion_multiplier = exhausted.map_or(1.0, |exhausted| exhausted.movement_penalty);
+            let carry_multiplier = carrying.map_or(1.0, |carrying| carrying.speed_multiplier);
+            let encumbrance_multiplier = encumbered.map_or(1.0, |encumbered| encumbered.speed_multiplier);
+            let aim_multiplier = aim_mode.map_or(1.0, |aim_mode| aim_mode.movement_multiplier());
+            let speed = 3.0
+                * sprint_multiplier
+                * exhaustion_multiplier
+                * carry_multiplier
+                * encumbrance_multiplier
+                * aim_multiplier
+                * current_stance.speed_multiplier();
 
             let velocity = if is_fps {
                 // FPS mode: camera-relative movement (Actor body rotation)
@@ -124,11 +298,30 @@ pub fn process_player_input(
             player_body.set_velocity(velocity);
         }
 
-        // Jump
-        if input.jump {
-            jump_events.write(JumpIntent {
-                entity: player_entity,
-            });
+        // Jump (лицом к препятствию по пояс → перелезаем вместо прыжка; Downed — не прыгает)
+        if input.jump && !downed {
+            let mantle_target = if current_stance == Stance::Standing && carrying.is_none() {
+                scene_root
+                    .node
+                    .get_world_3d()
+                    .and_then(|mut world| world.get_direct_space_state())
+                    .and_then(|mut space| crate::movement::find_mantle_target(&player_body, &mut space))
+            } else {
+                None
+            };
+
+            if let Some(target) = mantle_target {
+                let start = player_body.get_global_position();
+                mantle_events.write(MantleIntent {
+                    entity: player_entity,
+                    start: Vec3::new(start.x, start.y, start.z),
+                    end: Vec3::new(target.x, target.y, target.z),
+                });
+            } else {
+                jump_events.write(JumpIntent {
+                    entity: player_entity,
+                });
+            }
         }
     }
     player_body.move_and_slide();
@@ -138,16 +331,26 @@ pub fn process_player_input(
 ///
 /// # Архитектура
 /// - Читает: PlayerInputEvent
-/// - Пишет: MeleeAttackIntent, ParryIntent, ToggleADSIntent
+/// - Пишет: MeleeAttackIntent, ParryIntent, ToggleADSIntent, QuickMeleeIntent, ThrowIntent, TriggerIntent, HoldBreathIntent
 /// - Query: With<Player>
 ///
 /// # Actions
 /// - **Primary action (LMB):**
 ///   - Melee weapon → MeleeAttackIntent
-///   - Ranged weapon → RangedAttackIntent (TODO: Phase 3)
+///   - Ranged weapon → TriggerIntent (нажат / отпущен); заклинило → ClearJamIntent
+///   - ECS проверяет оружие как у AI (`fire_block`: cooldown / перегрев / клин / магазин / спринт),
+///     выстрелы шлёт `continue_firing`, отказ → FireDenied (HUD)
 /// - **Secondary action (RMB):**
 ///   - Melee weapon → ParryIntent (VisionCone-based parry)
 ///   - Ranged weapon → ToggleADSIntent (ADS toggle)
+/// - **Quick melee (MMB):**
+///   - Ranged weapon → QuickMeleeIntent (удар прикладом, ECS превращает в MeleeAttackType::Bash)
+/// - **Throw (G):** ThrowIntent (метательное оружие из consumable слота, с любым оружием в руках)
+/// - **Hold breath (Shift в полном ADS):** HoldBreathIntent (задержано / отпущено; гасит WeaponSway)
+///
+/// # Sprint / objective carry
+/// Пока Sprinting — ADS игнорируется, выстрел отклоняет ECS (FireDenied Busy); melee режет ActionLock(Sprint).
+/// Носитель объективного предмета (CarryingObjective) не может войти в ADS (выйти — может).
 ///
 /// # Parry Detection (Melee only)
 /// - Uses player VisionCone to find visible enemies
@@ -159,24 +362,72 @@ pub fn player_combat_input(
     mut attack_events: EventWriter<MeleeAttackIntent>,
     mut parry_events: EventWriter<ParryIntent>,
     mut ads_toggle_events: EventWriter<ToggleADSIntent>,
-    mut fire_intent_events: EventWriter<WeaponFireIntent>,
-    player_query: Query<Entity, With<Player>>,
+    mut trigger_events: EventWriter<TriggerIntent>,
+    mut clear_jam_events: EventWriter<ClearJamIntent>,
+    mut quick_melee_events: EventWriter<QuickMeleeIntent>,
+    mut throw_events: EventWriter<ThrowIntent>,
+    mut hold_breath_events: EventWriter<HoldBreathIntent>,
+    player_query: Query<
+        (Entity, Has<Sprinting>, Has<CarryingObjective>, Option<&AimMode>, Option<&EquippedWeapons>, Option<&ActiveCamera>),
+        With<Player>,
+    >,
     attack_states: Query<(Entity, &MeleeAttackState)>,
     parry_states: Query<&ParryState>,
     weapons: Query<&WeaponStats>,
     visuals: NonSend<VisualRegistry>,
+    mut trigger_held: Local<bool>,
+    mut breath_held: Local<bool>,
 ) {
     // Guard: нет player entity
-    let Ok(player_entity) = player_query.single() else {
+    let Ok((player_entity, sprinting, carrying, aim_mode, equipped, active_camera)) = player_query.single() else {
         return;
     };
+    // RTS камера: мышь — выделение юнитов и приказы (`rts_selection`), не оружие
+    let rts_mode = active_camera.is_some_and(|camera| camera.mode == CameraMode::RTS);
+    let jammed = equipped.is_some_and(|equipped| equipped.get_active_weapon().is_some_and(|weapon| weapon.jammed));
+
+    // Войти в ADS нельзя при спринте / с объективным предметом; выйти — всегда
+    let in_hip_fire = aim_mode.is_none_or(|aim_mode| !aim_mode.is_ads_or_entering());
+    let ads_blocked = (sprinting || carrying) && in_hip_fire;
 
     for input in input_events.read() {
+        // THROW (G) - не зависит от оружия в руках (не при спринте)
+        if input.throw && !sprinting {
+            throw_events.write(ThrowIntent {
+                thrower: player_entity,
+            });
+        }
+
+        // HOLD BREATH (Shift в полном ADS) - только смена состояния; stamina / Exhausted проверяет ECS
+        let breath_down = input.hold_breath && aim_mode.is_some_and(|aim_mode| aim_mode.is_fully_ads());
+        if breath_down != *breath_held {
+            hold_breath_events.write(HoldBreathIntent {
+                entity: player_entity,
+                active: breath_down,
+            });
+            *breath_held = breath_down;
+        }
+
         // Get weapon type (needed for context-dependent actions)
         let Ok(weapon_stats) = weapons.get(player_entity) else {
             continue;
         };
 
+        // TRIGGER (LMB held) - ranged: только смена состояния спуска
+        // Проверку оружия (cooldown / перегрев / спринт ...) и выстрелы ведёт ECS, как у AI
+        let trigger_down = weapon_stats.is_ranged() && input.primary_held && !jammed && !rts_mode;
+        if trigger_down != *trigger_held {
+            trigger_events.write(TriggerIntent {
+                shooter: player_entity,
+                held: trigger_down,
+            });
+            *trigger_held = trigger_down;
+        }
+
+        if rts_mode {
+            continue;
+        }
+
         // PRIMARY ACTION (LMB) - Attack/Fire
         if input.primary_action {
             if weapon_stats.is_melee() {
@@ -185,16 +436,10 @@ pub fn player_combat_input(
                     attacker: player_entity,
                     attack_type: voidrun_simulation::combat::MeleeAttackType::Normal,
                 });
-            } else if weapon_stats.is_ranged() {
-                // Ranged attack: emit WeaponFireIntent (no target, direction = weapon forward)
-                fire_intent_events.write(WeaponFireIntent {
-                    shooter: player_entity,
-                    target: None, // Player FPS shooting (direction from weapon/camera)
-                    damage: weapon_stats.base_damage,
-                    speed: weapon_stats.projectile_speed,
-                    max_range: weapon_stats.range,
-                    hearing_range: weapon_stats.hearing_range,
-                });
+            } else if weapon_stats.is_ranged() && jammed {
+                // Заклинило: огонь = устранить клин
+                clear_jam_events.write(ClearJamIntent { entity: player_entity });
+                logger::log("🔧 Clearing weapon jam");
             }
         }
 
@@ -210,7 +455,7 @@ pub fn player_combat_input(
                     &weapons,
                     &visuals,
                 );
-            } else if weapon_stats.is_ranged() {
+            } else if weapon_stats.is_ranged() && !ads_blocked {
                 // Ranged weapon → Toggle ADS
                 ads_toggle_events.write(ToggleADSIntent {
                     entity: player_entity,
@@ -218,6 +463,114 @@ pub fn player_combat_input(
                 logger::log("🎯 Toggle ADS");
             }
         }
+
+        // QUICK MELEE (MMB) - удар прикладом (melee оружие бьёт обычной атакой через LMB)
+        if input.quick_melee && weapon_stats.is_ranged() {
+            quick_melee_events.write(QuickMeleeIntent {
+                attacker: player_entity,
+            });
+        }
+    }
+}
+
+/// Player shield bash system - [C] → ShieldBashIntent
+///
+/// - Читает: PlayerInputEvent (`shield_bash`)
+/// - Пишет: ShieldBashIntent (с любым оружием в руках, не при спринте)
+///
+/// Щит (активен, хватает энергии) проверяет ECS `convert_shield_bash_intents`.
+pub fn player_shield_bash_input(
+    mut input_events: EventReader<PlayerInputEvent>,
+    mut shield_bash_events: EventWriter<ShieldBashIntent>,
+    player_query: Query<(Entity, Has<Sprinting>), With<Player>>,
+) {
+    let Ok((player_entity, sprinting)) = player_query.single() else {
+        return;
+    };
+
+    for input in input_events.read() {
+        if input.shield_bash && !sprinting {
+            shield_bash_events.write(ShieldBashIntent {
+                attacker: player_entity,
+            });
+        }
+    }
+}
+
+/// Player lean system - Q/E → LeanIntent
+///
+/// - Читает: PlayerInputEvent (`lean`)
+/// - Пишет: LeanIntent только при смене направления (сравнение с LeanState.target)
+///
+/// Запреты (спринт, лёжа, mantle, лестница) проверяет ECS `update_lean_states`.
+pub fn player_lean_input(
+    mut input_events: EventReader<PlayerInputEvent>,
+    mut lean_events: EventWriter<LeanIntent>,
+    player_query: Query<(Entity, &LeanState), With<Player>>,
+) {
+    let Ok((player_entity, lean)) = player_query.single() else {
+        return;
+    };
+
+    if let Some(input) = input_events.read().last() {
+        if input.lean != lean.target {
+            lean_events.write(LeanIntent {
+                entity: player_entity,
+                direction: input.lean,
+            });
+        }
+    }
+}
+
+/// Player interact system - [F] → hack панели / подобрать-бросить объективный предмет / вскрыть supply drop, [B] → BreachDoorIntent
+///
+/// # Архитектура
+/// - Читает: PlayerInputEvent (`interact`, `breach`)
+/// - Пишет: HackAlarmPanelIntent (ECS `start_alarm_panel_hacks` ищет панель в HACK_RANGE)
+/// - Пишет: PickUpObjectiveIntent (ECS `pick_up_objectives` ищет предмет в PICKUP_RANGE)
+/// - Пишет: DropObjectiveIntent (если уже несём предмет — F бросает его)
+/// - Пишет: OpenSupplyDropIntent (ECS `open_supply_drops` ищет открытый контейнер в INTERACT_RANGE)
+/// - Пишет: ReviveIntent (ECS `start_revives` ищет раненого союзника в REVIVE_RANGE)
+/// - Пишет: BreachDoorIntent (ECS `start_door_breaches` ищет дверь в BREACH_RANGE)
+///
+/// Нет панели/предмета/контейнера/союзника/двери рядом → intent игнорируется в ECS.
+pub fn player_interact_input(
+    mut input_events: EventReader<PlayerInputEvent>,
+    mut hack_events: EventWriter<HackAlarmPanelIntent>,
+    mut breach_events: EventWriter<BreachDoorIntent>,
+    mut pickup_events: EventWriter<PickUpObjectiveIntent>,
+    mut drop_events: EventWriter<DropObjectiveIntent>,
+    mut supply_drop_events: EventWriter<OpenSupplyDropIntent>,
+    mut revive_events: EventWriter<ReviveIntent>,
+    player_query: Query<(Entity, Has<CarryingObjective>), With<Player>>,
+) {
+    let Ok((player_entity, carrying)) = player_query.single() else {
+        return;
+    };
+
+    for input in input_events.read() {
+        if input.interact && carrying {
+            drop_events.write(DropObjectiveIntent {
+                actor: player_entity,
+            });
+        } else if input.interact {
+            hack_events.write(HackAlarmPanelIntent {
+                actor: player_entity,
+            });
+            pickup_events.write(PickUpObjectiveIntent {
+                actor: player_entity,
+            });
+            supply_drop_events.write(OpenSupplyDropIntent {
+                actor: player_entity,
+            });
+            revive_events.write(ReviveIntent {
+                reviver: player_entity,
+            });
+        } else if input.breach {
+            breach_events.write(BreachDoorIntent {
+                actor: player_entity,
+            });
+        }
     }
 }
 
@@ -0,0 +1,291 @@
+//! Interaction — [F] raycast → ECS InteractIntent, результаты → ноды уровня.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - Ноды группы `interactables` → Interactable entities (meta `interaction`: pickup / switch / container,
+//!   pickup — meta `item`, switch — meta `on` (+ `heat` / `heat_radius` → HeatSource: костёр, обогреватель), container — meta `items` через запятую и/или `loot_table`).
+//!   Двери регистрирует `doors` (группа `breachable_doors`), выпавшие предметы (WorldItem) — `visual_sync::spawn_world_item_visuals_main_thread`,
+//!   трупы с лутом (Container) — нода актора из VisualRegistry.
+//! - [F]: raycast камеры (environment + corpses layers) → первая зарегистрированная нода вверх по дереву;
+//!   промах → ближайший interactable вплотную к игроку. Открыт контейнер → [F] забирает всё
+//! - ItemPickedUp → queue_free ноды, SwitchToggled → сигнал `switch_toggled(on)` на ноде
+//!
+//! Правила (дистанция, заперто, инвентарь) — в ECS (`voidrun_simulation::interaction`).
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::PhysicsRayQueryParameters3D;
+use voidrun_simulation::interaction::{
+    Container, Interactable, InteractIntent, InteractionKind, ItemPickedUp, LootTableRef, OpenContainer, Pickup,
+    Switch, SwitchToggled, TakeFromContainerIntent,
+};
+use voidrun_simulation::environment::HeatSource;
+use voidrun_simulation::item_system::ItemInstance;
+use voidrun_simulation::player::Player;
+use voidrun_simulation::logger;
+use std::collections::{HashMap, HashSet};
+
+use crate::input::PlayerInputEvent;
+use crate::shared::{SceneRoot, VisualRegistry};
+use crate::shared::collision::{COLLISION_LAYER_CORPSES, COLLISION_LAYER_ENVIRONMENT};
+
+/// Группа Godot для используемых объектов (предметы, рубильники)
+pub const INTERACTABLE_GROUP: &str = "interactables";
+
+/// Длина луча [F] от камеры (метры; дистанцию использования проверяет ECS)
+const INTERACT_RAY_LENGTH: f32 = 3.0;
+
+/// Промах луча → ближайший interactable в этом радиусе от игрока (метры)
+const INTERACT_FALLBACK_RADIUS: f32 = 1.5;
+
+/// Registry: Interactable entity ↔ Godot нода
+///
+/// NonSend resource — main thread only (Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct InteractableNodeRegistry {
+    pub nodes: HashMap<Entity, Gd<Node3D>>,
+    /// Reverse mapping для raycast (нода → entity)
+    pub node_to_entity: HashMap<InstanceId, Entity>,
+    /// Уже зарегистрированные ноды (не спавним Interactable повторно)
+    pub registered: HashSet<InstanceId>,
+}
+
+impl InteractableNodeRegistry {
+    pub fn insert(&mut self, entity: Entity, node: Gd<Node3D>) {
+        self.node_to_entity.insert(node.instance_id(), entity);
+        self.nodes.insert(entity, node);
+    }
+
+    pub fn remove(&mut self, entity: Entity) -> Option<Gd<Node3D>> {
+        let node = self.nodes.remove(&entity)?;
+        self.node_to_entity.remove(&node.instance_id());
+        Some(node)
+    }
+
+    /// Entity ноды или её ближайшего зарегистрированного предка (коллайдер — обычно child)
+    fn resolve(&self, node: Gd<Node>) -> Option<Entity> {
+        let mut current = Some(node);
+        while let Some(node) = current {
+            if let Some(&entity) = self.node_to_entity.get(&node.instance_id()) {
+                return Some(entity);
+            }
+            current = node.get_parent();
+        }
+        None
+    }
+}
+
+/// System: новые ноды группы `interactables` → Interactable entities (предметы, рубильники, ящики)
+pub fn register_interactables_main_thread(
+    mut registry: NonSendMut<InteractableNodeRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut commands: Commands,
+) {
+    let Some(mut tree) = scene_root.node.get_tree() else {
+        return;
+    };
+
+    for node in tree.get_nodes_in_group(INTERACTABLE_GROUP).iter_shared() {
+        let Ok(node) = node.try_cast::<Node3D>() else {
+            continue;
+        };
+        if !registry.registered.insert(node.instance_id()) {
+            continue;
+        }
+
+        let kind_name = meta_string(&node, "interaction");
+        let origin = node.get_global_position();
+        let position = Vec3::new(origin.x, origin.y, origin.z);
+
+        let entity = match InteractionKind::from_name(&kind_name) {
+            Some(InteractionKind::Pickup) => {
+                let item_id = meta_string(&node, "item");
+                if item_id.is_empty() {
+                    logger::log_error(&format!("Pickup {} без meta `item` — игнорируется", node.get_name()));
+                    continue;
+                }
+                commands
+                    .spawn((
+                        Interactable::new(InteractionKind::Pickup, position),
+                        Pickup { item: ItemInstance::new(item_id.as_str()) },
+                    ))
+                    .id()
+            }
+            Some(InteractionKind::Switch) => {
+                let on = node.has_meta("on") && node.get_meta("on").try_to::<bool>().unwrap_or(false);
+                let mut switch = commands.spawn((Interactable::new(InteractionKind::Switch, position), Switch { on }));
+                // meta `heat`: костёр / обогреватель — греет, пока включён (°C в центре)
+                if node.has_meta("heat") {
+                    let warmth = node.get_meta("heat").try_to::<f32>().unwrap_or(0.0);
+                    let mut heat_source = HeatSource::new(position, warmth);
+                    if node.has_meta("heat_radius") {
+                        heat_source.radius = node.get_meta("heat_radius").try_to::<f32>().unwrap_or(heat_source.radius);
+                    }
+                    switch.insert(heat_source);
+                }
+                switch.id()
+            }
+            Some(InteractionKind::Container) => {
+                // meta `items`: "medkit,rifle_ammo" (пустой ящик тоже валиден)
+                let items = meta_string(&node, "items")
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|id| !id.is_empty())
+                    .map(ItemInstance::new)
+                    .collect();
+                let mut container = commands.spawn((
+                    Interactable::new(InteractionKind::Container, position),
+                    Container { items },
+                ));
+                // meta `loot_table`: содержимое бросает ECS LootTable
+                let loot_table = meta_string(&node, "loot_table");
+                if !loot_table.is_empty() {
+                    container.insert(LootTableRef(loot_table));
+                }
+                container.id()
+            }
+            Some(InteractionKind::Door) | None => {
+                logger::log_error(&format!(
+                    "Interactable {}: meta `interaction` \"{}\" не поддерживается (двери — группа `breachable_doors`)",
+                    node.get_name(),
+                    kind_name
+                ));
+                continue;
+            }
+        };
+
+        logger::log(&format!(
+            "🤚 Interactable {:?} ({}) registered at {:?}",
+            entity, kind_name, position
+        ));
+        registry.insert(entity, node);
+    }
+}
+
+/// System: [F] → raycast камеры → InteractIntent
+///
+/// - Открыт контейнер (`OpenContainer`) → TakeFromContainerIntent (забрать всё)
+/// - Луч только по environment + corpses layers (капсула игрока не мешает)
+/// - Коллайдер → первая зарегистрированная нода вверх по дереву
+/// - Промах → ближайший interactable в INTERACT_FALLBACK_RADIUS (мелкие предметы под ногами)
+pub fn player_interact_raycast_main_thread(
+    mut input_events: EventReader<PlayerInputEvent>,
+    player: Query<(Entity, Option<&OpenContainer>), With<Player>>,
+    registry: NonSend<InteractableNodeRegistry>,
+    visuals: NonSend<VisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut intents: EventWriter<InteractIntent>,
+    mut take_intents: EventWriter<TakeFromContainerIntent>,
+) {
+    // Дочитываем все события кадра (иначе непрочитанные всплывут на следующем кадре)
+    let pressed = input_events.read().fold(false, |pressed, input| pressed || input.interact);
+    if !pressed {
+        return;
+    }
+    let Ok((player_entity, open_container)) = player.single() else {
+        return;
+    };
+
+    if let Some(open) = open_container {
+        take_intents.write(TakeFromContainerIntent {
+            actor: player_entity,
+            container: open.container,
+            index: None,
+        });
+        return;
+    }
+
+    let target = raycast_interactable(&registry, &scene_root).or_else(|| {
+        let player_node = visuals.visuals.get(&player_entity)?;
+        nearest_interactable(&registry, player_node.get_global_position())
+    });
+
+    if let Some(target) = target {
+        intents.write(InteractIntent {
+            actor: player_entity,
+            target,
+        });
+    }
+}
+
+/// System: результаты взаимодействия → ноды уровня
+pub fn sync_interaction_results_main_thread(
+    mut picked_up: EventReader<ItemPickedUp>,
+    mut switched: EventReader<SwitchToggled>,
+    mut registry: NonSendMut<InteractableNodeRegistry>,
+) {
+    for event in picked_up.read() {
+        if let Some(mut node) = registry.remove(event.pickup) {
+            node.queue_free();
+        }
+    }
+
+    for event in switched.read() {
+        let Some(node) = registry.nodes.get_mut(&event.switch) else {
+            continue;
+        };
+
+        node.set_meta("on", &event.on.to_variant());
+        if node.has_signal("switch_toggled") {
+            node.emit_signal("switch_toggled", &[event.on.to_variant()]);
+        }
+        logger::log(&format!("🔌 Switch {:?} → {}", event.switch, if event.on { "on" } else { "off" }));
+    }
+}
+
+/// System: трупы с лутом ↔ InteractableNodeRegistry (нода — сам актор, не удаляем)
+///
+/// - Added<Container> → нода актора из VisualRegistry как цель [F]
+/// - Container снят (обыскан / despawn) → из registry (нода остаётся до despawn актора)
+pub fn sync_loot_containers_main_thread(
+    added: Query<Entity, Added<Container>>,
+    mut removed: RemovedComponents<Container>,
+    visuals: NonSend<VisualRegistry>,
+    mut registry: NonSendMut<InteractableNodeRegistry>,
+) {
+    for entity in removed.read() {
+        registry.remove(entity);
+    }
+
+    for entity in added.iter() {
+        if let Some(node) = visuals.visuals.get(&entity) {
+            registry.insert(entity, node.clone());
+        }
+    }
+}
+
+fn raycast_interactable(registry: &InteractableNodeRegistry, scene_root: &SceneRoot) -> Option<Entity> {
+    let camera = scene_root.node.get_viewport()?.get_camera_3d()?;
+    let transform = camera.get_global_transform();
+    let from = transform.origin;
+    let to = from - transform.basis.col_c() * INTERACT_RAY_LENGTH;
+
+    let mut space = scene_root.node.get_world_3d()?.get_direct_space_state()?;
+    let mut query = PhysicsRayQueryParameters3D::create(from, to)?;
+    query.set_collision_mask(COLLISION_LAYER_ENVIRONMENT | COLLISION_LAYER_CORPSES);
+    query.set_collide_with_areas(true);
+
+    let result = space.intersect_ray(&query);
+    let collider = result.get("collider")?.try_to::<Gd<Node>>().ok()?;
+    registry.resolve(collider)
+}
+
+fn nearest_interactable(registry: &InteractableNodeRegistry, origin: Vector3) -> Option<Entity> {
+    registry
+        .nodes
+        .iter()
+        .filter(|(_, node)| node.is_instance_valid())
+        .map(|(&entity, node)| (entity, node.get_global_position().distance_to(origin)))
+        .filter(|&(_, distance)| distance <= INTERACT_FALLBACK_RADIUS)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(entity, _)| entity)
+}
+
+fn meta_string(node: &Gd<Node3D>, key: &str) -> String {
+    if !node.has_meta(key) {
+        return String::new();
+    }
+    node.get_meta(key)
+        .try_to::<GString>()
+        .map(|value| value.to_string())
+        .unwrap_or_default()
+}
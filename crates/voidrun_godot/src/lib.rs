@@ -16,7 +16,20 @@ mod ui;              // Debug overlays + in-game UI
 mod player_shooting; // Player ADS + Hip Fire mechanics
 mod shield_vfx;
 mod attachment;
+mod gear_condition;  // Прочность оружия/брони → материал attached prefab'а
+mod weapon_heat;     // Нагрев энергооружия → свечение ствола attached prefab'а
 mod vision;
+mod smoke;           // Smoke volumes (vision blockers)
+mod decals;          // Impact decals (ECS ImpactDecal → Decal на полу)
+mod doors;           // Breachable doors (level nodes ↔ ECS Door)
+mod interaction;     // [F] use: raycast → InteractIntent, pickups / switches (level nodes ↔ ECS)
+mod scan;            // [T] scan: raycast → ScanIntent (HUD — ui::scan_panel)
+mod companions;      // [F5-F8] приказы спутникам → CompanionCommandIntent
+mod rts_selection;   // RTS камера: выделение (клик / рамка) + приказы ПКМ → UnitOrderIntent
+mod tactical_pause;  // Пауза симуляции: заморозка снарядов (приказы копятся в ECS OrderBuffer) + масштаб времени
+mod environment;     // Vacuum + hazard zones (level nodes ↔ ECS VacuumZone / HazardZone)
+mod objectives;      // Carryable objective items (ECS ObjectiveItem → визуал)
+mod supply_drops;    // Supply drop crates (ECS SupplyDrop → визуал)
 mod weapon_switch;
 mod movement;        // Movement commands + navigation + velocity
 
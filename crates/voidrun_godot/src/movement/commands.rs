@@ -6,13 +6,20 @@ use bevy::prelude::*;
 use godot::classes::{BoxMesh, Material, MeshInstance3D, NavigationAgent3D, StandardMaterial3D};
 use godot::prelude::*;
 use voidrun_simulation::{MovementCommand, NavigationState};
+use voidrun_simulation::movement::ClimbingState;
 use voidrun_simulation::logger;
 
+/// Разница высот, при которой NPC на лестнице перестаёт лезть (метры)
+const LADDER_ARRIVE_TOLERANCE: f32 = 0.2;
+
 
 /// Обработка MovementCommand → NavigationAgent3D target
 ///
 /// КРИТИЧНО: set_target_position() вызывается при Changed<MovementCommand>
 /// NavigationState.is_target_reached сбрасывается при новом MovementCommand.
+///
+/// На лестнице (ClimbingState) NavigationAgent ведёт только XZ —
+/// вертикаль: ClimbingState.direction к высоте цели (пересчёт и при Added<ClimbingState>).
 pub fn process_movement_commands_main_thread(
     mut query: Query<
         (
@@ -20,16 +27,21 @@ pub fn process_movement_commands_main_thread(
             &MovementCommand,
             &mut NavigationState,
             Option<&voidrun_simulation::combat::WeaponStats>,
+            Option<&mut ClimbingState>,
         ),
-        Changed<MovementCommand>,
+        Or<(Changed<MovementCommand>, Added<ClimbingState>)>,
     >,
     visuals: NonSend<VisualRegistry>,
 ) {
-    for (entity, command, mut nav_state, weapon_opt) in query.iter_mut() {
+    for (entity, command, mut nav_state, weapon_opt, climbing) in query.iter_mut() {
         let Some(actor_node) = visuals.visuals.get(&entity) else {
             continue;
         };
 
+        if let Some(mut climbing) = climbing {
+            climbing.direction = ladder_climb_direction(command, actor_node, &visuals);
+        }
+
         let Some(mut nav_agent) =
             actor_node.try_get_node_as::<NavigationAgent3D>("NavigationAgent3D")
         else {
@@ -121,6 +133,31 @@ pub fn process_movement_commands_main_thread(
     }
 }
 
+/// Направление подъёма по лестнице к высоте цели MovementCommand (1.0 / -1.0 / 0.0)
+fn ladder_climb_direction(
+    command: &MovementCommand,
+    actor_node: &Gd<Node3D>,
+    visuals: &VisualRegistry,
+) -> f32 {
+    let target_y = match command {
+        MovementCommand::MoveToPosition { target } => target.y,
+        MovementCommand::FollowEntity { target } => {
+            let Some(target_node) = visuals.visuals.get(target) else {
+                return 0.0;
+            };
+            target_node.get_global_position().y
+        }
+        _ => return 0.0,
+    };
+
+    let height_diff = target_y - actor_node.get_global_position().y;
+    if height_diff.abs() < LADDER_ARRIVE_TOLERANCE {
+        0.0
+    } else {
+        height_diff.signum()
+    }
+}
+
 /// Adjust desired distance based on LOS check (stateful iteration).
 ///
 /// Algorithm:
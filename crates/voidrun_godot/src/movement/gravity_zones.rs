@@ -0,0 +1,84 @@
+//! Gravity zones — Godot Area3D группы `gravity_zones` → GravityZoneEntered / GravityZoneExited.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! Poll-based (как ladders): каждый frame собираем акторов внутри зон, сравниваем
+//! с прошлым кадром → events. ECS вешает/снимает GravityState.
+//!
+//! Зона задаёт множитель через meta `gravity_scale` (по умолчанию 0.0 — невесомость).
+
+use bevy::prelude::*;
+use godot::classes::Area3D;
+use godot::prelude::*;
+use voidrun_simulation::movement::{GravityZoneEntered, GravityZoneExited};
+use std::collections::HashMap;
+
+use crate::shared::{SceneRoot, VisualRegistry};
+
+/// Группа Godot для gravity zones
+pub const GRAVITY_ZONE_GROUP: &str = "gravity_zones";
+
+/// Множитель гравитации зоны без meta `gravity_scale`
+const DEFAULT_ZONE_GRAVITY_SCALE: f32 = 0.0;
+
+/// Акторы внутри gravity zones на прошлом кадре (entity → множитель)
+///
+/// NonSend resource — main thread only
+#[derive(Default)]
+pub struct GravityZoneRegistry {
+    pub occupants: HashMap<Entity, f32>,
+}
+
+/// System: overlaps gravity zones → GravityZoneEntered / GravityZoneExited
+///
+/// Пересекающиеся зоны → минимальный множитель.
+/// Смена множителя (переход между зонами) → повторный GravityZoneEntered.
+pub fn detect_gravity_zones_main_thread(
+    mut registry: NonSendMut<GravityZoneRegistry>,
+    visuals: NonSend<VisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut entered_events: EventWriter<GravityZoneEntered>,
+    mut exited_events: EventWriter<GravityZoneExited>,
+) {
+    let Some(mut tree) = scene_root.node.get_tree() else {
+        return;
+    };
+
+    let mut current: HashMap<Entity, f32> = HashMap::new();
+    for node in tree.get_nodes_in_group(GRAVITY_ZONE_GROUP).iter_shared() {
+        let Ok(area) = node.try_cast::<Area3D>() else {
+            continue;
+        };
+
+        let scale = if area.has_meta("gravity_scale") {
+            area.get_meta("gravity_scale")
+                .try_to::<f64>()
+                .map(|value| value as f32)
+                .unwrap_or(DEFAULT_ZONE_GRAVITY_SCALE)
+        } else {
+            DEFAULT_ZONE_GRAVITY_SCALE
+        };
+
+        for body in area.get_overlapping_bodies().iter_shared() {
+            let Some(&entity) = visuals.node_to_entity.get(&body.instance_id()) else {
+                continue;
+            };
+            current
+                .entry(entity)
+                .and_modify(|existing| *existing = existing.min(scale))
+                .or_insert(scale);
+        }
+    }
+
+    for (&entity, &scale) in current.iter() {
+        if registry.occupants.get(&entity) != Some(&scale) {
+            entered_events.write(GravityZoneEntered { entity, scale });
+        }
+    }
+    for &entity in registry.occupants.keys() {
+        if !current.contains_key(&entity) {
+            exited_events.write(GravityZoneExited { entity });
+        }
+    }
+
+    registry.occupants = current;
+}
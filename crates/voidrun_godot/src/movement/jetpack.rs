@@ -0,0 +1,87 @@
+//! Jetpack VFX — JetpackIgnited / JetpackCutOff (ECS) → пламя двигателя на акторе.
+//!
+//! Тяга применяется в apply_gravity_to_all_actors, топливо — в ECS.
+//! Пламя — CpuParticles3D `JetpackThruster` (создаётся при первом включении).
+
+use bevy::prelude::*;
+use godot::classes::{
+    base_material_3d::{Flags as BaseMaterial3DFlags, ShadingMode as BaseMaterial3DShading},
+    cpu_particles_3d::Parameter as CpuParam,
+    CpuParticles3D, Material, Mesh, Node, SphereMesh, StandardMaterial3D,
+};
+use godot::prelude::*;
+use voidrun_simulation::movement::{JetpackCutOff, JetpackIgnited};
+use voidrun_simulation::logger;
+
+use crate::shared::VisualRegistry;
+
+/// Имя ноды пламени на акторе
+const THRUSTER_NODE_NAME: &str = "JetpackThruster";
+
+/// Высота сопла над ногами (спина актора)
+const THRUSTER_HEIGHT: f32 = 1.1;
+
+/// System: JetpackIgnited / JetpackCutOff → emitting пламени
+pub fn sync_jetpack_thrusters_main_thread(
+    mut ignited_events: EventReader<JetpackIgnited>,
+    mut cut_off_events: EventReader<JetpackCutOff>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    for event in ignited_events.read() {
+        let Some(actor_node) = visuals.visuals.get(&event.entity) else {
+            continue;
+        };
+
+        let mut thruster = match actor_node.try_get_node_as::<CpuParticles3D>(THRUSTER_NODE_NAME) {
+            Some(thruster) => thruster,
+            None => {
+                let thruster = create_thruster();
+                actor_node.clone().add_child(&thruster.clone().upcast::<Node>());
+                thruster
+            }
+        };
+        thruster.set_emitting(true);
+    }
+
+    for event in cut_off_events.read() {
+        let Some(actor_node) = visuals.visuals.get(&event.entity) else {
+            continue;
+        };
+
+        if let Some(mut thruster) = actor_node.try_get_node_as::<CpuParticles3D>(THRUSTER_NODE_NAME) {
+            thruster.set_emitting(false);
+        }
+        if event.out_of_fuel {
+            logger::log(&format!("🚀 {:?} jetpack out of fuel", event.entity));
+        }
+    }
+}
+
+/// Пламя двигателя: оранжевые частицы вниз из-за спины
+fn create_thruster() -> Gd<CpuParticles3D> {
+    let mut thruster = CpuParticles3D::new_alloc();
+    thruster.set_name(THRUSTER_NODE_NAME);
+    thruster.set_position(Vector3::new(0.0, THRUSTER_HEIGHT, 0.25));
+
+    let mut sphere_mesh = SphereMesh::new_gd();
+    sphere_mesh.set_radius(0.05);
+    sphere_mesh.set_height(0.1);
+    thruster.set_mesh(&sphere_mesh.upcast::<Mesh>());
+
+    let mut material = StandardMaterial3D::new_gd();
+    material.set_flag(BaseMaterial3DFlags::ALBEDO_FROM_VERTEX_COLOR, true);
+    material.set_albedo(Color::from_rgb(1.0, 0.6, 0.1));
+    material.set_shading_mode(BaseMaterial3DShading::UNSHADED);
+    thruster.set_material_override(&material.upcast::<Material>());
+
+    thruster.set_amount(24);
+    thruster.set_lifetime(0.3);
+    thruster.set_direction(Vector3::new(0.0, -1.0, 0.0));
+    thruster.set_spread(10.0);
+    thruster.set_param_min(CpuParam::INITIAL_LINEAR_VELOCITY, 2.0);
+    thruster.set_param_max(CpuParam::INITIAL_LINEAR_VELOCITY, 3.0);
+    thruster.set_gravity(Vector3::ZERO);
+    thruster.set_emitting(false);
+
+    thruster
+}
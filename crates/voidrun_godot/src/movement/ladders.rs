@@ -0,0 +1,65 @@
+//! Ladders — Godot Area3D группы `ladders` → LadderEntered / LadderExited.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! Poll-based (как VisionCone): каждый frame собираем акторов внутри ladder volumes,
+//! сравниваем с прошлым кадром → events. ECS вешает/снимает ClimbingState.
+//!
+//! Геометрия лестницы — в уровне: Area3D от низа лестницы до площадки наверху
+//! (с запасом над краем — актор выходит из volume уже над полом площадки).
+
+use bevy::prelude::*;
+use godot::classes::Area3D;
+use godot::prelude::*;
+use voidrun_simulation::movement::{LadderEntered, LadderExited};
+use std::collections::HashSet;
+
+use crate::shared::{SceneRoot, VisualRegistry};
+
+/// Группа Godot для ladder volumes
+pub const LADDER_GROUP: &str = "ladders";
+
+/// Акторы внутри ladder volumes на прошлом кадре
+///
+/// NonSend resource — main thread only
+#[derive(Default)]
+pub struct LadderRegistry {
+    pub occupants: HashSet<Entity>,
+}
+
+/// System: overlaps ladder volumes → LadderEntered / LadderExited
+///
+/// Несколько пересекающихся volumes считаются одной лестницей
+/// (переход между пролётами не роняет актора).
+pub fn detect_ladder_overlaps_main_thread(
+    mut registry: NonSendMut<LadderRegistry>,
+    visuals: NonSend<VisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut entered_events: EventWriter<LadderEntered>,
+    mut exited_events: EventWriter<LadderExited>,
+) {
+    let Some(mut tree) = scene_root.node.get_tree() else {
+        return;
+    };
+
+    let mut current = HashSet::new();
+    for node in tree.get_nodes_in_group(LADDER_GROUP).iter_shared() {
+        let Ok(area) = node.try_cast::<Area3D>() else {
+            continue;
+        };
+
+        for body in area.get_overlapping_bodies().iter_shared() {
+            if let Some(&entity) = visuals.node_to_entity.get(&body.instance_id()) {
+                current.insert(entity);
+            }
+        }
+    }
+
+    for &entity in current.difference(&registry.occupants) {
+        entered_events.write(LadderEntered { entity });
+    }
+    for &entity in registry.occupants.difference(&current) {
+        exited_events.write(LadderExited { entity });
+    }
+
+    registry.occupants = current;
+}
@@ -0,0 +1,48 @@
+//! Lean sync — LeanState (ECS) → крен Head/Torso актора (Godot).
+//!
+//! Верх корпуса наклоняется вокруг опоры актора (начало координат body) и сдвигается вбок;
+//! камера игрока (Head/CameraPivot) наклоняется вместе с Head.
+//! Боковой сдвиг глаз пишется в meta `lean_offset` — его читает `shared::los_helpers::eye_position`.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use voidrun_simulation::movement::LeanState;
+
+use crate::shared::los_helpers::{EYE_HEIGHT, LEAN_OFFSET_META};
+use crate::shared::VisualRegistry;
+
+/// Ноды верха корпуса, которые наклоняются (test_actor.tscn)
+const UPPER_BODY_NODES: [&str; 2] = ["Head", "Torso"];
+
+/// System: LeanState изменился → трансформы Head/Torso + meta `lean_offset`
+///
+/// Исходные трансформы запоминаются при первом наклоне (наклон = rest × крен).
+pub fn sync_lean_visuals_main_thread(
+    changed: Query<(Entity, &LeanState), Changed<LeanState>>,
+    visuals: NonSend<VisualRegistry>,
+    mut rest_poses: Local<HashMap<(Entity, &'static str), Transform3D>>,
+) {
+    for (entity, lean) in changed.iter() {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+        let mut actor_node = actor_node.clone();
+
+        let lean_transform = Transform3D::new(
+            Basis::from_axis_angle(Vector3::BACK, lean.roll_radians()),
+            Vector3::RIGHT * lean.amount * LeanState::SLIDE,
+        );
+
+        for name in UPPER_BODY_NODES {
+            let Some(mut node) = actor_node.try_get_node_as::<Node3D>(name) else {
+                continue;
+            };
+            let rest = *rest_poses.entry((entity, name)).or_insert_with(|| node.get_transform());
+            node.set_transform(lean_transform * rest);
+        }
+
+        actor_node.set_meta(LEAN_OFFSET_META, &lean.lateral_offset(EYE_HEIGHT).to_variant());
+    }
+}
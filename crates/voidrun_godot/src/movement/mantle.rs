@@ -0,0 +1,88 @@
+//! Mantle/vault — Godot raycast детекция препятствия + позиционный lerp.
+//!
+//! - `find_mantle_target`: препятствие по пояс впереди и свободное место над ним → точка приземления
+//! - `apply_mantle_positions_main_thread`: MantleState (ECS) → global_position тела (гравитация выключена)
+//!
+//! Правила (ActionLock, длительность) — в ECS (`voidrun_simulation::movement::MantleState`).
+
+use bevy::prelude::*;
+use godot::classes::{CharacterBody3D, PhysicsDirectSpaceState3D, PhysicsRayQueryParameters3D};
+use godot::prelude::*;
+use voidrun_simulation::movement::MantleState;
+
+use crate::shared::collision::COLLISION_LAYER_ENVIRONMENT;
+use crate::shared::VisualRegistry;
+
+/// Дистанция до препятствия, с которой можно перелезть (метры)
+const MANTLE_REACH: f32 = 0.9;
+/// Минимальная высота препятствия (ниже — просто прыжок/ступенька)
+const MIN_MANTLE_HEIGHT: f32 = 0.4;
+/// Максимальная высота препятствия (по пояс)
+const MAX_MANTLE_HEIGHT: f32 = 1.2;
+/// Насколько заходим на препятствие за точкой касания (метры)
+const LANDING_DEPTH: f32 = 0.4;
+
+/// Найти точку приземления для перелезания (ноги на верхней грани препятствия)
+///
+/// 1. Луч вперёд на высоте MIN_MANTLE_HEIGHT → должен упереться в препятствие
+/// 2. Луч вперёд над MAX_MANTLE_HEIGHT → должен быть свободен (стена, а не уступ)
+/// 3. Луч вниз за точкой касания → верхняя грань (в диапазоне высот)
+pub fn find_mantle_target(
+    body: &Gd<CharacterBody3D>,
+    space: &mut Gd<PhysicsDirectSpaceState3D>,
+) -> Option<Vector3> {
+    let feet = body.get_global_position();
+    let forward_godot = -body.get_global_basis().col_c();
+    let forward = Vector3::new(forward_godot.x, 0.0, forward_godot.z).try_normalized()?;
+
+    let low = feet + Vector3::UP * MIN_MANTLE_HEIGHT;
+    let obstacle_hit = raycast_environment(space, low, low + forward * MANTLE_REACH)?;
+    let obstacle_distance = (obstacle_hit - low).length();
+
+    let high = feet + Vector3::UP * (MAX_MANTLE_HEIGHT + 0.1);
+    if raycast_environment(space, high, high + forward * (obstacle_distance + LANDING_DEPTH)).is_some() {
+        return None; // Слишком высоко (стена)
+    }
+
+    let above_top = feet + forward * (obstacle_distance + LANDING_DEPTH) + Vector3::UP * (MAX_MANTLE_HEIGHT + 0.1);
+    let top = raycast_environment(space, above_top, above_top - Vector3::UP * MAX_MANTLE_HEIGHT)?;
+
+    let height = top.y - feet.y;
+    if !(MIN_MANTLE_HEIGHT..=MAX_MANTLE_HEIGHT).contains(&height) {
+        return None;
+    }
+
+    Some(top)
+}
+
+/// Raycast только по окружению (актеры не являются препятствием для mantle)
+fn raycast_environment(
+    space: &mut Gd<PhysicsDirectSpaceState3D>,
+    from: Vector3,
+    to: Vector3,
+) -> Option<Vector3> {
+    let mut query = PhysicsRayQueryParameters3D::create(from, to)?;
+    query.set_collision_mask(COLLISION_LAYER_ENVIRONMENT);
+
+    let result = space.intersect_ray(&query);
+    result.get("position")?.try_to::<Vector3>().ok()
+}
+
+/// System: MantleState → позиция тела по траектории (подъём → перенос)
+///
+/// Velocity обнуляется — после снятия MantleState гравитация стартует с нуля.
+pub fn apply_mantle_positions_main_thread(
+    query: Query<(Entity, &MantleState)>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    for (entity, mantle) in query.iter() {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+        let mut body = actor_node.clone().cast::<CharacterBody3D>();
+
+        let position = mantle.position();
+        body.set_velocity(Vector3::ZERO);
+        body.set_global_position(Vector3::new(position.x, position.y, position.z));
+    }
+}
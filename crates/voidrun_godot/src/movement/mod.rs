@@ -15,10 +15,22 @@
 //! - Для single-player достаточно простого pathfinding без obstacle avoidance
 
 pub mod commands;
+pub mod gravity_zones;
+pub mod jetpack;
+pub mod ladders;
+pub mod lean;
+pub mod mantle;
 pub mod navigation;
+pub mod stance;
 pub mod velocity;
 
 // Re-export all systems
 pub use commands::*;
+pub use gravity_zones::*;
+pub use jetpack::*;
+pub use ladders::*;
+pub use lean::*;
+pub use mantle::*;
 pub use navigation::*;
+pub use stance::*;
 pub use velocity::*;
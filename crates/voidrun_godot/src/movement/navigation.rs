@@ -26,6 +26,7 @@ fn log_every_30_frames(message: &str) {
 ///
 /// Система обрабатывает MovementCommand::FollowEntity:
 /// - Обновляет target_position в NavigationAgent3D каждый кадр (target двигается!)
+///   * Melee weapon: точка перехвата из Blackboard (где цель будет в момент удара)
 /// - Устанавливает target_desired_distance в зависимости от оружия:
 ///   * Melee weapon: attack_radius БЕЗ буфера (подходим вплотную)
 ///   * Ranged weapon: range - буфер безопасности (держим дистанцию)
@@ -39,11 +40,12 @@ pub fn update_follow_entity_targets_main_thread(
         &MovementCommand,
         &mut NavigationState,
         Option<&voidrun_simulation::combat::WeaponStats>,
+        Option<&voidrun_simulation::ai::Blackboard>,
     )>,
     visuals: NonSend<VisualRegistry>,
     scene_root: NonSend<crate::shared::SceneRoot>,
 ) {
-    for (entity, command, mut nav_state, weapon_opt) in query.iter_mut() {
+    for (entity, command, mut nav_state, weapon_opt, blackboard) in query.iter_mut() {
         let MovementCommand::FollowEntity { target } = command else {
             continue;
         };
@@ -63,7 +65,15 @@ pub fn update_follow_entity_targets_main_thread(
         };
 
         // Обновляем target position каждый кадр (target двигается!)
-        let target_pos = target_node.get_position();
+        // Melee: бежим наперерез — туда, где цель будет к моменту удара
+        let intercept = weapon_opt
+            .filter(|weapon| weapon.attack_radius > 0.0)
+            .zip(blackboard.filter(|board| board.target == Some(*target)))
+            .and_then(|(weapon, board)| board.target_intercept_point(weapon));
+        let target_pos = intercept.map_or_else(
+            || target_node.get_position(),
+            |point| Vector3::new(point.x, point.y, point.z),
+        );
         nav_agent.set_target_position(target_pos);
 
         // Дистанция остановки зависит от типа оружия:
@@ -108,22 +118,42 @@ pub fn update_follow_entity_targets_main_thread(
 /// Avoidance отключён — простой pathfinding для single-player game.
 /// ADR-005: Отправляем GodotTransformEvent::PositionChanged после move_and_slide
 ///
-/// NavigationState используется для one-time PositionChanged event (избегаем спама).
+/// NavigationState используется для one-time PositionChanged / NavigationFailed events (избегаем спама).
+/// Скорость: MOVE_SPEED × Stance × (Sprinting → Sprint::speed_multiplier) × CarryingObjective × Encumbered.
+/// В невесомости (GravityState::is_zero_g) пропускаем — дрейф ведёт apply_gravity_to_all_actors.
+/// Отброшенных (Shoved) тоже — отброс ведёт apply_gravity_to_all_actors.
 pub fn apply_navigation_velocity_main_thread(
     mut query: Query<
         (
             Entity,
-            &mut voidrun_simulation::ai::AIState,
             &mut NavigationState,
+            Option<&voidrun_simulation::movement::Stance>,
+            Option<&voidrun_simulation::movement::Sprint>,
+            Has<voidrun_simulation::movement::Sprinting>,
+            Option<&voidrun_simulation::objective::CarryingObjective>,
+            Option<&voidrun_simulation::Encumbered>,
+            Option<&voidrun_simulation::movement::GravityState>,
+            Has<voidrun_simulation::movement::Shoved>,
         ),
         With<voidrun_simulation::Actor>,
     >,
     visuals: NonSend<VisualRegistry>,
     mut transform_events: EventWriter<voidrun_simulation::ai::GodotTransformEvent>,
+    mut navigation_events: EventWriter<voidrun_simulation::ai::GodotNavigationEvent>,
 ) {
     const MOVE_SPEED: f32 = 5.0; // метры в секунду
 
-    for (entity, mut ai_state, mut nav_state) in query.iter_mut() {
+    for (entity, mut nav_state, stance, sprint, sprinting, carrying, encumbered, gravity, shoved) in query.iter_mut() {
+        // Невесомость: навмеша нет, движение — импульсы двигателей в apply_gravity_to_all_actors
+        if gravity.is_some_and(|gravity| gravity.is_zero_g()) {
+            continue;
+        }
+
+        // Отброшен (Shoved): горизонталь ведёт apply_gravity_to_all_actors
+        if shoved {
+            continue;
+        }
+
         // actor_node теперь САМ CharacterBody3D (root node из TSCN)
         let Some(actor_node) = visuals.visuals.get(&entity).cloned() else {
             continue;
@@ -143,10 +173,10 @@ pub fn apply_navigation_velocity_main_thread(
             // Нет валидного пути — стоим на месте
             nav_agent.set_velocity(Vector3::ZERO);
             body.set_velocity(Vector3::ZERO);
-            // TODO: send event чтобы AI:State сгенерировал новый MovementCommand
+            // One-time NavigationFailed → ECS решает (другая цель / обстрел с места / новая точка)
             if nav_state.can_reach_target {
                 nav_state.can_reach_target = false;
-                *ai_state = voidrun_simulation::ai::AIState::Idle;
+                navigation_events.write(voidrun_simulation::ai::GodotNavigationEvent::NavigationFailed { entity });
             }
             continue;
         }
@@ -199,12 +229,24 @@ pub fn apply_navigation_velocity_main_thread(
         }
 
         let local_direction = diff.normalized();
+        let sprint_multiplier = if sprinting {
+            sprint.copied().unwrap_or_default().speed_multiplier
+        } else {
+            1.0
+        };
+        let carry_multiplier = carrying.map_or(1.0, |carrying| carrying.speed_multiplier);
+        let encumbrance_multiplier = encumbered.map_or(1.0, |encumbered| encumbered.speed_multiplier);
+        let speed = MOVE_SPEED
+            * stance.map_or(1.0, |stance| stance.speed_multiplier())
+            * sprint_multiplier
+            * carry_multiplier
+            * encumbrance_multiplier;
 
         // Вычисляем desired_velocity в м/с (как enemy.gd line 37)
         let desired_velocity = Vector3::new(
-            local_direction.x * MOVE_SPEED,
+            local_direction.x * speed,
             0.0, // NavigationAgent работает в XZ плоскости (Y=0)
-            local_direction.z * MOVE_SPEED,
+            local_direction.z * speed,
         );
 
         // Передаём desired_velocity в AvoidanceReceiver (для debug логирования)
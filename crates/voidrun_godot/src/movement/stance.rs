@@ -0,0 +1,61 @@
+//! Stance sync — Stance (ECS) → высота CapsuleShape3D актора (Godot).
+//!
+//! Низ капсулы остаётся на месте (ноги), меняется только высота.
+//! Shape дублируется: SubResource в prefab общий для всех инстансов.
+
+use bevy::prelude::*;
+use godot::classes::{CapsuleShape3D, CollisionShape3D};
+use godot::prelude::*;
+use voidrun_simulation::movement::Stance;
+
+use crate::shared::VisualRegistry;
+
+/// Высота капсулы стоя (test_actor.tscn: CapsuleShape3D_body)
+const STANDING_CAPSULE_HEIGHT: f32 = 1.8;
+
+/// System: Stance изменился / удалён → пересчитать высоту капсулы коллизии
+pub fn sync_stance_collision_main_thread(
+    changed: Query<(Entity, &Stance), Changed<Stance>>,
+    mut removed: RemovedComponents<Stance>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    let updates = changed
+        .iter()
+        .map(|(entity, stance)| (entity, *stance))
+        .chain(removed.read().map(|entity| (entity, Stance::Standing)));
+
+    for (entity, stance) in updates {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+        let Some(mut collision) = actor_node.try_get_node_as::<CollisionShape3D>("CollisionShape3D") else {
+            continue;
+        };
+        let Some(capsule) = collision
+            .get_shape()
+            .and_then(|shape| shape.try_cast::<CapsuleShape3D>().ok())
+        else {
+            continue;
+        };
+
+        // Капсула не может быть ниже двух радиусов (сфера)
+        let radius = capsule.get_radius();
+        let height = (STANDING_CAPSULE_HEIGHT * stance.height_factor()).max(radius * 2.0);
+
+        let mut position = collision.get_position();
+        let bottom = position.y - capsule.get_height() / 2.0;
+        position.y = bottom + height / 2.0;
+
+        let mut resized = CapsuleShape3D::new_gd();
+        resized.set_radius(radius);
+        resized.set_height(height);
+
+        collision.set_shape(&resized.upcast::<godot::classes::Shape3D>());
+        collision.set_position(position);
+
+        voidrun_simulation::logger::log(&format!(
+            "🧍 {:?} stance {:?} → capsule height {:.2}m",
+            entity, stance, height
+        ));
+    }
+}
@@ -71,6 +71,8 @@ pub fn apply_retreat_velocity_main_thread(
 pub fn apply_safe_velocity_system(
     mut events: EventReader<crate::navigation::SafeVelocityComputed>,
     ai_query: Query<&voidrun_simulation::ai::AIState>,
+    gravity_query: Query<&voidrun_simulation::movement::GravityState>,
+    shoved_query: Query<&voidrun_simulation::movement::Shoved>,
     visuals: NonSend<VisualRegistry>,
     time: Res<Time>,
 ) {
@@ -85,6 +87,16 @@ pub fn apply_safe_velocity_system(
     let delta_time = time.delta_secs();
 
     for event in events.read() {
+        // Невесомость: safe_velocity (навмеш) не применяем — дрейф ведёт apply_gravity_to_all_actors
+        if gravity_query.get(event.entity).is_ok_and(|gravity| gravity.is_zero_g()) {
+            continue;
+        }
+
+        // Отброшен: горизонталь ведёт apply_gravity_to_all_actors (Shoved)
+        if shoved_query.contains(event.entity) {
+            continue;
+        }
+
         let Some(actor_node) = visuals.visuals.get(&event.entity).cloned() else {
             continue;
         };
@@ -222,10 +234,16 @@ pub fn apply_safe_velocity_system(
 /// 3. На земле: velocity.y = 0 (или JUMP_SPEED если JumpIntent)
 /// 4. В воздухе: velocity.y -= GRAVITY * delta
 /// 5. Вызываем move_and_slide() для обновления collision detection
+/// 6. Воздух → пол после move_and_slide → Landed { impact_speed } (fall damage в ECS)
 ///
 /// КРИТИЧНО:
 /// - Запускается ПЕРЕД apply_navigation_velocity (первая в цепочке)
 /// - Работает для Idle/Moving/Combat акторов (независимо от movement state)
+/// - Кроме перелезающих (MantleState): позицию ведёт apply_mantle_positions_main_thread
+/// - На лестнице (ClimbingState): без гравитации, velocity.y = ClimbingState::vertical_velocity()
+/// - Gravity zone (GravityState): GRAVITY × scale; невесомость — без пола, инерционный дрейф,
+///   NPC двигаются импульсами двигателей к цели MovementCommand (player — из input)
+/// - Jetpack (JetpackThrusting): тяга вверх поверх гравитации, подъём до Jetpack::max_rise_speed
 /// - move_and_slide() вызывается КАЖДЫЙ FRAME для КАЖДОГО актора
 ///
 /// Архитектура как в 3d-rpg:
@@ -233,8 +251,23 @@ pub fn apply_safe_velocity_system(
 /// - CharacterBody3D для deterministic movement
 /// - is_on_floor() для grounding detection
 pub fn apply_gravity_to_all_actors(
-    actor_query: Query<Entity, With<voidrun_simulation::Actor>>,
+    actor_query: Query<
+        (
+            Entity,
+            Option<&voidrun_simulation::movement::ClimbingState>,
+            Option<&voidrun_simulation::movement::GravityState>,
+            Option<&MovementCommand>,
+            Option<&voidrun_simulation::movement::Jetpack>,
+            Has<voidrun_simulation::movement::JetpackThrusting>,
+            Option<&voidrun_simulation::movement::Shoved>,
+        ),
+        (
+            With<voidrun_simulation::Actor>,
+            Without<voidrun_simulation::movement::MantleState>,
+        ),
+    >,
     mut jump_events: EventReader<voidrun_simulation::JumpIntent>,
+    mut landed_events: EventWriter<voidrun_simulation::movement::Landed>,
     visuals: NonSend<VisualRegistry>,
     time: Res<Time>,
 ) {
@@ -250,7 +283,7 @@ pub fn apply_gravity_to_all_actors(
     // Собираем entities из JumpIntent events
     let jump_entities: HashSet<Entity> = jump_events.read().map(|e| e.entity).collect();
 
-    for entity in actor_query.iter() {
+    for (entity, climbing, gravity, command, jetpack, thrusting, shoved) in actor_query.iter() {
         let Some(actor_node) = visuals.visuals.get(&entity).cloned() else {
             continue;
         };
@@ -261,7 +294,21 @@ pub fn apply_gravity_to_all_actors(
         let mut velocity = body.get_velocity();
 
         // Manual gravity (как в 3d-rpg: player.gd:68-71, enemy.gd:41-45)
-        if body.is_on_floor() {
+        let was_on_floor = body.is_on_floor();
+        let zero_g = gravity.is_some_and(|gravity| gravity.is_zero_g());
+        if let Some(climbing) = climbing {
+            // На лестнице → гравитации нет, подъём/спуск по ClimbingState
+            velocity.y = climbing.vertical_velocity();
+        } else if zero_g {
+            // Невесомость → пола нет, дрейф по инерции (+ импульс двигателей к цели команды)
+            if let Some(command) = command {
+                let position = body.get_global_position();
+                let thrust = zero_g_thrust_direction(command, position, velocity, &visuals);
+                let current = Vec3::new(velocity.x, velocity.y, velocity.z);
+                let drift = voidrun_simulation::movement::GravityState::drift_velocity(current, thrust, delta);
+                velocity = Vector3::new(drift.x, drift.y, drift.z);
+            }
+        } else if was_on_floor {
             // На земле → проверяем JumpIntent
             if jump_entities.contains(&entity) {
                 velocity.y = JUMP_SPEED; // Прыгаем!
@@ -273,8 +320,22 @@ pub fn apply_gravity_to_all_actors(
                 velocity.y = 0.0; // Стоим на земле
             }
         } else {
-            // В воздухе → применяем гравитацию
-            velocity.y -= GRAVITY * delta;
+            // В воздухе → применяем гравитацию (gravity zone масштабирует)
+            velocity.y -= GRAVITY * gravity.map_or(1.0, |gravity| gravity.scale) * delta;
+        }
+
+        // Отброс (ударная волна) → горизонталь по Shoved с затуханием (input / навигация ждут)
+        if let Some(shoved) = shoved {
+            let shove = shoved.current_velocity();
+            velocity.x = shove.x;
+            velocity.z = shove.z;
+        }
+
+        // Jetpack → тяга вверх поверх гравитации / дрейфа (на лестнице не работает)
+        if thrusting && climbing.is_none() {
+            if let Some(jetpack) = jetpack {
+                velocity.y = (velocity.y + jetpack.thrust * delta).min(jetpack.max_rise_speed);
+            }
         }
 
         // Применяем обновлённую velocity
@@ -283,5 +344,57 @@ pub fn apply_gravity_to_all_actors(
         // ✅ КРИТИЧНО: move_and_slide() каждый frame для collision detection
         // Без этого CharacterBody3D не обновляет is_on_floor() и проваливается сквозь пол
         body.move_and_slide();
+
+        // Приземление: скорость удара = вертикальная скорость ДО move_and_slide (после него y обнулён полом)
+        if climbing.is_none() && !zero_g && !was_on_floor && body.is_on_floor() && velocity.y < 0.0 {
+            landed_events.write(voidrun_simulation::movement::Landed {
+                entity,
+                impact_speed: -velocity.y,
+            });
+        }
+    }
+}
+
+/// Направление импульса двигателей в невесомости (длина ≤ 1.0)
+///
+/// - MoveToPosition / FollowEntity → к цели (вблизи — торможение)
+/// - RetreatFrom → от цели
+/// - Idle / Stop → торможение (против текущей скорости)
+fn zero_g_thrust_direction(
+    command: &MovementCommand,
+    position: Vector3,
+    velocity: Vector3,
+    visuals: &VisualRegistry,
+) -> Vec3 {
+    const ARRIVE_DISTANCE: f32 = 1.5; // метры — ближе к цели тормозим
+
+    let position = Vec3::new(position.x, position.y, position.z);
+    let brake = -Vec3::new(velocity.x, velocity.y, velocity.z);
+    let target_position = |target: &Entity| {
+        visuals.visuals.get(target).map(|node| {
+            let target = node.get_global_position();
+            Vec3::new(target.x, target.y, target.z)
+        })
+    };
+
+    let to_target = match command {
+        MovementCommand::MoveToPosition { target } => *target - position,
+        MovementCommand::FollowEntity { target } => match target_position(target) {
+            Some(target) => target - position,
+            None => return brake,
+        },
+        MovementCommand::RetreatFrom { target } => {
+            return match target_position(target) {
+                Some(target) => (position - target).normalize_or_zero(),
+                None => brake,
+            };
+        }
+        MovementCommand::Idle | MovementCommand::Stop => return brake,
+    };
+
+    if to_target.length() < ARRIVE_DISTANCE {
+        brake
+    } else {
+        to_target.normalize()
     }
 }
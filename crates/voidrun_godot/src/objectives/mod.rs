@@ -0,0 +1,89 @@
+//! Objective items — ECS ObjectiveItem → Godot визуал (ядро / флаг).
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - Added<ObjectiveItem> → светящийся куб-визуал
+//! - Каждый кадр: на земле → `ObjectiveItem::position`, у носителя → над головой носителя
+//! - Captured → queue_free
+//!
+//! Правила (подбор, сброс при смерти, захват) — в ECS (`voidrun_simulation::objective`).
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{BoxMesh, Material, Mesh, MeshInstance3D, StandardMaterial3D};
+use voidrun_simulation::objective::{ObjectiveItem, ObjectiveState};
+use voidrun_simulation::logger;
+use std::collections::HashMap;
+
+use crate::shared::{SceneRoot, VisualRegistry};
+
+/// Размер куба-визуала (метры)
+const OBJECTIVE_VISUAL_SIZE: f32 = 0.4;
+
+/// Высота визуала над носителем (метры)
+const CARRY_HEIGHT: f32 = 2.2;
+
+/// Registry: ObjectiveItem entity → Godot визуал
+///
+/// NonSend resource — main thread only (Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct ObjectiveVisualRegistry {
+    pub visuals: HashMap<Entity, Gd<MeshInstance3D>>,
+}
+
+/// System: Added<ObjectiveItem> → spawn визуал
+pub fn spawn_objective_visuals_main_thread(
+    items: Query<(Entity, &ObjectiveItem), Added<ObjectiveItem>>,
+    mut registry: NonSendMut<ObjectiveVisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+) {
+    for (entity, item) in items.iter() {
+        let mut mesh_instance = MeshInstance3D::new_alloc();
+        let mut cube = BoxMesh::new_gd();
+        cube.set_size(Vector3::splat(OBJECTIVE_VISUAL_SIZE));
+        mesh_instance.set_mesh(&cube.upcast::<Mesh>());
+
+        let mut material = StandardMaterial3D::new_gd();
+        material.set_albedo(Color::from_rgb(1.0, 0.6, 0.1));
+        material.set_feature(godot::classes::base_material_3d::Feature::EMISSION, true);
+        material.set_emission(Color::from_rgb(1.0, 0.5, 0.0));
+        mesh_instance.set_surface_override_material(0, &material.upcast::<Material>());
+
+        scene_root.node.clone().upcast::<Node>().add_child(&mesh_instance.clone().upcast::<Node>());
+        mesh_instance.set_global_position(Vector3::new(item.position.x, item.position.y, item.position.z));
+
+        registry.visuals.insert(entity, mesh_instance);
+
+        logger::log(&format!("📦 Objective visual spawned for {:?} at {:?}", entity, item.position));
+    }
+}
+
+/// System: позиция визуала (земля / над носителем), Captured → queue_free
+///
+/// Над носителем берём Godot transform (authoritative), а не StrategicPosition —
+/// иначе визуал отстаёт на тик синхронизации.
+pub fn sync_objective_visuals_main_thread(
+    items: Query<(Entity, &ObjectiveItem)>,
+    mut registry: NonSendMut<ObjectiveVisualRegistry>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    for (entity, item) in items.iter() {
+        if item.state == ObjectiveState::Captured {
+            if let Some(mut visual) = registry.visuals.remove(&entity) {
+                visual.queue_free();
+            }
+            continue;
+        }
+
+        let Some(visual) = registry.visuals.get_mut(&entity) else {
+            continue;
+        };
+
+        let carrier_node = item.carrier().and_then(|carrier| visuals.visuals.get(&carrier));
+        let position = match carrier_node {
+            Some(carrier_node) => carrier_node.get_global_position() + Vector3::UP * CARRY_HEIGHT,
+            None => Vector3::new(item.position.x, item.position.y, item.position.z),
+        };
+
+        visual.set_global_position(position);
+    }
+}
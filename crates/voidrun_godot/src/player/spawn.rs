@@ -18,7 +18,7 @@ use voidrun_simulation::*;
 /// - Player marker (отличает от NPC)
 /// - Actor (базовые характеристики)
 /// - Health, Stamina
-/// - WeaponStats (базовое melee оружие)
+/// - EquippedWeapons (меч; WeaponStats + Attachment выводит derive_weapon_stats)
 /// - Movement components (MovementCommand, NavigationState)
 /// - AI components НЕ добавляются (player controlled, не AI)
 /// - StrategicPosition (starting position)
@@ -32,8 +32,6 @@ pub fn spawn_player(
     commands: &mut Commands,
     position: Vec3,
 ) -> Entity {
-    use voidrun_simulation::combat::WeaponStats;
-
     let strategic_pos = StrategicPosition::from_world_position(position);
 
     commands
@@ -51,16 +49,14 @@ pub fn spawn_player(
                 max: 100.0,
                 regen_rate: 10.0, // 10 stamina/sec
             },
-            WeaponStats::melee_sword(), // Starting weapon (melee sword)
+            EquippedWeapons {
+                primary_large_1: Some(EquippedItem::from_instance(&ItemInstance::new("melee_sword"))), // Starting weapon
+                ..EquippedWeapons::empty()
+            },
             // НЕ добавляем MovementCommand - player управляется НАПРЯМУЮ через velocity (FPS-style)
             // НЕ добавляем NavigationState - player не использует NavigationAgent pathfinding
             // НЕ добавляем AIState, AIConfig, SpottedEnemies - это для NPC!
             // Player управляется через PlayerInputEvent → НАПРЯМУЮ CharacterBody3D velocity
-            Attachment {
-                prefab_path: "res://actors/test_sword.tscn".to_string(),
-                attachment_point: "RightHand/WeaponAttachment".to_string(),
-                attachment_type: AttachmentType::Weapon,
-            },
         ))
         .id()
 }
@@ -9,6 +9,9 @@
 //! 1. process_ads_toggle - Handle RMB toggle intent
 //! 2. update_ads_position_transition - Smooth lerp Hip↔ADS
 //! 3. player_hip_fire_aim - Dynamic raycast targeting
+//! 4. detect_aimed_at_main_thread - ADS прицел на акторе → GodotAIEvent::AimedAt
+//! 5. update_ads_fov_main_thread - FOV PlayerCamera по AimMode + прицелу оружия (AdsConfig)
+//! 6. apply_weapon_sway_main_thread - покачивание RightHand (движение / stamina, Shift в ADS → дыхание задержано)
 //!
 //! Flow:
 //! RMB → ToggleADSIntent → process_ads_toggle → update transition state
@@ -16,14 +19,23 @@
 //!                          update_ads_position_transition (lerp position)
 //!                                             ↓
 //!                          player_hip_fire_aim (if Hip Fire mode)
+//!
+//! Прицел оружия (WeaponStats.ads: AdsConfig): время вскидки → transition,
+//! зум → update_ads_fov_main_thread, чувствительность → player_mouse_look
+//!
+//! ADS → detect_aimed_at_main_thread (camera raycast → actor) → GodotAIEvent::AimedAt → ECS AimedAt
 
 use bevy::prelude::*;
 use godot::prelude::*;
-use godot::classes::Node3D;
+use godot::classes::{Camera3D, CharacterBody3D, Node3D};
 use godot::builtin::Transform3D as GodotTransform3D;
 
 use voidrun_simulation::player::Player;
-use voidrun_simulation::shooting::{AimMode, ToggleADSIntent, ease_out_cubic};
+use voidrun_simulation::shooting::{AimMode, HoldingBreath, ToggleADSIntent, WeaponSway, ease_out_cubic};
+use voidrun_simulation::movement::{Sprinting, Stance};
+use voidrun_simulation::components::Stamina;
+use voidrun_simulation::ai::GodotAIEvent;
+use voidrun_simulation::combat::{AdsConfig, WeaponStats};
 use voidrun_simulation::logger;
 use crate::shared::{VisualRegistry, SceneRoot, AttachmentRegistry, GodotDeltaTime};
 
@@ -85,27 +97,34 @@ pub fn calculate_ads_target_transform_cameraline(
     };
 
     // 3. Get camera transform (для position + rotation)
-    let camera_pivot_transform = camera_pivot.get_global_transform();
-    let camera_backward = camera_pivot_transform.basis.col_c(); // +Z = назад к камере
-    let camera_forward = -camera_pivot_transform.basis.col_c(); // -Z = вперёд от камеры
+    let camera_backward = camera_pivot.get_global_transform().basis.col_c(); // +Z = назад к камере
+
+    // 4. Offset от weapon root до SightSocket (world space)
+    let sight_offset = sight_socket.get_global_position() - weapon_node.get_global_position();
+
+    Some(ads_hand_target(camera_line_3d.get_global_position(), sight_offset, camera_backward))
+}
 
-    // 4. Вычисляем target position для RightHand
-    let camera_line_global = camera_line_3d.get_global_position();
-    let sight_socket_global = sight_socket.get_global_position();
-    let weapon_root_global = weapon_node.get_global_position();
+/// Смещение RightHand назад к камере в ADS (TUNEABLE!)
+const ADS_OFFSET_TOWARDS_CAMERA: f32 = 0.40;
 
-    // Offset от weapon root до SightSocket (world space)
-    let sight_offset = sight_socket_global - weapon_root_global;
+/// Hip fire позиция RightHand (локально в actor space)
+const HIP_FIRE_HAND_LOCAL: Vector3 = Vector3::new(-0.5, 0.0, 0.0);
 
-    // Target RightHand position
-    // Добавляем small offset назад к игроку (ближе к камере)
-    const ADS_OFFSET_TOWARDS_CAMERA: f32 = 0.40; // 15cm ближе к игроку (TUNEABLE!)
-    let target_hand_position = camera_line_global - sight_offset + camera_backward * ADS_OFFSET_TOWARDS_CAMERA;
+/// ADS math без scene tree: SightSocket совмещается с CameraLine
+///
+/// `camera_backward` — +Z камеры. Returns (позиция RightHand, точка look_at).
+pub fn ads_hand_target(camera_line: Vector3, sight_offset: Vector3, camera_backward: Vector3) -> (Vector3, Vector3) {
+    let target_hand_position = camera_line - sight_offset + camera_backward * ADS_OFFSET_TOWARDS_CAMERA;
+    let target_look_at = target_hand_position - camera_backward * 10.0;
 
-    // 5. Target rotation
-    let target_look_at = target_hand_position + camera_forward * 10.0;
+    (target_hand_position, target_look_at)
+}
 
-    Some((target_hand_position, target_look_at))
+/// Позиция руки в момент `progress` (0.0-1.0) перехода Hip↔ADS (ease-out)
+pub fn ads_transition_position(start: Vec3, target: Vector3, progress: f32) -> Vector3 {
+    let start_vec = Vector3::new(start.x, start.y, start.z);
+    start_vec.lerp(target, ease_out_cubic(progress))
 }
 
 /// Physics raycast helper (camera → world)
@@ -127,7 +146,11 @@ pub fn camera_raycast_hit_point(
     let query_params = godot::classes::PhysicsRayQueryParameters3D::create(camera_pos, target_pos)?;
     let mut query = query_params;
 
-    query.set_collision_mask(crate::shared::collision::COLLISION_MASK_RAYCAST_LOS);
+    // Прицел игрока проходит сквозь дым (дым блокирует видимость, а не пули)
+    query.set_collision_mask(
+        crate::shared::collision::COLLISION_MASK_RAYCAST_LOS
+            & !crate::shared::collision::COLLISION_LAYER_VISION_BLOCKERS,
+    );
 
     let result = space.intersect_ray(&query);
 
@@ -141,6 +164,40 @@ pub fn camera_raycast_hit_point(
     Some(position)
 }
 
+/// Дальность, на которой ADS прицел "чувствуется" целью (метры)
+pub const AIMED_AT_DETECTION_RANGE: f32 = 50.0;
+
+/// Physics raycast helper (camera → actor)
+///
+/// # Returns
+///
+/// Entity актора, в которого упёрся луч (None — промах / препятствие / не актор)
+pub fn camera_raycast_actor(
+    scene_root: &SceneRoot,
+    visuals: &VisualRegistry,
+    camera_pos: Vector3,
+    camera_forward: Vector3,
+    max_distance: f32,
+) -> Option<Entity> {
+    let mut world = scene_root.node.get_world_3d()?;
+    let mut space = world.get_direct_space_state()?;
+
+    let target_pos = camera_pos + camera_forward * max_distance;
+
+    let mut query = godot::classes::PhysicsRayQueryParameters3D::create(camera_pos, target_pos)?;
+
+    // Та же маска, что у hip fire прицела (дым не мешает целиться)
+    query.set_collision_mask(
+        crate::shared::collision::COLLISION_MASK_RAYCAST_LOS
+            & !crate::shared::collision::COLLISION_LAYER_VISION_BLOCKERS,
+    );
+
+    let result = space.intersect_ray(&query);
+    let collider = result.get("collider")?.try_to::<Gd<godot::classes::Node>>().ok()?;
+
+    visuals.node_to_entity.get(&collider.instance_id()).copied()
+}
+
 // ============================================================================
 // System 1: Process ADS Toggle (RMB Input)
 // ============================================================================
@@ -209,19 +266,23 @@ pub fn process_ads_toggle(
 /// System: Update ADS position transitions + continuous ADS positioning
 ///
 /// Runs EVERY frame for:
-/// - EnteringADS: lerp from hip → ads (0.3s)
+/// - EnteringADS: lerp from hip → ads (AdsConfig::transition_time оружия)
 /// - ADS: continuously update position (camera can rotate!)
-/// - ExitingADS: lerp from ads → hip (0.3s)
+/// - ExitingADS: lerp from ads → hip (AdsConfig::transition_time оружия)
 ///
 /// **CRITICAL:** Must run AFTER Godot animations but BEFORE other aim systems!
 pub fn update_ads_position_transition(
-    mut player_query: Query<(&mut AimMode, Entity), With<Player>>,
+    mut player_query: Query<(&mut AimMode, Entity, Option<&WeaponStats>), With<Player>>,
     visuals: NonSend<VisualRegistry>,
     attachments: NonSend<AttachmentRegistry>,
     scene_root: NonSend<SceneRoot>,
     time: Res<GodotDeltaTime>,
 ) {
-    for (mut aim_mode, entity) in player_query.iter_mut() {
+    for (mut aim_mode, entity, weapon) in player_query.iter_mut() {
+        let transition_time = weapon
+            .map_or(AimMode::TRANSITION_DURATION, |weapon| weapon.ads.transition_time)
+            .max(0.01);
+
         let Some(actor_node) = visuals.visuals.get(&entity) else {
             continue;
         };
@@ -233,7 +294,7 @@ pub fn update_ads_position_transition(
         match aim_mode.as_mut() {
             AimMode::EnteringADS { start_position, progress } => {
                 // Update progress
-                *progress += time.0 / AimMode::TRANSITION_DURATION;
+                *progress += time.0 / transition_time;
 
                 if *progress >= 1.0 {
                     // Transition complete
@@ -257,9 +318,7 @@ pub fn update_ads_position_transition(
                     };
 
                     // Smooth lerp with ease-out curve
-                    let t = ease_out_cubic(*progress);
-                    let start_vec = Vector3::new(start_position.x, start_position.y, start_position.z);
-                    let current_pos = start_vec.lerp(target_pos, t);
+                    let current_pos = ads_transition_position(*start_position, target_pos, *progress);
 
                     right_hand.set_global_position(current_pos);
                     right_hand.look_at(target_look_at); // Rotate to match camera direction
@@ -268,26 +327,22 @@ pub fn update_ads_position_transition(
 
             AimMode::ExitingADS { start_position, progress } => {
                 // Similar logic but reverse (ADS → Hip Fire)
-                *progress += time.0 / AimMode::TRANSITION_DURATION;
+                *progress += time.0 / transition_time;
 
                 if *progress >= 1.0 {
                     *aim_mode = AimMode::HipFire;
                     // Reset to local position (animation will control)
-                    right_hand.set_position(Vector3::new(-0.5, 0.0, 0.0));
+                    right_hand.set_position(HIP_FIRE_HAND_LOCAL);
                 } else {
                     // Lerp from current ADS position to hip fire base position
-                    let hip_fire_pos_local = Vector3::new(-0.5, 0.0, 0.0);
-
                     // Get actor node to convert local to global
                     let Some(actor_node) = visuals.visuals.get(&entity) else {
                         continue;
                     };
                     let actor_transform = actor_node.get_global_transform();
-                    let hip_fire_pos_global = actor_transform * hip_fire_pos_local;
+                    let hip_fire_pos_global = actor_transform * HIP_FIRE_HAND_LOCAL;
 
-                    let t = ease_out_cubic(*progress);
-                    let start_vec = Vector3::new(start_position.x, start_position.y, start_position.z);
-                    let current_pos = start_vec.lerp(hip_fire_pos_global, t);
+                    let current_pos = ads_transition_position(*start_position, hip_fire_pos_global, *progress);
 
                     right_hand.set_global_position(current_pos);
                 }
@@ -382,3 +437,206 @@ pub fn player_hip_fire_aim(
         ));
     }
 }
+
+// ============================================================================
+// System 4: Aimed-At Detection (ADS → AI)
+// ============================================================================
+
+/// System: Player в ADS навёл прицел на актора → GodotAIEvent::AimedAt
+///
+/// Flow:
+/// 1. Только полный ADS (transitions и hip fire не считаются)
+/// 2. Camera raycast (AIMED_AT_DETECTION_RANGE) → collider → entity
+/// 3. Событие каждый кадр, пока прицел на акторе (ECS держит AimedAt с LINGER)
+///
+/// Фракцию проверяет ECS (`apply_aimed_at`).
+pub fn detect_aimed_at_main_thread(
+    player_query: Query<(Entity, &AimMode), With<Player>>,
+    visuals: NonSend<VisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut ai_events: EventWriter<GodotAIEvent>,
+) {
+    for (aimer, aim_mode) in player_query.iter() {
+        if !aim_mode.is_fully_ads() {
+            continue;
+        }
+
+        let Some(camera_transform) = get_active_camera(&scene_root) else {
+            continue;
+        };
+
+        let camera_pos = camera_transform.origin;
+        let camera_forward = -camera_transform.basis.col_c(); // -Z = forward in Godot
+
+        let Some(target) = camera_raycast_actor(
+            &scene_root,
+            &visuals,
+            camera_pos,
+            camera_forward,
+            AIMED_AT_DETECTION_RANGE,
+        ) else {
+            continue;
+        };
+
+        if target == aimer {
+            continue;
+        }
+
+        ai_events.write(GodotAIEvent::AimedAt { aimer, target });
+    }
+}
+
+// ============================================================================
+// System 5: ADS FOV Zoom (PlayerCamera)
+// ============================================================================
+
+/// FOV камеры по вскидке и прицелу оружия (нет WeaponStats — FOV от бедра)
+fn player_camera_fov(aim_mode: &AimMode, weapon: Option<&WeaponStats>) -> f32 {
+    weapon.map_or(AdsConfig::HIP_FIRE_FOV, |weapon| weapon.ads.fov_at(aim_mode.ads_weight()))
+}
+
+/// System: FOV PlayerCamera следует за вскидкой (зум прицела оружия)
+///
+/// Flow:
+/// 1. AimMode::ads_weight (transitions интерполируются) → AdsConfig::fov_at
+/// 2. HIP_FIRE_FOV от бедра → `ads.fov` в ADS (оптика снайперки — сильный зум)
+///
+/// Смена оружия в ADS → FOV сразу нового прицела.
+pub fn update_ads_fov_main_thread(
+    player_query: Query<(Entity, &AimMode, Option<&WeaponStats>), With<Player>>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    for (entity, aim_mode, weapon) in player_query.iter() {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        let Some(mut camera) = actor_node.try_get_node_as::<Camera3D>("%CameraPivot/PlayerCamera") else {
+            continue;
+        };
+
+        let fov = player_camera_fov(aim_mode, weapon);
+        if (camera.get_fov() - fov).abs() > f32::EPSILON {
+            camera.set_fov(fov);
+        }
+    }
+}
+
+// ============================================================================
+// System 6: Weapon Sway (движение / stamina / задержка дыхания)
+// ============================================================================
+
+/// Скорость ходьбы игрока (м/с) — полная добавка покачивания от движения
+const SWAY_WALK_SPEED: f32 = 3.0;
+
+/// Доля скорости ходьбы по горизонтальной скорости тела (падение / прыжок не качают)
+fn sway_move_fraction(velocity: Vector3) -> f32 {
+    Vector2::new(velocity.x, velocity.z).length() / SWAY_WALK_SPEED
+}
+
+/// System: процедурное покачивание ranged оружия игрока (WeaponSway)
+///
+/// Flow:
+/// 1. ECS: Stamina / Sprinting / Stance / AimMode / HoldingBreath + скорость CharacterBody3D
+/// 2. WeaponSway::new → амплитуда и частота восьмёрки
+/// 3. RightHand доворачивается поверх прицеливания (yaw / pitch) — выстрел идёт по стволу
+///
+/// **CRITICAL:** после update_ads_position_transition / player_hip_fire_aim (они заново
+/// выставляют RightHand каждый кадр, доворот не копится). ExitingADS не трогаем —
+/// там выставляется только позиция.
+pub fn apply_weapon_sway_main_thread(
+    player_query: Query<
+        (Entity, &AimMode, &Stamina, Option<&Stance>, Has<Sprinting>, Has<HoldingBreath>, Option<&WeaponStats>),
+        With<Player>,
+    >,
+    visuals: NonSend<VisualRegistry>,
+    time: Res<GodotDeltaTime>,
+    mut phase: Local<f32>,
+) {
+    for (entity, aim_mode, stamina, stance, sprinting, holding_breath, weapon) in player_query.iter() {
+        if !weapon.is_some_and(|weapon| weapon.is_ranged()) || matches!(aim_mode, AimMode::ExitingADS { .. }) {
+            continue;
+        }
+
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        let Some(mut right_hand) = actor_node.try_get_node_as::<Node3D>("RightHand") else {
+            continue;
+        };
+
+        let move_fraction = actor_node
+            .clone()
+            .try_cast::<CharacterBody3D>()
+            .map_or(0.0, |body| sway_move_fraction(body.get_velocity()));
+
+        let sway = WeaponSway::new(
+            move_fraction,
+            sprinting,
+            stamina.current / stamina.max.max(f32::EPSILON),
+            stance.copied().unwrap_or_default(),
+            aim_mode,
+            holding_breath,
+        );
+
+        *phase = (*phase + time.0 * sway.frequency * std::f32::consts::TAU).rem_euclid(std::f32::consts::TAU);
+        let offset = sway.offset(*phase);
+
+        right_hand.rotate_object_local(Vector3::UP, offset.x.to_radians());
+        right_hand.rotate_object_local(Vector3::RIGHT, offset.y.to_radians());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ads_hand_target_aligns_sight_with_camera_line() {
+        let camera_line = Vector3::new(1.0, 1.6, 0.0);
+        let sight_offset = Vector3::new(0.0, 0.1, -0.2);
+        let backward = Vector3::new(0.0, 0.0, 1.0);
+
+        let (hand, look_at) = ads_hand_target(camera_line, sight_offset, backward);
+
+        // Прицел (hand + sight_offset) на линии камеры, смещён назад на ADS_OFFSET_TOWARDS_CAMERA
+        assert!((hand + sight_offset - camera_line - backward * ADS_OFFSET_TOWARDS_CAMERA).length() < 1e-5);
+        // Look-at — вперёд (-Z камеры)
+        assert!((look_at - hand).normalized().dot(-backward) > 0.999);
+    }
+
+    #[test]
+    fn test_ads_transition_endpoints() {
+        let start = Vec3::new(0.0, 1.0, 0.0);
+        let target = Vector3::new(2.0, 1.0, -1.0);
+
+        assert!((ads_transition_position(start, target, 0.0) - Vector3::new(0.0, 1.0, 0.0)).length() < 1e-5);
+        assert!((ads_transition_position(start, target, 1.0) - target).length() < 1e-5);
+    }
+
+    #[test]
+    fn test_ads_transition_eases_out() {
+        let start = Vec3::ZERO;
+        let target = Vector3::new(1.0, 0.0, 0.0);
+
+        // Ease-out: к середине перехода пройдено больше половины пути
+        assert!(ads_transition_position(start, target, 0.5).x > 0.5);
+    }
+
+    #[test]
+    fn test_player_camera_fov_follows_weapon_sight() {
+        let mut sniper = WeaponStats::ranged_pistol();
+        sniper.ads = AdsConfig::scope(18.0);
+
+        assert_eq!(player_camera_fov(&AimMode::HipFire, Some(&sniper)), AdsConfig::HIP_FIRE_FOV);
+        assert_eq!(player_camera_fov(&AimMode::ADS, Some(&sniper)), 18.0);
+        assert_eq!(player_camera_fov(&AimMode::ADS, None), AdsConfig::HIP_FIRE_FOV);
+    }
+
+    #[test]
+    fn test_sway_move_fraction_ignores_vertical_speed() {
+        assert_eq!(sway_move_fraction(Vector3::new(0.0, -9.0, 0.0)), 0.0);
+        assert!((sway_move_fraction(Vector3::new(SWAY_WALK_SPEED, 0.0, 0.0)) - 1.0).abs() < 1e-6);
+    }
+}
@@ -5,6 +5,7 @@
 //! This domain handles projectile lifecycle entirely within Godot:
 //! - **projectile**: GodotProjectile node (Area3D signal-based collision)
 //! - **registry**: GodotProjectileRegistry (tracking projectiles for ECS collision processing)
+//! - **thrown**: GodotThrownItem (метательное оружие по дуге) + ThrownItemRegistry
 //!
 //! # Design Rationale (ADR-005)
 //!
@@ -23,12 +24,17 @@
 //!
 //! - `projectile`: GodotProjectile node + collision structs
 //! - `registry`: GodotProjectileRegistry resource
+//! - `thrown`: GodotThrownItem node + ThrownItemRegistry resource
 
 pub mod projectile;
 pub mod registry;
+pub mod thrown;
 
 // Re-export projectile node
 pub use projectile::GodotProjectile;
 
 // Re-export registry
 pub use registry::GodotProjectileRegistry;
+
+// Re-export thrown items
+pub use thrown::{GodotThrownItem, ThrownItemRegistry};
@@ -8,21 +8,42 @@
 //! # Refactored Architecture (Area3D signal-based)
 //! - Projectile = Area3D (детектирует shields + bodies)
 //! - Signal area_entered → shield collision
-//! - Signal body_entered → actor hit
+//! - Signal body_entered → actor hit / стена (нормаль поверхности — короткий raycast)
 //! - Collision info хранится IN projectile (не в global queue)
 //! - GodotProjectileRegistry tracks all projectiles
+//!
+//! # Пробитие / рикошет
+//! - Пробиваемость преграды — meta `penetration_resistance` на body (конечности, тонкие стены);
+//!   без meta: актор ACTOR_RESISTANCE, стена WALL_RESISTANCE
+//! - Решение (пробил / рикошет / застрял) — projectile_collision_system_main_thread
+//!
+//! # Полёт
+//! - physics_process: gravity + drag из ProjectileBallistics (ProjectileBallistics::step_velocity)
+//! - distance_travelled → ProjectileHit.distance (падение урона считает ECS)
 
 use godot::prelude::*;
-use godot::classes::{Area3D, IArea3D, CharacterBody3D};
-use bevy::prelude::Entity;
+use godot::classes::{Area3D, IArea3D, CharacterBody3D, PhysicsRayQueryParameters3D};
+use bevy::prelude::{Entity, Vec3};
+use voidrun_simulation::combat::ProjectileBallistics;
 use voidrun_simulation::logger;
 
+/// Meta на body: сопротивление пробитию (перекрывает значения по умолчанию)
+pub const PENETRATION_RESISTANCE_META: &str = "penetration_resistance";
+
+/// Сопротивление тела актора по умолчанию
+pub const ACTOR_RESISTANCE: f32 = 1.0;
+
+/// Сопротивление стены по умолчанию (пробивают только тонкие стены с meta)
+pub const WALL_RESISTANCE: f32 = 3.0;
+
 /// Collision info (хранится в projectile до обработки ECS)
 #[derive(Clone, Debug)]
 pub struct ProjectileCollisionInfo {
     pub target_instance_id: InstanceId,
     pub impact_point: Vector3,
-    pub impact_normal: Vector3,  // Для VFX (spark direction, shield ripple, decals)
+    pub impact_normal: Vector3,  // Для VFX (spark direction, shield ripple, decals) + рикошета
+    /// Сопротивление пробитию преграды
+    pub resistance: f32,
 }
 
 /// Shield collision info (separate from body collision)
@@ -59,6 +80,30 @@ pub struct GodotProjectile {
 
     /// Shield collision info (separate detection via Area3D overlap)
     pub shield_collision_info: Option<ProjectileShieldCollisionInfo>,
+
+    /// Позиция на предыдущем кадре (near-miss detection: пролетела ли пуля мимо актора)
+    pub previous_position: Vector3,
+
+    /// Пробитие / рикошет (из WeaponFired)
+    pub ballistics: ProjectileBallistics,
+
+    /// Оставшаяся мощность пробития
+    pub penetration_left: f32,
+
+    /// Сколько преград пробито / рикошетов сделано
+    pub penetrations: u8,
+
+    /// Сколько рикошетов сделано (≤ ProjectileBallistics::MAX_RICOCHETS)
+    pub ricochets: u8,
+
+    /// Seed детерминированного разброса рикошета
+    pub ricochet_seed: u64,
+
+    /// Уже пробитые bodies (повторный overlap не считается новым попаданием)
+    pub passed_through: Vec<InstanceId>,
+
+    /// Пройденный путь (метры, ECS DamageFalloff)
+    pub distance_travelled: f32,
 }
 
 #[godot_api]
@@ -73,6 +118,14 @@ impl IArea3D for GodotProjectile {
             lifetime: 5.0,
             collision_info: None,
             shield_collision_info: None,
+            previous_position: Vector3::ZERO,
+            ballistics: ProjectileBallistics::none(),
+            penetration_left: 0.0,
+            penetrations: 0,
+            ricochets: 0,
+            ricochet_seed: 0,
+            passed_through: Vec::new(),
+            distance_travelled: 0.0,
         }
     }
 
@@ -85,14 +138,27 @@ impl IArea3D for GodotProjectile {
         self.base_mut().connect("body_entered", &callable_body);
     }
 
-    fn process(&mut self, delta: f64) {
-        // 1. Двигаем projectile (простое линейное движение)
-        let velocity = self.direction * self.speed * delta as f32;
+    fn physics_process(&mut self, delta: f64) {
+        let delta = delta as f32;
+
+        // 1. Баллистика: drag гасит скорость, gravity тянет вниз (направление следует за скоростью)
+        let velocity = self.direction * self.speed;
+        let stepped = self.ballistics.step_velocity(Vec3::new(velocity.x, velocity.y, velocity.z), delta);
+        let velocity = Vector3::new(stepped.x, stepped.y, stepped.z);
+        self.speed = velocity.length();
+        if self.speed > f32::EPSILON {
+            self.direction = velocity / self.speed;
+        }
+
+        // 2. Двигаем projectile
+        let step = velocity * delta;
         let current_pos = self.base().get_global_position();
-        self.base_mut().set_global_position(current_pos + velocity);
+        self.previous_position = current_pos;
+        self.distance_travelled += step.length();
+        self.base_mut().set_global_position(current_pos + step);
 
-        // 2. Уменьшаем lifetime
-        self.lifetime -= delta as f32;
+        // 3. Уменьшаем lifetime
+        self.lifetime -= delta;
 
         if self.lifetime <= 0.0 {
             // Удаляем projectile по истечению времени
@@ -117,6 +183,13 @@ impl GodotProjectile {
         ));
     }
 
+    /// Пробитие / рикошет пули (после setup)
+    pub fn set_ballistics(&mut self, ballistics: ProjectileBallistics, ricochet_seed: u64) {
+        self.ballistics = ballistics;
+        self.penetration_left = ballistics.penetration;
+        self.ricochet_seed = ricochet_seed;
+    }
+
     /// Signal handler: Area3D entered (shield collision)
     #[func]
     fn on_area_entered(&mut self, area: Gd<Area3D>) {
@@ -160,11 +233,15 @@ impl GodotProjectile {
         // НЕ удаляем projectile сразу! ECS система обработает collision и удалит позже
     }
 
-    /// Signal handler: Body entered (actor collision)
+    /// Signal handler: Body entered (actor / стена)
     #[func]
-    fn on_body_entered(&mut self, body: Gd<CharacterBody3D>) {
+    fn on_body_entered(&mut self, body: Gd<Node3D>) {
         let instance_id = body.instance_id();
 
+        if self.passed_through.contains(&instance_id) {
+            return; // Уже пробили эту преграду
+        }
+
         // Проверка self-hit через metadata (если есть)
         if body.has_meta("entity_id") {
             let entity_id_variant = body.get_meta("entity_id");
@@ -175,12 +252,27 @@ impl GodotProjectile {
             }
         }
 
+        let is_actor = body.clone().try_cast::<CharacterBody3D>().is_ok();
+        let resistance = if body.has_meta(PENETRATION_RESISTANCE_META) {
+            body.get_meta(PENETRATION_RESISTANCE_META).try_to::<f32>().unwrap_or(WALL_RESISTANCE)
+        } else if is_actor {
+            ACTOR_RESISTANCE
+        } else {
+            WALL_RESISTANCE
+        };
+
         // Store body collision info
         let impact_point = self.base().get_global_position();
+        let impact_normal = if is_actor {
+            Vector3::ZERO // Area3D не имеет normal (VFX актора не нужна)
+        } else {
+            self.surface_normal(instance_id)
+        };
         self.collision_info = Some(ProjectileCollisionInfo {
             target_instance_id: instance_id,
             impact_point,
-            impact_normal: Vector3::ZERO, // Area3D не имеет normal
+            impact_normal,
+            resistance,
         });
 
         logger::log(&format!(
@@ -190,4 +282,36 @@ impl GodotProjectile {
 
         // НЕ удаляем projectile сразу! ECS система обработает collision и удалит позже
     }
+
+    /// Нормаль поверхности стены: короткий raycast по траектории (Area3D normal не даёт)
+    fn surface_normal(&self, body_id: InstanceId) -> Vector3 {
+        let fallback = -self.direction;
+        let from = self.previous_position - self.direction * 0.5;
+        let to = self.base().get_global_position() + self.direction * 0.5;
+
+        let Some(mut world) = self.base().get_world_3d() else {
+            return fallback;
+        };
+        let Some(mut space) = world.get_direct_space_state() else {
+            return fallback;
+        };
+        let Some(mut query) = PhysicsRayQueryParameters3D::create(from, to) else {
+            return fallback;
+        };
+        query.set_collision_mask(crate::shared::collision::COLLISION_LAYER_ENVIRONMENT);
+
+        let result = space.intersect_ray(&query);
+        let hit_body = result
+            .get("collider_id")
+            .and_then(|id| id.try_to::<i64>().ok())
+            .is_some_and(|id| InstanceId::from_i64(id) == body_id);
+        if !hit_body {
+            return fallback;
+        }
+
+        result
+            .get("normal")
+            .and_then(|normal| normal.try_to::<Vector3>().ok())
+            .unwrap_or(fallback)
+    }
 }
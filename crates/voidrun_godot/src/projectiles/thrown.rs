@@ -0,0 +1,143 @@
+//! GodotThrownItem — брошенное метательное оружие (нож, копьё)
+//!
+//! Architecture (ADR-005), как у GodotProjectile:
+//! - Godot владеет полётом (баллистическая дуга) и collision (Area3D body_entered)
+//! - ECS получает MeleeHit (попадание в актора) + ThrowableLanded (предмет упал → WorldItem)
+//! - Брошенный ItemInstance едет внутри node до приземления
+
+use std::collections::HashMap;
+
+use bevy::prelude::Entity;
+use godot::classes::{Area3D, CharacterBody3D, IArea3D};
+use godot::prelude::*;
+use voidrun_simulation::item_system::ItemInstance;
+use voidrun_simulation::logger;
+
+/// Ускорение свободного падения для дуги броска (м/с²)
+pub const THROW_GRAVITY: f32 = 9.8;
+
+/// Удар брошенного предмета (хранится в node до обработки ECS системой)
+#[derive(Clone, Debug)]
+pub struct ThrownImpact {
+    /// Актор (CharacterBody3D), None — стена / пол
+    pub target_instance_id: Option<InstanceId>,
+    pub position: Vector3,
+    /// Направление полёта в момент удара (для VFX)
+    pub direction: Vector3,
+}
+
+/// Брошенный предмет — Area3D, летит по дуге до первого body
+#[derive(GodotClass)]
+#[class(base=Area3D)]
+pub struct GodotThrownItem {
+    base: Base<Area3D>,
+
+    /// Кто бросил (self-hit + attribution)
+    pub thrower: Entity,
+
+    /// Брошенный экземпляр (забирается при приземлении)
+    pub item: Option<ItemInstance>,
+
+    /// Урон при попадании в актора
+    pub damage: u32,
+
+    /// Текущая скорость (м/с, гравитация тянет вниз)
+    pub velocity: Vector3,
+
+    /// Время жизни (секунды) — истекло → падает где летел
+    pub lifetime: f32,
+
+    /// Удар (полёт окончен, ждёт ECS обработки)
+    pub impact: Option<ThrownImpact>,
+}
+
+#[godot_api]
+impl IArea3D for GodotThrownItem {
+    fn init(base: Base<Area3D>) -> Self {
+        Self {
+            base,
+            thrower: Entity::PLACEHOLDER,
+            item: None,
+            damage: 0,
+            velocity: Vector3::ZERO,
+            lifetime: 4.0,
+            impact: None,
+        }
+    }
+
+    fn ready(&mut self) {
+        let callable_body = self.base().callable("on_body_entered");
+        self.base_mut().connect("body_entered", &callable_body);
+    }
+
+    fn physics_process(&mut self, delta: f64) {
+        if self.impact.is_some() {
+            return;
+        }
+
+        let delta = delta as f32;
+        self.velocity.y -= THROW_GRAVITY * delta;
+
+        let position = self.base().get_global_position() + self.velocity * delta;
+        self.base_mut().set_global_position(position);
+
+        // Вращение в полёте (визуал ножа)
+        self.base_mut().rotate_object_local(Vector3::RIGHT, -12.0 * delta);
+
+        self.lifetime -= delta;
+        if self.lifetime <= 0.0 {
+            // Не встретил ничего (вылетел за карту) — падает где летел
+            self.impact = Some(ThrownImpact {
+                target_instance_id: None,
+                position,
+                direction: self.velocity.normalized(),
+            });
+        }
+    }
+}
+
+#[godot_api]
+impl GodotThrownItem {
+    /// Signal handler: Body entered (актор / стена / пол)
+    #[func]
+    fn on_body_entered(&mut self, body: Gd<Node3D>) {
+        if self.impact.is_some() {
+            return;
+        }
+
+        // Своё тело при вылете из руки — игнорируем
+        if body.has_meta("entity_id") {
+            if let Ok(entity_id) = body.get_meta("entity_id").try_to::<i64>() {
+                if Entity::from_raw(entity_id as u32) == self.thrower {
+                    return;
+                }
+            }
+        }
+
+        let target_instance_id = body.clone().try_cast::<CharacterBody3D>().ok().map(|actor| actor.instance_id());
+        self.impact = Some(ThrownImpact {
+            target_instance_id,
+            position: self.base().get_global_position(),
+            direction: self.velocity.normalized(),
+        });
+
+        logger::log(&format!("🗡️ Thrown item hit body: {:?} (actor: {})", body.get_name(), target_instance_id.is_some()));
+    }
+}
+
+/// Registry брошенных предметов (NonSend, main thread only)
+#[derive(Default)]
+pub struct ThrownItemRegistry {
+    pub items: HashMap<InstanceId, Gd<GodotThrownItem>>,
+}
+
+impl ThrownItemRegistry {
+    pub fn register(&mut self, item: Gd<GodotThrownItem>) {
+        self.items.insert(item.instance_id(), item);
+    }
+
+    /// Убрать queue_free()'d nodes
+    pub fn cleanup_destroyed(&mut self) {
+        self.items.retain(|_, item| item.is_instance_valid());
+    }
+}
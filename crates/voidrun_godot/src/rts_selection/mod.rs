@@ -0,0 +1,267 @@
+//! RTS selection — выделение юнитов и приказы в RTS камере.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - [LMB] клик: луч из курсора → дружественный NPC под курсором (Shift — добавить / снять)
+//! - [LMB] рамка: дружественные NPC, чья позиция на экране внутри рамки (Shift — добавить)
+//! - [RMB]: враг под курсором → Attack, иначе точка попадания → MoveTo (`UnitOrderIntent`)
+//!   строем: клин, [Alt] — шеренга, [Shift] — колонна ([Ctrl] занят стойкой)
+//! - Выделенные — кольцо под ногами (`sync_selection_rings_main_thread`)
+//!
+//! Мышь читается напрямую (Input singleton): PlayerInputEvent несёт FPS-действия.
+//! Фильтр фракции, приказы → Companion → AIState / MovementCommand — в ECS (`voidrun_simulation::companions`).
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::base_material_3d::ShadingMode;
+use godot::classes::{
+    Camera3D, Input, Material, Mesh, MeshInstance3D, PhysicsRayQueryParameters3D, StandardMaterial3D, TorusMesh,
+};
+use godot::global::{Key, MouseButton};
+use voidrun_simulation::camera::{ActiveCamera, CameraMode};
+use voidrun_simulation::combat::Dead;
+use voidrun_simulation::companions::{Selected, UnitOrder, UnitOrderIntent};
+use voidrun_simulation::formation::FormationShape;
+use voidrun_simulation::player::Player;
+use voidrun_simulation::Actor;
+
+use crate::shared::{SceneRoot, VisualRegistry};
+use crate::shared::collision::{COLLISION_LAYER_ACTORS, COLLISION_LAYER_ENVIRONMENT};
+
+/// Дальность луча из курсора (метры)
+const CURSOR_RAY_RANGE: f32 = 500.0;
+
+/// Рамка меньше — считается кликом (пиксели)
+const BOX_SELECT_MIN_SIZE: f32 = 6.0;
+
+/// Имя кольца выделения под актором
+const SELECTION_RING_NAME: &str = "SelectionRing";
+
+/// Состояние мыши между кадрами (фронты кнопок + начало рамки)
+#[derive(Default)]
+pub struct RtsMouseState {
+    left_held: bool,
+    right_held: bool,
+    drag_start: Option<Vec2>,
+}
+
+/// Попадание луча из курсора
+struct CursorHit {
+    /// Актор под курсором (None — пол / стены)
+    entity: Option<Entity>,
+    position: Vector3,
+}
+
+/// System: мышь в RTS камере → Selected (LMB клик / рамка) + UnitOrderIntent (RMB)
+///
+/// Вне RTS режима состояние сбрасывается (рамка не тянется через переключение камеры).
+#[allow(clippy::too_many_arguments)]
+pub fn rts_selection_input_main_thread(
+    player: Query<(Entity, &Actor, &ActiveCamera), With<Player>>,
+    actors: Query<&Actor, (Without<Player>, Without<Dead>)>,
+    selected: Query<Entity, With<Selected>>,
+    visuals: NonSend<VisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut order_intents: EventWriter<UnitOrderIntent>,
+    mut mouse: Local<RtsMouseState>,
+    mut commands: Commands,
+) {
+    let rts_player = player
+        .single()
+        .ok()
+        .filter(|(_, _, camera)| camera.mode == CameraMode::RTS);
+    let Some((player_entity, player_actor, _)) = rts_player else {
+        *mouse = RtsMouseState::default();
+        return;
+    };
+    let Some(viewport) = scene_root.node.get_viewport() else {
+        return;
+    };
+    let Some(camera) = viewport.get_camera_3d() else {
+        return;
+    };
+
+    let input = Input::singleton();
+    let left = input.is_mouse_button_pressed(MouseButton::LEFT);
+    let right = input.is_mouse_button_pressed(MouseButton::RIGHT);
+    let additive = input.is_key_pressed(Key::SHIFT);
+    let cursor = viewport.get_mouse_position();
+    let cursor_pos = Vec2::new(cursor.x, cursor.y);
+
+    let is_friendly = |entity: Entity| {
+        actors
+            .get(entity)
+            .is_ok_and(|actor| actor.faction_id == player_actor.faction_id)
+    };
+
+    // LMB нажата → начало рамки; отпущена → клик или рамка
+    if left && !mouse.left_held {
+        mouse.drag_start = Some(cursor_pos);
+    }
+    if !left && mouse.left_held {
+        if let Some(start) = mouse.drag_start.take() {
+            let is_click = start.distance(cursor_pos) < BOX_SELECT_MIN_SIZE;
+            let picked: Vec<Entity> = if is_click {
+                raycast_cursor(&camera, cursor, &visuals, &scene_root)
+                    .and_then(|hit| hit.entity)
+                    .filter(|entity| is_friendly(*entity))
+                    .into_iter()
+                    .collect()
+            } else {
+                units_in_screen_rect(&camera, start.min(cursor_pos), start.max(cursor_pos), &visuals)
+                    .filter(|entity| is_friendly(*entity))
+                    .collect()
+            };
+
+            if !additive {
+                for entity in selected.iter().filter(|entity| !picked.contains(entity)) {
+                    commands.entity(entity).remove::<Selected>();
+                }
+            }
+            for entity in picked {
+                let already_selected = selected.contains(entity);
+                if additive && is_click && already_selected {
+                    commands.entity(entity).remove::<Selected>();
+                } else if !already_selected {
+                    commands.entity(entity).insert(Selected);
+                }
+            }
+        }
+    }
+
+    // RMB нажата → приказ выделенным
+    if right && !mouse.right_held {
+        let units: Vec<Entity> = selected.iter().collect();
+        let hit = (!units.is_empty())
+            .then(|| raycast_cursor(&camera, cursor, &visuals, &scene_root))
+            .flatten();
+        if let Some(hit) = hit {
+            let enemy = hit
+                .entity
+                .filter(|entity| actors.get(*entity).is_ok_and(|actor| actor.faction_id != player_actor.faction_id));
+            let order = match enemy {
+                Some(target) => UnitOrder::Attack { target },
+                None => UnitOrder::MoveTo {
+                    position: Vec3::new(hit.position.x, hit.position.y, hit.position.z),
+                    formation: order_formation(&input),
+                },
+            };
+            order_intents.write(UnitOrderIntent {
+                issuer: player_entity,
+                units,
+                order,
+            });
+        }
+    }
+
+    mouse.left_held = left;
+    mouse.right_held = right;
+}
+
+/// System: Added<Selected> → кольцо под актором, снятие выделения → кольцо удаляется
+pub fn sync_selection_rings_main_thread(
+    added: Query<Entity, Added<Selected>>,
+    still_selected: Query<(), With<Selected>>,
+    mut removed: RemovedComponents<Selected>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    for entity in removed.read() {
+        if still_selected.contains(entity) {
+            continue;
+        }
+        let Some(node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+        if let Some(mut ring) = node.try_get_node_as::<Node>(SELECTION_RING_NAME) {
+            ring.queue_free();
+        }
+    }
+
+    for entity in added.iter() {
+        let Some(node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+        if node.try_get_node_as::<Node>(SELECTION_RING_NAME).is_some() {
+            continue;
+        }
+        node.clone().add_child(&create_selection_ring());
+    }
+}
+
+/// Строй приказа движения по модификатору: [Alt] шеренга, [Shift] колонна, иначе клин
+fn order_formation(input: &Gd<Input>) -> FormationShape {
+    if input.is_key_pressed(Key::ALT) {
+        FormationShape::Line
+    } else if input.is_key_pressed(Key::SHIFT) {
+        FormationShape::Column
+    } else {
+        FormationShape::Wedge
+    }
+}
+
+/// Луч из курсора (actors + environment) → актор (через VisualRegistry) и точка попадания
+fn raycast_cursor(
+    camera: &Gd<Camera3D>,
+    cursor: Vector2,
+    visuals: &VisualRegistry,
+    scene_root: &SceneRoot,
+) -> Option<CursorHit> {
+    let from = camera.project_ray_origin(cursor);
+    let to = from + camera.project_ray_normal(cursor) * CURSOR_RAY_RANGE;
+
+    let mut space = scene_root.node.get_world_3d()?.get_direct_space_state()?;
+    let mut query = PhysicsRayQueryParameters3D::create(from, to)?;
+    query.set_collision_mask(COLLISION_LAYER_ACTORS | COLLISION_LAYER_ENVIRONMENT);
+
+    let result = space.intersect_ray(&query);
+    let position = result.get("position")?.try_to::<Vector3>().ok()?;
+
+    // Коллайдер → ближайший предок из VisualRegistry
+    let mut current = result.get("collider").and_then(|collider| collider.try_to::<Gd<Node>>().ok());
+    while let Some(node) = current {
+        if let Some(&entity) = visuals.node_to_entity.get(&node.instance_id()) {
+            return Some(CursorHit {
+                entity: Some(entity),
+                position,
+            });
+        }
+        current = node.get_parent();
+    }
+    Some(CursorHit { entity: None, position })
+}
+
+/// Акторы, чья позиция на экране внутри рамки (за камерой — нет)
+fn units_in_screen_rect<'a>(
+    camera: &'a Gd<Camera3D>,
+    min: Vec2,
+    max: Vec2,
+    visuals: &'a VisualRegistry,
+) -> impl Iterator<Item = Entity> + 'a {
+    visuals.visuals.iter().filter_map(move |(entity, node)| {
+        let world = node.get_global_position();
+        if camera.is_position_behind(world) {
+            return None;
+        }
+        let screen = camera.unproject_position(world);
+        let screen = Vec2::new(screen.x, screen.y);
+        (screen.cmpge(min).all() && screen.cmple(max).all()).then_some(*entity)
+    })
+}
+
+/// Кольцо выделения (зелёный тор у ног, без освещения)
+fn create_selection_ring() -> Gd<MeshInstance3D> {
+    let mut ring = MeshInstance3D::new_alloc();
+    ring.set_name(SELECTION_RING_NAME);
+
+    let mut torus = TorusMesh::new_gd();
+    torus.set_inner_radius(0.45);
+    torus.set_outer_radius(0.55);
+    ring.set_mesh(&torus.upcast::<Mesh>());
+
+    let mut material = StandardMaterial3D::new_gd();
+    material.set_albedo(Color::from_rgb(0.3, 1.0, 0.4));
+    material.set_shading_mode(ShadingMode::UNSHADED);
+    ring.set_surface_override_material(0, &material.upcast::<Material>());
+
+    ring.set_position(Vector3::new(0.0, 0.05, 0.0));
+    ring
+}
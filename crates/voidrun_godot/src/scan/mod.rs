@@ -0,0 +1,81 @@
+//! Scan input — удержание [T] на акторе → ECS ScanIntent.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - [T] зажата: raycast камеры (actors + environment) → актор под прицелом → ScanIntent
+//!   (пока сканируем — повторно не шлём)
+//! - [T] отпущена во время скана → CancelScanIntent
+//!
+//! Канал, дальность, кэш отчётов — в ECS (`voidrun_simulation::scan`), HUD — `ui::scan_panel`.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::PhysicsRayQueryParameters3D;
+use voidrun_simulation::player::Player;
+use voidrun_simulation::scan::{CancelScanIntent, ScanIntent, Scanning};
+
+use crate::input::PlayerInputEvent;
+use crate::shared::{SceneRoot, VisualRegistry};
+use crate::shared::collision::{COLLISION_LAYER_ACTORS, COLLISION_LAYER_ENVIRONMENT};
+
+/// System: [T] → ScanIntent / CancelScanIntent
+pub fn player_scan_input_main_thread(
+    mut input_events: EventReader<PlayerInputEvent>,
+    player: Query<(Entity, Option<&Scanning>), With<Player>>,
+    visuals: NonSend<VisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut scan_intents: EventWriter<ScanIntent>,
+    mut cancel_intents: EventWriter<CancelScanIntent>,
+) {
+    // Последнее состояние клавиши за кадр
+    let Some(held) = input_events.read().last().map(|input| input.scan) else {
+        return;
+    };
+    let Ok((player_entity, scanning)) = player.single() else {
+        return;
+    };
+
+    match (held, scanning) {
+        (true, None) => {
+            let Some(target) = raycast_actor(&visuals, &scene_root, Scanning::RANGE) else {
+                return;
+            };
+            if target == player_entity {
+                return;
+            }
+            scan_intents.write(ScanIntent {
+                actor: player_entity,
+                target,
+            });
+        }
+        (false, Some(_)) => {
+            cancel_intents.write(CancelScanIntent { actor: player_entity });
+        }
+        _ => {}
+    }
+}
+
+/// Актор под прицелом в пределах `range` (стены закрывают)
+pub(crate) fn raycast_actor(visuals: &VisualRegistry, scene_root: &SceneRoot, range: f32) -> Option<Entity> {
+    let camera = scene_root.node.get_viewport()?.get_camera_3d()?;
+    let transform = camera.get_global_transform();
+    let from = transform.origin;
+    let to = from - transform.basis.col_c() * range;
+
+    let mut space = scene_root.node.get_world_3d()?.get_direct_space_state()?;
+    let mut query = PhysicsRayQueryParameters3D::create(from, to)?;
+    // Камера внутри капсулы игрока — hit_from_inside выключен по умолчанию
+    query.set_collision_mask(COLLISION_LAYER_ACTORS | COLLISION_LAYER_ENVIRONMENT);
+
+    let result = space.intersect_ray(&query);
+    let collider = result.get("collider")?.try_to::<Gd<Node>>().ok()?;
+
+    // Коллайдер → ближайший предок из VisualRegistry
+    let mut current = Some(collider);
+    while let Some(node) = current {
+        if let Some(&entity) = visuals.node_to_entity.get(&node.instance_id()) {
+            return Some(entity);
+        }
+        current = node.get_parent();
+    }
+    None
+}
@@ -11,6 +11,7 @@
 //! - Layer 2 (0b10 = 2): Actors (CharacterBody3D)
 //! - Layer 3 (0b100 = 4): Environment (StaticBody3D obstacles/walls)
 //! - Layer 4 (0b1000 = 8): Projectiles (CharacterBody3D bullets)
+//! - Layer 7 (0b1000000 = 64): Vision blockers (smoke volumes — только LOS)
 //!
 //! ## Использование:
 //! ```rust
@@ -47,6 +48,9 @@ pub const COLLISION_LAYER_SHIELDS: u32 = 0b10000; // 16
 /// Layer 6: Corpses (dead actors — лежат на земле, не блокируют живых)
 pub const COLLISION_LAYER_CORPSES: u32 = 0b100000; // 32
 
+/// Layer 7: Vision blockers (StaticBody3D — дым; блокируют только LOS raycasts)
+pub const COLLISION_LAYER_VISION_BLOCKERS: u32 = 0b1000000; // 64
+
 // ============================================================================
 // Mask Битовые Маски (с чем объект коллидирует)
 // ============================================================================
@@ -62,10 +66,12 @@ pub const COLLISION_MASK_ACTORS: u32 = COLLISION_LAYER_ACTORS | COLLISION_LAYER_
 /// НЕ коллидируют с другими projectiles (слой 4 отсутствует в маске).
 pub const COLLISION_MASK_PROJECTILES: u32 = COLLISION_LAYER_ACTORS | COLLISION_LAYER_ENVIRONMENT | COLLISION_LAYER_SHIELDS;
 
-/// Mask: Raycast для LOS check (Actors + Environment)
+/// Mask: Raycast для LOS check (Actors + Environment + Vision blockers)
 ///
 /// Используется для line-of-sight проверок (AI, weapons).
-pub const COLLISION_MASK_RAYCAST_LOS: u32 = COLLISION_LAYER_ACTORS | COLLISION_LAYER_ENVIRONMENT;
+/// Дым (vision blockers) закрывает LOS, но не движение/пули.
+pub const COLLISION_MASK_RAYCAST_LOS: u32 =
+    COLLISION_LAYER_ACTORS | COLLISION_LAYER_ENVIRONMENT | COLLISION_LAYER_VISION_BLOCKERS;
 
 /// Mask: Shields DON'T collide actively (passive collision)
 ///
@@ -80,6 +86,11 @@ pub const COLLISION_MASK_SHIELDS: u32 = 0;
 /// НЕ коллидируют с: Actors (layer 2), Projectiles (layer 4), другими Corpses.
 pub const COLLISION_MASK_CORPSES: u32 = COLLISION_LAYER_ENVIRONMENT;
 
+/// Mask: Vision blockers не коллидируют активно (пассивный occluder для raycasts)
+///
+/// Actors/Projectiles не включают слой 7 в маску → проходят сквозь дым.
+pub const COLLISION_MASK_VISION_BLOCKERS: u32 = 0;
+
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -92,6 +103,7 @@ pub fn get_layer_name(layer_bits: u32) -> &'static str {
         COLLISION_LAYER_PROJECTILES => "Projectiles",
         COLLISION_LAYER_SHIELDS => "Shields",
         COLLISION_LAYER_CORPSES => "Corpses",
+        COLLISION_LAYER_VISION_BLOCKERS => "VisionBlockers",
         _ => "Unknown",
     }
 }
@@ -0,0 +1,41 @@
+//! Fallible Godot lookups — node / prefab / визуал → `Result<_, BridgeError>`
+//!
+//! Вместо молчаливого `continue` система получает ошибку и пишет
+//! `ExpectationFailed` (см. `voidrun_simulation::shared::diagnostics`) — промах сетапа
+//! виден в debug overlay. Для node'ов, которых может не быть по дизайну (щит только
+//! у части акторов), остаётся `try_get_node_as`.
+
+use bevy::prelude::*;
+use godot::classes::{Node3D, PackedScene, ResourceLoader};
+use godot::prelude::*;
+use voidrun_simulation::BridgeError;
+
+use crate::shared::VisualRegistry;
+
+/// Node по пути (поддерживает `%UniqueName`) нужного типа
+pub fn require_node<T>(root: &Gd<Node3D>, path: &str) -> Result<Gd<T>, BridgeError>
+where
+    T: GodotClass + Inherits<Node>,
+{
+    let Some(node) = root.get_node_or_null(path) else {
+        return Err(BridgeError::NodeMissing { path: path.to_string() });
+    };
+
+    node.try_cast::<T>().map_err(|_| BridgeError::NodeTypeMismatch {
+        path: path.to_string(),
+        expected: T::class_name().to_string(),
+    })
+}
+
+/// Главный visual node entity
+pub fn require_visual(visuals: &VisualRegistry, entity: Entity) -> Result<Gd<Node3D>, BridgeError> {
+    visuals.visuals.get(&entity).cloned().ok_or(BridgeError::VisualMissing)
+}
+
+/// PackedScene по resource path
+pub fn load_prefab(path: &str) -> Result<Gd<PackedScene>, BridgeError> {
+    ResourceLoader::singleton()
+        .load(path)
+        .and_then(|resource| resource.try_cast::<PackedScene>().ok())
+        .ok_or_else(|| BridgeError::PrefabLoadFailed { path: path.to_string() })
+}
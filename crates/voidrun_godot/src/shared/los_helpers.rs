@@ -11,6 +11,24 @@ use voidrun_simulation::logger;
 
 use crate::shared::VisualRegistry;
 
+/// Высота глаз над началом координат актора (test_actor.tscn: Head y = 0.8)
+pub const EYE_HEIGHT: f32 = 0.8;
+
+/// Meta актора: боковой сдвиг глаз при наклоне (м, + вправо; пишет `sync_lean_visuals_main_thread`)
+pub const LEAN_OFFSET_META: &str = "lean_offset";
+
+/// Позиция глаз актора (world): голова + сдвиг наклона (Q/E из-за укрытия)
+pub fn eye_position(node: &Gd<Node3D>) -> Vector3 {
+    let transform = node.get_global_transform();
+    let lean_offset = if node.has_meta(LEAN_OFFSET_META) {
+        node.get_meta(LEAN_OFFSET_META).try_to::<f32>().unwrap_or(0.0)
+    } else {
+        0.0
+    };
+
+    transform.origin + Vector3::UP * EYE_HEIGHT + transform.basis.col_a().normalized() * lean_offset
+}
+
 /// Check line-of-sight between two entities using Godot physics raycast.
 ///
 /// Returns:
@@ -55,9 +73,9 @@ pub fn check_line_of_sight(
         return None;
     };
 
-    // 2. Get positions (eye-level: Y+0.8 для головы)
-    let from_pos = from_node.get_global_position() + Vector3::new(0.0, 0.8, 0.0);
-    let to_pos = to_node.get_global_position() + Vector3::new(0.0, 0.8, 0.0);
+    // 2. Get positions (eye-level, со сдвигом наклона — выглянувший из-за угла видит и виден)
+    let from_pos = eye_position(from_node_3d);
+    let to_pos = eye_position(to_node_3d);
 
     // 3. Raycast через PhysicsDirectSpaceState3D
     let world = scene_root.node.get_world_3d();
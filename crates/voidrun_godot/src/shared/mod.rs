@@ -20,6 +20,8 @@
 //! - `actor_utils`: Actor spatial utilities (mutual facing, angles, distance)
 //! - `los_helpers`: Line-of-sight raycast helpers
 //! - `collision`: Collision layer/mask constants
+//! - `lookup`: Fallible node / prefab lookups (`BridgeError` → `ExpectationFailed`)
+//! - `prefab_contract`: Required prefab nodes per archetype capabilities (validated at instantiate)
 
 use bevy::prelude::*;
 use godot::prelude::*;
@@ -28,6 +30,8 @@ use std::collections::HashMap;
 pub mod actor_utils;
 pub mod los_helpers;
 pub mod collision;
+pub mod lookup;
+pub mod prefab_contract;
 
 /// Registry: маппинг Entity ↔ Godot visual components
 ///
@@ -52,6 +56,9 @@ pub struct VisualRegistry {
 
     /// Shield energy labels (только для entities с EnergyShield)
     pub shield_labels: HashMap<Entity, Gd<godot::classes::Label3D>>,
+
+    /// Nameplates (имя / фракция над головой, видимость — NameplateSettings)
+    pub nameplates: HashMap<Entity, Gd<godot::classes::Label3D>>,
 }
 
 /// Registry: маппинг (Entity, attachment_point) → Godot Node3D (attached prefabs)
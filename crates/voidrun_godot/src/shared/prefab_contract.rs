@@ -0,0 +1,124 @@
+//! Prefab contract — проверка обязательных node'ов prefab'а при instantiate
+//!
+//! Archetype заявляет возможности через ECS компоненты (Player → камера, AI → зрение,
+//! EnergyShield → щит, WeaponStats → оружие в руке). Prefab обязан иметь node'ы, которые
+//! ищут системы этих возможностей. Все промахи — одним отчётом при spawn, а не
+//! «node not found» по одному в рантайме.
+
+use godot::classes::Node3D;
+use godot::prelude::*;
+use voidrun_simulation::logger;
+
+/// Как система ищет node
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeLookup {
+    /// `get_node` по пути (`%Unique` / `Child/Path`)
+    Path(&'static str),
+    /// Рекурсивный поиск по имени среди потомков
+    Descendant(&'static str),
+}
+
+/// Обязательный node + зачем он нужен
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeRequirement {
+    pub lookup: NodeLookup,
+    pub reason: &'static str,
+}
+
+/// Возможности actor archetype'а (из ECS компонентов)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ActorCapabilities {
+    /// Игрок — FPS камера
+    pub first_person: bool,
+    /// AI / VisionConfig — конус зрения
+    pub vision: bool,
+    /// EnergyShield — сфера щита
+    pub shield: bool,
+    /// WeaponStats — оружие в правой руке
+    pub armed: bool,
+}
+
+/// Обязательные node'ы actor prefab'а
+pub fn actor_requirements(capabilities: ActorCapabilities) -> Vec<NodeRequirement> {
+    let mut requirements = Vec::new();
+
+    if capabilities.first_person {
+        requirements.push(NodeRequirement { lookup: NodeLookup::Path("%CameraPivot"), reason: "first-person camera" });
+    }
+    if capabilities.armed {
+        requirements.push(NodeRequirement {
+            lookup: NodeLookup::Path("%RightHandAttachment"),
+            reason: "weapon socket",
+        });
+    }
+    if capabilities.vision {
+        requirements.push(NodeRequirement { lookup: NodeLookup::Descendant("VisionCone"), reason: "AI vision" });
+    }
+    if capabilities.shield {
+        requirements.push(NodeRequirement { lookup: NodeLookup::Path("ShieldSphere"), reason: "energy shield" });
+    }
+
+    requirements
+}
+
+/// Обязательные node'ы weapon prefab'а
+pub fn weapon_requirements(ranged: bool) -> Vec<NodeRequirement> {
+    if ranged {
+        vec![NodeRequirement { lookup: NodeLookup::Path("%BulletSpawn"), reason: "projectile spawn" }]
+    } else {
+        vec![NodeRequirement { lookup: NodeLookup::Path("WeaponPlacement/Hitbox"), reason: "melee hitbox" }]
+    }
+}
+
+/// Проверить prefab. Промахи → один сводный отчёт в лог; true — контракт выполнен
+pub fn validate_prefab(root: &Gd<Node3D>, prefab_path: &str, requirements: &[NodeRequirement]) -> bool {
+    let missing: Vec<String> = requirements
+        .iter()
+        .filter(|requirement| !has_node(root, requirement.lookup))
+        .map(|requirement| match requirement.lookup {
+            NodeLookup::Path(path) => format!("  - '{}' ({})", path, requirement.reason),
+            NodeLookup::Descendant(name) => format!("  - '{}' anywhere ({})", name, requirement.reason),
+        })
+        .collect();
+
+    if missing.is_empty() {
+        return true;
+    }
+
+    logger::log_error(&format!(
+        "❌ Prefab contract violated: '{}' is missing {} node(s):\n{}",
+        prefab_path,
+        missing.len(),
+        missing.join("\n")
+    ));
+    false
+}
+
+fn has_node(root: &Gd<Node3D>, lookup: NodeLookup) -> bool {
+    match lookup {
+        NodeLookup::Path(path) => root.get_node_or_null(path).is_some(),
+        NodeLookup::Descendant(name) => root.find_child_ex(name).owned(false).done().is_some(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_requirements_follow_capabilities() {
+        let npc = actor_requirements(ActorCapabilities { vision: true, armed: true, ..Default::default() });
+        assert_eq!(npc.len(), 2);
+        assert!(npc.iter().all(|requirement| requirement.lookup != NodeLookup::Path("%CameraPivot")));
+
+        let player = actor_requirements(ActorCapabilities { first_person: true, shield: true, ..Default::default() });
+        assert!(player.contains(&NodeRequirement { lookup: NodeLookup::Path("%CameraPivot"), reason: "first-person camera" }));
+        assert!(player.iter().any(|requirement| requirement.lookup == NodeLookup::Path("ShieldSphere")));
+    }
+
+    #[test]
+    fn test_weapon_requirements_by_type() {
+        assert_eq!(weapon_requirements(true)[0].lookup, NodeLookup::Path("%BulletSpawn"));
+        assert_eq!(weapon_requirements(false)[0].lookup, NodeLookup::Path("WeaponPlacement/Hitbox"));
+    }
+}
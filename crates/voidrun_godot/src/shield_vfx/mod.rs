@@ -3,20 +3,24 @@
 //! Обновляет shader uniforms для ShieldMesh на основе EnergyShield состояния.
 //!
 //! # Systems
-//! - `update_shield_energy_vfx_main_thread()` — обновляет `energy_percent` uniform
+//! - `update_shield_energy_vfx_main_thread()` — обновляет `energy_percent` и `arc_cos` (направленный щит) uniforms
 //! - `update_shield_ripple_vfx_main_thread()` — обновляет `last_hit_pos` и `last_hit_time` uniforms
+//! - `update_shield_emp_vfx_main_thread()` — обновляет `emp_disrupted` uniform (EMP выбил щит)
 //!
 //! # Architecture
 //! - Runs in MainThreadUpdate (Godot API calls)
 //! - Query: `Changed<EnergyShield>` (reactive — только когда энергия меняется)
 //! - Events: `ProjectileShieldHit` (для ripple VFX)
-//! - Uniforms: `energy_percent`, `last_hit_pos`, `last_hit_time`
+//! - Query: `Added<EmpDisrupted>` / `RemovedComponents<EmpDisrupted>` (EMP помехи)
+//! - Uniforms: `energy_percent`, `arc_cos`, `last_hit_pos`, `last_hit_time`, `emp_disrupted`
+//! - Щит снят (генератор брони) → ShieldSphere скрыт, collision выключен
 
 use bevy::prelude::*;
 use godot::prelude::*;
 use godot::classes::{MeshInstance3D, ShaderMaterial, StaticBody3D};
 use voidrun_simulation::logger;
 
+use voidrun_simulation::combat::EmpDisrupted;
 use voidrun_simulation::shared::equipment::EnergyShield;
 use crate::shared::VisualRegistry;
 use crate::shared::collision::COLLISION_LAYER_SHIELDS;
@@ -63,6 +67,13 @@ pub fn update_shield_energy_vfx_main_thread(
 
         let mut shader_mat = material.cast::<ShaderMaterial>();
 
+        // Покрытие (сфера / дуга) меняется только со сменой генератора
+        let arc_cos = shield.coverage.arc_cos();
+        let current_arc_cos = shader_mat.get_shader_parameter("arc_cos").try_to::<f32>().unwrap_or(-1.0);
+        if (arc_cos - current_arc_cos).abs() > f32::EPSILON {
+            shader_mat.set_shader_parameter("arc_cos", &Variant::from(arc_cos));
+        }
+
         // Calculate NEW energy_percent (0.0-1.0)
         let new_energy_percent = (shield.current_energy / shield.max_energy).clamp(0.0, 1.0);
 
@@ -96,6 +107,8 @@ pub fn update_shield_energy_vfx_main_thread(
 /// Включает/выключает collision layer щита на основе `is_active` состояния.
 /// - Active shield (is_active = true): collision_layer = 16 (SHIELDS)
 /// - Inactive shield (is_active = false): collision_layer = 0 (no collision)
+/// - EnergyShield снят (генератор брони) → ShieldSphere скрыт + collision_layer = 0;
+///   надет снова → виден
 ///
 /// # Flow
 /// 1. Query entities с Changed<EnergyShield>
@@ -105,9 +118,23 @@ pub fn update_shield_energy_vfx_main_thread(
 /// # Runs
 /// MainThreadUpdate (Godot API access)
 pub fn update_shield_collision_state_main_thread(
-    shields: Query<(Entity, &EnergyShield), Changed<EnergyShield>>,
+    shields: Query<(Entity, Ref<EnergyShield>), Changed<EnergyShield>>,
+    mut removed: RemovedComponents<EnergyShield>,
     visuals: NonSend<VisualRegistry>,
 ) {
+    for entity in removed.read() {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+        let Some(mut shield_sphere) = actor_node.try_get_node_as::<StaticBody3D>("ShieldSphere") else {
+            continue;
+        };
+
+        shield_sphere.set_collision_layer(0);
+        shield_sphere.set_visible(false);
+        logger::log(&format!("🛡️ Shield removed: entity={:?} (ShieldSphere hidden)", entity));
+    }
+
     for (entity, shield) in shields.iter() {
         let Some(actor_node) = visuals.visuals.get(&entity) else {
             continue;
@@ -126,6 +153,9 @@ pub fn update_shield_collision_state_main_thread(
         };
 
         shield_sphere.set_collision_layer(collision_layer);
+        if shield.is_added() {
+            shield_sphere.set_visible(true);
+        }
 
         logger::log(&format!(
             "🛡️ Shield collision state updated: entity={:?}, is_active={}, collision_layer={}",
@@ -201,6 +231,43 @@ pub fn update_shield_ripple_vfx_main_thread(
     }
 }
 
+/// System: EMP disabled state → `emp_disrupted` uniform
+///
+/// Added<EmpDisrupted> → 1.0 (щит гаснет, искрящие помехи), снят → 0.0.
+/// Collision щита выключает `update_shield_collision_state_main_thread` (энергия 0 → неактивен).
+///
+/// # Runs
+/// MainThreadUpdate (Godot API access)
+pub fn update_shield_emp_vfx_main_thread(
+    disrupted: Query<Entity, (Added<EmpDisrupted>, With<EnergyShield>)>,
+    mut recovered: RemovedComponents<EmpDisrupted>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    let updates = disrupted
+        .iter()
+        .map(|entity| (entity, 1.0_f32))
+        .chain(recovered.read().map(|entity| (entity, 0.0_f32)));
+
+    for (entity, value) in updates {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        let Some(shield_mesh) = actor_node.try_get_node_as::<MeshInstance3D>("ShieldSphere/ShieldMesh") else {
+            continue;
+        };
+
+        let Some(material) = shield_mesh.get_surface_override_material(0) else {
+            continue;
+        };
+
+        let mut shader_mat = material.cast::<ShaderMaterial>();
+        shader_mat.set_shader_parameter("emp_disrupted", &Variant::from(value));
+
+        logger::log(&format!("⚡ Shield EMP VFX: entity={:?}, disrupted={}", entity, value > 0.0));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
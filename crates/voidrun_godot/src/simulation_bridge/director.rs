@@ -0,0 +1,158 @@
+//! Spawn director — спавн подкреплений и орды по запросу ECS
+//!
+//! ECS (`security`, `horde`, `world_events`) решает КОГДА и СКОЛЬКО
+//! (ReinforcementsRequested, HordeSpawnRequested, WorldEventStarted),
+//! director решает КАК (prefab, archetype, раскладка точек спавна).
+
+use bevy::prelude::*;
+use voidrun_simulation::ai::SpottedEnemies;
+use voidrun_simulation::horde::{HordeLeader, HordeSpawnRequested, HORDE_FACTION_ID};
+use voidrun_simulation::interaction::LootTableRef;
+use voidrun_simulation::security::ReinforcementsRequested;
+use voidrun_simulation::world_events::{WorldEventKind, WorldEventStarted, ELITE_PATROL_FACTION_ID};
+use voidrun_simulation::MovementCommand;
+use voidrun_simulation::logger;
+
+use super::spawn::{spawn_melee_npc, spawn_test_npc};
+
+/// HP подкреплений (как у тестовых NPC)
+const REINFORCEMENT_HP: u32 = 60;
+
+/// Радиус кольца вокруг точки спавна (бойцы не спавнятся друг в друге)
+const SPAWN_RING_RADIUS: f32 = 1.5;
+
+/// HP рядового бойца орды (слабее подкреплений — берёт числом)
+const HORDE_GRUNT_HP: u32 = 40;
+
+/// HP элитного лидера орды
+const HORDE_LEADER_HP: u32 = 250;
+
+/// Радиус кольца волны орды вокруг лидера
+const HORDE_RING_RADIUS: f32 = 3.0;
+
+/// Элитный патруль: численность и HP
+const ELITE_PATROL_SIZE: u32 = 3;
+const ELITE_PATROL_HP: u32 = 120;
+
+/// Рейд фракции: численность (HP как у подкреплений)
+const FACTION_RAID_SIZE: u32 = 4;
+
+/// System: ReinforcementsRequested → ranged NPC фракции вокруг точки спавна
+///
+/// Бойцы сразу знают нарушителя (SpottedEnemies) → FSM переводит в Combat.
+pub fn spawn_reinforcements(
+    mut requests: EventReader<ReinforcementsRequested>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        for index in 0..request.count {
+            let angle = index as f32 / request.count as f32 * std::f32::consts::TAU;
+            let offset = Vec3::new(angle.cos(), 0.0, angle.sin()) * SPAWN_RING_RADIUS;
+            let position = request.position + offset;
+
+            let entity = spawn_test_npc(
+                &mut commands,
+                (position.x, position.y, position.z),
+                request.faction_id,
+                REINFORCEMENT_HP,
+            );
+            commands.entity(entity).insert(SpottedEnemies {
+                enemies: vec![request.intruder],
+            });
+        }
+
+        logger::log(&format!(
+            "🚁 Director: {} reinforcements for faction {} at {:?} (intruder {:?})",
+            request.count, request.faction_id, request.position, request.intruder
+        ));
+    }
+}
+
+/// System: HordeSpawnRequested → волна melee бойцов орды + элитный лидер в центре
+///
+/// Вся орда сразу знает цель (SpottedEnemies) → FSM переводит в Combat.
+pub fn spawn_horde(
+    mut requests: EventReader<HordeSpawnRequested>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        let target = SpottedEnemies {
+            enemies: vec![request.target],
+        };
+
+        let leader = spawn_melee_npc(
+            &mut commands,
+            (request.position.x, request.position.y, request.position.z),
+            HORDE_FACTION_ID,
+            HORDE_LEADER_HP,
+        );
+        commands.entity(leader).insert((HordeLeader, target.clone()));
+
+        for index in 0..request.count {
+            let angle = index as f32 / request.count as f32 * std::f32::consts::TAU;
+            let offset = Vec3::new(angle.cos(), 0.0, angle.sin()) * HORDE_RING_RADIUS;
+            let position = request.position + offset;
+
+            let entity = spawn_melee_npc(
+                &mut commands,
+                (position.x, position.y, position.z),
+                HORDE_FACTION_ID,
+                HORDE_GRUNT_HP,
+            );
+            commands.entity(entity).insert(target.clone());
+        }
+
+        logger::log(&format!(
+            "🧟 Director: horde of {} + leader {:?} at {:?} (target {:?})",
+            request.count, leader, request.position, request.target
+        ));
+    }
+}
+
+/// System: WorldEventStarted → отряды событий мира
+///
+/// - ElitePatrol → ranged бойцы `ELITE_PATROL_FACTION_ID` (дальше патрулируют по FSM, дроп `npc_elite`)
+/// - FactionRaid → бойцы фракции идут к chunk игрока (MoveToPosition)
+/// - SupplyDrop — уже в ECS (визуал — `supply_drops`), director ничего не спавнит
+pub fn spawn_world_event_forces(
+    mut started_events: EventReader<WorldEventStarted>,
+    mut commands: Commands,
+) {
+    for event in started_events.read() {
+        let (faction_id, count, max_hp, raid_target) = match event.kind {
+            WorldEventKind::SupplyDrop => continue,
+            WorldEventKind::ElitePatrol => (ELITE_PATROL_FACTION_ID, ELITE_PATROL_SIZE, ELITE_PATROL_HP, None),
+            WorldEventKind::FactionRaid { faction_id, target } => {
+                (faction_id, FACTION_RAID_SIZE, REINFORCEMENT_HP, Some(target))
+            }
+        };
+
+        for index in 0..count {
+            let angle = index as f32 / count as f32 * std::f32::consts::TAU;
+            let offset = Vec3::new(angle.cos(), 0.0, angle.sin()) * SPAWN_RING_RADIUS;
+            let position = event.position + offset;
+
+            let entity = spawn_test_npc(
+                &mut commands,
+                (position.x, position.y, position.z),
+                faction_id,
+                max_hp,
+            );
+            if let Some(target) = raid_target {
+                commands
+                    .entity(entity)
+                    .insert(MovementCommand::MoveToPosition { target });
+            }
+            if faction_id == ELITE_PATROL_FACTION_ID {
+                commands
+                    .entity(entity)
+                    .insert(LootTableRef("npc_elite".to_string()));
+            }
+        }
+
+        logger::log(&format!(
+            "📡 Director: world event #{} {:?} → {} fighters of faction {} at {:?}",
+            event.id, event.kind, count, faction_id, event.position
+        ));
+    }
+}
@@ -4,6 +4,7 @@
 //! - Создаёт всю 3D сцену программно в ready()
 //! - Каждый frame: ECS update → sync transforms → update health bars
 
+mod director;
 mod effects;
 mod scene;
 mod spawn;
@@ -15,9 +16,16 @@ use crate::vision::VisionTracking;
 use godot::classes::{INode3D, Node};
 use godot::prelude::*;
 use godot_logger::GodotLogger;
-use spawn::spawn_test_npc;
+use spawn::{
+    assign_companion, assign_guard_post, assign_patrol_route, assign_radio_operator, spawn_alarm_panel, spawn_extraction_point,
+    spawn_melee_npc, spawn_objective_item, spawn_test_npc, spawn_training_dummy,
+};
 use voidrun_simulation::{create_headless_app, SimulationPlugin};
 use voidrun_simulation::logger;
+
+/// Позиция тренировочного манекена (перед точкой spawn игрока)
+const TRAINING_DUMMY_POSITION: (f32, f32, f32) = (0.0, 0.0, 8.0);
+
 /// SimulationBridge: главный node для Godot ↔ ECS интеграции
 #[derive(GodotClass)]
 #[class(base=Node3D)]
@@ -53,21 +61,54 @@ impl INode3D for SimulationBridge {
         self.create_camera();
 
         // 3.5 Создаём DebugOverlay UI (FPS counter, spawn buttons)
-        self.create_debug_overlay();
+        let debug_overlay = self.create_debug_overlay();
 
         // 4. Инициализируем ECS симуляцию
         let mut app = create_headless_app(42);
         app.add_plugins(SimulationPlugin);
+        // В игре время реальное (headless app фиксирует 1/60 s на update — для тестов)
+        app.insert_resource(bevy::time::TimeUpdateStrategy::Automatic);
+        // Combat log матча → logs/combat (рядом с game.log)
+        app.insert_resource(voidrun_simulation::combat_log::CombatLogExport {
+            dir: Some("../logs/combat".into()),
+        });
 
         // 4.1 Регистрируем NonSend resources (main thread only)
         app.insert_non_send_resource(VisualRegistry::default());
         app.insert_non_send_resource(AttachmentRegistry::default());
         app.insert_non_send_resource(VisionTracking::default());
+        app.insert_non_send_resource(crate::smoke::SmokeVolumeRegistry::default());
+        app.insert_non_send_resource(crate::decals::ImpactDecalRegistry::default());
+        app.insert_non_send_resource(crate::doors::DoorNodeRegistry::default());
+        app.insert_non_send_resource(crate::interaction::InteractableNodeRegistry::default());
+        app.insert_non_send_resource(crate::movement::LadderRegistry::default());
+        app.insert_non_send_resource(crate::movement::GravityZoneRegistry::default());
+        app.insert_non_send_resource(crate::environment::VacuumZoneRegistry::default());
+        app.insert_non_send_resource(crate::environment::HazardZoneRegistry::default());
+        app.insert_non_send_resource(crate::environment::InteriorVolumeRegistry::default());
+        app.insert_non_send_resource(crate::visual_sync::AppearanceRegistry::default());
+        app.insert_non_send_resource(crate::visual_sync::FactionThemeCache::default());
+        app.insert_non_send_resource(crate::objectives::ObjectiveVisualRegistry::default());
+        app.insert_non_send_resource(crate::supply_drops::SupplyDropVisualRegistry::default());
+        app.insert_non_send_resource(crate::ui::FlashOverlay::default());
+        app.insert_non_send_resource(crate::ui::ArenaOverlay::default());
+        app.insert_non_send_resource(crate::ui::CompassStrip::default());
+        app.insert_non_send_resource(crate::ui::ContainerPanel::default());
+        app.insert_non_send_resource(crate::ui::ScanPanel::default());
+        app.insert_non_send_resource(crate::ui::TutorialPrompt::default());
+        app.insert_non_send_resource(crate::ui::ChatterSubtitles::default());
+        app.insert_non_send_resource(crate::ui::FireFeedback::default());
+        app.insert_non_send_resource(crate::ui::DebugOverlayHandle { overlay: debug_overlay });
         app.insert_non_send_resource(crate::projectiles::GodotProjectileRegistry::default());
+        app.insert_non_send_resource(crate::projectiles::ThrownItemRegistry::default());
         app.insert_non_send_resource(SceneRoot {
             node: self.base().clone().upcast::<Node3D>(),
         });
 
+        // 4.2 Настройки отображения (меняются из меню через #[func])
+        app.init_resource::<crate::visual_sync::NameplateSettings>();
+        app.init_resource::<crate::visual_sync::NameplateReveals>();
+
         // 4.3 Регистрируем custom schedules + timer systems
         systems_setup::register_schedules(&mut app);
 
@@ -110,19 +151,297 @@ impl SimulationBridge {
         let world = app.world_mut();
         let mut commands = world.commands();
 
-        spawn_test_npc(&mut commands, (0.0, 0.0, 3.0), 1, 60);
+        let patroller = spawn_test_npc(&mut commands, (0.0, 0.0, 3.0), 1, 60);
+        assign_patrol_route(
+            &mut commands,
+            patroller,
+            &[(0.0, 0.0, 3.0), (10.0, 0.0, 3.0), (10.0, 0.0, 12.0)],
+            voidrun_simulation::ai::PatrolMode::PingPong,
+        );
         spawn_test_npc(&mut commands, (25.0, 0.0, 6.0), 1, 60);
-        spawn_test_npc(&mut commands, (21.0, 0.0, 6.0), 1, 60);
+        let companion = spawn_test_npc(&mut commands, (21.0, 0.0, 6.0), 1, 60);
+        assign_companion(&mut commands, companion);
 
         spawn_test_npc(&mut commands, (0.0, 0.0, 0.0), 2, 60);
-        spawn_test_npc(&mut commands, (-26.0, 0.0, -5.0), 2, 60);
+        let guard = spawn_test_npc(&mut commands, (-26.0, 0.0, -5.0), 2, 60);
+        assign_guard_post(&mut commands, guard, (-26.0, 0.0, -5.0), 12.0);
+        spawn_alarm_panel(&mut commands, (-24.0, 0.0, -8.0), 2, 14.0, 2);
         spawn_test_npc(&mut commands, (-16.0, 0.0, -6.0), 2, 60);
 
-        spawn_test_npc(&mut commands, (3.0, 0.0, -6.0), 3, 60);
+        let radio_operator = spawn_test_npc(&mut commands, (3.0, 0.0, -6.0), 3, 60);
+        assign_radio_operator(&mut commands, radio_operator, 2);
         spawn_test_npc(&mut commands, (2.0, 0.0, -5.0), 3, 60);
         spawn_test_npc(&mut commands, (1.0, 0.0, -6.0), 3, 60);
 
-        logger::log("✅ NPCs spawned successfully (9 NPCs, 3 factions)");
+        spawn_objective_item(&mut commands, (6.0, 0.5, 8.0), (-10.0, 0.0, 14.0));
+        spawn_extraction_point(&mut commands, (-30.0, 0.0, 20.0));
+
+        logger::log("✅ NPCs spawned successfully (9 NPCs, 3 factions, 1 alarm panel, 1 objective, 1 extraction point)");
+    }
+
+    /// Установить сложность AI (Godot меню: 0 = Easy, 1 = Normal, 2 = Hard, 3 = Nightmare)
+    ///
+    /// Пресет применяется при spawn — уже заспавненные NPC не меняются.
+    #[func]
+    pub fn set_difficulty(&mut self, level: i64) {
+        let Some(app) = &mut self.simulation else {
+            logger::log_error("❌ Simulation not initialized!");
+            return;
+        };
+
+        let Some(level) = voidrun_simulation::ai::DifficultyLevel::from_index(level) else {
+            logger::log_error(&format!("❌ Unknown difficulty level: {}", level));
+            return;
+        };
+
+        app.world_mut()
+            .insert_resource(voidrun_simulation::ai::Difficulty { level });
+
+        logger::log(&format!("🎚️ Difficulty set to {:?} (applies to new spawns)", level));
+    }
+
+    /// Настройки nameplates (Godot меню настроек)
+    ///
+    /// Враги всегда видны только после обнаружения / урона; затухание — `fade_start` → `fade_end` метров.
+    #[func]
+    pub fn set_nameplate_settings(&mut self, show_friendly: bool, show_stats: bool, fade_start: f64, fade_end: f64) {
+        let Some(app) = &mut self.simulation else {
+            logger::log_error("❌ Simulation not initialized!");
+            return;
+        };
+
+        let mut settings = app.world_mut().resource_mut::<crate::visual_sync::NameplateSettings>();
+        settings.show_friendly = show_friendly;
+        settings.show_stats = show_stats;
+        settings.fade_start = fade_start as f32;
+        settings.fade_end = fade_end as f32;
+
+        logger::log(&format!("🏷️ Nameplate settings updated: {:?}", *settings));
+    }
+
+    /// Начать extraction run (Godot меню)
+    ///
+    /// Точки эвакуации работают только после открытия (таймер / захват объектива).
+    /// `PlayerProfile` (банк вынесенного лута) переживает runs.
+    #[func]
+    pub fn start_extraction_run(&mut self, time_limit_secs: f64, open_after_secs: f64) {
+        let Some(app) = &mut self.simulation else {
+            logger::log_error("❌ Simulation not initialized!");
+            return;
+        };
+
+        let run = voidrun_simulation::game_mode::ExtractionRun::new(
+            time_limit_secs as f32,
+            open_after_secs as f32,
+            1,
+        );
+        app.world_mut()
+            .insert_resource(voidrun_simulation::game_mode::GameMode::Extraction(run));
+
+        logger::log(&format!(
+            "🚁 Extraction run started ({:.0}s limit, extraction opens at {:.0}s)",
+            time_limit_secs, open_after_secs
+        ));
+    }
+
+    /// Тактическая пауза (Godot хоткей / меню)
+    ///
+    /// FixedUpdate стоит, рендер / камера / RTS выделение работают.
+    /// Приказы на паузе копятся в `OrderBuffer` — `voidrun_simulation::time_control`.
+    #[func]
+    pub fn pause_simulation(&mut self) {
+        self.set_simulation_state(voidrun_simulation::time_control::SimulationState::Paused);
+    }
+
+    /// Снять тактическую паузу (накопленные приказы применятся первым тиком)
+    #[func]
+    pub fn resume_simulation(&mut self) {
+        self.set_simulation_state(voidrun_simulation::time_control::SimulationState::Running);
+    }
+
+    /// Переключить тактическую паузу (хоткей)
+    #[func]
+    pub fn toggle_pause(&mut self) {
+        let Some(app) = &self.simulation else {
+            logger::log_error("❌ Simulation not initialized!");
+            return;
+        };
+
+        let state = *app.world().resource::<voidrun_simulation::time_control::SimulationState>();
+        self.set_simulation_state(state.toggled());
+    }
+
+    /// Симуляция на тактической паузе (Godot UI: индикатор паузы)
+    #[func]
+    pub fn is_simulation_paused(&self) -> bool {
+        self.simulation.as_ref().is_some_and(|app| {
+            app.world()
+                .resource::<voidrun_simulation::time_control::SimulationState>()
+                .is_paused()
+        })
+    }
+
+    /// Запрос перехода состояния игры (Godot меню: 0 = MainMenu, 1 = Loading, 2 = Playing, 3 = Paused, 4 = GameOver)
+    ///
+    /// Недопустимый переход отклоняется в ECS (`voidrun_simulation::game_state`).
+    #[func]
+    pub fn request_game_state(&mut self, state: i64) {
+        let Some(app) = &mut self.simulation else {
+            logger::log_error("❌ Simulation not initialized!");
+            return;
+        };
+
+        let Some(to) = voidrun_simulation::GameState::from_index(state) else {
+            logger::log_error(&format!("❌ Unknown game state: {}", state));
+            return;
+        };
+
+        app.world_mut()
+            .send_event(voidrun_simulation::game_state::GameStateRequest { to });
+    }
+
+    /// Текущее состояние игры (индекс как в `request_game_state`; -1 — симуляция не создана)
+    #[func]
+    pub fn get_game_state(&self) -> i64 {
+        self.simulation.as_ref().map_or(-1, |app| {
+            app.world()
+                .resource::<bevy::prelude::State<voidrun_simulation::GameState>>()
+                .get()
+                .index()
+        })
+    }
+
+    /// Масштаб времени симуляции (Godot меню / хоткеи): 0.25x bullet-time … 4x fast-forward
+    ///
+    /// Вне диапазона — clamp. Шаг FixedUpdate прежний, меняется число тиков за секунду
+    /// (`voidrun_simulation::time_control::TimeScale`).
+    #[func]
+    pub fn set_time_scale(&mut self, scale: f64) {
+        let Some(app) = &mut self.simulation else {
+            logger::log_error("❌ Simulation not initialized!");
+            return;
+        };
+
+        let scale = voidrun_simulation::time_control::TimeScale::new(scale as f32);
+        app.world_mut().insert_resource(scale);
+    }
+
+    /// Текущий масштаб времени (Godot UI: индикатор 0.25x / 1x / 4x)
+    #[func]
+    pub fn get_time_scale(&self) -> f64 {
+        self.simulation.as_ref().map_or(1.0, |app| {
+            app.world()
+                .resource::<voidrun_simulation::time_control::TimeScale>()
+                .get() as f64
+        })
+    }
+
+    /// Restart Tutorial button callback — туториал с первого шага
+    ///
+    /// Шаги и прогресс — `voidrun_simulation::tutorial`; манекен спавнится, если его нет.
+    #[func]
+    pub fn restart_tutorial(&mut self) {
+        let Some(app) = &mut self.simulation else {
+            logger::log_error("❌ Simulation not initialized!");
+            return;
+        };
+
+        let world = app.world_mut();
+        let has_dummy = world
+            .query_filtered::<(), bevy::prelude::With<voidrun_simulation::tutorial::TrainingDummy>>()
+            .iter(world)
+            .next()
+            .is_some();
+        if !has_dummy {
+            spawn_training_dummy(&mut world.commands(), TRAINING_DUMMY_POSITION);
+        }
+
+        world.send_event(voidrun_simulation::tutorial::StartTutorial { from_beginning: true });
+        logger::log("🎓 Tutorial restarted");
+    }
+
+    /// Craft menu callback — крафт рецепта игроком
+    ///
+    /// Проверка ингредиентов и канал крафта — `voidrun_simulation::crafting`.
+    #[func]
+    pub fn craft_item(&mut self, recipe_id: GString) {
+        let Some(app) = &mut self.simulation else {
+            logger::log_error("❌ Simulation not initialized!");
+            return;
+        };
+
+        let world = app.world_mut();
+        let Ok(player) = world
+            .query_filtered::<bevy::prelude::Entity, bevy::prelude::With<voidrun_simulation::player::Player>>()
+            .single(world)
+        else {
+            logger::log_error("❌ Craft: player not spawned");
+            return;
+        };
+
+        world.send_event(voidrun_simulation::crafting::CraftItemIntent {
+            actor: player,
+            recipe: recipe_id.to_string(),
+        });
+    }
+
+    /// Loadout menu callback — применить пресет снаряжения игроку
+    ///
+    /// Проверка Inventory и экипировка — `voidrun_simulation::equipment` (ApplyLoadoutIntent).
+    #[func]
+    pub fn apply_loadout(&mut self, loadout: GString) {
+        let Some(app) = &mut self.simulation else {
+            logger::log_error("❌ Simulation not initialized!");
+            return;
+        };
+
+        let world = app.world_mut();
+        let Ok(player) = world
+            .query_filtered::<bevy::prelude::Entity, bevy::prelude::With<voidrun_simulation::player::Player>>()
+            .single(world)
+        else {
+            logger::log_error("❌ Loadout: player not spawned");
+            return;
+        };
+
+        world.send_event(voidrun_simulation::ApplyLoadoutIntent {
+            entity: player,
+            loadout: loadout.to_string(),
+        });
+    }
+
+    /// Arena Duel button callback — 1v1 melee дуэль best-of-3
+    ///
+    /// Два melee NPC разных фракций + `GameMode::Arena`.
+    /// Раунды, сброс позиций и статистика — `voidrun_simulation::game_mode`.
+    #[func]
+    pub fn start_arena_duel(&mut self) {
+        const ARENA_BEST_OF: u32 = 3;
+        const SPAWN_POINTS: [(f32, f32, f32); 2] = [(-4.0, 0.0, 20.0), (4.0, 0.0, 20.0)];
+
+        let Some(app) = &mut self.simulation else {
+            logger::log_error("❌ Simulation not initialized!");
+            return;
+        };
+
+        let world = app.world_mut();
+        let mut commands = world.commands();
+
+        let fighters = [
+            spawn_melee_npc(&mut commands, SPAWN_POINTS[0], 10, 100),
+            spawn_melee_npc(&mut commands, SPAWN_POINTS[1], 11, 100),
+        ];
+        for (slot, fighter) in fighters.into_iter().enumerate() {
+            commands
+                .entity(fighter)
+                .insert(voidrun_simulation::game_mode::ArenaFighter { slot });
+        }
+
+        let spawn_points = SPAWN_POINTS.map(|(x, y, z)| bevy::prelude::Vec3::new(x, y, z));
+        let arena = voidrun_simulation::game_mode::ArenaMatch::new(fighters, spawn_points, ARENA_BEST_OF);
+        world.insert_resource(voidrun_simulation::game_mode::GameMode::Arena(arena));
+
+        logger::log(&format!("🏟️ Arena duel started (best of {})", ARENA_BEST_OF));
     }
 
     /// Spawn player button callback (вызывается при нажатии кнопки)
@@ -138,6 +457,10 @@ impl SimulationBridge {
         // Spawn player entity через helper
         let player_entity = {
             let world = app.world_mut();
+            let appearance = world
+                .resource::<voidrun_simulation::game_mode::PlayerProfile>()
+                .appearance
+                .clone();
             let mut entity_commands = world.spawn_empty();
             let player_entity = entity_commands.id();
 
@@ -159,34 +482,42 @@ impl SimulationBridge {
                     max: 100.0,
                     regen_rate: 10.0,
                 },
-                voidrun_simulation::WeaponStats::melee_sword(),
-                voidrun_simulation::Attachment {
-                    prefab_path: "res://actors/test_sword.tscn".to_string(),
-                    attachment_point: "%RightHandAttachment".to_string(),
-                    attachment_type: voidrun_simulation::AttachmentType::Weapon,
-                },
-                // Equipment components (new system)
+                // Equipment: WeaponStats + Attachment выводятся из активного слота (derive_weapon_stats)
                 voidrun_simulation::EquippedWeapons {
-                    primary_large_1: Some(voidrun_simulation::EquippedItem {
-                        definition_id: "melee_sword".into(),
-                        durability: 1.0,
-                        ammo_count: None,
-                    }),
+                    primary_large_1: Some(voidrun_simulation::EquippedItem::from_instance(
+                        &voidrun_simulation::ItemInstance::new("melee_sword"),
+                    )),
                     primary_large_2: None,
-                    secondary_small_1: Some(voidrun_simulation::EquippedItem {
-                        definition_id: "pistol_basic".into(),
-                        durability: 1.0,
-                        ammo_count: Some(30),
-                    }),
+                    secondary_small_1: Some(voidrun_simulation::EquippedItem::from_instance(
+                        &voidrun_simulation::ItemInstance::weapon_with_ammo("pistol_basic", 30),
+                    )),
                     secondary_small_2: None,
                     active_slot: 0, // Активен slot 0 (меч)
                 },
                 voidrun_simulation::ConsumableSlots::default(), // Базовые 2 слота
-                voidrun_simulation::Inventory::empty(), // Пустой инвентарь пока
+                voidrun_simulation::Inventory::empty().with_currency(100), // Пустой инвентарь + стартовые кредиты
                 // Player shooting components
                 voidrun_simulation::shooting::AimMode::default(), // Hip Fire по умолчанию
+                voidrun_simulation::combat::RecoilState::default(), // Отдача: подброс камеры + bloom разброса
+                (
+                    voidrun_simulation::movement::Jetpack::default(), // Space в воздухе → тяга
+                    voidrun_simulation::movement::LeanState::default(), // Q/E → наклон из-за укрытия
+                    voidrun_simulation::environment::Oxygen::default(), // Запас воздуха (вакуум), шлем брони добавляет
+                    voidrun_simulation::environment::BodyTemperature::default(), // Холод / жара (погода, броня, костры)
+                    appearance, // Внешность из профиля (PlayerProfile)
+                    voidrun_simulation::combat::CanBeDowned::default(), // 0 HP → Downed (bleed-out, союзник поднимает)
+                ),
             ));
 
+            // Первый запуск (туториал не пройден) → манекен для шагов парирования / убийства
+            if !world
+                .resource::<voidrun_simulation::game_mode::PlayerProfile>()
+                .tutorial
+                .completed
+            {
+                spawn_training_dummy(&mut world.commands(), TRAINING_DUMMY_POSITION);
+            }
+
             player_entity
         };
 
@@ -208,6 +539,16 @@ impl SimulationBridge {
         ));
     }
 
+    /// SimulationState → ECS (применит `apply_simulation_state` в начале следующего кадра)
+    fn set_simulation_state(&mut self, state: voidrun_simulation::time_control::SimulationState) {
+        let Some(app) = &mut self.simulation else {
+            logger::log_error("❌ Simulation not initialized!");
+            return;
+        };
+
+        app.world_mut().insert_resource(state);
+    }
+
     /// Записать SafeVelocityComputed event в ECS (вызывается из AvoidanceReceiver)
     ///
     /// Flow:
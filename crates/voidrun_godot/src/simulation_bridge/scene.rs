@@ -115,7 +115,8 @@ impl SimulationBridge {
     ///
     /// DebugOverlay — отдельный Control node с всем debug UI.
     /// Создаётся через CanvasLayer для рендеринга поверх 3D сцены.
-    pub(super) fn create_debug_overlay(&mut self) {
+    /// Возвращает node — ECS системы пишут в него диагностику (DebugOverlayHandle).
+    pub(super) fn create_debug_overlay(&mut self) -> Gd<crate::ui::DebugOverlay> {
         // CanvasLayer для UI overlay (рендерится поверх 3D сцены)
         let mut canvas_layer = CanvasLayer::new_alloc();
 
@@ -137,11 +138,12 @@ impl SimulationBridge {
         debug_overlay.set_anchors_preset(godot::classes::control::LayoutPreset::FULL_RECT);
 
         // Добавляем DebugOverlay в canvas layer
-        canvas_layer.add_child(&debug_overlay.upcast::<Node>());
+        canvas_layer.add_child(&debug_overlay.clone().upcast::<Node>());
 
         // Добавляем canvas layer в сцену
         self.base_mut().add_child(&canvas_layer.upcast::<Node>());
 
         logger::log("DebugOverlay created (F3 to toggle)");
+        debug_overlay
     }
 }
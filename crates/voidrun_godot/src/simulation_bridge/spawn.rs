@@ -39,8 +39,17 @@ pub fn spawn_melee_npc(
                 retreat_health_threshold: 0.0,
                 retreat_duration: 1.5,
                 patrol_direction_change_interval: 3.0,
+                parry_priority_bonus: 0.0,
             },
             ai::SpottedEnemies::default(),
+            (
+                combat::FlinchConfig::default(), // Melee archetype: default flinch thresholds
+                ai::VisionConfig::brawler(),     // Широкий, но короткий обзор
+                environment::Oxygen::default(),  // Задержка дыхания без шлема (вакуум)
+                Appearance::varied(appearance_seed(world_pos, faction_id)), // Тело + кожа, одежда — цвет фракции
+                interaction::LootTableRef("npc_common".to_string()), // Дроп при смерти (поверх снаряжения)
+            ),
+            npc_consumables(), // Health kit + AI self-heal
             Attachment {
                 prefab_path: "res://actors/test_sword.tscn".to_string(), // ✅ Sword prefab
                 attachment_point: "%RightHandAttachment".to_string(),
@@ -50,6 +59,18 @@ pub fn spawn_melee_npc(
         .id()
 }
 
+/// Спавн тренировочного манекена туториала (melee NPC без лута, цель шага "kill_dummy")
+///
+/// Атакует мечом — на нём же отрабатывается шаг парирования.
+pub fn spawn_training_dummy(commands: &mut Commands, position: (f32, f32, f32)) -> Entity {
+    let dummy = spawn_melee_npc(commands, position, 2, 40);
+    commands
+        .entity(dummy)
+        .remove::<interaction::LootTableRef>()
+        .insert(tutorial::TrainingDummy);
+    dummy
+}
+
 /// Спавн тестового NPC в ECS world (ADR-005: StrategicPosition + PrefabPath)
 pub fn spawn_test_npc(
     commands: &mut Commands,
@@ -83,9 +104,19 @@ pub fn spawn_test_npc(
                 retreat_health_threshold: 0.0,         // Retreat при HP < 10% (было 20%)
                 retreat_duration: 1.5,                 // Быстрее возвращаются в бой
                 patrol_direction_change_interval: 3.0, // Каждые 3 сек новое направление
+                parry_priority_bonus: 0.0,             // Difficulty preset применяется при spawn
             },
             ai::SpottedEnemies::default(), // Godot VisionCone → GodotAIEvent → обновляет список
             components::EnergyShield::basic(), // ✅ Energy shield (basic preset для тестов)
+            (
+                combat::FlinchConfig::skittish(), // Ranged archetype: сбивается легче
+                combat::AimSkill::default(), // Средний стрелок (spread 3°, reaction 0.4s, tracking 0.5)
+                ai::VisionConfig::default(), // 90° / 15м + периферия 160° / 4м
+                environment::Oxygen::default(), // Задержка дыхания без шлема (вакуум)
+                Appearance::varied(appearance_seed(world_pos, faction_id)), // Тело + кожа, одежда — цвет фракции
+                interaction::LootTableRef("npc_common".to_string()), // Дроп при смерти (поверх снаряжения)
+            ),
+            npc_consumables(), // Health kit + AI self-heal
             Attachment {
                 prefab_path: "res://actors/test_pistol.tscn".to_string(),
                 attachment_point: "%RightHandAttachment".to_string(),
@@ -94,3 +125,136 @@ pub fn spawn_test_npc(
         ))
         .id()
 }
+
+/// Seed вариации внешности NPC (позиция спавна + фракция — повторяемо между запусками)
+fn appearance_seed(world_pos: Vec3, faction_id: u64) -> u64 {
+    ((world_pos.x.to_bits() as u64) << 32 | world_pos.z.to_bits() as u64) ^ faction_id
+}
+
+/// Назначить NPC маршрут патруля (waypoints в world coordinates)
+///
+/// Без маршрута NPC патрулирует случайными точками.
+pub fn assign_patrol_route(
+    commands: &mut Commands,
+    entity: Entity,
+    waypoints: &[(f32, f32, f32)],
+    mode: ai::PatrolMode,
+) {
+    let waypoints = waypoints
+        .iter()
+        .map(|&(x, y, z)| Vec3::new(x, y, z))
+        .collect();
+
+    commands
+        .entity(entity)
+        .insert(ai::PatrolRoute::new(waypoints, mode));
+}
+
+/// Назначить NPC пост охраны (home = world position, leash в метрах)
+///
+/// Охранник не преследует врагов за leash, возвращается на пост и поднимает тревогу.
+pub fn assign_guard_post(
+    commands: &mut Commands,
+    entity: Entity,
+    home: (f32, f32, f32),
+    leash_radius: f32,
+) {
+    let home = Vec3::new(home.0, home.1, home.2);
+
+    commands
+        .entity(entity)
+        .insert(ai::GuardPost::new(home, leash_radius));
+}
+
+/// Назначить NPC рацию (вызов подкрепления в бою, channelled)
+///
+/// Контригра игрока: убить / застаггерить радиста во время вызова.
+pub fn assign_radio_operator(commands: &mut Commands, entity: Entity, reinforcements: u32) {
+    commands
+        .entity(entity)
+        .insert(ai::RadioOperator::new(reinforcements));
+}
+
+/// Назначить NPC спутником игрока (лидер — игрок той же фракции, приказы F5-F8)
+///
+/// Спутник при 0 HP падает (Downed) — игрок может его поднять.
+pub fn assign_companion(commands: &mut Commands, entity: Entity) {
+    commands.entity(entity).insert((
+        companions::Companion::default(),
+        combat::CanBeDowned::default(),
+    ));
+}
+
+/// Спавн тревожной панели фракции (территория = круг вокруг панели)
+///
+/// Обнаружение нарушителя на территории → тревога + `reinforcements` бойцов.
+pub fn spawn_alarm_panel(
+    commands: &mut Commands,
+    position: (f32, f32, f32),
+    faction_id: u64,
+    territory_radius: f32,
+    reinforcements: u32,
+) -> Entity {
+    let position = Vec3::new(position.0, position.1, position.2);
+
+    commands
+        .spawn(security::AlarmPanel::new(faction_id, position, territory_radius, reinforcements))
+        .id()
+}
+
+/// Спавн объективного предмета (ядро) с зоной захвата
+///
+/// Визуал создаёт `spawn_objective_visuals_main_thread` (Added<ObjectiveItem>).
+pub fn spawn_objective_item(
+    commands: &mut Commands,
+    position: (f32, f32, f32),
+    capture_point: (f32, f32, f32),
+) -> Entity {
+    let position = Vec3::new(position.0, position.1, position.2);
+    let capture_point = Vec3::new(capture_point.0, capture_point.1, capture_point.2);
+
+    commands
+        .spawn(objective::ObjectiveItem::new(
+            position,
+            capture_point,
+            objective::ObjectiveItem::DEFAULT_CAPTURE_RADIUS,
+        ))
+        .id()
+}
+
+/// Спавн точки эвакуации (Extraction mode)
+///
+/// Вне `GameMode::Extraction` точка неактивна.
+pub fn spawn_extraction_point(commands: &mut Commands, position: (f32, f32, f32)) -> Entity {
+    let position = Vec3::new(position.0, position.1, position.2);
+
+    commands
+        .spawn(game_mode::ExtractionPoint::new(position))
+        .id()
+}
+
+/// Перевести NPC с FSM на behavior tree (дерево в RON)
+///
+/// AIState удаляется — решения принимает `run_behavior_trees`.
+pub fn assign_behavior_tree(
+    commands: &mut Commands,
+    entity: Entity,
+    tree_ron: &str,
+) -> Result<(), ai::BtParseError> {
+    let tree = ai::BehaviorTree::from_ron(tree_ron)?;
+
+    commands
+        .entity(entity)
+        .remove::<ai::AIState>()
+        .insert(tree);
+
+    Ok(())
+}
+
+/// Стартовые расходники NPC: 1 health kit в слоте 0 + AI self-heal параметры
+fn npc_consumables() -> (ConsumableSlots, ai::AIConsumableUse) {
+    let mut slots = ConsumableSlots::default();
+    slots.set_slot(0, Some(ItemInstance::new("health_kit")));
+
+    (slots, ai::AIConsumableUse::default())
+}
@@ -4,28 +4,44 @@
 
 use crate::schedules::{CombatUpdate, FixedTickCounter, SlowUpdate};
 use bevy::prelude::*;
+use crate::tactical_pause::tactical_layer_active;
 
 /// Регистрация всех ECS систем в Bevy App
 pub fn register_systems(app: &mut App) {
     // Visual sync domain
     use crate::visual_sync::{
         spawn_actor_visuals_main_thread,
+        spawn_world_item_visuals_main_thread, // WorldItem → prefab предмета на земле
+        despawn_world_item_visuals_main_thread,
+        apply_appearance_main_thread, // Appearance → тело, цвета, косметика
         sync_health_labels_main_thread,
         sync_stamina_labels_main_thread,
         sync_shield_labels_main_thread,
         sync_ai_state_labels_main_thread,
+        update_nameplates_main_thread,
         disable_collision_on_death_main_thread,
         despawn_actor_visuals_main_thread,
+        sync_invulnerability_visuals_main_thread,
+        sync_channel_animations_main_thread,
+        sync_backup_call_labels_main_thread,
+        sync_objective_carrier_labels_main_thread,
+        sync_arena_round_resets_main_thread,
     };
 
     // Movement domain
     use crate::movement::{
         apply_gravity_to_all_actors, // Gravity + jump для ВСЕХ акторов (ПЕРВАЯ система!)
+        apply_mantle_positions_main_thread, // MantleState → позиция тела (вместо гравитации)
+        detect_ladder_overlaps_main_thread, // Ladder volumes → LadderEntered/Exited
+        detect_gravity_zones_main_thread, // Gravity zones → GravityZoneEntered/Exited
+        sync_jetpack_thrusters_main_thread, // JetpackIgnited/CutOff → пламя двигателя
         process_movement_commands_main_thread,
         update_follow_entity_targets_main_thread,
         apply_retreat_velocity_main_thread,
         apply_navigation_velocity_main_thread,
         apply_safe_velocity_system, // NavigationAgent3D avoidance
+        sync_stance_collision_main_thread,
+        sync_lean_visuals_main_thread, // LeanState → крен Head/Torso + сдвиг глаз для LOS
     };
 
     // Combat domain (UNIFIED: melee + ai_melee + ranged)
@@ -37,6 +53,10 @@ pub fn register_systems(app: &mut App) {
         weapon_fire_main_thread,
         projectile_collision_system_main_thread, // Event-driven projectile → body collision
         projectile_shield_collision_main_thread, // Shield collision detection (Area3D)
+        projectile_near_miss_detection_main_thread, // Near-miss → suppression
+        // Thrown weapons (нож / копьё по дуге)
+        spawn_thrown_items_main_thread,
+        process_thrown_item_impacts_main_thread,
         detect_melee_windups_main_thread, // Visual windup detection
         // Melee execution
         process_melee_attack_intents_main_thread,
@@ -44,24 +64,48 @@ pub fn register_systems(app: &mut App) {
         poll_melee_hitboxes_main_thread,
         execute_parry_animations_main_thread,
         execute_stagger_animations_main_thread,
+        execute_flinch_animations_main_thread,
+        execute_knockdown_getup_main_thread,
         // AI combat decision-making
+        track_actor_motion_main_thread,
+        update_ai_blackboards_main_thread,
         ai_melee_combat_decision_main_thread,
     };
 
     // Vision domain
-    use crate::vision::poll_vision_cones_main_thread;
+    use crate::vision::{
+        poll_vision_cones_main_thread, sample_light_levels_main_thread, detect_flash_exposure_main_thread,
+    };
+
+    // UI domain
+    use crate::ui::{
+        sync_camera_yaw_main_thread, update_arena_overlay_main_thread, update_compass_strip_main_thread,
+        update_container_panel_main_thread, update_flash_overlay_main_thread, update_scan_panel_main_thread,
+        update_tutorial_prompt_main_thread, update_chatter_subtitles_main_thread, update_fire_feedback_main_thread,
+        update_debug_diagnostics_main_thread,
+    };
+
+    // Smoke domain
+    use crate::smoke::{spawn_smoke_volumes_main_thread, despawn_smoke_volumes_main_thread};
+
+    // Decals domain
+    use crate::decals::{spawn_impact_decals_main_thread, despawn_impact_decals_main_thread};
 
     // Attachment domain
     use crate::attachment::{
         attach_prefabs_main_thread,
         detach_prefabs_main_thread,
+        sync_armor_attachments_main_thread,
     };
+    use crate::gear_condition::apply_gear_condition_main_thread;
+    use crate::weapon_heat::update_weapon_heat_glow_main_thread;
 
     // Camera domain
     use crate::camera::{
         setup_player_camera, // Setup player camera при spawn
         camera_toggle_system, // Camera toggle [V] key (FPS ↔ RTS)
         player_mouse_look,    // Mouse look (FPS only)
+        apply_recoil_kick_main_thread, // RecoilState → подброс камеры
     };
 
     // Weapon switch domain
@@ -72,6 +116,9 @@ pub fn register_systems(app: &mut App) {
         process_ads_toggle,
         update_ads_position_transition,
         player_hip_fire_aim,
+        detect_aimed_at_main_thread,
+        update_ads_fov_main_thread,
+        apply_weapon_sway_main_thread,
     };
 
     // Shield VFX domain
@@ -79,6 +126,7 @@ pub fn register_systems(app: &mut App) {
         update_shield_energy_vfx_main_thread,
         update_shield_ripple_vfx_main_thread, // Ripple VFX on ProjectileShieldHit
         update_shield_collision_state_main_thread, // Shield collision enable/disable based on is_active
+        update_shield_emp_vfx_main_thread, // EmpDisrupted → помехи на щите
     };
 
     // 1. Регистрируем Godot tactical layer events
@@ -98,30 +146,39 @@ pub fn register_systems(app: &mut App) {
         Main,
         (
             spawn_actor_visuals_main_thread,
+            spawn_world_item_visuals_main_thread, // WorldItem (дроп / лут) → prefab или placeholder
+            apply_appearance_main_thread, // Внешность поверх цвета фракции (ПОСЛЕ spawn!)
             attach_prefabs_main_thread,
+            sync_armor_attachments_main_thread, // EquippedArmor → prefab каждого слота брони
+            apply_gear_condition_main_thread, // Прочность → материал prefab'а (ПОСЛЕ attach!)
             setup_player_camera, // Setup FPS camera при player spawn (ПОСЛЕ attach!)
             detach_prefabs_main_thread,
         )
             .chain(),
     );
 
-    // 3. Update schedule - Movement chain (gravity → nav velocity → safe velocity)
+    // 3. Update schedule - Movement chain (gravity → nav velocity → safe velocity; стоит на паузе / вне Playing)
     app.add_systems(
         Update,
         (
+            detect_ladder_overlaps_main_thread,     // 0a. Ladder volumes → LadderEntered/Exited (ECS → ClimbingState)
+            detect_gravity_zones_main_thread,       // 0a. Gravity zones → GravityZoneEntered/Exited (ECS → GravityState)
+            apply_mantle_positions_main_thread,     // 0b. MantleState → lerp позиции (такие акторы без гравитации)
             apply_gravity_to_all_actors,            // 1. Gravity + jump для ВСЕХ акторов (ПЕРВАЯ!)
             apply_navigation_velocity_main_thread,  // 2. nav_agent.set_velocity(desired) → velocity_computed signal
             apply_safe_velocity_system,             // 3. SafeVelocityComputed event → CharacterBody3D (AFTER nav velocity)
+            sync_jetpack_thrusters_main_thread,     // 4. JetpackIgnited/CutOff → пламя (VFX)
         )
-            .chain(),
+            .chain()
+            .run_if(tactical_layer_active),
     );
 
     // 4. Update schedule - Input + Camera + Labels + Death handling + Weapon Switch + Shield VFX
     app.add_systems(
         Update,
         (
-            crate::input::process_player_input,       // Player input → velocity (FPS camera-relative)
-            crate::input::player_combat_input,        // Player input → MeleeAttackIntent + ToggleADSIntent
+            crate::input::process_player_input.run_if(tactical_layer_active), // Player input → velocity (FPS camera-relative)
+            crate::input::player_combat_input.run_if(tactical_layer_active),  // Player input → MeleeAttackIntent + ToggleADSIntent
             process_ads_toggle,                       // ToggleADSIntent → update AimMode state
             update_ads_position_transition,           // Smooth lerp Hip ↔ ADS transitions
             player_hip_fire_aim,                      // Hip Fire mode → dynamic raycast aiming
@@ -144,7 +201,177 @@ pub fn register_systems(app: &mut App) {
         ),
     );
 
-    // 5. Update schedule - Combat systems
+    // 4.1 Update schedule - Combat feedback visuals (invulnerability, nameplates и т.п.)
+    app.add_systems(
+        Update,
+        (
+            sync_invulnerability_visuals_main_thread, // Invulnerable added/removed → mesh transparency
+            sync_channel_animations_main_thread, // Channeling added/removed → use_item/reload/... animation
+            spawn_smoke_volumes_main_thread, // SmokeCloud added → vision-blocker body (LOS raycasts)
+            despawn_smoke_volumes_main_thread, // SmokeCloud removed → queue_free
+            spawn_impact_decals_main_thread, // ImpactDecal added → Decal (кровь / подпалина)
+            update_weapon_heat_glow_main_thread, // WeaponHeatChanged → свечение ствола энергооружия
+            despawn_impact_decals_main_thread, // ImpactDecal removed (бюджет) → queue_free
+            despawn_world_item_visuals_main_thread, // WorldItem removed → queue_free
+            detect_flash_exposure_main_thread, // FlashbangDetonated → FlashExposure (дистанция + взгляд)
+            update_flash_overlay_main_thread, // PlayerBlinded → засветка экрана + fade
+            sync_backup_call_labels_main_thread, // BackupCallStarted → красная метка радиста (telegraph)
+            update_nameplates_main_thread, // Фракция + обнаружение / урон → видимость меток, дистанция → alpha
+        ),
+    );
+
+    // 4.2 Update schedule - Security + interaction + doors + environment zones (interact → use/hack/breach, director → подкрепления / орда)
+    app.add_systems(
+        Update,
+        (
+            crate::input::player_interact_input, // [F] → hack / pickup / drop objective / supply drop, [B] → BreachDoorIntent
+            super::director::spawn_reinforcements, // ReinforcementsRequested → NPC фракции у панели
+            super::director::spawn_horde, // HordeSpawnRequested → волна орды + элитный лидер
+            super::director::spawn_world_event_forces, // WorldEventStarted → элитный патруль / рейд фракции
+            crate::interaction::player_interact_raycast_main_thread, // [F] → raycast камеры → InteractIntent
+            crate::scan::player_scan_input_main_thread, // [T] удержание → ScanIntent, отпущена → CancelScanIntent
+            crate::companions::player_companion_command_input_main_thread, // [F5-F8] → CompanionCommandIntent (F7 — цель под прицелом)
+            crate::interaction::register_interactables_main_thread, // Ноды interactables → Pickup / Switch entities
+            crate::interaction::sync_interaction_results_main_thread, // ItemPickedUp → queue_free, SwitchToggled → сигнал
+            crate::interaction::sync_loot_containers_main_thread, // Трупы с лутом ↔ цели [F]
+            crate::doors::register_breachable_doors_main_thread, // Ноды breachable_doors → Door + Interactable entities
+            crate::doors::sync_door_breaches_main_thread, // DoorToggled → открыть/закрыть, DoorBreached → queue_free полотна
+            crate::doors::sync_door_nav_links_main_thread, // Changed<Door> → NavigationLink3D проёма (заперта → выключен)
+            crate::environment::register_vacuum_zones_main_thread, // Ноды vacuum_zones → VacuumZone entities
+            crate::environment::detect_vacuum_zones_main_thread, // Overlaps → VacuumZoneEntered/Exited (ECS → InVacuum)
+            crate::environment::register_hazard_zones_main_thread, // Ноды hazard_zones → HazardZone entities
+            crate::environment::detect_hazard_zones_main_thread, // Overlaps → HazardZoneEntered/Exited (ECS → урон + статусы)
+            crate::environment::register_interior_volumes_main_thread, // Ноды interior_volumes → InteriorVolume entities
+            crate::environment::detect_interior_volumes_main_thread, // Overlaps → InteriorEntered/Exited (ECS → Sheltered)
+        ),
+    );
+
+    // 4.2.1 Update schedule - Objective items (визуал ядра, метка носителя)
+    app.add_systems(
+        Update,
+        (
+            crate::objectives::spawn_objective_visuals_main_thread, // ObjectiveItem added → куб-визуал
+            crate::objectives::sync_objective_visuals_main_thread, // Земля / над носителем, Captured → queue_free
+            sync_objective_carrier_labels_main_thread, // ObjectivePickedUp → оранжевая метка носителя
+        ),
+    );
+
+    // 4.2.2 Update schedule - Arena duel (сброс позиций раунда, overlay статистики)
+    app.add_systems(
+        Update,
+        (
+            sync_arena_round_resets_main_thread, // ArenaRoundReset → телепорт бойцов на старт
+            update_arena_overlay_main_thread,    // GameMode::Arena → счёт / фаза / статистика раундов
+        ),
+    );
+
+    // 4.2.2.1 Update schedule - Compass HUD (yaw → ECS Compass → полоса)
+    app.add_systems(
+        Update,
+        (
+            sync_camera_yaw_main_thread,      // Yaw тела игрока → CameraYaw
+            update_compass_strip_main_thread, // Compass → стороны света + маркеры на полосе
+        )
+            .chain(),
+    );
+
+    // 4.2.2.2 Update schedule - HUD панели (открытый контейнер, скан цели, подсказка туториала, callouts AI, отказ выстрела, диагностика сетапа)
+    app.add_systems(
+        Update,
+        (
+            update_container_panel_main_thread, // OpenContainer игрока → список предметов
+            update_scan_panel_main_thread,      // Channeling(Scan) → прогресс, ScanCache фокус → отчёт
+            update_tutorial_prompt_main_thread, // TutorialState → подсказка шага + прогресс
+            update_chatter_subtitles_main_thread, // Callout → дистанция + стены → субтитр (разборчиво / глухо)
+            update_fire_feedback_main_thread,     // FireDenied игрока → причина под прицелом
+            update_debug_diagnostics_main_thread, // ExpectationLog → блок диагностики DebugOverlay (F3)
+        ),
+    );
+
+    // 4.2.3 Update schedule - Supply drops (падение, пыль удара, замок, вскрытие)
+    app.add_systems(
+        Update,
+        (
+            crate::supply_drops::spawn_supply_drop_visuals_main_thread, // SupplyDrop added → ящик на высоте сброса
+            crate::supply_drops::sync_supply_drop_visuals_main_thread, // Падение, SupplyDropLanded → пыль, Unlocked → зелёный
+            crate::supply_drops::despawn_supply_drop_visuals_main_thread, // Вскрыт (despawn) → queue_free
+        )
+            .chain(),
+    );
+
+    // 4.3 Update schedule - Stance (Ctrl/Z → Stance → высота капсулы)
+    app.add_systems(
+        Update,
+        sync_stance_collision_main_thread.after(crate::input::process_player_input),
+    );
+
+    // 4.4 Update schedule - ADS прицел на акторе → GodotAIEvent::AimedAt (AI чувствует, что в него целятся)
+    app.add_systems(
+        Update,
+        detect_aimed_at_main_thread.after(update_ads_position_transition),
+    );
+
+    // 4.5 Update schedule - Отдача (RecoilState → подброс камеры поверх mouse look, ADS руки следуют за камерой)
+    app.add_systems(
+        Update,
+        apply_recoil_kick_main_thread
+            .after(player_mouse_look)
+            .before(update_ads_position_transition),
+    );
+
+    // 4.6 Update schedule - ADS зум (AimMode + AdsConfig оружия → FOV PlayerCamera)
+    app.add_systems(
+        Update,
+        update_ads_fov_main_thread.after(update_ads_position_transition),
+    );
+
+    // 4.7 Update schedule - Покачивание оружия (поверх прицеливания RightHand, до выстрела по стволу)
+    app.add_systems(
+        Update,
+        apply_weapon_sway_main_thread
+            .after(update_ads_position_transition)
+            .after(player_hip_fire_aim)
+            .before(weapon_fire_main_thread),
+    );
+
+    // 4.8 Update schedule - Наклон (Q/E → LeanIntent; LeanState → крен Head/Torso до ADS позиции рук)
+    app.add_systems(
+        Update,
+        (
+            crate::input::player_lean_input,
+            sync_lean_visuals_main_thread.before(update_ads_position_transition),
+        ),
+    );
+
+    // 4.9 Update schedule - EMP (EmpDisrupted → помехи на щите; энергия/collision — системы Shield VFX выше)
+    app.add_systems(
+        Update,
+        update_shield_emp_vfx_main_thread.after(update_shield_energy_vfx_main_thread),
+    );
+
+    // 4.10 Update schedule - Толчок щитом (C → ShieldBashIntent; анимация/цели — melee системы)
+    app.add_systems(Update, crate::input::player_shield_bash_input);
+
+    // 4.11 Update schedule - RTS выделение (LMB клик / рамка → Selected, RMB → UnitOrderIntent; кольца выделенных)
+    app.add_systems(
+        Update,
+        (
+            crate::rts_selection::rts_selection_input_main_thread,
+            crate::rts_selection::sync_selection_rings_main_thread,
+        )
+            .chain(),
+    );
+
+    // 4.12 Update schedule - Тактическая пауза (SimulationState → заморозка снарядов; приказы копятся в ECS) + масштаб времени
+    app.add_systems(
+        Update,
+        (
+            crate::tactical_pause::freeze_projectiles_on_pause_main_thread,
+            crate::tactical_pause::sync_engine_time_scale_main_thread, // TimeScale → Engine.time_scale
+        ),
+    );
+
+    // 5. Update schedule - Combat systems (стоят на паузе / вне Playing)
     app.add_systems(
         Update,
         (
@@ -153,13 +380,32 @@ pub fn register_systems(app: &mut App) {
             weapon_fire_main_thread,                 // WeaponFired → spawn GodotProjectile
             projectile_collision_system_main_thread, // Projectile → body collision (event-driven)
             projectile_shield_collision_main_thread, // Projectile → shield collision (Area3D)
+            projectile_near_miss_detection_main_thread, // Projectile пролетела рядом → ProjectileNearMiss (suppression)
+            track_actor_motion_main_thread, // Скорость акторов из последних позиций (melee intercept)
+            update_ai_blackboards_main_thread // Цель/скорости/knockdown/cooldown → Blackboard (для decision)
+                .after(track_actor_motion_main_thread)
+                .before(ai_melee_combat_decision_main_thread),
             ai_melee_combat_decision_main_thread, // Unified AI melee combat decision (attack/parry/wait)
             process_melee_attack_intents_main_thread, // MeleeAttackIntent → tactical validation → MeleeAttackStarted
             execute_melee_attacks_main_thread, // MeleeAttackState phases → animation + hitbox
             execute_parry_animations_main_thread, // ParryState changed → play melee_parry/melee_parry_recover animations
             execute_stagger_animations_main_thread, // StaggerState added → interrupt attack, play RESET
+            execute_flinch_animations_main_thread, // FlinchTriggered → light/heavy flinch / knockdown animation
+            execute_knockdown_getup_main_thread, // KnockdownGetUp → get_up animation
             poll_melee_hitboxes_main_thread, // Poll hitbox overlaps during ActiveHitbox phase → MeleeHit events
-        ),
+        )
+            .run_if(tactical_layer_active),
+    );
+
+    // 5.1 Update schedule - Thrown weapons (ThrowableLaunched → полёт → MeleeHit + ThrowableLanded)
+    app.add_systems(
+        Update,
+        (
+            spawn_thrown_items_main_thread,          // ThrowableLaunched → GodotThrownItem по дуге
+            process_thrown_item_impacts_main_thread, // Удар → MeleeHit (актор) + ThrowableLanded → WorldItem
+        )
+            .chain()
+            .run_if(tactical_layer_active),
     );
 
     // 6. SlowUpdate schedule (3 Hz = ~3 раза в секунду)
@@ -167,7 +413,8 @@ pub fn register_systems(app: &mut App) {
     app.add_systems(
         SlowUpdate,
         (
-            poll_vision_cones_main_thread,     // VisionCone → GodotAIEvent
+            sample_light_levels_main_thread,   // Освещённость + скорость акторов → StealthSampled
+            poll_vision_cones_main_thread,     // VisionCone → GodotAIEvent (порог Visibility)
             update_combat_targets_main_thread, // Dynamic target switching (closest visible spotted enemy)
         )
             .chain(),
@@ -0,0 +1,88 @@
+//! Smoke volumes — ECS SmokeCloud → Godot vision-blocker body.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - Added<SmokeCloud> → StaticBody3D (layer VISION_BLOCKERS) + сфера-визуал
+//! - RemovedComponents<SmokeCloud> → queue_free
+//!
+//! LOS raycasts (COLLISION_MASK_RAYCAST_LOS) упираются в body → цель "за дымом".
+//! VisionCone (Area3D overlap) дым не видит — там фильтр по SmokeOcclusionMap.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{
+    CollisionShape3D, Material, Mesh, MeshInstance3D, Shape3D, SphereMesh, SphereShape3D,
+    StandardMaterial3D, StaticBody3D,
+};
+use voidrun_simulation::combat::SmokeCloud;
+use voidrun_simulation::logger;
+use std::collections::HashMap;
+
+use crate::shared::collision::{COLLISION_LAYER_VISION_BLOCKERS, COLLISION_MASK_VISION_BLOCKERS};
+use crate::shared::SceneRoot;
+
+/// Прозрачность визуала дыма (0 = непрозрачный, 1 = невидимый)
+const SMOKE_TRANSPARENCY: f32 = 0.35;
+
+/// Registry: SmokeCloud entity → Godot body
+///
+/// NonSend resource — main thread only (Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct SmokeVolumeRegistry {
+    pub volumes: HashMap<Entity, Gd<StaticBody3D>>,
+}
+
+/// System: Added<SmokeCloud> → spawn vision-blocker volume
+pub fn spawn_smoke_volumes_main_thread(
+    clouds: Query<(Entity, &SmokeCloud), Added<SmokeCloud>>,
+    mut registry: NonSendMut<SmokeVolumeRegistry>,
+    scene_root: NonSend<SceneRoot>,
+) {
+    for (entity, cloud) in clouds.iter() {
+        let mut body = StaticBody3D::new_alloc();
+        body.set_collision_layer(COLLISION_LAYER_VISION_BLOCKERS);
+        body.set_collision_mask(COLLISION_MASK_VISION_BLOCKERS);
+
+        // Коллизия (сфера) — только для LOS raycasts
+        let mut collision = CollisionShape3D::new_alloc();
+        let mut shape = SphereShape3D::new_gd();
+        shape.set_radius(cloud.radius);
+        collision.set_shape(&shape.upcast::<Shape3D>());
+        body.add_child(&collision.upcast::<Node>());
+
+        // Визуал: полупрозрачная серая сфера
+        let mut mesh_instance = MeshInstance3D::new_alloc();
+        let mut sphere = SphereMesh::new_gd();
+        sphere.set_radius(cloud.radius);
+        sphere.set_height(cloud.radius * 2.0);
+        mesh_instance.set_mesh(&sphere.upcast::<Mesh>());
+
+        let mut material = StandardMaterial3D::new_gd();
+        material.set_albedo(Color::from_rgb(0.7, 0.7, 0.72));
+        mesh_instance.set_surface_override_material(0, &material.upcast::<Material>());
+        mesh_instance.set_transparency(SMOKE_TRANSPARENCY);
+        body.add_child(&mesh_instance.upcast::<Node>());
+
+        scene_root.node.clone().upcast::<Node>().add_child(&body.clone().upcast::<Node>());
+        body.set_global_position(Vector3::new(cloud.center.x, cloud.center.y, cloud.center.z));
+
+        registry.volumes.insert(entity, body);
+
+        logger::log(&format!(
+            "💨 Smoke volume spawned for {:?} (radius {:.1}m)",
+            entity, cloud.radius
+        ));
+    }
+}
+
+/// System: SmokeCloud despawned → удалить volume
+pub fn despawn_smoke_volumes_main_thread(
+    mut removed: RemovedComponents<SmokeCloud>,
+    mut registry: NonSendMut<SmokeVolumeRegistry>,
+) {
+    for entity in removed.read() {
+        if let Some(mut body) = registry.volumes.remove(&entity) {
+            body.queue_free();
+            logger::log(&format!("💨 Smoke volume for {:?} dissipated", entity));
+        }
+    }
+}
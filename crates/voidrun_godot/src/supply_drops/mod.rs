@@ -0,0 +1,140 @@
+//! Supply drops — ECS SupplyDrop → Godot визуал контейнера.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - Added<SupplyDrop> → ящик-визуал на высоте сброса (красный — заперт)
+//! - Каждый кадр: позиция по `SupplyDrop::position()` (падение считает ECS)
+//! - SupplyDropLanded → облако пыли, SupplyDropUnlocked → ящик зелёный
+//! - RemovedComponents<SupplyDrop> (вскрыт) → queue_free
+//!
+//! Правила (падение, ударная волна, замок, лут) — в ECS (`voidrun_simulation::world_events`).
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{
+    base_material_3d::Feature as BaseMaterial3DFeature, cpu_particles_3d::Parameter as CpuParam,
+    BoxMesh, CpuParticles3D, Material, Mesh, MeshInstance3D, SphereMesh, StandardMaterial3D,
+};
+use voidrun_simulation::world_events::{SupplyDrop, SupplyDropLanded, SupplyDropUnlocked};
+use voidrun_simulation::logger;
+use std::collections::HashMap;
+
+use crate::shared::SceneRoot;
+
+/// Размер ящика-визуала (метры)
+const CRATE_SIZE: Vector3 = Vector3::new(1.2, 0.8, 1.2);
+
+/// Цвет свечения: заперт / открыт
+const LOCKED_COLOR: Color = Color::from_rgb(1.0, 0.2, 0.1);
+const OPEN_COLOR: Color = Color::from_rgb(0.2, 1.0, 0.3);
+
+/// Registry: SupplyDrop entity → Godot визуал
+///
+/// NonSend resource — main thread only (Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct SupplyDropVisualRegistry {
+    pub visuals: HashMap<Entity, Gd<MeshInstance3D>>,
+}
+
+/// System: Added<SupplyDrop> → spawn визуал
+pub fn spawn_supply_drop_visuals_main_thread(
+    drops: Query<(Entity, &SupplyDrop), Added<SupplyDrop>>,
+    mut registry: NonSendMut<SupplyDropVisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+) {
+    for (entity, drop) in drops.iter() {
+        let mut mesh_instance = MeshInstance3D::new_alloc();
+        let mut crate_mesh = BoxMesh::new_gd();
+        crate_mesh.set_size(CRATE_SIZE);
+        mesh_instance.set_mesh(&crate_mesh.upcast::<Mesh>());
+        set_crate_color(&mut mesh_instance, LOCKED_COLOR);
+
+        scene_root.node.clone().upcast::<Node>().add_child(&mesh_instance.clone().upcast::<Node>());
+        mesh_instance.set_global_position(crate_position(drop));
+
+        registry.visuals.insert(entity, mesh_instance);
+
+        logger::log(&format!(
+            "📦 Supply drop visual spawned for {:?} (landing at {:?})",
+            entity, drop.landing_position
+        ));
+    }
+}
+
+/// System: позиция ящика (падение), пыль при ударе, цвет при открытии замка
+pub fn sync_supply_drop_visuals_main_thread(
+    drops: Query<(Entity, &SupplyDrop)>,
+    mut landed_events: EventReader<SupplyDropLanded>,
+    mut unlocked_events: EventReader<SupplyDropUnlocked>,
+    mut registry: NonSendMut<SupplyDropVisualRegistry>,
+) {
+    for (entity, drop) in drops.iter() {
+        if let Some(visual) = registry.visuals.get_mut(&entity) {
+            visual.set_global_position(crate_position(drop));
+        }
+    }
+
+    for event in landed_events.read() {
+        if let Some(visual) = registry.visuals.get_mut(&event.drop) {
+            visual.add_child(&create_landing_dust().upcast::<Node>());
+        }
+    }
+
+    for event in unlocked_events.read() {
+        if let Some(visual) = registry.visuals.get_mut(&event.drop) {
+            set_crate_color(visual, OPEN_COLOR);
+        }
+    }
+}
+
+/// System: RemovedComponents<SupplyDrop> → queue_free визуала
+pub fn despawn_supply_drop_visuals_main_thread(
+    mut removed: RemovedComponents<SupplyDrop>,
+    mut registry: NonSendMut<SupplyDropVisualRegistry>,
+) {
+    for entity in removed.read() {
+        if let Some(mut visual) = registry.visuals.remove(&entity) {
+            visual.queue_free();
+        }
+    }
+}
+
+/// Центр ящика (дно — на `SupplyDrop::position()`)
+fn crate_position(drop: &SupplyDrop) -> Vector3 {
+    let position = drop.position();
+    Vector3::new(position.x, position.y + CRATE_SIZE.y * 0.5, position.z)
+}
+
+fn set_crate_color(mesh_instance: &mut Gd<MeshInstance3D>, emission: Color) {
+    let mut material = StandardMaterial3D::new_gd();
+    material.set_albedo(Color::from_rgb(0.35, 0.35, 0.3));
+    material.set_feature(BaseMaterial3DFeature::EMISSION, true);
+    material.set_emission(emission);
+    mesh_instance.set_surface_override_material(0, &material.upcast::<Material>());
+}
+
+/// Облако пыли при ударе (one-shot, живёт вместе с ящиком)
+fn create_landing_dust() -> Gd<CpuParticles3D> {
+    let mut dust = CpuParticles3D::new_alloc();
+    dust.set_name("LandingDust");
+
+    let mut sphere_mesh = SphereMesh::new_gd();
+    sphere_mesh.set_radius(0.15);
+    sphere_mesh.set_height(0.3);
+    dust.set_mesh(&sphere_mesh.upcast::<Mesh>());
+
+    let mut material = StandardMaterial3D::new_gd();
+    material.set_albedo(Color::from_rgb(0.6, 0.55, 0.45));
+    dust.set_material_override(&material.upcast::<Material>());
+
+    dust.set_amount(48);
+    dust.set_lifetime(0.8);
+    dust.set_one_shot(true);
+    dust.set_explosiveness_ratio(1.0);
+    dust.set_direction(Vector3::UP);
+    dust.set_spread(80.0);
+    dust.set_param_min(CpuParam::INITIAL_LINEAR_VELOCITY, 3.0);
+    dust.set_param_max(CpuParam::INITIAL_LINEAR_VELOCITY, 6.0);
+    dust.set_emitting(true);
+
+    dust
+}
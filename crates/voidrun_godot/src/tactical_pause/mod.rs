@@ -0,0 +1,63 @@
+//! Tactical pause — заморозка Godot tactical слоя на паузе симуляции + масштаб времени.
+//!
+//! Architecture: ADR-004 (NonSend resources, _main_thread naming)
+//! - ECS `SimulationState::Paused` → FixedUpdate стоит, приказы копятся (`voidrun_simulation::time_control`)
+//! - Движение / бой (Update) гейтятся `tactical_layer_active` в `systems_setup`
+//!   (тактическая пауза или `GameState` вне Playing — меню, загрузка, game over)
+//! - Снаряды двигаются в собственном `physics_process` → ProcessMode DISABLED до resume
+//!
+//! - ECS `TimeScale` → `Engine.time_scale` (движение тел, снаряды, анимации в том же темпе,
+//!   что и тики симуляции)
+//!
+//! Камера, RTS выделение, ввод приказов и UI на паузе работают.
+
+use bevy::prelude::*;
+use godot::classes::node::ProcessMode;
+use godot::classes::Engine;
+use voidrun_simulation::game_state::GameState;
+use voidrun_simulation::time_control::{SimulationState, TimeScale};
+
+use crate::projectiles::{GodotProjectileRegistry, ThrownItemRegistry};
+
+/// Run condition: Godot tactical слой (движение, бой) работает — нет паузы и `GameState::Playing`
+pub fn tactical_layer_active(state: Res<SimulationState>, game_state: Res<State<GameState>>) -> bool {
+    !state.is_paused() && *game_state.get() == GameState::Playing
+}
+
+/// System: SimulationState сменилось → заморозить / отпустить снаряды и брошенные предметы
+pub fn freeze_projectiles_on_pause_main_thread(
+    state: Res<SimulationState>,
+    mut projectiles: NonSendMut<GodotProjectileRegistry>,
+    mut thrown_items: NonSendMut<ThrownItemRegistry>,
+) {
+    if !state.is_changed() {
+        return;
+    }
+
+    let mode = if state.is_paused() {
+        ProcessMode::DISABLED
+    } else {
+        ProcessMode::INHERIT
+    };
+
+    projectiles.cleanup_destroyed();
+    for projectile in projectiles.projectiles.values_mut() {
+        projectile.set_process_mode(mode);
+    }
+
+    thrown_items.cleanup_destroyed();
+    for item in thrown_items.items.values_mut() {
+        item.set_process_mode(mode);
+    }
+}
+
+/// System: TimeScale сменился → Engine.time_scale (Godot delta / физика тел / анимации)
+///
+/// ECS масштабирует число тиков, Godot слой — свою delta (tactical слой не детерминистичен).
+pub fn sync_engine_time_scale_main_thread(scale: Res<TimeScale>) {
+    if !scale.is_changed() {
+        return;
+    }
+
+    Engine::singleton().set_time_scale(scale.get() as f64);
+}
@@ -0,0 +1,109 @@
+//! Arena overlay — счёт, фаза раунда и статистика раундов (GameMode::Arena).
+//!
+//! CanvasLayer + Label создаются лениво при первом кадре arena матча,
+//! скрываются вне режима Arena.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{CanvasLayer, Label};
+use godot::classes::control::MouseFilter;
+use voidrun_simulation::game_mode::{ArenaMatch, ArenaPhase, GameMode, RoundStats};
+
+use crate::shared::SceneRoot;
+
+/// Слой над игровым UI, под flash overlay
+const ARENA_CANVAS_LAYER: i32 = 50;
+
+/// Label статистики (NonSend — Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct ArenaOverlay {
+    label: Option<Gd<Label>>,
+}
+
+/// System: GameMode::Arena → текст overlay (каждый кадр)
+pub fn update_arena_overlay_main_thread(
+    game_mode: Res<GameMode>,
+    mut overlay: NonSendMut<ArenaOverlay>,
+    scene_root: NonSend<SceneRoot>,
+) {
+    let Some(arena) = game_mode.arena_match() else {
+        if let Some(label) = overlay.label.as_mut() {
+            label.set_visible(false);
+        }
+        return;
+    };
+
+    let mut label = match overlay.label.clone() {
+        Some(label) => label,
+        None => {
+            let label = create_arena_label(&scene_root);
+            overlay.label = Some(label.clone());
+            label
+        }
+    };
+
+    label.set_text(&format_arena_text(arena));
+    label.set_visible(true);
+}
+
+/// Текст overlay: счёт, фаза, текущий раунд + история
+fn format_arena_text(arena: &ArenaMatch) -> String {
+    let phase = match arena.phase {
+        ArenaPhase::Countdown { remaining } => format!("Round {} — starts in {:.0}", arena.round, remaining.ceil()),
+        ArenaPhase::Fighting => format!(
+            "Round {} — {:.0}s left",
+            arena.round,
+            (arena.round_time_limit - arena.current.duration).max(0.0)
+        ),
+        ArenaPhase::RoundOver { .. } => format!("Round {} over", arena.round),
+        ArenaPhase::MatchOver { winner: Some(winner) } => format!("Match over — winner {:?}", winner),
+        ArenaPhase::MatchOver { winner: None } => "Match over — draw".to_string(),
+    };
+
+    let mut lines = vec![
+        format!("ARENA  {} : {}  (best of {})", arena.wins[0], arena.wins[1], arena.best_of),
+        phase,
+        format_round_stats(&arena.current),
+    ];
+    lines.extend(arena.history.iter().rev().map(format_round_stats));
+
+    lines.join("\n")
+}
+
+/// "R1  dmg 120/80  hits 6/4  parries 1/0  12.3s  → A"
+fn format_round_stats(stats: &RoundStats) -> String {
+    let winner = match stats.winner {
+        Some(0) => "  → A",
+        Some(_) => "  → B",
+        None => "",
+    };
+
+    format!(
+        "R{}  dmg {}/{}  hits {}/{}  parries {}/{}  {:.1}s{}",
+        stats.round,
+        stats.damage[0],
+        stats.damage[1],
+        stats.hits[0],
+        stats.hits[1],
+        stats.parries[0],
+        stats.parries[1],
+        stats.duration,
+        winner
+    )
+}
+
+/// CanvasLayer + Label в правом верхнем углу (не перехватывает мышь)
+fn create_arena_label(scene_root: &SceneRoot) -> Gd<Label> {
+    let mut layer = CanvasLayer::new_alloc();
+    layer.set_layer(ARENA_CANVAS_LAYER);
+
+    let mut label = Label::new_alloc();
+    label.set_position(Vector2::new(700.0, 10.0));
+    label.set_mouse_filter(MouseFilter::IGNORE);
+    label.add_theme_font_size_override("font_size", 18);
+
+    layer.add_child(&label.clone().upcast::<Node>());
+    scene_root.node.clone().upcast::<Node>().add_child(&layer.upcast::<Node>());
+
+    label
+}
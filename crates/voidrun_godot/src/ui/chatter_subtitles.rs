@@ -0,0 +1,188 @@
+//! Chatter subtitles — подслушанные callouts AI внизу экрана.
+//!
+//! ECS (`voidrun_simulation::ai::Callout`) говорит, кто и что крикнул. Здесь — слышимость для игрока:
+//! дистанция + стены между говорящим и игроком (лучи по слою окружения) → `callout_audibility`.
+//! Разборчиво — реплика и дистанция, глухо за стенами — только «неразборчиво».
+//! CanvasLayer создаётся лениво, строки гаснут через `SUBTITLE_DURATION`.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{CanvasLayer, Label, PhysicsDirectSpaceState3D, PhysicsRayQueryParameters3D, VBoxContainer};
+use godot::classes::control::MouseFilter;
+use godot::global::HorizontalAlignment;
+use voidrun_simulation::ai::{callout_audibility, Callout, CalloutClarity};
+use voidrun_simulation::player::Player;
+use voidrun_simulation::Actor;
+
+use crate::shared::collision::COLLISION_LAYER_ENVIRONMENT;
+use crate::shared::{SceneRoot, VisualRegistry};
+
+/// Слой вровень с HUD панелями
+const CHATTER_CANVAS_LAYER: i32 = 35;
+
+const PANEL_WIDTH: f32 = 520.0;
+/// Отступ снизу экрана
+const PANEL_BOTTOM: f32 = 140.0;
+/// Сколько строк видно одновременно (старые вытесняются)
+const MAX_LINES: usize = 4;
+/// Сколько висит строка (секунды)
+const SUBTITLE_DURATION: f32 = 3.5;
+/// Больше стен не считаем — голос уже не слышен
+const MAX_WALLS: u32 = 3;
+/// Высота ушей / рта над ногами
+const HEAD_HEIGHT: f32 = 1.6;
+
+/// Строка субтитра
+struct SubtitleLine {
+    label: Gd<Label>,
+    remaining: f32,
+}
+
+/// Панель субтитров (NonSend — Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct ChatterSubtitles {
+    root: Option<Gd<VBoxContainer>>,
+    lines: Vec<SubtitleLine>,
+}
+
+/// System: Callout → слышимость для игрока → строка субтитра (+ затухание старых)
+pub fn update_chatter_subtitles_main_thread(
+    mut callouts: EventReader<Callout>,
+    player: Query<(Entity, &Actor), With<Player>>,
+    speakers: Query<&Actor>,
+    visuals: NonSend<VisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut subtitles: NonSendMut<ChatterSubtitles>,
+    time: Res<crate::shared::GodotDeltaTime>,
+) {
+    let listener = player.single().ok().and_then(|(entity, actor)| {
+        let node = visuals.visuals.get(&entity)?;
+        Some((entity, actor.faction_id, node.get_global_position() + Vector3::UP * HEAD_HEIGHT))
+    });
+    let mut space = scene_root
+        .node
+        .get_world_3d()
+        .and_then(|mut world| world.get_direct_space_state());
+
+    for callout in callouts.read() {
+        let Some((player_entity, player_faction, ear)) = listener else {
+            continue;
+        };
+        if callout.speaker == player_entity {
+            continue;
+        }
+
+        let mouth = Vector3::new(callout.position.x, callout.position.y, callout.position.z)
+            + Vector3::UP * (HEAD_HEIGHT - 0.5);
+        let distance = ear.distance_to(mouth);
+        let range = callout.kind.voice_range();
+        if distance >= range {
+            continue;
+        }
+
+        let walls = space.as_mut().map_or(0, |space| count_walls(space, mouth, ear));
+        let Some(clarity) = CalloutClarity::from_audibility(callout_audibility(distance, range, walls)) else {
+            continue;
+        };
+
+        let hostile = speakers
+            .get(callout.speaker)
+            .is_ok_and(|actor| actor.faction_id != player_faction);
+        let text = match clarity {
+            CalloutClarity::Clear => format!("{}  ({:.0}м)", callout.kind.line(), distance),
+            CalloutClarity::Muffled => "…неразборчивые голоса за стеной…".to_string(),
+        };
+        let color = match (clarity, hostile) {
+            (CalloutClarity::Muffled, _) => Color::from_rgb(0.6, 0.6, 0.6),
+            (CalloutClarity::Clear, true) => Color::from_rgb(1.0, 0.5, 0.4),
+            (CalloutClarity::Clear, false) => Color::from_rgb(0.6, 0.85, 1.0),
+        };
+
+        push_line(&mut subtitles, &scene_root, &text, color);
+    }
+
+    for line in subtitles.lines.iter_mut() {
+        line.remaining -= time.0;
+        let alpha = (line.remaining / SUBTITLE_DURATION * 3.0).clamp(0.0, 1.0);
+        line.label.set_modulate(Color::from_rgba(1.0, 1.0, 1.0, alpha));
+    }
+    subtitles.lines.retain_mut(|line| {
+        if line.remaining > 0.0 {
+            return true;
+        }
+        line.label.queue_free();
+        false
+    });
+}
+
+/// Сколько стен (слой окружения) между точками: луч, исключая уже пробитые
+fn count_walls(space: &mut Gd<PhysicsDirectSpaceState3D>, from: Vector3, to: Vector3) -> u32 {
+    let mut exclude: Array<Rid> = Array::new();
+
+    for walls in 0..MAX_WALLS {
+        let Some(mut query) = PhysicsRayQueryParameters3D::create(from, to) else {
+            return walls;
+        };
+        query.set_collision_mask(COLLISION_LAYER_ENVIRONMENT);
+        query.set_exclude(&exclude);
+
+        let result = space.intersect_ray(&query);
+        let Some(rid) = result.get("rid").and_then(|rid| rid.try_to::<Rid>().ok()) else {
+            return walls;
+        };
+        exclude.push(rid);
+    }
+
+    MAX_WALLS
+}
+
+/// Новая строка снизу; лишние сверху удаляются
+fn push_line(subtitles: &mut ChatterSubtitles, scene_root: &SceneRoot, text: &str, color: Color) {
+    let mut root = match subtitles.root.clone() {
+        Some(root) => root,
+        None => {
+            let root = create_panel(scene_root);
+            subtitles.root = Some(root.clone());
+            root
+        }
+    };
+
+    let mut label = Label::new_alloc();
+    label.set_text(text);
+    label.set_horizontal_alignment(HorizontalAlignment::CENTER);
+    label.add_theme_font_size_override("font_size", 16);
+    label.add_theme_color_override("font_color", color);
+    label.set_mouse_filter(MouseFilter::IGNORE);
+    root.add_child(&label.clone().upcast::<Node>());
+
+    subtitles.lines.push(SubtitleLine { label, remaining: SUBTITLE_DURATION });
+    while subtitles.lines.len() > MAX_LINES {
+        let mut oldest = subtitles.lines.remove(0);
+        oldest.label.queue_free();
+    }
+}
+
+/// CanvasLayer + столбец строк по центру снизу (не перехватывает мышь)
+fn create_panel(scene_root: &SceneRoot) -> Gd<VBoxContainer> {
+    let mut layer = CanvasLayer::new_alloc();
+    layer.set_layer(CHATTER_CANVAS_LAYER);
+
+    let viewport_size = scene_root
+        .node
+        .get_viewport()
+        .map(|viewport| viewport.get_visible_rect().size)
+        .unwrap_or(Vector2::new(1280.0, 720.0));
+
+    let mut column = VBoxContainer::new_alloc();
+    column.set_position(Vector2::new(
+        (viewport_size.x - PANEL_WIDTH) * 0.5,
+        viewport_size.y - PANEL_BOTTOM,
+    ));
+    column.set_size(Vector2::new(PANEL_WIDTH, 0.0));
+    column.set_mouse_filter(MouseFilter::IGNORE);
+
+    layer.add_child(&column.clone().upcast::<Node>());
+    scene_root.node.clone().upcast::<Node>().add_child(&layer.upcast::<Node>());
+
+    column
+}
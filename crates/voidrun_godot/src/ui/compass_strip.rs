@@ -0,0 +1,171 @@
+//! Compass strip — полоса компаса вверху экрана (FPS режим).
+//!
+//! Данные (курс, стороны света, пеленги маркеров) считает ECS (`voidrun_simulation::compass`),
+//! здесь только yaw камеры → `CameraYaw` и отрисовка `Compass` resource.
+//! CanvasLayer + Label'ы создаются лениво, скрываются вне FPS режима / без игрока.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{CanvasLayer, ColorRect, Control, Label};
+use godot::classes::control::MouseFilter;
+use voidrun_simulation::compass::{CameraYaw, Compass, MarkerKind};
+use voidrun_simulation::player::Player;
+use voidrun_simulation::camera::{ActiveCamera, CameraMode};
+
+use crate::shared::{SceneRoot, VisualRegistry};
+
+/// Слой над игровым UI, под arena / flash overlay
+const COMPASS_CANVAS_LAYER: i32 = 40;
+
+/// Ширина полосы (пиксели)
+const STRIP_WIDTH: f32 = 600.0;
+const STRIP_HEIGHT: f32 = 40.0;
+/// Отступ сверху (пиксели)
+const STRIP_TOP: f32 = 8.0;
+
+/// Видимая часть круга: ±STRIP_HALF_ANGLE от курса (градусы)
+const STRIP_HALF_ANGLE: f32 = 90.0;
+
+/// Полоса + пулы меток (NonSend — Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct CompassStrip {
+    root: Option<Gd<Control>>,
+    cardinals: Vec<Gd<Label>>,
+    markers: Vec<Gd<Label>>,
+}
+
+/// System: yaw тела игрока (mouse look вращает тело) → CameraYaw
+pub fn sync_camera_yaw_main_thread(
+    player: Query<Entity, With<Player>>,
+    visuals: NonSend<VisualRegistry>,
+    mut yaw: ResMut<CameraYaw>,
+) {
+    let Some(player_node) = player.single().ok().and_then(|entity| visuals.visuals.get(&entity)) else {
+        return;
+    };
+
+    let rotation_y = player_node.get_rotation().y;
+    if yaw.0 != rotation_y {
+        yaw.0 = rotation_y;
+    }
+}
+
+/// System: Compass → полоса (каждый кадр)
+pub fn update_compass_strip_main_thread(
+    compass: Res<Compass>,
+    player: Query<&ActiveCamera, With<Player>>,
+    mut strip: NonSendMut<CompassStrip>,
+    scene_root: NonSend<SceneRoot>,
+) {
+    let fps_mode = player.single().is_ok_and(|camera| camera.mode == CameraMode::FirstPerson);
+    if !compass.active || !fps_mode {
+        if let Some(root) = strip.root.as_mut() {
+            root.set_visible(false);
+        }
+        return;
+    }
+
+    let root = match strip.root.clone() {
+        Some(root) => root,
+        None => {
+            let root = create_strip(&scene_root);
+            strip.root = Some(root.clone());
+            root
+        }
+    };
+
+    let strip = &mut *strip;
+
+    for (index, mark) in compass.cardinals.iter().enumerate() {
+        let mut label = pooled_label(&mut strip.cardinals, index, &root);
+        label.set_text(mark.label);
+        // Основные стороны крупнее промежуточных
+        let size = if mark.label.len() == 1 { 20 } else { 14 };
+        label.add_theme_font_size_override("font_size", size);
+        place_label(&mut label, mark.offset, 4.0, Color::WHITE);
+    }
+
+    for (index, marker) in compass.markers.iter().enumerate() {
+        let mut label = pooled_label(&mut strip.markers, index, &root);
+        label.set_text(&format!("▼ {} {:.0}m", marker.label, marker.distance));
+        label.add_theme_font_size_override("font_size", 12);
+        // За краем полосы — прижимаем к краю (направление поворота)
+        let offset = marker.offset.clamp(-STRIP_HALF_ANGLE, STRIP_HALF_ANGLE);
+        place_label(&mut label, offset, 24.0, marker_color(marker.kind));
+    }
+    for label in strip.markers.iter_mut().skip(compass.markers.len()) {
+        label.set_visible(false);
+    }
+
+    let mut root = root;
+    root.set_visible(true);
+}
+
+fn marker_color(kind: MarkerKind) -> Color {
+    match kind {
+        MarkerKind::Objective => Color::from_rgb(1.0, 0.6, 0.1),
+        MarkerKind::CapturePoint => Color::from_rgb(1.0, 0.85, 0.3),
+        MarkerKind::Extraction => Color::from_rgb(0.3, 1.0, 0.4),
+        MarkerKind::Waypoint => Color::from_rgb(0.4, 0.8, 1.0),
+    }
+}
+
+/// Label из пула (создаётся при нехватке)
+fn pooled_label(pool: &mut Vec<Gd<Label>>, index: usize, root: &Gd<Control>) -> Gd<Label> {
+    if let Some(label) = pool.get(index) {
+        return label.clone();
+    }
+
+    let mut label = Label::new_alloc();
+    label.set_mouse_filter(MouseFilter::IGNORE);
+    root.clone().add_child(&label.clone().upcast::<Node>());
+    pool.push(label.clone());
+    label
+}
+
+/// Смещение от курса → X на полосе (центр — курс), вне полосы — скрыт
+fn place_label(label: &mut Gd<Label>, offset: f32, y: f32, color: Color) {
+    if offset.abs() > STRIP_HALF_ANGLE {
+        label.set_visible(false);
+        return;
+    }
+
+    let width = label.get_size().x;
+    let x = STRIP_WIDTH * 0.5 * (1.0 + offset / STRIP_HALF_ANGLE) - width * 0.5;
+    label.set_position(Vector2::new(x, y));
+    label.set_modulate(color);
+    label.set_visible(true);
+}
+
+/// CanvasLayer + полупрозрачная полоса по центру сверху (не перехватывает мышь)
+fn create_strip(scene_root: &SceneRoot) -> Gd<Control> {
+    let mut layer = CanvasLayer::new_alloc();
+    layer.set_layer(COMPASS_CANVAS_LAYER);
+
+    let viewport_width = scene_root
+        .node
+        .get_viewport()
+        .map(|viewport| viewport.get_visible_rect().size.x)
+        .unwrap_or(1280.0);
+
+    let mut background = ColorRect::new_alloc();
+    background.set_color(Color::from_rgba(0.0, 0.0, 0.0, 0.35));
+    background.set_position(Vector2::new((viewport_width - STRIP_WIDTH) * 0.5, STRIP_TOP));
+    background.set_size(Vector2::new(STRIP_WIDTH, STRIP_HEIGHT));
+    background.set_mouse_filter(MouseFilter::IGNORE);
+    // Метки за краем полосы не рисуем
+    background.set_clip_contents(true);
+
+    // Центральная риска (курс)
+    let mut tick = ColorRect::new_alloc();
+    tick.set_color(Color::from_rgba(1.0, 1.0, 1.0, 0.8));
+    tick.set_position(Vector2::new(STRIP_WIDTH * 0.5 - 1.0, 0.0));
+    tick.set_size(Vector2::new(2.0, 6.0));
+    tick.set_mouse_filter(MouseFilter::IGNORE);
+    background.add_child(&tick.upcast::<Node>());
+
+    layer.add_child(&background.clone().upcast::<Node>());
+    scene_root.node.clone().upcast::<Node>().add_child(&layer.upcast::<Node>());
+
+    background.upcast::<Control>()
+}
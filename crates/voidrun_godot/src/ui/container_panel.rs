@@ -0,0 +1,118 @@
+//! Container panel — содержимое открытого контейнера (ящик уровня / труп).
+//!
+//! Что открыто и что внутри — ECS (`OpenContainer` на игроке + `Container`),
+//! здесь только список названий из ItemDefinitions и подсказка [F].
+//! CanvasLayer создаётся лениво, скрывается когда контейнер закрыт.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{CanvasLayer, ColorRect, Control, Label};
+use godot::classes::control::MouseFilter;
+use voidrun_simulation::interaction::{Container, OpenContainer};
+use voidrun_simulation::item_system::ItemDefinitions;
+use voidrun_simulation::player::Player;
+
+use crate::shared::SceneRoot;
+
+/// Слой под compass strip (панель не перекрывает HUD)
+const CONTAINER_CANVAS_LAYER: i32 = 35;
+
+const PANEL_WIDTH: f32 = 260.0;
+/// Высота строки списка (font_size 14)
+const PANEL_LINE_HEIGHT: f32 = 20.0;
+/// Отступ справа от центра экрана (пиксели)
+const PANEL_OFFSET_X: f32 = 80.0;
+
+/// Панель + текст (NonSend — Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct ContainerPanel {
+    root: Option<Gd<Control>>,
+    label: Option<Gd<Label>>,
+}
+
+/// System: OpenContainer игрока → список предметов
+pub fn update_container_panel_main_thread(
+    player: Query<Option<&OpenContainer>, With<Player>>,
+    containers: Query<&Container>,
+    definitions: Res<ItemDefinitions>,
+    mut panel: NonSendMut<ContainerPanel>,
+    scene_root: NonSend<SceneRoot>,
+) {
+    let container = player
+        .single()
+        .ok()
+        .flatten()
+        .and_then(|open| containers.get(open.container).ok());
+
+    let Some(container) = container else {
+        if let Some(root) = panel.root.as_mut() {
+            root.set_visible(false);
+        }
+        return;
+    };
+
+    if panel.root.is_none() {
+        let (root, label) = create_panel(&scene_root);
+        panel.root = Some(root);
+        panel.label = Some(label);
+    }
+
+    let mut text = String::from("CONTAINER\n");
+    if container.is_empty() {
+        text.push_str("  (empty)\n");
+    }
+    for item in container.items.iter() {
+        let name = definitions
+            .get(&item.definition_id)
+            .map(|definition| definition.name.as_str())
+            .unwrap_or(item.definition_id.0.as_str());
+        if item.stack_size > 1 {
+            text.push_str(&format!("  {} ×{}\n", name, item.stack_size));
+        } else {
+            text.push_str(&format!("  {}\n", name));
+        }
+    }
+    text.push_str("\n[F] Take all");
+
+    if let Some(label) = panel.label.as_mut() {
+        label.set_text(&text);
+    }
+    if let Some(root) = panel.root.as_mut() {
+        // Высота фона — по числу строк
+        let lines = text.lines().count() as f32;
+        root.set_size(Vector2::new(PANEL_WIDTH, lines * PANEL_LINE_HEIGHT + 16.0));
+        root.set_visible(true);
+    }
+}
+
+/// CanvasLayer + полупрозрачный фон правее прицела (не перехватывает мышь)
+fn create_panel(scene_root: &SceneRoot) -> (Gd<Control>, Gd<Label>) {
+    let mut layer = CanvasLayer::new_alloc();
+    layer.set_layer(CONTAINER_CANVAS_LAYER);
+
+    let viewport_size = scene_root
+        .node
+        .get_viewport()
+        .map(|viewport| viewport.get_visible_rect().size)
+        .unwrap_or(Vector2::new(1280.0, 720.0));
+
+    let mut background = ColorRect::new_alloc();
+    background.set_color(Color::from_rgba(0.0, 0.0, 0.0, 0.55));
+    background.set_position(Vector2::new(
+        viewport_size.x * 0.5 + PANEL_OFFSET_X,
+        viewport_size.y * 0.35,
+    ));
+    background.set_mouse_filter(MouseFilter::IGNORE);
+
+    let mut label = Label::new_alloc();
+    label.set_position(Vector2::new(12.0, 8.0));
+    label.add_theme_font_size_override("font_size", 14);
+    label.set_mouse_filter(MouseFilter::IGNORE);
+    background.add_child(&label.clone().upcast::<Node>());
+    background.set_size(Vector2::new(PANEL_WIDTH, 0.0));
+
+    layer.add_child(&background.clone().upcast::<Node>());
+    scene_root.node.clone().upcast::<Node>().add_child(&layer.upcast::<Node>());
+
+    (background.upcast::<Control>(), label)
+}
@@ -1,12 +1,13 @@
-//! Debug overlay UI — FPS counter, spawn buttons, AI state logger
+//! Debug overlay UI — FPS counter, spawn buttons, AI state logger, setup diagnostics
 //!
 //! Отдельный Godot node (Control) для debug информации.
 //! Создаётся SimulationBridge в ready(), toggle с F3.
 
+use bevy::prelude::{Local, NonSendMut, Res};
 use godot::classes::{Button, Control, IControl, InputEvent, InputEventKey, Label};
 use godot::global::Key;
 use godot::prelude::*;
-use voidrun_simulation::logger;
+use voidrun_simulation::{logger, ExpectationLog};
 
 /// Debug overlay — UI panel с FPS counter, spawn buttons, debug info
 ///
@@ -14,7 +15,9 @@ use voidrun_simulation::logger;
 /// - FPS counter (обновляется каждые 0.2 сек)
 /// - Spawn NPCs button (вызывает callback на SimulationBridge)
 /// - Spawn Player button (вызывает callback на SimulationBridge)
+/// - Arena Duel button (1v1 best-of-3, статистика раундов — ArenaOverlay)
 /// - AI state debug logger (каждую секунду, если enabled)
+/// - Setup diagnostics — промахи Godot lookup'ов (ExpectationLog, обновляет ECS система)
 /// - F3 toggle — показать/скрыть весь overlay
 ///
 /// # Архитектура
@@ -35,6 +38,15 @@ pub struct DebugOverlay {
     /// Spawn Player button
     player_button: Option<Gd<Button>>,
 
+    /// Arena Duel button
+    arena_button: Option<Gd<Button>>,
+
+    /// Restart Tutorial button
+    tutorial_button: Option<Gd<Button>>,
+
+    /// Setup diagnostics (ExpectationFailed: система, node path, счётчик)
+    diagnostics_label: Option<Gd<Label>>,
+
     /// FPS timer (для обновления каждые 0.2 сек)
     fps_timer: f32,
 
@@ -54,6 +66,9 @@ impl IControl for DebugOverlay {
             fps_label: None,
             spawn_button: None,
             player_button: None,
+            arena_button: None,
+            tutorial_button: None,
+            diagnostics_label: None,
             fps_timer: 0.0,
             frame_count: 0,
             simulation_bridge_path: GString::from(""),
@@ -129,6 +144,43 @@ impl DebugOverlay {
         self.base_mut()
             .add_child(&player_button.clone().upcast::<Node>());
         self.player_button = Some(player_button);
+
+        // === Arena Duel Button (top-left, below Spawn Player) ===
+        let mut arena_button = Button::new_alloc();
+        arena_button.set_text("Arena Duel");
+        arena_button.set_position(Vector2::new(10.0, 140.0));
+        arena_button.set_size(Vector2::new(150.0, 40.0));
+
+        self.base_mut()
+            .add_child(&arena_button.clone().upcast::<Node>());
+        self.arena_button = Some(arena_button);
+
+        // === Restart Tutorial Button (top-left, below Arena Duel) ===
+        let mut tutorial_button = Button::new_alloc();
+        tutorial_button.set_text("Restart Tutorial");
+        tutorial_button.set_position(Vector2::new(10.0, 190.0));
+        tutorial_button.set_size(Vector2::new(150.0, 40.0));
+
+        self.base_mut()
+            .add_child(&tutorial_button.clone().upcast::<Node>());
+        self.tutorial_button = Some(tutorial_button);
+
+        // === Setup diagnostics (top-left, below buttons) ===
+        let mut diagnostics_label = Label::new_alloc();
+        diagnostics_label.set_position(Vector2::new(10.0, 240.0));
+        diagnostics_label.add_theme_font_size_override("font_size", 14);
+        diagnostics_label.add_theme_color_override("font_color", Color::from_rgb(1.0, 0.6, 0.3));
+
+        self.base_mut()
+            .add_child(&diagnostics_label.clone().upcast::<Node>());
+        self.diagnostics_label = Some(diagnostics_label);
+    }
+
+    /// Обновить блок диагностики (пустой текст — блок скрыт)
+    pub fn set_diagnostics(&mut self, text: &str) {
+        if let Some(label) = self.diagnostics_label.as_mut() {
+            label.set_text(text);
+        }
     }
 
     /// Подключить button signals к SimulationBridge методам
@@ -162,6 +214,18 @@ impl DebugOverlay {
             button.connect("pressed", &callable);
         }
 
+        // Arena Duel button → SimulationBridge::start_arena_duel()
+        if let Some(mut button) = self.arena_button.as_mut() {
+            let callable = bridge.callable("start_arena_duel");
+            button.connect("pressed", &callable);
+        }
+
+        // Restart Tutorial button → SimulationBridge::restart_tutorial()
+        if let Some(mut button) = self.tutorial_button.as_mut() {
+            let callable = bridge.callable("restart_tutorial");
+            button.connect("pressed", &callable);
+        }
+
         logger::log("✅ DebugOverlay: buttons connected to SimulationBridge");
     }
 
@@ -184,3 +248,39 @@ impl DebugOverlay {
         }
     }
 }
+
+/// Handle на DebugOverlay для ECS систем (NonSend — Gd<T> не Send+Sync)
+pub struct DebugOverlayHandle {
+    pub overlay: Gd<DebugOverlay>,
+}
+
+/// System: ExpectationLog → блок диагностики в DebugOverlay (только при изменении журнала)
+pub fn update_debug_diagnostics_main_thread(
+    log: Res<ExpectationLog>,
+    mut handle: NonSendMut<DebugOverlayHandle>,
+    mut shown: Local<(usize, u32)>,
+) {
+    let total: u32 = log.records.iter().map(|record| record.count).sum();
+    if *shown == (log.records.len(), total) {
+        return;
+    }
+    *shown = (log.records.len(), total);
+
+    let text = if log.records.is_empty() {
+        String::new()
+    } else {
+        let lines: Vec<String> = log
+            .records
+            .iter()
+            .map(|record| {
+                format!(
+                    "{}: {} ×{} (last {:?})",
+                    record.system, record.reason, record.count, record.last_entity
+                )
+            })
+            .collect();
+        format!("⚠️ Setup expectations failed:\n{}", lines.join("\n"))
+    };
+
+    handle.overlay.bind_mut().set_diagnostics(&text);
+}
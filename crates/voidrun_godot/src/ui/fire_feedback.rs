@@ -0,0 +1,107 @@
+//! Fire feedback — почему выстрел игрока не состоялся (под прицелом).
+//!
+//! ECS (`voidrun_simulation::combat::FireDenied`) отклоняет спуск по тем же правилам, что и у AI
+//! (`fire_block`). Здесь — короткая надпись под прицелом, гаснет через `FEEDBACK_DURATION`.
+//! Cooldown молчит: обычный темп стрельбы — не ошибка.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{CanvasLayer, Label};
+use godot::classes::control::MouseFilter;
+use godot::global::HorizontalAlignment;
+use voidrun_simulation::combat::{FireBlock, FireDenied};
+use voidrun_simulation::player::Player;
+
+use crate::shared::SceneRoot;
+
+/// Слой вровень с HUD панелями
+const FEEDBACK_CANVAS_LAYER: i32 = 35;
+
+const LABEL_WIDTH: f32 = 320.0;
+/// Отступ вниз от центра экрана (под прицелом)
+const LABEL_OFFSET_Y: f32 = 40.0;
+/// Сколько висит надпись (секунды)
+const FEEDBACK_DURATION: f32 = 1.2;
+
+/// Надпись под прицелом (NonSend — Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct FireFeedback {
+    label: Option<Gd<Label>>,
+    remaining: f32,
+}
+
+/// System: FireDenied игрока → надпись под прицелом (+ затухание)
+pub fn update_fire_feedback_main_thread(
+    mut denied: EventReader<FireDenied>,
+    player: Query<Entity, With<Player>>,
+    scene_root: NonSend<SceneRoot>,
+    mut feedback: NonSendMut<FireFeedback>,
+    time: Res<crate::shared::GodotDeltaTime>,
+) {
+    let player_entity = player.single().ok();
+
+    let latest = denied
+        .read()
+        .filter(|event| Some(event.shooter) == player_entity)
+        .filter_map(|event| feedback_text(event.reason))
+        .last();
+
+    if let Some(text) = latest {
+        let mut label = match feedback.label.clone() {
+            Some(label) => label,
+            None => {
+                let label = create_label(&scene_root);
+                feedback.label = Some(label.clone());
+                label
+            }
+        };
+        label.set_text(text);
+        feedback.remaining = FEEDBACK_DURATION;
+    }
+
+    let Some(mut label) = feedback.label.clone() else {
+        return;
+    };
+    feedback.remaining = (feedback.remaining - time.0).max(0.0);
+    let alpha = (feedback.remaining / FEEDBACK_DURATION * 3.0).clamp(0.0, 1.0);
+    label.set_modulate(Color::from_rgba(1.0, 1.0, 1.0, alpha));
+}
+
+/// Текст причины (None — не показываем)
+fn feedback_text(reason: FireBlock) -> Option<&'static str> {
+    match reason {
+        FireBlock::Cooldown => None,
+        FireBlock::Overheated => Some("ПЕРЕГРЕВ"),
+        FireBlock::Jammed => Some("ЗАКЛИНИЛО"),
+        FireBlock::OutOfAmmo => Some("НЕТ ПАТРОНОВ"),
+        FireBlock::Busy => Some("НЕ ДО СТРЕЛЬБЫ"),
+    }
+}
+
+/// CanvasLayer + Label под центром экрана (не перехватывает мышь)
+fn create_label(scene_root: &SceneRoot) -> Gd<Label> {
+    let mut layer = CanvasLayer::new_alloc();
+    layer.set_layer(FEEDBACK_CANVAS_LAYER);
+
+    let viewport_size = scene_root
+        .node
+        .get_viewport()
+        .map(|viewport| viewport.get_visible_rect().size)
+        .unwrap_or(Vector2::new(1280.0, 720.0));
+
+    let mut label = Label::new_alloc();
+    label.set_position(Vector2::new(
+        (viewport_size.x - LABEL_WIDTH) * 0.5,
+        viewport_size.y * 0.5 + LABEL_OFFSET_Y,
+    ));
+    label.set_size(Vector2::new(LABEL_WIDTH, 0.0));
+    label.set_horizontal_alignment(HorizontalAlignment::CENTER);
+    label.add_theme_font_size_override("font_size", 14);
+    label.add_theme_color_override("font_color", Color::from_rgb(1.0, 0.7, 0.3));
+    label.set_mouse_filter(MouseFilter::IGNORE);
+
+    layer.add_child(&label.clone().upcast::<Node>());
+    scene_root.node.clone().upcast::<Node>().add_child(&layer.upcast::<Node>());
+
+    label
+}
@@ -0,0 +1,77 @@
+//! Flash overlay — белая засветка экрана игрока (PlayerBlinded HUD event).
+//!
+//! CanvasLayer + полноэкранный ColorRect создаются лениво при первой вспышке,
+//! alpha затухает линейно за длительность ослепления.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{CanvasLayer, ColorRect};
+use godot::classes::control::{LayoutPreset, MouseFilter};
+use voidrun_simulation::combat::PlayerBlinded;
+
+use crate::shared::SceneRoot;
+
+/// Слой поверх остального UI
+const FLASH_CANVAS_LAYER: i32 = 100;
+
+/// Состояние засветки (NonSend — Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct FlashOverlay {
+    rect: Option<Gd<ColorRect>>,
+    /// Оставшееся время fade (секунды)
+    remaining: f32,
+    duration: f32,
+    intensity: f32,
+}
+
+/// System: PlayerBlinded → засветка, затем fade out
+pub fn update_flash_overlay_main_thread(
+    mut blinded_events: EventReader<PlayerBlinded>,
+    mut overlay: NonSendMut<FlashOverlay>,
+    scene_root: NonSend<SceneRoot>,
+    time: Res<crate::shared::GodotDeltaTime>,
+) {
+    for event in blinded_events.read() {
+        // Более сильная/длинная вспышка перекрывает текущую
+        if event.intensity >= overlay.intensity * overlay.remaining / overlay.duration.max(f32::EPSILON) {
+            overlay.remaining = event.duration;
+            overlay.duration = event.duration;
+            overlay.intensity = event.intensity;
+        }
+    }
+
+    if overlay.remaining <= 0.0 {
+        return;
+    }
+
+    overlay.remaining = (overlay.remaining - time.0).max(0.0);
+    let alpha = overlay.intensity * overlay.remaining / overlay.duration.max(f32::EPSILON);
+
+    let mut rect = match overlay.rect.clone() {
+        Some(rect) => rect,
+        None => {
+            let rect = create_flash_rect(&scene_root);
+            overlay.rect = Some(rect.clone());
+            rect
+        }
+    };
+
+    rect.set_color(Color::from_rgba(1.0, 1.0, 1.0, alpha));
+    rect.set_visible(alpha > 0.0);
+}
+
+/// CanvasLayer + полноэкранный белый ColorRect (не перехватывает мышь)
+fn create_flash_rect(scene_root: &SceneRoot) -> Gd<ColorRect> {
+    let mut layer = CanvasLayer::new_alloc();
+    layer.set_layer(FLASH_CANVAS_LAYER);
+
+    let mut rect = ColorRect::new_alloc();
+    rect.set_anchors_preset(LayoutPreset::FULL_RECT);
+    rect.set_mouse_filter(MouseFilter::IGNORE);
+    rect.set_color(Color::from_rgba(1.0, 1.0, 1.0, 0.0));
+
+    layer.add_child(&rect.clone().upcast::<Node>());
+    scene_root.node.clone().upcast::<Node>().add_child(&layer.upcast::<Node>());
+
+    rect
+}
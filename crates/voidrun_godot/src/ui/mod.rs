@@ -3,7 +3,15 @@
 //! # Architecture
 //!
 //! This domain handles Godot UI layer:
-//! - **debug_overlay**: DebugOverlay node (FPS counter, spawn buttons, etc.)
+//! - **debug_overlay**: DebugOverlay node (FPS counter, spawn buttons, setup diagnostics, etc.)
+//! - **flash_overlay**: засветка экрана игрока от flashbang (PlayerBlinded)
+//! - **arena_overlay**: счёт и статистика раундов arena дуэли (GameMode::Arena)
+//! - **compass_strip**: полоса компаса FPS HUD (курс, стороны света, маркеры — данные из ECS Compass)
+//! - **container_panel**: содержимое открытого контейнера (ECS OpenContainer + Container)
+//! - **scan_panel**: прогресс скана и отчёт о цели (ECS ScanCache)
+//! - **tutorial_prompt**: подсказка активного шага туториала (ECS TutorialState)
+//! - **chatter_subtitles**: подслушанные callouts AI (ECS Callout + стены между говорящим и игроком)
+//! - **fire_feedback**: причина отказа выстрела игрока под прицелом (ECS FireDenied)
 //!
 //! # Design Rationale
 //!
@@ -14,9 +22,33 @@
 //!
 //! # Submodules
 //!
-//! - `debug_overlay`: DebugOverlay node (FPS, spawn controls, game state display)
+//! - `debug_overlay`: DebugOverlay node (FPS, spawn controls, game state display) + update_debug_diagnostics_main_thread
+//! - `flash_overlay`: FlashOverlay (NonSend) + update_flash_overlay_main_thread
+//! - `arena_overlay`: ArenaOverlay (NonSend) + update_arena_overlay_main_thread
+//! - `compass_strip`: CompassStrip (NonSend) + sync_camera_yaw / update_compass_strip_main_thread
+//! - `container_panel`: ContainerPanel (NonSend) + update_container_panel_main_thread
+//! - `scan_panel`: ScanPanel (NonSend) + update_scan_panel_main_thread
+//! - `tutorial_prompt`: TutorialPrompt (NonSend) + update_tutorial_prompt_main_thread
+//! - `chatter_subtitles`: ChatterSubtitles (NonSend) + update_chatter_subtitles_main_thread
+//! - `fire_feedback`: FireFeedback (NonSend) + update_fire_feedback_main_thread
 
 pub mod debug_overlay;
+pub mod flash_overlay;
+pub mod arena_overlay;
+pub mod compass_strip;
+pub mod container_panel;
+pub mod scan_panel;
+pub mod tutorial_prompt;
+pub mod chatter_subtitles;
+pub mod fire_feedback;
 
 // Re-export debug overlay node
-pub use debug_overlay::DebugOverlay;
+pub use debug_overlay::{DebugOverlay, DebugOverlayHandle, update_debug_diagnostics_main_thread};
+pub use flash_overlay::{FlashOverlay, update_flash_overlay_main_thread};
+pub use arena_overlay::{ArenaOverlay, update_arena_overlay_main_thread};
+pub use compass_strip::{CompassStrip, sync_camera_yaw_main_thread, update_compass_strip_main_thread};
+pub use container_panel::{ContainerPanel, update_container_panel_main_thread};
+pub use scan_panel::{ScanPanel, update_scan_panel_main_thread};
+pub use tutorial_prompt::{TutorialPrompt, update_tutorial_prompt_main_thread};
+pub use chatter_subtitles::{ChatterSubtitles, update_chatter_subtitles_main_thread};
+pub use fire_feedback::{FireFeedback, update_fire_feedback_main_thread};
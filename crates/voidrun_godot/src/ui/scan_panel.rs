@@ -0,0 +1,138 @@
+//! Scan panel — прогресс скана и отчёт о цели в фокусе.
+//!
+//! Канал и отчёты — ECS (`voidrun_simulation::scan`): Channeling(Scan) игрока → прогресс,
+//! `ScanCache::focused` → HP, щит, сопротивления, архетип (живые значения).
+//! CanvasLayer создаётся лениво, скрывается без скана и фокуса.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{CanvasLayer, ColorRect, Control, Label};
+use godot::classes::control::MouseFilter;
+use voidrun_simulation::combat::{ChannelKind, Channeling, DamageSource};
+use voidrun_simulation::player::Player;
+use voidrun_simulation::scan::{Resistance, ScanCache, ScanReport};
+use voidrun_simulation::SimulationTick;
+
+use crate::shared::SceneRoot;
+use crate::visual_sync::faction_theme;
+
+/// Слой вровень с container panel (разные углы экрана)
+const SCAN_CANVAS_LAYER: i32 = 35;
+
+const PANEL_WIDTH: f32 = 280.0;
+/// Высота строки (font_size 14)
+const PANEL_LINE_HEIGHT: f32 = 20.0;
+/// Отступ от левого края / сверху (пиксели)
+const PANEL_MARGIN: f32 = 16.0;
+const PANEL_TOP: f32 = 120.0;
+
+/// Панель + текст (NonSend — Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct ScanPanel {
+    root: Option<Gd<Control>>,
+    label: Option<Gd<Label>>,
+}
+
+/// System: скан игрока / фокус ScanCache → текст панели
+pub fn update_scan_panel_main_thread(
+    player: Query<Option<&Channeling>, With<Player>>,
+    cache: Res<ScanCache>,
+    tick: Res<SimulationTick>,
+    mut panel: NonSendMut<ScanPanel>,
+    scene_root: NonSend<SceneRoot>,
+) {
+    let channel = player
+        .single()
+        .ok()
+        .flatten()
+        .filter(|channel| channel.kind == ChannelKind::Scan);
+    let focused = cache.focused();
+
+    if channel.is_none() && focused.is_none() {
+        if let Some(root) = panel.root.as_mut() {
+            root.set_visible(false);
+        }
+        return;
+    }
+
+    if panel.root.is_none() {
+        let (root, label) = create_panel(&scene_root);
+        panel.root = Some(root);
+        panel.label = Some(label);
+    }
+
+    let mut text = String::new();
+    if let Some(channel) = channel {
+        let remaining = channel.complete_at_tick.saturating_sub(tick.get()) as f32 / SimulationTick::HZ;
+        text.push_str(&format!("SCANNING… {:.1}s\n", remaining));
+    }
+    if let Some((_, report)) = focused {
+        text.push_str(&report_text(report));
+    }
+
+    if let Some(label) = panel.label.as_mut() {
+        label.set_text(text.trim_end());
+    }
+    if let Some(root) = panel.root.as_mut() {
+        let lines = text.trim_end().lines().count() as f32;
+        root.set_size(Vector2::new(PANEL_WIDTH, lines * PANEL_LINE_HEIGHT + 16.0));
+        root.set_visible(true);
+    }
+}
+
+fn report_text(report: &ScanReport) -> String {
+    let mut text = format!(
+        "{} — {}\nHP {}/{}\n",
+        faction_theme(report.faction_id).name,
+        report.archetype.name(),
+        report.health,
+        report.max_health
+    );
+
+    if let Some(shield) = report.shield {
+        text.push_str(&format!(
+            "Shield {:.0}/{:.0}{}\n",
+            shield.energy,
+            shield.max_energy,
+            if shield.active { "" } else { " (down)" }
+        ));
+    }
+
+    for (name, source) in [
+        ("Ranged", DamageSource::Ranged),
+        ("Melee", DamageSource::Melee),
+        ("Env", DamageSource::Environmental),
+    ] {
+        let resistance = match report.resistance(source) {
+            Resistance::None => "—".to_string(),
+            Resistance::Shielded => "shield".to_string(),
+            Resistance::Armored(defense) => format!("armor {}", defense),
+        };
+        text.push_str(&format!("{}: {}\n", name, resistance));
+    }
+    text
+}
+
+/// CanvasLayer + полупрозрачный фон слева (не перехватывает мышь)
+fn create_panel(scene_root: &SceneRoot) -> (Gd<Control>, Gd<Label>) {
+    let mut layer = CanvasLayer::new_alloc();
+    layer.set_layer(SCAN_CANVAS_LAYER);
+
+    let mut background = ColorRect::new_alloc();
+    background.set_color(Color::from_rgba(0.0, 0.05, 0.1, 0.6));
+    background.set_position(Vector2::new(PANEL_MARGIN, PANEL_TOP));
+    background.set_size(Vector2::new(PANEL_WIDTH, 0.0));
+    background.set_mouse_filter(MouseFilter::IGNORE);
+
+    let mut label = Label::new_alloc();
+    label.set_position(Vector2::new(12.0, 8.0));
+    label.add_theme_font_size_override("font_size", 14);
+    label.add_theme_color_override("font_color", Color::from_rgb(0.6, 0.9, 1.0));
+    label.set_mouse_filter(MouseFilter::IGNORE);
+    background.add_child(&label.clone().upcast::<Node>());
+
+    layer.add_child(&background.clone().upcast::<Node>());
+    scene_root.node.clone().upcast::<Node>().add_child(&layer.upcast::<Node>());
+
+    (background.upcast::<Control>(), label)
+}
@@ -0,0 +1,102 @@
+//! Tutorial prompt — подсказка активного шага туториала по центру экрана.
+//!
+//! Шаги и гейтинг — ECS (`voidrun_simulation::tutorial`): `TutorialState::prompt()` → текст,
+//! `progress_fraction()` → полоса прогресса шага.
+//! CanvasLayer создаётся лениво, скрывается когда туториал не идёт.
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{CanvasLayer, ColorRect, Control, Label};
+use godot::classes::control::MouseFilter;
+use godot::global::{HorizontalAlignment, VerticalAlignment};
+use voidrun_simulation::tutorial::TutorialState;
+
+use crate::shared::SceneRoot;
+
+/// Слой вровень с HUD панелями
+const TUTORIAL_CANVAS_LAYER: i32 = 35;
+
+const PROMPT_WIDTH: f32 = 420.0;
+const PROMPT_HEIGHT: f32 = 48.0;
+/// Отступ сверху (под compass strip)
+const PROMPT_TOP: f32 = 64.0;
+/// Высота полосы прогресса шага
+const PROGRESS_HEIGHT: f32 = 4.0;
+
+/// Панель подсказки (NonSend — Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct TutorialPrompt {
+    root: Option<Gd<Control>>,
+    label: Option<Gd<Label>>,
+    progress: Option<Gd<ColorRect>>,
+}
+
+/// System: активный шаг TutorialState → подсказка + прогресс
+pub fn update_tutorial_prompt_main_thread(
+    state: Res<TutorialState>,
+    mut prompt: NonSendMut<TutorialPrompt>,
+    scene_root: NonSend<SceneRoot>,
+) {
+    let Some(text) = state.prompt() else {
+        if let Some(root) = prompt.root.as_mut() {
+            root.set_visible(false);
+        }
+        return;
+    };
+
+    if prompt.root.is_none() {
+        let (root, label, progress) = create_prompt(&scene_root);
+        prompt.root = Some(root);
+        prompt.label = Some(label);
+        prompt.progress = Some(progress);
+    }
+
+    if let (Some(index), Some(label)) = (state.current, prompt.label.as_mut()) {
+        label.set_text(&format!("{}/{}  {}", index + 1, state.steps.len(), text));
+    }
+    if let Some(progress) = prompt.progress.as_mut() {
+        progress.set_size(Vector2::new(PROMPT_WIDTH * state.progress_fraction(), PROGRESS_HEIGHT));
+    }
+    if let Some(root) = prompt.root.as_mut() {
+        root.set_visible(true);
+    }
+}
+
+/// CanvasLayer + фон по центру сверху + полоса прогресса снизу (не перехватывает мышь)
+fn create_prompt(scene_root: &SceneRoot) -> (Gd<Control>, Gd<Label>, Gd<ColorRect>) {
+    let mut layer = CanvasLayer::new_alloc();
+    layer.set_layer(TUTORIAL_CANVAS_LAYER);
+
+    let viewport_width = scene_root
+        .node
+        .get_viewport()
+        .map(|viewport| viewport.get_visible_rect().size.x)
+        .unwrap_or(1280.0);
+
+    let mut background = ColorRect::new_alloc();
+    background.set_color(Color::from_rgba(0.0, 0.0, 0.0, 0.6));
+    background.set_position(Vector2::new((viewport_width - PROMPT_WIDTH) * 0.5, PROMPT_TOP));
+    background.set_size(Vector2::new(PROMPT_WIDTH, PROMPT_HEIGHT));
+    background.set_mouse_filter(MouseFilter::IGNORE);
+
+    let mut label = Label::new_alloc();
+    label.set_size(Vector2::new(PROMPT_WIDTH, PROMPT_HEIGHT - PROGRESS_HEIGHT));
+    label.set_horizontal_alignment(HorizontalAlignment::CENTER);
+    label.set_vertical_alignment(VerticalAlignment::CENTER);
+    label.add_theme_font_size_override("font_size", 18);
+    label.add_theme_color_override("font_color", Color::from_rgb(1.0, 0.9, 0.5));
+    label.set_mouse_filter(MouseFilter::IGNORE);
+    background.add_child(&label.clone().upcast::<Node>());
+
+    let mut progress = ColorRect::new_alloc();
+    progress.set_color(Color::from_rgb(1.0, 0.8, 0.3));
+    progress.set_position(Vector2::new(0.0, PROMPT_HEIGHT - PROGRESS_HEIGHT));
+    progress.set_size(Vector2::new(0.0, PROGRESS_HEIGHT));
+    progress.set_mouse_filter(MouseFilter::IGNORE);
+    background.add_child(&progress.clone().upcast::<Node>());
+
+    layer.add_child(&background.clone().upcast::<Node>());
+    scene_root.node.clone().upcast::<Node>().add_child(&layer.upcast::<Node>());
+
+    (background.upcast::<Control>(), label, progress)
+}
@@ -0,0 +1,67 @@
+//! Flashbang exposure — FlashbangDetonated → FlashExposure (по взгляду каждого актора).
+//!
+//! Godot знает направление взгляда (basis), ECS — нет: здесь считаем
+//! дистанцию и facing dot, экспозицию (`Blinded::exposure`) отдаём в ECS.
+//! Дым (SmokeOcclusionMap) закрывает вспышку; стены пока нет (YAGNI).
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use voidrun_simulation::Actor;
+use voidrun_simulation::combat::{Blinded, FlashExposure, FlashbangDetonated, SmokeOcclusionMap};
+
+use crate::shared::los_helpers::eye_position;
+use crate::shared::VisualRegistry;
+
+/// System: детонация вспышки → экспозиция каждого актора в радиусе
+pub fn detect_flash_exposure_main_thread(
+    mut detonations: EventReader<FlashbangDetonated>,
+    actors: Query<Entity, With<Actor>>,
+    smoke: Res<SmokeOcclusionMap>,
+    visuals: NonSend<VisualRegistry>,
+    mut exposure_events: EventWriter<FlashExposure>,
+) {
+    for detonation in detonations.read() {
+        let flash = Vector3::new(detonation.position.x, detonation.position.y, detonation.position.z);
+
+        for entity in actors.iter() {
+            if entity == detonation.thrower {
+                continue;
+            }
+            let Some(node) = visuals.visuals.get(&entity) else {
+                continue;
+            };
+
+            let eye = eye_position(node);
+            let distance = eye.distance_to(flash);
+            if distance >= detonation.radius {
+                continue;
+            }
+
+            if smoke.blocks_line_of_sight(
+                Vec3::new(eye.x, eye.y, eye.z),
+                detonation.position,
+            ) {
+                continue;
+            }
+
+            // Godot actors face -Z
+            let forward = -node.get_global_transform().basis.col_c();
+            let facing_dot = if distance > f32::EPSILON {
+                forward.dot((flash - eye) / distance)
+            } else {
+                1.0
+            };
+
+            let exposure = Blinded::exposure(distance, detonation.radius, facing_dot);
+            if exposure <= 0.0 {
+                continue;
+            }
+
+            exposure_events.write(FlashExposure {
+                target: entity,
+                exposure,
+                max_duration: detonation.max_duration,
+            });
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! Light level sampler — освещённость + скорость актора → StealthSampled (stealth).
+//!
+//! ECS (`apply_stealth_samples`) пересчитывает LightLevel + Visibility.
+//!
+//! Источники: Light3D ноды в группе `stealth_lights` (Omni/Spot/Directional)
+//! + константный ambient. Тени не учитываются (YAGNI: без raycast к каждой лампе).
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{DirectionalLight3D, Light3D, SpotLight3D};
+use godot::classes::light_3d::Param;
+use voidrun_simulation::Actor;
+use voidrun_simulation::ai::StealthSampled;
+use voidrun_simulation::combat::ActorMotion;
+
+use crate::shared::{SceneRoot, VisualRegistry};
+
+/// Группа Godot для ламп, участвующих в stealth расчёте
+pub const STEALTH_LIGHT_GROUP: &str = "stealth_lights";
+
+/// Базовая освещённость без ламп (ночь / тёмное помещение)
+const AMBIENT_ILLUMINATION: f32 = 0.15;
+
+/// Высота сэмпла над позицией актора (грудь)
+const SAMPLE_HEIGHT: f32 = 1.0;
+
+/// Снимок лампы (собирается один раз на тик)
+struct LightSample {
+    position: Vector3,
+    /// Направление луча (spot/directional, -Z)
+    direction: Vector3,
+    energy: f32,
+    range: f32,
+    /// Половина угла конуса (радианы), None — omni
+    spot_half_angle: Option<f32>,
+    directional: bool,
+}
+
+/// System: сэмпл освещённости и скорости каждого актора (SlowUpdate, перед vision polling).
+///
+/// Скорость — из ActorMotion (XZ); без трекинга считаем, что актор стоит.
+pub fn sample_light_levels_main_thread(
+    actors: Query<(Entity, Option<&ActorMotion>), With<Actor>>,
+    visuals: NonSend<VisualRegistry>,
+    scene_root: NonSend<SceneRoot>,
+    mut stealth_events: EventWriter<StealthSampled>,
+) {
+    let lights = collect_lights(&scene_root);
+
+    for (entity, motion) in actors.iter() {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        let sample_pos = actor_node.get_global_position() + Vector3::new(0.0, SAMPLE_HEIGHT, 0.0);
+
+        stealth_events.write(StealthSampled {
+            entity,
+            illumination: illumination_at(sample_pos, &lights),
+            speed: motion.map_or(0.0, |motion| motion.velocity.length()),
+        });
+    }
+}
+
+/// Собрать видимые лампы из группы STEALTH_LIGHT_GROUP
+fn collect_lights(scene_root: &SceneRoot) -> Vec<LightSample> {
+    let Some(mut tree) = scene_root.node.get_tree() else {
+        return Vec::new();
+    };
+
+    let mut lights = Vec::new();
+    for node in tree.get_nodes_in_group(STEALTH_LIGHT_GROUP).iter_shared() {
+        let Ok(light) = node.try_cast::<Light3D>() else {
+            continue;
+        };
+        if !light.is_visible_in_tree() {
+            continue;
+        }
+
+        let transform = light.get_global_transform();
+        let directional = light.clone().try_cast::<DirectionalLight3D>().is_ok();
+        let spot_half_angle = light
+            .clone()
+            .try_cast::<SpotLight3D>()
+            .ok()
+            .map(|spot| spot.get_param(Param::SPOT_ANGLE).to_radians());
+
+        lights.push(LightSample {
+            position: transform.origin,
+            direction: -transform.basis.col_c(),
+            energy: light.get_param(Param::ENERGY),
+            range: light.get_param(Param::RANGE),
+            spot_half_angle,
+            directional,
+        });
+    }
+
+    lights
+}
+
+/// Освещённость точки (0..1): ambient + вклад ламп (квадратичное затухание по range)
+fn illumination_at(point: Vector3, lights: &[LightSample]) -> f32 {
+    let mut total = AMBIENT_ILLUMINATION;
+
+    for light in lights {
+        if light.directional {
+            // Солнце/луна — освещает всё (без теней)
+            total += light.energy;
+            continue;
+        }
+
+        let to_point = point - light.position;
+        let distance = to_point.length();
+        if light.range <= 0.0 || distance >= light.range {
+            continue;
+        }
+
+        if let Some(half_angle) = light.spot_half_angle {
+            if distance > f32::EPSILON && light.direction.angle_to(to_point) > half_angle {
+                continue;
+            }
+        }
+
+        let falloff = 1.0 - distance / light.range;
+        total += light.energy * falloff * falloff;
+    }
+
+    total.clamp(0.0, 1.0)
+}
@@ -5,11 +5,19 @@
 
 use bevy::prelude::*;
 use godot::prelude::*;
-use godot::classes::{Area3D, Node};
-use voidrun_simulation::ai::GodotAIEvent;
+use godot::classes::{Area3D, CollisionShape3D, ConvexPolygonShape3D, Node};
+use voidrun_simulation::ai::{GodotAIEvent, Visibility, VisionConfig, VisionSector};
+use voidrun_simulation::combat::{Blinded, SmokeOcclusionMap};
+use crate::shared::los_helpers::eye_position;
 use crate::shared::VisualRegistry;
 use std::collections::{HashMap, HashSet};
 
+pub mod light_sampler;
+pub mod flash;
+
+pub use light_sampler::sample_light_levels_main_thread;
+pub use flash::detect_flash_exposure_main_thread;
+
 /// VisionTracking resource — кто кого видит (state для ActorSpotted/ActorLost events)
 ///
 /// NonSend resource (HashMap<Entity, HashSet<Entity>>)
@@ -23,8 +31,19 @@ pub struct VisionTracking {
 ///
 /// NAMING: `_main_thread` суффикс = Godot API calls (NonSend resources)
 /// Каждый frame проверяем Area3D.get_overlapping_bodies() → сравниваем с prev state → events
+///
+/// Area3D — выпуклая оболочка секторов (грубый фильтр), дальше `VisionConfig::sector_of`:
+/// основной конус и периферия проверяются каждый со своими углом и дальностью.
+/// Stealth: цель замечается, только если её Visibility (свет × стойка × движение),
+/// усиленная близостью, выше порога (`Visibility::is_detectable`, дальность сектора).
+/// Дым (SmokeOcclusionMap) между глазами → цель не видна (ActorLost, ломает target lock).
+/// Ослеплённый (Blinded) наблюдатель не видит никого.
 pub fn poll_vision_cones_main_thread(
     query: Query<Entity, With<voidrun_simulation::Actor>>,
+    smoke: Res<SmokeOcclusionMap>,
+    blinded: Query<(), With<Blinded>>,
+    vision_configs: Query<&VisionConfig>,
+    visibilities: Query<&Visibility>,
     visuals: NonSend<VisualRegistry>,
     mut tracking: NonSendMut<VisionTracking>,
     mut ai_events: EventWriter<GodotAIEvent>,
@@ -34,7 +53,11 @@ pub fn poll_vision_cones_main_thread(
         let Some(observer_node) = visuals.visuals.get(&observer) else {
             continue;
         };
-            // Находим VisionCone child
+        // Без VisionConfig — форма VisionCone из prefab, секторы не проверяем
+        let vision_config = vision_configs.get(observer).ok();
+        let observer_forward = -observer_node.get_global_basis().col_c(); // Godot forward = -Z
+        let observer_forward = Vec3::new(observer_forward.x, observer_forward.y, observer_forward.z);
+        // Находим VisionCone child
         let Some(vision_cone_node) = find_child_by_name(observer_node, "VisionCone") else {
             continue;
         };
@@ -53,13 +76,35 @@ pub fn poll_vision_cones_main_thread(
                 // Reverse lookup: Godot InstanceId → ECS Entity
                 if let Some(&target_entity) = visuals.node_to_entity.get(&instance_id) {
                     // Не считаем себя
-                    if target_entity != observer {
+                    if target_entity == observer {
+                        continue;
+                    }
+                    let vision_range = match vision_config {
+                        Some(config) => {
+                            let Some(sector) =
+                                vision_sector(observer_node, observer_forward, target_entity, config, &visuals)
+                            else {
+                                continue;
+                            };
+                            config.range_of(sector)
+                        }
+                        None => VisionConfig::default().range,
+                    };
+
+                    if is_visible_enough(observer_node, target_entity, vision_range, &visuals, &visibilities)
+                        && !is_behind_smoke(observer_node, target_entity, &visuals, &smoke)
+                    {
                         current_spotted.insert(target_entity);
                     }
                 }
             }
         }
 
+        // Ослеплён → все цели потеряны (ActorLost), новых не замечаем
+        if blinded.contains(observer) {
+            current_spotted.clear();
+        }
+
         // Сравниваем с prev state → генерируем events
         let prev_spotted = tracking.spotted.entry(observer).or_default().clone();
 
@@ -85,8 +130,111 @@ pub fn poll_vision_cones_main_thread(
 
 }
 
+/// Сектор зрения, в котором находится цель (None — в оболочке VisionCone, но вне секторов)
+fn vision_sector(
+    observer_node: &Gd<Node3D>,
+    observer_forward: Vec3,
+    target: Entity,
+    config: &VisionConfig,
+    visuals: &VisualRegistry,
+) -> Option<VisionSector> {
+    let target_node = visuals.visuals.get(&target)?;
 
+    let offset = target_node.get_global_position() - observer_node.get_global_position();
+    config.sector_of(observer_forward, Vec3::new(offset.x, offset.y, offset.z))
+}
+
+/// Stealth: достаточно ли заметна цель для обнаружения на текущей дистанции
+///
+/// Без Visibility (ещё не сэмплирована) — считаем полностью заметной.
+fn is_visible_enough(
+    observer_node: &Gd<Node3D>,
+    target: Entity,
+    vision_range: f32,
+    visuals: &VisualRegistry,
+    visibilities: &Query<&Visibility>,
+) -> bool {
+    let Ok(visibility) = visibilities.get(target) else {
+        return true;
+    };
+    let Some(target_node) = visuals.visuals.get(&target) else {
+        return true;
+    };
+
+    let distance = observer_node
+        .get_global_position()
+        .distance_to(target_node.get_global_position());
+
+    visibility.is_detectable(distance, vision_range)
+}
+
+/// Дым между глазами наблюдателя и цели (strategic occlusion map)
+fn is_behind_smoke(
+    observer_node: &Gd<Node3D>,
+    target: Entity,
+    visuals: &VisualRegistry,
+    smoke: &SmokeOcclusionMap,
+) -> bool {
+    if smoke.occluders.is_empty() {
+        return false;
+    }
+    let Some(target_node) = visuals.visuals.get(&target) else {
+        return false;
+    };
 
+    let eye = |node: &Gd<Node3D>| {
+        let pos = eye_position(node);
+        Vec3::new(pos.x, pos.y, pos.z)
+    };
+
+    smoke.blocks_line_of_sight(eye(observer_node), eye(target_node))
+}
+
+/// Сегментов дуги на сектор (точность аппроксимации конуса)
+const VISION_ARC_SEGMENTS: i32 = 8;
+
+/// Половина вертикальной толщины конуса (метры)
+const VISION_HALF_HEIGHT: f32 = 1.5;
+
+/// Применить VisionConfig к VisionCone актора (вызывается при spawn визуала)
+///
+/// Заменяет shape у VisionCone/VisionShape на новый ConvexPolygonShape3D
+/// (unique per actor — ExtResource prefab шарится между инстансами).
+/// Форма: выпуклая оболочка основного сектора (fov × range) и периферии (широкий × короткий) —
+/// только broadphase, точную проверку секторов делает `poll_vision_cones_main_thread`.
+pub fn apply_vision_config(actor_node: &Gd<Node3D>, config: &VisionConfig) {
+    let Some(cone) = find_child_by_name(actor_node, "VisionCone") else {
+        return;
+    };
+    let Some(mut shape_node) = cone.try_get_node_as::<CollisionShape3D>("VisionShape") else {
+        return;
+    };
+
+    let mut points = PackedVector3Array::new();
+    points.push(Vector3::new(0.0, -VISION_HALF_HEIGHT, 0.0));
+    points.push(Vector3::new(0.0, VISION_HALF_HEIGHT, 0.0));
+    push_sector_points(&mut points, config.fov_degrees, config.range);
+    push_sector_points(&mut points, config.peripheral_fov_degrees, config.peripheral_range);
+
+    let mut shape = ConvexPolygonShape3D::new_gd();
+    shape.set_points(&points);
+    shape_node.set_shape(&shape);
+}
+
+/// Точки дуги сектора (forward = -Z), на двух высотах
+fn push_sector_points(points: &mut PackedVector3Array, fov_degrees: f32, range: f32) {
+    // > 180° выпуклая оболочка всё равно "съест" заднюю часть
+    let half_angle = fov_degrees.clamp(1.0, 180.0).to_radians() * 0.5;
+
+    for i in 0..=VISION_ARC_SEGMENTS {
+        let angle = -half_angle + 2.0 * half_angle * i as f32 / VISION_ARC_SEGMENTS as f32;
+        let x = angle.sin() * range;
+        let z = -angle.cos() * range;
+
+        points.push(Vector3::new(x, -VISION_HALF_HEIGHT, z));
+        points.push(Vector3::new(x, VISION_HALF_HEIGHT, z));
+    }
+}
 
 /// Поиск child node по имени (рекурсивно)
 fn find_child_by_name(parent: &Gd<Node3D>, name: &str) -> Option<Gd<Node>> {
@@ -0,0 +1,121 @@
+//! Actor appearance — ECS Appearance → меши/материалы prefab'а актора.
+//!
+//! - Пресет тела → масштаб ноды `Torso`
+//! - Цветовые слоты → albedo мешей (слот из meta `color_slot` или по имени ноды)
+//! - Косметика → TSCN prefab'ы на attachment points (перестраиваются при Changed<Appearance>)
+//!
+//! Запускается ПОСЛЕ spawn_actor_visuals (перекрашивает поверх цвета фракции).
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::{Material, MeshInstance3D, StandardMaterial3D};
+use voidrun_simulation::{Appearance, ColorSlot, ExpectationFailed};
+use voidrun_simulation::logger;
+use std::collections::HashMap;
+
+use crate::shared::lookup::{load_prefab, require_node};
+use crate::shared::VisualRegistry;
+
+/// Registry: косметические prefab'ы актора (удаляются при смене внешности)
+///
+/// NonSend resource — main thread only (Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct AppearanceRegistry {
+    pub cosmetics: HashMap<Entity, Vec<Gd<Node3D>>>,
+}
+
+/// System: Changed<Appearance> → тело, цвета, косметика
+pub fn apply_appearance_main_thread(
+    actors: Query<(Entity, &Appearance), Changed<Appearance>>,
+    visuals: NonSend<VisualRegistry>,
+    mut registry: NonSendMut<AppearanceRegistry>,
+    mut failures: EventWriter<ExpectationFailed>,
+) {
+    for (entity, appearance) in actors.iter() {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        // 1. Телосложение
+        if let Some(mut torso) = actor_node.try_get_node_as::<Node3D>("Torso") {
+            let scale = appearance.body.torso_scale();
+            torso.set_scale(Vector3::new(scale.x, scale.y, scale.z));
+        }
+
+        // 2. Цвета слотов
+        paint_slots_recursive(&actor_node.clone().upcast::<Node>(), appearance, None);
+
+        // 3. Косметика (старая → queue_free, новая → attach)
+        for mut cosmetic in registry.cosmetics.remove(&entity).unwrap_or_default() {
+            if cosmetic.is_instance_valid() {
+                cosmetic.queue_free();
+            }
+        }
+
+        let mut attached = Vec::new();
+        for cosmetic in appearance.cosmetics.iter() {
+            let socket = require_node::<Node3D>(actor_node, cosmetic.attachment_point.as_str())
+                .and_then(|point| load_prefab(cosmetic.prefab_path.as_str()).map(|scene| (point, scene)));
+            let (mut point, scene) = match socket {
+                Ok(found) => found,
+                Err(error) => {
+                    failures.write(error.expectation(Some(entity), "apply_appearance_main_thread"));
+                    continue;
+                }
+            };
+
+            let instance = scene.instantiate_as::<Node3D>();
+            // Меши косметики без своего слота → Accent
+            paint_slots_recursive(&instance.clone().upcast::<Node>(), appearance, Some(ColorSlot::Accent));
+            point.add_child(&instance);
+            attached.push(instance);
+        }
+        if !attached.is_empty() {
+            registry.cosmetics.insert(entity, attached);
+        }
+
+        logger::log(&format!(
+            "🎨 Appearance applied to {:?}: {:?}, {} cosmetics",
+            entity,
+            appearance.body,
+            appearance.cosmetics.len()
+        ));
+    }
+}
+
+/// Слот меша: meta `color_slot` или имя ноды prefab'а (test_actor.tscn)
+fn mesh_color_slot(mesh: &Gd<MeshInstance3D>) -> Option<ColorSlot> {
+    if mesh.has_meta("color_slot") {
+        let name = mesh.get_meta("color_slot").try_to::<GString>().ok()?;
+        return ColorSlot::from_name(&name.to_string());
+    }
+
+    match mesh.get_name().to_string().as_str() {
+        "Torso" => Some(ColorSlot::Primary),
+        "HeadMesh" | "RightHand" | "LeftHand" => Some(ColorSlot::Skin),
+        _ => None,
+    }
+}
+
+/// Покрасить меши поддерева (щит и attached prefab'ы оружия не трогаем)
+fn paint_slots_recursive(node: &Gd<Node>, appearance: &Appearance, fallback: Option<ColorSlot>) {
+    let name = node.get_name().to_string();
+    if name == "ShieldSphere" || name.ends_with("Attachment") {
+        return;
+    }
+
+    if let Ok(mut mesh) = node.clone().try_cast::<MeshInstance3D>() {
+        let color = mesh_color_slot(&mesh)
+            .or(fallback)
+            .and_then(|slot| appearance.colors.get(slot));
+        if let Some([r, g, b]) = color {
+            let mut material = StandardMaterial3D::new_gd();
+            material.set_albedo(Color::from_rgb(r, g, b));
+            mesh.set_surface_override_material(0, &material.upcast::<Material>());
+        }
+    }
+
+    for child in node.get_children().iter_shared() {
+        paint_slots_recursive(&child, appearance, fallback);
+    }
+}
@@ -0,0 +1,308 @@
+//! Faction theming — таблица внешности фракций → prefab NPC при spawn (и название для nameplate).
+//!
+//! - Tint: цвет мешей prefab'а (одежда; кожу потом перекрашивает Appearance)
+//! - Emblem: Decal на груди (текстура генерируется из формы, кэш на фракцию)
+//! - Armor variant: процедурные наплечники / нагрудник поверх торса
+//!
+//! Цель — бой 3+ фракций читается без плавающих меток.
+
+use godot::prelude::*;
+use godot::classes::{
+    decal::DecalTexture, image::Format as ImageFormat, BoxMesh, Decal, Image, ImageTexture, Material, Mesh,
+    MeshInstance3D, StandardMaterial3D, Texture2D,
+};
+use std::collections::HashMap;
+
+/// Форма эмблемы (рисуется в текстуру EMBLEM_SIZE × EMBLEM_SIZE)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmblemShape {
+    Ring,
+    Chevron,
+    Cross,
+}
+
+/// Вариант брони фракции (геометрия поверх торса)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArmorVariant {
+    /// Без брони (сброд, игрок — не мешает FPS камере)
+    Light,
+    /// Наплечники
+    Plated,
+    /// Наплечники + нагрудник
+    Heavy,
+}
+
+/// Визуальная тема фракции
+#[derive(Debug, Clone, Copy)]
+pub struct FactionTheme {
+    /// Название фракции (nameplate)
+    pub name: &'static str,
+    pub tint: Color,
+    /// Цвет эмблемы и брони
+    pub accent: Color,
+    pub emblem: Option<EmblemShape>,
+    pub armor: ArmorVariant,
+}
+
+/// Тема для фракций вне таблицы
+const DEFAULT_THEME: FactionTheme = FactionTheme {
+    name: "Unaffiliated",
+    tint: Color::from_rgb(0.5, 0.5, 0.5),
+    accent: Color::from_rgb(0.3, 0.3, 0.3),
+    emblem: None,
+    armor: ArmorVariant::Light,
+};
+
+/// Таблица внешности фракций (faction_id → тема)
+///
+/// 66 — орда (`HORDE_FACTION_ID`), 70 — элитный патруль (`ELITE_PATROL_FACTION_ID`).
+const FACTION_THEMES: &[(u64, FactionTheme)] = &[
+    (1, FactionTheme {
+        name: "Colonists",
+        tint: Color::from_rgb(0.2, 0.6, 1.0),
+        accent: Color::from_rgb(0.9, 0.95, 1.0),
+        emblem: Some(EmblemShape::Ring),
+        armor: ArmorVariant::Light,
+    }),
+    (2, FactionTheme {
+        name: "Raiders",
+        tint: Color::from_rgb(0.8, 0.2, 0.2),
+        accent: Color::from_rgb(0.15, 0.1, 0.1),
+        emblem: Some(EmblemShape::Chevron),
+        armor: ArmorVariant::Plated,
+    }),
+    (3, FactionTheme {
+        name: "Syndicate",
+        tint: Color::from_rgb(0.2, 0.8, 0.2),
+        accent: Color::from_rgb(0.95, 0.85, 0.3),
+        emblem: Some(EmblemShape::Cross),
+        armor: ArmorVariant::Plated,
+    }),
+    (66, FactionTheme {
+        name: "Horde",
+        tint: Color::from_rgb(0.45, 0.35, 0.25),
+        accent: Color::from_rgb(0.6, 0.3, 0.1),
+        emblem: None,
+        armor: ArmorVariant::Light,
+    }),
+    (70, FactionTheme {
+        name: "Elite Patrol",
+        tint: Color::from_rgb(0.15, 0.15, 0.18),
+        accent: Color::from_rgb(0.85, 0.65, 0.2),
+        emblem: Some(EmblemShape::Chevron),
+        armor: ArmorVariant::Heavy,
+    }),
+];
+
+/// Размер текстуры эмблемы (пиксели)
+const EMBLEM_SIZE: i32 = 64;
+
+/// Кэш текстур эмблем (одна на фракцию)
+///
+/// NonSend resource — main thread only (Gd<T> не Send+Sync)
+#[derive(Default)]
+pub struct FactionThemeCache {
+    pub emblems: HashMap<u64, Gd<Texture2D>>,
+}
+
+pub fn faction_theme(faction_id: u64) -> FactionTheme {
+    FACTION_THEMES
+        .iter()
+        .find(|(id, _)| *id == faction_id)
+        .map(|(_, theme)| *theme)
+        .unwrap_or(DEFAULT_THEME)
+}
+
+/// Применить тему фракции к prefab'у актора (tint мешей, эмблема, броня)
+pub fn apply_faction_theme(actor_node: &Gd<Node3D>, faction_id: u64, cache: &mut FactionThemeCache) {
+    let theme = faction_theme(faction_id);
+
+    // Tint — все MeshInstance3D верхнего уровня prefab'а
+    for child in actor_node.get_children().iter_shared() {
+        if let Ok(mut mesh) = child.try_cast::<MeshInstance3D>() {
+            mesh.set_surface_override_material(0, &solid_material(theme.tint));
+        }
+    }
+
+    let Some(mut torso) = actor_node.try_get_node_as::<Node3D>("Torso") else {
+        return;
+    };
+
+    if let Some(shape) = theme.emblem {
+        if !cache.emblems.contains_key(&faction_id) {
+            if let Some(texture) = create_emblem_texture(shape, theme.accent) {
+                cache.emblems.insert(faction_id, texture);
+            }
+        }
+        if let Some(texture) = cache.emblems.get(&faction_id) {
+            torso.add_child(&create_emblem_decal(texture).upcast::<Node>());
+        }
+    }
+
+    for plate in armor_plates(theme.armor) {
+        let mut mesh_instance = MeshInstance3D::new_alloc();
+        mesh_instance.set_name(plate.name);
+        let mut box_mesh = BoxMesh::new_gd();
+        box_mesh.set_size(plate.size);
+        mesh_instance.set_mesh(&box_mesh.upcast::<Mesh>());
+        mesh_instance.set_surface_override_material(0, &solid_material(theme.accent));
+        mesh_instance.set_position(plate.position);
+        torso.add_child(&mesh_instance.upcast::<Node>());
+    }
+}
+
+/// Элемент брони (локально к Torso: box 0.715 × 0.945 × 0.475, перед — -Z)
+struct ArmorPlate {
+    name: &'static str,
+    size: Vector3,
+    position: Vector3,
+}
+
+fn armor_plates(variant: ArmorVariant) -> Vec<ArmorPlate> {
+    let shoulders = [
+        ArmorPlate {
+            name: "ArmorShoulderLeft",
+            size: Vector3::new(0.28, 0.1, 0.5),
+            position: Vector3::new(0.3, 0.5, 0.0),
+        },
+        ArmorPlate {
+            name: "ArmorShoulderRight",
+            size: Vector3::new(0.28, 0.1, 0.5),
+            position: Vector3::new(-0.3, 0.5, 0.0),
+        },
+    ];
+    let chest = ArmorPlate {
+        name: "ArmorChest",
+        size: Vector3::new(0.55, 0.5, 0.06),
+        position: Vector3::new(0.0, 0.1, -0.26),
+    };
+
+    match variant {
+        ArmorVariant::Light => Vec::new(),
+        ArmorVariant::Plated => shoulders.into(),
+        ArmorVariant::Heavy => shoulders.into_iter().chain([chest]).collect(),
+    }
+}
+
+fn solid_material(color: Color) -> Gd<Material> {
+    let mut material = StandardMaterial3D::new_gd();
+    material.set_albedo(color);
+    material.upcast::<Material>()
+}
+
+/// Decal эмблемы на груди (проецируется вдоль +Z — на переднюю грань торса)
+fn create_emblem_decal(texture: &Gd<Texture2D>) -> Gd<Decal> {
+    let mut decal = Decal::new_alloc();
+    decal.set_name("FactionEmblem");
+    decal.set_texture(DecalTexture::ALBEDO, texture);
+    // Y — глубина проекции
+    decal.set_size(Vector3::new(0.3, 0.5, 0.3));
+    decal.set_position(Vector3::new(0.0, 0.15, -0.45));
+    decal.set_rotation(Vector3::new(-std::f32::consts::FRAC_PI_2, 0.0, 0.0));
+    decal
+}
+
+/// Процедурная текстура эмблемы (прозрачный фон, форма цветом `color`)
+fn create_emblem_texture(shape: EmblemShape, color: Color) -> Option<Gd<Texture2D>> {
+    let mut image = Image::create_empty(EMBLEM_SIZE, EMBLEM_SIZE, false, ImageFormat::RGBA8)?;
+    image.fill(Color::from_rgba(0.0, 0.0, 0.0, 0.0));
+
+    let center = EMBLEM_SIZE as f32 * 0.5;
+    for y in 0..EMBLEM_SIZE {
+        for x in 0..EMBLEM_SIZE {
+            if emblem_covers(shape, x as f32 + 0.5 - center, y as f32 + 0.5 - center) {
+                image.set_pixel(x, y, color);
+            }
+        }
+    }
+
+    Some(ImageTexture::create_from_image(&image)?.upcast::<Texture2D>())
+}
+
+/// Пиксель формы эмблемы (dx, dy — от центра текстуры, y вниз)
+fn emblem_covers(shape: EmblemShape, dx: f32, dy: f32) -> bool {
+    match shape {
+        EmblemShape::Ring => {
+            let distance = (dx * dx + dy * dy).sqrt();
+            (20.0..=28.0).contains(&distance) || distance <= 6.0
+        }
+        // Острие вверх: полоса шириной 10 px вдоль |dx| = dy + 8
+        EmblemShape::Chevron => (dx.abs() - (dy + 8.0)).abs() <= 5.0 && dx.abs() <= 26.0,
+        EmblemShape::Cross => (dx.abs() <= 6.0 || dy.abs() <= 6.0) && dx.abs().max(dy.abs()) <= 26.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unknown_faction_gets_default_theme() {
+        let theme = faction_theme(999);
+
+        assert_eq!(theme.name, DEFAULT_THEME.name);
+        assert_eq!(theme.emblem, None);
+        assert_eq!(theme.armor, ArmorVariant::Light);
+    }
+
+    #[test]
+    fn test_faction_themes_are_distinguishable() {
+        for (i, (id, theme)) in FACTION_THEMES.iter().enumerate() {
+            assert_eq!(faction_theme(*id).name, theme.name);
+            assert_ne!(theme.name, DEFAULT_THEME.name);
+
+            for (other_id, other) in &FACTION_THEMES[i + 1..] {
+                assert_ne!(id, other_id, "duplicate faction id {}", id);
+                assert_ne!(theme.name, other.name);
+                assert_ne!(theme.tint, other.tint, "{} and {} share a tint", theme.name, other.name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_main_factions_differ_by_emblem() {
+        let emblems: Vec<_> = [1, 2, 3].iter().map(|id| faction_theme(*id).emblem).collect();
+
+        assert!(emblems.iter().all(Option::is_some));
+        assert_ne!(emblems[0], emblems[1]);
+        assert_ne!(emblems[1], emblems[2]);
+        assert_ne!(emblems[0], emblems[2]);
+    }
+
+    #[test]
+    fn test_armor_variant_plates() {
+        let names = |variant| armor_plates(variant).iter().map(|plate| plate.name).collect::<Vec<_>>();
+
+        assert!(names(ArmorVariant::Light).is_empty());
+        assert_eq!(names(ArmorVariant::Plated), ["ArmorShoulderLeft", "ArmorShoulderRight"]);
+        assert_eq!(names(ArmorVariant::Heavy), ["ArmorShoulderLeft", "ArmorShoulderRight", "ArmorChest"]);
+
+        // Наплечники симметричны, нагрудник спереди (-Z)
+        let heavy = armor_plates(ArmorVariant::Heavy);
+        assert_eq!(heavy[0].position.x, -heavy[1].position.x);
+        assert!(heavy[2].position.z < 0.0);
+    }
+
+    #[test]
+    fn test_emblem_shapes() {
+        // Ring: центр и обод закрашены, зазор между ними пуст
+        assert!(emblem_covers(EmblemShape::Ring, 0.0, 0.0));
+        assert!(!emblem_covers(EmblemShape::Ring, 12.0, 0.0));
+        assert!(emblem_covers(EmblemShape::Ring, 0.0, 24.0));
+
+        // Cross: перекладины до 26 px
+        assert!(emblem_covers(EmblemShape::Cross, 20.0, 2.0));
+        assert!(!emblem_covers(EmblemShape::Cross, 15.0, 15.0));
+        assert!(!emblem_covers(EmblemShape::Cross, 30.0, 0.0));
+
+        // Chevron: острие вверху по центру, плечи расходятся вниз
+        assert!(emblem_covers(EmblemShape::Chevron, 0.0, -8.0));
+        assert!(emblem_covers(EmblemShape::Chevron, 18.0, 10.0));
+        assert!(!emblem_covers(EmblemShape::Chevron, 0.0, 10.0));
+
+        // Углы текстуры прозрачные у всех форм
+        for shape in [EmblemShape::Ring, EmblemShape::Chevron, EmblemShape::Cross] {
+            assert!(!emblem_covers(shape, 31.5, 31.5), "{:?}", shape);
+        }
+    }
+}
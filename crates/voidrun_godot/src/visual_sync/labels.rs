@@ -1,8 +1,9 @@
-//! Label synchronization systems (health, stamina, shield, AI state)
+//! Label synchronization systems (health, stamina, shield, AI state, backup call telegraph)
 
 use bevy::prelude::*;
 use voidrun_simulation::{Health, Stamina};
-use voidrun_simulation::ai::AIState;
+use voidrun_simulation::ai::{AIState, BackupCallCancelled, BackupCallCompleted, BackupCallStarted};
+use voidrun_simulation::objective::{ObjectiveCaptured, ObjectiveDropped, ObjectivePickedUp};
 use crate::shared::VisualRegistry;
 
 /// Sync health changes → Godot Label3D
@@ -72,3 +73,81 @@ pub fn sync_ai_state_labels_main_thread(
         label.set_text(text.as_str());
     }
 }
+
+/// Telegraph вызова подкрепления → AI state Label3D (красный "📻 CALLING BACKUP")
+///
+/// Завершение / отмена → метка возвращается к текущему AIState.
+/// NAMING: `_main_thread` суффикс = Godot API calls (NonSend resources)
+pub fn sync_backup_call_labels_main_thread(
+    mut started: EventReader<BackupCallStarted>,
+    mut cancelled: EventReader<BackupCallCancelled>,
+    mut completed: EventReader<BackupCallCompleted>,
+    states: Query<&AIState>,
+    mut visuals: NonSendMut<VisualRegistry>,
+) {
+    for event in started.read() {
+        let Some(label) = visuals.ai_state_labels.get_mut(&event.caller) else {
+            continue;
+        };
+
+        label.set_text("[📻 CALLING BACKUP]");
+        label.set_modulate(godot::prelude::Color::from_rgb(1.0, 0.25, 0.25));
+    }
+
+    let finished = cancelled
+        .read()
+        .map(|event| event.caller)
+        .chain(completed.read().map(|event| event.caller));
+
+    for caller in finished {
+        let Some(label) = visuals.ai_state_labels.get_mut(&caller) else {
+            continue;
+        };
+
+        let text = states
+            .get(caller)
+            .map(|state| format!("[{:?}]", state))
+            .unwrap_or_default();
+        label.set_text(text.as_str());
+        label.set_modulate(godot::prelude::Color::WHITE);
+    }
+}
+
+/// Носитель объективного предмета → AI state Label3D (оранжевый "📦 CARRYING OBJECTIVE")
+///
+/// Сброс / захват → метка возвращается к текущему AIState.
+/// NAMING: `_main_thread` суффикс = Godot API calls (NonSend resources)
+pub fn sync_objective_carrier_labels_main_thread(
+    mut picked_up: EventReader<ObjectivePickedUp>,
+    mut dropped: EventReader<ObjectiveDropped>,
+    mut captured: EventReader<ObjectiveCaptured>,
+    states: Query<&AIState>,
+    mut visuals: NonSendMut<VisualRegistry>,
+) {
+    for event in picked_up.read() {
+        let Some(label) = visuals.ai_state_labels.get_mut(&event.carrier) else {
+            continue;
+        };
+
+        label.set_text("[📦 CARRYING OBJECTIVE]");
+        label.set_modulate(godot::prelude::Color::from_rgb(1.0, 0.6, 0.1));
+    }
+
+    let finished = dropped
+        .read()
+        .map(|event| event.carrier)
+        .chain(captured.read().map(|event| event.carrier));
+
+    for carrier in finished {
+        let Some(label) = visuals.ai_state_labels.get_mut(&carrier) else {
+            continue;
+        };
+
+        let text = states
+            .get(carrier)
+            .map(|state| format!("[{:?}]", state))
+            .unwrap_or_default();
+        label.set_text(text.as_str());
+        label.set_modulate(godot::prelude::Color::WHITE);
+    }
+}
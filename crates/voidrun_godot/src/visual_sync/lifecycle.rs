@@ -4,9 +4,10 @@ use bevy::prelude::*;
 use godot::prelude::*;
 use godot::classes::{MeshInstance3D, StandardMaterial3D, Material, NavigationAgent3D};
 use voidrun_simulation::Health;
+use voidrun_simulation::combat::Dead;
 use crate::shared::VisualRegistry;
 use voidrun_simulation::logger;
-/// Disable collision for dead actors (HP == 0) + full cleanup + schedule despawn after 5 sec
+/// Disable collision for dead actors (Added<Dead>) + full cleanup + schedule despawn after 5 sec
 ///
 /// **Complete cleanup for dead actors:**
 /// - Отключает collision (layer/mask = 0) у CharacterBody3D
@@ -14,18 +15,24 @@ use voidrun_simulation::logger;
 /// - Красит все MeshInstance3D в серый цвет
 /// - Удаляет VisionCone (Area3D) если есть
 /// - Отключает AvoidanceReceiver (для предотвращения signal callbacks)
-/// - Добавляет DespawnAfter компонент (desp spawn через 5 сек)
+/// - Добавляет DespawnAfter компонент (desp spawn через 5 сек; труп с лутом — таймер ставит ECS)
 ///
 /// **Result:** Dead actor больше не мешает живым (no collision, no pathfinding, no vision)
+///
+/// Arena бойцы (ArenaFighter) пропускаются: HP = 0 — нокаут, следующий раунд их восстанавливает.
+/// Downed (HP = 0, CanBeDowned) — ещё не труп: Dead появится после bleed-out / добивания.
 pub fn disable_collision_on_death_main_thread(
-    query: Query<(Entity, &Health), Changed<Health>>,
+    query: Query<
+        (Entity, &Health, Has<voidrun_simulation::interaction::Container>),
+        (Added<Dead>, Without<voidrun_simulation::game_mode::ArenaFighter>),
+    >,
     visuals: NonSend<VisualRegistry>,
     mut commands: Commands,
     time: Res<Time>,
 ) {
     use godot::classes::{CharacterBody3D, Node};
 
-    for (entity, health) in query.iter() {
+    for (entity, health, has_loot) in query.iter() {
         // Проверяем что актёр мёртв (HP == 0)
         if health.current > 0 {
             continue;
@@ -91,6 +98,10 @@ pub fn disable_collision_on_death_main_thread(
             // ========================================
             // 6. SCHEDULE DESPAWN AFTER 5 SECONDS
             // ========================================
+            // Труп с лутом лежит дольше (DespawnAfter от create_corpse_loot)
+            if has_loot {
+                continue;
+            }
             let despawn_time = time.elapsed_secs() + 5.0;
             commands.entity(entity).insert(voidrun_simulation::combat::DespawnAfter { despawn_time });
         }
@@ -116,11 +127,158 @@ pub fn despawn_actor_visuals_main_thread(
         visuals.health_labels.remove(&entity);
         visuals.stamina_labels.remove(&entity);
         visuals.ai_state_labels.remove(&entity);
+        visuals.shield_labels.remove(&entity);
+        visuals.nameplates.remove(&entity);
         // node_to_entity будет очищен автоматически при queue_free
     }
 }
 
+/// Despawn Godot visuals for WorldItem entities
+///
+/// Подобранные уже удалены через ItemPickedUp (registry пуст) — здесь прочие despawn.
+pub fn despawn_world_item_visuals_main_thread(
+    mut removed: RemovedComponents<voidrun_simulation::interaction::WorldItem>,
+    mut interactables: NonSendMut<crate::interaction::InteractableNodeRegistry>,
+) {
+    for entity in removed.read() {
+        if let Some(mut node) = interactables.remove(entity) {
+            if node.is_instance_valid() {
+                node.queue_free();
+            }
+        }
+    }
+}
+
+/// Invulnerability visual feedback (spawn protection / post-stagger grace)
+///
+/// - Added<Invulnerable> → все MeshInstance3D полупрозрачные
+/// - RemovedComponents<Invulnerable> → прозрачность сброшена
+pub fn sync_invulnerability_visuals_main_thread(
+    added: Query<(Entity, &voidrun_simulation::combat::Invulnerable), Added<voidrun_simulation::combat::Invulnerable>>,
+    mut removed: RemovedComponents<voidrun_simulation::combat::Invulnerable>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    for (entity, invulnerable) in added.iter() {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        set_mesh_transparency(actor_node, 0.5);
+
+        logger::log(&format!(
+            "✨ Entity {:?} invulnerable ({:?}) — transparency on",
+            entity, invulnerable.reason
+        ));
+    }
+
+    for entity in removed.read() {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        set_mesh_transparency(actor_node, 0.0);
+    }
+}
+
+/// Channelled action animations ("using item", reload, hack, cast)
+///
+/// - Added<Channeling> → ActionAnimationPlayer играет анимацию по ChannelKind
+/// - RemovedComponents<Channeling> (completed или interrupted) → RESET
+///
+/// Prefab без ActionAnimationPlayer / нужной анимации → тихо пропускаем.
+pub fn sync_channel_animations_main_thread(
+    added: Query<(Entity, &voidrun_simulation::combat::Channeling), Added<voidrun_simulation::combat::Channeling>>,
+    mut removed: RemovedComponents<voidrun_simulation::combat::Channeling>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    use voidrun_simulation::combat::ChannelKind;
+
+    for (entity, channel) in added.iter() {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        let anim_name = match channel.kind {
+            ChannelKind::Reload | ChannelKind::ClearJam => "reload",
+            ChannelKind::Consumable { .. } => "use_item",
+            ChannelKind::Hack => "hack",
+            ChannelKind::AbilityCast => "cast",
+            ChannelKind::RadioCall => "radio_call",
+            ChannelKind::Breach => "breach_kick",
+            ChannelKind::Scan => "scan",
+            ChannelKind::Craft => "craft",
+            ChannelKind::Revive => "revive",
+        };
+
+        let Some(mut anim_player) = actor_node
+            .try_get_node_as::<godot::classes::AnimationPlayer>("ActionAnimationPlayer")
+        else {
+            continue;
+        };
+
+        if !anim_player.has_animation(anim_name) {
+            continue;
+        }
+
+        anim_player.play_ex().name(anim_name).done();
+
+        logger::log(&format!(
+            "🎬 Godot: Playing '{}' (entity: {:?})",
+            anim_name, entity
+        ));
+    }
+
+    for entity in removed.read() {
+        let Some(actor_node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        let Some(mut anim_player) = actor_node
+            .try_get_node_as::<godot::classes::AnimationPlayer>("ActionAnimationPlayer")
+        else {
+            continue;
+        };
+
+        anim_player.play_ex().name("RESET").done();
+    }
+}
+
+/// Helper: прозрачность для всех MeshInstance3D (direct children)
+fn set_mesh_transparency(actor_node: &Gd<Node3D>, transparency: f32) {
+    for i in 0..actor_node.get_child_count() {
+        if let Some(mut mesh) = actor_node.get_child(i).and_then(|c| c.try_cast::<MeshInstance3D>().ok()) {
+            mesh.set_transparency(transparency);
+        }
+    }
+}
+
 // УДАЛЕНО: sync_transforms_main_thread
 // ADR-005: Godot Transform authoritative (не синхронизируем из ECS)
 // Transform обновляется через CharacterBody3D.move_and_slide()
 // StrategicPosition sync только при zone transitions (0.1-1 Hz)
+
+/// Arena: ArenaRoundReset → бойцы телепортируются на точки старта раунда
+///
+/// Velocity сбрасывается (не влетаем в раунд с инерцией/падением).
+pub fn sync_arena_round_resets_main_thread(
+    mut reset_events: EventReader<voidrun_simulation::game_mode::ArenaRoundReset>,
+    visuals: NonSend<VisualRegistry>,
+) {
+    use godot::classes::CharacterBody3D;
+
+    for reset in reset_events.read() {
+        for (fighter, spawn_point) in reset.fighters.iter().zip(reset.spawn_points.iter()) {
+            let Some(actor_node) = visuals.visuals.get(fighter) else {
+                continue;
+            };
+            let Ok(mut body) = actor_node.clone().try_cast::<CharacterBody3D>() else {
+                continue;
+            };
+
+            body.set_velocity(Vector3::ZERO);
+            body.set_global_position(Vector3::new(spawn_point.x, spawn_point.y, spawn_point.z));
+        }
+
+        logger::log(&format!("🏟️ Arena round {}: fighters reset to start positions", reset.round));
+    }
+}
@@ -6,7 +6,13 @@
 mod spawn;
 mod labels;
 mod lifecycle;
+mod appearance;
+mod faction_theme;
+mod nameplates;
 
 pub use spawn::*;
 pub use labels::*;
 pub use lifecycle::*;
+pub use appearance::*;
+pub use faction_theme::*;
+pub use nameplates::*;
@@ -0,0 +1,284 @@
+//! Nameplates — видимость меток над актором по фракции и восприятию.
+//!
+//! - Союзники (фракция игрока): nameplate виден всегда
+//! - Враги: только после обнаружения (враг заметил игрока — SpottedEnemies)
+//!   или обмена уроном с игроком; после потери контакта видны ещё `enemy_reveal_secs`
+//! - Прозрачность падает с дистанцией (`fade_start` → `fade_end`)
+//! - HP / stamina / shield / AI метки — детали nameplate'а, подчиняются тем же правилам
+//!
+//! Без игрока (RTS / debug) nameplates видны всем.
+
+use bevy::prelude::*;
+use godot::classes::Label3D;
+use godot::prelude::Gd;
+use voidrun_simulation::ai::SpottedEnemies;
+use voidrun_simulation::combat::DamageDealt;
+use voidrun_simulation::player::Player;
+use voidrun_simulation::{Actor, Health};
+use std::collections::HashMap;
+
+use crate::shared::VisualRegistry;
+
+/// Настройки nameplates (меню: `SimulationBridge::set_nameplate_settings`)
+#[derive(Resource, Debug, Clone)]
+pub struct NameplateSettings {
+    /// Показывать nameplates союзников
+    pub show_friendly: bool,
+    /// Показывать HP / stamina / shield / AI метки под nameplate
+    pub show_stats: bool,
+    /// Сколько враг остаётся видимым после потери контакта (секунды)
+    pub enemy_reveal_secs: f32,
+    /// Дистанция начала затухания (метры)
+    pub fade_start: f32,
+    /// Дистанция полного исчезновения (метры)
+    pub fade_end: f32,
+}
+
+impl Default for NameplateSettings {
+    fn default() -> Self {
+        Self {
+            show_friendly: true,
+            show_stats: true,
+            enemy_reveal_secs: 8.0,
+            fade_start: 15.0,
+            fade_end: 30.0,
+        }
+    }
+}
+
+impl NameplateSettings {
+    /// Прозрачность на дистанции (1.0 вплотную → 0.0 за fade_end)
+    pub fn alpha_at(&self, distance: f32) -> f32 {
+        if self.fade_end <= self.fade_start {
+            return if distance <= self.fade_end { 1.0 } else { 0.0 };
+        }
+        1.0 - ((distance - self.fade_start) / (self.fade_end - self.fade_start)).clamp(0.0, 1.0)
+    }
+}
+
+/// Раскрытые враги — обнаружение / урон (entity → время окончания показа)
+///
+/// Resource — без Godot типов.
+#[derive(Resource, Debug, Default)]
+pub struct NameplateReveals {
+    pub revealed_until: HashMap<Entity, f32>,
+}
+
+impl NameplateReveals {
+    /// Раскрыть врага до `until` (повторное обнаружение продлевает)
+    pub fn reveal(&mut self, entity: Entity, until: f32) {
+        self.revealed_until.insert(entity, until);
+    }
+
+    pub fn is_revealed(&self, entity: Entity, now: f32) -> bool {
+        self.revealed_until.get(&entity).is_some_and(|&until| until > now)
+    }
+
+    /// Убрать истёкшие раскрытия
+    pub fn expire(&mut self, now: f32) {
+        self.revealed_until.retain(|_, until| *until > now);
+    }
+}
+
+/// System: видимость + затухание nameplates и stat меток
+pub fn update_nameplates_main_thread(
+    mut damage_events: EventReader<DamageDealt>,
+    actors: Query<(Entity, &Actor, &Health, Option<&SpottedEnemies>), Without<Player>>,
+    player: Query<(Entity, &Actor), With<Player>>,
+    settings: Res<NameplateSettings>,
+    mut reveals: ResMut<NameplateReveals>,
+    mut visuals: NonSendMut<VisualRegistry>,
+    time: Res<Time>,
+) {
+    let now = time.elapsed_secs();
+    let player = player.single().ok();
+    // Reborrow — раздельный доступ к map'ам registry
+    let visuals = &mut *visuals;
+
+    // Обмен уроном с игроком → враг раскрыт
+    for event in damage_events.read() {
+        let Some((player_entity, _)) = player else {
+            continue;
+        };
+        let Some(enemy) = damage_counterpart(event.attacker, event.target, player_entity) else {
+            continue;
+        };
+        reveals.reveal(enemy, now + settings.enemy_reveal_secs);
+    }
+
+    let viewer = player
+        .and_then(|(entity, _)| visuals.visuals.get(&entity))
+        .map(|node| node.get_global_position());
+
+    let viewer_faction = player.map(|(entity, actor)| (entity, actor.faction_id));
+
+    for (entity, actor, health, spotted) in actors.iter() {
+        let Some(node) = visuals.visuals.get(&entity) else {
+            continue;
+        };
+
+        // Живой враг заметил игрока → раскрыт (пока видит — продлевается каждый кадр)
+        let spotted_player = viewer_faction
+            .filter(|&(_, player_faction)| player_faction != actor.faction_id)
+            .zip(spotted)
+            .is_some_and(|((player_entity, _), spotted)| spotted.enemies.contains(&player_entity));
+        if health.is_alive() && spotted_player {
+            reveals.reveal(entity, now + settings.enemy_reveal_secs);
+        }
+
+        let visible = health.is_alive()
+            && nameplate_visible(&settings, &reveals, now, viewer_faction, entity, actor.faction_id);
+
+        let alpha = match viewer {
+            Some(viewer) if visible => settings.alpha_at(node.get_global_position().distance_to(viewer)),
+            _ if visible => 1.0,
+            _ => 0.0,
+        };
+
+        if let Some(nameplate) = visuals.nameplates.get_mut(&entity) {
+            apply_alpha(nameplate, alpha);
+        }
+
+        let stats_alpha = if settings.show_stats { alpha } else { 0.0 };
+        for labels in [
+            &mut visuals.health_labels,
+            &mut visuals.stamina_labels,
+            &mut visuals.shield_labels,
+            &mut visuals.ai_state_labels,
+        ] {
+            if let Some(label) = labels.get_mut(&entity) {
+                apply_alpha(label, stats_alpha);
+            }
+        }
+    }
+
+    reveals.expire(now);
+}
+
+/// Видимость nameplate живого актора
+///
+/// `player` — (entity, faction_id) игрока; без игрока видны все.
+fn nameplate_visible(
+    settings: &NameplateSettings,
+    reveals: &NameplateReveals,
+    now: f32,
+    player: Option<(Entity, u64)>,
+    entity: Entity,
+    faction_id: u64,
+) -> bool {
+    match player {
+        None => true,
+        Some((_, player_faction)) if player_faction == faction_id => settings.show_friendly,
+        Some(_) => reveals.is_revealed(entity, now),
+    }
+}
+
+/// Второй участник обмена уроном с игроком (None — игрок не участвовал)
+fn damage_counterpart(attacker: Entity, target: Entity, player: Entity) -> Option<Entity> {
+    if attacker == player {
+        Some(target)
+    } else if target == player {
+        Some(attacker)
+    } else {
+        None
+    }
+}
+
+/// Alpha метки (цвет — свой: фракция, telegraph подкрепления и т.п.)
+fn apply_alpha(label: &mut Gd<Label3D>, alpha: f32) {
+    label.set_visible(alpha > 0.0);
+    if alpha <= 0.0 {
+        return;
+    }
+
+    let mut modulate = label.get_modulate();
+    modulate.a = alpha;
+    label.set_modulate(modulate);
+
+    let mut outline = label.get_outline_modulate();
+    outline.a = alpha;
+    label.set_outline_modulate(outline);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAYER_FACTION: u64 = 1;
+    const ENEMY_FACTION: u64 = 2;
+
+    fn player() -> Option<(Entity, u64)> {
+        Some((Entity::from_raw(1), PLAYER_FACTION))
+    }
+
+    #[test]
+    fn test_alpha_fades_between_start_and_end() {
+        let settings = NameplateSettings::default();
+
+        assert_eq!(settings.alpha_at(0.0), 1.0);
+        assert_eq!(settings.alpha_at(settings.fade_start), 1.0);
+        assert!((settings.alpha_at(22.5) - 0.5).abs() < 1e-5);
+        assert_eq!(settings.alpha_at(settings.fade_end), 0.0);
+        assert_eq!(settings.alpha_at(100.0), 0.0);
+    }
+
+    #[test]
+    fn test_alpha_without_fade_band_is_hard_cutoff() {
+        let settings = NameplateSettings {
+            fade_start: 20.0,
+            fade_end: 10.0,
+            ..Default::default()
+        };
+
+        assert_eq!(settings.alpha_at(10.0), 1.0);
+        assert_eq!(settings.alpha_at(10.5), 0.0);
+    }
+
+    #[test]
+    fn test_friendly_visibility_follows_setting() {
+        let ally = Entity::from_raw(2);
+        let reveals = NameplateReveals::default();
+        let mut settings = NameplateSettings::default();
+
+        assert!(nameplate_visible(&settings, &reveals, 0.0, player(), ally, PLAYER_FACTION));
+
+        settings.show_friendly = false;
+        assert!(!nameplate_visible(&settings, &reveals, 0.0, player(), ally, PLAYER_FACTION));
+    }
+
+    #[test]
+    fn test_enemy_hidden_until_revealed_then_expires() {
+        let enemy = Entity::from_raw(3);
+        let settings = NameplateSettings::default();
+        let mut reveals = NameplateReveals::default();
+
+        assert!(!nameplate_visible(&settings, &reveals, 0.0, player(), enemy, ENEMY_FACTION));
+
+        reveals.reveal(enemy, settings.enemy_reveal_secs);
+        assert!(nameplate_visible(&settings, &reveals, 1.0, player(), enemy, ENEMY_FACTION));
+
+        reveals.expire(settings.enemy_reveal_secs);
+        assert!(reveals.revealed_until.is_empty());
+        assert!(!nameplate_visible(&settings, &reveals, settings.enemy_reveal_secs, player(), enemy, ENEMY_FACTION));
+    }
+
+    #[test]
+    fn test_everyone_visible_without_player() {
+        let settings = NameplateSettings {
+            show_friendly: false,
+            ..Default::default()
+        };
+        let reveals = NameplateReveals::default();
+
+        assert!(nameplate_visible(&settings, &reveals, 0.0, None, Entity::from_raw(3), ENEMY_FACTION));
+    }
+
+    #[test]
+    fn test_damage_counterpart_only_for_player_exchanges() {
+        let (player, enemy, other) = (Entity::from_raw(1), Entity::from_raw(2), Entity::from_raw(3));
+
+        assert_eq!(damage_counterpart(player, enemy, player), Some(enemy));
+        assert_eq!(damage_counterpart(enemy, player, player), Some(enemy));
+        assert_eq!(damage_counterpart(enemy, other, player), None);
+    }
+}
@@ -3,13 +3,19 @@
 use bevy::prelude::*;
 use godot::prelude::*;
 use godot::classes::{
-    MeshInstance3D, Label3D, Node, PackedScene, ResourceLoader,
-    StandardMaterial3D, Material, NavigationAgent3D,
-    base_material_3d::BillboardMode,
+    Area3D, BoxMesh, CollisionShape3D, Label3D, Mesh, MeshInstance3D, Node, PackedScene, ResourceLoader,
+    NavigationAgent3D, SphereShape3D, StandardMaterial3D, Material, base_material_3d::BillboardMode,
 };
 use voidrun_simulation::{Actor, Health, Stamina};
+use voidrun_simulation::interaction::{Interactable, WorldItem};
+use crate::interaction::InteractableNodeRegistry;
+use crate::shared::prefab_contract::{actor_requirements, validate_prefab, ActorCapabilities};
 use crate::shared::VisualRegistry;
 use voidrun_simulation::logger;
+
+/// Радиус зоны попадания луча [F] вокруг WorldItem (метры)
+const WORLD_ITEM_PICK_RADIUS: f32 = 0.35;
+
 /// Spawn visuals for newly created actors
 ///
 /// NAMING: `_main_thread` суффикс = Godot API calls (NonSend resources)
@@ -19,21 +25,13 @@ pub fn spawn_actor_visuals_main_thread(
     mut visuals: NonSendMut<VisualRegistry>,
     scene_root: NonSend<crate::shared::SceneRoot>,
     mut transform_events: EventWriter<voidrun_simulation::ai::GodotTransformEvent>,
+    vision_configs: Query<&voidrun_simulation::ai::VisionConfig>,
+    archetypes: Query<(Has<voidrun_simulation::player::Player>, Has<voidrun_simulation::AIConfig>, Has<voidrun_simulation::WeaponStats>)>,
+    mut faction_themes: NonSendMut<super::FactionThemeCache>,
 ) {
     for (entity, actor, health, stamina, shield_opt, strategic_pos, prefab_path) in query.iter() {
         // Загружаем TSCN prefab из PrefabPath компонента
-        let mut loader = ResourceLoader::singleton();
-        let scene = loader.load_ex(&prefab_path.path).done();
-
-        let Some(scene) = scene else {
-            logger::log(&format!("❌ Failed to load prefab: {}", prefab_path.path));
-            continue;
-        };
-
-        let packed_scene: Gd<PackedScene> = scene.cast();
-
-        let Some(instance) = packed_scene.instantiate() else {
-            logger::log(&format!("❌ Failed to instantiate prefab: {}", prefab_path.path));
+        let Some(instance) = instantiate_prefab(&prefab_path.path) else {
             continue;
         };
 
@@ -54,6 +52,17 @@ pub fn spawn_actor_visuals_main_thread(
             (wrapper, actor_child)
         };
 
+        // Контракт prefab'а: node'ы, которые ищут системы заявленных возможностей (один отчёт)
+        if let Ok((is_player, has_ai, armed)) = archetypes.get(entity) {
+            let capabilities = ActorCapabilities {
+                first_person: is_player,
+                vision: has_ai || vision_configs.contains(entity),
+                shield: shield_opt.is_some(),
+                armed,
+            };
+            validate_prefab(&actor_node, &prefab_path.path, &actor_requirements(capabilities));
+        }
+
         // Спавним на стратегической позиции (StrategicPosition → world coordinates)
         let spawn_pos = strategic_pos.to_world_position(0.5); // Y=0.5 (над землёй)
         actor_node.set_position(Vector3::new(spawn_pos.x, spawn_pos.y, spawn_pos.z));
@@ -62,23 +71,14 @@ pub fn spawn_actor_visuals_main_thread(
         let entity_id_variant = (entity.to_bits() as i64).to_variant();
         actor_node.set_meta("entity_id", &entity_id_variant);
 
-        // Цвет фракции — красим все MeshInstance3D дочерние ноды
-        let faction_color = match actor.faction_id {
-            1 => Color::from_rgb(0.2, 0.6, 1.0), // Blue
-            2 => Color::from_rgb(0.8, 0.2, 0.2), // Red
-            3 => Color::from_rgb(0.2, 0.8, 0.2), // Green
-            _ => Color::from_rgb(0.5, 0.5, 0.5), // Gray
-        };
-
-        // Красим все mesh instances в prefab
-        for i in 0..actor_node.get_child_count() {
-            if let Some(mut child) = actor_node.get_child(i).and_then(|c| c.try_cast::<MeshInstance3D>().ok()) {
-                let mut material = StandardMaterial3D::new_gd();
-                material.set_albedo(faction_color);
-                child.set_surface_override_material(0, &material.upcast::<Material>());
-            }
+        // Per-archetype зрение (иначе форма VisionCone из prefab)
+        if let Ok(vision_config) = vision_configs.get(entity) {
+            crate::vision::apply_vision_config(&actor_node, vision_config);
         }
 
+        // Тема фракции — tint мешей, эмблема на груди, вариант брони (таблица в faction_theme)
+        super::apply_faction_theme(&actor_node, actor.faction_id, &mut faction_themes);
+
         // КРИТИЧНО: Создаём unique shield material для каждого актора
         // (иначе все щиты будут share один material и гаснуть одновременно)
         if let Some(shield_sphere) = actor_node.try_get_node_as::<Node3D>("ShieldSphere") {
@@ -101,6 +101,19 @@ pub fn spawn_actor_visuals_main_thread(
             }
         }
 
+        // Nameplate (над всеми метками; видимость — update_nameplates_main_thread)
+        let theme = super::faction_theme(actor.faction_id);
+        let mut nameplate = Label3D::new_alloc();
+        nameplate.set_name("Nameplate");
+        nameplate.set_text(theme.name);
+        nameplate.set_pixel_size(0.005);
+        nameplate.set_billboard_mode(BillboardMode::ENABLED);
+        nameplate.set_position(Vector3::new(0.0, 2.45, 0.0));
+        nameplate.set_modulate(theme.tint);
+        nameplate.set_outline_size(8);
+        nameplate.set_visible(false);
+        actor_node.add_child(&nameplate.clone().upcast::<Node>());
+
         // AI state label (над головой, самый верхний)
         let mut ai_label = Label3D::new_alloc();
         let ai_text = format!("AI");
@@ -222,6 +235,7 @@ pub fn spawn_actor_visuals_main_thread(
         visuals.health_labels.insert(entity, health_label);
         visuals.stamina_labels.insert(entity, stamina_label);
         visuals.ai_state_labels.insert(entity, ai_label);
+        visuals.nameplates.insert(entity, nameplate);
         if let Some(shield_label) = shield_label_opt {
             visuals.shield_labels.insert(entity, shield_label);
         }
@@ -240,3 +254,82 @@ pub fn spawn_actor_visuals_main_thread(
         logger::log(&format!("✅ Spawned visual (prefab: {}) at strategic {:?}", prefab_path.path, strategic_pos));
     }
 }
+
+/// Spawn visuals for items dropped into the world (WorldItem)
+///
+/// - Prefab из PrefabPath (пустой путь / ошибка загрузки → placeholder box)
+/// - Area3D на environment layer — цель для raycast [F]
+/// - Нода регистрируется в InteractableNodeRegistry (pickup → queue_free через ItemPickedUp)
+pub fn spawn_world_item_visuals_main_thread(
+    query: Query<(Entity, &Interactable, &voidrun_simulation::PrefabPath), Added<WorldItem>>,
+    mut interactables: NonSendMut<InteractableNodeRegistry>,
+    scene_root: NonSend<crate::shared::SceneRoot>,
+) {
+    for (entity, interactable, prefab_path) in query.iter() {
+        let mut item_node = Node3D::new_alloc();
+        item_node.set_name(format!("WorldItem_{}", entity.index()).as_str());
+
+        let prefab = if prefab_path.path.is_empty() {
+            None
+        } else {
+            instantiate_prefab(&prefab_path.path)
+        };
+        match prefab {
+            Some(prefab) => item_node.add_child(&prefab),
+            None => item_node.add_child(&create_world_item_placeholder().upcast::<Node>()),
+        }
+
+        let mut pick_area = Area3D::new_alloc();
+        pick_area.set_name("PickArea");
+        pick_area.set_collision_layer(crate::shared::collision::COLLISION_LAYER_ENVIRONMENT);
+        pick_area.set_collision_mask(0);
+        let mut shape = SphereShape3D::new_gd();
+        shape.set_radius(WORLD_ITEM_PICK_RADIUS);
+        let mut collision_shape = CollisionShape3D::new_alloc();
+        collision_shape.set_shape(&shape);
+        pick_area.add_child(&collision_shape.upcast::<Node>());
+        item_node.add_child(&pick_area.upcast::<Node>());
+
+        // Чуть над полом (StrategicPosition теряет Y — берём позицию Interactable)
+        let position = interactable.position;
+        item_node.set_position(Vector3::new(position.x, position.y + 0.1, position.z));
+        item_node.set_meta("entity_id", &(entity.to_bits() as i64).to_variant());
+
+        let mut root = scene_root.node.clone();
+        root.add_child(&item_node.clone().upcast::<Node>());
+        interactables.insert(entity, item_node);
+
+        logger::log(&format!("📦 Spawned world item {:?} (prefab: '{}')", entity, prefab_path.path));
+    }
+}
+
+/// Загрузить и инстанцировать TSCN prefab (ошибка → лог + None)
+fn instantiate_prefab(path: &str) -> Option<Gd<Node>> {
+    let mut loader = ResourceLoader::singleton();
+    let Some(scene) = loader.load_ex(path).done() else {
+        logger::log(&format!("❌ Failed to load prefab: {}", path));
+        return None;
+    };
+
+    let packed_scene: Gd<PackedScene> = scene.cast();
+
+    let Some(instance) = packed_scene.instantiate() else {
+        logger::log(&format!("❌ Failed to instantiate prefab: {}", path));
+        return None;
+    };
+    Some(instance)
+}
+
+/// Placeholder предмета без prefab'а (жёлтый ящик 0.3 м)
+fn create_world_item_placeholder() -> Gd<MeshInstance3D> {
+    let mut mesh_instance = MeshInstance3D::new_alloc();
+    mesh_instance.set_name("Placeholder");
+    let mut box_mesh = BoxMesh::new_gd();
+    box_mesh.set_size(Vector3::new(0.3, 0.3, 0.3));
+    mesh_instance.set_mesh(&box_mesh.upcast::<Mesh>());
+    let mut material = StandardMaterial3D::new_gd();
+    material.set_albedo(Color::from_rgb(0.9, 0.75, 0.2));
+    mesh_instance.set_surface_override_material(0, &material.upcast::<Material>());
+    mesh_instance.set_position(Vector3::new(0.0, 0.15, 0.0));
+    mesh_instance
+}
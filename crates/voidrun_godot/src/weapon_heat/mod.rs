@@ -0,0 +1,143 @@
+//! Weapon heat VFX — нагрев энергооружия → свечение ствола attached prefab'а.
+//!
+//! Architecture: ADR-007 (attached prefabs) + ADR-004 (NonSend, _main_thread naming)
+//! - WeaponHeatChanged (ECS update_weapon_heat) → последнее значение за кадр на entity
+//! - ShaderMaterial: uniform `heat` (0.0 - 1.0) + `overheated` — шейдер сам рисует накал
+//! - StandardMaterial3D: emission (тёмно-красный → оранжевый), energy растёт с нагревом
+//!
+//! Материал копируется в surface override (оригинал может быть shared между prefab'ами).
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use godot::prelude::*;
+use godot::classes::base_material_3d::Feature;
+use godot::classes::{Material, MeshInstance3D, ShaderMaterial, StandardMaterial3D};
+use voidrun_simulation::combat::WeaponHeatChanged;
+use voidrun_simulation::Attachment;
+
+use crate::shared::AttachmentRegistry;
+
+/// Meta на корне prefab'а: последний применённый нагрев (шаг меньше порога не переприменяем)
+const APPLIED_HEAT_META: &str = "heat_glow";
+
+/// Meta на корне prefab'а: последний применённый статус перегрева
+const APPLIED_OVERHEAT_META: &str = "heat_overheated";
+
+/// Минимальный шаг нагрева для обновления материалов
+const HEAT_STEP: f32 = 0.03;
+
+/// Цвет едва нагретого ствола
+const GLOW_COLD: Color = Color::from_rgb(0.6, 0.05, 0.0);
+
+/// Цвет раскалённого ствола
+const GLOW_HOT: Color = Color::from_rgb(1.0, 0.55, 0.1);
+
+/// Яркость emission при полном нагреве (перегрев — ×1.5)
+const MAX_GLOW_ENERGY: f32 = 4.0;
+
+/// System: WeaponHeatChanged → свечение ствола оружия в руке
+pub fn update_weapon_heat_glow_main_thread(
+    mut heat_events: EventReader<WeaponHeatChanged>,
+    weapons: Query<&Attachment>,
+    attachments: NonSend<AttachmentRegistry>,
+) {
+    // Остывание шлёт событие каждый tick — применяем только последнее за кадр
+    let latest: HashMap<Entity, (f32, bool)> = heat_events
+        .read()
+        .map(|event| (event.entity, (event.heat, event.overheated)))
+        .collect();
+
+    for (entity, (heat, overheated)) in latest {
+        let Ok(attachment) = weapons.get(entity) else {
+            continue;
+        };
+        let Some(prefab) = attachments.attachments.get(&(entity, attachment.attachment_point.clone())) else {
+            continue;
+        };
+
+        apply_heat_glow(prefab, heat, overheated);
+    }
+}
+
+/// Применить нагрев к prefab'у (no-op, если шаг меньше HEAT_STEP и статус перегрева тот же)
+fn apply_heat_glow(prefab: &Gd<Node3D>, heat: f32, overheated: bool) {
+    if !prefab.is_instance_valid() {
+        return;
+    }
+
+    let mut prefab = prefab.clone();
+    let applied_heat = if prefab.has_meta(APPLIED_HEAT_META) {
+        prefab.get_meta(APPLIED_HEAT_META).try_to::<f32>().unwrap_or(0.0)
+    } else {
+        0.0
+    };
+    let applied_overheated =
+        prefab.has_meta(APPLIED_OVERHEAT_META) && prefab.get_meta(APPLIED_OVERHEAT_META).try_to::<bool>().unwrap_or(false);
+
+    let unchanged = if heat <= 0.0 { applied_heat <= 0.0 } else { (applied_heat - heat).abs() < HEAT_STEP };
+    if unchanged && applied_overheated == overheated {
+        return;
+    }
+
+    apply_glow_recursive(&prefab.clone().upcast::<Node>(), heat, overheated);
+    prefab.set_meta(APPLIED_HEAT_META, &Variant::from(heat));
+    prefab.set_meta(APPLIED_OVERHEAT_META, &Variant::from(overheated));
+}
+
+fn apply_glow_recursive(node: &Gd<Node>, heat: f32, overheated: bool) {
+    if let Ok(mut mesh_instance) = node.clone().try_cast::<MeshInstance3D>() {
+        for surface in 0..mesh_instance.get_surface_override_material_count() {
+            let Some(material) = override_material(&mut mesh_instance, surface) else {
+                continue;
+            };
+            set_glow(material, heat, overheated);
+        }
+    }
+
+    for child in node.get_children().iter_shared() {
+        apply_glow_recursive(&child, heat, overheated);
+    }
+}
+
+/// Surface override материал (нет override → копия активного материала становится override)
+fn override_material(mesh_instance: &mut Gd<MeshInstance3D>, surface: i32) -> Option<Gd<Material>> {
+    if let Some(material) = mesh_instance.get_surface_override_material(surface) {
+        return Some(material);
+    }
+
+    let active = mesh_instance.get_active_material(surface)?;
+    let duplicated = active.duplicate()?.try_cast::<Material>().ok()?;
+    mesh_instance.set_surface_override_material(surface, &duplicated);
+    Some(duplicated)
+}
+
+fn set_glow(material: Gd<Material>, heat: f32, overheated: bool) {
+    let material = match material.try_cast::<ShaderMaterial>() {
+        Ok(mut shader_mat) => {
+            shader_mat.set_shader_parameter("heat", &Variant::from(heat));
+            shader_mat.set_shader_parameter("overheated", &Variant::from(overheated));
+            return;
+        }
+        Err(material) => material,
+    };
+
+    let Ok(mut standard) = material.try_cast::<StandardMaterial3D>() else {
+        // Прочие материалы (ORM, canvas) — без свечения
+        return;
+    };
+
+    if heat <= 0.0 {
+        standard.set_feature(Feature::EMISSION, false);
+        return;
+    }
+
+    standard.set_feature(Feature::EMISSION, true);
+    standard.set_emission(Color::from_rgb(
+        GLOW_COLD.r + (GLOW_HOT.r - GLOW_COLD.r) * heat,
+        GLOW_COLD.g + (GLOW_HOT.g - GLOW_COLD.g) * heat,
+        GLOW_COLD.b + (GLOW_HOT.b - GLOW_COLD.b) * heat,
+    ));
+    let boost = if overheated { 1.5 } else { 1.0 };
+    standard.set_emission_energy_multiplier(heat * MAX_GLOW_ENERGY * boost);
+}
@@ -0,0 +1,130 @@
+//! Внешность актора: пресет тела, цветовые слоты, косметика.
+//!
+//! Данные — в ECS (и в `PlayerProfile`, сериализуются через RON), применение к prefab — Godot.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Ошибка разбора внешности из RON
+pub type AppearanceParseError = ron::error::SpannedError;
+
+/// Пресет телосложения (масштаб торса prefab'а)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect, Serialize, Deserialize)]
+pub enum BodyPreset {
+    Slim,
+    #[default]
+    Average,
+    Heavy,
+}
+
+impl BodyPreset {
+    pub const ALL: [BodyPreset; 3] = [BodyPreset::Slim, BodyPreset::Average, BodyPreset::Heavy];
+
+    /// Масштаб торса (ширина X/Z, рост Y не меняем — коллизия одна на всех)
+    pub fn torso_scale(&self) -> Vec3 {
+        match self {
+            BodyPreset::Slim => Vec3::new(0.85, 1.0, 0.85),
+            BodyPreset::Average => Vec3::ONE,
+            BodyPreset::Heavy => Vec3::new(1.2, 1.0, 1.2),
+        }
+    }
+}
+
+/// Цветовой слот (меш prefab'а выбирает слот по имени ноды или meta `color_slot`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Serialize, Deserialize)]
+pub enum ColorSlot {
+    /// Голова, руки
+    Skin,
+    /// Торс (одежда) — по умолчанию цвет фракции
+    Primary,
+    /// Косметика, мелкие детали
+    Accent,
+}
+
+impl ColorSlot {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "skin" => Some(ColorSlot::Skin),
+            "primary" => Some(ColorSlot::Primary),
+            "accent" => Some(ColorSlot::Accent),
+            _ => None,
+        }
+    }
+}
+
+/// Цвета слотов (RGB 0.0-1.0). `None` — цвет prefab'а / фракции не трогаем.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppearanceColors {
+    pub skin: Option<[f32; 3]>,
+    pub primary: Option<[f32; 3]>,
+    pub accent: Option<[f32; 3]>,
+}
+
+impl AppearanceColors {
+    pub fn get(&self, slot: ColorSlot) -> Option<[f32; 3]> {
+        match slot {
+            ColorSlot::Skin => self.skin,
+            ColorSlot::Primary => self.primary,
+            ColorSlot::Accent => self.accent,
+        }
+    }
+}
+
+/// Косметический prefab (шлем, нашивка, антенна) на attachment point актора
+#[derive(Debug, Clone, PartialEq, Reflect, Serialize, Deserialize)]
+pub struct CosmeticAttachment {
+    /// Путь к TSCN prefab (например "res://cosmetics/visor.tscn")
+    pub prefab_path: String,
+    /// Attachment point на prefab актора (например "Head/HeadMeshes")
+    pub attachment_point: String,
+}
+
+/// Внешность актора
+///
+/// Godot применяет при появлении (и при изменении): масштаб торса, цвета слотов, косметика.
+#[derive(Component, Debug, Clone, PartialEq, Default, Reflect, Serialize, Deserialize)]
+#[reflect(Component)]
+#[serde(default)]
+pub struct Appearance {
+    pub body: BodyPreset,
+    pub colors: AppearanceColors,
+    pub cosmetics: Vec<CosmeticAttachment>,
+}
+
+/// Палитра кожи для `Appearance::varied`
+const SKIN_TONES: [[f32; 3]; 5] = [
+    [0.96, 0.80, 0.69],
+    [0.88, 0.67, 0.52],
+    [0.72, 0.52, 0.38],
+    [0.55, 0.38, 0.26],
+    [0.36, 0.24, 0.17],
+];
+
+impl Appearance {
+    /// Детерминированная вариация для NPC (тело + кожа, цвет одежды — фракции)
+    pub fn varied(seed: u64) -> Self {
+        // splitmix64 — разброс соседних seed'ов
+        let mut hash = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        hash = (hash ^ (hash >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        hash = (hash ^ (hash >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        hash ^= hash >> 31;
+
+        Self {
+            body: BodyPreset::ALL[(hash % BodyPreset::ALL.len() as u64) as usize],
+            colors: AppearanceColors {
+                skin: Some(SKIN_TONES[((hash >> 16) % SKIN_TONES.len() as u64) as usize]),
+                ..default()
+            },
+            cosmetics: Vec::new(),
+        }
+    }
+
+    pub fn to_ron(&self) -> Result<String, ron::Error> {
+        ron::ser::to_string(self)
+    }
+
+    pub fn from_ron(source: &str) -> Result<Self, AppearanceParseError> {
+        ron::from_str(source)
+    }
+}
@@ -0,0 +1,43 @@
+//! Tests for actor appearance (вариации NPC, RON сериализация).
+
+#[cfg(test)]
+mod tests {
+    use super::super::appearance::*;
+
+    #[test]
+    fn test_appearance_ron_roundtrip() {
+        let appearance = Appearance {
+            body: BodyPreset::Heavy,
+            colors: AppearanceColors {
+                skin: Some([0.7, 0.5, 0.4]),
+                primary: None,
+                accent: Some([1.0, 0.8, 0.0]),
+            },
+            cosmetics: vec![CosmeticAttachment {
+                prefab_path: "res://cosmetics/visor.tscn".to_string(),
+                attachment_point: "Head/HeadMeshes".to_string(),
+            }],
+        };
+
+        let source = appearance.to_ron().unwrap();
+        assert_eq!(Appearance::from_ron(&source).unwrap(), appearance);
+    }
+
+    #[test]
+    fn test_appearance_missing_fields_default() {
+        let appearance = Appearance::from_ron("(body: Slim)").unwrap();
+        assert_eq!(appearance.body, BodyPreset::Slim);
+        assert_eq!(appearance.colors, AppearanceColors::default());
+        assert!(appearance.cosmetics.is_empty());
+    }
+
+    #[test]
+    fn test_varied_is_deterministic_and_keeps_faction_color() {
+        assert_eq!(Appearance::varied(42), Appearance::varied(42));
+        assert!(Appearance::varied(42).colors.skin.is_some());
+        assert!(Appearance::varied(42).colors.primary.is_none());
+
+        let bodies: std::collections::HashSet<_> = (0..32).map(|seed| Appearance::varied(seed).body).collect();
+        assert!(bodies.len() > 1, "seed'ы должны давать разные пресеты тела");
+    }
+}
@@ -98,6 +98,12 @@ impl Stamina {
         }
     }
 
+    /// Непрерывный расход (спринт): clamp в 0, true если stamina закончилась
+    pub fn drain(&mut self, amount: f32) -> bool {
+        self.current = (self.current - amount).max(0.0);
+        self.current <= 0.0
+    }
+
     pub fn regenerate(&mut self, delta_time: f32) {
         self.current = (self.current + self.regen_rate * delta_time).min(self.max);
     }
@@ -5,8 +5,15 @@
 //! - Health (здоровье)
 //! - Stamina (выносливость)
 //! - PlayerControlled (маркер для игрока)
+//! - Appearance (внешность: тело, цвета, косметика)
 
+pub mod appearance;
 pub mod components;
 
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod appearance_tests;
+
 // Re-export all components
+pub use appearance::*;
 pub use components::*;
@@ -0,0 +1,30 @@
+//! BehaviorTree component.
+
+use bevy::prelude::*;
+use super::node::{BtAction, BtNode, BtParseError};
+
+/// Behavior tree NPC (альтернатива AIState FSM).
+///
+/// NPC с этим компонентом спавнятся без `AIState` — FSM системы их пропускают,
+/// решения принимает `run_behavior_trees`.
+#[derive(Component, Debug, Clone)]
+pub struct BehaviorTree {
+    /// Корневой узел (обычно Selector)
+    pub root: BtNode,
+    /// Действия последнего тика (debug)
+    pub last_actions: Vec<BtAction>,
+}
+
+impl BehaviorTree {
+    pub fn new(root: BtNode) -> Self {
+        Self {
+            root,
+            last_actions: Vec::new(),
+        }
+    }
+
+    /// Загрузить дерево из RON строки
+    pub fn from_ron(source: &str) -> Result<Self, BtParseError> {
+        BtNode::from_ron(source).map(Self::new)
+    }
+}
@@ -0,0 +1,91 @@
+//! Behavior tree evaluator (чистая функция: контекст → статус + действия).
+
+use bevy::prelude::*;
+use super::node::{BtAction, BtCondition, BtNode, BtStatus};
+
+/// Снимок состояния актора для оценки дерева (собирается adapter системой).
+#[derive(Debug, Clone, Default)]
+pub struct BtContext {
+    /// HP (0..1)
+    pub health_fraction: f32,
+    /// Stamina (0..1)
+    pub stamina_fraction: f32,
+    /// Текущая цель (ближайший spotted враг)
+    pub target: Option<Entity>,
+    /// Дистанция до цели (метры)
+    pub target_distance: Option<f32>,
+    /// Cooldown оружия истёк
+    pub weapon_ready: bool,
+    pub has_melee_weapon: bool,
+    pub has_ranged_weapon: bool,
+}
+
+impl BtCondition {
+    pub fn check(&self, ctx: &BtContext) -> bool {
+        match *self {
+            Self::HasTarget => ctx.target.is_some(),
+            Self::HealthBelow(fraction) => ctx.health_fraction < fraction,
+            Self::StaminaBelow(fraction) => ctx.stamina_fraction < fraction,
+            Self::TargetWithin(distance) => ctx.target_distance.is_some_and(|d| d <= distance),
+            Self::WeaponReady => ctx.weapon_ready,
+            Self::HasMeleeWeapon => ctx.has_melee_weapon,
+            Self::HasRangedWeapon => ctx.has_ranged_weapon,
+        }
+    }
+}
+
+/// Оценить дерево за один тик.
+///
+/// Выполненные действия добавляются в `actions` (adapter превращает их в команды/events).
+/// - Движение (Follow/Retreat/Idle) → Running (продолжается между тиками)
+/// - Атака/выстрел → Success (мгновенный intent), Failure если нет цели/оружия
+pub fn evaluate(node: &BtNode, ctx: &BtContext, actions: &mut Vec<BtAction>) -> BtStatus {
+    match node {
+        BtNode::Selector(children) => {
+            for child in children {
+                let status = evaluate(child, ctx, actions);
+                if status != BtStatus::Failure {
+                    return status;
+                }
+            }
+            BtStatus::Failure
+        }
+        BtNode::Sequence(children) => {
+            for child in children {
+                let status = evaluate(child, ctx, actions);
+                if status != BtStatus::Success {
+                    return status;
+                }
+            }
+            BtStatus::Success
+        }
+        BtNode::Condition(condition) => {
+            if condition.check(ctx) {
+                BtStatus::Success
+            } else {
+                BtStatus::Failure
+            }
+        }
+        BtNode::Action(action) => run_action(*action, ctx, actions),
+    }
+}
+
+fn run_action(action: BtAction, ctx: &BtContext, actions: &mut Vec<BtAction>) -> BtStatus {
+    let possible = match action {
+        BtAction::Idle => true,
+        BtAction::FollowTarget | BtAction::RetreatFromTarget => ctx.target.is_some(),
+        BtAction::MeleeAttack => ctx.target.is_some() && ctx.has_melee_weapon && ctx.weapon_ready,
+        BtAction::FireWeapon => ctx.target.is_some() && ctx.has_ranged_weapon && ctx.weapon_ready,
+    };
+
+    if !possible {
+        return BtStatus::Failure;
+    }
+
+    actions.push(action);
+
+    match action {
+        BtAction::Idle | BtAction::FollowTarget | BtAction::RetreatFromTarget => BtStatus::Running,
+        BtAction::MeleeAttack | BtAction::FireWeapon => BtStatus::Success,
+    }
+}
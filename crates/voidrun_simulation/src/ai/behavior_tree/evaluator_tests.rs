@@ -0,0 +1,92 @@
+//! Tests for behavior tree evaluator.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::Entity;
+    use super::super::evaluator::*;
+    use super::super::node::*;
+
+    const BRAWLER: &str = r#"
+        Selector([
+            Sequence([Condition(HealthBelow(0.3)), Action(RetreatFromTarget)]),
+            Sequence([Condition(TargetWithin(2.0)), Action(MeleeAttack)]),
+            Action(FollowTarget),
+            Action(Idle),
+        ])
+    "#;
+
+    fn ctx_with_target(distance: f32) -> BtContext {
+        BtContext {
+            health_fraction: 1.0,
+            stamina_fraction: 1.0,
+            target: Some(Entity::from_raw(7)),
+            target_distance: Some(distance),
+            weapon_ready: true,
+            has_melee_weapon: true,
+            has_ranged_weapon: false,
+        }
+    }
+
+    #[test]
+    fn test_bt_parse_ron() {
+        let tree = BtNode::from_ron(BRAWLER).expect("valid RON");
+
+        let BtNode::Selector(children) = tree else {
+            panic!("root must be Selector");
+        };
+        assert_eq!(children.len(), 4);
+        assert_eq!(children[3], BtNode::Action(BtAction::Idle));
+    }
+
+    #[test]
+    fn test_bt_parse_invalid_ron() {
+        assert!(BtNode::from_ron("Selector([Action(Dance)])").is_err());
+    }
+
+    #[test]
+    fn test_bt_selector_picks_first_viable_branch() {
+        let tree = BtNode::from_ron(BRAWLER).unwrap();
+
+        // В радиусе → атака
+        let mut actions = Vec::new();
+        assert_eq!(evaluate(&tree, &ctx_with_target(1.5), &mut actions), BtStatus::Success);
+        assert_eq!(actions, vec![BtAction::MeleeAttack]);
+
+        // Далеко → следуем
+        let mut actions = Vec::new();
+        assert_eq!(evaluate(&tree, &ctx_with_target(8.0), &mut actions), BtStatus::Running);
+        assert_eq!(actions, vec![BtAction::FollowTarget]);
+
+        // Нет цели → Idle
+        let mut actions = Vec::new();
+        assert_eq!(evaluate(&tree, &BtContext::default(), &mut actions), BtStatus::Running);
+        assert_eq!(actions, vec![BtAction::Idle]);
+    }
+
+    #[test]
+    fn test_bt_low_health_retreats() {
+        let tree = BtNode::from_ron(BRAWLER).unwrap();
+        let ctx = BtContext {
+            health_fraction: 0.2,
+            ..ctx_with_target(1.0)
+        };
+
+        let mut actions = Vec::new();
+        evaluate(&tree, &ctx, &mut actions);
+        assert_eq!(actions, vec![BtAction::RetreatFromTarget]);
+    }
+
+    #[test]
+    fn test_bt_sequence_stops_on_failure() {
+        // Оружие на cooldown → MeleeAttack Failure → Selector идёт дальше (FollowTarget)
+        let tree = BtNode::from_ron(BRAWLER).unwrap();
+        let ctx = BtContext {
+            weapon_ready: false,
+            ..ctx_with_target(1.0)
+        };
+
+        let mut actions = Vec::new();
+        evaluate(&tree, &ctx, &mut actions);
+        assert_eq!(actions, vec![BtAction::FollowTarget]);
+    }
+}
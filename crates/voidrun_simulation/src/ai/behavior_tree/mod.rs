@@ -0,0 +1,21 @@
+//! Behavior tree runtime (альтернатива FSM для сложных NPC)
+//!
+//! Data-driven деревья (Selector/Sequence/Condition/Action), загружаются из RON.
+//! Adapter система выдаёт те же выходы что FSM: MovementCommand, MeleeAttackIntent,
+//! WeaponFireIntent — Godot слой не знает, кто принял решение.
+//!
+//! Простые NPC остаются на FSM (AIState); NPC с `BehaviorTree` спавнятся без AIState.
+
+pub mod node;
+pub mod evaluator;
+pub mod component;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod evaluator_tests;
+
+pub use node::{BtAction, BtCondition, BtNode, BtParseError, BtStatus};
+pub use evaluator::{evaluate, BtContext};
+pub use component::BehaviorTree;
+pub use systems::run_behavior_trees;
@@ -0,0 +1,76 @@
+//! Behavior tree nodes (data-driven, RON).
+
+use serde::{Deserialize, Serialize};
+
+/// Ошибка парсинга RON (с позицией в исходнике)
+pub type BtParseError = ron::error::SpannedError;
+
+/// Узел behavior tree.
+///
+/// ```ron
+/// Selector([
+///     Sequence([Condition(HealthBelow(0.3)), Action(RetreatFromTarget)]),
+///     Sequence([Condition(TargetWithin(2.0)), Action(MeleeAttack)]),
+///     Action(FollowTarget),
+/// ])
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum BtNode {
+    /// Первый не-Failure ребёнок (OR)
+    Selector(Vec<BtNode>),
+    /// Все дети по порядку, до первого не-Success (AND)
+    Sequence(Vec<BtNode>),
+    /// Проверка (Success/Failure, без побочных эффектов)
+    Condition(BtCondition),
+    /// Действие (выдаёт команду через adapter)
+    Action(BtAction),
+}
+
+/// Условия (читают `BtContext`).
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum BtCondition {
+    /// Есть цель (ближайший spotted враг)
+    HasTarget,
+    /// HP < доли от max
+    HealthBelow(f32),
+    /// Stamina < доли от max
+    StaminaBelow(f32),
+    /// Цель ближе N метров (StrategicPosition, приблизительно)
+    TargetWithin(f32),
+    /// Оружие готово (cooldown истёк)
+    WeaponReady,
+    /// Melee оружие
+    HasMeleeWeapon,
+    /// Ranged оружие
+    HasRangedWeapon,
+}
+
+/// Действия (adapter переводит в MovementCommand / MeleeAttackIntent / WeaponFireIntent).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BtAction {
+    /// Стоять на месте
+    Idle,
+    /// Следовать за целью (FollowEntity)
+    FollowTarget,
+    /// Отступать от цели (RetreatFrom)
+    RetreatFromTarget,
+    /// Melee атака (MeleeAttackIntent)
+    MeleeAttack,
+    /// Выстрел по цели (WeaponFireIntent)
+    FireWeapon,
+}
+
+/// Результат узла.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BtStatus {
+    Success,
+    Failure,
+    Running,
+}
+
+impl BtNode {
+    /// Загрузить дерево из RON строки
+    pub fn from_ron(source: &str) -> Result<Self, BtParseError> {
+        ron::from_str(source)
+    }
+}
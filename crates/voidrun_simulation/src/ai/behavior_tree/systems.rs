@@ -0,0 +1,118 @@
+//! Behavior tree adapter system (BtAction → MovementCommand / intents).
+
+use bevy::prelude::*;
+use crate::components::{Actor, Health, Stamina, MovementCommand};
+use crate::combat::{
+    KnockdownState, MeleeAttackIntent, MeleeAttackState, MeleeAttackType, WeaponFireIntent,
+    WeaponStats,
+};
+use crate::ai::SpottedEnemies;
+use super::component::BehaviorTree;
+use super::evaluator::{evaluate, BtContext};
+use super::node::BtAction;
+
+/// Система: оценка behavior trees → те же выходы что у FSM
+///
+/// - Цель: ближайший живой враг из SpottedEnemies (по StrategicPosition)
+/// - Движение: MovementCommand (пишем только при изменении — Changed<MovementCommand>)
+/// - Атаки: MeleeAttackIntent / WeaponFireIntent (Godot валидирует как обычно)
+///
+/// Лежащие (KnockdownState) NPC не думают — стоят Idle.
+pub fn run_behavior_trees(
+    mut actors: Query<(
+        Entity,
+        &mut BehaviorTree,
+        &mut MovementCommand,
+        &mut WeaponStats,
+        &SpottedEnemies,
+        &Health,
+        &Stamina,
+        &crate::StrategicPosition,
+        Option<&MeleeAttackState>,
+        Option<&KnockdownState>,
+    ), With<Actor>>,
+    targets: Query<(&crate::StrategicPosition, &Health)>,
+    mut melee_intents: EventWriter<MeleeAttackIntent>,
+    mut fire_intents: EventWriter<WeaponFireIntent>,
+) {
+    for (entity, mut tree, mut command, mut weapon, spotted, health, stamina, position, attack, knockdown) in actors.iter_mut() {
+        if !health.is_alive() || knockdown.is_some() {
+            if !matches!(*command, MovementCommand::Idle) {
+                *command = MovementCommand::Idle;
+            }
+            continue;
+        }
+
+        let own_pos = position.to_world_position(0.5);
+
+        // Ближайший живой враг
+        let nearest = spotted
+            .enemies
+            .iter()
+            .filter_map(|&enemy| {
+                let (pos, target_health) = targets.get(enemy).ok()?;
+                target_health
+                    .is_alive()
+                    .then(|| (enemy, own_pos.distance(pos.to_world_position(0.5))))
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+
+        let ctx = BtContext {
+            health_fraction: health.current as f32 / health.max.max(1) as f32,
+            stamina_fraction: if stamina.max > 0.0 { stamina.current / stamina.max } else { 0.0 },
+            target: nearest.map(|(enemy, _)| enemy),
+            target_distance: nearest.map(|(_, distance)| distance),
+            weapon_ready: weapon.can_attack() && attack.is_none(),
+            has_melee_weapon: weapon.is_melee(),
+            has_ranged_weapon: weapon.is_ranged(),
+        };
+
+        let mut actions = Vec::new();
+        evaluate(&tree.root, &ctx, &mut actions);
+
+        // Движение: последнее выбранное движение (если ни одного — Idle)
+        let movement = actions
+            .iter()
+            .rev()
+            .find_map(|action| match (action, ctx.target) {
+                (BtAction::Idle, _) => Some(MovementCommand::Idle),
+                (BtAction::FollowTarget, Some(target)) => Some(MovementCommand::FollowEntity { target }),
+                (BtAction::RetreatFromTarget, Some(target)) => Some(MovementCommand::RetreatFrom { target }),
+                _ => None,
+            })
+            .unwrap_or(MovementCommand::Idle);
+
+        if *command != movement {
+            *command = movement;
+        }
+
+        for action in &actions {
+            match action {
+                BtAction::MeleeAttack => {
+                    melee_intents.write(MeleeAttackIntent {
+                        attacker: entity,
+                        attack_type: MeleeAttackType::Normal,
+                    });
+                }
+                BtAction::FireWeapon => {
+                    fire_intents.write(WeaponFireIntent {
+                        shooter: entity,
+                        target: ctx.target,
+                        damage: weapon.base_damage,
+                        speed: weapon.projectile_speed,
+                        max_range: weapon.range,
+                        hearing_range: weapon.hearing_range,
+                        ballistics: weapon.ballistics,
+                        fire_mode: weapon.fire_mode,
+                    });
+                    weapon.start_cooldown();
+                }
+                _ => {}
+            }
+        }
+
+        if tree.last_actions != actions {
+            tree.last_actions = actions;
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! AI blackboard (shared decision data per actor).
+
+use bevy::prelude::*;
+use crate::combat::{melee_intercept_point, KnockdownPhase, WeaponStats};
+
+/// Per-actor blackboard: perception системы пишут, decision системы читают.
+///
+/// Writers:
+/// - `update_ai_blackboards_main_thread` (Godot) — позиции/скорости/состояние цели,
+///   угроза цели из ThreatTable
+///
+/// Readers: ai_melee decision, melee движение и наведение (Godot), в будущем BT/utility AI.
+#[derive(Component, Debug, Clone, Default)]
+pub struct Blackboard {
+    /// Текущая боевая цель (из AIState::Combat)
+    pub target: Option<Entity>,
+    /// Последняя известная позиция цели (world, обновляется пока есть визуал)
+    pub last_target_position: Option<Vec3>,
+    /// Сглаженная скорость цели (XZ, м/с)
+    pub target_velocity: Vec3,
+    /// Собственная сглаженная скорость (XZ, м/с)
+    pub self_velocity: Vec3,
+    /// Knockdown цели: фаза + оставшееся время фазы
+    pub target_knockdown: Option<(KnockdownPhase, f32)>,
+    /// Цель Downed (0 HP, ждёт поднятия) — добить
+    pub target_downed: bool,
+    /// Угроза текущей цели (снимок ThreatTable)
+    pub target_threat: f32,
+    /// Cooldown hint: оружие готово к атаке
+    pub weapon_ready: bool,
+}
+
+impl Blackboard {
+    /// Цель лежит (можно добивать): knockdown Down или Downed
+    pub fn target_is_down(&self) -> bool {
+        self.target_downed || matches!(self.target_knockdown, Some((KnockdownPhase::Down, _)))
+    }
+
+    /// Цель встаёт: оставшееся время get-up
+    pub fn target_getting_up(&self) -> Option<f32> {
+        match self.target_knockdown {
+            Some((KnockdownPhase::GettingUp, remaining)) => Some(remaining),
+            _ => None,
+        }
+    }
+
+    /// Где будет цель в момент melee удара (цель движения и наведения); None — позиция неизвестна
+    pub fn target_intercept_point(&self, weapon: &WeaponStats) -> Option<Vec3> {
+        self.last_target_position
+            .map(|position| melee_intercept_point(position, self.target_velocity, weapon))
+    }
+}
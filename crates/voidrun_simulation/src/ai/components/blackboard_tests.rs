@@ -0,0 +1,47 @@
+//! Tests for AI blackboard.
+
+#[cfg(test)]
+mod tests {
+    use super::super::blackboard::*;
+    use crate::combat::KnockdownPhase;
+
+    #[test]
+    fn test_blackboard_target_knockdown_hints() {
+        let mut board = Blackboard::default();
+        assert!(!board.target_is_down());
+
+        board.target_knockdown = Some((KnockdownPhase::Down, 1.0));
+        assert!(board.target_is_down());
+        assert_eq!(board.target_getting_up(), None);
+
+        board.target_knockdown = Some((KnockdownPhase::GettingUp, 0.4));
+        assert_eq!(board.target_getting_up(), Some(0.4));
+    }
+
+    #[test]
+    fn test_blackboard_downed_target_is_down() {
+        let board = Blackboard {
+            target_downed: true,
+            ..Default::default()
+        };
+
+        assert!(board.target_is_down());
+        assert_eq!(board.target_getting_up(), None);
+    }
+
+    #[test]
+    fn test_blackboard_target_intercept_point() {
+        use bevy::prelude::*;
+        use crate::combat::WeaponStats;
+
+        let weapon = WeaponStats::melee_sword(); // удар через 0.45с
+        let mut board = Blackboard::default();
+        assert_eq!(board.target_intercept_point(&weapon), None);
+
+        board.last_target_position = Some(Vec3::new(0.0, 0.0, 3.0));
+        board.target_velocity = Vec3::new(-2.0, 0.0, 0.0);
+
+        let point = board.target_intercept_point(&weapon).unwrap();
+        assert!((point - Vec3::new(-0.9, 0.0, 3.0)).length() < 1e-4, "point = {:?}", point);
+    }
+}
@@ -0,0 +1,111 @@
+//! Chatter components (голосовые callouts AI + модель слышимости).
+//!
+//! AI выкрикивает callouts на переходах FSM / при вызове подкрепления / гибели союзника.
+//! Слышимость: линейное затухание по `voice_range`, каждая стена между говорящим
+//! и слушателем глушит голос в `WALL_OCCLUSION` раз. Тихий голос слышен, но неразборчив.
+
+use bevy::prelude::*;
+
+/// Доля громкости, проходящая через одну стену
+pub const WALL_OCCLUSION: f32 = 0.35;
+/// Ниже — не слышно вовсе
+pub const AUDIBLE_THRESHOLD: f32 = 0.05;
+/// Ниже — слышно, но слов не разобрать
+pub const INTELLIGIBLE_THRESHOLD: f32 = 0.3;
+
+/// Тип callout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum CalloutKind {
+    /// Вошёл в бой
+    Contact,
+    /// Потерял цель (Combat → Patrol/Idle)
+    LostContact,
+    /// Отступает
+    FallingBack,
+    /// Начал вызов подкрепления
+    CallingBackup,
+    /// Союзник погиб рядом
+    ManDown,
+}
+
+impl CalloutKind {
+    /// Дальность голоса (метры, громкость 0 на границе)
+    pub fn voice_range(self) -> f32 {
+        match self {
+            CalloutKind::Contact => 30.0,
+            CalloutKind::LostContact => 18.0,
+            CalloutKind::FallingBack => 25.0,
+            CalloutKind::CallingBackup => 20.0,
+            CalloutKind::ManDown => 30.0,
+        }
+    }
+
+    /// Реплика (субтитр)
+    pub fn line(self) -> &'static str {
+        match self {
+            CalloutKind::Contact => "Контакт! Вижу цель!",
+            CalloutKind::LostContact => "Потерял цель, прочёсываю.",
+            CalloutKind::FallingBack => "Отхожу! Прикройте!",
+            CalloutKind::CallingBackup => "Вызываю подкрепление!",
+            CalloutKind::ManDown => "Минус один! У нас потери!",
+        }
+    }
+}
+
+/// Насколько разборчиво услышан callout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalloutClarity {
+    Clear,
+    Muffled,
+}
+
+impl CalloutClarity {
+    /// None — не слышно
+    pub fn from_audibility(audibility: f32) -> Option<Self> {
+        if audibility >= INTELLIGIBLE_THRESHOLD {
+            Some(CalloutClarity::Clear)
+        } else if audibility >= AUDIBLE_THRESHOLD {
+            Some(CalloutClarity::Muffled)
+        } else {
+            None
+        }
+    }
+}
+
+/// Громкость голоса у слушателя [0, 1]: затухание по дистанции × стены
+pub fn callout_audibility(distance: f32, voice_range: f32, walls: u32) -> f32 {
+    if voice_range <= 0.0 {
+        return 0.0;
+    }
+    let falloff = (1.0 - distance / voice_range).clamp(0.0, 1.0);
+    falloff * WALL_OCCLUSION.powi(walls as i32)
+}
+
+/// Голос AI: cooldown реплик + прошлое состояние FSM (для детекта переходов)
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Chatter {
+    /// Пауза между репликами (секунды)
+    pub cooldown: f32,
+    /// Tick, с которого можно говорить снова
+    pub ready_at_tick: u64,
+    pub was_in_combat: bool,
+    pub was_retreating: bool,
+}
+
+impl Default for Chatter {
+    fn default() -> Self {
+        Self {
+            cooldown: 4.0,
+            ready_at_tick: 0,
+            was_in_combat: false,
+            was_retreating: false,
+        }
+    }
+}
+
+impl Chatter {
+    pub fn is_ready(&self, tick: u64) -> bool {
+        tick >= self.ready_at_tick
+    }
+}
@@ -0,0 +1,37 @@
+//! Tests for chatter components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::chatter::*;
+
+    #[test]
+    fn test_callout_audibility_falls_off_with_distance() {
+        let range = CalloutKind::Contact.voice_range();
+
+        assert_eq!(callout_audibility(0.0, range, 0), 1.0);
+        assert!(callout_audibility(10.0, range, 0) > callout_audibility(20.0, range, 0));
+        assert_eq!(callout_audibility(range + 1.0, range, 0), 0.0);
+    }
+
+    #[test]
+    fn test_walls_muffle_then_silence_callouts() {
+        let range = CalloutKind::Contact.voice_range();
+        let open = callout_audibility(6.0, range, 0);
+        let one_wall = callout_audibility(6.0, range, 1);
+        let three_walls = callout_audibility(6.0, range, 3);
+
+        assert_eq!(CalloutClarity::from_audibility(open), Some(CalloutClarity::Clear));
+        assert_eq!(CalloutClarity::from_audibility(one_wall), Some(CalloutClarity::Muffled));
+        assert_eq!(CalloutClarity::from_audibility(three_walls), None);
+    }
+
+    #[test]
+    fn test_chatter_ready_after_cooldown_tick() {
+        let mut chatter = Chatter::default();
+        assert!(chatter.is_ready(0));
+
+        chatter.ready_at_tick = 50;
+        assert!(!chatter.is_ready(49));
+        assert!(chatter.is_ready(50));
+    }
+}
@@ -0,0 +1,53 @@
+//! AI consumable usage components (self-heal when low).
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::components::Health;
+use crate::item_system::ItemId;
+
+/// AI consumable usage (параметры + per-item cooldowns).
+///
+/// Без этого компонента AI расходники не использует.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct AIConsumableUse {
+    /// Порог HP для лечения (доля от max HP)
+    pub heal_threshold: f32,
+    /// Не лечимся, если враг ближе (метры, melee range + запас)
+    pub min_enemy_distance: f32,
+    /// Длительность использования (секунды, interruptible Channeling)
+    pub use_duration: f32,
+    /// Cooldown одного и того же предмета (секунды)
+    pub item_cooldown: f32,
+    /// ItemId → tick, когда предмет снова доступен
+    pub cooldowns: HashMap<ItemId, u64>,
+}
+
+impl Default for AIConsumableUse {
+    fn default() -> Self {
+        Self {
+            heal_threshold: 0.35,
+            min_enemy_distance: 4.0,
+            use_duration: 1.2,
+            item_cooldown: 10.0,
+            cooldowns: HashMap::new(),
+        }
+    }
+}
+
+impl AIConsumableUse {
+    /// Готов ли предмет (cooldown истёк)
+    pub fn is_ready(&self, item: &ItemId, tick: u64) -> bool {
+        self.cooldowns.get(item).is_none_or(|ready_tick| tick >= *ready_tick)
+    }
+
+    /// Доля HP, если она ниже `heal_threshold` (пора лечиться); иначе None
+    ///
+    /// `max == 0` (HP не заданы) — доля не определена, не лечимся.
+    pub fn heal_needed(&self, health: &Health) -> Option<f32> {
+        let max = std::num::NonZeroU32::new(health.max)?;
+
+        let health_percent = health.current as f32 / max.get() as f32;
+        (health_percent < self.heal_threshold).then_some(health_percent)
+    }
+}
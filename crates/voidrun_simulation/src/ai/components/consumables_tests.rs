@@ -0,0 +1,43 @@
+//! Tests for AI consumable usage components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::consumables::*;
+    use crate::components::Health;
+    use crate::item_system::ItemId;
+
+    #[test]
+    fn test_heal_needed_below_threshold() {
+        let usage = AIConsumableUse::default(); // порог 35%
+        let health = Health { current: 30, max: 100 };
+
+        assert_eq!(usage.heal_needed(&health), Some(0.3));
+    }
+
+    #[test]
+    fn test_heal_not_needed_at_or_above_threshold() {
+        let usage = AIConsumableUse::default();
+
+        assert_eq!(usage.heal_needed(&Health { current: 35, max: 100 }), None);
+        assert_eq!(usage.heal_needed(&Health::new(100)), None);
+    }
+
+    #[test]
+    fn test_heal_not_needed_without_max_health() {
+        let usage = AIConsumableUse::default();
+
+        // max = 0 → доля не определена (0/0 = NaN), лечение не запускаем
+        assert_eq!(usage.heal_needed(&Health { current: 0, max: 0 }), None);
+    }
+
+    #[test]
+    fn test_item_cooldown() {
+        let mut usage = AIConsumableUse::default();
+        let item = ItemId::from("health_kit");
+        assert!(usage.is_ready(&item, 0));
+
+        usage.cooldowns.insert(item.clone(), 600);
+        assert!(!usage.is_ready(&item, 599));
+        assert!(usage.is_ready(&item, 600));
+    }
+}
@@ -0,0 +1,112 @@
+//! AI difficulty presets (spawn-time scaling).
+//!
+//! `Difficulty` — текущий уровень (меняется из Godot меню через SimulationBridge::set_difficulty).
+//! `DifficultyPresets` — таблица пресетов (data-driven, можно переопределить resource).
+//!
+//! Применяется один раз при spawn (`apply_difficulty_on_spawn`):
+//! смена уровня влияет только на новых NPC.
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Уровень сложности.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+pub enum DifficultyLevel {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+    Nightmare,
+}
+
+impl DifficultyLevel {
+    /// Из индекса Godot меню (0 = Easy … 3 = Nightmare)
+    pub fn from_index(index: i64) -> Option<Self> {
+        match index {
+            0 => Some(Self::Easy),
+            1 => Some(Self::Normal),
+            2 => Some(Self::Hard),
+            3 => Some(Self::Nightmare),
+            _ => None,
+        }
+    }
+}
+
+/// Текущая сложность (resource).
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct Difficulty {
+    pub level: DifficultyLevel,
+}
+
+/// Параметры одного пресета сложности.
+///
+/// Множители 1.0 = без изменений.
+#[derive(Debug, Clone)]
+pub struct DifficultyPreset {
+    /// Множитель max HP NPC
+    pub health_multiplier: f32,
+    /// Множитель разброса AimSkill
+    pub spread_multiplier: f32,
+    /// Множитель reaction delay AimSkill
+    pub reaction_multiplier: f32,
+    /// Добавка к AimSkill::tracking (clamp 0.0-1.0)
+    pub tracking_bonus: f32,
+    /// Множитель порогов retreat в AIConfig (меньше = дерётся до последнего)
+    pub retreat_threshold_multiplier: f32,
+    /// Добавка к AIConfig::parry_priority_bonus
+    pub parry_priority_bonus: f32,
+}
+
+/// Таблица пресетов (resource).
+#[derive(Resource, Debug, Clone)]
+pub struct DifficultyPresets {
+    pub presets: HashMap<DifficultyLevel, DifficultyPreset>,
+}
+
+impl Default for DifficultyPresets {
+    fn default() -> Self {
+        let mut presets = HashMap::new();
+
+        presets.insert(DifficultyLevel::Easy, DifficultyPreset {
+            health_multiplier: 0.75,
+            spread_multiplier: 2.0,
+            reaction_multiplier: 1.5,
+            tracking_bonus: -0.3,
+            retreat_threshold_multiplier: 1.5,
+            parry_priority_bonus: -0.3,
+        });
+        presets.insert(DifficultyLevel::Normal, DifficultyPreset {
+            health_multiplier: 1.0,
+            spread_multiplier: 1.0,
+            reaction_multiplier: 1.0,
+            tracking_bonus: 0.0,
+            retreat_threshold_multiplier: 1.0,
+            parry_priority_bonus: 0.0,
+        });
+        presets.insert(DifficultyLevel::Hard, DifficultyPreset {
+            health_multiplier: 1.25,
+            spread_multiplier: 0.6,
+            reaction_multiplier: 0.75,
+            tracking_bonus: 0.2,
+            retreat_threshold_multiplier: 0.75,
+            parry_priority_bonus: 0.1,
+        });
+        presets.insert(DifficultyLevel::Nightmare, DifficultyPreset {
+            health_multiplier: 1.6,
+            spread_multiplier: 0.3,
+            reaction_multiplier: 0.5,
+            tracking_bonus: 0.4,
+            retreat_threshold_multiplier: 0.5,
+            parry_priority_bonus: 0.2,
+        });
+
+        Self { presets }
+    }
+}
+
+impl DifficultyPresets {
+    /// Пресет для уровня (None если таблица переопределена без этого уровня)
+    pub fn get(&self, level: DifficultyLevel) -> Option<&DifficultyPreset> {
+        self.presets.get(&level)
+    }
+}
@@ -0,0 +1,31 @@
+//! Tests for AI difficulty presets.
+
+#[cfg(test)]
+mod tests {
+    use super::super::difficulty::*;
+
+    #[test]
+    fn test_difficulty_level_from_index() {
+        assert_eq!(DifficultyLevel::from_index(0), Some(DifficultyLevel::Easy));
+        assert_eq!(DifficultyLevel::from_index(3), Some(DifficultyLevel::Nightmare));
+        assert_eq!(DifficultyLevel::from_index(4), None);
+        assert_eq!(DifficultyLevel::default(), DifficultyLevel::Normal);
+    }
+
+    #[test]
+    fn test_default_presets_cover_all_levels() {
+        let presets = DifficultyPresets::default();
+
+        let normal = presets.get(DifficultyLevel::Normal).unwrap();
+        assert_eq!(normal.health_multiplier, 1.0);
+        assert_eq!(normal.spread_multiplier, 1.0);
+
+        // Сложнее → больше HP, точнее
+        let easy = presets.get(DifficultyLevel::Easy).unwrap();
+        let hard = presets.get(DifficultyLevel::Hard).unwrap();
+        let nightmare = presets.get(DifficultyLevel::Nightmare).unwrap();
+        assert!(easy.health_multiplier < hard.health_multiplier);
+        assert!(hard.health_multiplier < nightmare.health_multiplier);
+        assert!(easy.spread_multiplier > nightmare.spread_multiplier);
+    }
+}
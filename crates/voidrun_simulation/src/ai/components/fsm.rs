@@ -3,8 +3,12 @@
 use bevy::prelude::*;
 
 /// AI FSM состояния (event-driven)
+///
+/// Переходы с причиной пишутся в `AIStateHistory` (required).
+/// Недостижимые цели помнит `PerceptionMemory` (required).
 #[derive(Component, Debug, Clone, PartialEq, Reflect)]
 #[reflect(Component)]
+#[require(super::history::AIStateHistory, super::perception::PerceptionMemory)]
 pub enum AIState {
     /// Idle — начальное состояние после спавна
     Idle,
@@ -62,6 +66,8 @@ pub struct AIConfig {
     pub retreat_duration: f32,
     /// Patrol: время между сменой направления (секунды)
     pub patrol_direction_change_interval: f32,
+    /// Бонус к приоритету парирования в melee decision (difficulty scaling, 0.0 = базовый)
+    pub parry_priority_bonus: f32,
 }
 
 impl Default for AIConfig {
@@ -71,6 +77,7 @@ impl Default for AIConfig {
             retreat_health_threshold: 0.2,  // 20% health
             retreat_duration: 2.0,
             patrol_direction_change_interval: 10.0, // Каждые 10 сек новое направление (было 3 сек)
+            parry_priority_bonus: 0.0,
         }
     }
 }
@@ -17,6 +17,7 @@ mod tests {
         assert_eq!(config.retreat_health_threshold, 0.2);
         assert_eq!(config.retreat_duration, 2.0);
         assert_eq!(config.patrol_direction_change_interval, 10.0);
+        assert_eq!(config.parry_priority_bonus, 0.0);
     }
 
     #[test]
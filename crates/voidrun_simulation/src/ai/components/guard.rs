@@ -0,0 +1,37 @@
+//! Guard post components (территория + leash для оборонительных NPC).
+
+use bevy::prelude::*;
+
+/// Пост охраны: NPC защищает территорию вокруг `home`.
+///
+/// - Combat: не преследует цель за пределами `leash_radius` (держится у границы/дома)
+/// - Patrol: без PatrolRoute возвращается домой
+/// - Враг вошёл на территорию → `GuardAlarm` (союзники в `alarm_radius` подключаются)
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct GuardPost {
+    /// Позиция поста (world coordinates)
+    pub home: Vec3,
+    /// Радиус территории / leash (метры, XZ)
+    pub leash_radius: f32,
+    /// Радиус, в котором союзники слышат тревогу (метры)
+    pub alarm_radius: f32,
+    /// Нарушители, о которых тревога уже поднята (пока они на территории)
+    pub alerted_intruders: Vec<Entity>,
+}
+
+impl GuardPost {
+    pub fn new(home: Vec3, leash_radius: f32) -> Self {
+        Self {
+            home,
+            leash_radius,
+            alarm_radius: 20.0,
+            alerted_intruders: Vec::new(),
+        }
+    }
+
+    /// Позиция внутри территории (XZ, высота игнорируется)
+    pub fn contains(&self, position: Vec3) -> bool {
+        Vec2::new(position.x - self.home.x, position.z - self.home.z).length() <= self.leash_radius
+    }
+}
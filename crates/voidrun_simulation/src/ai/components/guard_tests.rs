@@ -0,0 +1,27 @@
+//! Tests for guard post components.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::guard::*;
+
+    #[test]
+    fn test_guard_post_territory_is_horizontal_circle() {
+        let post = GuardPost::new(Vec3::new(10.0, 0.0, 10.0), 5.0);
+
+        assert!(post.contains(Vec3::new(10.0, 0.0, 10.0)));
+        assert!(post.contains(Vec3::new(13.0, 0.0, 14.0))); // ровно на границе (3-4-5)
+        assert!(!post.contains(Vec3::new(13.0, 0.0, 14.1)));
+
+        // Высота не учитывается (балкон над постом — территория)
+        assert!(post.contains(Vec3::new(10.0, 20.0, 12.0)));
+    }
+
+    #[test]
+    fn test_guard_post_defaults() {
+        let post = GuardPost::new(Vec3::ZERO, 8.0);
+
+        assert_eq!(post.alarm_radius, 20.0);
+        assert!(post.alerted_intruders.is_empty());
+    }
+}
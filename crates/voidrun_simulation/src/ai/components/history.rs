@@ -0,0 +1,79 @@
+//! AI FSM transition history (отладка целей AI).
+//!
+//! Кольцевой буфер последних переходов AIState с причиной на каждого NPC — восстановить
+//! «почему NPC стоял / убежал / сменил цель» без раскопок в общем логе.
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+use super::fsm::AIState;
+
+/// Почему AI сменил состояние
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum TransitionCause {
+    /// Idle → Patrol после спавна / сброса
+    PatrolStarted,
+    /// Замечен враг (ActorSpotted / урон / выстрел)
+    Spotted { target: Entity },
+    /// Цель потеряна из виду или погибла
+    TargetLost { target: Entity },
+    /// Другая цель заметно опаснее (ThreatTable)
+    ThreatSwitch { from: Entity, to: Entity },
+    /// HP / stamina ниже порога отступления
+    RetreatThreshold { health_percent: f32, stamina_percent: f32 },
+    /// Прижат огнём (Suppressed)
+    Suppressed,
+    /// Таймер отступления истёк
+    RetreatEnded,
+    /// Godot не смог построить путь
+    NavigationFailed,
+    /// Путь до цели не строится (PerceptionMemory) — другая цель или патруль
+    TargetUnreachable { target: Entity },
+    /// HP = 0
+    Died,
+}
+
+/// Один переход FSM
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct AIStateTransition {
+    pub tick: u64,
+    pub from: AIState,
+    pub to: AIState,
+    pub cause: TransitionCause,
+}
+
+/// История переходов AIState (последние `CAPACITY`, старые вытесняются)
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct AIStateHistory {
+    pub transitions: VecDeque<AIStateTransition>,
+}
+
+impl AIStateHistory {
+    /// Сколько переходов хранится
+    pub const CAPACITY: usize = 8;
+
+    /// Записать переход (только смена варианта или цели — тики таймеров не пишутся)
+    pub fn record(&mut self, tick: u64, from: &AIState, to: &AIState, cause: TransitionCause) {
+        if !is_transition(from, to) {
+            return;
+        }
+        if self.transitions.len() == Self::CAPACITY {
+            self.transitions.pop_front();
+        }
+        self.transitions.push_back(AIStateTransition { tick, from: from.clone(), to: to.clone(), cause });
+    }
+
+    /// Последний переход
+    pub fn last(&self) -> Option<&AIStateTransition> {
+        self.transitions.back()
+    }
+}
+
+/// Смена варианта состояния или цели боя (Patrol с новым таймером — не переход)
+pub fn is_transition(from: &AIState, to: &AIState) -> bool {
+    match (from, to) {
+        (AIState::Combat { target: a }, AIState::Combat { target: b }) => a != b,
+        _ => std::mem::discriminant(from) != std::mem::discriminant(to),
+    }
+}
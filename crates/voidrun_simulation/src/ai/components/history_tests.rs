@@ -0,0 +1,38 @@
+//! Tests for AI FSM transition history.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::Entity;
+    use super::super::fsm::AIState;
+    use super::super::history::{is_transition, AIStateHistory, TransitionCause};
+
+    fn patrol(timer: f32) -> AIState {
+        AIState::Patrol { next_direction_timer: timer, target_position: None }
+    }
+
+    #[test]
+    fn test_timer_updates_are_not_transitions() {
+        assert!(!is_transition(&patrol(5.0), &patrol(4.9)));
+        assert!(is_transition(&AIState::Idle, &patrol(10.0)));
+
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        assert!(!is_transition(&AIState::Combat { target: a }, &AIState::Combat { target: a }));
+        assert!(is_transition(&AIState::Combat { target: a }, &AIState::Combat { target: b }));
+    }
+
+    #[test]
+    fn test_history_ring_buffer() {
+        let mut history = AIStateHistory::default();
+        history.record(1, &patrol(5.0), &patrol(4.0), TransitionCause::PatrolStarted);
+        assert!(history.transitions.is_empty());
+
+        for tick in 0..(AIStateHistory::CAPACITY as u64 + 2) {
+            history.record(tick, &AIState::Idle, &patrol(10.0), TransitionCause::PatrolStarted);
+        }
+
+        assert_eq!(history.transitions.len(), AIStateHistory::CAPACITY);
+        assert_eq!(history.transitions.front().unwrap().tick, 2);
+        assert_eq!(history.last().unwrap().cause, TransitionCause::PatrolStarted);
+    }
+}
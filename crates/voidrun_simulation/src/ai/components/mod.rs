@@ -1,10 +1,50 @@
 //! AI components
 
 pub mod fsm;
+pub mod history;
+pub mod difficulty;
+pub mod consumables;
+pub mod patrol;
+pub mod guard;
+pub mod perception;
+pub mod blackboard;
+pub mod threat;
+pub mod radio;
+pub mod chatter;
 
 // Tests (separate files with _tests suffix)
 #[cfg(test)]
 mod fsm_tests;
+#[cfg(test)]
+mod history_tests;
+#[cfg(test)]
+mod difficulty_tests;
+#[cfg(test)]
+mod consumables_tests;
+#[cfg(test)]
+mod patrol_tests;
+#[cfg(test)]
+mod guard_tests;
+#[cfg(test)]
+mod perception_tests;
+#[cfg(test)]
+mod blackboard_tests;
+#[cfg(test)]
+mod threat_tests;
+#[cfg(test)]
+mod radio_tests;
+#[cfg(test)]
+mod chatter_tests;
 
 // Re-export all components
 pub use fsm::*;
+pub use history::*;
+pub use difficulty::*;
+pub use consumables::*;
+pub use patrol::*;
+pub use guard::*;
+pub use perception::*;
+pub use blackboard::*;
+pub use threat::*;
+pub use radio::*;
+pub use chatter::*;
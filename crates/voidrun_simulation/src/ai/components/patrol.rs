@@ -0,0 +1,77 @@
+//! Patrol route components (waypoints вместо случайного патруля).
+
+use bevy::prelude::*;
+
+/// Режим обхода waypoints
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub enum PatrolMode {
+    /// A → B → C → A → ...
+    #[default]
+    Loop,
+    /// A → B → C → B → A → ...
+    PingPong,
+}
+
+/// Маршрут патруля (упорядоченные waypoints, world coordinates).
+///
+/// Без компонента (или с пустым списком) AIState::Patrol генерирует случайные точки.
+/// `ai_fsm_transitions` переключает waypoint при достижении `arrival_radius`.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct PatrolRoute {
+    pub waypoints: Vec<Vec3>,
+    pub mode: PatrolMode,
+    /// Дистанция (XZ), при которой waypoint считается достигнутым
+    pub arrival_radius: f32,
+    /// Индекс текущего waypoint
+    pub current: usize,
+    /// PingPong: направление обхода (true = по возрастанию индекса)
+    pub forward: bool,
+}
+
+impl PatrolRoute {
+    pub fn new(waypoints: Vec<Vec3>, mode: PatrolMode) -> Self {
+        Self {
+            waypoints,
+            mode,
+            arrival_radius: 1.0,
+            current: 0,
+            forward: true,
+        }
+    }
+
+    /// Текущий waypoint (None если маршрут пустой)
+    pub fn current_waypoint(&self) -> Option<Vec3> {
+        self.waypoints.get(self.current).copied()
+    }
+
+    /// Достигнут ли текущий waypoint (сравнение в XZ, высота игнорируется)
+    pub fn is_reached(&self, position: Vec3) -> bool {
+        self.current_waypoint().is_some_and(|waypoint| {
+            Vec2::new(waypoint.x - position.x, waypoint.z - position.z).length() <= self.arrival_radius
+        })
+    }
+
+    /// Перейти к следующему waypoint согласно `mode`
+    pub fn advance(&mut self) {
+        let len = self.waypoints.len();
+        if len < 2 {
+            return;
+        }
+
+        match self.mode {
+            PatrolMode::Loop => {
+                self.current = (self.current + 1) % len;
+            }
+            PatrolMode::PingPong => {
+                if self.forward && self.current + 1 >= len {
+                    self.forward = false;
+                } else if !self.forward && self.current == 0 {
+                    self.forward = true;
+                }
+
+                self.current = if self.forward { self.current + 1 } else { self.current - 1 };
+            }
+        }
+    }
+}
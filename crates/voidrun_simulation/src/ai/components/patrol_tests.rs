@@ -0,0 +1,52 @@
+//! Tests for patrol routes.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::patrol::*;
+
+    fn route(mode: PatrolMode) -> PatrolRoute {
+        PatrolRoute::new(
+            vec![Vec3::ZERO, Vec3::new(10.0, 0.0, 0.0), Vec3::new(10.0, 0.0, 10.0)],
+            mode,
+        )
+    }
+
+    fn visit_order(route: &mut PatrolRoute, steps: usize) -> Vec<usize> {
+        (0..steps)
+            .map(|_| {
+                route.advance();
+                route.current
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_loop_wraps_around() {
+        let mut route = route(PatrolMode::Loop);
+        assert_eq!(visit_order(&mut route, 4), vec![1, 2, 0, 1]);
+    }
+
+    #[test]
+    fn test_ping_pong_reverses_at_ends() {
+        let mut route = route(PatrolMode::PingPong);
+        assert_eq!(visit_order(&mut route, 6), vec![1, 2, 1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_waypoint_reached_ignores_height() {
+        let route = route(PatrolMode::Loop);
+
+        assert!(route.is_reached(Vec3::new(0.5, 3.0, 0.0)));
+        assert!(!route.is_reached(Vec3::new(2.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_empty_route() {
+        let mut route = PatrolRoute::new(Vec::new(), PatrolMode::Loop);
+        route.advance();
+
+        assert_eq!(route.current_waypoint(), None);
+        assert!(!route.is_reached(Vec3::ZERO));
+    }
+}
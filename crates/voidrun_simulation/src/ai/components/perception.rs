@@ -0,0 +1,276 @@
+//! Perception components (vision cone parameters per archetype, light level + visibility для stealth,
+//! память о недостижимых целях, на актора целятся в ADS).
+
+use bevy::prelude::*;
+use crate::movement::Stance;
+
+/// Параметры зрения (per archetype) — Godot применяет к VisionCone при spawn.
+///
+/// Без компонента используется форма VisionCone из prefab.
+///
+/// # Форма
+/// - Основной конус: `fov_degrees` × `range`
+/// - Периферия: более широкий сектор `peripheral_fov_degrees`, но короткий (`peripheral_range`)
+///
+/// VisionCone (Area3D) — выпуклая оболочка обоих секторов (грубый фильтр),
+/// видимость решает `sector_of`: каждый сектор со своими углом и дальностью.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct VisionConfig {
+    /// Угол основного конуса (градусы, полный)
+    pub fov_degrees: f32,
+    /// Дальность основного конуса (метры)
+    pub range: f32,
+    /// Угол периферийного зрения (градусы, полный, ≥ fov_degrees)
+    pub peripheral_fov_degrees: f32,
+    /// Дальность периферийного зрения (метры)
+    pub peripheral_range: f32,
+}
+
+impl Default for VisionConfig {
+    fn default() -> Self {
+        Self {
+            fov_degrees: 90.0,
+            range: 15.0,
+            peripheral_fov_degrees: 160.0,
+            peripheral_range: 4.0,
+        }
+    }
+}
+
+impl VisionConfig {
+    /// Ближний бой: широкий, но короткий обзор
+    pub fn brawler() -> Self {
+        Self {
+            fov_degrees: 110.0,
+            range: 10.0,
+            peripheral_fov_degrees: 180.0,
+            peripheral_range: 5.0,
+        }
+    }
+
+    /// Снайпер: узкий и дальний конус, слабая периферия
+    pub fn sniper() -> Self {
+        Self {
+            fov_degrees: 40.0,
+            range: 40.0,
+            peripheral_fov_degrees: 100.0,
+            peripheral_range: 3.0,
+        }
+    }
+
+    /// Сектор, в котором наблюдатель видит точку `offset` (от наблюдателя) при взгляде `forward`
+    ///
+    /// Проверка в горизонтальной плоскости (XZ). Основной сектор приоритетнее периферии.
+    /// None — точка вне обоих секторов (в т.ч. в "углах" выпуклой оболочки между ними).
+    pub fn sector_of(&self, forward: Vec3, offset: Vec3) -> Option<VisionSector> {
+        if in_sector(forward, offset, self.fov_degrees, self.range) {
+            Some(VisionSector::Main)
+        } else if in_sector(forward, offset, self.peripheral_fov_degrees, self.peripheral_range) {
+            Some(VisionSector::Peripheral)
+        } else {
+            None
+        }
+    }
+
+    /// Дальность сектора (для stealth порога обнаружения)
+    pub fn range_of(&self, sector: VisionSector) -> f32 {
+        match sector {
+            VisionSector::Main => self.range,
+            VisionSector::Peripheral => self.peripheral_range,
+        }
+    }
+}
+
+/// Сектор зрения (`VisionConfig::sector_of`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VisionSector {
+    /// Основной конус
+    Main,
+    /// Периферия
+    Peripheral,
+}
+
+/// Точка внутри сектора `fov_degrees` × `range` вокруг `forward` (XZ)
+fn in_sector(forward: Vec3, offset: Vec3, fov_degrees: f32, range: f32) -> bool {
+    let offset = Vec3::new(offset.x, 0.0, offset.z);
+    let forward = Vec3::new(forward.x, 0.0, forward.z);
+
+    let distance = offset.length();
+    if distance > range {
+        return false;
+    }
+    // Вплотную / наблюдатель без направления — направление не определено, считаем видимым
+    if distance <= f32::EPSILON || forward.length_squared() <= f32::EPSILON {
+        return true;
+    }
+
+    let half_angle = fov_degrees.clamp(0.0, 360.0).to_radians() * 0.5;
+    forward.angle_between(offset) <= half_angle
+}
+
+/// Минимальная доля дальности обнаружения в полной темноте
+pub const DARKNESS_VISIBILITY: f32 = 0.3;
+
+/// Освещённость позиции актора (0 = полная темнота, 1 = полный свет).
+///
+/// Пишет Godot light sampler (периодически, SlowUpdate).
+/// Stealth: в темноте актора видно только с близкой дистанции.
+/// Без компонента актор считается полностью освещённым.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct LightLevel {
+    /// Освещённость (0..1)
+    pub illumination: f32,
+}
+
+impl Default for LightLevel {
+    fn default() -> Self {
+        Self { illumination: 1.0 }
+    }
+}
+
+impl LightLevel {
+    pub fn new(illumination: f32) -> Self {
+        Self {
+            illumination: illumination.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Множитель дальности обнаружения (DARKNESS_VISIBILITY..1)
+    pub fn visibility(&self) -> f32 {
+        DARKNESS_VISIBILITY + (1.0 - DARKNESS_VISIBILITY) * self.illumination
+    }
+
+    /// Заметен ли актор на дистанции `distance` для конуса дальностью `range`
+    pub fn is_detectable(&self, distance: f32, range: f32) -> bool {
+        distance <= range * self.visibility()
+    }
+}
+
+/// Заметность актора (0..1): освещённость × стойка × движение.
+///
+/// Пересчитывается из `StealthSampled` (Godot сэмплирует свет и скорость, SlowUpdate).
+/// Vision poll требует `is_detectable` перед ActorSpotted.
+/// Без компонента актор полностью заметен.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Visibility {
+    pub score: f32,
+}
+
+impl Default for Visibility {
+    fn default() -> Self {
+        Self { score: 1.0 }
+    }
+}
+
+impl Visibility {
+    /// Минимальная воспринимаемая заметность для обнаружения
+    pub const DETECTION_THRESHOLD: f32 = 0.4;
+    /// Множитель движения на месте (бег = 1.0)
+    pub const STILL_FACTOR: f32 = 0.7;
+    /// Скорость, на которой движение максимально заметно (м/с, спринт)
+    pub const FULL_MOTION_SPEED: f32 = 6.0;
+    /// Вплотную замечается всегда (метры)
+    pub const ALWAYS_DETECT_DISTANCE: f32 = 1.5;
+
+    /// Заметность из стойки, горизонтальной скорости и освещённости
+    pub fn compute(stance: Stance, speed: f32, light: LightLevel) -> Self {
+        let motion = (speed / Self::FULL_MOTION_SPEED).clamp(0.0, 1.0);
+        let motion_factor = Self::STILL_FACTOR + (1.0 - Self::STILL_FACTOR) * motion;
+
+        Self {
+            score: (light.visibility() * stance.visibility_factor() * motion_factor).clamp(0.0, 1.0),
+        }
+    }
+
+    /// Воспринимаемая заметность: вблизи ×2, на границе конуса ×1
+    pub fn perceived(&self, distance: f32, range: f32) -> f32 {
+        let proximity = if range > 0.0 { (distance / range).clamp(0.0, 1.0) } else { 1.0 };
+        self.score * (2.0 - proximity)
+    }
+
+    /// Замечен ли актор на дистанции `distance` для конуса дальностью `range`
+    pub fn is_detectable(&self, distance: f32, range: f32) -> bool {
+        distance <= Self::ALWAYS_DETECT_DISTANCE || self.perceived(distance, range) >= Self::DETECTION_THRESHOLD
+    }
+}
+
+/// Недостижимая цель: Godot не построил путь (GodotNavigationEvent::NavigationFailed)
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct UnreachableTarget {
+    pub target: Entity,
+    /// Сколько ещё помним (секунды)
+    pub remaining: f32,
+}
+
+/// Память восприятия AI: до каких целей нельзя дойти.
+///
+/// FSM выбирает другую цель; ranged AI без альтернатив остаётся в бою и стреляет с места
+/// вместо бесконечного перепрокладывания пути. Запись забывается через `UNREACHABLE_MEMORY`
+/// (цель могла выйти в доступную зону).
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct PerceptionMemory {
+    pub unreachable: Vec<UnreachableTarget>,
+}
+
+impl PerceptionMemory {
+    /// Сколько помним недостижимую цель (секунды)
+    pub const UNREACHABLE_MEMORY: f32 = 8.0;
+
+    /// Пометить цель недостижимой (повторная пометка обновляет таймер)
+    pub fn mark_unreachable(&mut self, target: Entity) {
+        match self.unreachable.iter_mut().find(|entry| entry.target == target) {
+            Some(entry) => entry.remaining = Self::UNREACHABLE_MEMORY,
+            None => self.unreachable.push(UnreachableTarget {
+                target,
+                remaining: Self::UNREACHABLE_MEMORY,
+            }),
+        }
+    }
+
+    pub fn is_unreachable(&self, target: Entity) -> bool {
+        self.unreachable.iter().any(|entry| entry.target == target)
+    }
+
+    /// Забыть истёкшие записи
+    pub fn tick(&mut self, delta: f32) {
+        for entry in self.unreachable.iter_mut() {
+            entry.remaining -= delta;
+        }
+        self.unreachable.retain(|entry| entry.remaining > 0.0);
+    }
+}
+
+/// На актора целится враг в ADS (GodotAIEvent::AimedAt, обновляется каждый кадр прицеливания).
+///
+/// Снимается `apply_aimed_at`, когда прицел ушёл дольше `LINGER`.
+/// Источник угрозы для ThreatTable (`ThreatTable::AIMED_AT_RATE`).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct AimedAt {
+    /// Кто целится
+    pub aimer: Entity,
+    /// Сколько ещё считаем, что целятся (секунды)
+    pub remaining: f32,
+}
+
+impl AimedAt {
+    /// Сколько помним прицел после того, как он ушёл (секунды)
+    pub const LINGER: f32 = 0.5;
+
+    pub fn new(aimer: Entity) -> Self {
+        Self {
+            aimer,
+            remaining: Self::LINGER,
+        }
+    }
+
+    /// Tick. Returns `true` если прицел ушёл (пора снять компонент)
+    pub fn tick(&mut self, delta: f32) -> bool {
+        self.remaining -= delta;
+        self.remaining <= 0.0
+    }
+}
@@ -0,0 +1,154 @@
+//! Tests for perception components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::perception::*;
+    use crate::movement::Stance;
+
+    #[test]
+    fn test_light_level_clamped() {
+        assert_eq!(LightLevel::new(1.7).illumination, 1.0);
+        assert_eq!(LightLevel::new(-0.2).illumination, 0.0);
+    }
+
+    #[test]
+    fn test_light_level_visibility_range() {
+        assert!((LightLevel::new(0.0).visibility() - DARKNESS_VISIBILITY).abs() < 1e-6);
+        assert!((LightLevel::default().visibility() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_darkness_shortens_detection() {
+        let range = 15.0;
+
+        // На свету — весь конус
+        assert!(LightLevel::default().is_detectable(14.0, range));
+        // В темноте — только вблизи (30% от 15м = 4.5м)
+        assert!(!LightLevel::new(0.0).is_detectable(6.0, range));
+        assert!(LightLevel::new(0.0).is_detectable(4.0, range));
+    }
+
+    #[test]
+    fn test_visibility_crouch_and_stillness_reduce_score() {
+        let light = LightLevel::default();
+        let running = Visibility::compute(Stance::Standing, Visibility::FULL_MOTION_SPEED, light);
+        let still = Visibility::compute(Stance::Standing, 0.0, light);
+        let crouched = Visibility::compute(Stance::Crouched, 0.0, light);
+        let prone = Visibility::compute(Stance::Prone, 0.0, light);
+
+        assert!((running.score - 1.0).abs() < 1e-6);
+        assert!(still.score < running.score);
+        assert!(crouched.score < still.score);
+        assert!(prone.score < crouched.score);
+    }
+
+    #[test]
+    fn test_visibility_threshold_in_darkness() {
+        let range = 15.0;
+        let hidden = Visibility::compute(Stance::Crouched, 0.0, LightLevel::new(0.0));
+
+        // Пригнувшись в темноте — не замечен даже на середине конуса
+        assert!(!hidden.is_detectable(5.0, range));
+        // Вплотную — замечен всегда
+        assert!(hidden.is_detectable(1.0, range));
+        // На свету бегом — на всей дальности
+        assert!(Visibility::default().is_detectable(range, range));
+    }
+
+    #[test]
+    fn test_perception_memory_forgets_unreachable() {
+        use bevy::prelude::Entity;
+
+        let target = Entity::from_raw(5);
+        let mut memory = PerceptionMemory::default();
+        memory.mark_unreachable(target);
+        assert!(memory.is_unreachable(target));
+
+        // Повторный NavigationFailed — таймер заново, не дубликат
+        memory.tick(PerceptionMemory::UNREACHABLE_MEMORY - 1.0);
+        memory.mark_unreachable(target);
+        assert_eq!(memory.unreachable.len(), 1);
+
+        memory.tick(PerceptionMemory::UNREACHABLE_MEMORY - 1.0);
+        assert!(memory.is_unreachable(target));
+        memory.tick(1.0);
+        assert!(!memory.is_unreachable(target));
+    }
+
+    #[test]
+    fn test_aimed_at_lingers_then_expires() {
+        use bevy::prelude::Entity;
+
+        let aimer = Entity::from_raw(7);
+        let mut aimed = AimedAt::new(aimer);
+
+        assert!(!aimed.tick(AimedAt::LINGER * 0.5));
+        assert!(aimed.tick(AimedAt::LINGER));
+
+        // Повторное событие AimedAt → таймер заново
+        aimed = AimedAt::new(aimer);
+        assert_eq!(aimed.remaining, AimedAt::LINGER);
+    }
+
+    #[test]
+    fn test_vision_main_sector_uses_own_range_and_angle() {
+        use bevy::prelude::*;
+
+        let config = VisionConfig::default(); // main 90° × 15м, периферия 160° × 4м
+        let forward = Vec3::NEG_Z;
+
+        // Прямо по курсу, далеко — только основной конус
+        assert_eq!(config.sector_of(forward, Vec3::new(0.0, 0.0, -14.0)), Some(VisionSector::Main));
+        // 40° от оси (< 45°) на 10м — основной
+        let inside = Vec3::new(40f32.to_radians().sin(), 0.0, -40f32.to_radians().cos()) * 10.0;
+        assert_eq!(config.sector_of(forward, inside), Some(VisionSector::Main));
+        // Дальше range
+        assert_eq!(config.sector_of(forward, Vec3::new(0.0, 0.0, -16.0)), None);
+    }
+
+    #[test]
+    fn test_vision_peripheral_sector_uses_own_range_and_angle() {
+        use bevy::prelude::*;
+
+        let config = VisionConfig::default();
+        let forward = Vec3::NEG_Z;
+        let at = |degrees: f32, distance: f32| {
+            Vec3::new(degrees.to_radians().sin(), 0.0, -degrees.to_radians().cos()) * distance
+        };
+
+        // 70° от оси: вне основного (45°), внутри периферии (80°) на 3м
+        assert_eq!(config.sector_of(forward, at(70.0, 3.0)), Some(VisionSector::Peripheral));
+        // Тот же угол, но дальше peripheral_range
+        assert_eq!(config.sector_of(forward, at(70.0, 5.0)), None);
+        // Шире периферии
+        assert_eq!(config.sector_of(forward, at(85.0, 2.0)), None);
+        // Сзади
+        assert_eq!(config.sector_of(forward, Vec3::new(0.0, 0.0, 1.0)), None);
+
+        assert_eq!(config.range_of(VisionSector::Main), 15.0);
+        assert_eq!(config.range_of(VisionSector::Peripheral), 4.0);
+    }
+
+    #[test]
+    fn test_vision_hull_gap_between_sectors_is_not_seen() {
+        use bevy::prelude::*;
+
+        // Выпуклая оболочка соединяет конец основного конуса (45°, 15м) с краем периферии (80°, 4м):
+        // точка на 50° × 8м внутри оболочки, но ни в одном секторе
+        let config = VisionConfig::default();
+        let point = Vec3::new(50f32.to_radians().sin(), 0.0, -50f32.to_radians().cos()) * 8.0;
+
+        assert_eq!(config.sector_of(Vec3::NEG_Z, point), None);
+    }
+
+    #[test]
+    fn test_vision_sector_ignores_height() {
+        use bevy::prelude::*;
+
+        let config = VisionConfig::sniper();
+        assert_eq!(
+            config.sector_of(Vec3::new(0.0, -0.5, -1.0), Vec3::new(0.0, 6.0, -30.0)),
+            Some(VisionSector::Main)
+        );
+    }
+}
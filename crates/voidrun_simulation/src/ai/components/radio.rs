@@ -0,0 +1,47 @@
+//! Radio components (вызов подкрепления по рации).
+
+use bevy::prelude::*;
+
+/// Радист: в бою вызывает подкрепление (channelled, шумно).
+///
+/// Контригра: убить / застаггерить / прервать уроном во время вызова → вызов отменён.
+/// Cooldown ставится при старте — отменённый вызов тоже не спамится.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct RadioOperator {
+    /// Длительность вызова (секунды, interruptible Channeling)
+    pub call_duration: f32,
+    /// Пауза между вызовами (секунды)
+    pub cooldown: f32,
+    /// Сколько бойцов вызывает успешный вызов
+    pub reinforcements: u32,
+    /// Радиус, в котором враги слышат вызов (метры)
+    pub noise_radius: f32,
+    /// Tick, с которого можно звать снова
+    pub ready_at_tick: u64,
+}
+
+impl RadioOperator {
+    pub fn new(reinforcements: u32) -> Self {
+        Self {
+            call_duration: 3.0,
+            cooldown: 30.0,
+            reinforcements,
+            noise_radius: 12.0,
+            ready_at_tick: 0,
+        }
+    }
+
+    /// Можно ли начать вызов на данном tick
+    pub fn is_ready(&self, tick: u64) -> bool {
+        tick >= self.ready_at_tick
+    }
+}
+
+/// Вызов подкрепления в процессе (пока идёт Channeling::RadioCall).
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct CallingBackup {
+    /// Враг, против которого вызвано подкрепление
+    pub target: Entity,
+}
@@ -0,0 +1,16 @@
+//! Tests for radio components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::radio::*;
+
+    #[test]
+    fn test_radio_operator_ready_after_cooldown_tick() {
+        let mut radio = RadioOperator::new(2);
+        assert!(radio.is_ready(0));
+
+        radio.ready_at_tick = 100;
+        assert!(!radio.is_ready(99));
+        assert!(radio.is_ready(100));
+    }
+}
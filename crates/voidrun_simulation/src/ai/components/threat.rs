@@ -0,0 +1,102 @@
+//! Threat table (target selection by accumulated threat).
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Угроза по источникам — выбор цели по threat score вместо "первого замеченного".
+///
+/// Источники угрозы (`update_threat_tables`):
+/// - Полученный урон (damage × DAMAGE_WEIGHT)
+/// - Атаки по нам (выстрел в нас / видимый замах) — ATTACK_THREAT за атаку
+/// - Близость замеченного врага (PROXIMITY_RATE/сек вплотную, 0 на PROXIMITY_RANGE)
+/// - Враг держит нас на прицеле в ADS (AIMED_AT_RATE/сек)
+///
+/// Угроза затухает (DECAY_PER_SEC), записи с нулём удаляются.
+#[derive(Component, Debug, Clone, Default)]
+pub struct ThreatTable {
+    pub threats: HashMap<Entity, f32>,
+}
+
+impl ThreatTable {
+    /// Угроза за единицу урона
+    pub const DAMAGE_WEIGHT: f32 = 1.0;
+    /// Угроза за атаку по нам
+    pub const ATTACK_THREAT: f32 = 5.0;
+    /// Угроза/сек от врага вплотную
+    pub const PROXIMITY_RATE: f32 = 4.0;
+    /// Дальше — близость угрозы не добавляет (метры)
+    pub const PROXIMITY_RANGE: f32 = 10.0;
+    /// Угроза/сек, пока враг целится в нас в ADS (перекрывает затухание)
+    pub const AIMED_AT_RATE: f32 = 6.0;
+    /// Затухание (угроза/сек)
+    pub const DECAY_PER_SEC: f32 = 2.0;
+    /// Hysteresis: новая цель должна превышать угрозу текущей в N раз
+    pub const SWITCH_MARGIN: f32 = 1.2;
+
+    pub fn add(&mut self, source: Entity, amount: f32) {
+        if amount <= 0.0 {
+            return;
+        }
+        *self.threats.entry(source).or_insert(0.0) += amount;
+    }
+
+    pub fn add_damage(&mut self, source: Entity, damage: u32) {
+        self.add(source, damage as f32 * Self::DAMAGE_WEIGHT);
+    }
+
+    pub fn add_attack(&mut self, source: Entity) {
+        self.add(source, Self::ATTACK_THREAT);
+    }
+
+    pub fn add_proximity(&mut self, source: Entity, distance: f32, delta: f32) {
+        let closeness = (1.0 - distance / Self::PROXIMITY_RANGE).clamp(0.0, 1.0);
+        self.add(source, Self::PROXIMITY_RATE * closeness * delta);
+    }
+
+    pub fn add_aimed_at(&mut self, source: Entity, delta: f32) {
+        self.add(source, Self::AIMED_AT_RATE * delta);
+    }
+
+    /// Затухание; нулевые записи удаляются
+    pub fn decay(&mut self, delta: f32) {
+        let decay = Self::DECAY_PER_SEC * delta;
+        self.threats.retain(|_, threat| {
+            *threat -= decay;
+            *threat > 0.0
+        });
+    }
+
+    pub fn threat_of(&self, source: Entity) -> f32 {
+        self.threats.get(&source).copied().unwrap_or(0.0)
+    }
+
+    /// Выбрать цель среди кандидатов по угрозе
+    ///
+    /// - Максимальная угроза; при равенстве — первый кандидат (порядок вызывающего)
+    /// - Текущая цель (если среди кандидатов) сохраняется, пока лучший не превысит её
+    ///   угрозу в SWITCH_MARGIN раз (без дёрганья между равными целями)
+    pub fn select_target(
+        &self,
+        candidates: impl IntoIterator<Item = Entity>,
+        current: Option<Entity>,
+    ) -> Option<Entity> {
+        let mut best: Option<(Entity, f32)> = None;
+        let mut current_present = false;
+
+        for candidate in candidates {
+            current_present |= Some(candidate) == current;
+
+            let threat = self.threat_of(candidate);
+            if best.is_none_or(|(_, best_threat)| threat > best_threat) {
+                best = Some((candidate, threat));
+            }
+        }
+
+        let (best_entity, best_threat) = best?;
+
+        match current.filter(|_| current_present) {
+            Some(current) if best_threat <= self.threat_of(current) * Self::SWITCH_MARGIN => Some(current),
+            _ => Some(best_entity),
+        }
+    }
+}
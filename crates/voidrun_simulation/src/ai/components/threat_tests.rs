@@ -0,0 +1,81 @@
+//! Tests for threat table.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::Entity;
+    use super::super::threat::*;
+
+    fn entities() -> (Entity, Entity, Entity) {
+        (Entity::from_raw(1), Entity::from_raw(2), Entity::from_raw(3))
+    }
+
+    #[test]
+    fn test_threat_sources_accumulate() {
+        let (a, b, _) = entities();
+        let mut table = ThreatTable::default();
+
+        table.add_damage(a, 10);
+        table.add_attack(a);
+        assert_eq!(table.threat_of(a), 10.0 * ThreatTable::DAMAGE_WEIGHT + ThreatTable::ATTACK_THREAT);
+
+        // Вплотную 1 сек → PROXIMITY_RATE; за PROXIMITY_RANGE → ничего
+        table.add_proximity(b, 0.0, 1.0);
+        assert!((table.threat_of(b) - ThreatTable::PROXIMITY_RATE).abs() < 1e-5);
+        table.add_proximity(b, ThreatTable::PROXIMITY_RANGE + 1.0, 1.0);
+        assert!((table.threat_of(b) - ThreatTable::PROXIMITY_RATE).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_threat_decay_removes_entries() {
+        let (a, _, _) = entities();
+        let mut table = ThreatTable::default();
+
+        table.add(a, 1.0);
+        table.decay(1.0);
+        assert!(table.threats.is_empty());
+    }
+
+    #[test]
+    fn test_select_target_by_threat() {
+        let (a, b, c) = entities();
+        let mut table = ThreatTable::default();
+
+        // Без угрозы — первый кандидат (как раньше spotted.enemies.first())
+        assert_eq!(table.select_target([a, b, c], None), Some(a));
+
+        table.add(b, 20.0);
+        assert_eq!(table.select_target([a, b, c], None), Some(b));
+        assert_eq!(table.select_target(Vec::new(), None), None);
+    }
+
+    #[test]
+    fn test_select_target_hysteresis() {
+        let (a, b, _) = entities();
+        let mut table = ThreatTable::default();
+
+        table.add(a, 10.0);
+        table.add(b, 11.0);
+        // 11 < 10 × 1.2 → остаёмся на текущей
+        assert_eq!(table.select_target([a, b], Some(a)), Some(a));
+
+        table.add(b, 5.0);
+        assert_eq!(table.select_target([a, b], Some(a)), Some(b));
+
+        // Текущая цель не среди кандидатов (потеряна) → лучший
+        assert_eq!(table.select_target([b], Some(a)), Some(b));
+    }
+
+    #[test]
+    fn test_aimed_at_outpaces_decay() {
+        let (a, _, _) = entities();
+        let mut table = ThreatTable::default();
+
+        // Держит на прицеле 1 сек → угроза растёт быстрее затухания
+        for _ in 0..10 {
+            table.decay(0.1);
+            table.add_aimed_at(a, 0.1);
+        }
+        let expected = ThreatTable::AIMED_AT_RATE - ThreatTable::DECAY_PER_SEC;
+        assert!((table.threat_of(a) - expected).abs() < 0.5);
+    }
+}
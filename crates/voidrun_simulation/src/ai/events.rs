@@ -10,6 +10,7 @@ use bevy::prelude::*;
 /// Godot отправляет через Bevy Events когда:
 /// - ActorSpotted: враг вошёл в VisionCone
 /// - ActorLost: враг вышел из VisionCone
+/// - AimedAt: игрок в ADS навёл прицел на актора
 #[derive(Event, Debug, Clone)]
 pub enum GodotAIEvent {
     /// Враг обнаружен (entered VisionCone)
@@ -50,6 +51,17 @@ pub enum GodotAIEvent {
         /// Time remaining in windup phase (seconds)
         windup_remaining: f32,
     },
+
+    /// Игрок в ADS держит прицел на акторе (camera raycast, каждый кадр)
+    ///
+    /// Generated by `detect_aimed_at_main_thread` (Godot). ECS: `apply_aimed_at` → AimedAt
+    /// (угроза в ThreatTable). Только враги: союзники игнорируют.
+    AimedAt {
+        /// Кто целится (игрок в ADS)
+        aimer: Entity,
+        /// На кого наведён прицел
+        target: Entity,
+    },
 }
 
 /// Transform события от Godot (PostSpawn коррекция + движение)
@@ -83,6 +95,83 @@ pub enum GodotNavigationEvent {
     },
 }
 
+/// Stealth сэмпл актора (Godot → ECS, SlowUpdate)
+///
+/// Godot light sampler: освещённость позиции + горизонтальная скорость.
+/// Обрабатывается `apply_stealth_samples` → LightLevel + Visibility.
+#[derive(Event, Debug, Clone)]
+pub struct StealthSampled {
+    pub entity: Entity,
+    /// Освещённость (0..1)
+    pub illumination: f32,
+    /// Горизонтальная скорость (м/с)
+    pub speed: f32,
+}
+
+/// Радист начал вызов подкрепления (ECS → Godot telegraph)
+///
+/// Генерируется `ai_call_for_backup`. Игрок видит/слышит вызов и может прервать его.
+#[derive(Event, Debug, Clone)]
+pub struct BackupCallStarted {
+    pub caller: Entity,
+    /// Позиция радиста (world coordinates)
+    pub position: Vec3,
+    /// Длительность вызова (секунды)
+    pub duration: f32,
+}
+
+/// Почему вызов подкрепления отменён
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackupCallCancelReason {
+    /// Радист убит
+    Killed,
+    /// Stagger / knockdown
+    Staggered,
+    /// Прерван уроном / flinch
+    Interrupted,
+}
+
+/// Вызов подкрепления отменён (контригра сработала)
+#[derive(Event, Debug, Clone)]
+pub struct BackupCallCancelled {
+    pub caller: Entity,
+    pub reason: BackupCallCancelReason,
+}
+
+/// Вызов подкрепления завершён → ReinforcementsRequested
+#[derive(Event, Debug, Clone)]
+pub struct BackupCallCompleted {
+    pub caller: Entity,
+}
+
+/// AI выкрикнул callout (ECS → Godot)
+///
+/// Генерируется `ai_emit_callouts`. Godot считает стены между говорящим и игроком
+/// (`callout_audibility`) и показывает услышанное субтитром.
+#[derive(Event, Debug, Clone)]
+pub struct Callout {
+    pub speaker: Entity,
+    pub kind: super::components::CalloutKind,
+    /// Позиция говорящего (world coordinates)
+    pub position: Vec3,
+}
+
+/// Тревога поста охраны (ECS → ECS)
+///
+/// Генерируется `raise_guard_alarms`, когда spotted враг входит на территорию GuardPost.
+/// Один раз на нарушителя, пока он остаётся на территории.
+#[derive(Event, Debug, Clone)]
+pub struct GuardAlarm {
+    /// Охранник, поднявший тревогу
+    pub guard: Entity,
+    /// Нарушитель
+    pub intruder: Entity,
+    /// Позиция поста (центр территории)
+    pub home: Vec3,
+    /// Радиус, в котором союзники слышат тревогу
+    pub alarm_radius: f32,
+}
+
 /// Combat события (ECS → ECS, для AI reaction)
 ///
 /// Эти события генерируются в ECS combat системах и используются AI для принятия решений.
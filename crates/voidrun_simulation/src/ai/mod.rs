@@ -4,14 +4,27 @@
 //! Архитектура: docs/arch_backlog.md (#5)
 
 use bevy::prelude::*;
+use crate::game_state::GameState;
 
 // Domain modules
 pub mod components;
 pub mod systems;
 pub mod events;
+pub mod behavior_tree;
 
 // Re-export components
-pub use components::{AIState, AIConfig, SpottedEnemies};
+pub use components::{
+    AIState, AIConfig, SpottedEnemies,
+    AIStateHistory, AIStateTransition, TransitionCause,
+    Difficulty, DifficultyLevel, DifficultyPreset, DifficultyPresets,
+    AIConsumableUse,
+    PatrolRoute, PatrolMode,
+    GuardPost,
+    VisionConfig, VisionSector, LightLevel, Visibility, AimedAt, PerceptionMemory, UnreachableTarget,
+    Blackboard, ThreatTable,
+    RadioOperator, CallingBackup,
+    Chatter, CalloutKind, CalloutClarity, callout_audibility,
+};
 
 // Re-export systems
 pub use systems::{
@@ -21,10 +34,31 @@ pub use systems::{
     ai_movement_from_state, ai_attack_execution, simple_collision_resolution,
     // Reaction systems
     handle_actor_death, react_to_damage, ai_react_to_gunfire,
+    // Difficulty systems
+    apply_difficulty_on_spawn,
+    // Consumable systems
+    ai_consumable_decision,
+    // Guard systems
+    raise_guard_alarms, respond_to_guard_alarms,
+    // Blackboard / threat systems
+    insert_ai_blackboards, update_threat_tables,
+    // Perception systems
+    apply_stealth_samples, apply_aimed_at,
+    // Radio systems
+    ai_call_for_backup, resolve_backup_calls,
+    // Chatter systems
+    insert_ai_chatter, ai_emit_callouts,
 };
 
+// Re-export behavior tree runtime
+pub use behavior_tree::{BehaviorTree, BtNode, BtAction, BtCondition, BtStatus, BtParseError, run_behavior_trees};
+
 // Re-export events
-pub use events::{GodotAIEvent, GodotTransformEvent, GodotNavigationEvent, CombatAIEvent};
+pub use events::{
+    GodotAIEvent, GodotTransformEvent, GodotNavigationEvent, CombatAIEvent, GuardAlarm, StealthSampled,
+    BackupCallStarted, BackupCallCancelled, BackupCallCancelReason, BackupCallCompleted,
+    Callout,
+};
 
 /// AI Plugin
 ///
@@ -32,6 +66,7 @@ pub use events::{GodotAIEvent, GodotTransformEvent, GodotNavigationEvent, Combat
 /// Порядок выполнения:
 /// 1. ai_fsm_transitions — обновление FSM state
 /// 2. ai_movement_from_state — конвертация state → MovementCommand
+///    (run_behavior_trees — то же для NPC с BehaviorTree)
 /// 3. simple_collision_resolution — отталкивание NPC друг от друга
 ///
 /// NOTE: Атаки генерируются через combat systems (ai_melee_attack_intent, ai_weapon_fire_intent)
@@ -44,20 +79,49 @@ impl Plugin for AIPlugin {
         app.add_event::<GodotTransformEvent>();
         app.add_event::<GodotNavigationEvent>();
         app.add_event::<CombatAIEvent>();
+        app.add_event::<GuardAlarm>();
+        app.add_event::<StealthSampled>();
+        app.add_event::<BackupCallStarted>();
+        app.add_event::<BackupCallCancelled>();
+        app.add_event::<BackupCallCompleted>();
+        app.add_event::<Callout>();
+        app.init_resource::<Difficulty>();
+        app.init_resource::<DifficultyPresets>();
         app.add_systems(
             FixedUpdate,
             (
-                sync_strategic_position_from_godot_events, // 0. Event-driven sync (Godot → ECS)
-                handle_actor_death,          // 1. Обработка смерти → Dead state
-                update_spotted_enemies,      // 2. Обновляем SpottedEnemies из GodotAIEvent
-                react_to_damage,             // 3. AI реакция на урон (DamageDealt → FollowEntity)
-                ai_react_to_gunfire,         // 4. AI реакция на звук выстрела (WeaponFired → ActorSpotted)
-                ai_fsm_transitions,          // 5. FSM transitions на основе SpottedEnemies
-                ai_movement_from_state,      // 6. Конвертация state → MovementCommand
-                // УДАЛЕНО: ai_attack_execution (заменён на ai_melee_attack_intent в combat systems)
-                simple_collision_resolution, // 7. Отталкивание NPC
+                (
+                    apply_difficulty_on_spawn,   // 0. Difficulty пресет для новых NPC (Added<AIConfig>)
+                    sync_strategic_position_from_godot_events, // 0. Event-driven sync (Godot → ECS)
+                    apply_stealth_samples,       // 0.1. StealthSampled → LightLevel + Visibility
+                    handle_actor_death,          // 1. Обработка смерти → Dead state
+                    update_spotted_enemies,      // 2. Обновляем SpottedEnemies из GodotAIEvent
+                    apply_aimed_at,              // 2.1. GodotAIEvent::AimedAt → AimedAt (игрок целится в ADS)
+                    react_to_damage,             // 3. AI реакция на урон (DamageDealt → FollowEntity)
+                    insert_ai_blackboards,       // 3.1. Blackboard + ThreatTable для новых AI
+                    insert_ai_chatter,           // 3.1.1. Chatter (голос callouts) для новых AI
+                    update_threat_tables,        // 3.2. Урон/атаки/близость → ThreatTable (+ decay)
+                    ai_react_to_gunfire,         // 4. AI реакция на звук выстрела (WeaponFired → ActorSpotted)
+                    raise_guard_alarms,          // 4.1. Враг на территории GuardPost → GuardAlarm
+                    respond_to_guard_alarms,     // 4.2. Союзники охранника → ActorSpotted нарушителя
+                    handle_navigation_failed,    // 4.3. NavigationFailed → цель в PerceptionMemory (недостижима)
+                )
+                    .chain(),
+                (
+                    ai_fsm_transitions,          // 5. FSM transitions на основе SpottedEnemies
+                    ai_movement_from_state,      // 6. Конвертация state → MovementCommand
+                    run_behavior_trees,          // 6.1. BT NPC (без AIState) → MovementCommand + intents
+                    ai_consumable_decision,      // 6.5. Self-heal (HP low, враг не рядом → Channeling)
+                    ai_call_for_backup,          // 6.6. Радист в бою → Channeling(RadioCall) + telegraph
+                    resolve_backup_calls,        // 6.7. Вызов завершён → подкрепления / сорван → отмена
+                    ai_emit_callouts,            // 6.8. Переходы FSM / вызов / гибель союзника → Callout
+                    // УДАЛЕНО: ai_attack_execution (заменён на ai_melee_attack_intent в combat systems)
+                    simple_collision_resolution, // 7. Отталкивание NPC
+                )
+                    .chain(),
             )
-                .chain(), // Последовательное выполнение для детерминизма
+                .chain() // Последовательное выполнение для детерминизма
+                .run_if(in_state(GameState::Playing)),
         );
     }
 }
@@ -97,17 +161,36 @@ pub fn sync_strategic_position_from_godot_events(
     }
 }
 
+/// Godot не построил путь → стратегическое решение (вместо бесконечного перепрокладывания)
+///
+/// - Combat: цель помечается недостижимой в PerceptionMemory — `ai_fsm_transitions` выберет
+///   другую цель (ranged без альтернатив — обстрел с места, melee — патруль)
+/// - Остальные состояния (точка патруля / отступления недостижима): сброс в Idle → новая точка
 pub fn handle_navigation_failed(
-    mut actors: Query<(Entity, &mut AIState)>,
+    mut actors: Query<(&mut AIState, &mut PerceptionMemory, Option<&mut AIStateHistory>)>,
     mut navigation_events: EventReader<GodotNavigationEvent>,
+    tick: Res<crate::SimulationTick>,
 ) {
     for event in navigation_events.read() {
-        let GodotNavigationEvent::NavigationFailed { entity } = event else {
-            continue;
-        };
-        let Ok((entity, mut state)) = actors.get_mut(*entity) else {
+        let GodotNavigationEvent::NavigationFailed { entity } = event;
+        let Ok((mut state, mut memory, history)) = actors.get_mut(*entity) else {
             continue;
         };
-        *state = AIState::Idle;
+
+        match *state {
+            AIState::Dead => {}
+            AIState::Combat { target } => {
+                if !memory.is_unreachable(target) {
+                    crate::logger::log(&format!("🚧 {:?}: no path to target {:?} → unreachable", entity, target));
+                }
+                memory.mark_unreachable(target);
+            }
+            _ => {
+                if let Some(mut history) = history {
+                    history.record(tick.get(), &state, &AIState::Idle, TransitionCause::NavigationFailed);
+                }
+                *state = AIState::Idle;
+            }
+        }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,21 @@
+//! Blackboard systems (per-actor AI data setup).
+
+use bevy::prelude::*;
+use crate::ai::{AIConfig, Blackboard, ThreatTable};
+
+/// Система: AI акторы (AIConfig) получают пустые Blackboard и ThreatTable
+///
+/// Заполняют: `update_threat_tables` (ECS), `update_ai_blackboards_main_thread` (Godot).
+pub fn insert_ai_blackboards(
+    missing_boards: Query<Entity, (With<AIConfig>, Without<Blackboard>)>,
+    missing_threats: Query<Entity, (With<AIConfig>, Without<ThreatTable>)>,
+    mut commands: Commands,
+) {
+    for entity in missing_boards.iter() {
+        commands.entity(entity).insert(Blackboard::default());
+    }
+
+    for entity in missing_threats.iter() {
+        commands.entity(entity).insert(ThreatTable::default());
+    }
+}
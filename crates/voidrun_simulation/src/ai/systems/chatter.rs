@@ -0,0 +1,105 @@
+//! Chatter systems (callouts AI на переходах FSM / вызове подкрепления / гибели союзника).
+
+use bevy::prelude::*;
+use crate::ai::{AIConfig, AIState, BackupCallStarted, Callout, CalloutKind, Chatter};
+use crate::combat::EntityDied;
+use crate::components::{Actor, Health};
+use crate::{SimulationTick, StrategicPosition};
+
+/// System: Chatter для новых AI
+pub fn insert_ai_chatter(missing: Query<Entity, (With<AIConfig>, Without<Chatter>)>, mut commands: Commands) {
+    for entity in missing.iter() {
+        commands.entity(entity).insert(Chatter::default());
+    }
+}
+
+/// System: AI → Callout
+///
+/// - Переходы FSM: → Combat (Contact), Combat → Patrol/Idle (LostContact), → Retreat (FallingBack)
+/// - BackupCallStarted → CallingBackup (без cooldown — у радиста свой)
+/// - EntityDied → ManDown от ближайшего живого союзника в пределах голоса
+pub fn ai_emit_callouts(
+    mut speakers: Query<(Entity, &Actor, &AIState, &Health, &StrategicPosition, &mut Chatter)>,
+    actors: Query<(&Actor, &StrategicPosition)>,
+    mut backup_events: EventReader<BackupCallStarted>,
+    mut death_events: EventReader<EntityDied>,
+    tick: Res<SimulationTick>,
+    mut callouts: EventWriter<Callout>,
+) {
+    let now = tick.get();
+
+    for (entity, _, state, health, position, mut chatter) in speakers.iter_mut() {
+        let in_combat = matches!(state, AIState::Combat { .. });
+        let retreating = matches!(state, AIState::Retreat { .. });
+
+        let kind = if !health.is_alive() || matches!(state, AIState::Dead) {
+            None
+        } else if in_combat && !chatter.was_in_combat {
+            Some(CalloutKind::Contact)
+        } else if retreating && !chatter.was_retreating {
+            Some(CalloutKind::FallingBack)
+        } else if chatter.was_in_combat && !in_combat && !retreating {
+            Some(CalloutKind::LostContact)
+        } else {
+            None
+        };
+
+        chatter.was_in_combat = in_combat;
+        chatter.was_retreating = retreating;
+
+        let Some(kind) = kind else {
+            continue;
+        };
+        if !chatter.is_ready(now) {
+            continue;
+        }
+
+        chatter.ready_at_tick = tick.after_secs(chatter.cooldown);
+        callouts.write(Callout {
+            speaker: entity,
+            kind,
+            position: position.to_world_position(0.5),
+        });
+    }
+
+    for started in backup_events.read() {
+        callouts.write(Callout {
+            speaker: started.caller,
+            kind: CalloutKind::CallingBackup,
+            position: started.position,
+        });
+    }
+
+    for died in death_events.read() {
+        let Ok((dead_actor, dead_position)) = actors.get(died.entity) else {
+            continue;
+        };
+        let dead_pos = dead_position.to_world_position(0.5);
+        let range = CalloutKind::ManDown.voice_range();
+
+        let witness = speakers
+            .iter()
+            .filter(|(entity, actor, _, health, _, _)| {
+                *entity != died.entity && actor.faction_id == dead_actor.faction_id && health.is_alive()
+            })
+            .map(|(entity, _, _, _, position, _)| {
+                let pos = position.to_world_position(0.5);
+                (entity, pos, pos.distance(dead_pos))
+            })
+            .filter(|(_, _, distance)| *distance <= range)
+            .min_by(|a, b| a.2.total_cmp(&b.2));
+
+        let Some((speaker, position, _)) = witness else {
+            continue;
+        };
+
+        if let Ok((_, _, _, _, _, mut chatter)) = speakers.get_mut(speaker) {
+            chatter.ready_at_tick = tick.after_secs(chatter.cooldown);
+        }
+        callouts.write(Callout {
+            speaker,
+            kind: CalloutKind::ManDown,
+            position,
+        });
+    }
+}
@@ -0,0 +1,91 @@
+//! AI consumable usage systems (self-heal decision).
+
+use bevy::prelude::*;
+use crate::ai::{AIConsumableUse, AIState, SpottedEnemies};
+use crate::combat::{ActionKind, ActionLock, ActionPhase, CancelTable, ChannelKind, Channeling};
+use crate::components::{ConsumableSlots, Health};
+use crate::item_system::{ConsumableEffect, ItemDefinitions};
+use crate::{SimulationTick, StrategicPosition};
+
+/// System: AI решает использовать лечащий расходник
+///
+/// Условия:
+/// - HP < heal_threshold
+/// - Ни один spotted враг не ближе min_enemy_distance (StrategicPosition, приблизительно)
+/// - В разблокированном слоте есть RestoreHealth предмет без cooldown
+/// - ActionLock + CancelTable разрешают UseConsumable (не в атаке/парировании/stagger)
+///
+/// Результат: Channeling(Consumable { slot }) — interruptible "using item" state.
+/// Эффект применяется по ChannelCompleted (`complete_consumable_channels` → UseConsumableIntent).
+pub fn ai_consumable_decision(
+    mut ai_query: Query<
+        (
+            Entity,
+            &AIState,
+            &Health,
+            &ConsumableSlots,
+            &SpottedEnemies,
+            &StrategicPosition,
+            &mut AIConsumableUse,
+            Option<&ActionLock>,
+        ),
+        Without<Channeling>,
+    >,
+    positions: Query<&StrategicPosition>,
+    definitions: Res<ItemDefinitions>,
+    cancel_table: Res<CancelTable>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    for (entity, state, health, slots, spotted, position, mut usage, lock) in ai_query.iter_mut() {
+        if matches!(state, AIState::Dead) || !health.is_alive() {
+            continue;
+        }
+
+        let Some(health_percent) = usage.heal_needed(health) else {
+            continue;
+        };
+
+        // Враг в melee range → лечиться опасно
+        let own_pos = position.to_world_position(0.5);
+        let enemy_too_close = spotted.enemies.iter().any(|enemy| {
+            positions
+                .get(*enemy)
+                .map(|enemy_pos| own_pos.distance(enemy_pos.to_world_position(0.5)) < usage.min_enemy_distance)
+                .unwrap_or(false)
+        });
+        if enemy_too_close {
+            continue;
+        }
+
+        // Первый разблокированный слот с лечилкой без cooldown
+        let heal_slot = (0..slots.unlocked_count).find_map(|slot_index| {
+            let item = slots.get_slot(slot_index)?;
+            let def = definitions.get(&item.definition_id)?;
+            let is_heal = matches!(def.consumable_effect, Some(ConsumableEffect::RestoreHealth { .. }));
+            (is_heal && usage.is_ready(&item.definition_id, tick.get()))
+                .then(|| (slot_index, item.definition_id.clone()))
+        });
+        let Some((slot_index, item_id)) = heal_slot else {
+            continue;
+        };
+
+        if !ActionLock::permits(lock, ActionKind::UseConsumable, &cancel_table) {
+            continue;
+        }
+
+        commands.entity(entity).insert((
+            Channeling::new(ChannelKind::Consumable { slot_index }, tick.after_secs(usage.use_duration)),
+            ActionLock::new(ActionKind::UseConsumable, ActionPhase::Active),
+        ));
+
+        // Cooldown ставится при старте: прерванное использование тоже не спамится
+        let ready_tick = tick.after_secs(usage.item_cooldown);
+        usage.cooldowns.insert(item_id.clone(), ready_tick);
+
+        crate::logger::log(&format!(
+            "💊 AI: {:?} using {:?} from slot {} (HP {:.0}%)",
+            entity, item_id, slot_index, health_percent * 100.0
+        ));
+    }
+}
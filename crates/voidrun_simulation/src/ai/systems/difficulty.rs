@@ -0,0 +1,47 @@
+//! Difficulty systems (spawn-time scaling).
+
+use bevy::prelude::*;
+use crate::ai::{AIConfig, Difficulty, DifficultyPresets};
+use crate::combat::AimSkill;
+use crate::components::Health;
+
+/// System: применить текущий пресет сложности к только что заспавненным NPC
+///
+/// Added<AIConfig> — срабатывает один раз на entity (player без AIConfig не затрагивается).
+/// Масштабирует: Health (max + current), AimSkill (spread/reaction/tracking),
+/// AIConfig (retreat пороги, parry priority).
+pub fn apply_difficulty_on_spawn(
+    mut new_ai: Query<
+        (Entity, &mut AIConfig, Option<&mut AimSkill>, Option<&mut Health>),
+        Added<AIConfig>,
+    >,
+    difficulty: Res<Difficulty>,
+    presets: Res<DifficultyPresets>,
+) {
+    let Some(preset) = presets.get(difficulty.level) else {
+        return;
+    };
+
+    for (entity, mut config, aim_skill, health) in new_ai.iter_mut() {
+        config.retreat_health_threshold *= preset.retreat_threshold_multiplier;
+        config.retreat_stamina_threshold *= preset.retreat_threshold_multiplier;
+        config.parry_priority_bonus += preset.parry_priority_bonus;
+
+        if let Some(mut aim_skill) = aim_skill {
+            aim_skill.spread_degrees *= preset.spread_multiplier;
+            aim_skill.reaction_delay *= preset.reaction_multiplier;
+            aim_skill.tracking = (aim_skill.tracking + preset.tracking_bonus).clamp(0.0, 1.0);
+        }
+
+        if let Some(mut health) = health {
+            let max = ((health.max as f32 * preset.health_multiplier).round() as u32).max(1);
+            health.current = health.current * max / health.max.max(1);
+            health.max = max;
+        }
+
+        crate::logger::log(&format!(
+            "🎚️ ECS: Difficulty {:?} applied (entity: {:?})",
+            difficulty.level, entity
+        ));
+    }
+}
@@ -2,7 +2,16 @@
 
 use bevy::prelude::*;
 use crate::components::{Actor, Health, Stamina};
-use crate::ai::{GodotAIEvent, AIState, SpottedEnemies, AIConfig};
+use crate::ai::{
+    GodotAIEvent, AIState, AIStateHistory, SpottedEnemies, AIConfig, PatrolRoute, GuardPost, ThreatTable, TransitionCause,
+    PerceptionMemory,
+};
+use crate::combat::{Downed, WeaponStats};
+use crate::environment::{traversal_cost, HazardZone, VacuumZone};
+use crate::SimulationTick;
+
+/// Сколько раз перебрасываем случайную точку патруля, попавшую в вакуум / зону опасности
+const PATROL_SAFE_POINT_ATTEMPTS: u32 = 4;
 
 /// Система: обновление SpottedEnemies из GodotAIEvent
 ///
@@ -13,7 +22,7 @@ pub fn update_spotted_enemies(
     mut ai_query: Query<(&mut SpottedEnemies, &Actor)>,
     mut ai_events: EventReader<GodotAIEvent>,
     actors: Query<&Actor>, // Для получения Actor по Entity
-    potential_targets: Query<&Health>, // Для проверки что target жив
+    potential_targets: Query<(&Health, Has<Downed>)>, // Для проверки что target жив (или Downed — добить)
 ) {
     for event in ai_events.read() {
         match event {
@@ -21,6 +30,10 @@ pub fn update_spotted_enemies(
                 // Skip: handled by ai_melee_combat_decision system
                 continue;
             }
+            GodotAIEvent::AimedAt { .. } => {
+                // Skip: handled by apply_aimed_at system
+                continue;
+            }
             GodotAIEvent::ActorSpotted { observer, target } => {
                 // Получаем observer actor
                 let Ok((mut spotted, observer_actor)) = ai_query.get_mut(*observer) else {
@@ -68,7 +81,7 @@ pub fn update_spotted_enemies(
         spotted.enemies.retain(|&e| {
             potential_targets
                 .get(e)
-                .map(|h| h.is_alive())
+                .map(is_valid_target)
                 .unwrap_or(false) // Если entity despawned или нет Health — удаляем
         });
 
@@ -84,8 +97,13 @@ pub fn update_spotted_enemies(
 /// Обновляет AIState на основе SpottedEnemies, health, stamina.
 /// Порядок приоритетов:
 /// 1. Retreat (если low health/stamina)
-/// 2. Combat (если есть spotted enemies)
-/// 3. Patrol (если никого не видим)
+/// 2. Combat (если есть spotted enemies) — цель по ThreatTable (без таблицы — первый замеченный)
+///    Недостижимые цели (PerceptionMemory) пропускаются; ranged без достижимых — обстрел недостижимой,
+///    melee — патруль, пока память не остынет
+/// 3. Patrol (если никого не видим) — по PatrolRoute; охранник без маршрута — домой (GuardPost);
+///    иначе случайные точки (предпочтительно вне VacuumZone и HazardZone — `traversal_cost`)
+///
+/// Каждый переход пишется в `AIStateHistory` с причиной (замечен / потерян / порог отступления ...).
 ///
 /// ADR-005: Использует StrategicPosition для AI decisions (не Godot Transform)
 pub fn ai_fsm_transitions(
@@ -98,21 +116,43 @@ pub fn ai_fsm_transitions(
         &Stamina,
         &crate::StrategicPosition,
         Option<&crate::combat::MeleeAttackState>, // Check if in attack animation
+        Option<&crate::combat::Suppressed>, // Прижат огнём → предпочитаем Retreat
+        Option<&mut PatrolRoute>, // Маршрут патруля (None → случайный патруль)
+        Option<&GuardPost>, // Охранник: без маршрута возвращается на пост
+        Option<&ThreatTable>, // Выбор цели по угрозе
+        Option<&mut AIStateHistory>, // Переходы с причиной (отладка / forensics)
+        Option<&mut PerceptionMemory>, // Недостижимые цели (NavigationFailed)
     )>,
-    potential_targets: Query<&Health>, // Для проверки что target жив
+    potential_targets: Query<(&Health, Has<Downed>)>, // Для проверки что target жив (или Downed — добить)
+    weapons: Query<&WeaponStats>, // Ranged может обстреливать недостижимую цель
+    vacuum_zones: Query<&VacuumZone>, // Разгерметизированные отсеки (патруль их обходит)
+    hazard_zones: Query<&HazardZone>, // Радиация / огонь / газ (патруль их обходит)
     time: Res<Time<Fixed>>,
+    tick: Res<SimulationTick>,
 ) {
     let delta = time.delta_secs();
 
-    for (entity, mut state, mut spotted, config, health, stamina, strategic_pos, melee_attack_state) in ai_query.iter_mut() {
+    for (entity, mut state, mut spotted, config, health, stamina, strategic_pos, melee_attack_state, suppressed, mut route, guard_post, threat, history, mut memory) in ai_query.iter_mut() {
+        // Память о недостижимых целях остывает (пустую не трогаем — без Changed спама)
+        if let Some(memory) = memory.as_mut().filter(|memory| !memory.unreachable.is_empty()) {
+            memory.tick(delta);
+        }
+        let memory = memory.as_deref();
+        let ranged = weapons.get(entity).is_ok_and(|weapon| weapon.is_ranged());
+
         let stamina_percent = stamina.current / stamina.max;
         let health_percent = health.current as f32 / health.max as f32;
+        let pinned = suppressed.is_some_and(|s| s.is_pinned());
 
         // Проверяем нужно ли отступить
         // ⚠️ НЕ отступаем если в процессе атаки (MeleeAttackState active)!
         let should_retreat = melee_attack_state.is_none()
             && (stamina_percent < config.retreat_stamina_threshold
-                || health_percent < config.retreat_health_threshold);
+                || health_percent < config.retreat_health_threshold
+                || pinned);
+
+        // Причина перехода (None — состояние не сменилось)
+        let mut cause = None;
 
         let new_state = match state.as_ref() {
             AIState::Dead => {
@@ -123,6 +163,7 @@ pub fn ai_fsm_transitions(
             AIState::Idle => {
                 // Idle → Patrol (начинаем патрулировать)
                 crate::logger::log(&format!("AI: {:?} Idle → Patrol", entity));
+                cause = Some(TransitionCause::PatrolStarted);
                 AIState::Patrol {
                     next_direction_timer: config.patrol_direction_change_interval,
                     target_position: None, // Будет сгенерирована в ai_movement_from_state
@@ -130,26 +171,29 @@ pub fn ai_fsm_transitions(
             }
 
             AIState::Patrol { next_direction_timer, target_position } => {
-                // Если spotted enemy → Combat
-                if let Some(&target) = spotted.enemies.first() {
-                    crate::logger::log(&format!("🔍 {:?} Patrol: spotted {} enemies, first = {:?}", entity, spotted.enemies.len(), target));
-                    // Проверяем что target жив
-                    if let Ok(target_health) = potential_targets.get(target) {
-                        if target_health.is_alive() {
-                            crate::logger::log(&format!("⚔️ {:?} Patrol → Combat (target {:?})", entity, target));
-                            AIState::Combat { target }
-                        } else {
-                            // Target мертв, продолжаем патруль
-                            AIState::Patrol {
-                                next_direction_timer: *next_direction_timer,
-                                target_position: *target_position,
-                            }
-                        }
-                    } else {
-                        AIState::Patrol {
-                            next_direction_timer: *next_direction_timer,
-                            target_position: *target_position,
-                        }
+                // Если spotted enemy → Combat (цель с максимальной угрозой)
+                // Все замеченные мертвы / недостижимы (melee) → продолжаем патруль
+                let spotted_target = pick_target(&spotted, threat, None, memory, ranged, &potential_targets);
+                if let Some(target) = spotted_target {
+                    crate::logger::log(&format!("🔍 {:?} Patrol: spotted {} enemies", entity, spotted.enemies.len()));
+                    crate::logger::log(&format!("⚔️ {:?} Patrol → Combat (target {:?})", entity, target));
+                    cause = Some(TransitionCause::Spotted { target });
+                    AIState::Combat { target }
+                } else if let Some(route) = route.as_deref_mut().filter(|r| !r.waypoints.is_empty()) {
+                    // Патруль по маршруту: дошли до waypoint → следующий
+                    if route.is_reached(strategic_pos.to_world_position(0.5)) {
+                        route.advance();
+                    }
+
+                    AIState::Patrol {
+                        next_direction_timer: *next_direction_timer,
+                        target_position: route.current_waypoint(),
+                    }
+                } else if let Some(post) = guard_post {
+                    // Охранник: потерял цели → возвращается на пост и стоит там
+                    AIState::Patrol {
+                        next_direction_timer: *next_direction_timer,
+                        target_position: Some(post.home),
                     }
                 } else {
                     // Продолжаем патруль, обновляем таймер
@@ -160,13 +204,27 @@ pub fn ai_fsm_transitions(
                         use rand::Rng;
                         let mut rng = rand::thread_rng();
 
-                        let angle = rng.gen::<f32>() * std::f32::consts::TAU;
-                        let distance = 5.0 + rng.gen::<f32>() * 10.0; // 5-15м radius
-
                         // Генерируем от текущей strategic position
                         let current_world_pos = strategic_pos.to_world_position(0.5);
-                        let offset = Vec3::new(angle.cos() * distance, 0.0, angle.sin() * distance);
-                        let patrol_target = current_world_pos + offset;
+
+                        // Точка в вакууме / зоне опасности → перебрасываем (безопасной нет — самая дешёвая)
+                        let mut patrol_target = current_world_pos;
+                        let mut best_cost = f32::MAX;
+                        for _ in 0..PATROL_SAFE_POINT_ATTEMPTS {
+                            let angle = rng.gen::<f32>() * std::f32::consts::TAU;
+                            let distance = 5.0 + rng.gen::<f32>() * 10.0; // 5-15м radius
+
+                            let offset = Vec3::new(angle.cos() * distance, 0.0, angle.sin() * distance);
+                            let candidate = current_world_pos + offset;
+                            let cost = traversal_cost(vacuum_zones.iter(), hazard_zones.iter(), candidate);
+                            if cost < best_cost {
+                                patrol_target = candidate;
+                                best_cost = cost;
+                            }
+                            if cost <= 0.0 {
+                                break;
+                            }
+                        }
 
                         // для теста генерируем точку всегда с -z от текущей позиции
                         // let patrol_target = Vec3::new(current_world_pos.x, current_world_pos.y, -current_world_pos.z);
@@ -189,7 +247,16 @@ pub fn ai_fsm_transitions(
             AIState::Combat { target } => {
                 // Проверяем retreat conditions
                 if should_retreat {
-                    crate::logger::log(&format!("AI: {:?} Combat → Retreat (low hp/stamina)", entity));
+                    crate::logger::log(&format!(
+                        "AI: {:?} Combat → Retreat ({})",
+                        entity,
+                        if pinned { "suppressed" } else { "low hp/stamina" }
+                    ));
+                    cause = Some(if pinned {
+                        TransitionCause::Suppressed
+                    } else {
+                        TransitionCause::RetreatThreshold { health_percent, stamina_percent }
+                    });
                     AIState::Retreat {
                         timer: config.retreat_duration,
                         from_target: Some(*target),
@@ -199,7 +266,7 @@ pub fn ai_fsm_transitions(
                     let target_valid = spotted.enemies.contains(target)
                         && potential_targets
                             .get(*target)
-                            .map(|h| h.is_alive())
+                            .map(is_valid_target)
                             .unwrap_or(false);
 
                     if !target_valid {
@@ -207,9 +274,10 @@ pub fn ai_fsm_transitions(
                         crate::logger::log(&format!("❌ {:?} Combat: target {:?} INVALID (in spotted: {}, alive: {})",
                             entity, target,
                             spotted.enemies.contains(target),
-                            potential_targets.get(*target).map(|h| h.is_alive()).unwrap_or(false)
+                            potential_targets.get(*target).map(is_valid_target).unwrap_or(false)
                         ));
-                        if let Some(&new_target) = spotted.enemies.first() {
+                        cause = Some(TransitionCause::TargetLost { target: *target });
+                        if let Some(new_target) = pick_target(&spotted, threat, None, memory, ranged, &potential_targets) {
                             crate::logger::log(&format!("🔄 {:?} Combat: target lost, switching to {:?}", entity, new_target));
                             AIState::Combat { target: new_target }
                         } else {
@@ -220,8 +288,30 @@ pub fn ai_fsm_transitions(
                             }
                         }
                     } else {
-                        // Продолжаем бой
-                        AIState::Combat { target: *target }
+                        // Продолжаем бой; переключаемся на заметно более опасную или на достижимую цель
+                        let unreachable = memory.is_some_and(|memory| memory.is_unreachable(*target));
+                        match pick_target(&spotted, threat, Some(*target), memory, ranged, &potential_targets) {
+                            Some(best) => {
+                                if best != *target {
+                                    crate::logger::log(&format!("🎯 {:?} Combat: target switch {:?} → {:?}", entity, target, best));
+                                    cause = Some(if unreachable {
+                                        TransitionCause::TargetUnreachable { target: *target }
+                                    } else {
+                                        TransitionCause::ThreatSwitch { from: *target, to: best }
+                                    });
+                                }
+                                AIState::Combat { target: best }
+                            }
+                            None => {
+                                // Melee: пути нет, других целей нет → патруль (не перепрокладываем путь)
+                                crate::logger::log(&format!("🚧 {:?} Combat → Patrol (target {:?} unreachable)", entity, target));
+                                cause = Some(TransitionCause::TargetUnreachable { target: *target });
+                                AIState::Patrol {
+                                    next_direction_timer: config.patrol_direction_change_interval,
+                                    target_position: None,
+                                }
+                            }
+                        }
                     }
                 }
             }
@@ -229,13 +319,15 @@ pub fn ai_fsm_transitions(
             AIState::Retreat { timer, from_target } => {
                 let new_timer = (*timer - delta).max(0.0);
 
-                if new_timer <= 0.0 {
+                // Прижат огнём — не возвращаемся в бой, пока подавление не спадёт
+                if new_timer <= 0.0 && !pinned {
                     // Retreat закончен — проверяем можем ли вернуться в Combat
+                    cause = Some(TransitionCause::RetreatEnded);
 
                     // Приоритет 1: возвращаемся к from_target (даже если VisionCone потерял)
                     if let Some(target) = from_target {
                         // Проверяем что target всё ещё жив
-                        if potential_targets.get(*target).map(|h| h.is_alive()).unwrap_or(false) {
+                        if potential_targets.get(*target).map(is_valid_target).unwrap_or(false) {
                             // ✅ Добавляем from_target обратно в SpottedEnemies (VisionCone мог потерять во время retreat)
                             if !spotted.enemies.contains(target) {
                                 spotted.enemies.push(*target);
@@ -245,7 +337,7 @@ pub fn ai_fsm_transitions(
                             AIState::Combat { target: *target }
                         } else {
                             // from_target мёртв — ищем другого spotted enemy
-                            if let Some(&new_target) = spotted.enemies.first() {
+                            if let Some(new_target) = pick_target(&spotted, threat, None, memory, ranged, &potential_targets) {
                                 crate::logger::log(&format!("AI: {:?} Retreat → Combat (from_target dead, switching to {:?})", entity, new_target));
                                 AIState::Combat { target: new_target }
                             } else {
@@ -259,7 +351,7 @@ pub fn ai_fsm_transitions(
                         }
                     } else {
                         // Нет from_target — проверяем spotted enemies
-                        if let Some(&target) = spotted.enemies.first() {
+                        if let Some(target) = pick_target(&spotted, threat, None, memory, ranged, &potential_targets) {
                             crate::logger::log(&format!("AI: {:?} Retreat → Combat (spotted enemy)", entity));
                             AIState::Combat { target }
                         } else {
@@ -281,8 +373,48 @@ pub fn ai_fsm_transitions(
             }
         };
 
+        if let (Some(cause), Some(mut history)) = (cause, history) {
+            history.record(tick.get(), &state, &new_state, cause);
+        }
+
         if *state != new_state {
             *state = new_state;
         }
     }
 }
+
+/// Цель ещё в бою: жива или лежит Downed (можно добить)
+fn is_valid_target((health, downed): (&Health, bool)) -> bool {
+    health.is_alive() || downed
+}
+
+/// Выбор цели среди живых замеченных врагов
+///
+/// С ThreatTable — по угрозе (с hysteresis относительно `current`),
+/// без — первый живой в порядке обнаружения.
+/// Недостижимые (PerceptionMemory) — только если достижимых нет и оружие ranged.
+fn pick_target(
+    spotted: &SpottedEnemies,
+    threat: Option<&ThreatTable>,
+    current: Option<Entity>,
+    memory: Option<&PerceptionMemory>,
+    ranged: bool,
+    potential_targets: &Query<(&Health, Has<Downed>)>,
+) -> Option<Entity> {
+    let is_alive = |enemy: Entity| potential_targets.get(enemy).is_ok_and(is_valid_target);
+    let is_reachable = |enemy: Entity| memory.is_none_or(|memory| !memory.is_unreachable(enemy));
+    let any_reachable = spotted.enemies.iter().any(|&enemy| is_alive(enemy) && is_reachable(enemy));
+    let allowed = |enemy: Entity| is_reachable(enemy) || (ranged && !any_reachable);
+
+    let current = current.filter(|&current| allowed(current));
+    let mut alive = spotted
+        .enemies
+        .iter()
+        .copied()
+        .filter(|&enemy| is_alive(enemy) && allowed(enemy));
+
+    match threat {
+        Some(table) => table.select_target(alive, current),
+        None => current.or_else(|| alive.next()),
+    }
+}
@@ -0,0 +1,93 @@
+//! Guard post systems (territory alarm).
+
+use bevy::prelude::*;
+use crate::components::Actor;
+use crate::ai::{AIState, GodotAIEvent, GuardAlarm, GuardPost, SpottedEnemies};
+
+/// System: охранник поднимает тревогу, когда spotted враг на территории
+///
+/// - Тревога один раз на нарушителя (`alerted_intruders`)
+/// - Нарушитель покинул территорию / пропал из SpottedEnemies → снова может вызвать тревогу
+pub fn raise_guard_alarms(
+    mut guards: Query<(Entity, &mut GuardPost, &SpottedEnemies, &AIState)>,
+    positions: Query<&crate::StrategicPosition>,
+    mut alarm_events: EventWriter<GuardAlarm>,
+) {
+    for (guard_entity, mut post, spotted, state) in guards.iter_mut() {
+        if matches!(state, AIState::Dead) {
+            continue;
+        }
+
+        let intruders: Vec<Entity> = spotted
+            .enemies
+            .iter()
+            .copied()
+            .filter(|enemy| {
+                positions
+                    .get(*enemy)
+                    .is_ok_and(|pos| post.contains(pos.to_world_position(0.5)))
+            })
+            .collect();
+
+        for &intruder in &intruders {
+            if post.alerted_intruders.contains(&intruder) {
+                continue;
+            }
+
+            crate::logger::log(&format!(
+                "🚨 Guard {:?}: intruder {:?} entered territory (home {:?})",
+                guard_entity, intruder, post.home
+            ));
+            alarm_events.write(GuardAlarm {
+                guard: guard_entity,
+                intruder,
+                home: post.home,
+                alarm_radius: post.alarm_radius,
+            });
+        }
+
+        if post.alerted_intruders != intruders {
+            post.alerted_intruders = intruders;
+        }
+    }
+}
+
+/// System: союзники охранника реагируют на тревогу
+///
+/// Союзники (тот же faction_id) в `alarm_radius` от поста, не занятые боем,
+/// получают ActorSpotted нарушителя → FSM переведёт их в Combat.
+pub fn respond_to_guard_alarms(
+    mut alarm_events: EventReader<GuardAlarm>,
+    actors: Query<(Entity, &Actor, &crate::StrategicPosition, &AIState)>,
+    mut spotted_events: EventWriter<GodotAIEvent>,
+) {
+    for alarm in alarm_events.read() {
+        let Ok((_, guard_actor, _, _)) = actors.get(alarm.guard) else {
+            continue;
+        };
+
+        for (ally, ally_actor, ally_pos, ally_state) in actors.iter() {
+            if ally == alarm.guard || ally_actor.faction_id != guard_actor.faction_id {
+                continue;
+            }
+
+            // Занятые боем и мёртвые тревогу игнорируют
+            if matches!(ally_state, AIState::Combat { .. } | AIState::Dead) {
+                continue;
+            }
+
+            if ally_pos.to_world_position(0.5).distance(alarm.home) > alarm.alarm_radius {
+                continue;
+            }
+
+            crate::logger::log(&format!(
+                "📢 {:?} responds to guard alarm from {:?} (intruder {:?})",
+                ally, alarm.guard, alarm.intruder
+            ));
+            spotted_events.write(GodotAIEvent::ActorSpotted {
+                observer: ally,
+                target: alarm.intruder,
+            });
+        }
+    }
+}
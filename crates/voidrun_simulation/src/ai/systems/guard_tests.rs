@@ -0,0 +1,138 @@
+//! Tests for guard post systems (alarm, allies response, leash).
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use crate::ai::{AIState, GodotAIEvent, GuardAlarm, GuardPost, SpottedEnemies};
+    use crate::components::{Actor, MovementCommand};
+    use crate::{create_test_app, drain_events, StrategicPosition};
+    use super::super::guard::{raise_guard_alarms, respond_to_guard_alarms};
+    use super::super::movement::ai_movement_from_state;
+
+    const GUARD_FACTION: u64 = 1;
+    const ENEMY_FACTION: u64 = 2;
+
+    fn at(x: f32, z: f32) -> StrategicPosition {
+        StrategicPosition::from_world_position(Vec3::new(x, 0.0, z))
+    }
+
+    fn spawn_guard(world: &mut World, home: Vec3, spotted: Vec<Entity>) -> Entity {
+        world
+            .spawn((
+                Actor { faction_id: GUARD_FACTION },
+                StrategicPosition::from_world_position(home),
+                GuardPost::new(home, 10.0),
+                SpottedEnemies { enemies: spotted },
+                AIState::Idle,
+            ))
+            .id()
+    }
+
+    #[test]
+    fn test_alarm_once_per_intruder_while_on_territory() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let intruder = world.spawn((Actor { faction_id: ENEMY_FACTION }, at(5.0, 0.0))).id();
+        let guard = spawn_guard(world, Vec3::ZERO, vec![intruder]);
+
+        world.run_system_once(raise_guard_alarms).unwrap();
+        let alarms = drain_events::<GuardAlarm>(world);
+        assert_eq!(alarms.len(), 1);
+        assert_eq!(alarms[0].guard, guard);
+        assert_eq!(alarms[0].intruder, intruder);
+
+        // Остаётся на территории → тревога не повторяется
+        world.run_system_once(raise_guard_alarms).unwrap();
+        assert!(drain_events::<GuardAlarm>(world).is_empty());
+
+        // Вышел и вернулся → новая тревога
+        world.entity_mut(intruder).insert(at(30.0, 0.0));
+        world.run_system_once(raise_guard_alarms).unwrap();
+        assert!(drain_events::<GuardAlarm>(world).is_empty());
+        assert!(world.get::<GuardPost>(guard).unwrap().alerted_intruders.is_empty());
+
+        world.entity_mut(intruder).insert(at(3.0, 3.0));
+        world.run_system_once(raise_guard_alarms).unwrap();
+        assert_eq!(drain_events::<GuardAlarm>(world).len(), 1);
+    }
+
+    #[test]
+    fn test_no_alarm_for_enemy_outside_territory_or_dead_guard() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let outsider = world.spawn((Actor { faction_id: ENEMY_FACTION }, at(25.0, 0.0))).id();
+        spawn_guard(world, Vec3::ZERO, vec![outsider]);
+
+        world.run_system_once(raise_guard_alarms).unwrap();
+        assert!(drain_events::<GuardAlarm>(world).is_empty());
+
+        let intruder = world.spawn((Actor { faction_id: ENEMY_FACTION }, at(2.0, 0.0))).id();
+        let dead_guard = spawn_guard(world, Vec3::new(100.0, 0.0, 0.0), vec![intruder]);
+        world.entity_mut(dead_guard).insert(AIState::Dead);
+        world.entity_mut(intruder).insert(at(101.0, 0.0));
+
+        world.run_system_once(raise_guard_alarms).unwrap();
+        assert!(drain_events::<GuardAlarm>(world).is_empty());
+    }
+
+    #[test]
+    fn test_allies_in_alarm_radius_respond() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let intruder = world.spawn((Actor { faction_id: ENEMY_FACTION }, at(5.0, 0.0))).id();
+        let guard = spawn_guard(world, Vec3::ZERO, vec![intruder]);
+
+        let near_ally = world.spawn((Actor { faction_id: GUARD_FACTION }, at(10.0, 0.0), AIState::Idle)).id();
+        let far_ally = world.spawn((Actor { faction_id: GUARD_FACTION }, at(50.0, 0.0), AIState::Idle)).id();
+        let busy_ally = world
+            .spawn((Actor { faction_id: GUARD_FACTION }, at(0.0, 5.0), AIState::Combat { target: intruder }))
+            .id();
+        let stranger = world.spawn((Actor { faction_id: ENEMY_FACTION }, at(0.0, 3.0), AIState::Idle)).id();
+
+        world.run_system_once(raise_guard_alarms).unwrap();
+        world.run_system_once(respond_to_guard_alarms).unwrap();
+
+        let observers: Vec<Entity> = drain_events::<GodotAIEvent>(world)
+            .into_iter()
+            .filter_map(|event| match event {
+                GodotAIEvent::ActorSpotted { observer, target } if target == intruder => Some(observer),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(observers, vec![near_ally]);
+        assert!(!observers.contains(&guard));
+        assert!(!observers.contains(&far_ally));
+        assert!(!observers.contains(&busy_ally));
+        assert!(!observers.contains(&stranger));
+    }
+
+    #[test]
+    fn test_guard_does_not_chase_beyond_leash() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let home = Vec3::new(4.0, 0.0, 4.0);
+        let target = world.spawn(at(40.0, 4.0)).id();
+        let guard = world
+            .spawn((
+                AIState::Combat { target },
+                MovementCommand::Idle,
+                StrategicPosition::from_world_position(home),
+                GuardPost::new(home, 10.0),
+            ))
+            .id();
+
+        // Цель за leash → возвращается на пост
+        world.run_system_once(ai_movement_from_state).unwrap();
+        assert_eq!(
+            world.get::<MovementCommand>(guard),
+            Some(&MovementCommand::MoveToPosition { target: home })
+        );
+
+        // Цель на территории → преследует
+        world.entity_mut(target).insert(at(8.0, 4.0));
+        world.run_system_once(ai_movement_from_state).unwrap();
+        assert_eq!(world.get::<MovementCommand>(guard), Some(&MovementCommand::FollowEntity { target }));
+    }
+}
@@ -3,8 +3,28 @@
 pub mod fsm;
 pub mod movement;
 pub mod reactions;
+pub mod difficulty;
+pub mod consumables;
+pub mod guard;
+pub mod blackboard;
+pub mod threat;
+pub mod perception;
+pub mod radio;
+pub mod chatter;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod guard_tests;
 
 // Re-export all systems
 pub use fsm::*;
 pub use movement::*;
 pub use reactions::*;
+pub use difficulty::*;
+pub use consumables::*;
+pub use guard::*;
+pub use blackboard::*;
+pub use threat::*;
+pub use perception::*;
+pub use radio::*;
+pub use chatter::*;
@@ -2,18 +2,42 @@
 
 use bevy::prelude::*;
 use crate::components::{Actor, MovementCommand, Stamina};
-use crate::combat::WeaponStats;
-use crate::ai::AIState;
+use crate::combat::{KnockdownState, WeaponStats};
+use crate::ai::{AIState, GuardPost, PerceptionMemory};
+use crate::environment::HazardZone;
+
+/// Насколько дальше края зоны опасности уходит отступающий (метры)
+const HAZARD_ESCAPE_MARGIN: f32 = 2.0;
 
 /// Система: AI movement from state
 ///
 /// Конвертирует AIState → MovementCommand для Godot.
+/// GuardPost: в Combat не преследует цель за leash — возвращается на пост (стрелять/ждать оттуда).
+/// KnockdownState: лежит/встаёт — стоим на месте (Idle).
+/// Спутники (Companion) пропускаются — движение по приказу (`companion_movement_from_orders`).
+/// Combat с недостижимой целью (PerceptionMemory, только ranged) — стоим и стреляем с места (Stop).
+/// Retreat внутри HazardZone: не пятимся на месте, а уходим за ближайший край зоны.
 /// ADR-005: Используем StrategicPosition для AI decisions
 pub fn ai_movement_from_state(
-    mut ai_query: Query<(&AIState, &mut MovementCommand, &crate::StrategicPosition)>,
-    _targets_query: Query<&crate::StrategicPosition>,
+    mut ai_query: Query<(
+        &AIState,
+        &mut MovementCommand,
+        &crate::StrategicPosition,
+        Option<&GuardPost>,
+        Option<&KnockdownState>,
+        Option<&PerceptionMemory>,
+    ), Without<crate::companions::Companion>>,
+    targets_query: Query<&crate::StrategicPosition>,
+    hazard_zones: Query<&HazardZone>,
 ) {
-    for (state, mut command, _strategic_pos) in ai_query.iter_mut() {
+    for (state, mut command, strategic_pos, guard_post, knockdown, memory) in ai_query.iter_mut() {
+        if knockdown.is_some() {
+            if !matches!(*command, MovementCommand::Idle) {
+                *command = MovementCommand::Idle;
+            }
+            continue;
+        }
+
         match state {
             AIState::Dead => {
                 // Dead — не двигаемся
@@ -29,7 +53,7 @@ pub fn ai_movement_from_state(
             }
 
             AIState::Patrol { target_position, .. } => {
-                // Двигаемся к сгенерированной patrol точке (генерируется в ai_fsm_transitions)
+                // Двигаемся к patrol точке (waypoint PatrolRoute или случайная, выбирается в ai_fsm_transitions)
                 if let Some(target) = target_position {
                     // Проверяем что команда изменилась — иначе Changed<MovementCommand> спамит
                     if !matches!(*command, MovementCommand::MoveToPosition { target: t } if t == *target) {
@@ -46,6 +70,29 @@ pub fn ai_movement_from_state(
             }
 
             AIState::Combat { target } => {
+                // Охранник: цель за пределами территории → не преследуем, держим пост
+                let leashed_post = guard_post.filter(|post| {
+                    targets_query
+                        .get(*target)
+                        .is_ok_and(|target_pos| !post.contains(target_pos.to_world_position(0.5)))
+                });
+                if let Some(post) = leashed_post {
+                    if !matches!(*command, MovementCommand::MoveToPosition { target: t } if t == post.home) {
+                        crate::logger::log(&format!("🪢 AI movement: target {:?} beyond leash → return to post", target));
+                        *command = MovementCommand::MoveToPosition { target: post.home };
+                    }
+                    continue;
+                }
+
+                // Пути до цели нет → обстрел с места (иначе NavigationAgent перепрокладывает путь бесконечно)
+                if memory.is_some_and(|memory| memory.is_unreachable(*target)) {
+                    if !matches!(*command, MovementCommand::Stop) {
+                        crate::logger::log(&format!("🚧 AI movement: target {:?} unreachable → hold position", target));
+                        *command = MovementCommand::Stop;
+                    }
+                    continue;
+                }
+
                 // Следуем за target (FollowEntity для динамического преследования)
                 if !matches!(*command, MovementCommand::FollowEntity { target: t } if t == *target) {
                     crate::logger::log(&format!("🏃 AI movement: Combat → FollowEntity {:?}", target));
@@ -64,6 +111,18 @@ pub fn ai_movement_from_state(
                     continue;
                 };
 
+                // В зоне опасности → сначала выходим из неё (дорогая точка для отступления)
+                let current_pos = strategic_pos.to_world_position(0.5);
+                if let Some(zone) = hazard_zones.iter().find(|zone| zone.contains(current_pos)) {
+                    // Уже идём за край — цель не пересчитываем (иначе Changed<MovementCommand> спамит)
+                    if !matches!(*command, MovementCommand::MoveToPosition { target: t } if !zone.contains(t)) {
+                        *command = MovementCommand::MoveToPosition {
+                            target: zone.escape_point(current_pos, HAZARD_ESCAPE_MARGIN),
+                        };
+                    }
+                    continue;
+                }
+
                 // Используем RetreatFrom для тактического отступления
                 if !matches!(*command, MovementCommand::RetreatFrom { target: t } if t == *target_entity) {
                     *command = MovementCommand::RetreatFrom {
@@ -0,0 +1,95 @@
+//! Perception systems (stealth samples → LightLevel + Visibility, ADS прицел → AimedAt).
+
+use bevy::prelude::*;
+use crate::ai::{AimedAt, GodotAIEvent, LightLevel, StealthSampled, Visibility};
+use crate::components::Actor;
+use crate::environment::WeatherExposure;
+use crate::movement::Stance;
+
+/// Минимальное изменение для записи (избегаем Changed<LightLevel>/Changed<Visibility> спама)
+const STEALTH_EPSILON: f32 = 0.02;
+
+/// System: StealthSampled → LightLevel + Visibility (стойка из компонента Stance)
+///
+/// Погода (WeatherExposure, в помещении не действует) снижает заметность.
+/// Актор без компонентов получает их при первом сэмпле.
+pub fn apply_stealth_samples(
+    mut samples: EventReader<StealthSampled>,
+    mut actors: Query<(
+        Option<&Stance>,
+        Option<&WeatherExposure>,
+        Option<&mut LightLevel>,
+        Option<&mut Visibility>,
+    )>,
+    mut commands: Commands,
+) {
+    for sample in samples.read() {
+        let Ok((stance, weather, light_level, visibility)) = actors.get_mut(sample.entity) else {
+            continue;
+        };
+
+        let light = LightLevel::new(sample.illumination);
+        let mut computed = Visibility::compute(stance.copied().unwrap_or_default(), sample.speed, light);
+        if let Some(weather) = weather {
+            computed.score *= weather.visibility;
+        }
+
+        match light_level {
+            Some(mut level) => {
+                if (level.illumination - light.illumination).abs() > STEALTH_EPSILON {
+                    *level = light;
+                }
+            }
+            None => {
+                commands.entity(sample.entity).insert(light);
+            }
+        }
+
+        match visibility {
+            Some(mut visibility) => {
+                if (visibility.score - computed.score).abs() > STEALTH_EPSILON {
+                    *visibility = computed;
+                }
+            }
+            None => {
+                commands.entity(sample.entity).insert(computed);
+            }
+        }
+    }
+}
+
+/// System: GodotAIEvent::AimedAt → AimedAt (refresh), истёкший прицел → remove
+///
+/// - Союзник в прицеле не реагирует (та же фракция)
+/// - Один актор — один aimer (последний, кто навёл прицел)
+pub fn apply_aimed_at(
+    mut ai_events: EventReader<GodotAIEvent>,
+    actors: Query<&Actor>,
+    mut aimed_query: Query<(Entity, &mut AimedAt)>,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut aimed) in aimed_query.iter_mut() {
+        if aimed.tick(delta) {
+            commands.entity(entity).remove::<AimedAt>();
+        }
+    }
+
+    for event in ai_events.read() {
+        let GodotAIEvent::AimedAt { aimer, target } = event else {
+            continue;
+        };
+
+        let (Ok(aimer_actor), Ok(target_actor)) = (actors.get(*aimer), actors.get(*target)) else {
+            continue;
+        };
+        if aimer_actor.faction_id == target_actor.faction_id {
+            continue;
+        }
+
+        // insert заменяет истекающий компонент (remove выше) — команды применяются по порядку
+        commands.entity(*target).insert(AimedAt::new(*aimer));
+    }
+}
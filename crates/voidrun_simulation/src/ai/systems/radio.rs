@@ -0,0 +1,187 @@
+//! Radio systems (вызов подкрепления + отмена как контригра).
+
+use bevy::prelude::*;
+use std::collections::HashSet;
+use crate::ai::{
+    AIState, BackupCallCancelReason, BackupCallCancelled, BackupCallCompleted, BackupCallStarted, CallingBackup,
+    GodotAIEvent, RadioOperator,
+};
+use crate::combat::{
+    ActionKind, ActionLock, ActionPhase, CancelTable, ChannelCompleted, ChannelInterrupted, ChannelKind, Channeling,
+    KnockdownState, StaggerState,
+};
+use crate::components::{Actor, Health};
+use crate::security::ReinforcementsRequested;
+use crate::{SimulationTick, StrategicPosition};
+
+/// System: радист в бою начинает вызов подкрепления
+///
+/// Условия: Combat, жив, cooldown прошёл, ActionLock + CancelTable разрешают RadioCall.
+/// Результат: Channeling(RadioCall) + BackupCallStarted (telegraph).
+/// Шум: враги в `noise_radius` слышат радиста (ActorSpotted).
+#[allow(clippy::too_many_arguments)]
+pub fn ai_call_for_backup(
+    mut callers: Query<
+        (
+            Entity,
+            &Actor,
+            &AIState,
+            &Health,
+            &StrategicPosition,
+            &mut RadioOperator,
+            Option<&ActionLock>,
+        ),
+        (Without<Channeling>, Without<CallingBackup>),
+    >,
+    listeners: Query<(Entity, &Actor, &StrategicPosition)>,
+    cancel_table: Res<CancelTable>,
+    tick: Res<SimulationTick>,
+    mut started_events: EventWriter<BackupCallStarted>,
+    mut spotted_events: EventWriter<GodotAIEvent>,
+    mut commands: Commands,
+) {
+    for (entity, actor, state, health, position, mut radio, lock) in callers.iter_mut() {
+        let AIState::Combat { target } = state else {
+            continue;
+        };
+        if !health.is_alive() || !radio.is_ready(tick.get()) {
+            continue;
+        }
+        if !ActionLock::permits(lock, ActionKind::RadioCall, &cancel_table) {
+            continue;
+        }
+
+        commands.entity(entity).insert((
+            Channeling::new(ChannelKind::RadioCall, tick.after_secs(radio.call_duration)),
+            ActionLock::new(ActionKind::RadioCall, ActionPhase::Active),
+            CallingBackup { target: *target },
+        ));
+
+        // Cooldown ставится при старте: сорванный вызов тоже не спамится
+        radio.ready_at_tick = tick.after_secs(radio.cooldown);
+
+        let caller_pos = position.to_world_position(0.5);
+        started_events.write(BackupCallStarted {
+            caller: entity,
+            position: caller_pos,
+            duration: radio.call_duration,
+        });
+
+        // Вызов шумный: враги рядом слышат радиста
+        for (listener, listener_actor, listener_pos) in listeners.iter() {
+            if listener_actor.faction_id == actor.faction_id {
+                continue;
+            }
+            if listener_pos.to_world_position(0.5).distance(caller_pos) > radio.noise_radius {
+                continue;
+            }
+
+            spotted_events.write(GodotAIEvent::ActorSpotted {
+                observer: listener,
+                target: entity,
+            });
+        }
+
+        crate::logger::log(&format!(
+            "📻 {:?} calling for backup against {:?} ({:.1}s)",
+            entity, target, radio.call_duration
+        ));
+    }
+}
+
+/// System: завершение / отмена вызова подкрепления
+///
+/// - ChannelCompleted(RadioCall), радист жив → ReinforcementsRequested + BackupCallCompleted
+/// - Убит → Killed; Stagger/Knockdown → Staggered; урон/flinch сняли channel → Interrupted
+pub fn resolve_backup_calls(
+    mut completed_events: EventReader<ChannelCompleted>,
+    mut interrupted_events: EventReader<ChannelInterrupted>,
+    callers: Query<(
+        Entity,
+        &Actor,
+        &Health,
+        &StrategicPosition,
+        &RadioOperator,
+        &CallingBackup,
+        Has<Channeling>,
+        Has<StaggerState>,
+        Has<KnockdownState>,
+    )>,
+    mut reinforcement_events: EventWriter<ReinforcementsRequested>,
+    mut completed_calls: EventWriter<BackupCallCompleted>,
+    mut cancelled_calls: EventWriter<BackupCallCancelled>,
+    mut commands: Commands,
+) {
+    let mut resolved: HashSet<Entity> = HashSet::new();
+
+    for completed in completed_events.read() {
+        if completed.kind != ChannelKind::RadioCall {
+            continue;
+        }
+        let Ok((entity, actor, health, position, radio, call, ..)) = callers.get(completed.entity) else {
+            continue;
+        };
+        if !health.is_alive() {
+            continue; // Отмена (Killed) ниже
+        }
+
+        resolved.insert(entity);
+        commands.entity(entity).remove::<CallingBackup>();
+
+        reinforcement_events.write(ReinforcementsRequested {
+            faction_id: actor.faction_id,
+            position: position.to_world_position(0.5),
+            count: radio.reinforcements,
+            intruder: call.target,
+        });
+        completed_calls.write(BackupCallCompleted { caller: entity });
+
+        crate::logger::log(&format!(
+            "📻 {:?} backup call completed → {} reinforcements",
+            entity, radio.reinforcements
+        ));
+    }
+
+    for interrupted in interrupted_events.read() {
+        if interrupted.kind != ChannelKind::RadioCall || resolved.contains(&interrupted.entity) {
+            continue;
+        }
+        if !callers.contains(interrupted.entity) {
+            continue;
+        }
+
+        resolved.insert(interrupted.entity);
+        cancel_call(interrupted.entity, BackupCallCancelReason::Interrupted, &mut cancelled_calls, &mut commands);
+    }
+
+    for (entity, _, health, _, _, _, channeling, staggered, knocked_down) in callers.iter() {
+        if resolved.contains(&entity) {
+            continue;
+        }
+
+        let reason = if !health.is_alive() {
+            BackupCallCancelReason::Killed
+        } else if staggered || knocked_down {
+            BackupCallCancelReason::Staggered
+        } else if !channeling {
+            BackupCallCancelReason::Interrupted
+        } else {
+            continue;
+        };
+
+        cancel_call(entity, reason, &mut cancelled_calls, &mut commands);
+    }
+}
+
+/// Снять вызов (CallingBackup + Channeling) и сообщить об отмене
+fn cancel_call(
+    entity: Entity,
+    reason: BackupCallCancelReason,
+    cancelled_calls: &mut EventWriter<BackupCallCancelled>,
+    commands: &mut Commands,
+) {
+    commands.entity(entity).remove::<(CallingBackup, Channeling)>();
+    cancelled_calls.write(BackupCallCancelled { caller: entity, reason });
+
+    crate::logger::log(&format!("📵 {:?} backup call cancelled ({:?})", entity, reason));
+}
@@ -2,16 +2,21 @@
 
 use bevy::prelude::*;
 use crate::components::{Actor, MovementCommand};
-use crate::ai::{AIState, SpottedEnemies, GodotAIEvent};
+use crate::ai::{AIState, AIStateHistory, SpottedEnemies, GodotAIEvent, TransitionCause};
+use crate::SimulationTick;
 
 /// System: обработка смерти → переключение AI в Dead state
 ///
 /// При HP == 0 отключаем AI (Dead state) чтобы мертвые не стреляли/двигались
 pub fn handle_actor_death(
-    mut actors: Query<(&crate::Health, &mut AIState), Changed<crate::Health>>,
+    mut actors: Query<(&crate::Health, &mut AIState, Option<&mut AIStateHistory>), Changed<crate::Health>>,
+    tick: Res<SimulationTick>,
 ) {
-    for (health, mut state) in actors.iter_mut() {
+    for (health, mut state, history) in actors.iter_mut() {
         if health.current == 0 && !matches!(*state, AIState::Dead) {
+            if let Some(mut history) = history {
+                history.record(tick.get(), &state, &AIState::Dead, TransitionCause::Died);
+            }
             *state = AIState::Dead;
             crate::logger::log("Actor died → AI disabled (Dead state)");
         }
@@ -0,0 +1,78 @@
+//! Threat systems (ThreatTable accumulation).
+
+use bevy::prelude::*;
+use crate::ai::{AimedAt, GodotAIEvent, SpottedEnemies, ThreatTable};
+use crate::combat::{DamageDealt, WeaponFired};
+
+/// Система: накопление угрозы в ThreatTable
+///
+/// - Затухание (ThreatTable::DECAY_PER_SEC)
+/// - Близость замеченных врагов (StrategicPosition, каждый тик)
+/// - Враг целится в нас в ADS (AimedAt, каждый тик)
+/// - DamageDealt → урон от атакующего
+/// - Атаки по нам: WeaponFired (target = мы), EnemyWindupVisible (замах на нас)
+pub fn update_threat_tables(
+    mut tables: Query<(
+        &mut ThreatTable,
+        Option<&SpottedEnemies>,
+        Option<&crate::StrategicPosition>,
+        Option<&AimedAt>,
+    )>,
+    positions: Query<&crate::StrategicPosition>,
+    mut damage_events: EventReader<DamageDealt>,
+    mut fired_events: EventReader<WeaponFired>,
+    mut ai_events: EventReader<GodotAIEvent>,
+    time: Res<Time<Fixed>>,
+) {
+    let delta = time.delta_secs();
+
+    for (mut table, spotted, position, aimed_at) in tables.iter_mut() {
+        if !table.threats.is_empty() {
+            table.decay(delta);
+        }
+
+        if let Some(aimed_at) = aimed_at {
+            table.add_aimed_at(aimed_at.aimer, delta);
+        }
+
+        let (Some(spotted), Some(position)) = (spotted, position) else {
+            continue;
+        };
+        let own_pos = position.to_world_position(0.5);
+
+        for &enemy in &spotted.enemies {
+            let Ok(enemy_pos) = positions.get(enemy) else {
+                continue;
+            };
+            let distance = own_pos.distance(enemy_pos.to_world_position(0.5));
+            table.add_proximity(enemy, distance, delta);
+        }
+    }
+
+    for event in damage_events.read() {
+        if event.attacker == event.target {
+            continue;
+        }
+        if let Ok((mut table, _, _, _)) = tables.get_mut(event.target) {
+            table.add_damage(event.attacker, event.damage);
+        }
+    }
+
+    for event in fired_events.read() {
+        let Some(target) = event.target else {
+            continue;
+        };
+        if let Ok((mut table, _, _, _)) = tables.get_mut(target) {
+            table.add_attack(event.shooter);
+        }
+    }
+
+    for event in ai_events.read() {
+        let GodotAIEvent::EnemyWindupVisible { attacker, defender, .. } = event else {
+            continue;
+        };
+        if let Ok((mut table, _, _, _)) = tables.get_mut(*defender) {
+            table.add_attack(*attacker);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Battlefield components (трупы, декали, предметы на земле под общим бюджетом).
+
+use bevy::prelude::*;
+
+/// Тип долгоживущего артефакта боя
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum ArtifactKind {
+    Corpse,
+    Decal,
+    DroppedItem,
+}
+
+impl ArtifactKind {
+    pub const ALL: [ArtifactKind; 3] = [ArtifactKind::Corpse, ArtifactKind::Decal, ArtifactKind::DroppedItem];
+}
+
+/// Артефакт под бюджетом (`ArtifactBudget`)
+///
+/// LRU: `last_used_tick` обновляется при создании и пока игрок рядом (`keep_radius`).
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct BattlefieldArtifact {
+    pub kind: ArtifactKind,
+    pub last_used_tick: u64,
+}
+
+impl BattlefieldArtifact {
+    pub fn new(kind: ArtifactKind, tick: u64) -> Self {
+        Self { kind, last_used_tick: tick }
+    }
+}
+
+/// Вид декали
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum DecalKind {
+    /// Кровь под раненым
+    Blood,
+    /// Подпалина от вспышки
+    Scorch,
+}
+
+/// Декаль на поверхности (Godot рисует Decal node, despawn entity → queue_free)
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct ImpactDecal {
+    pub kind: DecalKind,
+    /// Точка на поверхности (world coordinates)
+    pub position: Vec3,
+    /// Нормаль поверхности (направление проекции — против неё)
+    pub normal: Vec3,
+}
+
+/// Бюджет артефактов боя (resource)
+///
+/// Сверх лимита вытесняются давно не использованные, при равенстве — дальние от игрока.
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct ArtifactBudget {
+    pub max_corpses: usize,
+    pub max_decals: usize,
+    pub max_dropped_items: usize,
+    /// Артефакты ближе к игроку считаются используемыми (метры)
+    pub keep_radius: f32,
+}
+
+impl Default for ArtifactBudget {
+    fn default() -> Self {
+        Self {
+            max_corpses: 20,
+            max_decals: 64,
+            max_dropped_items: 40,
+            keep_radius: 25.0,
+        }
+    }
+}
+
+impl ArtifactBudget {
+    /// Как часто проверять бюджет (ticks, 60 Hz)
+    pub const ENFORCE_INTERVAL_TICKS: u64 = 60;
+
+    pub fn cap(&self, kind: ArtifactKind) -> usize {
+        match kind {
+            ArtifactKind::Corpse => self.max_corpses,
+            ArtifactKind::Decal => self.max_decals,
+            ArtifactKind::DroppedItem => self.max_dropped_items,
+        }
+    }
+}
+
+/// Кандидат на вытеснение
+#[derive(Debug, Clone, Copy)]
+pub struct ArtifactEntry {
+    pub entity: Entity,
+    pub last_used_tick: u64,
+    /// Дистанция до игрока (нет игрока — 0)
+    pub distance: f32,
+}
+
+/// Кого вытеснить, чтобы осталось не больше `cap`: самые давно использованные, при равенстве — дальние
+pub fn select_evictions(mut entries: Vec<ArtifactEntry>, cap: usize) -> Vec<Entity> {
+    if entries.len() <= cap {
+        return Vec::new();
+    }
+
+    entries.sort_by(|a, b| {
+        a.last_used_tick
+            .cmp(&b.last_used_tick)
+            .then_with(|| b.distance.total_cmp(&a.distance))
+            .then_with(|| a.entity.cmp(&b.entity))
+    });
+
+    let excess = entries.len() - cap;
+    entries.into_iter().take(excess).map(|entry| entry.entity).collect()
+}
@@ -0,0 +1,37 @@
+//! Tests for battlefield components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::components::*;
+    use bevy::prelude::Entity;
+
+    fn entry(index: u32, last_used_tick: u64, distance: f32) -> ArtifactEntry {
+        ArtifactEntry { entity: Entity::from_raw(index), last_used_tick, distance }
+    }
+
+    #[test]
+    fn test_under_cap_evicts_nothing() {
+        let entries = vec![entry(1, 10, 5.0), entry(2, 20, 5.0)];
+        assert!(select_evictions(entries, 2).is_empty());
+    }
+
+    #[test]
+    fn test_evicts_least_recently_used_first() {
+        let entries = vec![entry(1, 30, 5.0), entry(2, 10, 5.0), entry(3, 20, 5.0)];
+        assert_eq!(select_evictions(entries, 1), vec![Entity::from_raw(2), Entity::from_raw(3)]);
+    }
+
+    #[test]
+    fn test_ties_evict_farthest_first() {
+        let entries = vec![entry(1, 10, 5.0), entry(2, 10, 80.0), entry(3, 10, 40.0)];
+        assert_eq!(select_evictions(entries, 2), vec![Entity::from_raw(2)]);
+    }
+
+    #[test]
+    fn test_budget_caps_per_kind() {
+        let budget = ArtifactBudget::default();
+        assert_eq!(budget.cap(ArtifactKind::Corpse), budget.max_corpses);
+        assert_eq!(budget.cap(ArtifactKind::Decal), budget.max_decals);
+        assert_eq!(budget.cap(ArtifactKind::DroppedItem), budget.max_dropped_items);
+    }
+}
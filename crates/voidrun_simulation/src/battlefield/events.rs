@@ -0,0 +1,11 @@
+//! Battlefield events.
+
+use bevy::prelude::*;
+use super::components::ArtifactKind;
+
+/// Артефакт вытеснен бюджетом (entity уже despawn'ится)
+#[derive(Event, Debug, Clone)]
+pub struct ArtifactEvicted {
+    pub entity: Entity,
+    pub kind: ArtifactKind,
+}
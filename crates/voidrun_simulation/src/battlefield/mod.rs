@@ -0,0 +1,51 @@
+//! Battlefield module — бюджет долгоживущих артефактов боя (трупы, декали, предметы на земле)
+//!
+//! # Architecture
+//!
+//! Длинная сессия копит трупы, кровь и выброшенный лут. Каждый такой entity помечается
+//! `BattlefieldArtifact` (тип + tick последнего использования), `ArtifactBudget` держит лимит на тип.
+//!
+//! **Flow:**
+//! - `Added<WorldItem>` / `EntityDied` (не арена, не игрок) → `BattlefieldArtifact`
+//! - `DamageDealt` (мимо щита) → `ImpactDecal::Blood`, `FlashbangDetonated` → `ImpactDecal::Scorch`
+//! - Раз в секунду: артефакты рядом с игроком «используются» (LRU), сверх лимита —
+//!   самые давно использованные, при равенстве дальние, despawn + `ArtifactEvicted`
+//!
+//! Godot: node'ы следуют за ECS — трупы и WorldItem убираются по `RemovedComponents`,
+//! декали (`ImpactDecal`) — своим registry.
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Battlefield Plugin
+///
+/// Регистрирует бюджет артефактов и декали в FixedUpdate.
+pub struct BattlefieldPlugin;
+
+impl Plugin for BattlefieldPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ArtifactEvicted>()
+            .init_resource::<ArtifactBudget>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    tag_battlefield_artifacts, // 1. WorldItem / трупы → BattlefieldArtifact
+                    spawn_impact_decals,       // 2. DamageDealt / FlashbangDetonated → ImpactDecal
+                    enforce_artifact_budget,   // 3. LRU + лимиты → despawn + ArtifactEvicted
+                )
+                    .chain(),
+            );
+    }
+}
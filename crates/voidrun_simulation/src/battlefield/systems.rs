@@ -0,0 +1,127 @@
+//! Battlefield systems (метки артефактов, декали, вытеснение сверх бюджета).
+
+use bevy::prelude::*;
+use crate::combat::{AppliedDamage, DamageDealt, EntityDied, FlashbangDetonated};
+use crate::components::Actor;
+use crate::game_mode::ArenaFighter;
+use crate::interaction::{OpenContainer, WorldItem};
+use crate::player::Player;
+use crate::{SimulationTick, StrategicPosition};
+use super::components::{
+    select_evictions, ArtifactBudget, ArtifactEntry, ArtifactKind, BattlefieldArtifact, DecalKind, ImpactDecal,
+};
+use super::events::ArtifactEvicted;
+
+/// System: новые WorldItem и трупы → BattlefieldArtifact
+///
+/// Арена и игрок не в бюджете (их жизненный цикл ведёт GameMode / Session).
+pub fn tag_battlefield_artifacts(
+    new_items: Query<Entity, (Added<WorldItem>, Without<BattlefieldArtifact>)>,
+    actors: Query<(), (With<Actor>, Without<ArenaFighter>, Without<Player>, Without<BattlefieldArtifact>)>,
+    mut death_events: EventReader<EntityDied>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    let now = tick.get();
+
+    for entity in new_items.iter() {
+        commands
+            .entity(entity)
+            .insert(BattlefieldArtifact::new(ArtifactKind::DroppedItem, now));
+    }
+
+    for died in death_events.read() {
+        if actors.get(died.entity).is_err() {
+            continue;
+        }
+        if let Ok(mut entity) = commands.get_entity(died.entity) {
+            entity.insert(BattlefieldArtifact::new(ArtifactKind::Corpse, now));
+        }
+    }
+}
+
+/// System: попадания → кровь под целью, вспышки → подпалина
+///
+/// Щит поглотил удар — крови нет. Декаль кладётся на пол (y = 0) под точкой попадания.
+pub fn spawn_impact_decals(
+    mut damage_events: EventReader<DamageDealt>,
+    mut flash_events: EventReader<FlashbangDetonated>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    let now = tick.get();
+
+    let blood = damage_events
+        .read()
+        .filter(|event| event.damage > 0 && !matches!(event.applied_damage, AppliedDamage::ShieldAbsorbed))
+        .map(|event| (DecalKind::Blood, event.impact_point));
+    let scorch = flash_events
+        .read()
+        .map(|event| (DecalKind::Scorch, event.position));
+
+    for (kind, point) in blood.chain(scorch) {
+        let position = Vec3::new(point.x, 0.0, point.z);
+        commands.spawn((
+            ImpactDecal { kind, position, normal: Vec3::Y },
+            BattlefieldArtifact::new(ArtifactKind::Decal, now),
+            StrategicPosition::from_world_position(position),
+        ));
+    }
+}
+
+/// System: бюджет артефактов (раз в `ENFORCE_INTERVAL_TICKS`)
+///
+/// - Рядом с игроком (`keep_radius`) → `last_used_tick` = сейчас (LRU)
+/// - Открытый кем-то контейнер не вытесняется
+/// - Сверх лимита → despawn (Godot убирает node через RemovedComponents) + `ArtifactEvicted`
+pub fn enforce_artifact_budget(
+    mut artifacts: Query<(Entity, &mut BattlefieldArtifact, &StrategicPosition)>,
+    player: Query<&StrategicPosition, With<Player>>,
+    open_containers: Query<&OpenContainer>,
+    budget: Res<ArtifactBudget>,
+    tick: Res<SimulationTick>,
+    mut evicted_events: EventWriter<ArtifactEvicted>,
+    mut commands: Commands,
+) {
+    let now = tick.get();
+    if !now.is_multiple_of(ArtifactBudget::ENFORCE_INTERVAL_TICKS) {
+        return;
+    }
+
+    let player_pos = player.single().ok().map(|position| position.to_world_position(0.0));
+
+    let mut entries: Vec<(ArtifactKind, ArtifactEntry)> = Vec::new();
+    for (entity, mut artifact, position) in artifacts.iter_mut() {
+        let distance = player_pos.map_or(0.0, |player_pos| player_pos.distance(position.to_world_position(0.0)));
+        if player_pos.is_some() && distance <= budget.keep_radius {
+            artifact.last_used_tick = now;
+        }
+        if open_containers.iter().any(|open| open.container == entity) {
+            continue;
+        }
+
+        entries.push((
+            artifact.kind,
+            ArtifactEntry { entity, last_used_tick: artifact.last_used_tick, distance },
+        ));
+    }
+
+    for kind in ArtifactKind::ALL {
+        let candidates: Vec<ArtifactEntry> = entries
+            .iter()
+            .filter(|(entry_kind, _)| *entry_kind == kind)
+            .map(|(_, entry)| *entry)
+            .collect();
+
+        let evicted = select_evictions(candidates, budget.cap(kind));
+        if evicted.is_empty() {
+            continue;
+        }
+
+        crate::logger::log(&format!("🧹 Battlefield budget: evicting {} {:?}", evicted.len(), kind));
+        for entity in evicted {
+            commands.entity(entity).despawn();
+            evicted_events.write(ArtifactEvicted { entity, kind });
+        }
+    }
+}
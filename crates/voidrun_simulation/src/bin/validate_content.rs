@@ -0,0 +1,62 @@
+//! validate-content — проверка контента (RON данные + ItemDefinitions + prefab'ы Godot)
+//!
+//! ```text
+//! cargo run -p voidrun_simulation --bin validate-content -- [--data <dir>] [--godot <dir>] [--no-godot]
+//! ```
+//!
+//! По умолчанию — раскладка репозитория (`data/`, `../../godot`). Код выхода 1 — есть проблемы.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use voidrun_simulation::content::{validate_content, ContentPaths};
+
+fn main() -> ExitCode {
+    let mut paths = ContentPaths::from_crate_dir(Path::new(env!("CARGO_MANIFEST_DIR")));
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--data" => match args.next() {
+                Some(dir) => paths.data_dir = PathBuf::from(dir),
+                None => return usage("--data needs a directory"),
+            },
+            "--godot" => match args.next() {
+                Some(dir) => paths.godot_project = Some(PathBuf::from(dir)),
+                None => return usage("--godot needs a directory"),
+            },
+            "--no-godot" => paths.godot_project = None,
+            "-h" | "--help" => {
+                print_usage();
+                return ExitCode::SUCCESS;
+            }
+            other => return usage(&format!("unknown argument '{}'", other)),
+        }
+    }
+
+    let report = validate_content(&paths);
+    for issue in report.issues.iter() {
+        eprintln!("error: {}", issue);
+    }
+
+    println!(
+        "validate-content: {} files + item definitions checked, {} issue(s)",
+        report.checked.len(),
+        report.issues.len()
+    );
+
+    if report.is_ok() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn print_usage() {
+    println!("usage: validate-content [--data <dir>] [--godot <dir>] [--no-godot]");
+}
+
+fn usage(error: &str) -> ExitCode {
+    eprintln!("error: {}", error);
+    print_usage();
+    ExitCode::from(2)
+}
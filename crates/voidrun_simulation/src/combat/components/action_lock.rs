@@ -0,0 +1,208 @@
+//! Action arbitration (ActionLock + CancelTable).
+//!
+//! Одно "занятое" действие на актора. Раньше каждая start-система проверяла
+//! MeleeAttackState/ParryState/... ad hoc; теперь все спрашивают `ActionLock::permits`.
+//!
+//! # Правила
+//! - Нет ActionLock → можно начать что угодно
+//! - Есть ActionLock → можно начать только то, что разрешено `CancelTable`
+//!   для (текущее действие, фаза) — data-driven (`data/cancel_table.ron`), тюнинг без изменения кода
+//! - Stagger/Flinch/Knockdown — принудительные реакции, накладываются мимо арбитража
+//!
+//! Godot-side `CurrentAction` (ai_melee) остаётся детальным view для AI scoring;
+//! ActionLock — ECS-авторитетное правило "можно ли начать".
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use super::channel::ChannelKind;
+
+/// Ошибка разбора cancel таблицы из RON
+pub type CancelTableParseError = ron::error::SpannedError;
+
+/// Встроенная cancel таблица (data/cancel_table.ron)
+const DEFAULT_CANCEL_TABLE: &str = include_str!("../../../data/cancel_table.ron");
+
+/// Вид действия (для арбитража).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Deserialize)]
+pub enum ActionKind {
+    /// Melee атака (MeleeAttackState)
+    MeleeAttack,
+    /// Парирование (ParryState)
+    Parry,
+    /// Перезарядка / устранение клина (Channeling::Reload, Channeling::ClearJam)
+    Reload,
+    /// Использование расходника (Channeling::Consumable)
+    UseConsumable,
+    /// Взлом (Channeling::Hack)
+    Hack,
+    /// Каст способности (Channeling::AbilityCast)
+    AbilityCast,
+    /// Вызов подкрепления (Channeling::RadioCall)
+    RadioCall,
+    /// Выбивание двери (Channeling::Breach)
+    Breach,
+    /// Сканирование цели (Channeling::Scan)
+    Scan,
+    /// Крафт (Channeling::Craft)
+    Craft,
+    /// Поднятие союзника (Channeling::Revive)
+    Revive,
+    /// Уклонение
+    Dodge,
+    /// Спринт
+    Sprint,
+    /// Перелезание через препятствие (MantleState)
+    Mantle,
+    /// Heavy flinch (FlinchState)
+    Flinch,
+    /// Stagger после парирования (StaggerState)
+    Stagger,
+    /// Падение + подъём (KnockdownState)
+    Knockdown,
+    /// Ранен при 0 HP, ждёт поднятия (Downed)
+    Downed,
+}
+
+impl ActionKind {
+    /// Принудительная реакция (накладывается мимо арбитража — не цель отмены)
+    pub fn is_forced_reaction(&self) -> bool {
+        matches!(self, Self::Flinch | Self::Stagger | Self::Knockdown | Self::Downed)
+    }
+}
+
+impl From<ChannelKind> for ActionKind {
+    fn from(kind: ChannelKind) -> Self {
+        match kind {
+            ChannelKind::Reload | ChannelKind::ClearJam => Self::Reload,
+            ChannelKind::Consumable { .. } => Self::UseConsumable,
+            ChannelKind::Hack => Self::Hack,
+            ChannelKind::AbilityCast => Self::AbilityCast,
+            ChannelKind::RadioCall => Self::RadioCall,
+            ChannelKind::Breach => Self::Breach,
+            ChannelKind::Scan => Self::Scan,
+            ChannelKind::Craft => Self::Craft,
+            ChannelKind::Revive => Self::Revive,
+        }
+    }
+}
+
+/// Фаза текущего действия (ключ cancel таблицы).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect, Deserialize)]
+pub enum ActionPhase {
+    /// Подготовка (windup) — до active frames
+    Startup,
+    /// Active frames / основная часть действия
+    Active,
+    /// Восстановление после active frames
+    Recovery,
+}
+
+/// Текущее занятое действие актора.
+///
+/// Пересчитывается `update_action_locks` из state компонентов каждый тик;
+/// start-системы также вставляют lock сразу при старте (видно следующим системам в chain).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ActionLock {
+    pub action: ActionKind,
+    pub phase: ActionPhase,
+}
+
+impl ActionLock {
+    pub fn new(action: ActionKind, phase: ActionPhase) -> Self {
+        Self { action, phase }
+    }
+
+    /// Можно ли начать `new_action` поверх текущего lock
+    pub fn allows(&self, new_action: ActionKind, table: &CancelTable) -> bool {
+        table.can_cancel(self.action, self.phase, new_action)
+    }
+
+    /// Helper для start-систем: Option<&ActionLock> (None = свободен)
+    pub fn permits(lock: Option<&ActionLock>, new_action: ActionKind, table: &CancelTable) -> bool {
+        lock.is_none_or(|lock| lock.allows(new_action, table))
+    }
+}
+
+/// Правило cancel таблицы в RON: (from, phase) → into
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CancelRule {
+    pub from: ActionKind,
+    pub phase: ActionPhase,
+    pub into: Vec<ActionKind>,
+}
+
+/// Файл cancel таблицы (`data/cancel_table.ron`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct CancelTableSource {
+    rules: Vec<CancelRule>,
+}
+
+/// Cancel таблица: (действие, фаза) → в какие действия можно отменить (resource).
+///
+/// Отсутствующая запись = отмена запрещена.
+/// По умолчанию — `data/cancel_table.ron`, замена — `from_ron`.
+#[derive(Resource, Debug, Clone)]
+pub struct CancelTable {
+    pub rules: HashMap<(ActionKind, ActionPhase), Vec<ActionKind>>,
+    /// Пары (from, phase), встретившиеся в файле больше одного раза (для `issues`)
+    duplicates: Vec<(ActionKind, ActionPhase)>,
+}
+
+impl Default for CancelTable {
+    fn default() -> Self {
+        Self::from_ron(DEFAULT_CANCEL_TABLE).unwrap_or_else(|error| {
+            crate::logger::log_error(&format!("Default cancel table failed to parse: {}", error));
+            Self { rules: HashMap::new(), duplicates: Vec::new() }
+        })
+    }
+}
+
+impl CancelTable {
+    pub fn from_ron(source: &str) -> Result<Self, CancelTableParseError> {
+        let source: CancelTableSource = ron::from_str(source)?;
+
+        let mut table = Self { rules: HashMap::new(), duplicates: Vec::new() };
+        for rule in source.rules {
+            if table.rules.contains_key(&(rule.from, rule.phase)) {
+                table.duplicates.push((rule.from, rule.phase));
+            }
+            table.allow(rule.from, rule.phase, &rule.into);
+        }
+        Ok(table)
+    }
+
+    /// Проблемы таблицы ("from phase: описание"): повторные пары, пустые into,
+    /// принудительные реакции как цель отмены
+    pub fn issues(&self) -> Vec<String> {
+        let mut issues: Vec<String> = self
+            .duplicates
+            .iter()
+            .map(|(from, phase)| format!("{:?} {:?}: duplicate rule", from, phase))
+            .collect();
+
+        for ((from, phase), into) in self.rules.iter() {
+            if into.is_empty() {
+                issues.push(format!("{:?} {:?}: empty cancel list", from, phase));
+            }
+            for action in into.iter().filter(|action| action.is_forced_reaction()) {
+                issues.push(format!("{:?} {:?}: forced reaction {:?} cannot be a cancel target", from, phase, action));
+            }
+        }
+        issues.sort();
+        issues
+    }
+
+    /// Разрешить отмену (from, phase) → into
+    pub fn allow(&mut self, from: ActionKind, phase: ActionPhase, into: &[ActionKind]) {
+        self.rules.entry((from, phase)).or_default().extend_from_slice(into);
+    }
+
+    /// Можно ли отменить `from` в фазе `phase` действием `into`
+    pub fn can_cancel(&self, from: ActionKind, phase: ActionPhase, into: ActionKind) -> bool {
+        self.rules
+            .get(&(from, phase))
+            .is_some_and(|allowed| allowed.contains(&into))
+    }
+}
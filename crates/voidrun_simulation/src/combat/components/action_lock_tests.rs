@@ -0,0 +1,128 @@
+//! Tests for action arbitration (ActionLock + CancelTable).
+
+#[cfg(test)]
+mod tests {
+    use super::super::action_lock::*;
+
+    #[test]
+    fn test_free_actor_can_start_anything() {
+        let table = CancelTable::default();
+
+        assert!(ActionLock::permits(None, ActionKind::MeleeAttack, &table));
+        assert!(ActionLock::permits(None, ActionKind::Reload, &table));
+    }
+
+    #[test]
+    fn test_attack_cancels_into_dodge_only_outside_active_frames() {
+        let table = CancelTable::default();
+
+        let windup = ActionLock::new(ActionKind::MeleeAttack, ActionPhase::Startup);
+        let active = ActionLock::new(ActionKind::MeleeAttack, ActionPhase::Active);
+        let recovery = ActionLock::new(ActionKind::MeleeAttack, ActionPhase::Recovery);
+
+        assert!(windup.allows(ActionKind::Parry, &table));
+        assert!(!active.allows(ActionKind::Dodge, &table));
+        assert!(!active.allows(ActionKind::Parry, &table));
+        assert!(recovery.allows(ActionKind::Dodge, &table));
+        assert!(recovery.allows(ActionKind::MeleeAttack, &table));
+    }
+
+    #[test]
+    fn test_committed_actions_block() {
+        let table = CancelTable::default();
+
+        let parry = ActionLock::new(ActionKind::Parry, ActionPhase::Active);
+        let stagger = ActionLock::new(ActionKind::Stagger, ActionPhase::Active);
+        let reload = ActionLock::new(ActionKind::Reload, ActionPhase::Active);
+
+        assert!(!parry.allows(ActionKind::Dodge, &table));
+        assert!(!stagger.allows(ActionKind::MeleeAttack, &table));
+        assert!(!reload.allows(ActionKind::MeleeAttack, &table));
+        assert!(reload.allows(ActionKind::Sprint, &table));
+    }
+
+    #[test]
+    fn test_sprint_blocks_attacks() {
+        let table = CancelTable::default();
+
+        let sprint = ActionLock::new(ActionKind::Sprint, ActionPhase::Active);
+
+        assert!(!sprint.allows(ActionKind::MeleeAttack, &table));
+        assert!(sprint.allows(ActionKind::Dodge, &table));
+        assert!(sprint.allows(ActionKind::Reload, &table));
+    }
+
+    #[test]
+    fn test_cancel_table_is_tunable() {
+        let mut table = CancelTable::default();
+        let parry = ActionLock::new(ActionKind::Parry, ActionPhase::Active);
+
+        table.allow(ActionKind::Parry, ActionPhase::Active, &[ActionKind::Dodge]);
+        assert!(parry.allows(ActionKind::Dodge, &table));
+    }
+
+    #[test]
+    fn test_mantle_from_sprint_only() {
+        let table = CancelTable::default();
+
+        let sprint = ActionLock::new(ActionKind::Sprint, ActionPhase::Active);
+        let mantle = ActionLock::new(ActionKind::Mantle, ActionPhase::Active);
+        let attack = ActionLock::new(ActionKind::MeleeAttack, ActionPhase::Recovery);
+
+        assert!(sprint.allows(ActionKind::Mantle, &table));
+        assert!(!attack.allows(ActionKind::Mantle, &table));
+        assert!(!mantle.allows(ActionKind::MeleeAttack, &table));
+    }
+
+    #[test]
+    fn test_cancel_table_parses_from_ron() {
+        let table = CancelTable::from_ron(
+            "(rules: [
+                (from: Parry, phase: Recovery, into: [Dodge]),
+                (from: Reload, phase: Active, into: [Sprint, Dodge]),
+            ])",
+        )
+        .unwrap();
+
+        let parry = ActionLock::new(ActionKind::Parry, ActionPhase::Recovery);
+        let reload = ActionLock::new(ActionKind::Reload, ActionPhase::Active);
+        assert!(parry.allows(ActionKind::Dodge, &table));
+        assert!(reload.allows(ActionKind::Dodge, &table));
+        assert!(!reload.allows(ActionKind::MeleeAttack, &table));
+        assert!(table.issues().is_empty());
+    }
+
+    #[test]
+    fn test_cancel_table_rejects_unknown_action() {
+        assert!(CancelTable::from_ron("(rules: [(from: Teleport, phase: Active, into: [])])").is_err());
+    }
+
+    #[test]
+    fn test_cancel_table_issues() {
+        let table = CancelTable::from_ron(
+            "(rules: [
+                (from: Sprint, phase: Active, into: [Stagger]),
+                (from: Sprint, phase: Active, into: [Dodge]),
+                (from: Dodge, phase: Recovery, into: []),
+            ])",
+        )
+        .unwrap();
+
+        assert_eq!(
+            table.issues(),
+            vec![
+                "Dodge Recovery: empty cancel list".to_string(),
+                "Sprint Active: duplicate rule".to_string(),
+                "Sprint Active: forced reaction Stagger cannot be a cancel target".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_shipped_cancel_table_is_clean() {
+        let table = CancelTable::default();
+
+        assert!(!table.rules.is_empty());
+        assert!(table.issues().is_empty(), "{:?}", table.issues());
+    }
+}
@@ -0,0 +1,92 @@
+//! Aim skill components (ranged AI accuracy model).
+//!
+//! AI не стреляет идеально:
+//! - Spread: случайное отклонение в конусе
+//! - Reaction delay: задержка перед первым выстрелом по новой цели
+//! - Tracking: насколько точно AI упреждает движущуюся цель
+//!
+//! ECS: reaction timer (AimReaction) + fire intent gating.
+//! Godot: spread + lead (weapon_fire_main_thread).
+
+use bevy::prelude::*;
+
+/// Aim skill (per-actor, задаётся при spawn).
+///
+/// Без AimSkill стрелок стреляет по направлению weapon bone (player/legacy NPC).
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct AimSkill {
+    /// Базовый разброс (градусы, половина угла конуса)
+    pub spread_degrees: f32,
+    /// Задержка перед первым выстрелом по новой цели (секунды)
+    pub reaction_delay: f32,
+    /// Точность упреждения движущейся цели (0.0 = стреляет в текущую позицию, 1.0 = идеальный lead)
+    pub tracking: f32,
+}
+
+impl Default for AimSkill {
+    /// Средний стрелок
+    fn default() -> Self {
+        Self {
+            spread_degrees: 3.0,
+            reaction_delay: 0.4,
+            tracking: 0.5,
+        }
+    }
+}
+
+impl AimSkill {
+    /// Новичок: большой разброс, медленная реакция, не упреждает
+    pub fn rookie() -> Self {
+        Self {
+            spread_degrees: 6.0,
+            reaction_delay: 0.8,
+            tracking: 0.0,
+        }
+    }
+
+    /// Ветеран: точный, быстрый, хорошо упреждает
+    pub fn veteran() -> Self {
+        Self {
+            spread_degrees: 1.5,
+            reaction_delay: 0.25,
+            tracking: 0.8,
+        }
+    }
+
+    /// Элита: почти идеальный стрелок
+    pub fn elite() -> Self {
+        Self {
+            spread_degrees: 0.5,
+            reaction_delay: 0.15,
+            tracking: 1.0,
+        }
+    }
+}
+
+/// Reaction timer для текущей цели.
+///
+/// Обновляется `update_aim_reaction`: сбрасывается при смене цели,
+/// `ai_weapon_fire_intent` не стреляет пока `!is_ready()`.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct AimReaction {
+    /// Цель, на которую идёт реакция
+    pub target: Entity,
+    /// Оставшееся время реакции (секунды)
+    pub timer: f32,
+}
+
+impl AimReaction {
+    pub fn new(target: Entity, reaction_delay: f32) -> Self {
+        Self {
+            target,
+            timer: reaction_delay,
+        }
+    }
+
+    /// Реакция завершена — можно стрелять
+    pub fn is_ready(&self) -> bool {
+        self.timer <= 0.0
+    }
+}
@@ -0,0 +1,70 @@
+//! Blindness components (flashbang).
+//!
+//! Вспышка ослепляет акторов в радиусе, смотрящих на детонацию:
+//! - SpottedEnemies очищается, VisionCone не детектирует на время ослепления
+//! - Игрок получает HUD событие (белый экран)
+//! - Отвернувшиеся получают ослабленный эффект (facing-away mitigation)
+//! - Стрельба вслепую: разброс до `MAX_SPREAD_DEGREES` (по силе ослепления)
+
+use bevy::prelude::*;
+
+/// Blinded status component.
+///
+/// Добавляется `apply_flash_exposure`, удаляется `update_blinded_states` по истечении.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Blinded {
+    /// Оставшееся время (секунды)
+    pub remaining: f32,
+    /// Полная длительность (для fade HUD)
+    pub duration: f32,
+    /// Сила ослепления (0.0-1.0)
+    pub intensity: f32,
+}
+
+impl Blinded {
+    /// Множитель эффекта спиной к вспышке (facing dot = -1)
+    pub const FACING_AWAY_MITIGATION: f32 = 0.25;
+    /// Минимальная экспозиция для ослепления (ниже — без эффекта)
+    pub const MIN_EXPOSURE: f32 = 0.15;
+    /// Доп. разброс стрельбы при полном ослеплении (градусы, половина угла конуса)
+    pub const MAX_SPREAD_DEGREES: f32 = 20.0;
+
+    pub fn new(duration: f32, intensity: f32) -> Self {
+        Self {
+            remaining: duration,
+            duration,
+            intensity: intensity.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Экспозиция вспышки (0.0-1.0)
+    ///
+    /// - `distance` / `radius`: линейное затухание к краю радиуса
+    /// - `facing_dot`: dot(forward, направление на вспышку), 1 = смотрит прямо, -1 = спиной
+    pub fn exposure(distance: f32, radius: f32, facing_dot: f32) -> f32 {
+        if radius <= 0.0 || distance >= radius {
+            return 0.0;
+        }
+
+        let proximity = 1.0 - distance / radius;
+        let facing = (facing_dot.clamp(-1.0, 1.0) + 1.0) * 0.5;
+        let facing_factor = Self::FACING_AWAY_MITIGATION + (1.0 - Self::FACING_AWAY_MITIGATION) * facing;
+
+        proximity * facing_factor
+    }
+
+    /// Доп. разброс стрельбы (градусы): полная сила — `MAX_SPREAD_DEGREES`
+    pub fn extra_spread_degrees(&self) -> f32 {
+        Self::MAX_SPREAD_DEGREES * self.intensity
+    }
+
+    /// Усилить ослепление (повторная вспышка — берём максимум)
+    pub fn refresh(&mut self, duration: f32, intensity: f32) {
+        if duration > self.remaining {
+            self.remaining = duration;
+            self.duration = duration;
+        }
+        self.intensity = self.intensity.max(intensity.clamp(0.0, 1.0));
+    }
+}
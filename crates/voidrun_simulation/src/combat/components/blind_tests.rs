@@ -0,0 +1,43 @@
+//! Tests for blindness components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::blind::*;
+
+    #[test]
+    fn test_flash_exposure_facing() {
+        // Вплотную, смотрит прямо — полная экспозиция
+        assert!((Blinded::exposure(0.0, 10.0, 1.0) - 1.0).abs() < 1e-6);
+        // Спиной — только mitigation
+        assert!((Blinded::exposure(0.0, 10.0, -1.0) - Blinded::FACING_AWAY_MITIGATION).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_flash_exposure_range() {
+        // За радиусом — ничего
+        assert_eq!(Blinded::exposure(10.0, 10.0, 1.0), 0.0);
+        // Половина радиуса — половина
+        assert!((Blinded::exposure(5.0, 10.0, 1.0) - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_blinded_refresh_takes_max() {
+        let mut blinded = Blinded::new(3.0, 0.8);
+        blinded.remaining = 1.0;
+
+        // Слабая вспышка не сокращает и не ослабляет
+        blinded.refresh(0.5, 0.2);
+        assert_eq!(blinded.remaining, 1.0);
+        assert_eq!(blinded.intensity, 0.8);
+
+        blinded.refresh(2.5, 1.0);
+        assert_eq!(blinded.remaining, 2.5);
+        assert_eq!(blinded.intensity, 1.0);
+    }
+
+    #[test]
+    fn test_blinded_spread_scales_with_intensity() {
+        assert_eq!(Blinded::new(3.0, 1.0).extra_spread_degrees(), Blinded::MAX_SPREAD_DEGREES);
+        assert!((Blinded::new(3.0, 0.5).extra_spread_degrees() - Blinded::MAX_SPREAD_DEGREES * 0.5).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,118 @@
+//! Channelled actions (reload, consumable use, hacking, ability cast, radio call, door breach, scan, craft, revive).
+//!
+//! Единые правила прерывания: действие отменяется, если урон за скользящее
+//! окно превысил порог (`ChannelInterruptRules`). Каждое channelled действие
+//! только вставляет `Channeling` и слушает `ChannelCompleted`/`ChannelInterrupted` —
+//! своя логика прерывания не нужна.
+//!
+//! Tick-based (SimulationTick), как Invulnerable — детерминизм.
+
+use bevy::prelude::*;
+
+/// Тип channelled действия.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ChannelKind {
+    /// Перезарядка оружия
+    Reload,
+    /// Использование расходника из слота
+    Consumable { slot_index: u8 },
+    /// Взлом (терминалы, двери)
+    Hack,
+    /// Каст способности
+    AbilityCast,
+    /// Вызов подкрепления по рации (RadioOperator)
+    RadioCall,
+    /// Выбивание двери (удар/рывок)
+    Breach,
+    /// Сканирование цели (удержание клавиши, `scan`)
+    Scan,
+    /// Крафт по рецепту (`crafting`)
+    Craft,
+    /// Устранение клина оружия (`ClearJamIntent`)
+    ClearJam,
+    /// Поднятие раненого союзника (`ReviveIntent`)
+    Revive,
+}
+
+/// Channelled action в процессе.
+///
+/// Вставляется системой, начинающей действие. Удаляется:
+/// - `update_channels` → ChannelCompleted (tick >= complete_at_tick)
+/// - `interrupt_channels_on_damage` → ChannelInterrupted (урон за окно > порога)
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Channeling {
+    pub kind: ChannelKind,
+    /// Tick завершения (exclusive)
+    pub complete_at_tick: u64,
+    /// Можно ли прервать уроном (например, ability с super armor)
+    pub interruptible: bool,
+    /// Урон, полученный во время channel: (tick, amount)
+    pub damage_taken: Vec<(u64, u32)>,
+}
+
+impl Channeling {
+    /// Начать channel (complete_at_tick обычно `tick.after_secs(duration)`)
+    pub fn new(kind: ChannelKind, complete_at_tick: u64) -> Self {
+        Self {
+            kind,
+            complete_at_tick,
+            interruptible: true,
+            damage_taken: Vec::new(),
+        }
+    }
+
+    /// Channel без прерывания уроном
+    pub fn uninterruptible(mut self) -> Self {
+        self.interruptible = false;
+        self
+    }
+
+    /// Завершён ли channel на данном tick
+    pub fn is_complete_at(&self, tick: u64) -> bool {
+        tick >= self.complete_at_tick
+    }
+
+    /// Записать урон (tick, amount)
+    pub fn record_damage(&mut self, tick: u64, amount: u32) {
+        self.damage_taken.push((tick, amount));
+    }
+
+    /// Суммарный урон за последние `window_ticks` (включая текущий tick).
+    /// Старые записи отбрасываются (окно одинаковое с первого tick матча).
+    pub fn damage_in_window(&mut self, tick: u64, window_ticks: u64) -> u32 {
+        self.damage_taken.retain(|(t, _)| tick.saturating_sub(*t) < window_ticks);
+        self.damage_taken.iter().map(|(_, amount)| amount).sum()
+    }
+}
+
+/// Правила прерывания channelled действий (resource).
+///
+/// Порог = max(`min_damage`, `max_health_fraction` × max HP).
+#[derive(Resource, Debug, Clone)]
+pub struct ChannelInterruptRules {
+    /// Окно накопления урона (секунды)
+    pub window_secs: f32,
+    /// Порог как доля от max HP (0.0-1.0)
+    pub max_health_fraction: f32,
+    /// Минимальный абсолютный порог (не прерываемся от царапин у low-HP акторов)
+    pub min_damage: u32,
+}
+
+impl Default for ChannelInterruptRules {
+    fn default() -> Self {
+        Self {
+            window_secs: 1.0,
+            max_health_fraction: 0.1,
+            min_damage: 5,
+        }
+    }
+}
+
+impl ChannelInterruptRules {
+    /// Порог урона для актора с данным max HP
+    pub fn threshold(&self, max_health: u32) -> u32 {
+        let fraction = (max_health as f32 * self.max_health_fraction).ceil() as u32;
+        fraction.max(self.min_damage)
+    }
+}
@@ -0,0 +1,49 @@
+//! Tests for channelled action components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::channel::*;
+
+    #[test]
+    fn test_damage_window_drops_old_samples() {
+        let mut channel = Channeling::new(ChannelKind::Reload, 120);
+
+        channel.record_damage(0, 10);
+        channel.record_damage(30, 5);
+        assert_eq!(channel.damage_in_window(30, 60), 15);
+
+        // Через 70 тиков первая запись (tick 0) вышла из окна
+        assert_eq!(channel.damage_in_window(70, 60), 5);
+        assert_eq!(channel.damage_taken.len(), 1);
+    }
+
+    #[test]
+    fn test_damage_window_counts_early_game_hits() {
+        let mut channel = Channeling::new(ChannelKind::Reload, 120);
+
+        // tick < window: удар на tick 0 ещё в окне
+        channel.record_damage(0, 10);
+        assert_eq!(channel.damage_in_window(5, 60), 10);
+        assert_eq!(channel.damage_in_window(59, 60), 10);
+        assert_eq!(channel.damage_in_window(60, 60), 0);
+    }
+
+    #[test]
+    fn test_interrupt_threshold() {
+        let rules = ChannelInterruptRules::default();
+
+        // 10% от 200 HP = 20
+        assert_eq!(rules.threshold(200), 20);
+        // Low-HP актор: min_damage
+        assert_eq!(rules.threshold(20), rules.min_damage);
+    }
+
+    #[test]
+    fn test_channel_completion() {
+        let channel = Channeling::new(ChannelKind::Consumable { slot_index: 1 }, 60).uninterruptible();
+
+        assert!(!channel.interruptible);
+        assert!(!channel.is_complete_at(59));
+        assert!(channel.is_complete_at(60));
+    }
+}
@@ -0,0 +1,95 @@
+//! Downed components (ранен при 0 HP вместо мгновенной смерти).
+//!
+//! Только для акторов с `CanBeDowned` (игрок, опционально союзные NPC):
+//! - HP = 0 → `Downed`: лежит (Stance::Prone), только ползком, ActionLock(Downed)
+//! - Союзник рядом → ReviveIntent → Channeling(Revive) → встаёт с долей HP
+//! - Таймер истёк (bleed-out) или добивание (Execution) → EntityDied → Dead
+
+use bevy::prelude::*;
+
+/// Дистанция поднятия союзника (метры)
+pub const REVIVE_RANGE: f32 = 2.0;
+
+/// Длительность поднятия (секунды, Channeling::Revive)
+pub const REVIVE_DURATION: f32 = 4.0;
+
+/// Доля max HP после поднятия
+pub const REVIVE_HEALTH_FRACTION: f32 = 0.3;
+
+/// Маркер: при 0 HP актор падает (Downed), а не умирает сразу.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct CanBeDowned {
+    /// Время до смерти от кровопотери (секунды)
+    pub bleed_out_secs: f32,
+}
+
+impl Default for CanBeDowned {
+    fn default() -> Self {
+        Self { bleed_out_secs: 30.0 }
+    }
+}
+
+/// Downed state.
+///
+/// Добавляется `down_actors_at_zero_health`, удаляется поднятием (`complete_revives`)
+/// или смертью (`update_downed_states` → EntityDied).
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Downed {
+    /// Оставшееся время до смерти (секунды)
+    pub remaining: f32,
+    /// Полное время bleed-out (секунды)
+    pub duration: f32,
+    /// Кто уронил (killer при bleed-out)
+    pub attacker: Option<Entity>,
+    /// Кто добил (Execution) — смерть на ближайшем тике
+    pub finished_by: Option<Entity>,
+}
+
+impl Downed {
+    pub fn new(duration: f32, attacker: Option<Entity>) -> Self {
+        Self {
+            remaining: duration,
+            duration,
+            attacker,
+            finished_by: None,
+        }
+    }
+
+    /// Отсчёт bleed-out таймера
+    pub fn tick(&mut self, delta: f32) {
+        self.remaining = (self.remaining - delta).max(0.0);
+    }
+
+    /// Истёк кровью
+    pub fn is_bled_out(&self) -> bool {
+        self.remaining <= 0.0
+    }
+
+    /// Пора умирать (истёк кровью или добит)
+    pub fn should_die(&self) -> bool {
+        self.finished_by.is_some() || self.is_bled_out()
+    }
+
+    /// Кому засчитать смерть: добивший, иначе уронивший
+    pub fn killer(&self) -> Option<Entity> {
+        self.finished_by.or(self.attacker)
+    }
+
+    /// Доля оставшегося времени 0.0 - 1.0 (HUD)
+    pub fn remaining_fraction(&self) -> f32 {
+        if self.duration <= 0.0 {
+            0.0
+        } else {
+            (self.remaining / self.duration).clamp(0.0, 1.0)
+        }
+    }
+}
+
+/// Актор поднимает союзника (пока идёт Channeling::Revive).
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Reviving {
+    pub target: Entity,
+}
@@ -0,0 +1,35 @@
+//! Tests for downed components.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::downed::*;
+
+    #[test]
+    fn test_downed_bleeds_out_after_duration() {
+        let attacker = Entity::from_raw(7);
+        let mut downed = Downed::new(2.0, Some(attacker));
+
+        downed.tick(1.5);
+        assert!(!downed.should_die());
+        assert!((downed.remaining_fraction() - 0.25).abs() < 1e-5);
+
+        downed.tick(1.0);
+        assert!(downed.is_bled_out());
+        assert!(downed.should_die());
+        assert_eq!(downed.killer(), Some(attacker));
+    }
+
+    #[test]
+    fn test_finisher_kills_immediately_and_takes_credit() {
+        let attacker = Entity::from_raw(7);
+        let finisher = Entity::from_raw(9);
+        let mut downed = Downed::new(30.0, Some(attacker));
+
+        downed.finished_by = Some(finisher);
+
+        assert!(!downed.is_bled_out());
+        assert!(downed.should_die());
+        assert_eq!(downed.killer(), Some(finisher));
+    }
+}
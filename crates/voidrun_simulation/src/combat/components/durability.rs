@@ -0,0 +1,30 @@
+//! Weapon durability components (износ, клин, ремонт).
+//!
+//! Прочность живёт в `EquippedItem::durability` (0.0-1.0) активного оружия:
+//! - выстрел (`WeaponFired`) → `WEAR_PER_SHOT`, попадание в ближнем бою → `WEAR_PER_HIT`
+//! - ниже `JAM_THRESHOLD` каждый выстрел может заклинить оружие (`jam_chance`)
+//! - клин (`EquippedItem::jammed`) блокирует стрельбу до `ClearJamIntent` (channel `ClearJam`)
+//! - ремкомплект (`ConsumableEffect::RepairWeapon`) возвращает прочность
+
+use crate::shared::ConditionTier;
+
+/// Износ за выстрел
+pub const WEAR_PER_SHOT: f32 = 0.004;
+/// Износ за попадание в ближнем бою
+pub const WEAR_PER_HIT: f32 = 0.006;
+/// Ниже этой прочности оружие может клинить (граница Worn/Damaged)
+pub const JAM_THRESHOLD: f32 = ConditionTier::WORN_THRESHOLD;
+/// Шанс клина на выстрел при нулевой прочности
+pub const MAX_JAM_CHANCE: f32 = 0.25;
+/// Сколько длится устранение клина (секунды)
+pub const CLEAR_JAM_DURATION: f32 = 1.2;
+
+/// Шанс клина на выстрел: 0 выше `JAM_THRESHOLD`, линейно до `MAX_JAM_CHANCE` при 0
+pub fn jam_chance(durability: f32) -> f32 {
+    if durability >= JAM_THRESHOLD {
+        return 0.0;
+    }
+
+    let wear = 1.0 - durability.max(0.0) / JAM_THRESHOLD;
+    MAX_JAM_CHANCE * wear
+}
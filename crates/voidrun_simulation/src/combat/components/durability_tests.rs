@@ -0,0 +1,22 @@
+//! Tests for weapon durability components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::durability::*;
+
+    #[test]
+    fn test_no_jams_above_threshold() {
+        assert_eq!(jam_chance(1.0), 0.0);
+        assert_eq!(jam_chance(JAM_THRESHOLD), 0.0);
+    }
+
+    #[test]
+    fn test_jam_chance_grows_as_durability_drops() {
+        let worn = jam_chance(JAM_THRESHOLD * 0.5);
+        let broken = jam_chance(0.0);
+
+        assert!(worn > 0.0);
+        assert!(broken > worn);
+        assert_eq!(broken, MAX_JAM_CHANCE);
+    }
+}
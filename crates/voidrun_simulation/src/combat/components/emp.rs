@@ -0,0 +1,59 @@
+//! EMP components (электромагнитный импульс).
+//!
+//! Импульс не ранит, а выводит из строя электронику в радиусе:
+//! - EnergyShield обнуляется, recharge заблокирован на время `EmpDisrupted`
+//! - Электронные акторы (`Electronic`: дроны, турели) дополнительно оглушены (StaggerState)
+//! - Godot гасит щит (shader `disrupted`) пока висит статус
+
+use bevy::prelude::*;
+
+/// EMP status component.
+///
+/// Добавляется `apply_emp_detonations`, удаляется `update_emp_disruptions` по истечении.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct EmpDisrupted {
+    /// Оставшееся время (секунды)
+    pub remaining: f32,
+    /// Полная длительность (секунды)
+    pub duration: f32,
+}
+
+impl EmpDisrupted {
+    pub fn new(duration: f32) -> Self {
+        Self {
+            remaining: duration,
+            duration,
+        }
+    }
+
+    /// Повторный импульс — берём максимум (не сокращает)
+    pub fn refresh(&mut self, duration: f32) {
+        if duration > self.remaining {
+            self.remaining = duration;
+            self.duration = duration;
+        }
+    }
+
+    /// Длительность на дистанции `distance` от эпицентра
+    ///
+    /// Полная до половины радиуса, дальше линейно до `MIN_FALLOFF` на краю; вне радиуса — 0.
+    pub fn duration_at(distance: f32, radius: f32, max_duration: f32) -> f32 {
+        if radius <= 0.0 || distance > radius {
+            return 0.0;
+        }
+
+        let edge = ((distance / radius - 0.5) * 2.0).clamp(0.0, 1.0);
+        max_duration * (1.0 - edge * (1.0 - Self::MIN_FALLOFF))
+    }
+
+    /// Доля длительности на краю радиуса
+    pub const MIN_FALLOFF: f32 = 0.5;
+}
+
+/// Маркер: актор — электроника (дрон, турель)
+///
+/// EMP оглушает такого актора (StaggerState на всю длительность импульса), а не только гасит щит.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct Electronic;
@@ -0,0 +1,30 @@
+//! Tests for EMP components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::emp::*;
+
+    #[test]
+    fn test_emp_duration_falloff() {
+        // До половины радиуса — полная длительность
+        assert_eq!(EmpDisrupted::duration_at(0.0, 8.0, 6.0), 6.0);
+        assert_eq!(EmpDisrupted::duration_at(4.0, 8.0, 6.0), 6.0);
+
+        // Край радиуса — MIN_FALLOFF, за радиусом — ничего
+        assert!((EmpDisrupted::duration_at(8.0, 8.0, 6.0) - 6.0 * EmpDisrupted::MIN_FALLOFF).abs() < 1e-6);
+        assert_eq!(EmpDisrupted::duration_at(8.5, 8.0, 6.0), 0.0);
+    }
+
+    #[test]
+    fn test_emp_refresh_keeps_longest() {
+        let mut disrupted = EmpDisrupted::new(4.0);
+        disrupted.remaining = 1.0;
+
+        disrupted.refresh(0.5);
+        assert_eq!(disrupted.remaining, 1.0);
+
+        disrupted.refresh(3.0);
+        assert_eq!(disrupted.remaining, 3.0);
+        assert_eq!(disrupted.duration, 3.0);
+    }
+}
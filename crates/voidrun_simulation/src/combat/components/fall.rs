@@ -0,0 +1,51 @@
+//! Fall damage components.
+//!
+//! Godot gravity (`apply_gravity_to_all_actors`) знает вертикальную скорость
+//! и шлёт `Landed { impact_speed }` при касании пола. ECS переводит скорость в урон:
+//! - `impact_speed ≤ safe_speed` → без урона (прыжок, ступеньки)
+//! - выше порога → урон линейно от превышения
+//! - `impact_speed ≥ hard_landing_speed` → жёсткое приземление (StaggerState)
+
+use bevy::prelude::*;
+
+/// Fall damage parameters (global resource).
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct FallDamageConfig {
+    /// Скорость удара без урона (м/с)
+    pub safe_speed: f32,
+    /// Урон за каждый м/с сверх `safe_speed`
+    pub damage_per_speed: f32,
+    /// Скорость удара для жёсткого приземления (м/с)
+    pub hard_landing_speed: f32,
+    /// Длительность stagger после жёсткого приземления (секунды)
+    pub stagger_duration: f32,
+}
+
+impl Default for FallDamageConfig {
+    fn default() -> Self {
+        Self {
+            safe_speed: 8.0,         // ~3.3м падения при g = 9.8
+            damage_per_speed: 12.0,
+            hard_landing_speed: 13.0, // ~8.6м падения
+            stagger_duration: 0.8,
+        }
+    }
+}
+
+impl FallDamageConfig {
+    /// Урон от приземления с указанной скоростью удара
+    pub fn damage_for(&self, impact_speed: f32) -> u32 {
+        let excess = impact_speed - self.safe_speed;
+        if excess <= 0.0 {
+            return 0;
+        }
+
+        (excess * self.damage_per_speed).round() as u32
+    }
+
+    /// Жёсткое приземление (сбивает с ног)
+    pub fn is_hard_landing(&self, impact_speed: f32) -> bool {
+        impact_speed >= self.hard_landing_speed
+    }
+}
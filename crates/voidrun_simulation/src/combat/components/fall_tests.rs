@@ -0,0 +1,34 @@
+//! Tests for fall damage components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::fall::*;
+
+    #[test]
+    fn test_fall_damage_below_threshold() {
+        let config = FallDamageConfig::default();
+
+        // Прыжок на месте / ступеньки — без урона
+        assert_eq!(config.damage_for(4.5), 0);
+        assert_eq!(config.damage_for(config.safe_speed), 0);
+    }
+
+    #[test]
+    fn test_fall_damage_scales_with_excess_speed() {
+        let config = FallDamageConfig::default();
+
+        let light = config.damage_for(config.safe_speed + 1.0);
+        let heavy = config.damage_for(config.safe_speed + 4.0);
+
+        assert_eq!(light, config.damage_per_speed.round() as u32);
+        assert!(heavy > light);
+    }
+
+    #[test]
+    fn test_hard_landing_threshold() {
+        let config = FallDamageConfig::default();
+
+        assert!(!config.is_hard_landing(config.hard_landing_speed - 0.1));
+        assert!(config.is_hard_landing(config.hard_landing_speed));
+    }
+}
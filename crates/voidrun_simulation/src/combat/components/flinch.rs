@@ -0,0 +1,143 @@
+//! Flinch (pain reaction) components.
+//!
+//! Реакция на полученный урон, масштабируется от величины удара:
+//! - Light flinch: короткая upper-body анимация, действия НЕ прерываются
+//! - Heavy flinch: mini-stagger, текущая атака/парирование прерываются
+//! - Knockdown: массивный удар/взрыв валит с ног (см. `KnockdownState`)
+
+use bevy::prelude::*;
+
+// ============================================================================
+// Flinch Config Component
+// ============================================================================
+
+/// Flinch parameters (per archetype).
+///
+/// Акторы без этого компонента не реагируют на урон (турели, боссы и т.п.).
+///
+/// # Thresholds
+/// Пороги задаются в процентах от `Health::max`:
+/// - `damage_percent < light_threshold` → без реакции
+/// - `light_threshold ≤ damage_percent < heavy_threshold` → Light flinch
+/// - `heavy_threshold ≤ damage_percent < knockdown_threshold` → Heavy flinch (interrupt)
+/// - `damage_percent ≥ knockdown_threshold` → Knockdown (падение + get-up)
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct FlinchConfig {
+    /// Минимальный урон для light flinch (0.0-1.0 от max HP)
+    pub light_threshold: f32,
+    /// Минимальный урон для heavy flinch (0.0-1.0 от max HP)
+    pub heavy_threshold: f32,
+    /// Длительность heavy flinch interrupt (секунды)
+    pub heavy_duration: f32,
+    /// Минимальный урон для knockdown (0.0-1.0 от max HP, > 1.0 = не падает)
+    pub knockdown_threshold: f32,
+    /// Сколько актор лежит (секунды, окно для добивания)
+    pub knockdown_duration: f32,
+    /// Длительность подъёма (секунды, get-up анимация)
+    pub getup_duration: f32,
+}
+
+impl Default for FlinchConfig {
+    fn default() -> Self {
+        Self {
+            light_threshold: 0.02, // 2% HP — любой заметный удар
+            heavy_threshold: 0.25, // 25% HP — тяжёлый удар
+            heavy_duration: 0.4,
+            knockdown_threshold: 0.5, // 50% HP за удар — валит с ног
+            knockdown_duration: 1.5,
+            getup_duration: 0.8,
+        }
+    }
+}
+
+impl FlinchConfig {
+    /// Heavy/armored archetype (почти не сбивается)
+    pub fn stoic() -> Self {
+        Self {
+            light_threshold: 0.1,
+            heavy_threshold: 0.5,
+            heavy_duration: 0.25,
+            knockdown_threshold: 0.9,
+            knockdown_duration: 1.0,
+            getup_duration: 0.6,
+        }
+    }
+
+    /// Light/skittish archetype (сбивается легко)
+    pub fn skittish() -> Self {
+        Self {
+            light_threshold: 0.0,
+            heavy_threshold: 0.15,
+            heavy_duration: 0.6,
+            knockdown_threshold: 0.4,
+            knockdown_duration: 2.0,
+            getup_duration: 1.0,
+        }
+    }
+
+    /// Классифицировать удар по величине урона.
+    ///
+    /// Returns `None` если урон ниже light порога (или max HP == 0).
+    pub fn classify(&self, damage: u32, max_health: u32) -> Option<FlinchKind> {
+        if damage == 0 || max_health == 0 {
+            return None;
+        }
+
+        let damage_percent = damage as f32 / max_health as f32;
+
+        if damage_percent >= self.knockdown_threshold {
+            Some(FlinchKind::Knockdown)
+        } else if damage_percent >= self.heavy_threshold {
+            Some(FlinchKind::Heavy)
+        } else if damage_percent >= self.light_threshold {
+            Some(FlinchKind::Light)
+        } else {
+            None
+        }
+    }
+}
+
+/// Flinch severity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum FlinchKind {
+    /// Upper-body flinch (анимация поверх текущего действия)
+    Light,
+    /// Mini-stagger (прерывает атаку/парирование)
+    Heavy,
+    /// Падение (KnockdownState: лежит → встаёт)
+    Knockdown,
+}
+
+// ============================================================================
+// Flinch State Component
+// ============================================================================
+
+/// Heavy flinch interrupt state.
+///
+/// Добавляется только при Heavy flinch (light flinch — чисто визуальный, через event).
+/// Пока компонент есть, AI не начинает новые атаки/парирования.
+/// Удаляется `update_flinch_states` когда timer истёк.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct FlinchState {
+    /// Time remaining (seconds)
+    pub timer: f32,
+    /// Кто нанёс удар
+    pub source: Entity,
+}
+
+impl FlinchState {
+    /// Create new heavy flinch state.
+    pub fn new(duration: f32, source: Entity) -> Self {
+        Self {
+            timer: duration,
+            source,
+        }
+    }
+
+    /// Check if still flinching.
+    pub fn is_flinching(&self) -> bool {
+        self.timer > 0.0
+    }
+}
@@ -0,0 +1,44 @@
+//! Tests for flinch components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::flinch::*;
+
+    #[test]
+    fn test_flinch_classify_thresholds() {
+        let config = FlinchConfig::default();
+
+        // 1 / 100 = 1% < 2% → без реакции
+        assert_eq!(config.classify(1, 100), None);
+        // 10 / 100 = 10% → light
+        assert_eq!(config.classify(10, 100), Some(FlinchKind::Light));
+        // 25 / 100 = 25% → heavy (граница включительно)
+        assert_eq!(config.classify(25, 100), Some(FlinchKind::Heavy));
+    }
+
+    #[test]
+    fn test_flinch_classify_zero() {
+        let config = FlinchConfig::skittish();
+
+        // Нулевой урон не вызывает flinch даже при light_threshold = 0
+        assert_eq!(config.classify(0, 100), None);
+        assert_eq!(config.classify(10, 0), None);
+    }
+
+    #[test]
+    fn test_flinch_stoic_archetype() {
+        let config = FlinchConfig::stoic();
+
+        // 25% — heavy для default, но light для stoic
+        assert_eq!(config.classify(25, 100), Some(FlinchKind::Light));
+        assert_eq!(config.classify(50, 100), Some(FlinchKind::Heavy));
+    }
+
+    #[test]
+    fn test_flinch_knockdown_threshold() {
+        // 50% — knockdown для default (граница включительно), ниже — heavy
+        assert_eq!(FlinchConfig::default().classify(50, 100), Some(FlinchKind::Knockdown));
+        assert_eq!(FlinchConfig::default().classify(49, 100), Some(FlinchKind::Heavy));
+        assert_eq!(FlinchConfig::skittish().classify(40, 100), Some(FlinchKind::Knockdown));
+    }
+}
@@ -0,0 +1,83 @@
+//! Melee intercept prediction (движущиеся цели).
+//!
+//! Атака по текущей дистанции → быстрая цель уходит за windup, и каждый замах мимо.
+//! Скорость актора — сглаженная по последним позициям (`ActorMotion`, сэмплы пишет Godot layer).
+//! Точка перехвата — позиция цели в момент удара (конец windup + середина active фазы):
+//! по ней AI проверяет досягаемость, ведёт движение и наводит удар.
+
+use bevy::prelude::*;
+use super::weapon::WeaponStats;
+
+/// Сглаживание скорости (0..1, доля нового сэмпла)
+pub const VELOCITY_SMOOTHING: f32 = 0.3;
+
+/// Допуск к attack_radius (NavigationAgent останавливается примерно на attack_radius)
+pub const REACH_TOLERANCE: f32 = 0.5;
+
+/// Движение актора по последним позициям (горизонтальная скорость, XZ).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ActorMotion {
+    /// Позиция последнего сэмпла
+    pub position: Vec3,
+    /// Сглаженная скорость (м/с, y = 0)
+    pub velocity: Vec3,
+}
+
+impl ActorMotion {
+    /// Первый сэмпл: скорость неизвестна → 0
+    pub fn at(position: Vec3) -> Self {
+        Self {
+            position,
+            velocity: Vec3::ZERO,
+        }
+    }
+
+    /// Новый сэмпл позиции через `delta` секунд (EMA скорости, вертикаль отбрасывается)
+    pub fn record(&mut self, position: Vec3, delta: f32) {
+        if delta <= 0.0 {
+            return;
+        }
+
+        let mut sample = (position - self.position) / delta;
+        sample.y = 0.0;
+
+        self.velocity = self.velocity.lerp(sample, VELOCITY_SMOOTHING);
+        self.position = position;
+    }
+}
+
+/// Время от решения атаковать до попадания (windup + половина active фазы).
+pub fn melee_time_to_hit(weapon: &WeaponStats) -> f32 {
+    weapon.windup_duration + weapon.attack_duration * 0.5
+}
+
+/// Позиция `position` через `seconds` при постоянной скорости.
+pub fn predict_position(position: Vec3, velocity: Vec3, seconds: f32) -> Vec3 {
+    position + velocity * seconds
+}
+
+/// Точка перехвата: где будет цель в момент удара (цель движения и наведения melee AI).
+pub fn melee_intercept_point(target_pos: Vec3, target_velocity: Vec3, weapon: &WeaponStats) -> Vec3 {
+    predict_position(target_pos, target_velocity, melee_time_to_hit(weapon))
+}
+
+/// Будет ли цель в досягаемости в момент удара (intercept check).
+///
+/// Учитывает движение обоих: атакующий тоже продолжает сближаться во время windup.
+pub fn target_in_reach_at_hit(
+    attacker_pos: Vec3,
+    attacker_velocity: Vec3,
+    target_pos: Vec3,
+    target_velocity: Vec3,
+    weapon: &WeaponStats,
+) -> bool {
+    let seconds = melee_time_to_hit(weapon);
+    let attacker_at_hit = predict_position(attacker_pos, attacker_velocity, seconds);
+    let target_at_hit = predict_position(target_pos, target_velocity, seconds);
+
+    let mut separation = target_at_hit - attacker_at_hit;
+    separation.y = 0.0;
+
+    separation.length() <= weapon.attack_radius + REACH_TOLERANCE
+}
@@ -0,0 +1,101 @@
+//! Tests for melee intercept prediction.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use crate::combat::WeaponStats;
+    use super::super::intercept::*;
+
+    #[test]
+    fn test_motion_first_sample_is_still() {
+        let motion = ActorMotion::at(Vec3::new(1.0, 0.0, 2.0));
+        assert_eq!(motion.velocity, Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_motion_converges_to_constant_velocity() {
+        let mut motion = ActorMotion::at(Vec3::ZERO);
+        let delta = 1.0 / 60.0;
+
+        // Бег по X 5 м/с, по Y прыжок — вертикаль не учитывается
+        for frame in 1..=120 {
+            let t = frame as f32 * delta;
+            motion.record(Vec3::new(5.0 * t, 2.0 * t, 0.0), delta);
+        }
+
+        assert!((motion.velocity.x - 5.0).abs() < 0.01, "velocity = {:?}", motion.velocity);
+        assert_eq!(motion.velocity.y, 0.0);
+        assert!(motion.velocity.z.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_motion_ignores_zero_delta() {
+        let mut motion = ActorMotion::at(Vec3::ZERO);
+        motion.record(Vec3::new(3.0, 0.0, 0.0), 0.0);
+
+        assert_eq!(motion, ActorMotion::at(Vec3::ZERO));
+    }
+
+    #[test]
+    fn test_intercept_point_leads_moving_target() {
+        let weapon = WeaponStats::melee_sword(); // windup 0.3 + active 0.3 / 2 = 0.45s
+        assert!((melee_time_to_hit(&weapon) - 0.45).abs() < f32::EPSILON);
+
+        let point = melee_intercept_point(Vec3::new(0.0, 0.0, 4.0), Vec3::new(4.0, 0.0, 0.0), &weapon);
+        assert!((point - Vec3::new(1.8, 0.0, 4.0)).length() < 1e-4, "point = {:?}", point);
+
+        // Стоящая цель — точка перехвата совпадает с позицией
+        let still = melee_intercept_point(Vec3::new(0.0, 0.0, 4.0), Vec3::ZERO, &weapon);
+        assert_eq!(still, Vec3::new(0.0, 0.0, 4.0));
+    }
+
+    #[test]
+    fn test_fleeing_target_out_of_reach_at_hit() {
+        let weapon = WeaponStats::melee_sword(); // reach 2.0 + 0.5 допуск
+
+        // Сейчас в досягаемости (2.0м), но убегает 6 м/с → через 0.45с уже 4.7м
+        assert!(!target_in_reach_at_hit(
+            Vec3::ZERO,
+            Vec3::ZERO,
+            Vec3::new(0.0, 0.0, 2.0),
+            Vec3::new(0.0, 0.0, 6.0),
+            &weapon,
+        ));
+
+        // Атакующий бежит следом с той же скоростью → дистанция не меняется
+        assert!(target_in_reach_at_hit(
+            Vec3::ZERO,
+            Vec3::new(0.0, 0.0, 6.0),
+            Vec3::new(0.0, 0.0, 2.0),
+            Vec3::new(0.0, 0.0, 6.0),
+            &weapon,
+        ));
+    }
+
+    #[test]
+    fn test_approaching_target_in_reach_at_hit() {
+        let weapon = WeaponStats::melee_sword();
+
+        // Сейчас 4м (вне досягаемости), но бежит навстречу 4 м/с → через 0.45с 2.2м
+        assert!(target_in_reach_at_hit(
+            Vec3::ZERO,
+            Vec3::ZERO,
+            Vec3::new(0.0, 0.0, 4.0),
+            Vec3::new(0.0, 0.0, -4.0),
+            &weapon,
+        ));
+    }
+
+    #[test]
+    fn test_reach_ignores_height_difference() {
+        let weapon = WeaponStats::melee_sword();
+
+        assert!(target_in_reach_at_hit(
+            Vec3::ZERO,
+            Vec3::ZERO,
+            Vec3::new(0.0, 3.0, 2.0),
+            Vec3::ZERO,
+            &weapon,
+        ));
+    }
+}
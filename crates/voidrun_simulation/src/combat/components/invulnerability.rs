@@ -0,0 +1,59 @@
+//! Invulnerability (damage immunity frames) components.
+//!
+//! Временная неуязвимость:
+//! - Spawn protection (анти spawn-camping в wave mode)
+//! - Post-stagger grace (анти stunlock chains)
+//!
+//! Tick-based (SimulationTick), не float timers — детерминизм.
+
+use bevy::prelude::*;
+
+/// Invulnerability window component.
+///
+/// Пока `tick < until_tick` — весь входящий урон игнорируется
+/// (process_melee_hits / process_projectile_hits / process_projectile_shield_hits).
+/// Удаляется `expire_invulnerability` когда окно истекло.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Invulnerable {
+    /// Tick на котором неуязвимость заканчивается (exclusive)
+    pub until_tick: u64,
+    /// Причина (для VFX/UI/логов)
+    pub reason: InvulnerabilityReason,
+}
+
+impl Invulnerable {
+    /// Активна ли неуязвимость на данном tick
+    pub fn is_active_at(&self, tick: u64) -> bool {
+        tick < self.until_tick
+    }
+}
+
+/// Причина неуязвимости.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum InvulnerabilityReason {
+    /// Защита после спавна
+    SpawnProtection,
+    /// Grace период после окончания stagger
+    StaggerRecovery,
+}
+
+/// Invulnerability windows config (resource).
+///
+/// 0.0 секунд = окно выключено.
+#[derive(Resource, Debug, Clone)]
+pub struct InvulnerabilityConfig {
+    /// Длительность spawn protection (секунды)
+    pub spawn_protection_secs: f32,
+    /// Grace после окончания stagger (секунды)
+    pub post_stagger_grace_secs: f32,
+}
+
+impl Default for InvulnerabilityConfig {
+    fn default() -> Self {
+        Self {
+            spawn_protection_secs: 3.0,
+            post_stagger_grace_secs: 0.5,
+        }
+    }
+}
@@ -0,0 +1,58 @@
+//! Knockdown components (падение от массивного удара/взрыва).
+//!
+//! Отдельный слой action state machine (сильнее Heavy flinch / Stagger):
+//! - **Down**: актор лежит — обычные melee удары не проходят, добивание (Execution) проходит
+//! - **GettingUp**: get-up анимация — уязвим как обычно, добивание уже невозможно
+//!
+//! Всё время knockdown действует ActionLock(Knockdown) — никаких действий.
+
+use bevy::prelude::*;
+
+/// Множитель урона добивания (Execution по лежащему, блок игнорируется)
+pub const EXECUTION_DAMAGE_MULTIPLIER: f32 = 2.5;
+
+/// Фаза knockdown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum KnockdownPhase {
+    /// Лежит на земле (окно для добивания)
+    Down,
+    /// Поднимается (get-up анимация)
+    GettingUp,
+}
+
+/// Knockdown state.
+///
+/// Добавляется `apply_flinch_on_damage` при FlinchKind::Knockdown.
+/// Down → GettingUp → удаляется (`update_knockdown_states`).
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct KnockdownState {
+    pub phase: KnockdownPhase,
+    /// Time remaining in current phase (seconds)
+    pub timer: f32,
+    /// Длительность подъёма (секунды, из FlinchConfig)
+    pub getup_duration: f32,
+    /// Кто сбил с ног
+    pub source: Entity,
+}
+
+impl KnockdownState {
+    pub fn new(down_duration: f32, getup_duration: f32, source: Entity) -> Self {
+        Self {
+            phase: KnockdownPhase::Down,
+            timer: down_duration,
+            getup_duration,
+            source,
+        }
+    }
+
+    /// Лежит на земле (не встаёт)
+    pub fn is_down(&self) -> bool {
+        self.phase == KnockdownPhase::Down
+    }
+
+    /// Проходит ли melee удар по актору (лежачего бьёт только Execution)
+    pub fn accepts_melee_hit(&self, is_execution: bool) -> bool {
+        !self.is_down() || is_execution
+    }
+}
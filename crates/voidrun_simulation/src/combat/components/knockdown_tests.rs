@@ -0,0 +1,25 @@
+//! Tests for knockdown components.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::knockdown::*;
+
+    #[test]
+    fn test_downed_actor_only_takes_executions() {
+        let knockdown = KnockdownState::new(1.5, 0.8, Entity::PLACEHOLDER);
+
+        assert!(knockdown.is_down());
+        assert!(!knockdown.accepts_melee_hit(false));
+        assert!(knockdown.accepts_melee_hit(true));
+    }
+
+    #[test]
+    fn test_getting_up_is_vulnerable() {
+        let mut knockdown = KnockdownState::new(1.5, 0.8, Entity::PLACEHOLDER);
+        knockdown.phase = KnockdownPhase::GettingUp;
+
+        assert!(!knockdown.is_down());
+        assert!(knockdown.accepts_melee_hit(false));
+    }
+}
@@ -24,17 +24,26 @@ pub struct MeleeAttackState {
     pub phase_timer: f32,
     /// Entities already hit during this attack (prevents multiple hits on same target)
     pub hit_entities: Vec<Entity>,
+    /// Tick начала атаки (кто начал позже — "defender" при размене ударами)
+    pub started_at_tick: u64,
+    /// Тип атаки (Execution — добивание лежачего)
+    pub attack_type: MeleeAttackType,
+    /// Сколько тел уже задето за замах (cleave: урон следующему × falloff^n)
+    pub struck_targets: u8,
 }
 
 impl MeleeAttackState {
     /// Create new attack state in Windup phase.
-    pub fn new_windup(windup_duration: f32) -> Self {
+    pub fn new_windup(windup_duration: f32, started_at_tick: u64) -> Self {
         Self {
             phase: AttackPhase::Windup {
                 duration: windup_duration,
             },
             phase_timer: windup_duration,
             hit_entities: Vec::new(),
+            started_at_tick,
+            attack_type: MeleeAttackType::Normal,
+            struck_targets: 0,
         }
     }
 
@@ -229,6 +238,25 @@ impl StaggerState {
     }
 }
 
+// ============================================================================
+// Trade Rule Resource
+// ============================================================================
+
+/// Правило размена: два актора попали друг в друга в один тик (A→B и B→A).
+///
+/// Без правила исход зависел от порядка событий в очереди (кто первый убил).
+/// Parried удары в размен не входят (parry резолвится отдельно).
+#[derive(Resource, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+pub enum MeleeTradeRule {
+    /// Оба удара проходят (double KO возможен)
+    #[default]
+    BothApply,
+    /// Проходит удар с большим base damage (равный damage → оба)
+    HigherDamageWins,
+    /// Проходит удар того, кто начал атаку позже (контратака; равный tick → оба)
+    DefenderPriority,
+}
+
 // ============================================================================
 // Parry Delay Timer Component
 // ============================================================================
@@ -271,4 +299,124 @@ pub enum MeleeAttackType {
     Heavy,
     /// Quick attack (fast, low damage) - TODO: future
     Quick,
+    /// Добивание лежачего (KnockdownState::Down) — единственный melee удар, который проходит
+    Execution,
+    /// Удар прикладом с ranged оружием в руках (QuickMeleeIntent): без смены оружия,
+    /// фиксированные тайминги, малый урон + poise (сбивает с ног сильнее урона)
+    Bash,
+    /// Толчок энергощитом (ShieldBashIntent): только при активном EnergyShield,
+    /// тратит энергию щита вместо stamina, короткий замах, большой poise
+    ShieldBash,
+}
+
+// ============================================================================
+// Cleave
+// ============================================================================
+
+/// Проход замаха сквозь толпу (per-weapon, WeaponStats.cleave)
+///
+/// Тела задеваются по очереди: первое — полный урон, n-е — урон × `falloff`^n,
+/// после `max_targets` замах увяз — остальные не получают ничего.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct MeleeCleave {
+    /// Сколько тел может задеть один замах
+    pub max_targets: u8,
+    /// Множитель урона за каждое уже задетое тело (0.0 - 1.0)
+    pub falloff: f32,
+}
+
+impl Default for MeleeCleave {
+    fn default() -> Self {
+        Self::single_target()
+    }
+}
+
+impl MeleeCleave {
+    pub fn new(max_targets: u8, falloff: f32) -> Self {
+        Self { max_targets, falloff }
+    }
+
+    /// Только первое задетое тело (колющее оружие)
+    pub fn single_target() -> Self {
+        Self::new(1, 0.0)
+    }
+
+    /// Урон `index`-го задетого тела (0 — первое). None — замах увяз, удар не проходит
+    pub fn damage_for(&self, index: u8, damage: u32) -> Option<u32> {
+        if index >= self.max_targets {
+            return None;
+        }
+        if damage == 0 || index == 0 {
+            return Some(damage);
+        }
+        let multiplier = self.falloff.clamp(0.0, 1.0).powi(index as i32);
+        Some(((damage as f32 * multiplier).round() as u32).max(1))
+    }
+}
+
+// ============================================================================
+// Quick Melee (Bash)
+// ============================================================================
+
+/// Урон удара прикладом
+pub const BASH_DAMAGE: u32 = 8;
+
+/// Poise урон удара прикладом (HP-эквивалент, добавляется к урону при классификации flinch)
+pub const BASH_POISE_DAMAGE: u32 = 30;
+
+/// Дальность удара прикладом (метры, конус перед атакующим)
+pub const BASH_RANGE: f32 = 1.6;
+
+// ============================================================================
+// Shield Bash
+// ============================================================================
+
+/// Урон толчка щитом
+pub const SHIELD_BASH_DAMAGE: u32 = 5;
+
+/// Poise урон толчка щитом (HP-эквивалент, обычно хватает на heavy flinch / падение)
+pub const SHIELD_BASH_POISE_DAMAGE: u32 = 60;
+
+/// Дальность толчка щитом (метры, широкий конус перед атакующим)
+pub const SHIELD_BASH_RANGE: f32 = 2.0;
+
+/// Цена толчка в энергии щита (вместо stamina)
+pub const SHIELD_BASH_ENERGY_COST: f32 = 60.0;
+
+/// Длительности фаз атаки
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeleeTimings {
+    pub windup: f32,
+    /// Начало active фазы, когда удар можно парировать (hitbox OFF)
+    pub parry_window: f32,
+    /// Вся active фаза (parry window + hitbox)
+    pub attack_duration: f32,
+    pub recovery: f32,
+}
+
+impl MeleeTimings {
+    /// Тайминги удара прикладом (не зависят от оружия)
+    pub const BASH: Self = Self { windup: 0.08, parry_window: 0.04, attack_duration: 0.12, recovery: 0.25 };
+
+    /// Тайминги толчка щитом (не зависят от оружия, замах короче удара прикладом)
+    pub const SHIELD_BASH: Self = Self { windup: 0.06, parry_window: 0.04, attack_duration: 0.14, recovery: 0.21 };
+
+    /// Тайминги атаки: Bash / ShieldBash — фиксированные, остальные — из WeaponStats
+    pub fn for_attack(attack_type: &MeleeAttackType, weapon: &super::weapon::WeaponStats) -> Self {
+        match attack_type {
+            MeleeAttackType::Bash => Self::BASH,
+            MeleeAttackType::ShieldBash => Self::SHIELD_BASH,
+            _ => Self {
+                windup: weapon.windup_duration,
+                parry_window: weapon.parry_window,
+                attack_duration: weapon.attack_duration,
+                recovery: weapon.recovery_duration,
+            },
+        }
+    }
+
+    /// Полная длительность атаки (секунды)
+    pub fn total(&self) -> f32 {
+        self.windup + self.attack_duration + self.recovery
+    }
 }
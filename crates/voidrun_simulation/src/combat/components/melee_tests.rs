@@ -0,0 +1,39 @@
+//! Tests for melee components (attack timings).
+
+#[cfg(test)]
+mod tests {
+    use super::super::melee::*;
+    use super::super::weapon::WeaponStats;
+
+    #[test]
+    fn test_timings_follow_weapon_for_regular_attacks() {
+        let sword = WeaponStats::melee_sword();
+        let timings = MeleeTimings::for_attack(&MeleeAttackType::Normal, &sword);
+
+        assert_eq!(timings.windup, sword.windup_duration);
+        assert_eq!(timings.parry_window, sword.parry_window);
+        assert_eq!(timings.attack_duration, sword.attack_duration);
+        assert_eq!(timings.recovery, sword.recovery_duration);
+    }
+
+    #[test]
+    fn test_bash_timings_ignore_weapon() {
+        // У пистолета нет melee таймингов — приклад всё равно бьёт
+        let pistol = WeaponStats::ranged_pistol();
+        let timings = MeleeTimings::for_attack(&MeleeAttackType::Bash, &pistol);
+
+        assert_eq!(timings, MeleeTimings::BASH);
+        assert!(timings.parry_window < timings.attack_duration);
+        assert!(timings.total() < MeleeTimings::for_attack(&MeleeAttackType::Normal, &WeaponStats::melee_sword()).total());
+    }
+
+    #[test]
+    fn test_shield_bash_windup_shorter_than_bash() {
+        // Толчок щитом — короткий замах, быстрее приклада
+        let pistol = WeaponStats::ranged_pistol();
+        let timings = MeleeTimings::for_attack(&MeleeAttackType::ShieldBash, &pistol);
+
+        assert_eq!(timings, MeleeTimings::SHIELD_BASH);
+        assert!(timings.windup < MeleeTimings::BASH.windup);
+    }
+}
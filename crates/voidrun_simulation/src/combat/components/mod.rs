@@ -3,12 +3,66 @@
 pub mod melee;
 pub mod weapon;
 pub mod stamina;
+pub mod flinch;
+pub mod invulnerability;
+pub mod suppression;
+pub mod aim;
+pub mod channel;
+pub mod action_lock;
+pub mod knockdown;
+pub mod smoke;
+pub mod blind;
+pub mod emp;
+pub mod fall;
+pub mod durability;
+pub mod downed;
+pub mod intercept;
 
 // Tests (separate files with _tests suffix)
 #[cfg(test)]
+mod melee_tests;
+#[cfg(test)]
 mod weapon_tests;
+#[cfg(test)]
+mod flinch_tests;
+#[cfg(test)]
+mod suppression_tests;
+#[cfg(test)]
+mod channel_tests;
+#[cfg(test)]
+mod action_lock_tests;
+#[cfg(test)]
+mod knockdown_tests;
+#[cfg(test)]
+mod smoke_tests;
+#[cfg(test)]
+mod blind_tests;
+#[cfg(test)]
+mod emp_tests;
+#[cfg(test)]
+mod fall_tests;
+#[cfg(test)]
+mod durability_tests;
+#[cfg(test)]
+mod downed_tests;
+#[cfg(test)]
+mod intercept_tests;
 
 // Re-export all components
 pub use melee::*;
 pub use weapon::*;
 pub use stamina::*;
+pub use flinch::*;
+pub use invulnerability::*;
+pub use suppression::*;
+pub use aim::*;
+pub use channel::*;
+pub use action_lock::*;
+pub use knockdown::*;
+pub use smoke::*;
+pub use blind::*;
+pub use emp::*;
+pub use fall::*;
+pub use durability::*;
+pub use downed::*;
+pub use intercept::*;
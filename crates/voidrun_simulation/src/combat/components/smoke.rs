@@ -0,0 +1,61 @@
+//! Smoke components (dynamic LOS occluders).
+
+use bevy::prelude::*;
+
+/// Дымовое облако (volume entity).
+///
+/// Пока активно — блокирует LOS:
+/// - Godot: StaticBody3D на слое VISION_BLOCKERS (LOS raycasts)
+/// - ECS: запись в `SmokeOcclusionMap` (strategic LOS, vision polling)
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct SmokeCloud {
+    /// Центр облака (world position)
+    pub center: Vec3,
+    /// Радиус (метры)
+    pub radius: f32,
+    /// Оставшееся время (секунды)
+    pub remaining: f32,
+}
+
+impl SmokeCloud {
+    pub fn new(center: Vec3, radius: f32, duration: f32) -> Self {
+        Self {
+            center,
+            radius,
+            remaining: duration,
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.remaining <= 0.0
+    }
+}
+
+/// Strategic occlusion map: активные облака дыма (пересобирается каждый тик)
+#[derive(Resource, Debug, Default)]
+pub struct SmokeOcclusionMap {
+    /// (центр, радиус) активных облаков
+    pub occluders: Vec<(Vec3, f32)>,
+}
+
+impl SmokeOcclusionMap {
+    /// Проходит ли отрезок from → to через дым (включая случай "внутри облака")
+    pub fn blocks_line_of_sight(&self, from: Vec3, to: Vec3) -> bool {
+        self.occluders
+            .iter()
+            .any(|&(center, radius)| segment_distance_to_point(from, to, center) <= radius)
+    }
+}
+
+/// Расстояние от точки до отрезка
+fn segment_distance_to_point(from: Vec3, to: Vec3, point: Vec3) -> f32 {
+    let segment = to - from;
+    let length_sq = segment.length_squared();
+    if length_sq <= f32::EPSILON {
+        return from.distance(point);
+    }
+
+    let t = ((point - from).dot(segment) / length_sq).clamp(0.0, 1.0);
+    (from + segment * t).distance(point)
+}
@@ -0,0 +1,46 @@
+//! Tests for smoke components.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::Vec3;
+    use super::super::smoke::*;
+
+    fn map_with_cloud(center: Vec3, radius: f32) -> SmokeOcclusionMap {
+        SmokeOcclusionMap {
+            occluders: vec![(center, radius)],
+        }
+    }
+
+    #[test]
+    fn test_smoke_blocks_line_through_cloud() {
+        let map = map_with_cloud(Vec3::new(5.0, 1.0, 0.0), 2.0);
+
+        assert!(map.blocks_line_of_sight(Vec3::new(0.0, 1.0, 0.0), Vec3::new(10.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_smoke_does_not_block_line_beside_cloud() {
+        let map = map_with_cloud(Vec3::new(5.0, 1.0, 0.0), 2.0);
+
+        // Линия проходит в 3м от центра
+        assert!(!map.blocks_line_of_sight(Vec3::new(0.0, 1.0, 3.0), Vec3::new(10.0, 1.0, 3.0)));
+        // Облако позади цели — не мешает
+        assert!(!map.blocks_line_of_sight(Vec3::new(0.0, 1.0, 0.0), Vec3::new(2.0, 1.0, 0.0)));
+    }
+
+    #[test]
+    fn test_smoke_observer_inside_cloud_is_blind() {
+        let map = map_with_cloud(Vec3::ZERO, 3.0);
+
+        assert!(map.blocks_line_of_sight(Vec3::new(1.0, 0.0, 0.0), Vec3::new(20.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_smoke_cloud_expiry() {
+        let mut cloud = SmokeCloud::new(Vec3::ZERO, 4.0, 0.5);
+        assert!(!cloud.is_expired());
+
+        cloud.remaining -= 0.5;
+        assert!(cloud.is_expired());
+    }
+}
@@ -13,17 +13,44 @@ pub const DODGE_COST: f32 = 25.0; // Для будущего
 /// - Медленнее движение
 /// - Меньше урона
 /// - Дольше regen
+///
+/// Спринт до 0 stamina → Exhausted с recovery delay: до `recover_at_tick`
+/// stamina не восстанавливается и спринт заблокирован.
 #[derive(Component, Debug, Clone, Copy, Reflect)]
 #[reflect(Component)]
 pub struct Exhausted {
     /// Movement speed multiplier (0.5 = half speed)
     pub movement_penalty: f32,
+    /// Тик, до которого regen стоит (0 = без задержки)
+    pub recover_at_tick: u64,
+}
+
+impl Exhausted {
+    /// Задержка восстановления после спринта до 0 stamina (секунды)
+    pub const SPRINT_RECOVERY_DELAY: f32 = 2.0;
+
+    /// Задержка восстановления после задержки дыхания до 0 stamina (секунды)
+    pub const BREATH_RECOVERY_DELAY: f32 = 3.0;
+
+    /// Exhausted от спринта / задержки дыхания: regen стоит до `recover_at_tick`
+    pub fn with_recovery_delay(recover_at_tick: u64) -> Self {
+        Self {
+            recover_at_tick,
+            ..Self::default()
+        }
+    }
+
+    /// Ещё идёт recovery delay (regen стоит)
+    pub fn is_recovering(&self, current_tick: u64) -> bool {
+        current_tick < self.recover_at_tick
+    }
 }
 
 impl Default for Exhausted {
     fn default() -> Self {
         Self {
             movement_penalty: 0.7, // 30% slower
+            recover_at_tick: 0,
         }
     }
 }
@@ -0,0 +1,79 @@
+//! Suppression components.
+//!
+//! Пули, пролетающие рядом (near-miss), подавляют актора:
+//! - Точность AI падает (больше разброс)
+//! - Fire rate падает (длиннее cooldown)
+//! - При сильном подавлении FSM предпочитает Retreat
+//!
+//! Подавление стакается от плотного огня и спадает со временем.
+
+use bevy::prelude::*;
+
+/// Suppression state component.
+///
+/// Добавляется `apply_suppression_from_near_misses`, удаляется `decay_suppression`
+/// когда `level` спал до 0.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Suppressed {
+    /// Уровень подавления (0.0-1.0)
+    pub level: f32,
+    /// Время до начала decay (секунды, сбрасывается каждым near-miss)
+    pub decay_delay_timer: f32,
+}
+
+impl Suppressed {
+    /// Прирост уровня за один near-miss (на расстоянии 0м)
+    pub const STACK_PER_NEAR_MISS: f32 = 0.25;
+    /// Задержка перед decay (секунды)
+    pub const DECAY_DELAY: f32 = 1.0;
+    /// Скорость decay (уровень/сек)
+    pub const DECAY_RATE: f32 = 0.3;
+    /// Порог "прижат огнём" (FSM → Retreat)
+    pub const PINNED_THRESHOLD: f32 = 0.7;
+    /// Максимальный дополнительный разброс AI при level = 1.0 (градусы)
+    pub const MAX_EXTRA_SPREAD_DEGREES: f32 = 8.0;
+
+    /// Создать подавление с начальным уровнем
+    pub fn new(level: f32) -> Self {
+        Self {
+            level: level.clamp(0.0, 1.0),
+            decay_delay_timer: Self::DECAY_DELAY,
+        }
+    }
+
+    /// Добавить стак подавления (near-miss)
+    ///
+    /// `proximity` (0.0-1.0): 1.0 = пуля прошла вплотную, 0.0 = на границе радиуса.
+    pub fn add_stack(&mut self, proximity: f32) {
+        let amount = Self::STACK_PER_NEAR_MISS * proximity.clamp(0.0, 1.0);
+        self.level = (self.level + amount).min(1.0);
+        self.decay_delay_timer = Self::DECAY_DELAY;
+    }
+
+    /// Tick decay. Returns `true` если подавление полностью спало.
+    pub fn decay(&mut self, delta: f32) -> bool {
+        if self.decay_delay_timer > 0.0 {
+            self.decay_delay_timer -= delta;
+            return false;
+        }
+
+        self.level = (self.level - Self::DECAY_RATE * delta).max(0.0);
+        self.level <= 0.0
+    }
+
+    /// Прижат огнём (FSM предпочитает Retreat)
+    pub fn is_pinned(&self) -> bool {
+        self.level >= Self::PINNED_THRESHOLD
+    }
+
+    /// Множитель cooldown оружия (1.0 → 2.0 при полном подавлении)
+    pub fn cooldown_multiplier(&self) -> f32 {
+        1.0 + self.level
+    }
+
+    /// Дополнительный разброс AI (градусы)
+    pub fn extra_spread_degrees(&self) -> f32 {
+        self.level * Self::MAX_EXTRA_SPREAD_DEGREES
+    }
+}
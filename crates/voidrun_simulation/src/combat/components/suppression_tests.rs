@@ -0,0 +1,51 @@
+//! Tests for suppression component.
+
+#[cfg(test)]
+mod tests {
+    use super::super::suppression::*;
+
+    #[test]
+    fn test_suppression_stacking() {
+        let mut suppressed = Suppressed::new(0.0);
+
+        // 3 near-miss вплотную → 0.75 (pinned)
+        suppressed.add_stack(1.0);
+        suppressed.add_stack(1.0);
+        assert!(!suppressed.is_pinned());
+        suppressed.add_stack(1.0);
+        assert!(suppressed.is_pinned());
+
+        // Clamp к 1.0
+        for _ in 0..10 {
+            suppressed.add_stack(1.0);
+        }
+        assert_eq!(suppressed.level, 1.0);
+        assert_eq!(suppressed.cooldown_multiplier(), 2.0);
+    }
+
+    #[test]
+    fn test_suppression_decay() {
+        let mut suppressed = Suppressed::new(0.6);
+
+        // Decay delay: уровень не меняется первую секунду
+        assert!(!suppressed.decay(0.5));
+        assert_eq!(suppressed.level, 0.6);
+        assert!(!suppressed.decay(0.5));
+
+        // 1 сек decay × 0.3/сек → 0.3
+        assert!(!suppressed.decay(1.0));
+        assert!((suppressed.level - 0.3).abs() < 0.001);
+
+        // Полностью спадает
+        assert!(suppressed.decay(2.0));
+        assert_eq!(suppressed.level, 0.0);
+    }
+
+    #[test]
+    fn test_near_miss_resets_decay_delay() {
+        let mut suppressed = Suppressed::new(0.5);
+        suppressed.decay(1.0); // delay истёк
+        suppressed.add_stack(0.0); // near-miss на границе радиуса: +0, но delay сброшен
+        assert_eq!(suppressed.decay_delay_timer, Suppressed::DECAY_DELAY);
+    }
+}
@@ -17,6 +17,7 @@
 
 use bevy::prelude::*;
 use crate::Attachment;
+use super::melee::MeleeCleave;
 
 /// Weapon stats component (melee + ranged)
 ///
@@ -85,6 +86,504 @@ pub struct WeaponStats {
 
     /// Радиус слышимости выстрела (метры)
     pub hearing_range: f32,
+
+    // === Energy weapons ===
+    /// Нагрев (`WeaponHeat::none()` — оружие не греется)
+    pub heat: WeaponHeat,
+
+    /// Баллистика пули: пробитие / рикошет / падение / сопротивление воздуха
+    pub ballistics: ProjectileBallistics,
+
+    /// Падение урона с дистанцией (`DamageFalloff::none()` — урон постоянный)
+    pub falloff: DamageFalloff,
+
+    /// Режим огня: одиночный / очередь / автомат / дробь
+    pub fire_mode: FireMode,
+
+    /// Отдача: подброс камеры и bloom разброса за выстрел (`RecoilPattern::none()` — без отдачи)
+    pub recoil: RecoilPattern,
+
+    /// Прицел (ADS): зум камеры, время вскидки, чувствительность мыши
+    pub ads: AdsConfig,
+
+    /// Cleave: сколько тел задевает замах (удар прикладом у ranged) и падение урона по толпе
+    pub cleave: MeleeCleave,
+}
+
+/// Модель нагрева энергетического оружия
+///
+/// Выстрел добавляет `heat_per_shot`, остывание — `cooling_rate` в секунду.
+/// Дошло до 1.0 → перегрев: стрелять нельзя `overheat_lockout` секунд.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
+pub struct WeaponHeat {
+    /// Нагрев за выстрел (доля шкалы, 0.0 = не греется)
+    pub heat_per_shot: f32,
+    /// Остывание (доля шкалы в секунду)
+    pub cooling_rate: f32,
+    /// Блокировка огня после перегрева (секунды)
+    pub overheat_lockout: f32,
+    /// Текущий нагрев (0.0 - 1.0)
+    pub current: f32,
+    /// Оставшаяся блокировка перегрева (секунды, 0 = не перегрето)
+    pub lockout_timer: f32,
+}
+
+impl WeaponHeat {
+    /// Оружие без нагрева (кинетическое / melee)
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    /// Энергетическое оружие
+    pub fn energy(heat_per_shot: f32, cooling_rate: f32, overheat_lockout: f32) -> Self {
+        Self { heat_per_shot, cooling_rate, overheat_lockout, current: 0.0, lockout_timer: 0.0 }
+    }
+
+    /// Греется ли оружие вообще
+    pub fn is_enabled(&self) -> bool {
+        self.heat_per_shot > 0.0
+    }
+
+    /// Перегрето (огонь заблокирован)
+    pub fn is_overheated(&self) -> bool {
+        self.lockout_timer > 0.0
+    }
+
+    /// Учесть выстрел. true — этот выстрел перегрел оружие
+    pub fn add_shot(&mut self) -> bool {
+        if !self.is_enabled() || self.is_overheated() {
+            return false;
+        }
+
+        self.current = (self.current + self.heat_per_shot).min(1.0);
+        if self.current >= 1.0 {
+            self.lockout_timer = self.overheat_lockout;
+            return true;
+        }
+        false
+    }
+
+    /// Остывание за `delta` секунд (во время блокировки тоже остывает)
+    pub fn cool(&mut self, delta: f32) {
+        self.current = (self.current - self.cooling_rate * delta).max(0.0);
+        self.lockout_timer = (self.lockout_timer - delta).max(0.0);
+    }
+}
+
+/// Баллистика пули: пробитие, рикошет, падение, сопротивление воздуха
+///
+/// - Полёт: скорость гаснет по `drag` (экспоненциально), `gravity` тянет вниз (Godot physics_process)
+/// - Пробитие: сопротивление препятствия (тело / конечность / слабый материал) меньше
+///   оставшейся мощности → пуля проходит насквозь, мощность уменьшается, урон × PENETRATION_DAMAGE_FACTOR
+/// - Рикошет: угол к поверхности стены ≤ `ricochet_angle` → отражение с детерминированным разбросом
+#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
+pub struct ProjectileBallistics {
+    /// Мощность пробития (0.0 = не пробивает; тело актора ≈ 1.0)
+    pub penetration: f32,
+    /// Максимальный угол к поверхности для рикошета (градусы, 0.0 = без рикошета)
+    pub ricochet_angle: f32,
+    /// Ускорение падения пули (м/с², 0.0 = летит прямо)
+    pub gravity: f32,
+    /// Сопротивление воздуха (доля скорости в секунду, экспоненциально; 0.0 = без потерь)
+    pub drag: f32,
+}
+
+impl ProjectileBallistics {
+    /// Множитель урона после пробития
+    pub const PENETRATION_DAMAGE_FACTOR: f32 = 0.6;
+    /// Множитель урона после рикошета
+    pub const RICOCHET_DAMAGE_FACTOR: f32 = 0.5;
+    /// Максимум рикошетов одной пули
+    pub const MAX_RICOCHETS: u8 = 2;
+    /// Разброс направления рикошета (± градусы)
+    pub const RICOCHET_SCATTER_DEG: f32 = 8.0;
+
+    /// Пуля без пробития и рикошета (плазма, melee)
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn new(penetration: f32, ricochet_angle: f32) -> Self {
+        Self { penetration, ricochet_angle, ..Self::default() }
+    }
+
+    /// Падение пули + сопротивление воздуха
+    pub fn with_drop(self, gravity: f32, drag: f32) -> Self {
+        Self { gravity, drag, ..self }
+    }
+
+    /// Скорость пули через `delta` секунд полёта (drag, затем gravity)
+    pub fn step_velocity(&self, velocity: Vec3, delta: f32) -> Vec3 {
+        velocity * (-self.drag * delta).exp() + Vec3::NEG_Y * self.gravity * delta
+    }
+
+    /// Пробить препятствие: оставшаяся мощность после него (None — пуля застряла)
+    pub fn penetrate(remaining: f32, resistance: f32) -> Option<f32> {
+        (remaining > resistance).then_some(remaining - resistance)
+    }
+
+    /// Урон после пробития / рикошета (`factor` — PENETRATION / RICOCHET_DAMAGE_FACTOR)
+    pub fn reduced_damage(damage: u32, factor: f32) -> u32 {
+        (damage as f32 * factor).round() as u32
+    }
+
+    /// Угол между полётом и поверхностью (градусы, 0 = вскользь, 90 = в лоб)
+    pub fn grazing_angle(direction: Vec3, normal: Vec3) -> f32 {
+        direction.normalize_or_zero().dot(normal.normalize_or_zero()).abs().clamp(0.0, 1.0).asin().to_degrees()
+    }
+
+    /// Рикошетит ли пуля от стены (учитывая уже сделанные рикошеты)
+    pub fn should_ricochet(&self, direction: Vec3, normal: Vec3, ricochets: u8) -> bool {
+        self.ricochet_angle > 0.0
+            && ricochets < Self::MAX_RICOCHETS
+            && normal != Vec3::ZERO
+            && Self::grazing_angle(direction, normal) <= self.ricochet_angle
+    }
+
+    /// Направление после рикошета: отражение + разброс, детерминированный по `seed`
+    ///
+    /// Разброс — поворот в плоскости отражения; уход под поверхность → чистое отражение.
+    pub fn ricochet_direction(direction: Vec3, normal: Vec3, seed: u64) -> Vec3 {
+        let normal = normal.normalize_or_zero();
+        let reflected = direction.normalize_or_zero().reflect(normal);
+
+        let tangent = normal.cross(reflected).normalize_or_zero();
+        if tangent == Vec3::ZERO {
+            return reflected;
+        }
+
+        let scatter = (unit_from_seed(seed) * 2.0 - 1.0) * Self::RICOCHET_SCATTER_DEG.to_radians();
+        let scattered = Quat::from_axis_angle(tangent, scatter) * reflected;
+
+        if scattered.dot(normal) > 0.0 { scattered.normalize() } else { reflected }
+    }
+}
+
+/// Падение урона с дистанцией полёта пули (process_projectile_hits)
+///
+/// До `start` — полный урон, к `end` линейно падает до `min_multiplier`, дальше не меняется.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct DamageFalloff {
+    /// Дистанция начала падения (метры)
+    pub start: f32,
+    /// Дистанция минимального урона (метры)
+    pub end: f32,
+    /// Множитель урона после `end` (0.0 - 1.0)
+    pub min_multiplier: f32,
+}
+
+impl Default for DamageFalloff {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl DamageFalloff {
+    /// Урон не зависит от дистанции
+    pub fn none() -> Self {
+        Self { start: f32::INFINITY, end: f32::INFINITY, min_multiplier: 1.0 }
+    }
+
+    pub fn new(start: f32, end: f32, min_multiplier: f32) -> Self {
+        Self { start, end, min_multiplier }
+    }
+
+    /// Множитель урона на дистанции `distance`
+    pub fn multiplier(&self, distance: f32) -> f32 {
+        if distance <= self.start {
+            return 1.0;
+        }
+        if distance >= self.end || self.end <= self.start {
+            return self.min_multiplier;
+        }
+
+        let t = (distance - self.start) / (self.end - self.start);
+        1.0 + (self.min_multiplier - 1.0) * t
+    }
+
+    /// Урон с учётом дистанции (не меньше 1 для ненулевого урона)
+    pub fn apply(&self, damage: u32, distance: f32) -> u32 {
+        if damage == 0 {
+            return 0;
+        }
+        ((damage as f32 * self.multiplier(distance)).round() as u32).max(1)
+    }
+}
+
+/// Режим огня ranged оружия
+///
+/// - Single / Shotgun — один WeaponFireIntent на нажатие
+/// - Burst / Auto — очередь / огонь, пока спуск зажат
+///
+/// Player: любое нажатие → FiringState, `continue_firing` шлёт intent'ы сам.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
+pub enum FireMode {
+    /// Один выстрел на нажатие
+    #[default]
+    Single,
+    /// Очередь `shots` выстрелов с интервалом `interval` (секунды); cooldown — между очередями
+    Burst { shots: u8, interval: f32 },
+    /// Огонь, пока спуск зажат (темп — attack_cooldown)
+    Auto,
+    /// Выстрел = `pellets` дробин в конусе `spread_degrees` (урон base_damage — на дробину)
+    Shotgun { pellets: u8, spread_degrees: f32 },
+}
+
+impl FireMode {
+    /// Projectiles на один выстрел
+    pub fn pellets(&self) -> u8 {
+        match self {
+            FireMode::Shotgun { pellets, .. } => (*pellets).max(1),
+            _ => 1,
+        }
+    }
+
+    /// Стрельба с зажатым спуском (очередь / автомат)
+    pub fn holds_trigger(&self) -> bool {
+        matches!(self, FireMode::Burst { .. } | FireMode::Auto)
+    }
+
+    /// Выстрелов на одно нажатие (Auto — 0: темп задаёт cooldown, пока спуск зажат)
+    pub fn shots_per_pull(&self) -> u8 {
+        match self {
+            FireMode::Single | FireMode::Shotgun { .. } => 1,
+            FireMode::Burst { shots, .. } => *shots,
+            FireMode::Auto => 0,
+        }
+    }
+
+    /// Интервал между выстрелами очереди (секунды, 0 — не очередь)
+    pub fn burst_interval(&self) -> f32 {
+        match self {
+            FireMode::Burst { interval, .. } => *interval,
+            _ => 0.0,
+        }
+    }
+
+    /// Отклонение дробины `index`: (угол от оси, азимут) в радианах
+    ///
+    /// Детерминированно от seed выстрела — тот же выстрел в реплее даёт тот же разлёт.
+    /// Не Shotgun → (0, 0).
+    pub fn pellet_offset(&self, seed: u64, index: u8) -> (f32, f32) {
+        let FireMode::Shotgun { spread_degrees, .. } = self else {
+            return (0.0, 0.0);
+        };
+
+        let pellet_seed = seed ^ (index as u64 + 1).wrapping_mul(0xD1B5_4A32_D192_ED03);
+        // sqrt — равномерно по площади конуса (без сгустка в центре)
+        let deviation = unit_from_seed(pellet_seed).sqrt() * spread_degrees.to_radians();
+        let azimuth = unit_from_seed(pellet_seed.wrapping_add(1)) * std::f32::consts::TAU;
+        (deviation, azimuth)
+    }
+}
+
+/// Отдача оружия (per-weapon паттерн)
+///
+/// - Камера: каждый выстрел подбрасывает прицел на `kick_pitch` вверх и уводит вбок по
+///   повторяемому паттерну (`kick_yaw` × sin от номера выстрела серии) — очередь можно выучить
+/// - Bloom: выстрел добавляет `bloom_per_shot` градусов разброса (до `max_bloom`),
+///   остывает со скоростью `recovery_rate` градусов в секунду (после паузы `RecoilState::RECOVERY_DELAY`)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Reflect)]
+pub struct RecoilPattern {
+    /// Подброс камеры вверх за выстрел (градусы)
+    pub kick_pitch: f32,
+    /// Амплитуда увода вбок (градусы)
+    pub kick_yaw: f32,
+    /// Разброс за выстрел (градусы)
+    pub bloom_per_shot: f32,
+    /// Потолок накопленного разброса (градусы)
+    pub max_bloom: f32,
+    /// Восстановление разброса (градусы в секунду)
+    pub recovery_rate: f32,
+}
+
+impl RecoilPattern {
+    /// Шаг паттерна увода вбок (радианы sin на выстрел серии)
+    const YAW_PATTERN_STEP: f32 = 1.3;
+
+    /// Без отдачи (melee)
+    pub fn none() -> Self {
+        Self::default()
+    }
+
+    pub fn new(kick_pitch: f32, kick_yaw: f32, bloom_per_shot: f32, max_bloom: f32, recovery_rate: f32) -> Self {
+        Self { kick_pitch, kick_yaw, bloom_per_shot, max_bloom, recovery_rate }
+    }
+
+    /// Подброс камеры выстрела `shot_index` серии: (pitch вверх, yaw вправо) в градусах
+    pub fn kick(&self, shot_index: u32) -> (f32, f32) {
+        let yaw = self.kick_yaw * (shot_index as f32 * Self::YAW_PATTERN_STEP).sin();
+        (self.kick_pitch, yaw)
+    }
+}
+
+/// Прицел оружия в ADS (player)
+///
+/// - FOV камеры: `HIP_FIRE_FOV` от бедра → `fov` в ADS (лерп по `AimMode::ads_weight`)
+/// - Время вскидки: длительность Hip ↔ ADS перехода
+/// - Чувствительность мыши × `sensitivity_multiplier` в ADS (сильный зум — медленнее поворот)
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct AdsConfig {
+    /// FOV камеры в ADS (градусы)
+    pub fov: f32,
+    /// Длительность перехода Hip ↔ ADS (секунды)
+    pub transition_time: f32,
+    /// Множитель чувствительности мыши в ADS
+    pub sensitivity_multiplier: f32,
+}
+
+impl Default for AdsConfig {
+    fn default() -> Self {
+        Self::iron_sights()
+    }
+}
+
+impl AdsConfig {
+    /// FOV камеры от бедра (градусы)
+    pub const HIP_FIRE_FOV: f32 = 90.0;
+
+    /// Открытый прицел (лёгкий зум)
+    pub fn iron_sights() -> Self {
+        Self { fov: 70.0, transition_time: 0.3, sensitivity_multiplier: 0.8 }
+    }
+
+    /// Оптика: сильный зум, медленная вскидка, точная мышь (снайперская — fov ~15-20°)
+    pub fn scope(fov: f32) -> Self {
+        let zoom = Self::HIP_FIRE_FOV / fov.max(1.0);
+        Self {
+            fov,
+            transition_time: 0.45,
+            sensitivity_multiplier: (1.0 / zoom).clamp(0.1, 1.0),
+        }
+    }
+
+    /// FOV камеры при `ads_weight` (0.0 = от бедра, 1.0 = ADS)
+    pub fn fov_at(&self, ads_weight: f32) -> f32 {
+        let weight = ads_weight.clamp(0.0, 1.0);
+        Self::HIP_FIRE_FOV + (self.fov - Self::HIP_FIRE_FOV) * weight
+    }
+
+    /// Множитель чувствительности мыши при `ads_weight`
+    pub fn sensitivity_at(&self, ads_weight: f32) -> f32 {
+        let weight = ads_weight.clamp(0.0, 1.0);
+        1.0 + (self.sensitivity_multiplier - 1.0) * weight
+    }
+}
+
+/// Накопленная отдача стрелка (player)
+///
+/// Обновляет `update_recoil` (WeaponFired → выстрел, каждый tick — восстановление).
+/// Godot забирает подброс камеры (`take_kick`) и добавляет `bloom` к разбросу выстрела.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct RecoilState {
+    /// Накопленный разброс (градусы)
+    pub bloom: f32,
+    /// Номер выстрела в текущей серии (паттерн увода)
+    pub shot_index: u32,
+    /// Подброс камеры, ещё не применённый Godot (pitch, yaw в градусах)
+    pub pending_kick: Vec2,
+    /// Секунд с последнего выстрела
+    pub since_shot: f32,
+}
+
+impl RecoilState {
+    /// Пауза после выстрела до начала восстановления (секунды) — очередь копит разброс
+    pub const RECOVERY_DELAY: f32 = 0.3;
+
+    /// Выстрел: подброс камеры + bloom, следующий номер серии
+    pub fn add_shot(&mut self, pattern: &RecoilPattern) {
+        let (pitch, yaw) = pattern.kick(self.shot_index);
+        self.pending_kick += Vec2::new(pitch, yaw);
+        self.bloom = (self.bloom + pattern.bloom_per_shot).min(pattern.max_bloom);
+        self.shot_index += 1;
+        self.since_shot = 0.0;
+    }
+
+    /// Восстановление разброса (после RECOVERY_DELAY); разброс ушёл в 0 → серия сначала
+    pub fn recover(&mut self, pattern: &RecoilPattern, delta: f32) {
+        self.since_shot += delta;
+        if self.since_shot < Self::RECOVERY_DELAY {
+            return;
+        }
+        self.bloom = (self.bloom - pattern.recovery_rate * delta).max(0.0);
+        if self.bloom <= 0.0 {
+            self.shot_index = 0;
+        }
+    }
+
+    /// Забрать подброс камеры (Godot применяет и обнуляет)
+    pub fn take_kick(&mut self) -> Vec2 {
+        std::mem::take(&mut self.pending_kick)
+    }
+}
+
+/// Состояние спуска: очередь выстрелов на нажатие / огонь с зажатым спуском
+///
+/// Вставляется `apply_trigger_intents` (player, все режимы) / `ai_weapon_fire_intent` (AI очередь),
+/// снимается `continue_firing`, когда спуск отпущен и очередь отстреляна.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct FiringState {
+    /// Спуск зажат (Auto стреляет, пока true)
+    pub trigger_held: bool,
+    /// Осталось выстрелов очереди
+    pub burst_remaining: u8,
+    /// До следующего выстрела очереди (секунды)
+    pub shot_timer: f32,
+    /// Цель (AI) / None — player (направление от оружия)
+    pub target: Option<Entity>,
+}
+
+impl FiringState {
+    /// Спуск нажат: Single / Shotgun / Burst — новая очередь (если оружие готово), Auto — огонь до отпускания
+    ///
+    /// Returns `true` если очередь началась (вызывающий запускает cooldown оружия).
+    pub fn pull(&mut self, fire_mode: FireMode, weapon_ready: bool) -> bool {
+        self.trigger_held = true;
+
+        let shots = fire_mode.shots_per_pull();
+        if shots == 0 || !weapon_ready || self.burst_remaining > 0 {
+            return false;
+        }
+
+        self.burst_remaining = shots;
+        self.shot_timer = 0.0;
+        true
+    }
+
+    /// Tick: пора ли следующий выстрел
+    ///
+    /// - Single / Shotgun / Burst: очередь по `interval` (отпускание спуска её не прерывает)
+    /// - Auto: пока спуск зажат и оружие готово (cooldown)
+    pub fn tick(&mut self, fire_mode: FireMode, weapon_ready: bool, delta: f32) -> bool {
+        self.shot_timer = (self.shot_timer - delta).max(0.0);
+
+        if matches!(fire_mode, FireMode::Auto) {
+            return self.trigger_held && weapon_ready;
+        }
+
+        if self.burst_remaining == 0 || self.shot_timer > 0.0 {
+            return false;
+        }
+        self.burst_remaining -= 1;
+        self.shot_timer = fire_mode.burst_interval();
+        true
+    }
+
+    /// Спуск отпущен и очередь отстреляна → компонент можно снять
+    pub fn is_finished(&self) -> bool {
+        !self.trigger_held && self.burst_remaining == 0
+    }
+}
+
+/// Seed → [0, 1) (splitmix64: одинаковый seed — одинаковый рикошет в реплее)
+fn unit_from_seed(seed: u64) -> f32 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^= z >> 31;
+    (z >> 40) as f32 / (1u64 << 24) as f32
 }
 
 /// Тип оружия
@@ -134,6 +633,13 @@ impl WeaponStats {
             range: 0.0,
             projectile_speed: 0.0,
             hearing_range: 0.0,
+            heat: WeaponHeat::none(),
+            ballistics: ProjectileBallistics::none(),
+            falloff: DamageFalloff::none(),
+            fire_mode: FireMode::Single,
+            recoil: RecoilPattern::none(),
+            ads: AdsConfig::iron_sights(),
+            cleave: MeleeCleave::new(3, 0.6), // Широкий рубящий замах
         }
     }
 
@@ -158,12 +664,37 @@ impl WeaponStats {
             range: 20.0,
             projectile_speed: 8.0,
             hearing_range: 100.0,
+            heat: WeaponHeat::none(),
+            ballistics: ProjectileBallistics::new(0.5, 15.0).with_drop(1.0, 0.05),
+            falloff: DamageFalloff::new(10.0, 20.0, 0.5),
+            fire_mode: FireMode::Single,
+            recoil: RecoilPattern::new(2.0, 0.5, 1.0, 4.0, 3.0),
+            ads: AdsConfig { fov: 75.0, transition_time: 0.2, sensitivity_multiplier: 0.9 }, // Короткий ствол — быстрая вскидка
+            cleave: MeleeCleave::new(2, 0.5), // Приклад: толчок задевает соседа
+        }
+    }
+
+    /// Создать энергетическое ranged weapon (плазменная винтовка, греется вместо патронов)
+    pub fn energy_rifle() -> Self {
+        Self {
+            base_damage: 14,
+            attack_cooldown: 0.25,
+            range: 35.0,
+            projectile_speed: 30.0,
+            hearing_range: 80.0,
+            heat: WeaponHeat::energy(0.12, 0.3, 2.5),
+            ballistics: ProjectileBallistics::none(), // Плазма летит прямо, не рикошетит и не пробивает
+            falloff: DamageFalloff::new(15.0, 35.0, 0.4), // Плазменный сгусток рассеивается
+            fire_mode: FireMode::Auto, // Темп ограничен нагревом, а не магазином
+            recoil: RecoilPattern::new(0.6, 0.4, 0.5, 4.0, 4.0), // Слабый толчок, но очередь расползается
+            ads: AdsConfig { fov: 60.0, transition_time: 0.35, sensitivity_multiplier: 0.7 }, // Коллиматор
+            ..Self::ranged_pistol()
         }
     }
 
-    /// Может ли weapon атаковать (cooldown == 0)
+    /// Может ли weapon атаковать (cooldown == 0, не перегрето)
     pub fn can_attack(&self) -> bool {
-        self.cooldown_timer <= 0.0
+        self.cooldown_timer <= 0.0 && !self.heat.is_overheated()
     }
 
     /// Начать cooldown после атаки
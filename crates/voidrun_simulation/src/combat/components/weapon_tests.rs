@@ -2,6 +2,7 @@
 
 #[cfg(test)]
 mod tests {
+    use bevy::prelude::*;
     use super::super::weapon::*;
 
     #[test]
@@ -42,4 +43,213 @@ mod tests {
         weapon.cooldown_timer -= 0.5;
         assert!(weapon.can_attack());
     }
+
+    #[test]
+    fn test_energy_weapon_overheat_lockout() {
+        let mut weapon = WeaponStats::energy_rifle();
+        assert!(weapon.heat.is_enabled());
+        assert!(!WeaponStats::ranged_pistol().heat.is_enabled());
+
+        // 0.12 за выстрел → 9-й выстрел перегревает
+        let overheated_on = (1..=20).find(|_| weapon.heat.add_shot()).unwrap();
+        assert_eq!(overheated_on, 9);
+        assert!(weapon.heat.is_overheated());
+        assert!(!weapon.can_attack());
+
+        // Во время блокировки выстрелы нагрев не добавляют
+        assert!(!weapon.heat.add_shot());
+
+        weapon.heat.cool(weapon.heat.overheat_lockout);
+        assert!(!weapon.heat.is_overheated());
+        assert!(weapon.can_attack());
+        assert!(weapon.heat.current < 1.0);
+    }
+
+    #[test]
+    fn test_penetration_consumes_power() {
+        assert_eq!(ProjectileBallistics::penetrate(1.5, 1.0), Some(0.5));
+        assert_eq!(ProjectileBallistics::penetrate(0.5, 1.0), None);
+        // Без пробития — любая преграда останавливает
+        assert_eq!(ProjectileBallistics::penetrate(0.0, 0.0), None);
+        assert_eq!(ProjectileBallistics::reduced_damage(20, ProjectileBallistics::PENETRATION_DAMAGE_FACTOR), 12);
+    }
+
+    #[test]
+    fn test_ricochet_only_at_shallow_angle() {
+        let ballistics = ProjectileBallistics::new(0.0, 15.0);
+        let normal = Vec3::Y;
+        let grazing = Vec3::new(1.0, -0.1, 0.0);
+        let head_on = Vec3::new(0.2, -1.0, 0.0);
+
+        assert!(ballistics.should_ricochet(grazing, normal, 0));
+        assert!(!ballistics.should_ricochet(head_on, normal, 0));
+        assert!(!ballistics.should_ricochet(grazing, normal, ProjectileBallistics::MAX_RICOCHETS));
+        assert!(!ProjectileBallistics::none().should_ricochet(grazing, normal, 0));
+    }
+
+    #[test]
+    fn test_ricochet_direction_is_deterministic_and_leaves_surface() {
+        let direction = Vec3::new(1.0, -0.1, 0.0).normalize();
+
+        let first = ProjectileBallistics::ricochet_direction(direction, Vec3::Y, 42);
+        let replay = ProjectileBallistics::ricochet_direction(direction, Vec3::Y, 42);
+        assert_eq!(first, replay);
+        assert!(first.dot(Vec3::Y) > 0.0);
+        assert!((first.length() - 1.0).abs() < 1e-4);
+
+        // Отклонение от чистого отражения в пределах разброса
+        let reflected = direction.reflect(Vec3::Y);
+        let scatter = first.angle_between(reflected).to_degrees();
+        assert!(scatter <= ProjectileBallistics::RICOCHET_SCATTER_DEG + 1e-3);
+    }
+
+    #[test]
+    fn test_step_velocity_drop_and_drag() {
+        let straight = ProjectileBallistics::none();
+        assert_eq!(straight.step_velocity(Vec3::new(0.0, 0.0, -20.0), 0.5), Vec3::new(0.0, 0.0, -20.0));
+
+        let ballistics = ProjectileBallistics::none().with_drop(10.0, 0.5);
+        let velocity = ballistics.step_velocity(Vec3::new(0.0, 0.0, -20.0), 1.0);
+
+        // Вниз на gravity × t, вперёд — экспоненциальная потеря скорости
+        assert!((velocity.y + 10.0).abs() < 1e-5);
+        assert!((velocity.z + 20.0 * (-0.5_f32).exp()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_damage_falloff_curve() {
+        let falloff = DamageFalloff::new(10.0, 20.0, 0.5);
+
+        assert_eq!(falloff.multiplier(5.0), 1.0);
+        assert!((falloff.multiplier(15.0) - 0.75).abs() < 1e-6);
+        assert_eq!(falloff.multiplier(40.0), 0.5);
+        assert_eq!(falloff.apply(10, 40.0), 5);
+        // Ненулевой урон не обнуляется
+        assert_eq!(DamageFalloff::new(0.0, 1.0, 0.0).apply(3, 5.0), 1);
+        assert_eq!(DamageFalloff::none().apply(10, 1000.0), 10);
+    }
+
+    #[test]
+    fn test_shotgun_pellets_deterministic_within_cone() {
+        let shotgun = FireMode::Shotgun { pellets: 8, spread_degrees: 6.0 };
+        assert_eq!(shotgun.pellets(), 8);
+        assert_eq!(FireMode::Single.pellets(), 1);
+        assert_eq!(FireMode::Single.pellet_offset(42, 0), (0.0, 0.0));
+
+        let offsets: Vec<(f32, f32)> = (0..8).map(|index| shotgun.pellet_offset(42, index)).collect();
+        for &(deviation, azimuth) in &offsets {
+            assert!((0.0..=6.0_f32.to_radians()).contains(&deviation));
+            assert!((0.0..std::f32::consts::TAU).contains(&azimuth));
+        }
+
+        // Тот же выстрел (seed) → тот же разлёт, дробины не совпадают
+        let replay: Vec<(f32, f32)> = (0..8).map(|index| shotgun.pellet_offset(42, index)).collect();
+        assert_eq!(offsets, replay);
+        assert_ne!(offsets[0], offsets[1]);
+        assert_ne!(shotgun.pellet_offset(43, 0), offsets[0]);
+    }
+
+    #[test]
+    fn test_burst_fires_all_shots_after_release() {
+        let burst = FireMode::Burst { shots: 3, interval: 0.1 };
+        let mut firing = FiringState::default();
+
+        assert!(firing.pull(burst, true));
+        firing.trigger_held = false; // Отпустили сразу — очередь всё равно достреливается
+
+        let mut shots = 0;
+        for _ in 0..20 {
+            if firing.tick(burst, false, 0.05) {
+                shots += 1;
+            }
+        }
+        assert_eq!(shots, 3);
+        assert!(firing.is_finished());
+
+        // Оружие не готово (cooldown) → новая очередь не начинается
+        assert!(!firing.pull(burst, false));
+        assert_eq!(firing.burst_remaining, 0);
+    }
+
+    #[test]
+    fn test_auto_fires_while_held_and_ready() {
+        let mut firing = FiringState::default();
+        assert!(!firing.pull(FireMode::Auto, true));
+
+        assert!(firing.tick(FireMode::Auto, true, 0.016));
+        assert!(!firing.tick(FireMode::Auto, false, 0.016)); // cooldown
+
+        firing.trigger_held = false;
+        assert!(!firing.tick(FireMode::Auto, true, 0.016));
+        assert!(firing.is_finished());
+
+        assert!(!FireMode::Single.holds_trigger());
+        assert!(FireMode::Auto.holds_trigger());
+    }
+
+    #[test]
+    fn test_single_pull_fires_once() {
+        let mut firing = FiringState::default();
+        assert_eq!(FireMode::Single.shots_per_pull(), 1);
+        assert_eq!(FireMode::Auto.shots_per_pull(), 0);
+
+        assert!(firing.pull(FireMode::Single, true));
+
+        // Спуск держат — но Single стреляет только раз на нажатие
+        let shots = (0..10).filter(|_| firing.tick(FireMode::Single, true, 0.05)).count();
+        assert_eq!(shots, 1);
+        assert_eq!(firing.burst_remaining, 0);
+    }
+
+    #[test]
+    fn test_recoil_accumulates_and_recovers() {
+        let pattern = RecoilPattern::new(1.0, 0.5, 1.0, 2.5, 5.0);
+        let mut recoil = RecoilState::default();
+
+        for _ in 0..4 {
+            recoil.add_shot(&pattern);
+        }
+        // Bloom упирается в потолок, подброс копится до take_kick
+        assert_eq!(recoil.bloom, 2.5);
+        assert_eq!(recoil.shot_index, 4);
+        let kick = recoil.take_kick();
+        assert_eq!(kick.x, 4.0);
+        assert!(kick.y.abs() <= 4.0 * 0.5);
+        assert_eq!(recoil.take_kick(), Vec2::ZERO);
+
+        // Паттерн увода повторяем (тот же номер выстрела → тот же увод)
+        assert_eq!(pattern.kick(2), pattern.kick(2));
+        assert_ne!(pattern.kick(1), pattern.kick(2));
+
+        // Пауза после выстрела — разброс держится, потом восстанавливается и серия сначала
+        recoil.recover(&pattern, RecoilState::RECOVERY_DELAY * 0.5);
+        assert_eq!(recoil.bloom, 2.5);
+        for _ in 0..20 {
+            recoil.recover(&pattern, 0.1);
+        }
+        assert_eq!(recoil.bloom, 0.0);
+        assert_eq!(recoil.shot_index, 0);
+
+        // Без отдачи (melee) — ничего не копится
+        let mut still = RecoilState::default();
+        still.add_shot(&RecoilPattern::none());
+        assert_eq!(still.bloom, 0.0);
+        assert_eq!(still.take_kick(), Vec2::ZERO);
+    }
+
+    #[test]
+    fn test_ads_config_lerps_fov_and_sensitivity() {
+        let sights = AdsConfig::iron_sights();
+        assert_eq!(sights.fov_at(0.0), AdsConfig::HIP_FIRE_FOV);
+        assert_eq!(sights.fov_at(1.0), sights.fov);
+        assert_eq!(sights.fov_at(0.5), (AdsConfig::HIP_FIRE_FOV + sights.fov) * 0.5);
+        assert_eq!(sights.sensitivity_at(0.0), 1.0);
+        assert_eq!(sights.sensitivity_at(1.0), sights.sensitivity_multiplier);
+
+        // Оптика: чувствительность падает вместе с зумом
+        let scope = AdsConfig::scope(18.0);
+        assert_eq!(scope.fov, 18.0);
+        assert!((scope.sensitivity_multiplier - 0.2).abs() < 1e-5);
+        assert!(scope.transition_time > sights.transition_time);
+    }
 }
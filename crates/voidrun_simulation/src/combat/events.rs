@@ -4,6 +4,10 @@
 
 use bevy::prelude::*;
 use super::components::melee::MeleeAttackType;
+use super::components::flinch::FlinchKind;
+use super::components::invulnerability::InvulnerabilityReason;
+use super::components::channel::ChannelKind;
+use super::components::weapon::{FireMode, ProjectileBallistics};
 
 // ============================================================================
 // Melee Events
@@ -24,7 +28,7 @@ use super::components::melee::MeleeAttackType;
 pub struct MeleeAttackIntent {
     /// Entity initiating attack
     pub attacker: Entity,
-    /// Attack type (Normal/Heavy/Quick)
+    /// Attack type (Normal/Heavy/Quick/Execution/Bash)
     pub attack_type: MeleeAttackType,
 }
 
@@ -53,6 +57,38 @@ pub struct MeleeAttackStarted {
     pub recovery_duration: f32,
 }
 
+/// Quick melee: удар прикладом, не убирая ranged оружие (игрок [MMB] / AI).
+///
+/// `convert_quick_melee_intents` → `MeleeAttackIntent { attack_type: Bash }` →
+/// обычная melee валидация (Godot) и ActionLock арбитраж.
+#[derive(Event, Clone, Debug)]
+pub struct QuickMeleeIntent {
+    /// Entity initiating bash
+    pub attacker: Entity,
+}
+
+/// Толчок энергощитом (игрок [C] / AI).
+///
+/// `convert_shield_bash_intents` (активный щит с запасом энергии) → `MeleeAttackIntent { attack_type: ShieldBash }` →
+/// обычная melee валидация (Godot) и ActionLock арбитраж; энергия списывается на старте атаки.
+#[derive(Event, Clone, Debug)]
+pub struct ShieldBashIntent {
+    /// Entity initiating shield bash
+    pub attacker: Entity,
+}
+
+/// Poise урон (выводит из равновесия сверх урона по HP).
+///
+/// Генерируется `process_melee_hits` для Bash / ShieldBash; `apply_flinch_on_damage` складывает
+/// `poise_damage` с уроном того же удара при классификации flinch.
+#[derive(Event, Clone, Debug)]
+pub struct PoiseHit {
+    pub attacker: Entity,
+    pub target: Entity,
+    /// HP-эквивалент для `FlinchConfig::classify`
+    pub poise_damage: u32,
+}
+
 /// Melee hitbox collision detected (Godot → ECS).
 ///
 /// Generated by Godot when weapon hitbox (Area3D) collides with target.
@@ -141,6 +177,25 @@ pub struct WeaponFireIntent {
 
     /// Радиус слышимости выстрела (для AI reaction)
     pub hearing_range: f32,
+
+    /// Пробитие / рикошет пули (из Weapon component)
+    pub ballistics: ProjectileBallistics,
+
+    /// Режим огня (Shotgun → несколько дробин на выстрел)
+    pub fire_mode: FireMode,
+}
+
+/// Event: спуск нажат / отпущен (player, любое ranged оружие)
+///
+/// ECS `apply_trigger_intents` проверяет оружие (`fire_block`, как у AI) → FiringState;
+/// `continue_firing` шлёт WeaponFireIntent, пока спуск зажат (Auto) / очередь не отстреляна.
+/// Отказ → FireDenied (HUD).
+#[derive(Event, Debug, Clone)]
+pub struct TriggerIntent {
+    /// Кто стреляет
+    pub shooter: Entity,
+    /// true — спуск нажат, false — отпущен
+    pub held: bool,
 }
 
 /// Event: Актёр стреляет (ECS → Godot, после validation)
@@ -167,6 +222,12 @@ pub struct WeaponFired {
 
     /// Радиус слышимости выстрела (для AI reaction)
     pub hearing_range: f32,
+
+    /// Пробитие / рикошет пули
+    pub ballistics: ProjectileBallistics,
+
+    /// Режим огня (Shotgun → Godot спавнит `pellets` дробин)
+    pub fire_mode: FireMode,
 }
 
 /// Event: Projectile попал в цель (Godot → ECS)
@@ -186,6 +247,12 @@ pub struct ProjectileHit {
 
     /// Нормаль поверхности (для VFX направления)
     pub impact_normal: Vec3,
+
+    /// Сколько преград пуля пробила / рикошетов сделала до этого попадания (0 = прямое)
+    pub penetrations: u8,
+
+    /// Пройденный пулей путь (метры, для DamageFalloff)
+    pub distance: f32,
 }
 
 /// Event: Projectile попал в щит (Godot → ECS)
@@ -266,6 +333,266 @@ pub struct EntityDied {
     pub killer: Option<Entity>,
 }
 
+/// Событие: удар проигнорирован из-за неуязвимости (visual feedback hook)
+///
+/// Генерируется damage системами вместо DamageDealt когда цель Invulnerable.
+/// Godot может показать "immune" VFX/звук.
+#[derive(Event, Debug, Clone)]
+pub struct InvulnerableHit {
+    pub attacker: Entity,
+    pub target: Entity,
+    pub reason: InvulnerabilityReason,
+}
+
+// ============================================================================
+// Flinch Events
+// ============================================================================
+
+/// Событие: актор вздрогнул от урона (ECS → Godot animation bridge)
+///
+/// Генерируется `apply_flinch_on_damage` после DamageDealt.
+/// - Light: Godot проигрывает upper-body flinch поверх текущей анимации
+/// - Heavy: Godot прерывает атаку (ECS уже снял MeleeAttackState/ParryState)
+#[derive(Event, Debug, Clone)]
+pub struct FlinchTriggered {
+    /// Кто вздрогнул
+    pub entity: Entity,
+    /// Кто нанёс урон
+    pub source: Entity,
+    /// Сила реакции
+    pub kind: FlinchKind,
+    /// Направление удара (нормаль попадания, для направленной анимации)
+    pub hit_direction: Vec3,
+}
+
+/// Событие: актор начинает вставать после knockdown (ECS → Godot get-up анимация)
+///
+/// Генерируется `update_knockdown_states` при переходе Down → GettingUp.
+#[derive(Event, Debug, Clone)]
+pub struct KnockdownGetUp {
+    pub entity: Entity,
+}
+
+// ============================================================================
+// Suppression Events
+// ============================================================================
+
+/// Событие: пуля пролетела рядом с актором (Godot near-miss detection → ECS)
+///
+/// Генерируется Godot один раз на пару (projectile, actor), когда пуля
+/// проходит в пределах near-miss радиуса и не попадает.
+/// ECS: `apply_suppression_from_near_misses` → Suppressed.
+#[derive(Event, Debug, Clone)]
+pub struct ProjectileNearMiss {
+    /// Кто стрелял
+    pub shooter: Entity,
+    /// Мимо кого пролетела пуля
+    pub target: Entity,
+    /// Минимальная дистанция пули до актора (метры)
+    pub distance: f32,
+    /// Near-miss радиус, в котором проводилась проверка (метры)
+    pub radius: f32,
+}
+
+// ============================================================================
+// Channel Events
+// ============================================================================
+
+/// Событие: channelled действие завершено (reload/consumable/hack/cast применяется)
+///
+/// Генерируется `update_channels`. Система-владелец действия применяет эффект.
+#[derive(Event, Debug, Clone)]
+pub struct ChannelCompleted {
+    pub entity: Entity,
+    pub kind: ChannelKind,
+}
+
+/// Событие: channelled действие прервано уроном
+///
+/// Генерируется `interrupt_channels_on_damage`. Эффект НЕ применяется;
+/// Godot может отменить анимацию/показать feedback.
+#[derive(Event, Debug, Clone)]
+pub struct ChannelInterrupted {
+    pub entity: Entity,
+    pub kind: ChannelKind,
+    /// Кто прервал (последний удар)
+    pub attacker: Entity,
+    /// Урон за окно на момент прерывания
+    pub window_damage: u32,
+}
+
+// ============================================================================
+// Smoke Events
+// ============================================================================
+
+/// Событие: дымовая граната сработала (consumable / скрипт)
+///
+/// Обрабатывается `spawn_smoke_clouds` → SmokeCloud entity.
+#[derive(Event, Debug, Clone)]
+pub struct SmokeDeployed {
+    /// Центр облака (world position)
+    pub center: Vec3,
+    /// Радиус (метры)
+    pub radius: f32,
+    /// Длительность (секунды)
+    pub duration: f32,
+}
+
+// ============================================================================
+// Flashbang Events
+// ============================================================================
+
+/// Событие: светошумовая граната сработала (ECS → Godot)
+///
+/// Godot считает дистанцию/направление взгляда каждого актора → `FlashExposure`.
+#[derive(Event, Debug, Clone)]
+pub struct FlashbangDetonated {
+    /// Кто применил (не ослепляет сам себя — граната под ногами, бросков пока нет)
+    pub thrower: Entity,
+    /// Точка детонации (world position)
+    pub position: Vec3,
+    /// Радиус действия (метры)
+    pub radius: f32,
+    /// Максимальная длительность ослепления (секунды, при exposure = 1)
+    pub max_duration: f32,
+}
+
+/// Событие: актор попал под вспышку (Godot → ECS)
+///
+/// Генерируется `detect_flash_exposure_main_thread`, обрабатывается `apply_flash_exposure`.
+#[derive(Event, Debug, Clone)]
+pub struct FlashExposure {
+    pub target: Entity,
+    /// Экспозиция (0.0-1.0, `Blinded::exposure`)
+    pub exposure: f32,
+    /// Максимальная длительность (из FlashbangDetonated)
+    pub max_duration: f32,
+}
+
+/// HUD событие: игрок ослеплён (Godot засвечивает экран)
+#[derive(Event, Debug, Clone)]
+pub struct PlayerBlinded {
+    pub entity: Entity,
+    /// Сила (0.0-1.0, начальная непрозрачность засветки)
+    pub intensity: f32,
+    /// Длительность fade (секунды)
+    pub duration: f32,
+}
+
+// ============================================================================
+// EMP Events
+// ============================================================================
+
+/// Событие: EMP импульс (граната / оружие)
+///
+/// Обрабатывается `apply_emp_detonations`: щиты в радиусе обнуляются (`EmpDisrupted`),
+/// электронные акторы (`Electronic`) оглушены.
+#[derive(Event, Debug, Clone)]
+pub struct EmpDetonated {
+    /// Кто применил (не задевает сам себя — граната под ногами, бросков пока нет)
+    pub source: Entity,
+    /// Эпицентр (world position)
+    pub position: Vec3,
+    /// Радиус действия (метры)
+    pub radius: f32,
+    /// Длительность блокировки щита в эпицентре (секунды)
+    pub duration: f32,
+}
+
+// ============================================================================
+// Weapon Durability Events
+// ============================================================================
+
+/// Событие: оружие заклинило (стрельба заблокирована до устранения)
+#[derive(Event, Debug, Clone)]
+pub struct WeaponJammed {
+    pub entity: Entity,
+    /// Прочность в момент клина
+    pub durability: f32,
+}
+
+/// Intent: устранить клин активного оружия (channel `ClearJam`)
+#[derive(Event, Debug, Clone)]
+pub struct ClearJamIntent {
+    pub entity: Entity,
+}
+
+/// Событие: клин устранён
+#[derive(Event, Debug, Clone)]
+pub struct JamCleared {
+    pub entity: Entity,
+}
+
+// ============================================================================
+// Downed Events
+// ============================================================================
+
+/// Событие: актор упал при 0 HP (CanBeDowned) — вместо EntityDied
+///
+/// Генерируется `down_actors_at_zero_health`. Godot: downed анимация / HUD таймер.
+#[derive(Event, Debug, Clone)]
+pub struct ActorDowned {
+    pub entity: Entity,
+    /// Кто уронил
+    pub attacker: Option<Entity>,
+}
+
+/// Intent: поднять ближайшего раненого союзника (channel `Revive`)
+#[derive(Event, Debug, Clone)]
+pub struct ReviveIntent {
+    pub reviver: Entity,
+}
+
+/// Событие: раненый поднят союзником
+#[derive(Event, Debug, Clone)]
+pub struct ActorRevived {
+    pub entity: Entity,
+    pub reviver: Entity,
+}
+
+// ============================================================================
+// Fire Gating Events
+// ============================================================================
+
+/// Почему выстрел не состоялся (`fire_block`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum FireBlock {
+    /// Cooldown между выстрелами / очередями
+    Cooldown,
+    /// Перегрев энергооружия (WeaponHeat)
+    Overheated,
+    /// Оружие заклинило (ClearJamIntent)
+    Jammed,
+    /// Магазин пуст (`EquippedItem::ammo_count == Some(0)`)
+    OutOfAmmo,
+    /// Занят: channelled действие (reload, аптечка) / лежит (knockdown, downed) / спринт
+    Busy,
+}
+
+/// Событие: выстрел отклонён проверкой оружия (ECS → Godot HUD)
+///
+/// Генерируется `apply_trigger_intents` (нажатие спуска) и `continue_firing`
+/// (очередь / автомат прерваны клином, перегревом, действием).
+#[derive(Event, Debug, Clone)]
+pub struct FireDenied {
+    pub shooter: Entity,
+    pub reason: FireBlock,
+}
+
+// ============================================================================
+// Weapon Heat Events
+// ============================================================================
+
+/// Событие: нагрев энергетического оружия изменился (ECS → Godot, свечение ствола)
+#[derive(Event, Debug, Clone)]
+pub struct WeaponHeatChanged {
+    pub entity: Entity,
+    /// Нагрев 0.0 - 1.0
+    pub heat: f32,
+    /// Перегрето (огонь заблокирован)
+    pub overheated: bool,
+}
+
 // ============================================================================
 // Attack Type Enum (shared between melee events and components)
 // ============================================================================
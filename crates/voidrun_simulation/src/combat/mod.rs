@@ -13,6 +13,7 @@
 //! Архитектура: docs/decisions/ADR-003-ecs-vs-godot-physics-ownership.md
 
 use bevy::prelude::*;
+use crate::game_state::GameState;
 
 // Domain modules
 pub mod components;
@@ -23,21 +24,73 @@ pub mod events;
 pub use components::{
     // Melee components
     MeleeAttackState, AttackPhase, ParryState, ParryPhase, StaggerState, ParryDelayTimer,
-    MeleeAttackType,
+    MeleeAttackType, MeleeTradeRule, MeleeTimings, MeleeCleave, BASH_DAMAGE, BASH_POISE_DAMAGE, BASH_RANGE,
+    SHIELD_BASH_DAMAGE, SHIELD_BASH_POISE_DAMAGE, SHIELD_BASH_RANGE, SHIELD_BASH_ENERGY_COST,
     // Weapon component
-    WeaponStats, WeaponType,
+    WeaponStats, WeaponType, WeaponHeat, ProjectileBallistics, DamageFalloff, FireMode, FiringState,
+    RecoilPattern, RecoilState, AdsConfig,
     // Stamina components
     Exhausted,
+    // Flinch components
+    FlinchConfig, FlinchKind, FlinchState,
+    // Invulnerability components
+    Invulnerable, InvulnerabilityReason, InvulnerabilityConfig,
+    // Suppression components
+    Suppressed,
+    // Aim components
+    AimSkill, AimReaction,
+    // Channel components
+    Channeling, ChannelKind, ChannelInterruptRules,
+    // Action arbitration components
+    ActionLock, ActionKind, ActionPhase, CancelTable,
+    // Knockdown components
+    KnockdownState, KnockdownPhase, EXECUTION_DAMAGE_MULTIPLIER,
+    // Smoke components
+    SmokeCloud, SmokeOcclusionMap,
+    // Blindness components
+    Blinded,
+    // EMP components
+    EmpDisrupted, Electronic,
+    // Fall damage components
+    FallDamageConfig,
+    // Weapon durability components
+    jam_chance, JAM_THRESHOLD, MAX_JAM_CHANCE, WEAR_PER_SHOT, WEAR_PER_HIT, CLEAR_JAM_DURATION,
+    // Downed components
+    CanBeDowned, Downed, Reviving, REVIVE_RANGE, REVIVE_DURATION, REVIVE_HEALTH_FRACTION,
+    // Melee intercept prediction
+    ActorMotion, melee_time_to_hit, predict_position, melee_intercept_point, target_in_reach_at_hit,
 };
 
 // Re-export events
 pub use events::{
     // Melee events
-    MeleeAttackIntent, MeleeAttackStarted, MeleeHit, ParryIntent, ParrySuccess,
+    MeleeAttackIntent, MeleeAttackStarted, MeleeHit, ParryIntent, ParrySuccess, QuickMeleeIntent, ShieldBashIntent, PoiseHit,
     // Ranged events
-    WeaponFireIntent, WeaponFired, ProjectileHit, ProjectileShieldHit,
+    WeaponFireIntent, WeaponFired, TriggerIntent, ProjectileHit, ProjectileShieldHit,
     // Damage events
     DamageDealt, EntityDied, DamageSource, AppliedDamage,
+    // Flinch events
+    FlinchTriggered, KnockdownGetUp,
+    // Invulnerability events
+    InvulnerableHit,
+    // Suppression events
+    ProjectileNearMiss,
+    // Channel events
+    ChannelCompleted, ChannelInterrupted,
+    // Smoke events
+    SmokeDeployed,
+    // Flashbang events
+    FlashbangDetonated, FlashExposure, PlayerBlinded,
+    // EMP events
+    EmpDetonated,
+    // Weapon durability events
+    WeaponJammed, ClearJamIntent, JamCleared,
+    // Downed events
+    ActorDowned, ReviveIntent, ActorRevived,
+    // Weapon heat events
+    WeaponHeatChanged,
+    // Fire gating events
+    FireBlock, FireDenied,
     // Shared enums
     AttackType,
 };
@@ -45,17 +98,42 @@ pub use events::{
 // Re-export systems
 pub use systems::{
     // Melee systems
-    start_melee_attacks, update_melee_attack_phases, process_melee_hits,
+    convert_quick_melee_intents, convert_shield_bash_intents, start_melee_attacks, update_melee_attack_phases, process_melee_hits, resolve_melee_trades, apply_melee_cleave,
     start_parry, update_parry_states, update_stagger_states, process_parry_delay_timers,
     // Weapon systems
-    update_weapon_cooldowns, ai_weapon_fire_intent,
+    update_weapon_cooldowns, update_weapon_heat, update_recoil, ai_weapon_fire_intent, fire_block, apply_trigger_intents, continue_firing,
     process_projectile_hits, process_projectile_shield_hits,
     // Damage systems
-    Dead, DespawnAfter, apply_damage, calculate_damage, apply_damage_with_shield,
-    shield_recharge_system, disable_ai_on_death, despawn_after_timeout,
+    Dead, DespawnAfter, DamageGate, apply_damage, calculate_damage,
+    shield_recharge_system, detect_deaths, disable_ai_on_death, despawn_after_timeout,
     // Stamina systems
     ATTACK_COST, BLOCK_COST, DODGE_COST,
     regenerate_stamina, consume_stamina_on_attack, detect_exhaustion,
+    apply_sprint_intents, drain_sprint_stamina, apply_hold_breath_intents, drain_breath_stamina,
+    // Flinch systems
+    apply_flinch_on_damage, update_flinch_states, update_knockdown_states,
+    // Invulnerability systems
+    grant_spawn_protection, grant_post_stagger_grace, expire_invulnerability,
+    // Suppression systems
+    apply_suppression_from_near_misses, decay_suppression,
+    // Aim systems
+    update_aim_reaction,
+    // Channel systems
+    interrupt_channels_on_damage, update_channels,
+    // Action arbitration systems
+    update_action_locks, validate_cancel_table,
+    // Smoke systems
+    spawn_smoke_clouds, update_smoke_clouds,
+    // Blindness systems
+    apply_flash_exposure, update_blinded_states,
+    // EMP systems
+    apply_emp_detonations, update_emp_disruptions,
+    // Fall damage systems
+    apply_fall_damage,
+    // Weapon durability systems
+    wear_weapons, ai_clear_weapon_jams, start_clear_jam, complete_clear_jam,
+    // Downed systems
+    down_actors_at_zero_health, update_downed_states, ai_revive_downed_allies, start_revives, complete_revives,
 };
 
 /// Combat Plugin (domain-driven architecture)
@@ -63,13 +141,17 @@ pub use systems::{
 /// Регистрирует combat системы в FixedUpdate (64Hz).
 ///
 /// Порядок выполнения:
-/// 1. tick_attack_cooldowns — обновление cooldown таймеров
-/// 2. apply_damage — обработка GodotCombatEvent → damage calculation
-/// 3. disable_ai_on_death — отключение AI у мертвых
-/// 4. regenerate_stamina — восстановление stamina
+/// 1. tick_attack_cooldowns — обновление cooldown таймеров (+ нагрев / перегрев энергооружия)
+/// 2. process_projectile_hits / process_melee_hits / apply_fall_damage — урон через `apply_damage`
+///    (единая точка: неуязвимость → щит → Health)
+/// 3. detect_deaths + disable_ai_on_death — HP = 0 → EntityDied, отключение AI у мертвых
+///    (CanBeDowned: HP = 0 → Downed → bleed-out / добивание → EntityDied; союзник поднимает ReviveIntent)
+/// 4. regenerate_stamina — восстановление stamina (спринт: apply_sprint_intents + drain_sprint_stamina,
+///    задержка дыхания: apply_hold_breath_intents + drain_breath_stamina)
 /// 5. detect_exhaustion — exhaustion status management
+/// 6. wear_weapons → клин на изношенном оружии → ClearJamIntent → Channeling(ClearJam)
 ///
-/// Godot отправляет GodotCombatEvent::WeaponHit → apply_damage → DamageDealt
+/// Godot отправляет ProjectileHit / MeleeHit → apply_damage → DamageDealt
 pub struct CombatPlugin;
 
 impl Plugin for CombatPlugin {
@@ -79,54 +161,164 @@ impl Plugin for CombatPlugin {
             .add_event::<EntityDied>()
             .add_event::<WeaponFireIntent>()
             .add_event::<WeaponFired>()
+            .add_event::<TriggerIntent>()
+            .add_event::<FireDenied>()
             .add_event::<ProjectileHit>()
             .add_event::<ProjectileShieldHit>() // Shield collision events
             .add_event::<MeleeAttackIntent>()
             .add_event::<MeleeAttackStarted>()
             .add_event::<MeleeHit>()
+            .add_event::<QuickMeleeIntent>()
+            .add_event::<ShieldBashIntent>()
+            .add_event::<PoiseHit>()
             .add_event::<ParryIntent>()
-            .add_event::<ParrySuccess>();
+            .add_event::<ParrySuccess>()
+            .add_event::<FlinchTriggered>()
+            .add_event::<KnockdownGetUp>()
+            .add_event::<InvulnerableHit>()
+            .add_event::<ProjectileNearMiss>()
+            .add_event::<ChannelCompleted>()
+            .add_event::<ChannelInterrupted>()
+            .add_event::<SmokeDeployed>()
+            .add_event::<FlashbangDetonated>()
+            .add_event::<FlashExposure>()
+            .add_event::<PlayerBlinded>()
+            .add_event::<EmpDetonated>()
+            .add_event::<WeaponJammed>()
+            .add_event::<ClearJamIntent>()
+            .add_event::<JamCleared>()
+            .add_event::<ActorDowned>()
+            .add_event::<ReviveIntent>()
+            .add_event::<ActorRevived>()
+            .add_event::<WeaponHeatChanged>()
+            .add_event::<crate::movement::SprintIntent>()
+            .add_event::<crate::shooting::HoldBreathIntent>()
+            .add_event::<crate::movement::Landed>();
+
+        app.init_resource::<InvulnerabilityConfig>()
+            .init_resource::<ChannelInterruptRules>()
+            .init_resource::<CancelTable>()
+            .init_resource::<MeleeTradeRule>()
+            .init_resource::<SmokeOcclusionMap>()
+            .init_resource::<FallDamageConfig>()
+            .add_systems(Startup, validate_cancel_table);
 
         // Регистрация систем в FixedUpdate
+        // Фазы сгруппированы в nested tuples (лимит Bevy — 20 систем на tuple),
+        // внутри фазы и между фазами — последовательное выполнение (.chain()).
         app.add_systems(
             FixedUpdate,
             (
-                // Фаза 1: Cooldowns (unified weapon cooldowns)
-                update_weapon_cooldowns,
-
-                // Фаза 2: Attack intent generation (ECS strategic decision)
-                // Godot tactical validation в process_*_intents_main_thread
-                ai_weapon_fire_intent,
-                // NOTE: ai_melee_attack_intent REMOVED - replaced by unified ai_combat_decision_main_thread (in Godot layer)
-
-                // Фаза 3: Attack execution (start attacks from approved intents)
-                start_melee_attacks,
-                update_melee_attack_phases,
-
-                // Фаза 3.5: Parry system (defensive actions)
-                process_parry_delay_timers, // Tick delay timers → generate ParryIntent
-                start_parry,
-                update_parry_states, // Includes parry success check at critical moment
-                update_stagger_states,
-
-                // Фаза 4: Damage application (from Godot events + projectiles + melee hits)
-                apply_damage,
-                process_projectile_hits,
-                process_projectile_shield_hits, // Shield collision events → damage shield
-                process_melee_hits,
-
-                // Фаза 5: Death handling
-                disable_ai_on_death,
-                despawn_after_timeout,
-
-                // Фаза 6: Stamina management + Shield recharge
-                regenerate_stamina,
-                detect_exhaustion,
-                shield_recharge_system,
-
-                // Projectile cleanup — в Godot (GodotProjectile::_physics_process)
+                (
+                    // Фаза 0: Action arbitration (ActionLock из state компонентов)
+                    update_action_locks,
+
+                    // Фаза 1: Cooldowns (unified weapon cooldowns) + нагрев энергооружия + отдача + aim reaction timers
+                    update_weapon_cooldowns,
+                    update_weapon_heat, // WeaponFired → нагрев / остывание / перегрев → WeaponHeatChanged
+                    update_recoil,      // WeaponFired → подброс камеры + bloom, восстановление разброса
+                    update_aim_reaction,
+
+                    // Фаза 2: Attack intent generation (ECS strategic decision)
+                    // Godot tactical validation в process_*_intents_main_thread
+                    ai_weapon_fire_intent,
+                    apply_trigger_intents, // TriggerIntent (player) → fire_block → FiringState / FireDenied
+                    continue_firing,       // FiringState (Burst / Auto) → WeaponFireIntent
+                    // NOTE: ai_melee_attack_intent REMOVED - replaced by unified ai_combat_decision_main_thread (in Godot layer)
+
+                    // Фаза 2.5: Quick melee (удар прикладом) → MeleeAttackIntent(Bash) → Godot валидация
+                    convert_quick_melee_intents,
+                    convert_shield_bash_intents, // ShieldBashIntent → MeleeAttackIntent(ShieldBash), если щит активен
+
+                    // Фаза 3: Attack execution (start attacks from approved intents)
+                    start_melee_attacks,
+                    update_melee_attack_phases,
+                )
+                    .chain(),
+                (
+                    // Фаза 3.5: Parry system (defensive actions)
+                    process_parry_delay_timers, // Tick delay timers → generate ParryIntent
+                    start_parry,
+                    update_parry_states, // Includes parry success check at critical moment
+                    update_stagger_states,
+                    grant_post_stagger_grace, // Stagger ended → короткое окно неуязвимости
+                )
+                    .chain(),
+                (
+                    // Фаза 4: Damage application (from Godot events + projectiles + melee hits)
+                    grant_spawn_protection,
+                    expire_invulnerability,
+                    process_projectile_hits,
+                    process_projectile_shield_hits, // Shield collision events → damage shield
+                    process_melee_hits,
+                    apply_fall_damage, // Landed (Godot gravity) → урон + stagger при жёстком приземлении
+
+                    // Фаза 4.5: Flinch reactions (DamageDealt → light/heavy flinch/knockdown)
+                    apply_flinch_on_damage,
+                    update_flinch_states,
+                    update_knockdown_states, // Down → GettingUp → встал
+
+                    // Фаза 4.55: Channelled actions (урон за окно > порога → interrupt, иначе complete)
+                    interrupt_channels_on_damage,
+                    update_channels,
+
+                    // Фаза 4.6: Suppression (near-miss → Suppressed, decay)
+                    apply_suppression_from_near_misses,
+                    decay_suppression,
+                )
+                    .chain(),
+                (
+                    // Фаза 4.8: Downed (HP = 0 у CanBeDowned → Downed; bleed-out / добивание → EntityDied; поднятие)
+                    down_actors_at_zero_health,
+                    update_downed_states,
+                    ai_revive_downed_allies,
+                    start_revives,
+                    complete_revives, // ChannelCompleted(Revive) → союзник встаёт
+                )
+                    .chain(),
+                (
+                    // Фаза 5: Death handling (HP = 0 → EntityDied → Dead, лут — InteractionPlugin)
+                    detect_deaths,
+                    disable_ai_on_death,
+                    despawn_after_timeout,
+
+                    // Фаза 6: Stamina management + Shield recharge
+                    // (спринт: intent → Sprinting → drain → 0 stamina → Exhausted lockout;
+                    //  дыхание в ADS: intent → HoldingBreath → drain → 0 stamina → Exhausted lockout)
+                    apply_sprint_intents,
+                    drain_sprint_stamina,
+                    apply_hold_breath_intents,
+                    drain_breath_stamina,
+                    regenerate_stamina,
+                    detect_exhaustion,
+                    shield_recharge_system,
+
+                    // Фаза 7: Smoke (spawn облаков, lifetime, strategic occlusion map)
+                    spawn_smoke_clouds,
+                    update_smoke_clouds,
+
+                    // Фаза 8: Flashbang (FlashExposure → Blinded, expiry)
+                    apply_flash_exposure,
+                    update_blinded_states,
+
+                    // Фаза 8.5: EMP (EmpDetonated → щит обнулён + EmpDisrupted, электроника оглушена; expiry)
+                    apply_emp_detonations,
+                    update_emp_disruptions,
+
+                    // Projectile cleanup — в Godot (GodotProjectile::_physics_process)
+                )
+                    .chain(),
+                (
+                    // Фаза 9: Weapon durability (износ → клин → устранение клина channel'ом)
+                    wear_weapons,
+                    ai_clear_weapon_jams,
+                    start_clear_jam,
+                    complete_clear_jam, // ChannelCompleted(ClearJam) → клин снят
+                )
+                    .chain(),
             )
-                .chain(), // Последовательное выполнение
+                .chain() // Последовательное выполнение
+                .run_if(in_state(GameState::Playing)),
         );
     }
 }
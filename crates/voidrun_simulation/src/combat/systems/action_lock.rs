@@ -0,0 +1,90 @@
+//! ActionLock systems (derive current action from state components).
+
+use bevy::prelude::*;
+use crate::components::Actor;
+use crate::movement::{MantleState, Sprinting};
+use crate::combat::{
+    ActionKind, ActionLock, ActionPhase, AttackPhase, CancelTable, Channeling, Downed, FlinchState, KnockdownPhase,
+    KnockdownState, MeleeAttackState, ParryState, StaggerState,
+};
+
+/// System: пересчитать ActionLock из state компонентов (начало FixedUpdate)
+///
+/// Приоритет: Downed > Knockdown > Stagger > Flinch > Mantle > Parry > MeleeAttack > Channel > Sprint.
+/// Фаза Knockdown: Down → Active, GettingUp → Recovery.
+/// Lock снимается, когда ни одного state компонента не осталось.
+/// Фаза MeleeAttack: Windup → Startup, ActiveParryWindow/ActiveHitbox → Active, Recovery → Recovery.
+pub fn update_action_locks(
+    query: Query<
+        (
+            Entity,
+            Has<Downed>,
+            Option<&KnockdownState>,
+            Option<&StaggerState>,
+            Option<&FlinchState>,
+            Option<&ParryState>,
+            Option<&MeleeAttackState>,
+            Option<&Channeling>,
+            Has<MantleState>,
+            Has<Sprinting>,
+            Option<&ActionLock>,
+        ),
+        With<Actor>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, downed, knockdown, stagger, flinch, parry, attack, channel, mantling, sprinting, current_lock) in query.iter() {
+        let desired = if downed {
+            Some(ActionLock::new(ActionKind::Downed, ActionPhase::Active))
+        } else if let Some(knockdown) = knockdown {
+            let phase = match knockdown.phase {
+                KnockdownPhase::Down => ActionPhase::Active,
+                KnockdownPhase::GettingUp => ActionPhase::Recovery,
+            };
+            Some(ActionLock::new(ActionKind::Knockdown, phase))
+        } else if stagger.is_some() {
+            Some(ActionLock::new(ActionKind::Stagger, ActionPhase::Active))
+        } else if flinch.is_some() {
+            Some(ActionLock::new(ActionKind::Flinch, ActionPhase::Active))
+        } else if mantling {
+            Some(ActionLock::new(ActionKind::Mantle, ActionPhase::Active))
+        } else if parry.is_some() {
+            Some(ActionLock::new(ActionKind::Parry, ActionPhase::Active))
+        } else if let Some(attack) = attack {
+            let phase = match attack.phase {
+                AttackPhase::Windup { .. } | AttackPhase::Idle => ActionPhase::Startup,
+                AttackPhase::ActiveParryWindow { .. } | AttackPhase::ActiveHitbox { .. } => {
+                    ActionPhase::Active
+                }
+                AttackPhase::Recovery { .. } => ActionPhase::Recovery,
+            };
+            Some(ActionLock::new(ActionKind::MeleeAttack, phase))
+        } else if let Some(channel) = channel {
+            Some(ActionLock::new(channel.kind.into(), ActionPhase::Active))
+        } else if sprinting {
+            Some(ActionLock::new(ActionKind::Sprint, ActionPhase::Active))
+        } else {
+            None
+        };
+
+        if desired.as_ref() == current_lock {
+            continue;
+        }
+
+        match desired {
+            Some(lock) => {
+                commands.entity(entity).insert(lock);
+            }
+            None => {
+                commands.entity(entity).remove::<ActionLock>();
+            }
+        }
+    }
+}
+
+/// System (Startup): проблемы cancel таблицы (`data/cancel_table.ron`) → warning в лог
+pub fn validate_cancel_table(table: Res<CancelTable>) {
+    for issue in table.issues() {
+        crate::logger::log_warning(&format!("🚦 Cancel table: {}", issue));
+    }
+}
@@ -0,0 +1,38 @@
+//! Aim systems (reaction delay для ranged AI).
+
+use bevy::prelude::*;
+use crate::ai::AIState;
+use crate::combat::{AimReaction, AimSkill};
+
+/// System: AimReaction timer (смена цели → reaction delay заново)
+///
+/// - Combat { target } + новая цель → AimReaction::new(target, reaction_delay)
+/// - Та же цель → tick timer
+/// - Не в Combat → AimReaction удаляется
+pub fn update_aim_reaction(
+    mut query: Query<(Entity, &AIState, &AimSkill, Option<&mut AimReaction>)>,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, state, skill, reaction) in query.iter_mut() {
+        let AIState::Combat { target } = state else {
+            if reaction.is_some() {
+                commands.entity(entity).remove::<AimReaction>();
+            }
+            continue;
+        };
+
+        match reaction {
+            Some(mut reaction) if reaction.target == *target => {
+                reaction.timer = (reaction.timer - delta).max(0.0);
+            }
+            _ => {
+                commands
+                    .entity(entity)
+                    .insert(AimReaction::new(*target, skill.reaction_delay));
+            }
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! Blindness systems (flash exposure → Blinded, expiry).
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::components::Health;
+use crate::combat::{Blinded, FlashExposure, PlayerBlinded};
+use crate::ai::SpottedEnemies;
+use crate::player::Player;
+
+/// System: FlashExposure → Blinded (+ очистка SpottedEnemies, HUD событие для игрока)
+///
+/// - Экспозиция ниже `Blinded::MIN_EXPOSURE` игнорируется
+/// - Длительность = max_duration × exposure, сила = exposure
+/// - Повторная вспышка усиливает (refresh), не сокращает
+pub fn apply_flash_exposure(
+    mut exposure_events: EventReader<FlashExposure>,
+    mut targets: Query<(&Health, Option<&mut Blinded>, Option<&mut SpottedEnemies>, Has<Player>)>,
+    mut hud_events: EventWriter<PlayerBlinded>,
+    mut commands: Commands,
+) {
+    // Несколько вспышек в один тик → один insert
+    let mut new_blinds: HashMap<Entity, Blinded> = HashMap::new();
+
+    for exposure in exposure_events.read() {
+        if exposure.exposure < Blinded::MIN_EXPOSURE {
+            continue;
+        }
+
+        let Ok((health, blinded, spotted, is_player)) = targets.get_mut(exposure.target) else {
+            continue;
+        };
+
+        if !health.is_alive() {
+            continue;
+        }
+
+        let duration = exposure.max_duration * exposure.exposure;
+
+        match blinded {
+            Some(mut blinded) => blinded.refresh(duration, exposure.exposure),
+            None => {
+                new_blinds
+                    .entry(exposure.target)
+                    .and_modify(|blinded| blinded.refresh(duration, exposure.exposure))
+                    .or_insert_with(|| Blinded::new(duration, exposure.exposure));
+            }
+        }
+
+        // Ослеплённый теряет все цели (VisionCone не вернёт их до конца ослепления)
+        if let Some(mut spotted) = spotted {
+            if !spotted.enemies.is_empty() {
+                spotted.enemies.clear();
+            }
+        }
+
+        if is_player {
+            hud_events.write(PlayerBlinded {
+                entity: exposure.target,
+                intensity: exposure.exposure,
+                duration,
+            });
+        }
+
+        crate::logger::log(&format!(
+            "💥 Flash: {:?} blinded (exposure {:.2}, {:.1}s)",
+            exposure.target, exposure.exposure, duration
+        ));
+    }
+
+    for (entity, blinded) in new_blinds {
+        commands.entity(entity).insert(blinded);
+    }
+}
+
+/// System: тик Blinded → удаление по истечении
+pub fn update_blinded_states(
+    mut blinded_query: Query<(Entity, &mut Blinded)>,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut blinded) in blinded_query.iter_mut() {
+        blinded.remaining -= delta;
+
+        if blinded.remaining <= 0.0 {
+            commands.entity(entity).remove::<Blinded>();
+            crate::logger::log(&format!("👁️ {:?} recovered from blindness", entity));
+        }
+    }
+}
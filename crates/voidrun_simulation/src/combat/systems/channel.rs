@@ -0,0 +1,83 @@
+//! Channel systems (centralized interruption + completion).
+
+use bevy::prelude::*;
+use crate::components::Health;
+use crate::combat::{
+    ChannelCompleted, ChannelInterruptRules, ChannelInterrupted, Channeling, DamageDealt,
+};
+use crate::SimulationTick;
+
+/// System: DamageDealt → запись урона в Channeling → прерывание при превышении порога
+///
+/// Единая точка прерывания для всех channelled действий (reload, consumable, hack, cast).
+/// Урон суммируется за скользящее окно `ChannelInterruptRules::window_secs`;
+/// channel прерывается, когда сумма превышает `threshold(max HP)`.
+pub fn interrupt_channels_on_damage(
+    mut damage_events: EventReader<DamageDealt>,
+    mut channels: Query<(&mut Channeling, &Health)>,
+    mut interrupted_events: EventWriter<ChannelInterrupted>,
+    rules: Res<ChannelInterruptRules>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    let window_ticks = SimulationTick::ticks_for_secs(rules.window_secs);
+
+    for damage in damage_events.read() {
+        let Ok((mut channel, health)) = channels.get_mut(damage.target) else {
+            continue;
+        };
+
+        if !channel.interruptible {
+            continue;
+        }
+
+        channel.record_damage(tick.get(), damage.damage);
+
+        let window_damage = channel.damage_in_window(tick.get(), window_ticks);
+        let threshold = rules.threshold(health.max);
+        if window_damage <= threshold {
+            continue;
+        }
+
+        commands.entity(damage.target).remove::<Channeling>();
+        interrupted_events.write(ChannelInterrupted {
+            entity: damage.target,
+            kind: channel.kind,
+            attacker: damage.attacker,
+            window_damage,
+        });
+
+        crate::logger::log(&format!(
+            "✋ ECS: {:?} interrupted (entity: {:?}, {} dmg > {} threshold)",
+            channel.kind, damage.target, window_damage, threshold
+        ));
+
+        // Не записываем урон повторно в уже прерванный channel (несколько DamageDealt за тик)
+        channel.interruptible = false;
+    }
+}
+
+/// System: завершение channel (tick >= complete_at_tick → ChannelCompleted)
+pub fn update_channels(
+    query: Query<(Entity, &Channeling)>,
+    mut completed_events: EventWriter<ChannelCompleted>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    for (entity, channel) in query.iter() {
+        if !channel.is_complete_at(tick.get()) {
+            continue;
+        }
+
+        commands.entity(entity).remove::<Channeling>();
+        completed_events.write(ChannelCompleted {
+            entity,
+            kind: channel.kind,
+        });
+
+        crate::logger::log(&format!(
+            "✅ ECS: {:?} completed (entity: {:?})",
+            channel.kind, entity
+        ));
+    }
+}
@@ -1,8 +1,10 @@
 //! Damage calculation and death systems.
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
-use crate::components::{Health, Stamina};
-use crate::combat::{WeaponStats, DamageDealt, EntityDied, DamageSource, AppliedDamage};
+use crate::components::{EnergyShield, Health, Stamina};
+use crate::combat::{DamageDealt, EntityDied, DamageSource, AppliedDamage, Invulnerable, InvulnerableHit};
+use crate::SimulationTick;
 
 /// Компонент-маркер: entity мертв (Health <= 0)
 ///
@@ -21,29 +23,62 @@ pub struct DespawnAfter {
     pub despawn_time: f32,
 }
 
-/// Система: apply damage (placeholder для Godot-driven combat)
+/// SystemParam: проверка неуязвимости для `apply_damage`
 ///
-/// TODO: Будет читать GodotCombatEvent::WeaponHit когда Godot integration готов
-/// Сейчас: stub system для компиляции
+/// Один на все damage пути — ни один путь не проверяет `Invulnerable` сам.
+#[derive(SystemParam)]
+pub struct DamageGate<'w, 's> {
+    invulnerables: Query<'w, 's, &'static Invulnerable>,
+    tick: Res<'w, SimulationTick>,
+    invulnerable_hits: EventWriter<'w, InvulnerableHit>,
+}
+
+impl DamageGate<'_, '_> {
+    /// Цель неуязвима → InvulnerableHit (для VFX), урон игнорируется
+    fn blocks(&mut self, attacker: Entity, target: Entity) -> bool {
+        let Ok(invulnerable) = self.invulnerables.get(target) else {
+            return false;
+        };
+
+        if !invulnerable.is_active_at(self.tick.get()) {
+            return false;
+        }
+
+        self.invulnerable_hits.write(InvulnerableHit {
+            attacker,
+            target,
+            reason: invulnerable.reason,
+        });
+
+        crate::logger::log(&format!(
+            "🛡️ Damage ignored: {:?} invulnerable ({:?}), attacker {:?}",
+            target, invulnerable.reason, attacker
+        ));
+
+        true
+    }
+}
+
+/// Единая точка применения урона (melee, projectile, shield hits, падение, среда)
+///
+/// - Invulnerable цель (spawn protection, post-stagger grace) → `None`, урон не применён
+/// - Иначе щит / Health по правилам `DamageSource` → `Some(AppliedDamage)` для VFX
+///
+/// DamageDealt пишет вызывающая система (у неё impact point / normal).
 pub fn apply_damage(
-    mut _damage_dealt_events: EventWriter<DamageDealt>,
-    mut _entity_died_events: EventWriter<EntityDied>,
-    mut _targets: Query<(&mut Health, Option<&Stamina>)>,
-    _attackers: Query<(&WeaponStats, &Stamina)>,
-) {
-    // TODO: Читать GodotCombatEvent::WeaponHit events
-    // Godot AnimationTree trigger hitbox → WeaponHit { attacker, target } → apply_damage
-    //
-    // for event in godot_combat_events.read() {
-    //     match event {
-    //         GodotCombatEvent::WeaponHit { attacker, target } => {
-    //             apply_weapon_hit(*attacker, *target, &mut targets, &attackers, ...);
-    //         }
-    //     }
-    // }
-
-    // Stub для компиляции
-    // Реальная логика будет после Godot integration
+    gate: &mut DamageGate,
+    attacker: Entity,
+    target: Entity,
+    target_health: &mut Health,
+    target_shield: Option<&mut EnergyShield>,
+    damage: u32,
+    damage_source: DamageSource,
+) -> Option<AppliedDamage> {
+    if gate.blocks(attacker, target) {
+        return None;
+    }
+
+    Some(apply_damage_with_shield(target_health, target_shield, damage, damage_source))
 }
 
 /// Вычисляет final damage с модификаторами
@@ -75,7 +110,7 @@ pub fn calculate_damage(
     final_damage.round() as u32
 }
 
-/// Apply damage with shield absorption logic
+/// Apply damage with shield absorption logic (без проверки неуязвимости — только через `apply_damage`)
 ///
 /// Shield blocks ONLY Ranged damage (slow kinetic like melee bypasses shield).
 /// Returns AppliedDamage for VFX feedback.
@@ -84,9 +119,9 @@ pub fn calculate_damage(
 /// - Ranged damage: Shield absorbs if active, overflow goes to health
 /// - Melee damage: Bypasses shield completely (slow kinetic)
 /// - Environmental: Direct damage (TODO: future logic)
-pub fn apply_damage_with_shield(
-    target_health: &mut crate::Health,
-    target_shield: Option<&mut crate::components::EnergyShield>,
+fn apply_damage_with_shield(
+    target_health: &mut Health,
+    target_shield: Option<&mut EnergyShield>,
     damage: u32,
     damage_source: DamageSource,
 ) -> AppliedDamage {
@@ -132,9 +167,10 @@ pub fn apply_damage_with_shield(
 ///
 /// Tick shield energy regeneration после recharge_delay.
 /// Updates active state based on hysteresis logic (deactivate at 0%, reactivate at 50%).
+/// EMP (`EmpDisrupted`) блокирует recharge — щит остаётся пустым до конца импульса.
 /// Runs in FixedUpdate (64 Hz).
 pub fn shield_recharge_system(
-    mut shields: Query<&mut crate::components::EnergyShield>,
+    mut shields: Query<&mut crate::components::EnergyShield, Without<crate::combat::EmpDisrupted>>,
     time: Res<Time>,
 ) {
     for mut shield in shields.iter_mut() {
@@ -143,6 +179,41 @@ pub fn shield_recharge_system(
     }
 }
 
+/// Система: HP → 0 → EntityDied (один раз на смерть)
+///
+/// Убийца — attacker последнего DamageDealt по цели в этом тике (иначе `None`: падение, зона).
+/// Arena бойцы пропускаются: HP = 0 — нокаут, раунд их восстанавливает.
+/// CanBeDowned тоже: HP = 0 → Downed (`down_actors_at_zero_health`), смерть — после bleed-out / добивания.
+pub fn detect_deaths(
+    mut damage_events: EventReader<DamageDealt>,
+    actors: Query<
+        (Entity, &Health),
+        (
+            Changed<Health>,
+            Without<Dead>,
+            Without<crate::game_mode::ArenaFighter>,
+            Without<crate::combat::CanBeDowned>,
+        ),
+    >,
+    mut death_events: EventWriter<EntityDied>,
+) {
+    let attackers: std::collections::HashMap<Entity, Entity> = damage_events
+        .read()
+        .map(|event| (event.target, event.attacker))
+        .collect();
+
+    for (entity, health) in actors.iter() {
+        if health.is_alive() {
+            continue;
+        }
+
+        death_events.write(EntityDied {
+            entity,
+            killer: attackers.get(&entity).copied(),
+        });
+    }
+}
+
 /// Система: отключение AI при смерти
 ///
 /// Убирает AIState и MovementCommand компоненты у мертвых entities.
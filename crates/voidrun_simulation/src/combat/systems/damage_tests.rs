@@ -2,10 +2,10 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::components::Stamina;
+    use crate::components::{Health, Stamina};
     use crate::combat::{DamageDealt, EntityDied, DamageSource, AppliedDamage};
     use bevy::prelude::*;
-    use super::super::damage::calculate_damage;
+    use super::super::damage::{apply_damage, calculate_damage, DamageGate};
 
     #[test]
     fn test_damage_calculation_full_stamina() {
@@ -72,4 +72,87 @@ mod tests {
 
         assert!(event.killer.is_some());
     }
+
+    #[test]
+    fn test_invulnerable_window() {
+        use crate::combat::{Invulnerable, InvulnerabilityReason};
+        use crate::SimulationTick;
+
+        let tick = SimulationTick(100);
+        let invulnerable = Invulnerable {
+            until_tick: tick.after_secs(0.5), // 0.5s × 60Hz = 30 ticks
+            reason: InvulnerabilityReason::StaggerRecovery,
+        };
+
+        assert_eq!(invulnerable.until_tick, 130);
+        assert!(invulnerable.is_active_at(100));
+        assert!(invulnerable.is_active_at(129));
+        assert!(!invulnerable.is_active_at(130)); // until_tick exclusive
+    }
+
+    /// Каждой цели по 30 урона Melee через единую точку `apply_damage`
+    fn hit_every_target(
+        mut targets: Query<(Entity, &mut Health)>,
+        mut gate: DamageGate,
+    ) -> Vec<(Entity, Option<AppliedDamage>)> {
+        let mut results: Vec<_> = targets
+            .iter_mut()
+            .map(|(entity, mut health)| {
+                let applied = apply_damage(
+                    &mut gate,
+                    Entity::PLACEHOLDER,
+                    entity,
+                    &mut health,
+                    None,
+                    30,
+                    DamageSource::Melee,
+                );
+                (entity, applied)
+            })
+            .collect();
+        results.sort_by_key(|(entity, _)| *entity);
+        results
+    }
+
+    #[test]
+    fn test_apply_damage_respects_invulnerability() {
+        use bevy::ecs::system::RunSystemOnce;
+        use crate::combat::{Invulnerable, InvulnerabilityReason, InvulnerableHit};
+        use crate::SimulationTick;
+
+        let mut world = World::new();
+        world.insert_resource(SimulationTick(100));
+        world.init_resource::<Events<InvulnerableHit>>();
+
+        let protected = world
+            .spawn((
+                Health::new(100),
+                Invulnerable { until_tick: 130, reason: InvulnerabilityReason::SpawnProtection },
+            ))
+            .id();
+        let expired = world
+            .spawn((
+                Health::new(100),
+                Invulnerable { until_tick: 100, reason: InvulnerabilityReason::StaggerRecovery },
+            ))
+            .id();
+        let plain = world.spawn(Health::new(100)).id();
+
+        let results = world.run_system_once(hit_every_target).unwrap();
+        let applied_to = |target: Entity| results.iter().find(|(entity, _)| *entity == target).unwrap().1;
+
+        assert_eq!(applied_to(protected), None);
+        assert_eq!(applied_to(expired), Some(AppliedDamage::Direct));
+        assert_eq!(applied_to(plain), Some(AppliedDamage::Direct));
+
+        assert_eq!(world.get::<Health>(protected).unwrap().current, 100);
+        assert_eq!(world.get::<Health>(expired).unwrap().current, 70);
+        assert_eq!(world.get::<Health>(plain).unwrap().current, 70);
+
+        // Заблокированный удар → InvulnerableHit для VFX
+        let hits: Vec<_> = world.resource_mut::<Events<InvulnerableHit>>().drain().collect();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].target, protected);
+        assert_eq!(hits[0].reason, InvulnerabilityReason::SpawnProtection);
+    }
 }
@@ -0,0 +1,196 @@
+//! Downed systems (0 HP → Downed, bleed-out, добивание, поднятие союзником).
+
+use bevy::prelude::*;
+use crate::combat::{
+    ActionKind, ActionLock, ActionPhase, ActorDowned, ActorRevived, CanBeDowned, CancelTable, ChannelCompleted,
+    ChannelInterrupted, ChannelKind, Channeling, DamageDealt, Dead, Downed, EntityDied, FiringState, MeleeAttackState,
+    ParryState, ReviveIntent, Reviving, REVIVE_DURATION, REVIVE_HEALTH_FRACTION, REVIVE_RANGE,
+};
+use crate::ai::{AIConfig, AIState};
+use crate::components::{Actor, Health};
+use crate::movement::{Sprinting, Stance};
+use crate::{SimulationTick, StrategicPosition};
+
+/// System: HP = 0 у CanBeDowned → Downed вместо EntityDied
+///
+/// Актор ложится (Stance::Prone — только ползком), текущие действия сбрасываются.
+/// `detect_deaths` таких акторов пропускает — смерть пишет `update_downed_states`.
+pub fn down_actors_at_zero_health(
+    mut damage_events: EventReader<DamageDealt>,
+    actors: Query<(Entity, &Health, &CanBeDowned), (Changed<Health>, Without<Downed>, Without<Dead>)>,
+    mut downed_events: EventWriter<ActorDowned>,
+    mut commands: Commands,
+) {
+    let attackers: std::collections::HashMap<Entity, Entity> = damage_events
+        .read()
+        .map(|event| (event.target, event.attacker))
+        .collect();
+
+    for (entity, health, can_be_downed) in actors.iter() {
+        if health.is_alive() {
+            continue;
+        }
+
+        let attacker = attackers.get(&entity).copied();
+        commands
+            .entity(entity)
+            .remove::<(MeleeAttackState, ParryState, Channeling, Reviving, Sprinting, FiringState)>()
+            .insert((
+                Downed::new(can_be_downed.bleed_out_secs, attacker),
+                Stance::Prone,
+                ActionLock::new(ActionKind::Downed, ActionPhase::Active),
+            ));
+        downed_events.write(ActorDowned { entity, attacker });
+
+        crate::logger::log(&format!(
+            "🩸 {:?} downed (attacker: {:?}, bleed-out in {:.0}s)",
+            entity, attacker, can_be_downed.bleed_out_secs
+        ));
+    }
+}
+
+/// System: bleed-out таймер; истёк или добит (Execution) → EntityDied
+pub fn update_downed_states(
+    mut downed: Query<(Entity, &mut Downed)>,
+    mut death_events: EventWriter<EntityDied>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    for (entity, mut state) in downed.iter_mut() {
+        state.tick(time.delta_secs());
+        if !state.should_die() {
+            continue;
+        }
+
+        commands.entity(entity).remove::<Downed>();
+        death_events.write(EntityDied {
+            entity,
+            killer: state.killer(),
+        });
+
+        crate::logger::log(&format!(
+            "💀 {:?} {} (killer: {:?})",
+            entity,
+            if state.finished_by.is_some() { "finished off" } else { "bled out" },
+            state.killer()
+        ));
+    }
+}
+
+/// System: AI вне боя рядом с раненым союзником → ReviveIntent (игрок — по F, Godot input)
+pub fn ai_revive_downed_allies(
+    actors: Query<
+        (Entity, &Actor, &AIState, &StrategicPosition),
+        (With<AIConfig>, Without<Channeling>, Without<Downed>, Without<Dead>),
+    >,
+    downed: Query<(&Actor, &StrategicPosition), With<Downed>>,
+    mut intents: EventWriter<ReviveIntent>,
+) {
+    for (entity, actor, state, position) in actors.iter() {
+        if matches!(state, AIState::Combat { .. }) {
+            continue;
+        }
+
+        let pos = position.to_world_position(0.5);
+        let ally_in_reach = downed.iter().any(|(ally, ally_pos)| {
+            ally.faction_id == actor.faction_id && ally_pos.to_world_position(0.5).distance(pos) <= REVIVE_RANGE
+        });
+        if ally_in_reach {
+            intents.write(ReviveIntent { reviver: entity });
+        }
+    }
+}
+
+/// System: ReviveIntent → Channeling(Revive) над ближайшим раненым союзником (та же фракция)
+pub fn start_revives(
+    mut intents: EventReader<ReviveIntent>,
+    revivers: Query<(&Actor, &StrategicPosition, Option<&ActionLock>), (Without<Channeling>, Without<Downed>, Without<Dead>)>,
+    downed: Query<(Entity, &Actor, &StrategicPosition), With<Downed>>,
+    being_revived: Query<&Reviving>,
+    cancel_table: Res<CancelTable>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((actor, position, lock)) = revivers.get(intent.reviver) else {
+            continue;
+        };
+        if !ActionLock::permits(lock, ActionKind::Revive, &cancel_table) {
+            continue;
+        }
+
+        let reviver_pos = position.to_world_position(0.5);
+        let nearest = downed
+            .iter()
+            .filter(|(entity, ally, _)| {
+                ally.faction_id == actor.faction_id
+                    && *entity != intent.reviver
+                    && !being_revived.iter().any(|reviving| reviving.target == *entity)
+            })
+            .map(|(entity, _, pos)| (entity, pos.to_world_position(0.5).distance(reviver_pos)))
+            .filter(|(_, distance)| *distance <= REVIVE_RANGE)
+            .min_by(|(_, a), (_, b)| a.total_cmp(b));
+        let Some((target, _)) = nearest else {
+            continue;
+        };
+
+        commands.entity(intent.reviver).insert((
+            Channeling::new(ChannelKind::Revive, tick.after_secs(REVIVE_DURATION)),
+            ActionLock::new(ActionKind::Revive, ActionPhase::Active),
+            Reviving { target },
+        ));
+
+        crate::logger::log(&format!("🩹 {:?} started reviving {:?}", intent.reviver, target));
+    }
+}
+
+/// System: завершённый Revive channel → союзник встаёт с долей HP; прерванный → поднятие сброшено
+pub fn complete_revives(
+    mut completed_events: EventReader<ChannelCompleted>,
+    mut interrupted_events: EventReader<ChannelInterrupted>,
+    revivers: Query<&Reviving>,
+    mut downed: Query<&mut Health, With<Downed>>,
+    mut revived_events: EventWriter<ActorRevived>,
+    mut commands: Commands,
+) {
+    for completed in completed_events.read() {
+        if completed.kind != ChannelKind::Revive {
+            continue;
+        }
+        let Ok(reviving) = revivers.get(completed.entity) else {
+            continue;
+        };
+
+        commands.entity(completed.entity).remove::<Reviving>();
+
+        // Успел истечь кровью / добит — поднимать некого
+        let Ok(mut health) = downed.get_mut(reviving.target) else {
+            continue;
+        };
+
+        let restored = ((health.max as f32 * REVIVE_HEALTH_FRACTION) as u32).max(1);
+        health.current = restored.min(health.max);
+        commands
+            .entity(reviving.target)
+            .remove::<(Downed, ActionLock)>()
+            .insert(Stance::Standing);
+        revived_events.write(ActorRevived {
+            entity: reviving.target,
+            reviver: completed.entity,
+        });
+
+        crate::logger::log(&format!(
+            "🩹 {:?} revived {:?} ({} HP)",
+            completed.entity, reviving.target, health.current
+        ));
+    }
+
+    for interrupted in interrupted_events.read() {
+        if interrupted.kind != ChannelKind::Revive {
+            continue;
+        }
+        if revivers.contains(interrupted.entity) {
+            commands.entity(interrupted.entity).remove::<Reviving>();
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Weapon durability systems (износ, клин, устранение клина).
+
+use bevy::prelude::*;
+use rand::Rng;
+use crate::ai::AIConfig;
+use crate::combat::{
+    jam_chance, ActionKind, ActionLock, ActionPhase, CancelTable, ChannelCompleted, ChannelKind, Channeling,
+    ClearJamIntent, DamageDealt, DamageSource, JamCleared, WeaponFired, WeaponJammed, WeaponStats,
+    CLEAR_JAM_DURATION, WEAR_PER_HIT, WEAR_PER_SHOT,
+};
+use crate::components::EquippedWeapons;
+use crate::{DeterministicRng, SimulationTick};
+
+/// System: выстрелы / попадания в ближнем бою → износ активного оружия
+///
+/// Выстрел оружием ниже `JAM_THRESHOLD` — бросок на клин (`jam_chance`).
+pub fn wear_weapons(
+    mut fired_events: EventReader<WeaponFired>,
+    mut damage_events: EventReader<DamageDealt>,
+    mut actors: Query<(&mut EquippedWeapons, &WeaponStats)>,
+    mut rng: ResMut<DeterministicRng>,
+    mut jammed_events: EventWriter<WeaponJammed>,
+) {
+    for fired in fired_events.read() {
+        let Ok((mut weapons, stats)) = actors.get_mut(fired.shooter) else {
+            continue;
+        };
+        let Some(weapon) = weapons.get_active_weapon_mut() else {
+            continue;
+        };
+
+        weapon.wear(WEAR_PER_SHOT);
+        if !stats.is_ranged() || weapon.jammed {
+            continue;
+        }
+
+        if rng.rng.gen::<f32>() < jam_chance(weapon.durability) {
+            weapon.jammed = true;
+            jammed_events.write(WeaponJammed {
+                entity: fired.shooter,
+                durability: weapon.durability,
+            });
+            crate::logger::log(&format!(
+                "🔧 {:?} weapon jammed ({:.0}% durability)",
+                fired.shooter,
+                weapon.durability * 100.0
+            ));
+        }
+    }
+
+    for dealt in damage_events.read() {
+        if dealt.source != DamageSource::Melee {
+            continue;
+        }
+        let Ok((mut weapons, _)) = actors.get_mut(dealt.attacker) else {
+            continue;
+        };
+        if let Some(weapon) = weapons.get_active_weapon_mut() {
+            weapon.wear(WEAR_PER_HIT);
+        }
+    }
+}
+
+/// System: AI с заклинившим оружием → ClearJamIntent (игрок — по нажатию огня, Godot input)
+pub fn ai_clear_weapon_jams(
+    actors: Query<(Entity, &EquippedWeapons), (With<AIConfig>, Without<Channeling>)>,
+    mut intents: EventWriter<ClearJamIntent>,
+) {
+    for (entity, weapons) in actors.iter() {
+        if weapons.get_active_weapon().is_some_and(|weapon| weapon.jammed) {
+            intents.write(ClearJamIntent { entity });
+        }
+    }
+}
+
+/// System: ClearJamIntent → Channeling(ClearJam) (занят / не заклинило → игнор)
+pub fn start_clear_jam(
+    mut intents: EventReader<ClearJamIntent>,
+    actors: Query<(&EquippedWeapons, Option<&ActionLock>, Has<Channeling>)>,
+    cancel_table: Res<CancelTable>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((weapons, lock, channeling)) = actors.get(intent.entity) else {
+            continue;
+        };
+        if !weapons.get_active_weapon().is_some_and(|weapon| weapon.jammed) {
+            continue;
+        }
+        if channeling || !ActionLock::permits(lock, ActionKind::Reload, &cancel_table) {
+            continue;
+        }
+
+        commands.entity(intent.entity).insert((
+            Channeling::new(ChannelKind::ClearJam, tick.after_secs(CLEAR_JAM_DURATION)),
+            ActionLock::new(ActionKind::Reload, ActionPhase::Active),
+        ));
+    }
+}
+
+/// System: завершённый ClearJam channel → клин снят (прерванный — оружие остаётся заклинившим)
+pub fn complete_clear_jam(
+    mut completed_events: EventReader<ChannelCompleted>,
+    mut actors: Query<&mut EquippedWeapons>,
+    mut cleared_events: EventWriter<JamCleared>,
+) {
+    for completed in completed_events.read() {
+        if completed.kind != ChannelKind::ClearJam {
+            continue;
+        }
+        let Ok(mut weapons) = actors.get_mut(completed.entity) else {
+            continue;
+        };
+        let Some(weapon) = weapons.get_active_weapon_mut() else {
+            continue;
+        };
+
+        weapon.jammed = false;
+        cleared_events.write(JamCleared { entity: completed.entity });
+        crate::logger::log(&format!("🔧 {:?} cleared weapon jam", completed.entity));
+    }
+}
@@ -0,0 +1,100 @@
+//! EMP systems (EmpDetonated → EmpDisrupted, щит заблокирован, электроника оглушена; expiry).
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::components::{EnergyShield, Health};
+use crate::combat::{EmpDetonated, EmpDisrupted, Electronic, MeleeAttackState, StaggerState};
+use crate::StrategicPosition;
+
+/// System: EmpDetonated → EmpDisrupted
+///
+/// - Живые акторы в радиусе (горизонтальная дистанция), кроме источника
+/// - Длительность: `EmpDisrupted::duration_at` (к краю радиуса короче), повторный импульс — refresh
+/// - EnergyShield → 0 энергии, неактивен (recharge стоит, пока висит EmpDisrupted)
+/// - `Electronic` → StaggerState на длительность импульса (текущая атака прерывается)
+pub fn apply_emp_detonations(
+    mut detonations: EventReader<EmpDetonated>,
+    mut targets: Query<(
+        Entity,
+        &StrategicPosition,
+        &Health,
+        Option<&mut EnergyShield>,
+        Option<&mut EmpDisrupted>,
+        Has<Electronic>,
+    )>,
+    mut commands: Commands,
+) {
+    // Несколько импульсов в один тик → один insert
+    let mut new_disruptions: HashMap<Entity, EmpDisrupted> = HashMap::new();
+
+    for detonation in detonations.read() {
+        for (entity, position, health, shield, disrupted, electronic) in targets.iter_mut() {
+            if entity == detonation.source || !health.is_alive() {
+                continue;
+            }
+
+            let actor_pos = position.to_world_position(0.0);
+            let distance = Vec2::new(actor_pos.x - detonation.position.x, actor_pos.z - detonation.position.z).length();
+            let duration = EmpDisrupted::duration_at(distance, detonation.radius, detonation.duration);
+            if duration <= 0.0 {
+                continue;
+            }
+
+            match disrupted {
+                Some(mut disrupted) => disrupted.refresh(duration),
+                None => {
+                    new_disruptions
+                        .entry(entity)
+                        .and_modify(|disrupted| disrupted.refresh(duration))
+                        .or_insert_with(|| EmpDisrupted::new(duration));
+                }
+            }
+
+            if let Some(mut shield) = shield {
+                shield.current_energy = 0.0;
+                shield.update_active_state();
+            }
+
+            if electronic {
+                commands
+                    .entity(entity)
+                    .insert(StaggerState::new(duration, detonation.source))
+                    .remove::<MeleeAttackState>();
+            }
+
+            crate::logger::log(&format!(
+                "⚡ EMP: {:?} disrupted ({:.1}s{})",
+                entity,
+                duration,
+                if electronic { ", stunned" } else { "" }
+            ));
+        }
+    }
+
+    for (entity, disrupted) in new_disruptions {
+        commands.entity(entity).insert(disrupted);
+    }
+}
+
+/// System: тик EmpDisrupted → удаление по истечении
+///
+/// Щит после импульса заряжается с обычной задержкой (`recharge_delay`), как после урона.
+pub fn update_emp_disruptions(
+    mut disrupted_query: Query<(Entity, &mut EmpDisrupted, Option<&mut EnergyShield>)>,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut disrupted, shield) in disrupted_query.iter_mut() {
+        disrupted.remaining -= delta;
+
+        if disrupted.remaining <= 0.0 {
+            if let Some(mut shield) = shield {
+                shield.recharge_timer = shield.recharge_delay;
+            }
+            commands.entity(entity).remove::<EmpDisrupted>();
+            crate::logger::log(&format!("⚡ {:?} recovered from EMP", entity));
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Fall damage systems (Landed → урон + stagger при жёстком приземлении).
+
+use bevy::prelude::*;
+use crate::components::Health;
+use crate::combat::{
+    apply_damage, DamageDealt, DamageGate, DamageSource, FallDamageConfig, MeleeAttackState, StaggerState,
+};
+use crate::movement::Landed;
+use crate::StrategicPosition;
+
+/// System: Landed → fall damage
+///
+/// - `impact_speed` ниже `FallDamageConfig::safe_speed` игнорируется
+/// - Урон `DamageSource::Environmental` (щит не защищает), attacker = сам актор
+/// - Жёсткое приземление → StaggerState (текущая атака прерывается)
+/// - Invulnerable (spawn protection и т.п.) блокирует урон в `apply_damage`
+pub fn apply_fall_damage(
+    mut landed_events: EventReader<Landed>,
+    mut targets: Query<(&mut Health, Option<&StrategicPosition>, Has<StaggerState>)>,
+    mut damage_events: EventWriter<DamageDealt>,
+    mut damage_gate: DamageGate,
+    config: Res<FallDamageConfig>,
+    mut commands: Commands,
+) {
+    for landed in landed_events.read() {
+        let damage = config.damage_for(landed.impact_speed);
+        if damage == 0 {
+            continue;
+        }
+
+        let Ok((mut health, position, staggered)) = targets.get_mut(landed.entity) else {
+            continue;
+        };
+
+        if !health.is_alive() {
+            continue;
+        }
+
+        let Some(applied) = apply_damage(
+            &mut damage_gate,
+            landed.entity,
+            landed.entity,
+            &mut health,
+            None,
+            damage,
+            DamageSource::Environmental,
+        ) else {
+            continue;
+        };
+
+        damage_events.write(DamageDealt {
+            attacker: landed.entity,
+            target: landed.entity,
+            damage,
+            source: DamageSource::Environmental,
+            applied_damage: applied,
+            impact_point: position.map(|pos| pos.to_world_position(0.0)).unwrap_or(Vec3::ZERO),
+            impact_normal: Vec3::Y,
+        });
+
+        // Жёсткое приземление → сбит с ног (мёртвым stagger не нужен)
+        if config.is_hard_landing(landed.impact_speed) && !staggered && health.is_alive() {
+            commands
+                .entity(landed.entity)
+                .insert(StaggerState::new(config.stagger_duration, landed.entity))
+                .remove::<MeleeAttackState>();
+        }
+
+        crate::logger::log(&format!(
+            "🪂 Fall damage: {:?} landed at {:.1} m/s → {} dmg (HP: {})",
+            landed.entity, landed.impact_speed, damage, health.current
+        ));
+    }
+}
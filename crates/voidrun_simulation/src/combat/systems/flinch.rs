@@ -0,0 +1,145 @@
+//! Flinch systems (pain reactions scaled by damage).
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::components::Health;
+use crate::combat::{
+    Channeling, DamageDealt, FlinchConfig, FlinchKind, FlinchState, FlinchTriggered,
+    KnockdownGetUp, KnockdownPhase, KnockdownState, MeleeAttackState, ParryDelayTimer, ParryState,
+    PoiseHit, StaggerState,
+};
+
+/// System: DamageDealt → flinch reaction
+///
+/// Классифицирует удар через `FlinchConfig::classify` (процент от max HP):
+/// - Light: только FlinchTriggered event (действие продолжается)
+/// - Heavy: FlinchTriggered + FlinchState, прерывает атаку/парирование
+/// - Knockdown: FlinchTriggered + KnockdownState, прерывает всё (включая channel/stagger)
+///
+/// PoiseHit того же удара (Bash) прибавляется к урону — удар прикладом сбивает сильнее.
+///
+/// Мёртвые не вздрагивают (Health == 0). Уже лежащий не сбивается повторно.
+pub fn apply_flinch_on_damage(
+    mut damage_events: EventReader<DamageDealt>,
+    mut poise_events: EventReader<PoiseHit>,
+    mut flinch_events: EventWriter<FlinchTriggered>,
+    targets: Query<(&Health, &FlinchConfig, Option<&KnockdownState>)>,
+    mut commands: Commands,
+) {
+    let poise: HashMap<(Entity, Entity), u32> = poise_events
+        .read()
+        .map(|hit| ((hit.attacker, hit.target), hit.poise_damage))
+        .collect();
+
+    for damage in damage_events.read() {
+        let Ok((health, config, knockdown)) = targets.get(damage.target) else {
+            continue;
+        };
+
+        // Лежит/встаёт — анимация knockdown не перебивается
+        if knockdown.is_some() {
+            continue;
+        }
+
+        if !health.is_alive() {
+            continue;
+        }
+
+        let poise_damage = poise.get(&(damage.attacker, damage.target)).copied().unwrap_or(0);
+        let Some(kind) = config.classify(damage.damage + poise_damage, health.max) else {
+            continue;
+        };
+
+        match kind {
+            FlinchKind::Light => {}
+            FlinchKind::Heavy => {
+                // Mini-stagger: прерываем текущие боевые действия
+                commands
+                    .entity(damage.target)
+                    .insert(FlinchState::new(config.heavy_duration, damage.attacker))
+                    .remove::<MeleeAttackState>()
+                    .remove::<ParryState>()
+                    .remove::<ParryDelayTimer>();
+            }
+            FlinchKind::Knockdown => {
+                // Падение: прерываем всё, включая channel и flinch/stagger
+                commands
+                    .entity(damage.target)
+                    .insert(KnockdownState::new(
+                        config.knockdown_duration,
+                        config.getup_duration,
+                        damage.attacker,
+                    ))
+                    .remove::<MeleeAttackState>()
+                    .remove::<ParryState>()
+                    .remove::<ParryDelayTimer>()
+                    .remove::<Channeling>()
+                    .remove::<FlinchState>()
+                    .remove::<StaggerState>();
+            }
+        }
+
+        flinch_events.write(FlinchTriggered {
+            entity: damage.target,
+            source: damage.attacker,
+            kind,
+            hit_direction: damage.impact_normal,
+        });
+
+        crate::logger::log(&format!(
+            "😣 ECS: {:?} flinch (entity: {:?}, damage: {}/{})",
+            kind, damage.target, damage.damage, health.max
+        ));
+    }
+}
+
+/// System: Update heavy flinch states (tick timers, remove expired).
+pub fn update_flinch_states(
+    mut query: Query<(Entity, &mut FlinchState)>,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut flinch) in query.iter_mut() {
+        flinch.timer -= delta;
+
+        if !flinch.is_flinching() {
+            commands.entity(entity).remove::<FlinchState>();
+            crate::logger::log(&format!("✅ ECS: Flinch ended (entity: {:?})", entity));
+        }
+    }
+}
+
+/// System: Update knockdown states (Down → GettingUp → removed).
+///
+/// При переходе в GettingUp генерирует `KnockdownGetUp` (Godot get-up анимация).
+pub fn update_knockdown_states(
+    mut query: Query<(Entity, &mut KnockdownState)>,
+    mut getup_events: EventWriter<KnockdownGetUp>,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut knockdown) in query.iter_mut() {
+        knockdown.timer -= delta;
+
+        if knockdown.timer > 0.0 {
+            continue;
+        }
+
+        match knockdown.phase {
+            KnockdownPhase::Down => {
+                knockdown.phase = KnockdownPhase::GettingUp;
+                knockdown.timer = knockdown.getup_duration;
+                getup_events.write(KnockdownGetUp { entity });
+                crate::logger::log(&format!("🧍 ECS: Getting up (entity: {:?})", entity));
+            }
+            KnockdownPhase::GettingUp => {
+                commands.entity(entity).remove::<KnockdownState>();
+                crate::logger::log(&format!("✅ ECS: Knockdown ended (entity: {:?})", entity));
+            }
+        }
+    }
+}
@@ -0,0 +1,84 @@
+//! Invulnerability systems (spawn protection, post-stagger grace, expiry).
+
+use bevy::prelude::*;
+use crate::components::Actor;
+use crate::combat::{
+    Invulnerable, InvulnerabilityReason, InvulnerabilityConfig, StaggerState,
+};
+use crate::SimulationTick;
+
+/// System: spawn protection для новых акторов (Added<Actor>)
+///
+/// Не перезаписывает уже выданную неуязвимость (например, spawn с custom окном).
+pub fn grant_spawn_protection(
+    new_actors: Query<Entity, (Added<Actor>, Without<Invulnerable>)>,
+    config: Res<InvulnerabilityConfig>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    if config.spawn_protection_secs <= 0.0 {
+        return;
+    }
+
+    for entity in new_actors.iter() {
+        commands.entity(entity).insert(Invulnerable {
+            until_tick: tick.after_secs(config.spawn_protection_secs),
+            reason: InvulnerabilityReason::SpawnProtection,
+        });
+
+        crate::logger::log(&format!(
+            "🛡️ ECS: Spawn protection {:.1}s (entity: {:?})",
+            config.spawn_protection_secs, entity
+        ));
+    }
+}
+
+/// System: post-stagger grace (StaggerState removed → Invulnerable)
+///
+/// Предотвращает stunlock: после выхода из stagger актор не может
+/// быть сразу же снова застаггерен/добит комбо.
+pub fn grant_post_stagger_grace(
+    mut stagger_removed: RemovedComponents<StaggerState>,
+    actors: Query<(), With<Actor>>,
+    config: Res<InvulnerabilityConfig>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    for entity in stagger_removed.read() {
+        if config.post_stagger_grace_secs <= 0.0 {
+            continue;
+        }
+
+        // Entity мог быть despawned в том же тике
+        if actors.get(entity).is_err() {
+            continue;
+        }
+
+        commands.entity(entity).insert(Invulnerable {
+            until_tick: tick.after_secs(config.post_stagger_grace_secs),
+            reason: InvulnerabilityReason::StaggerRecovery,
+        });
+
+        crate::logger::log(&format!(
+            "🛡️ ECS: Post-stagger grace {:.2}s (entity: {:?})",
+            config.post_stagger_grace_secs, entity
+        ));
+    }
+}
+
+/// System: удаление истёкших Invulnerable окон
+pub fn expire_invulnerability(
+    query: Query<(Entity, &Invulnerable)>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    for (entity, invulnerable) in query.iter() {
+        if !invulnerable.is_active_at(tick.get()) {
+            commands.entity(entity).remove::<Invulnerable>();
+            crate::logger::log(&format!(
+                "✅ ECS: Invulnerability ended ({:?}, entity: {:?})",
+                invulnerable.reason, entity
+            ));
+        }
+    }
+}
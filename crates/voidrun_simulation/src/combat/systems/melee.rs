@@ -1,23 +1,125 @@
 //! Melee combat systems (strategic layer logic).
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use crate::components::{Health, Stamina};
 use crate::combat::{
     DamageDealt, MeleeAttackStarted, MeleeHit, ParryIntent,
     MeleeAttackState, AttackPhase, ParryState, ParryPhase, StaggerState, ParryDelayTimer,
-    WeaponStats,
+    WeaponStats, DamageGate, apply_damage,
+    ActionKind, ActionLock, ActionPhase, CancelTable, MeleeTradeRule,
+    KnockdownState, MeleeAttackType, MeleeAttackIntent, MeleeTimings, MeleeCleave, QuickMeleeIntent, PoiseHit,
+    ShieldBashIntent, Downed, EXECUTION_DAMAGE_MULTIPLIER, BASH_POISE_DAMAGE, SHIELD_BASH_POISE_DAMAGE, SHIELD_BASH_ENERGY_COST,
 };
+use crate::SimulationTick;
+use std::collections::{HashMap, HashSet};
+
+/// System: QuickMeleeIntent → MeleeAttackIntent(Bash)
+///
+/// Удар прикладом только с ranged оружием в руках (с melee оружием — обычная атака).
+/// Дальше — обычный melee pipeline: Godot валидация → MeleeAttackStarted → ActionLock.
+pub fn convert_quick_melee_intents(
+    mut quick_events: EventReader<QuickMeleeIntent>,
+    weapons: Query<&WeaponStats>,
+    mut intent_events: EventWriter<MeleeAttackIntent>,
+) {
+    for quick in quick_events.read() {
+        let Ok(weapon) = weapons.get(quick.attacker) else {
+            continue;
+        };
+        if !weapon.is_ranged() {
+            continue;
+        }
+
+        intent_events.write(MeleeAttackIntent {
+            attacker: quick.attacker,
+            attack_type: MeleeAttackType::Bash,
+        });
+    }
+}
+
+/// System: ShieldBashIntent → MeleeAttackIntent(ShieldBash)
+///
+/// Только с активным EnergyShield, где энергии хватает на `SHIELD_BASH_ENERGY_COST`
+/// (списывается в `start_melee_attacks`). Дальше — обычный melee pipeline.
+pub fn convert_shield_bash_intents(
+    mut bash_events: EventReader<ShieldBashIntent>,
+    shields: Query<&crate::components::EnergyShield>,
+    mut intent_events: EventWriter<MeleeAttackIntent>,
+) {
+    for bash in bash_events.read() {
+        let Ok(shield) = shields.get(bash.attacker) else {
+            continue;
+        };
+        if !shield.can_spend(SHIELD_BASH_ENERGY_COST) {
+            crate::logger::log(&format!(
+                "🛡️ ECS: Shield bash denied (attacker: {:?}, energy: {:.0}, active: {})",
+                bash.attacker,
+                shield.current_energy,
+                shield.is_active()
+            ));
+            continue;
+        }
+
+        intent_events.write(MeleeAttackIntent {
+            attacker: bash.attacker,
+            attack_type: MeleeAttackType::ShieldBash,
+        });
+    }
+}
 
 // REMOVED: ai_melee_attack_intent
 // Replaced by unified ai_combat_decision_main_thread system (see ai_combat_decision.rs)
 // That system handles both attack AND parry decisions to prevent race conditions.
 
+/// SystemParam: может ли актор начать атаку (для `start_melee_attacks`)
+///
+/// ActionLock + CancelTable; ShieldBash дополнительно платит энергией щита.
+#[derive(SystemParam)]
+pub struct MeleeStartGate<'w, 's> {
+    shields: Query<'w, 's, &'static mut crate::components::EnergyShield>,
+    locks: Query<'w, 's, &'static ActionLock>,
+    cancel_table: Res<'w, CancelTable>,
+    tick: Res<'w, SimulationTick>,
+}
+
+impl MeleeStartGate<'_, '_> {
+    /// Action arbitration: занят (parry/stagger/channel/active attack) → отказ
+    fn permits(&self, attacker: Entity) -> bool {
+        let lock = self.locks.get(attacker).ok();
+        if ActionLock::permits(lock, ActionKind::MeleeAttack, &self.cancel_table) {
+            return true;
+        }
+
+        crate::logger::log(&format!(
+            "🔒 ECS: Melee attack rejected (attacker: {:?}, busy: {:?})",
+            attacker,
+            lock.map(|lock| lock.action)
+        ));
+        false
+    }
+
+    /// Shield bash: энергия щита вместо stamina (могла уйти под огнём после intent)
+    fn spend_bash_energy(&mut self, attacker: Entity) -> bool {
+        let Ok(mut shield) = self.shields.get_mut(attacker) else {
+            return false;
+        };
+        if !shield.can_spend(SHIELD_BASH_ENERGY_COST) {
+            return false;
+        }
+        shield.take_damage(SHIELD_BASH_ENERGY_COST);
+        shield.update_active_state();
+        true
+    }
+}
+
 /// System: Start melee attacks (process MeleeAttackStarted events).
 ///
 /// When Godot approves attack (tactical validation passed):
-/// - Adds `MeleeAttackState` component (phase = Windup)
+/// - Checks `ActionLock` + `CancelTable` (занятый актор не начинает атаку)
+/// - Adds `MeleeAttackState` component (phase = Windup) + ActionLock
 /// - Starts weapon cooldown
-/// - Consumes stamina
+/// - Consumes stamina (ShieldBash — энергию щита; щит успел разрядиться → отказ)
 ///
 /// **CHANGED:** No longer generates telegraph events (handled by `detect_melee_windups_main_thread`).
 pub fn start_melee_attacks(
@@ -25,12 +127,26 @@ pub fn start_melee_attacks(
     mut commands: Commands,
     mut weapons: Query<&mut WeaponStats>,
     mut staminas: Query<&mut Stamina>,
+    mut gate: MeleeStartGate,
 ) {
     for event in started_events.read() {
-        // Add MeleeAttackState (phase = Windup)
-        commands.entity(event.attacker).insert(
-            MeleeAttackState::new_windup(event.windup_duration)
-        );
+        if !gate.permits(event.attacker) {
+            continue;
+        }
+
+        let is_shield_bash = event.attack_type == MeleeAttackType::ShieldBash;
+        if is_shield_bash && !gate.spend_bash_energy(event.attacker) {
+            continue;
+        }
+
+        // Add MeleeAttackState (phase = Windup) + lock (interruptible windup)
+        commands.entity(event.attacker).insert((
+            MeleeAttackState {
+                attack_type: event.attack_type.clone(),
+                ..MeleeAttackState::new_windup(event.windup_duration, gate.tick.get())
+            },
+            ActionLock::new(ActionKind::MeleeAttack, ActionPhase::Startup),
+        ));
 
         // Start weapon cooldown
         if let Ok(mut weapon) = weapons.get_mut(event.attacker) {
@@ -39,8 +155,10 @@ pub fn start_melee_attacks(
 
         // Consume stamina (attack cost)
         const ATTACK_COST: f32 = 30.0;
-        if let Ok(mut stamina) = staminas.get_mut(event.attacker) {
-            stamina.consume(ATTACK_COST);
+        if !is_shield_bash {
+            if let Ok(mut stamina) = staminas.get_mut(event.attacker) {
+                stamina.consume(ATTACK_COST);
+            }
         }
 
         crate::logger::log(&format!(
@@ -75,27 +193,28 @@ pub fn update_melee_attack_phases(
                 continue;
             };
 
-            // Get weapon stats for phase durations
+            // Phase durations (weapon stats; Bash — фиксированные)
             let Ok(weapon) = weapons.get(entity) else {
                 continue;
             };
+            let timings = MeleeTimings::for_attack(&attack_state.attack_type, weapon);
 
             // Set new phase timer based on phase type
             match new_phase {
                 AttackPhase::ActiveParryWindow { .. } => {
-                    // Parry window: weapon.parry_window duration
+                    // Parry window: timings.parry_window duration
                     attack_state.phase = AttackPhase::ActiveParryWindow {
-                        duration: weapon.parry_window,
+                        duration: timings.parry_window,
                     };
-                    attack_state.phase_timer = weapon.parry_window;
+                    attack_state.phase_timer = timings.parry_window;
                     crate::logger::log(&format!(
                         "⚔️ ECS: Windup → ActiveParryWindow ({:.3}s) (entity: {:?})",
-                        weapon.parry_window, entity
+                        timings.parry_window, entity
                     ));
                 }
                 AttackPhase::ActiveHitbox { .. } => {
                     // Hitbox window: attack_duration - parry_window
-                    let hitbox_duration = weapon.attack_duration - weapon.parry_window;
+                    let hitbox_duration = timings.attack_duration - timings.parry_window;
                     attack_state.phase = AttackPhase::ActiveHitbox {
                         duration: hitbox_duration,
                     };
@@ -107,9 +226,9 @@ pub fn update_melee_attack_phases(
                 }
                 AttackPhase::Recovery { .. } => {
                     attack_state.phase = AttackPhase::Recovery {
-                        duration: weapon.recovery_duration,
+                        duration: timings.recovery,
                     };
-                    attack_state.phase_timer = weapon.recovery_duration;
+                    attack_state.phase_timer = timings.recovery;
                     crate::logger::log(&format!("🛡️ ECS: ActiveHitbox → Recovery (entity: {:?})", entity));
                 }
                 _ => {}
@@ -124,24 +243,105 @@ pub fn update_melee_attack_phases(
 /// - Blocked: 70% damage reduction
 /// - Parried: 100% damage negation + stagger attacker
 /// - Normal: full damage (bypasses shield, slow kinetic)
+/// - Invulnerable target: ignored в `apply_damage` (`InvulnerableHit` вместо `DamageDealt`)
+/// - Knocked down target: только Execution проходит (x`EXECUTION_DAMAGE_MULTIPLIER`, блок игнорируется)
+/// - EquippedArmor цели: `reduce_damage` после модификаторов
+/// - Bash (удар прикладом) / ShieldBash (толчок щитом): + `PoiseHit` (сбивает сильнее, чем велит урон)
+///
+/// Удары одного тика сортируются по (attacker, target), размены (A→B + B→A)
+/// резолвятся через `MeleeTradeRule` — исход не зависит от порядка событий.
+/// Cleave (`WeaponStats.cleave`): замах режет толпу с падением урона, после `max_targets` — увяз.
 ///
 /// Generates `DamageDealt` events with impact data.
+#[allow(clippy::too_many_arguments)]
 pub fn process_melee_hits(
     mut melee_hit_events: EventReader<MeleeHit>,
     mut damage_dealt_events: EventWriter<DamageDealt>,
-    mut healths: Query<(&mut Health, Option<&mut crate::components::EnergyShield>)>,
-    _weapons: Query<&WeaponStats>,
+    mut damage_gate: DamageGate,
+    mut poise_events: EventWriter<PoiseHit>,
+    mut healths: Query<(
+        &mut Health,
+        Option<&mut crate::components::EnergyShield>,
+        Option<&crate::components::EquippedArmor>,
+    )>,
+    mut attacks: Query<&mut MeleeAttackState>,
+    weapons: Query<&WeaponStats>,
+    knockdowns: Query<&KnockdownState>,
+    mut downed: Query<&mut Downed>,
+    trade_rule: Res<MeleeTradeRule>,
 ) {
-    for hit in melee_hit_events.read() {
+    let hits = resolve_melee_trades(
+        melee_hit_events.read().cloned().collect(),
+        *trade_rule,
+        |entity| attacks.get(entity).ok().map(|attack| attack.started_at_tick),
+    );
+
+    // Cleave: счётчик задетых тел живёт в MeleeAttackState (замах длится несколько тиков)
+    let mut struck: HashMap<Entity, u8> = hits
+        .iter()
+        .filter_map(|hit| attacks.get(hit.attacker).ok().map(|attack| (hit.attacker, attack.struck_targets)))
+        .collect();
+    let hits = apply_melee_cleave(
+        hits,
+        |attacker| {
+            attacks.get(attacker).ok()?;
+            weapons.get(attacker).ok().map(|weapon| weapon.cleave)
+        },
+        &mut struck,
+    );
+    for (attacker, count) in struck {
+        if let Ok(mut attack) = attacks.get_mut(attacker) {
+            attack.struck_targets = count;
+        }
+    }
+
+    for hit in &hits {
         // Skip self-hits
         if hit.attacker == hit.target {
             continue;
         }
 
+        // Knockdown: лежачего бьёт только добивание
+        let attack_type = attacks.get(hit.attacker).ok().map(|attack| attack.attack_type.clone());
+        let is_execution = attack_type == Some(MeleeAttackType::Execution);
+        let poise_damage = match attack_type {
+            Some(MeleeAttackType::Bash) => Some(BASH_POISE_DAMAGE),
+            Some(MeleeAttackType::ShieldBash) => Some(SHIELD_BASH_POISE_DAMAGE),
+            _ => None,
+        };
+        let knockdown = knockdowns.get(hit.target).ok();
+
+        if knockdown.is_some_and(|knockdown| !knockdown.accepts_melee_hit(is_execution)) {
+            crate::logger::log(&format!(
+                "🛌 Melee hit ignored: target knocked down (attacker: {:?}, target: {:?})",
+                hit.attacker, hit.target
+            ));
+            continue;
+        }
+
+        // Downed: обычные удары не проходят, добивание (Execution) — смерть на следующем шаге
+        if let Ok(mut downed) = downed.get_mut(hit.target) {
+            if is_execution && downed.finished_by.is_none() {
+                downed.finished_by = Some(hit.attacker);
+                crate::logger::log(&format!(
+                    "💀 Downed target finished off (attacker: {:?}, target: {:?})",
+                    hit.attacker, hit.target
+                ));
+            }
+            continue;
+        }
+
         // Calculate damage with modifiers
         let mut final_damage = hit.damage;
 
-        if hit.was_parried {
+        if is_execution && knockdown.is_some_and(|knockdown| knockdown.is_down()) {
+            // Execution: лежачий не блокирует и не парирует
+            final_damage = (final_damage as f32 * EXECUTION_DAMAGE_MULTIPLIER) as u32;
+            crate::logger::log(&format!(
+                "💀 Melee EXECUTION (attacker: {:?}, target: {:?}, damage: {})",
+                hit.attacker, hit.target, final_damage
+            ));
+        } else if hit.was_parried {
             // Parried: 100% negation
             final_damage = 0;
             crate::logger::log(&format!(
@@ -163,16 +363,25 @@ pub fn process_melee_hits(
 
         // Apply damage (melee bypasses shield)
         if final_damage > 0 {
-            let Ok((mut health, mut shield_opt)) = healths.get_mut(hit.target) else {
+            let Ok((mut health, mut shield_opt, armor)) = healths.get_mut(hit.target) else {
                 continue;
             };
 
-            let applied = crate::combat::apply_damage_with_shield(
+            // Броня снижает урон (после блока / добивания)
+            let final_damage = armor.map_or(final_damage, |armor| armor.reduce_damage(final_damage));
+
+            // Invulnerable (spawn protection, post-stagger grace) → урон не прошёл
+            let Some(applied) = apply_damage(
+                &mut damage_gate,
+                hit.attacker,
+                hit.target,
                 &mut health,
                 shield_opt.as_deref_mut(),
                 final_damage,
                 crate::combat::DamageSource::Melee,
-            );
+            ) else {
+                continue;
+            };
 
             // Generate DamageDealt event with impact data
             damage_dealt_events.write(DamageDealt {
@@ -185,6 +394,14 @@ pub fn process_melee_hits(
                 impact_normal: hit.impact_normal,
             });
 
+            if let Some(poise_damage) = poise_damage {
+                poise_events.write(PoiseHit {
+                    attacker: hit.attacker,
+                    target: hit.target,
+                    poise_damage,
+                });
+            }
+
             crate::logger::log(&format!(
                 "💥 Melee damage dealt (attacker: {:?}, target: {:?}, damage: {}, applied: {:?}, HP: {})",
                 hit.attacker, hit.target, final_damage, applied, health.current
@@ -193,6 +410,103 @@ pub fn process_melee_hits(
     }
 }
 
+/// Cleave: урон ударов по толпе (после `resolve_melee_trades`, порядок детерминирован)
+///
+/// - `cleave_of(attacker)` — cleave оружия замаха (None — не замах: метательное, без оружия → удар как есть)
+/// - `struck` — сколько тел каждый атакующий уже задел этим замахом (обновляется)
+/// - n-е тело получает `MeleeCleave::damage_for(n)`, сверх `max_targets` удар отбрасывается
+///
+/// Каждое задетое тело занимает слот, даже если урон не пройдёт (парирование, блок, неуязвимость,
+/// лежачий) — клинок упёрся в него.
+pub fn apply_melee_cleave(
+    hits: Vec<MeleeHit>,
+    mut cleave_of: impl FnMut(Entity) -> Option<MeleeCleave>,
+    struck: &mut HashMap<Entity, u8>,
+) -> Vec<MeleeHit> {
+    hits.into_iter()
+        .filter_map(|mut hit| {
+            if hit.attacker == hit.target {
+                return Some(hit);
+            }
+            let Some(cleave) = cleave_of(hit.attacker) else {
+                return Some(hit);
+            };
+
+            let index = struck.entry(hit.attacker).or_insert(0);
+            let damage = cleave.damage_for(*index, hit.damage);
+            *index = index.saturating_add(1);
+
+            let Some(damage) = damage else {
+                crate::logger::log(&format!(
+                    "🪓 Melee cleave exhausted (attacker: {:?}, target: {:?}, max targets: {})",
+                    hit.attacker, hit.target, cleave.max_targets
+                ));
+                return None;
+            };
+            hit.damage = damage;
+            Some(hit)
+        })
+        .collect()
+}
+
+/// Детерминированный порядок + резолв разменов для ударов одного тика.
+///
+/// - Сортировка по (attacker, target), дубликаты пары отбрасываются (parried дубликат побеждает —
+///   парирование не теряется из-за порядка событий)
+/// - Размен = A→B и B→A, оба не parried; проигравший удар отбрасывается по `rule`
+/// - `attack_started_at`: tick начала атаки (для `DefenderPriority`)
+pub fn resolve_melee_trades(
+    mut hits: Vec<MeleeHit>,
+    rule: MeleeTradeRule,
+    attack_started_at: impl Fn(Entity) -> Option<u64>,
+) -> Vec<MeleeHit> {
+    hits.sort_by_key(|hit| (hit.attacker, hit.target, !hit.was_parried));
+    hits.dedup_by_key(|hit| (hit.attacker, hit.target));
+
+    if rule == MeleeTradeRule::BothApply {
+        return hits;
+    }
+
+    let find_trade_hit = |attacker: Entity, target: Entity| {
+        hits.iter()
+            .find(|hit| hit.attacker == attacker && hit.target == target && !hit.was_parried)
+    };
+
+    let mut dropped: HashSet<(Entity, Entity)> = HashSet::new();
+    for hit in hits.iter().filter(|hit| !hit.was_parried && hit.attacker < hit.target) {
+        let Some(counter) = find_trade_hit(hit.target, hit.attacker) else {
+            continue;
+        };
+
+        // Ordering: Greater → hit побеждает, Less → counter побеждает, Equal → оба проходят
+        let outcome = match rule {
+            MeleeTradeRule::BothApply => std::cmp::Ordering::Equal,
+            MeleeTradeRule::HigherDamageWins => hit.damage.cmp(&counter.damage),
+            MeleeTradeRule::DefenderPriority => {
+                match (attack_started_at(hit.attacker), attack_started_at(counter.attacker)) {
+                    (Some(hit_started), Some(counter_started)) => hit_started.cmp(&counter_started),
+                    _ => std::cmp::Ordering::Equal,
+                }
+            }
+        };
+
+        let loser = match outcome {
+            std::cmp::Ordering::Greater => counter,
+            std::cmp::Ordering::Less => hit,
+            std::cmp::Ordering::Equal => continue,
+        };
+
+        crate::logger::log(&format!(
+            "🤺 Melee trade {:?} ⇄ {:?} ({:?}): hit from {:?} discarded",
+            hit.attacker, hit.target, rule, loser.attacker
+        ));
+        dropped.insert((loser.attacker, loser.target));
+    }
+
+    hits.retain(|hit| !dropped.contains(&(hit.attacker, hit.target)));
+    hits
+}
+
 // ============================================================================
 // Parry Systems
 // ============================================================================
@@ -208,6 +522,8 @@ pub fn start_parry(
     mut intent_events: EventReader<ParryIntent>,
     mut commands: Commands,
     weapons: Query<&WeaponStats>,
+    locks: Query<&ActionLock>,
+    cancel_table: Res<CancelTable>,
 ) {
     for intent in intent_events.read() {
         // Get weapon stats for parry check
@@ -215,6 +531,17 @@ pub fn start_parry(
             continue;
         };
 
+        // Action arbitration: CancelTable (parry может прервать windup/recovery атаки)
+        let current_lock = locks.get(intent.defender).ok();
+        if !ActionLock::permits(current_lock, ActionKind::Parry, &cancel_table) {
+            crate::logger::log(&format!(
+                "🔒 ECS: Parry rejected (defender: {:?}, busy: {:?})",
+                intent.defender,
+                current_lock.map(|lock| lock.action)
+            ));
+            continue;
+        }
+
         // Check if weapon can parry
         if !weapon.can_parry() {
             crate::logger::log(&format!(
@@ -229,9 +556,15 @@ pub fn start_parry(
         let parry_windup = 0.1;
 
         // Add ParryState component (attacker can be None for idle parry)
-        commands
-            .entity(intent.defender)
-            .insert(ParryState::new(intent.attacker, parry_windup));
+        commands.entity(intent.defender).insert((
+            ParryState::new(intent.attacker, parry_windup),
+            ActionLock::new(ActionKind::Parry, ActionPhase::Active),
+        ));
+
+        // Parry заменяет прерванную атаку (windup/recovery)
+        if current_lock.is_some_and(|lock| lock.action == ActionKind::MeleeAttack) {
+            commands.entity(intent.defender).remove::<MeleeAttackState>();
+        }
 
         // Log based on parry type
         if let Some(attacker) = intent.attacker {
@@ -0,0 +1,170 @@
+//! Tests for melee hit resolution (simultaneous trades).
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use std::collections::HashMap;
+    use crate::combat::{MeleeCleave, MeleeHit, MeleeTradeRule};
+    use super::super::melee::{apply_melee_cleave, resolve_melee_trades};
+
+    fn hit(attacker: Entity, target: Entity, damage: u32) -> MeleeHit {
+        MeleeHit {
+            attacker,
+            target,
+            damage,
+            was_blocked: false,
+            was_parried: false,
+            impact_point: Vec3::ZERO,
+            impact_normal: Vec3::Z,
+        }
+    }
+
+    fn pairs(hits: &[MeleeHit]) -> Vec<(Entity, Entity)> {
+        hits.iter().map(|hit| (hit.attacker, hit.target)).collect()
+    }
+
+    #[test]
+    fn test_trade_order_independent() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+
+        let forward = resolve_melee_trades(vec![hit(a, b, 20), hit(b, a, 30)], MeleeTradeRule::BothApply, |_| None);
+        let reversed = resolve_melee_trades(vec![hit(b, a, 30), hit(a, b, 20)], MeleeTradeRule::BothApply, |_| None);
+
+        assert_eq!(pairs(&forward), vec![(a, b), (b, a)]);
+        assert_eq!(pairs(&forward), pairs(&reversed));
+    }
+
+    #[test]
+    fn test_higher_damage_wins_trade() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let c = Entity::from_raw(3);
+
+        let hits = vec![hit(b, a, 30), hit(a, b, 20), hit(a, c, 10)];
+        let resolved = resolve_melee_trades(hits, MeleeTradeRule::HigherDamageWins, |_| None);
+
+        // a→b проигрывает размен, a→c не размен — проходит
+        assert_eq!(pairs(&resolved), vec![(a, c), (b, a)]);
+
+        // Равный damage → оба удара
+        let tied = resolve_melee_trades(vec![hit(a, b, 25), hit(b, a, 25)], MeleeTradeRule::HigherDamageWins, |_| None);
+        assert_eq!(tied.len(), 2);
+    }
+
+    #[test]
+    fn test_defender_priority_favours_later_attack() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        // b начал атаку позже (контратака)
+        let started_at = |entity: Entity| Some(if entity == a { 100 } else { 110 });
+
+        let resolved = resolve_melee_trades(vec![hit(a, b, 50), hit(b, a, 10)], MeleeTradeRule::DefenderPriority, started_at);
+        assert_eq!(pairs(&resolved), vec![(b, a)]);
+    }
+
+    #[test]
+    fn test_parried_hit_is_not_a_trade() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let mut parried = hit(b, a, 50);
+        parried.was_parried = true;
+
+        let resolved = resolve_melee_trades(vec![hit(a, b, 20), parried], MeleeTradeRule::HigherDamageWins, |_| None);
+        assert_eq!(resolved.len(), 2);
+    }
+
+    #[test]
+    fn test_parried_duplicate_wins_dedup() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let mut parried = hit(a, b, 20);
+        parried.was_parried = true;
+
+        let forward = resolve_melee_trades(vec![parried.clone(), hit(a, b, 20)], MeleeTradeRule::BothApply, |_| None);
+        let reversed = resolve_melee_trades(vec![hit(a, b, 20), parried], MeleeTradeRule::BothApply, |_| None);
+
+        assert_eq!(forward.len(), 1);
+        assert_eq!(reversed.len(), 1);
+        assert!(forward[0].was_parried);
+        assert!(reversed[0].was_parried);
+    }
+
+    #[test]
+    fn test_cleave_falloff_through_crowd() {
+        let attacker = Entity::from_raw(1);
+        let crowd: Vec<Entity> = (10..15).map(Entity::from_raw).collect();
+        let hits: Vec<MeleeHit> = crowd.iter().map(|&target| hit(attacker, target, 40)).collect();
+
+        let mut struck = HashMap::new();
+        let hits = resolve_melee_trades(hits, MeleeTradeRule::BothApply, |_| None);
+        let cleaved = apply_melee_cleave(hits, |_| Some(MeleeCleave::new(3, 0.5)), &mut struck);
+
+        // 5 тел в хитбоксе — задеты первые 3: 40 → 20 → 10, остальные не получают ничего
+        let damage: Vec<u32> = cleaved.iter().map(|hit| hit.damage).collect();
+        assert_eq!(damage, vec![40, 20, 10]);
+        assert_eq!(pairs(&cleaved), vec![(attacker, crowd[0]), (attacker, crowd[1]), (attacker, crowd[2])]);
+        assert_eq!(struck[&attacker], 5);
+    }
+
+    #[test]
+    fn test_cleave_carries_across_ticks_of_one_swing() {
+        let attacker = Entity::from_raw(1);
+        let cleave = |_| Some(MeleeCleave::new(2, 0.5));
+        let mut struck = HashMap::new();
+
+        // Тик 1: одно тело, тик 2: ещё два — слоты замаха общие
+        let first = apply_melee_cleave(vec![hit(attacker, Entity::from_raw(10), 30)], cleave, &mut struck);
+        let second = apply_melee_cleave(
+            vec![hit(attacker, Entity::from_raw(11), 30), hit(attacker, Entity::from_raw(12), 30)],
+            cleave,
+            &mut struck,
+        );
+
+        assert_eq!(first[0].damage, 30);
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].damage, 15);
+    }
+
+    #[test]
+    fn test_crowded_brawl_cleave_is_per_attacker() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let thrower = Entity::from_raw(3);
+        let x = Entity::from_raw(10);
+        let y = Entity::from_raw(11);
+
+        // Свалка: a (меч) и b (кинжал) рубят одних и тех же, метательное (без замаха) — как есть
+        let hits = vec![hit(a, x, 30), hit(a, y, 30), hit(b, x, 20), hit(b, y, 20), hit(thrower, y, 12), hit(b, a, 20)];
+        let cleave_of = |attacker: Entity| match attacker.index() {
+            1 => Some(MeleeCleave::new(3, 0.6)),
+            2 => Some(MeleeCleave::single_target()),
+            _ => None,
+        };
+
+        let mut struck = HashMap::new();
+        let resolved = resolve_melee_trades(hits, MeleeTradeRule::BothApply, |_| None);
+        let cleaved = apply_melee_cleave(resolved, cleave_of, &mut struck);
+
+        let damage_of = |attacker: Entity, target: Entity| {
+            cleaved.iter().find(|hit| hit.attacker == attacker && hit.target == target).map(|hit| hit.damage)
+        };
+        assert_eq!(damage_of(a, x), Some(30));
+        assert_eq!(damage_of(a, y), Some(18));
+        // Кинжал: первое тело по детерминированному порядку (a), дальше увяз
+        assert_eq!(damage_of(b, a), Some(20));
+        assert_eq!(damage_of(b, x), None);
+        assert_eq!(damage_of(b, y), None);
+        assert_eq!(damage_of(thrower, y), Some(12));
+        assert!(!struck.contains_key(&thrower));
+    }
+
+    #[test]
+    fn test_cleave_damage_floor() {
+        let cleave = MeleeCleave::new(4, 0.1);
+        assert_eq!(cleave.damage_for(0, 5), Some(5));
+        assert_eq!(cleave.damage_for(3, 5), Some(1)); // Задетое тело всегда получает хотя бы 1
+        assert_eq!(cleave.damage_for(4, 5), None);
+        assert_eq!(MeleeCleave::single_target().damage_for(1, 50), None);
+    }
+}
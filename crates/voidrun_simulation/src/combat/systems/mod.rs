@@ -4,6 +4,18 @@ pub mod melee;
 pub mod stamina;
 pub mod weapon;
 pub mod damage;
+pub mod flinch;
+pub mod invulnerability;
+pub mod suppression;
+pub mod aim;
+pub mod channel;
+pub mod action_lock;
+pub mod smoke;
+pub mod blind;
+pub mod emp;
+pub mod fall;
+pub mod durability;
+pub mod downed;
 
 // Tests (separate files with _tests suffix)
 #[cfg(test)]
@@ -12,9 +24,23 @@ mod stamina_tests;
 mod weapon_tests;
 #[cfg(test)]
 mod damage_tests;
+#[cfg(test)]
+mod melee_tests;
 
 // Re-export all systems
 pub use melee::*;
 pub use stamina::*;
 pub use weapon::*;
 pub use damage::*;
+pub use flinch::*;
+pub use invulnerability::*;
+pub use suppression::*;
+pub use aim::*;
+pub use channel::*;
+pub use action_lock::*;
+pub use smoke::*;
+pub use blind::*;
+pub use emp::*;
+pub use fall::*;
+pub use durability::*;
+pub use downed::*;
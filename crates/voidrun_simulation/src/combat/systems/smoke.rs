@@ -0,0 +1,50 @@
+//! Smoke systems (deploy, lifetime, occlusion map).
+
+use bevy::prelude::*;
+use crate::combat::{SmokeCloud, SmokeDeployed, SmokeOcclusionMap};
+
+/// System: SmokeDeployed → spawn SmokeCloud entity
+///
+/// Godot создаёт vision-blocker volume по Added<SmokeCloud>.
+pub fn spawn_smoke_clouds(
+    mut deployed_events: EventReader<SmokeDeployed>,
+    mut commands: Commands,
+) {
+    for event in deployed_events.read() {
+        commands.spawn(SmokeCloud::new(event.center, event.radius, event.duration));
+
+        crate::logger::log(&format!(
+            "💨 Smoke deployed at {:?} (radius {:.1}m, {:.1}s)",
+            event.center, event.radius, event.duration
+        ));
+    }
+}
+
+/// System: тик времени жизни дыма + пересборка SmokeOcclusionMap
+///
+/// Истёкшее облако despawn'ится (Godot удаляет volume по RemovedComponents).
+pub fn update_smoke_clouds(
+    mut clouds: Query<(Entity, &mut SmokeCloud)>,
+    mut occlusion: ResMut<SmokeOcclusionMap>,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+    let mut occluders = Vec::new();
+
+    for (entity, mut cloud) in clouds.iter_mut() {
+        cloud.remaining -= delta;
+
+        if cloud.is_expired() {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        occluders.push((cloud.center, cloud.radius));
+    }
+
+    // Не трогаем ресурс без нужды (Changed<SmokeOcclusionMap>)
+    if occlusion.occluders != occluders {
+        occlusion.occluders = occluders;
+    }
+}
@@ -1,8 +1,12 @@
 //! Stamina management systems.
 
 use bevy::prelude::*;
-use crate::components::Stamina;
+use crate::components::{Encumbered, EquippedArmor, Stamina};
 use crate::combat::components::stamina::Exhausted;
+use crate::combat::{ActionKind, ActionLock, ActionPhase, CancelTable};
+use crate::movement::{Sprint, SprintIntent, Sprinting, Stance};
+use crate::shooting::{AimMode, HoldBreathIntent, HoldingBreath};
+use crate::SimulationTick;
 
 /// Стоимость различных действий (stamina points)
 pub const ATTACK_COST: f32 = 30.0;
@@ -12,15 +16,174 @@ pub const DODGE_COST: f32 = 25.0; // Для будущего
 /// Система: regenerate stamina для всех entities
 ///
 /// Работает в FixedUpdate для детерминизма.
-/// Regen rate берется из Stamina::regen_rate (default 10.0 units/sec).
+/// Regen rate берется из Stamina::regen_rate (default 10.0 units/sec),
+/// affixes брони и бонус комплекта добавляют `EquippedArmor::stamina_regen_bonus`.
+/// Во время спринта, задержки дыхания и recovery delay (Exhausted) regen стоит.
 pub fn regenerate_stamina(
-    mut query: Query<&mut Stamina>,
+    mut query: Query<
+        (&mut Stamina, Option<&Exhausted>, Option<&EquippedArmor>),
+        (Without<Sprinting>, Without<HoldingBreath>),
+    >,
     time: Res<Time<Fixed>>,
+    tick: Res<SimulationTick>,
 ) {
     let delta = time.delta_secs();
 
-    for mut stamina in query.iter_mut() {
-        stamina.regenerate(delta);
+    for (mut stamina, exhausted, armor) in query.iter_mut() {
+        if exhausted.is_some_and(|exhausted| exhausted.is_recovering(tick.0)) {
+            continue;
+        }
+
+        stamina.regenerate(delta * (1.0 + armor.map_or(0.0, |armor| armor.stamina_regen_bonus())));
+    }
+}
+
+/// Система: SprintIntent → вставить/снять Sprinting
+///
+/// Старт спринта разрешён если:
+/// - stamina > 0 и нет Exhausted (recovery lockout)
+/// - нет перегруза (Encumbered)
+/// - стойка позволяет (Stance::can_sprint)
+/// - не в ADS (AimMode::allows_sprint)
+/// - ActionLock разрешает Sprint (CancelTable)
+///
+/// ActionLock(Sprint) вставляется сразу (видно следующим системам в chain).
+pub fn apply_sprint_intents(
+    mut intents: EventReader<SprintIntent>,
+    actors: Query<(&Stamina, Option<&Stance>, Option<&AimMode>, Option<&ActionLock>, Has<Exhausted>, Has<Encumbered>, Has<Sprinting>)>,
+    cancel_table: Res<CancelTable>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((stamina, stance, aim_mode, lock, exhausted, encumbered, sprinting)) = actors.get(intent.entity) else {
+            continue;
+        };
+
+        if !intent.active {
+            if sprinting {
+                commands.entity(intent.entity).remove::<Sprinting>();
+            }
+            continue;
+        }
+
+        if sprinting || exhausted || encumbered || stamina.current <= 0.0 {
+            continue;
+        }
+
+        if !stance.copied().unwrap_or_default().can_sprint() {
+            continue;
+        }
+
+        if aim_mode.is_some_and(|aim_mode| !aim_mode.allows_sprint()) {
+            continue;
+        }
+
+        if !ActionLock::permits(lock, ActionKind::Sprint, &cancel_table) {
+            continue;
+        }
+
+        commands.entity(intent.entity).insert((
+            Sprinting,
+            ActionLock::new(ActionKind::Sprint, ActionPhase::Active),
+        ));
+    }
+}
+
+/// Система: расход stamina во время спринта
+///
+/// - Расход `Sprint::stamina_drain_per_sec` (нет компонента → Sprint::default())
+/// - Stamina = 0 → спринт снят + Exhausted с recovery delay
+/// - Стойка сменилась / вошли в ADS / спринт отменён другим действием (ActionLock) → спринт снят
+pub fn drain_sprint_stamina(
+    mut query: Query<
+        (Entity, &mut Stamina, Option<&Sprint>, Option<&Stance>, Option<&AimMode>, Option<&ActionLock>),
+        With<Sprinting>,
+    >,
+    time: Res<Time<Fixed>>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut stamina, sprint, stance, aim_mode, lock) in query.iter_mut() {
+        let cancelled = lock.is_some_and(|lock| lock.action != ActionKind::Sprint);
+        let aiming = aim_mode.is_some_and(|aim_mode| !aim_mode.allows_sprint());
+        if cancelled || aiming || !stance.copied().unwrap_or_default().can_sprint() {
+            commands.entity(entity).remove::<Sprinting>();
+            continue;
+        }
+
+        let drain = sprint.copied().unwrap_or_default().stamina_drain_per_sec * delta;
+        if stamina.drain(drain) {
+            commands
+                .entity(entity)
+                .remove::<Sprinting>()
+                .insert(Exhausted::with_recovery_delay(
+                    tick.after_secs(Exhausted::SPRINT_RECOVERY_DELAY),
+                ));
+        }
+    }
+}
+
+/// Система: HoldBreathIntent → вставить/снять HoldingBreath
+///
+/// Задержать дыхание можно только в полном ADS, без Exhausted и при stamina > 0.
+pub fn apply_hold_breath_intents(
+    mut intents: EventReader<HoldBreathIntent>,
+    actors: Query<(&Stamina, Option<&AimMode>, Has<Exhausted>, Has<HoldingBreath>)>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((stamina, aim_mode, exhausted, holding)) = actors.get(intent.entity) else {
+            continue;
+        };
+
+        if !intent.active {
+            if holding {
+                commands.entity(intent.entity).remove::<HoldingBreath>();
+            }
+            continue;
+        }
+
+        if holding || exhausted || stamina.current <= 0.0 {
+            continue;
+        }
+
+        if !aim_mode.is_some_and(|aim_mode| aim_mode.is_fully_ads()) {
+            continue;
+        }
+
+        commands.entity(intent.entity).insert(HoldingBreath);
+    }
+}
+
+/// Система: расход stamina при задержке дыхания
+///
+/// - Расход `HoldingBreath::STAMINA_DRAIN_PER_SEC`
+/// - Stamina = 0 → дыхание сбито + Exhausted с recovery delay
+/// - Вышли из ADS → дыхание отпущено
+pub fn drain_breath_stamina(
+    mut query: Query<(Entity, &mut Stamina, Option<&AimMode>), With<HoldingBreath>>,
+    time: Res<Time<Fixed>>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut stamina, aim_mode) in query.iter_mut() {
+        if !aim_mode.is_some_and(|aim_mode| aim_mode.is_fully_ads()) {
+            commands.entity(entity).remove::<HoldingBreath>();
+            continue;
+        }
+
+        if stamina.drain(HoldingBreath::STAMINA_DRAIN_PER_SEC * delta) {
+            commands
+                .entity(entity)
+                .remove::<HoldingBreath>()
+                .insert(Exhausted::with_recovery_delay(
+                    tick.after_secs(Exhausted::BREATH_RECOVERY_DELAY),
+                ));
+        }
     }
 }
 
@@ -39,10 +202,11 @@ pub fn consume_stamina_on_attack(
 /// Система: detect exhaustion (stamina < 20%)
 ///
 /// Добавляет Exhausted компонент когда stamina низкая.
-/// Убирает когда восстановилась > 50%.
+/// Убирает когда восстановилась > 50% (и recovery delay прошёл).
 pub fn detect_exhaustion(
     mut commands: Commands,
     query: Query<(Entity, &Stamina, Option<&Exhausted>)>,
+    tick: Res<SimulationTick>,
 ) {
     for (entity, stamina, exhausted) in query.iter() {
         let stamina_percent = stamina.current / stamina.max;
@@ -52,7 +216,9 @@ pub fn detect_exhaustion(
             commands.entity(entity).insert(Exhausted::default());
             // Debug logging
             // eprintln!("DEBUG: Entity {:?} is now exhausted (stamina: {:.1}%)", entity, stamina_percent * 100.0);
-        } else if exhausted.is_some() && stamina_percent > 0.5 {
+        } else if exhausted.is_some_and(|exhausted| !exhausted.is_recovering(tick.0))
+            && stamina_percent > 0.5
+        {
             // Восстановился
             commands.entity(entity).remove::<Exhausted>();
             // eprintln!("DEBUG: Entity {:?} recovered from exhaustion", entity);
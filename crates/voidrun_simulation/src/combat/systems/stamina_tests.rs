@@ -3,7 +3,9 @@
 #[cfg(test)]
 mod tests {
     use crate::components::Stamina;
-    use crate::combat::{ATTACK_COST, BLOCK_COST, DODGE_COST};
+    use crate::combat::{Exhausted, ATTACK_COST, BLOCK_COST, DODGE_COST};
+    use crate::shooting::HoldingBreath;
+    use crate::SimulationTick;
 
     #[test]
     fn test_stamina_regeneration_logic() {
@@ -52,4 +54,47 @@ mod tests {
         assert_eq!(BLOCK_COST, 20.0);
         assert_eq!(DODGE_COST, 25.0);
     }
+
+    #[test]
+    fn test_sprint_drain_clamps_at_zero() {
+        let mut stamina = Stamina::new(100.0);
+
+        assert!(!stamina.drain(40.0));
+        assert_eq!(stamina.current, 60.0);
+
+        // Последний кусок больше остатка → 0, stamina закончилась
+        assert!(stamina.drain(75.0));
+        assert_eq!(stamina.current, 0.0);
+    }
+
+    #[test]
+    fn test_sprint_exhaustion_recovery_delay() {
+        let tick = SimulationTick(100);
+        let exhausted = Exhausted::with_recovery_delay(tick.after_secs(Exhausted::SPRINT_RECOVERY_DELAY));
+
+        assert!(exhausted.is_recovering(tick.0));
+        assert!(exhausted.is_recovering(exhausted.recover_at_tick - 1));
+        assert!(!exhausted.is_recovering(exhausted.recover_at_tick));
+
+        // Обычный Exhausted (порог stamina) — без задержки
+        assert!(!Exhausted::default().is_recovering(0));
+    }
+
+    #[test]
+    fn test_breath_hold_drain_and_recovery_delay() {
+        let mut stamina = Stamina::new(100.0);
+        let step = 1.0 / 60.0;
+
+        // Полная stamina держит дыхание ~5 секунд
+        let mut ticks = 0;
+        while !stamina.drain(HoldingBreath::STAMINA_DRAIN_PER_SEC * step) {
+            ticks += 1;
+        }
+        assert!((ticks as f32 * step - 5.0).abs() < 0.1, "held for {} ticks", ticks);
+
+        // Сбитое дыхание восстанавливается дольше спринта
+        let tick = SimulationTick(0);
+        let exhausted = Exhausted::with_recovery_delay(tick.after_secs(Exhausted::BREATH_RECOVERY_DELAY));
+        assert!(exhausted.recover_at_tick > tick.after_secs(Exhausted::SPRINT_RECOVERY_DELAY));
+    }
 }
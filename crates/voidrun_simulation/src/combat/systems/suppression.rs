@@ -0,0 +1,77 @@
+//! Suppression systems (near-miss stacking, decay).
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::components::{Actor, Health};
+use crate::combat::{ProjectileNearMiss, Suppressed};
+
+/// System: ProjectileNearMiss → Suppressed (stacking)
+///
+/// - Friendly fire near-miss (та же фракция) не подавляет
+/// - Мёртвые не подавляются
+/// - Чем ближе прошла пуля, тем больше стак (`proximity = 1 - distance / radius`)
+pub fn apply_suppression_from_near_misses(
+    mut near_miss_events: EventReader<ProjectileNearMiss>,
+    actors: Query<(&Actor, &Health)>,
+    mut suppressed_query: Query<&mut Suppressed>,
+    mut commands: Commands,
+) {
+    // Новые подавления за этот тик (несколько пуль в один тик → один insert)
+    let mut new_suppressions: HashMap<Entity, Suppressed> = HashMap::new();
+
+    for near_miss in near_miss_events.read() {
+        let Ok((target_actor, target_health)) = actors.get(near_miss.target) else {
+            continue;
+        };
+
+        if !target_health.is_alive() {
+            continue;
+        }
+
+        if let Ok((shooter_actor, _)) = actors.get(near_miss.shooter) {
+            if shooter_actor.faction_id == target_actor.faction_id {
+                continue;
+            }
+        }
+
+        let proximity = if near_miss.radius > 0.0 {
+            1.0 - near_miss.distance / near_miss.radius
+        } else {
+            0.0
+        };
+
+        if let Ok(mut suppressed) = suppressed_query.get_mut(near_miss.target) {
+            suppressed.add_stack(proximity);
+            continue;
+        }
+
+        new_suppressions
+            .entry(near_miss.target)
+            .or_insert_with(|| Suppressed::new(0.0))
+            .add_stack(proximity);
+    }
+
+    for (entity, suppressed) in new_suppressions {
+        crate::logger::log(&format!(
+            "🫣 ECS: Suppressed (entity: {:?}, level: {:.2})",
+            entity, suppressed.level
+        ));
+        commands.entity(entity).insert(suppressed);
+    }
+}
+
+/// System: Suppression decay (tick, remove when level == 0)
+pub fn decay_suppression(
+    mut query: Query<(Entity, &mut Suppressed)>,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut suppressed) in query.iter_mut() {
+        if suppressed.decay(delta) {
+            commands.entity(entity).remove::<Suppressed>();
+            crate::logger::log(&format!("✅ ECS: Suppression ended (entity: {:?})", entity));
+        }
+    }
+}
@@ -1,8 +1,11 @@
 //! Weapon systems (cooldowns + ranged combat).
 
 use bevy::prelude::*;
+use std::collections::HashMap;
 use crate::combat::{
-    WeaponStats, WeaponFireIntent, ProjectileHit, ProjectileShieldHit, DamageDealt, DamageSource,
+    WeaponStats, WeaponFireIntent, WeaponFired, TriggerIntent, FireMode, FiringState, RecoilState, FireBlock, FireDenied, Dead, WeaponHeatChanged, ProjectileHit, ProjectileShieldHit, DamageDealt, DamageSource,
+    DamageGate, apply_damage, Suppressed,
+    AimSkill, AimReaction, Channeling, KnockdownState, Downed,
 };
 
 /// System: обновление weapon cooldowns
@@ -18,6 +21,73 @@ pub fn update_weapon_cooldowns(
     }
 }
 
+/// System: нагрев энергетического оружия
+///
+/// - WeaponFired → `heat_per_shot`, дошло до 1.0 → перегрев (`can_attack` = false на `overheat_lockout`)
+/// - Каждый tick остывание
+/// - Изменился нагрев / статус перегрева → WeaponHeatChanged (Godot: свечение ствола)
+///
+/// Перегретое оружие intent'ов не даёт: AI/BT/игрок проверяют `can_attack`, Godot отбрасывает
+/// intent перегретого стрелка (intent, отправленный до перегрева в том же кадре).
+pub fn update_weapon_heat(
+    mut fired_events: EventReader<WeaponFired>,
+    mut weapons: Query<(Entity, &mut WeaponStats)>,
+    time: Res<Time>,
+    mut heat_events: EventWriter<WeaponHeatChanged>,
+) {
+    let fired_by: Vec<Entity> = fired_events.read().map(|fired| fired.shooter).collect();
+
+    for (entity, mut weapon) in weapons.iter_mut() {
+        if !weapon.heat.is_enabled() {
+            continue;
+        }
+
+        let before = weapon.heat;
+        weapon.heat.cool(time.delta_secs());
+
+        let shots = fired_by.iter().filter(|shooter| **shooter == entity).count();
+        for _ in 0..shots {
+            if weapon.heat.add_shot() {
+                crate::logger::log(&format!(
+                    "🔥 {:?} weapon overheated (lockout {:.1}s)",
+                    entity, weapon.heat.overheat_lockout
+                ));
+            }
+        }
+
+        let heat = weapon.heat;
+        if heat.current != before.current || heat.is_overheated() != before.is_overheated() {
+            heat_events.write(WeaponHeatChanged {
+                entity,
+                heat: heat.current,
+                overheated: heat.is_overheated(),
+            });
+        }
+    }
+}
+
+/// System: отдача стрелка (RecoilState)
+///
+/// - Каждый tick bloom остывает по `recovery_rate` активного оружия
+/// - WeaponFired → подброс камеры (паттерн оружия) + bloom; Godot забирает подброс и
+///   добавляет bloom к разбросу следующих выстрелов — длинная очередь ложится шире
+pub fn update_recoil(
+    mut fired_events: EventReader<WeaponFired>,
+    mut shooters: Query<(Entity, &WeaponStats, &mut RecoilState)>,
+    time: Res<Time>,
+) {
+    let fired_by: Vec<Entity> = fired_events.read().map(|fired| fired.shooter).collect();
+
+    for (entity, weapon, mut recoil) in shooters.iter_mut() {
+        recoil.recover(&weapon.recoil, time.delta_secs());
+
+        let shots = fired_by.iter().filter(|shooter| **shooter == entity).count();
+        for _ in 0..shots {
+            recoil.add_shot(&weapon.recoil);
+        }
+    }
+}
+
 /// System: AI weapon fire intent (ECS strategic decision)
 ///
 /// Архитектура (Hybrid Intent-based):
@@ -28,13 +98,25 @@ pub fn update_weapon_cooldowns(
 /// - ECS не знает точных Godot positions (только chunk-based StrategicPosition)
 /// - Godot authoritative для tactical validation (distance, line of sight)
 /// - Разделение ответственности: strategic intent vs tactical execution
+///
+/// FireMode::Burst → FiringState (очередь ведёт `continue_firing`), следующая очередь — после cooldown.
 pub fn ai_weapon_fire_intent(
-    mut actors: Query<(Entity, &crate::ai::AIState, &mut WeaponStats)>,
+    mut actors: Query<(
+        Entity,
+        &crate::ai::AIState,
+        &mut WeaponStats,
+        Option<&Suppressed>,
+        Option<&AimSkill>,
+        Option<&AimReaction>,
+        Option<&crate::components::EquippedWeapons>,
+        Has<FiringState>,
+    ), (Without<Channeling>, Without<KnockdownState>)>, // "Using item"/reload/лежит — не стреляем
     mut intent_events: EventWriter<WeaponFireIntent>,
+    mut commands: Commands,
 ) {
     use crate::ai::AIState;
 
-    for (entity, state, mut weapon) in actors.iter_mut() {
+    for (entity, state, mut weapon, suppressed, aim_skill, aim_reaction, equipped, firing) in actors.iter_mut() {
         // Стреляем только в Combat state
         let AIState::Combat { target } = state else {
             continue;
@@ -45,24 +127,51 @@ pub fn ai_weapon_fire_intent(
             continue;
         }
 
-        // Проверяем cooldown (strategic constraint)
-        if !weapon.can_attack() {
+        // Клин (устраняет ai_clear_weapon_jams) / пустой магазин / перегрев / cooldown; очередь ещё идёт — ждём
+        if fire_block(&weapon, equipped).is_some() || firing {
             continue;
         }
 
-        // Генерируем intent (Godot проверит distance/LOS)
-        intent_events.write(WeaponFireIntent {
-            shooter: entity,
-            target: Some(*target),
-            damage: weapon.base_damage,
-            speed: weapon.projectile_speed,
-            max_range: weapon.range,
-            hearing_range: weapon.hearing_range,
-        });
+        // AimSkill: ждём reaction delay по текущей цели (AimReaction ставится update_aim_reaction)
+        if aim_skill.is_some() {
+            let reaction_ready = aim_reaction
+                .is_some_and(|reaction| reaction.target == *target && reaction.is_ready());
+            if !reaction_ready {
+                continue;
+            }
+        }
+
+        if matches!(weapon.fire_mode, FireMode::Burst { .. }) {
+            // Очередь: короткое нажатие, выстрелы шлёт continue_firing
+            let mut burst = FiringState {
+                target: Some(*target),
+                ..default()
+            };
+            burst.pull(weapon.fire_mode, true);
+            burst.trigger_held = false;
+            commands.entity(entity).insert(burst);
+        } else {
+            // Генерируем intent (Godot проверит distance/LOS)
+            intent_events.write(WeaponFireIntent {
+                shooter: entity,
+                target: Some(*target),
+                damage: weapon.base_damage,
+                speed: weapon.projectile_speed,
+                max_range: weapon.range,
+                hearing_range: weapon.hearing_range,
+                ballistics: weapon.ballistics,
+                fire_mode: weapon.fire_mode,
+            });
+        }
 
         // Начинаем cooldown (ECS владеет cooldown state)
         weapon.start_cooldown();
 
+        // Подавленный стрелок стреляет реже
+        if let Some(suppressed) = suppressed {
+            weapon.cooldown_timer *= suppressed.cooldown_multiplier();
+        }
+
         crate::logger::log(&format!(
             "Actor {:?} wants to fire at {:?} (intent generated)",
             entity, target
@@ -70,14 +179,188 @@ pub fn ai_weapon_fire_intent(
     }
 }
 
+/// Проверка оружия перед выстрелом (одна для AI и player)
+///
+/// Порядок: клин → пустой магазин → перегрев → cooldown.
+/// Занятость актора (Channeling / knockdown / спринт) проверяет вызывающий.
+///
+/// Патроны пока не расходуются (нет перезарядки) — блокирует только явно пустой магазин.
+pub fn fire_block(
+    weapon: &WeaponStats,
+    equipped: Option<&crate::components::EquippedWeapons>,
+) -> Option<FireBlock> {
+    let active = equipped.and_then(|equipped| equipped.get_active_weapon());
+
+    if active.is_some_and(|active| active.jammed) {
+        return Some(FireBlock::Jammed);
+    }
+    if active.is_some_and(|active| active.ammo_count == Some(0)) {
+        return Some(FireBlock::OutOfAmmo);
+    }
+    if weapon.heat.is_overheated() {
+        return Some(FireBlock::Overheated);
+    }
+    if weapon.cooldown_timer > 0.0 {
+        return Some(FireBlock::Cooldown);
+    }
+    None
+}
+
+/// System: TriggerIntent (player) → fire_block → FiringState / FireDenied
+///
+/// - Все ranged режимы: Single / Shotgun — выстрел на нажатие, Burst — очередь, Auto — огонь до отпускания
+/// - Нажатие проверяется как выстрел AI (`fire_block`) + занятость (Channeling / knockdown / спринт);
+///   отказ → FireDenied (HUD), cooldown очереди стартует сразу при нажатии
+/// - Auto: cooldown при нажатии не отказ — огонь начнётся, когда оружие будет готово
+/// - Отпускание: Auto прекращает огонь, начатая очередь достреливается
+pub fn apply_trigger_intents(
+    mut intents: EventReader<TriggerIntent>,
+    mut shooters: Query<(
+        &mut WeaponStats,
+        Option<&mut FiringState>,
+        Option<&crate::components::EquippedWeapons>,
+        Has<crate::movement::Sprinting>,
+        Has<Channeling>,
+        Has<KnockdownState>,
+        Has<Downed>,
+    )>,
+    mut denied_events: EventWriter<FireDenied>,
+    mut commands: Commands,
+) {
+    // Новые FiringState за этот тик (нажатие + отпускание в один тик → один insert)
+    let mut new_states: HashMap<Entity, FiringState> = HashMap::new();
+
+    for intent in intents.read() {
+        let Ok((mut weapon, firing, equipped, sprinting, channeling, knocked_down, downed)) = shooters.get_mut(intent.shooter) else {
+            continue;
+        };
+
+        if !weapon.is_ranged() {
+            continue;
+        }
+
+        let fire_mode = weapon.fire_mode;
+
+        if intent.held {
+            let block = if sprinting || channeling || knocked_down || downed {
+                Some(FireBlock::Busy)
+            } else {
+                fire_block(&weapon, equipped)
+                    .filter(|block| !(*block == FireBlock::Cooldown && fire_mode == FireMode::Auto))
+            };
+
+            if let Some(reason) = block {
+                denied_events.write(FireDenied { shooter: intent.shooter, reason });
+                continue;
+            }
+        }
+
+        let firing = match firing {
+            Some(firing) => firing.into_inner(),
+            None if intent.held || new_states.contains_key(&intent.shooter) => {
+                new_states.entry(intent.shooter).or_default()
+            }
+            None => continue,
+        };
+
+        if !intent.held {
+            firing.trigger_held = false;
+            continue;
+        }
+
+        if firing.pull(fire_mode, weapon.can_attack()) {
+            weapon.start_cooldown();
+        }
+    }
+
+    for (entity, firing) in new_states {
+        commands.entity(entity).insert(firing);
+    }
+}
+
+/// System: FiringState → WeaponFireIntent (очередь / автоматический огонь)
+///
+/// Стрельба прерывается (FiringState снимается), если:
+/// - оружие сменилось на не ranged
+/// - заклинило / пустой магазин / перегрев → FireDenied (HUD)
+/// - спринт / channelled действие / knockdown
+///
+/// Auto: каждый выстрел запускает cooldown (темп = attack_cooldown).
+/// Single / Shotgun / Burst: cooldown запущен при нажатии, выстрелы очереди идут по `interval`.
+pub fn continue_firing(
+    mut shooters: Query<
+        (
+            Entity,
+            &mut FiringState,
+            &mut WeaponStats,
+            Option<&crate::components::EquippedWeapons>,
+            Has<crate::movement::Sprinting>,
+            Has<Channeling>,
+            Has<KnockdownState>,
+            Has<Downed>,
+        ),
+        Without<Dead>,
+    >,
+    mut intent_events: EventWriter<WeaponFireIntent>,
+    mut denied_events: EventWriter<FireDenied>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut firing, mut weapon, equipped, sprinting, channeling, knocked_down, downed) in shooters.iter_mut() {
+        let weapon_block = fire_block(&weapon, equipped).filter(|block| *block != FireBlock::Cooldown);
+
+        if let Some(reason) = weapon_block {
+            denied_events.write(FireDenied { shooter: entity, reason });
+        }
+
+        if !weapon.is_ranged() || weapon_block.is_some() || sprinting || channeling || knocked_down || downed {
+            commands.entity(entity).remove::<FiringState>();
+            continue;
+        }
+
+        if firing.tick(weapon.fire_mode, weapon.can_attack(), delta) {
+            intent_events.write(WeaponFireIntent {
+                shooter: entity,
+                target: firing.target,
+                damage: weapon.base_damage,
+                speed: weapon.projectile_speed,
+                max_range: weapon.range,
+                hearing_range: weapon.hearing_range,
+                ballistics: weapon.ballistics,
+                fire_mode: weapon.fire_mode,
+            });
+
+            if matches!(weapon.fire_mode, FireMode::Auto) {
+                weapon.start_cooldown();
+            }
+        }
+
+        if firing.is_finished() {
+            commands.entity(entity).remove::<FiringState>();
+        }
+    }
+}
+
 /// System: обработка ProjectileHit событий → нанесение урона
 ///
 /// Godot отправляет событие после collision detection.
+/// Урон падает с пройденным путём (`DamageFalloff` оружия стрелка).
 /// Применяет damage с учётом shield (ranged блокируется щитом).
+/// Направленный щит (`ShieldCoverage::FrontArc`) попадания по телу не ловит: дугу проверяет
+/// Godot при столкновении с ShieldSphere, пуля в теле = прошла вне дуги.
+/// Неуязвимые цели (Invulnerable) игнорируют урон (`apply_damage`).
 pub fn process_projectile_hits(
     mut hit_events: EventReader<ProjectileHit>,
-    mut targets: Query<(&mut crate::Health, Option<&mut crate::components::EnergyShield>)>,
+    weapons: Query<&WeaponStats>,
+    mut targets: Query<(
+        &mut crate::Health,
+        Option<&mut crate::components::EnergyShield>,
+        Option<&crate::components::EquippedArmor>,
+    )>,
     mut damage_events: EventWriter<DamageDealt>,
+    mut damage_gate: DamageGate,
 ) {
     for hit in hit_events.read() {
         crate::logger::log(&format!(
@@ -95,22 +378,38 @@ pub fn process_projectile_hits(
         }
 
         // Наносим урон цели (с учётом shield)
-        let Ok((mut health, mut shield_opt)) = targets.get_mut(hit.target) else {
+        let Ok((mut health, mut shield_opt, armor)) = targets.get_mut(hit.target) else {
             continue;
         };
 
-        let applied = crate::combat::apply_damage_with_shield(
+        // Дальний выстрел слабее (оружие стрелка; стрелок погиб — урон без падения)
+        let damage = weapons
+            .get(hit.shooter)
+            .map_or(hit.damage, |weapon| weapon.falloff.apply(hit.damage, hit.distance));
+
+        // Броня снижает урон по телу (щит поглощает до брони)
+        let damage = armor.map_or(damage, |armor| armor.reduce_damage(damage));
+
+        let shield = shield_opt
+            .as_deref_mut()
+            .filter(|shield| !shield.coverage.is_directional());
+        let Some(applied) = apply_damage(
+            &mut damage_gate,
+            hit.shooter,
+            hit.target,
             &mut health,
-            shield_opt.as_deref_mut(),
-            hit.damage,
+            shield,
+            damage,
             DamageSource::Ranged,
-        );
+        ) else {
+            continue;
+        };
 
         // Генерируем DamageDealt event для визуальных эффектов
         damage_events.write(DamageDealt {
             attacker: hit.shooter,
             target: hit.target,
-            damage: hit.damage,
+            damage,
             source: DamageSource::Ranged,
             applied_damage: applied,
             impact_point: hit.impact_point,
@@ -129,10 +428,12 @@ pub fn process_projectile_hits(
 /// Godot отправляет событие когда projectile коллидирует с ShieldSphere.
 /// Применяет damage только к щиту (урон в health не проходит).
 /// Self-shield bypass уже проверен в Godot layer.
+/// Неуязвимые цели (Invulnerable) не теряют энергию щита (`apply_damage`).
 pub fn process_projectile_shield_hits(
     mut hit_events: EventReader<ProjectileShieldHit>,
     mut targets: Query<(&mut crate::Health, Option<&mut crate::components::EnergyShield>)>,
     mut damage_events: EventWriter<DamageDealt>,
+    mut damage_gate: DamageGate,
 ) {
     for hit in hit_events.read() {
         crate::logger::log(&format!(
@@ -154,12 +455,17 @@ pub fn process_projectile_shield_hits(
             continue;
         };
 
-        let applied = crate::combat::apply_damage_with_shield(
+        let Some(applied) = apply_damage(
+            &mut damage_gate,
+            hit.shooter,
+            hit.target,
             &mut health,
             shield_opt.as_deref_mut(),
             hit.damage,
             DamageSource::Ranged, // Shield blocks ranged
-        );
+        ) else {
+            continue;
+        };
 
         // Генерируем DamageDealt event для визуальных эффектов
         damage_events.write(DamageDealt {
@@ -3,7 +3,7 @@
 #[cfg(test)]
 mod tests {
     use bevy::prelude::*;
-    use crate::combat::{ProjectileHit, WeaponFireIntent};
+    use crate::combat::{AimReaction, AimSkill, ProjectileHit, WeaponFireIntent};
 
     #[test]
     fn test_projectile_hit_event() {
@@ -16,6 +16,8 @@ mod tests {
             damage: 20,
             impact_point: Vec3::ZERO,
             impact_normal: Vec3::Z,
+            penetrations: 0,
+            distance: 5.0,
         };
 
         assert_eq!(hit.shooter, shooter);
@@ -35,10 +37,55 @@ mod tests {
             speed: 8.0,
             max_range: 20.0,
             hearing_range: 100.0,
+            ballistics: crate::combat::ProjectileBallistics::none(),
+            fire_mode: crate::combat::FireMode::Single,
         };
 
         assert_eq!(intent.shooter, shooter);
         assert_eq!(intent.target, Some(target));
         assert_eq!(intent.damage, 10);
     }
+
+    #[test]
+    fn test_aim_reaction_delay() {
+        let target = Entity::from_raw(1);
+        let skill = AimSkill::veteran();
+
+        let mut reaction = AimReaction::new(target, skill.reaction_delay);
+        assert!(!reaction.is_ready());
+
+        reaction.timer -= skill.reaction_delay;
+        assert!(reaction.is_ready());
+
+        // Пресеты упорядочены по точности
+        assert!(AimSkill::rookie().spread_degrees > AimSkill::default().spread_degrees);
+        assert!(AimSkill::default().spread_degrees > AimSkill::veteran().spread_degrees);
+        assert!(AimSkill::veteran().spread_degrees > AimSkill::elite().spread_degrees);
+    }
+
+    #[test]
+    fn test_fire_block_order() {
+        use crate::combat::{fire_block, FireBlock, WeaponStats};
+        use crate::components::{EquippedItem, EquippedWeapons};
+
+        let mut weapon = WeaponStats::ranged_pistol();
+        assert_eq!(fire_block(&weapon, None), None);
+
+        weapon.cooldown_timer = 0.2;
+        assert_eq!(fire_block(&weapon, None), Some(FireBlock::Cooldown));
+
+        weapon.heat.lockout_timer = 1.0;
+        assert_eq!(fire_block(&weapon, None), Some(FireBlock::Overheated));
+
+        // Пустой магазин и клин — состояние экземпляра в руках (клин важнее)
+        let mut item = EquippedItem::from_instance(&crate::item_system::ItemInstance::new("pistol_basic"));
+        item.ammo_count = Some(0);
+        let mut equipped = EquippedWeapons { primary_large_1: Some(item), ..Default::default() };
+        assert_eq!(fire_block(&weapon, Some(&equipped)), Some(FireBlock::OutOfAmmo));
+
+        if let Some(active) = equipped.get_active_weapon_mut() {
+            active.jammed = true;
+        }
+        assert_eq!(fire_block(&weapon, Some(&equipped)), Some(FireBlock::Jammed));
+    }
 }
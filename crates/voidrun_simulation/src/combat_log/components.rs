@@ -0,0 +1,97 @@
+//! Combat log components (записи боя матча, буфер, экспорт).
+
+use bevy::prelude::*;
+use std::path::PathBuf;
+use crate::combat::{DamageSource, FireBlock, FlinchKind, MeleeAttackType};
+use crate::StableId;
+
+/// Участник записи — StableId (стабилен между запусками и для внешних инструментов)
+///
+/// Entity без StableId (не успел получить / не актор) → `CombatLog::unstable_id`:
+/// namespace `UNSTABLE_NAMESPACE`, счётчик — биты Entity.
+pub type LogActor = StableId;
+
+/// Событие боя в логе (компактная копия ECS события)
+#[derive(Debug, Clone, PartialEq)]
+pub enum CombatLogEvent {
+    /// ECS решил стрелять (AI / очередь игрока)
+    FireIntent { shooter: LogActor, target: Option<LogActor> },
+    /// Выстрел прошёл tactical валидацию (Godot)
+    Fired { shooter: LogActor, target: Option<LogActor>, damage: u32, pellets: u8 },
+    /// Спуск отклонён (cooldown / перегрев / клин / магазин / занят)
+    FireDenied { shooter: LogActor, reason: FireBlock },
+    /// Замах начат
+    MeleeStarted { attacker: LogActor, attack_type: MeleeAttackType },
+    /// Hitbox замаха задел тело
+    MeleeHit { attacker: LogActor, target: LogActor, damage: u32, blocked: bool, parried: bool },
+    /// Парирование удалось
+    Parry { attacker: LogActor, defender: LogActor },
+    /// Урон применён
+    Damage { attacker: LogActor, target: LogActor, damage: u32, source: DamageSource },
+    /// Реакция на урон (flinch / mini-stagger / knockdown)
+    Flinch { entity: LogActor, source: LogActor, kind: FlinchKind },
+    /// Встаёт после knockdown
+    GetUp { entity: LogActor },
+    /// Смерть
+    Died { entity: LogActor, killer: Option<LogActor> },
+}
+
+/// Запись лога: tick симуляции + событие
+#[derive(Debug, Clone, PartialEq)]
+pub struct CombatLogRecord {
+    pub tick: u64,
+    pub event: CombatLogEvent,
+}
+
+/// Полный упорядоченный лог боя текущего матча (resource)
+///
+/// Порядок записей: по tick, внутри tick — по фазам боя (`record_combat_log`).
+/// Конец матча → экспорт в файл (`CombatLogExport`) и новый лог.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CombatLog {
+    /// Seed симуляции (реплей того же матча)
+    pub seed: u64,
+    pub records: Vec<CombatLogRecord>,
+}
+
+impl CombatLog {
+    /// Namespace id для entity без StableId
+    pub const UNSTABLE_NAMESPACE: u16 = u16::MAX;
+
+    pub fn new(seed: u64) -> Self {
+        Self { seed, records: Vec::new() }
+    }
+
+    pub fn push(&mut self, tick: u64, event: CombatLogEvent) {
+        self.records.push(CombatLogRecord { tick, event });
+    }
+
+    /// Id для entity без StableId (стабилен только внутри матча)
+    pub fn unstable_id(entity: Entity) -> LogActor {
+        StableId::new(Self::UNSTABLE_NAMESPACE, entity.to_bits())
+    }
+
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+}
+
+/// Куда сохранять лог в конце матча (resource; `dir: None` — не сохранять)
+///
+/// Godot задаёт каталог при старте (logs/combat рядом с game.log).
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CombatLogExport {
+    pub dir: Option<PathBuf>,
+}
+
+impl CombatLogExport {
+    /// Файл лога матча: seed + tick конца (не перезаписывает прошлые матчи)
+    pub fn file_path(&self, seed: u64, end_tick: u64) -> Option<PathBuf> {
+        let dir = self.dir.as_ref()?;
+        Some(dir.join(format!("match_{:016x}_{}.vrcl", seed, end_tick)))
+    }
+}
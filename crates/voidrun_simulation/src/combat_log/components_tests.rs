@@ -0,0 +1,32 @@
+//! Tests for combat log components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::components::*;
+    use crate::StableId;
+    use bevy::prelude::Entity;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_unstable_id_does_not_collide_with_stable_ids() {
+        let entity = Entity::from_raw(7);
+        let unstable = CombatLog::unstable_id(entity);
+
+        assert_eq!(unstable, CombatLog::unstable_id(entity));
+        assert_ne!(unstable, CombatLog::unstable_id(Entity::from_raw(8)));
+        assert_ne!(unstable, StableId::new(0, 7));
+    }
+
+    #[test]
+    fn test_export_path_per_match() {
+        let disabled = CombatLogExport::default();
+        assert_eq!(disabled.file_path(1, 100), None);
+
+        let export = CombatLogExport { dir: Some(PathBuf::from("logs/combat")) };
+        let first = export.file_path(0x2A, 100).unwrap();
+        let second = export.file_path(0x2A, 2_000).unwrap();
+
+        assert_eq!(first, PathBuf::from("logs/combat/match_000000000000002a_100.vrcl"));
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,477 @@
+//! Бинарный формат combat log (.vrcl) + reader API для внешних инструментов.
+//!
+//! # Layout (little-endian)
+//!
+//! ```text
+//! header:  "VRCL" | version: u16 | seed: u64 | record_count: varint
+//! record:  tick_delta: varint | tag: u8 | поля события
+//! ```
+//!
+//! - varint — LEB128 (id участников, урон, дельта tick — 1-3 байта в типичном матче)
+//! - участник — varint `StableId`, необязательный — байт присутствия + varint
+//! - enum'ы (причина отказа, тип удара, источник урона, flinch) — u8
+//! - флаги MeleeHit — байт (bit 0 blocked, bit 1 parried)
+//!
+//! Версия поднимается при изменении layout; reader отказывает неизвестной версии.
+
+use std::fmt;
+use std::path::Path;
+use crate::combat::{DamageSource, FireBlock, FlinchKind, MeleeAttackType};
+use crate::StableId;
+use super::components::{CombatLog, CombatLogEvent, CombatLogRecord, LogActor};
+
+/// Сигнатура файла
+pub const COMBAT_LOG_MAGIC: [u8; 4] = *b"VRCL";
+
+/// Версия формата
+pub const COMBAT_LOG_VERSION: u16 = 1;
+
+/// Заголовок лога
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CombatLogHeader {
+    pub version: u16,
+    pub seed: u64,
+    pub record_count: u64,
+}
+
+/// Ошибка чтения лога
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CombatLogError {
+    /// Файл не читается
+    Io(std::io::ErrorKind),
+    /// Не combat log (сигнатура не совпала)
+    BadMagic,
+    /// Версия формата новее / старее reader'а
+    UnsupportedVersion(u16),
+    /// Данные оборвались посреди записи
+    Truncated,
+    /// Неизвестный тег события
+    UnknownTag(u8),
+    /// Недопустимое значение поля enum
+    InvalidValue { field: &'static str, value: u8 },
+}
+
+impl fmt::Display for CombatLogError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CombatLogError::Io(kind) => write!(f, "combat log io error: {:?}", kind),
+            CombatLogError::BadMagic => write!(f, "not a combat log (bad magic)"),
+            CombatLogError::UnsupportedVersion(version) => write!(f, "unsupported combat log version {}", version),
+            CombatLogError::Truncated => write!(f, "combat log truncated"),
+            CombatLogError::UnknownTag(tag) => write!(f, "unknown combat log event tag {}", tag),
+            CombatLogError::InvalidValue { field, value } => write!(f, "invalid {} value {}", field, value),
+        }
+    }
+}
+
+impl std::error::Error for CombatLogError {}
+
+// ============================================================================
+// Encode
+// ============================================================================
+
+mod tag {
+    pub const FIRE_INTENT: u8 = 1;
+    pub const FIRED: u8 = 2;
+    pub const FIRE_DENIED: u8 = 3;
+    pub const MELEE_STARTED: u8 = 4;
+    pub const MELEE_HIT: u8 = 5;
+    pub const PARRY: u8 = 6;
+    pub const DAMAGE: u8 = 7;
+    pub const FLINCH: u8 = 8;
+    pub const GET_UP: u8 = 9;
+    pub const DIED: u8 = 10;
+}
+
+/// Лог → байты .vrcl
+///
+/// Записи пишутся по возрастанию tick (стабильная сортировка: порядок внутри tick сохраняется),
+/// дельта tick всегда неотрицательна — tick'и не переписываются.
+pub fn encode_combat_log(log: &CombatLog) -> Vec<u8> {
+    let mut out = Vec::with_capacity(16 + log.records.len() * 8);
+    out.extend_from_slice(&COMBAT_LOG_MAGIC);
+    out.extend_from_slice(&COMBAT_LOG_VERSION.to_le_bytes());
+    out.extend_from_slice(&log.seed.to_le_bytes());
+    write_varint(&mut out, log.records.len() as u64);
+
+    let mut records: Vec<&CombatLogRecord> = log.records.iter().collect();
+    records.sort_by_key(|record| record.tick);
+
+    let mut last_tick = 0;
+    for record in records {
+        write_varint(&mut out, record.tick - last_tick);
+        last_tick = record.tick;
+        encode_event(&mut out, &record.event);
+    }
+    out
+}
+
+fn encode_event(out: &mut Vec<u8>, event: &CombatLogEvent) {
+    match event {
+        CombatLogEvent::FireIntent { shooter, target } => {
+            out.push(tag::FIRE_INTENT);
+            write_actor(out, *shooter);
+            write_optional_actor(out, *target);
+        }
+        CombatLogEvent::Fired { shooter, target, damage, pellets } => {
+            out.push(tag::FIRED);
+            write_actor(out, *shooter);
+            write_optional_actor(out, *target);
+            write_varint(out, *damage as u64);
+            out.push(*pellets);
+        }
+        CombatLogEvent::FireDenied { shooter, reason } => {
+            out.push(tag::FIRE_DENIED);
+            write_actor(out, *shooter);
+            out.push(fire_block_code(*reason));
+        }
+        CombatLogEvent::MeleeStarted { attacker, attack_type } => {
+            out.push(tag::MELEE_STARTED);
+            write_actor(out, *attacker);
+            out.push(attack_type_code(attack_type));
+        }
+        CombatLogEvent::MeleeHit { attacker, target, damage, blocked, parried } => {
+            out.push(tag::MELEE_HIT);
+            write_actor(out, *attacker);
+            write_actor(out, *target);
+            write_varint(out, *damage as u64);
+            out.push(*blocked as u8 | (*parried as u8) << 1);
+        }
+        CombatLogEvent::Parry { attacker, defender } => {
+            out.push(tag::PARRY);
+            write_actor(out, *attacker);
+            write_actor(out, *defender);
+        }
+        CombatLogEvent::Damage { attacker, target, damage, source } => {
+            out.push(tag::DAMAGE);
+            write_actor(out, *attacker);
+            write_actor(out, *target);
+            write_varint(out, *damage as u64);
+            out.push(damage_source_code(*source));
+        }
+        CombatLogEvent::Flinch { entity, source, kind } => {
+            out.push(tag::FLINCH);
+            write_actor(out, *entity);
+            write_actor(out, *source);
+            out.push(flinch_code(*kind));
+        }
+        CombatLogEvent::GetUp { entity } => {
+            out.push(tag::GET_UP);
+            write_actor(out, *entity);
+        }
+        CombatLogEvent::Died { entity, killer } => {
+            out.push(tag::DIED);
+            write_actor(out, *entity);
+            write_optional_actor(out, *killer);
+        }
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7F) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+fn write_actor(out: &mut Vec<u8>, actor: LogActor) {
+    write_varint(out, actor.0);
+}
+
+fn write_optional_actor(out: &mut Vec<u8>, actor: Option<LogActor>) {
+    match actor {
+        Some(actor) => {
+            out.push(1);
+            write_actor(out, actor);
+        }
+        None => out.push(0),
+    }
+}
+
+fn fire_block_code(reason: FireBlock) -> u8 {
+    match reason {
+        FireBlock::Cooldown => 0,
+        FireBlock::Overheated => 1,
+        FireBlock::Jammed => 2,
+        FireBlock::OutOfAmmo => 3,
+        FireBlock::Busy => 4,
+    }
+}
+
+fn attack_type_code(attack_type: &MeleeAttackType) -> u8 {
+    match attack_type {
+        MeleeAttackType::Normal => 0,
+        MeleeAttackType::Heavy => 1,
+        MeleeAttackType::Quick => 2,
+        MeleeAttackType::Execution => 3,
+        MeleeAttackType::Bash => 4,
+        MeleeAttackType::ShieldBash => 5,
+    }
+}
+
+fn damage_source_code(source: DamageSource) -> u8 {
+    match source {
+        DamageSource::Melee => 0,
+        DamageSource::Ranged => 1,
+        DamageSource::Environmental => 2,
+    }
+}
+
+fn flinch_code(kind: FlinchKind) -> u8 {
+    match kind {
+        FlinchKind::Light => 0,
+        FlinchKind::Heavy => 1,
+        FlinchKind::Knockdown => 2,
+    }
+}
+
+// ============================================================================
+// Decode
+// ============================================================================
+
+/// Потоковый reader .vrcl: заголовок сразу, записи — итератором по порядку
+///
+/// ```ignore
+/// let reader = CombatLogReader::new(&bytes)?;
+/// for record in reader {
+///     let record = record?;
+/// }
+/// ```
+///
+/// Ошибка в записи завершает итерацию (дальше позиция не определена).
+pub struct CombatLogReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    header: CombatLogHeader,
+    remaining: u64,
+    last_tick: u64,
+    failed: bool,
+}
+
+impl<'a> CombatLogReader<'a> {
+    /// Проверить сигнатуру / версию и прочитать заголовок
+    pub fn new(bytes: &'a [u8]) -> Result<Self, CombatLogError> {
+        let mut reader = Self {
+            bytes,
+            position: 0,
+            header: CombatLogHeader { version: 0, seed: 0, record_count: 0 },
+            remaining: 0,
+            last_tick: 0,
+            failed: false,
+        };
+
+        if reader.take_bytes(4)? != COMBAT_LOG_MAGIC {
+            return Err(CombatLogError::BadMagic);
+        }
+        let version = u16::from_le_bytes(reader.take_array()?);
+        if version != COMBAT_LOG_VERSION {
+            return Err(CombatLogError::UnsupportedVersion(version));
+        }
+        let seed = u64::from_le_bytes(reader.take_array()?);
+        let record_count = reader.read_varint()?;
+
+        reader.header = CombatLogHeader { version, seed, record_count };
+        reader.remaining = record_count;
+        Ok(reader)
+    }
+
+    pub fn header(&self) -> CombatLogHeader {
+        self.header
+    }
+
+    fn take_bytes(&mut self, len: usize) -> Result<&'a [u8], CombatLogError> {
+        let end = self.position.checked_add(len).ok_or(CombatLogError::Truncated)?;
+        let slice = self.bytes.get(self.position..end).ok_or(CombatLogError::Truncated)?;
+        self.position = end;
+        Ok(slice)
+    }
+
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], CombatLogError> {
+        let mut array = [0; N];
+        array.copy_from_slice(self.take_bytes(N)?);
+        Ok(array)
+    }
+
+    fn read_u8(&mut self) -> Result<u8, CombatLogError> {
+        Ok(self.take_bytes(1)?[0])
+    }
+
+    fn read_varint(&mut self) -> Result<u64, CombatLogError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.read_u8()?;
+            value |= ((byte & 0x7F) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(CombatLogError::InvalidValue { field: "varint", value: 0x80 })
+    }
+
+    fn read_u32(&mut self) -> Result<u32, CombatLogError> {
+        let value = self.read_varint()?;
+        u32::try_from(value).map_err(|_| CombatLogError::InvalidValue { field: "u32", value: 0xFF })
+    }
+
+    fn read_actor(&mut self) -> Result<LogActor, CombatLogError> {
+        Ok(StableId(self.read_varint()?))
+    }
+
+    fn read_optional_actor(&mut self) -> Result<Option<LogActor>, CombatLogError> {
+        match self.read_u8()? {
+            0 => Ok(None),
+            1 => self.read_actor().map(Some),
+            value => Err(CombatLogError::InvalidValue { field: "optional actor", value }),
+        }
+    }
+
+    fn read_record(&mut self) -> Result<CombatLogRecord, CombatLogError> {
+        let tick = self
+            .last_tick
+            .checked_add(self.read_varint()?)
+            .ok_or(CombatLogError::InvalidValue { field: "tick", value: 0xFF })?;
+        self.last_tick = tick;
+
+        let event = match self.read_u8()? {
+            tag::FIRE_INTENT => CombatLogEvent::FireIntent {
+                shooter: self.read_actor()?,
+                target: self.read_optional_actor()?,
+            },
+            tag::FIRED => CombatLogEvent::Fired {
+                shooter: self.read_actor()?,
+                target: self.read_optional_actor()?,
+                damage: self.read_u32()?,
+                pellets: self.read_u8()?,
+            },
+            tag::FIRE_DENIED => CombatLogEvent::FireDenied {
+                shooter: self.read_actor()?,
+                reason: decode_fire_block(self.read_u8()?)?,
+            },
+            tag::MELEE_STARTED => CombatLogEvent::MeleeStarted {
+                attacker: self.read_actor()?,
+                attack_type: decode_attack_type(self.read_u8()?)?,
+            },
+            tag::MELEE_HIT => {
+                let attacker = self.read_actor()?;
+                let target = self.read_actor()?;
+                let damage = self.read_u32()?;
+                let flags = self.read_u8()?;
+                CombatLogEvent::MeleeHit {
+                    attacker,
+                    target,
+                    damage,
+                    blocked: flags & 0b01 != 0,
+                    parried: flags & 0b10 != 0,
+                }
+            }
+            tag::PARRY => CombatLogEvent::Parry {
+                attacker: self.read_actor()?,
+                defender: self.read_actor()?,
+            },
+            tag::DAMAGE => CombatLogEvent::Damage {
+                attacker: self.read_actor()?,
+                target: self.read_actor()?,
+                damage: self.read_u32()?,
+                source: decode_damage_source(self.read_u8()?)?,
+            },
+            tag::FLINCH => CombatLogEvent::Flinch {
+                entity: self.read_actor()?,
+                source: self.read_actor()?,
+                kind: decode_flinch(self.read_u8()?)?,
+            },
+            tag::GET_UP => CombatLogEvent::GetUp {
+                entity: self.read_actor()?,
+            },
+            tag::DIED => CombatLogEvent::Died {
+                entity: self.read_actor()?,
+                killer: self.read_optional_actor()?,
+            },
+            unknown => return Err(CombatLogError::UnknownTag(unknown)),
+        };
+
+        Ok(CombatLogRecord { tick, event })
+    }
+}
+
+impl Iterator for CombatLogReader<'_> {
+    type Item = Result<CombatLogRecord, CombatLogError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 || self.failed {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let record = self.read_record();
+        self.failed = record.is_err();
+        Some(record)
+    }
+}
+
+fn decode_fire_block(value: u8) -> Result<FireBlock, CombatLogError> {
+    Ok(match value {
+        0 => FireBlock::Cooldown,
+        1 => FireBlock::Overheated,
+        2 => FireBlock::Jammed,
+        3 => FireBlock::OutOfAmmo,
+        4 => FireBlock::Busy,
+        value => return Err(CombatLogError::InvalidValue { field: "fire block", value }),
+    })
+}
+
+fn decode_attack_type(value: u8) -> Result<MeleeAttackType, CombatLogError> {
+    Ok(match value {
+        0 => MeleeAttackType::Normal,
+        1 => MeleeAttackType::Heavy,
+        2 => MeleeAttackType::Quick,
+        3 => MeleeAttackType::Execution,
+        4 => MeleeAttackType::Bash,
+        5 => MeleeAttackType::ShieldBash,
+        value => return Err(CombatLogError::InvalidValue { field: "attack type", value }),
+    })
+}
+
+fn decode_damage_source(value: u8) -> Result<DamageSource, CombatLogError> {
+    Ok(match value {
+        0 => DamageSource::Melee,
+        1 => DamageSource::Ranged,
+        2 => DamageSource::Environmental,
+        value => return Err(CombatLogError::InvalidValue { field: "damage source", value }),
+    })
+}
+
+fn decode_flinch(value: u8) -> Result<FlinchKind, CombatLogError> {
+    Ok(match value {
+        0 => FlinchKind::Light,
+        1 => FlinchKind::Heavy,
+        2 => FlinchKind::Knockdown,
+        value => return Err(CombatLogError::InvalidValue { field: "flinch kind", value }),
+    })
+}
+
+/// Байты .vrcl → лог целиком
+pub fn decode_combat_log(bytes: &[u8]) -> Result<CombatLog, CombatLogError> {
+    let reader = CombatLogReader::new(bytes)?;
+    let seed = reader.header().seed;
+    let records = reader.collect::<Result<Vec<_>, _>>()?;
+    Ok(CombatLog { seed, records })
+}
+
+// ============================================================================
+// Files
+// ============================================================================
+
+/// Сохранить лог в файл (каталог создаётся)
+pub fn write_combat_log(path: &Path, log: &CombatLog) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, encode_combat_log(log))
+}
+
+/// Прочитать лог из файла
+pub fn read_combat_log(path: &Path) -> Result<CombatLog, CombatLogError> {
+    let bytes = std::fs::read(path).map_err(|error| CombatLogError::Io(error.kind()))?;
+    decode_combat_log(&bytes)
+}
@@ -0,0 +1,122 @@
+//! Tests for combat log binary format.
+
+#[cfg(test)]
+mod tests {
+    use super::super::components::*;
+    use super::super::format::*;
+    use crate::combat::{DamageSource, FireBlock, FlinchKind, MeleeAttackType};
+    use crate::StableId;
+    use bevy::prelude::Entity;
+
+    fn sample_log() -> CombatLog {
+        let player = StableId::new(0, 1);
+        let raider = StableId::new(0, 2);
+        let stray = CombatLog::unstable_id(Entity::from_raw(40));
+
+        let mut log = CombatLog::new(42);
+        log.push(10, CombatLogEvent::FireIntent { shooter: player, target: None });
+        log.push(10, CombatLogEvent::Fired { shooter: player, target: Some(raider), damage: 12, pellets: 8 });
+        log.push(11, CombatLogEvent::FireDenied { shooter: player, reason: FireBlock::Overheated });
+        log.push(30, CombatLogEvent::MeleeStarted { attacker: raider, attack_type: MeleeAttackType::Bash });
+        log.push(45, CombatLogEvent::MeleeHit { attacker: raider, target: player, damage: 300, blocked: true, parried: false });
+        log.push(45, CombatLogEvent::Parry { attacker: raider, defender: player });
+        log.push(46, CombatLogEvent::Damage { attacker: stray, target: player, damage: 5, source: DamageSource::Environmental });
+        log.push(46, CombatLogEvent::Flinch { entity: player, source: stray, kind: FlinchKind::Knockdown });
+        log.push(120, CombatLogEvent::GetUp { entity: player });
+        log.push(9_000, CombatLogEvent::Died { entity: raider, killer: Some(player) });
+        log.push(9_000, CombatLogEvent::Died { entity: stray, killer: None });
+        log
+    }
+
+    #[test]
+    fn test_round_trip_preserves_order_and_ticks() {
+        let log = sample_log();
+        let bytes = encode_combat_log(&log);
+        let decoded = decode_combat_log(&bytes).unwrap();
+
+        assert_eq!(decoded.seed, 42);
+        assert_eq!(decoded.records, log.records);
+    }
+
+    #[test]
+    fn test_reader_streams_header_and_records() {
+        let log = sample_log();
+        let bytes = encode_combat_log(&log);
+        let reader = CombatLogReader::new(&bytes).unwrap();
+
+        assert_eq!(
+            reader.header(),
+            CombatLogHeader { version: COMBAT_LOG_VERSION, seed: 42, record_count: log.len() as u64 }
+        );
+
+        let kills: Vec<_> = reader
+            .filter_map(Result::ok)
+            .filter(|record| matches!(record.event, CombatLogEvent::Died { killer: Some(_), .. }))
+            .collect();
+        assert_eq!(kills.len(), 1);
+        assert_eq!(kills[0].tick, 9_000);
+    }
+
+    #[test]
+    fn test_compact_encoding() {
+        let log = sample_log();
+        let bytes = encode_combat_log(&log);
+
+        // Типичная запись (малые id / урон / дельта tick) — единицы байт
+        assert!(bytes.len() < 16 + log.len() * 12, "log too large: {} bytes", bytes.len());
+    }
+
+    #[test]
+    fn test_rejects_bad_input() {
+        assert_eq!(CombatLogReader::new(b"NOPE").err(), Some(CombatLogError::BadMagic));
+        assert_eq!(CombatLogReader::new(b"VR").err(), Some(CombatLogError::Truncated));
+
+        let mut bytes = encode_combat_log(&sample_log());
+        bytes[4] = 99;
+        assert_eq!(CombatLogReader::new(&bytes).err(), Some(CombatLogError::UnsupportedVersion(99)));
+    }
+
+    #[test]
+    fn test_encode_sorts_out_of_order_ticks() {
+        let actor = StableId::new(0, 1);
+        let mut log = CombatLog::new(7);
+        log.push(20, CombatLogEvent::GetUp { entity: actor });
+        log.push(5, CombatLogEvent::Died { entity: actor, killer: None });
+        log.push(20, CombatLogEvent::Parry { attacker: actor, defender: actor });
+
+        let decoded = decode_combat_log(&encode_combat_log(&log)).unwrap();
+
+        let ticks: Vec<u64> = decoded.records.iter().map(|record| record.tick).collect();
+        assert_eq!(ticks, vec![5, 20, 20]);
+        assert!(matches!(decoded.records[1].event, CombatLogEvent::GetUp { .. }));
+        assert!(matches!(decoded.records[2].event, CombatLogEvent::Parry { .. }));
+    }
+
+    #[test]
+    fn test_tick_overflow_is_invalid_value() {
+        let actor = StableId::new(0, 1);
+        let mut log = CombatLog::new(7);
+        log.push(u64::MAX, CombatLogEvent::GetUp { entity: actor });
+        log.push(u64::MAX, CombatLogEvent::GetUp { entity: actor });
+        let mut bytes = encode_combat_log(&log);
+
+        // Дельта второй записи (0) → 1: u64::MAX + 1 переполняет tick
+        let second_delta = bytes.len() - 3;
+        assert_eq!(bytes[second_delta], 0);
+        bytes[second_delta] = 1;
+
+        let results: Vec<_> = CombatLogReader::new(&bytes).unwrap().collect();
+        assert_eq!(results.last(), Some(&Err(CombatLogError::InvalidValue { field: "tick", value: 0xFF })));
+    }
+
+    #[test]
+    fn test_truncated_record_stops_iteration() {
+        let bytes = encode_combat_log(&sample_log());
+        let cut = &bytes[..bytes.len() - 1];
+
+        let results: Vec<_> = CombatLogReader::new(cut).unwrap().collect();
+        assert_eq!(results.last(), Some(&Err(CombatLogError::Truncated)));
+        assert!(results[..results.len() - 1].iter().all(Result::is_ok));
+        assert_eq!(decode_combat_log(cut).err(), Some(CombatLogError::Truncated));
+    }
+}
@@ -0,0 +1,54 @@
+//! Combat log module — упорядоченный поток событий боя матча для внешней статистики (esports-style)
+//!
+//! # Architecture
+//!
+//! Каждый fixed tick события боя (выстрелы, отказы, замахи, попадания, парирования, урон,
+//! реакции, смерти) копируются в `CombatLog` с tick симуляции и `StableId` участников.
+//! В конце матча лог пишется компактным бинарным файлом (`.vrcl`, см. `format`)
+//! в `CombatLogExport::dir` и начинается заново.
+//!
+//! **Flow:**
+//! - события боя tick → `record_combat_log` → `CombatLog`
+//! - `ArenaMatchEnded` / `RunExtracted` / `RunFailed` → `match_<seed>_<tick>.vrcl`
+//!
+//! Чтение: `CombatLogReader` (потоково) / `decode_combat_log` / `read_combat_log`.
+
+use bevy::prelude::*;
+use crate::DeterministicRng;
+
+pub mod components;
+pub mod format;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+#[cfg(test)]
+mod format_tests;
+
+// Re-exports
+pub use components::*;
+pub use format::*;
+pub use systems::*;
+
+/// Combat Log Plugin
+///
+/// Пишет лог в FixedPostUpdate — после всех боевых систем tick (события tick уже отправлены).
+pub struct CombatLogPlugin;
+
+impl Plugin for CombatLogPlugin {
+    fn build(&self, app: &mut App) {
+        let seed = app.world().get_resource::<DeterministicRng>().map_or(0, |rng| rng.seed);
+
+        app.insert_resource(CombatLog::new(seed))
+            .init_resource::<CombatLogExport>()
+            .add_systems(
+                FixedPostUpdate,
+                (
+                    record_combat_log,              // 1. События боя tick → CombatLog
+                    export_combat_log_on_match_end, // 2. Конец матча → файл + новый лог
+                )
+                    .chain(),
+            );
+    }
+}
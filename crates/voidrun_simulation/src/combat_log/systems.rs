@@ -0,0 +1,133 @@
+//! Combat log systems (ECS события боя → CombatLog → файл в конце матча).
+
+use bevy::prelude::*;
+use crate::combat::{
+    DamageDealt, EntityDied, FireDenied, FlinchTriggered, KnockdownGetUp, MeleeAttackStarted, MeleeHit, ParrySuccess,
+    WeaponFireIntent, WeaponFired,
+};
+use crate::game_mode::{ArenaMatchEnded, RunExtracted, RunFailed};
+use crate::{SimulationTick, StableId};
+use super::components::{CombatLog, CombatLogEvent, CombatLogExport, LogActor};
+use super::format::write_combat_log;
+
+/// Entity → id записи (StableId, иначе `CombatLog::unstable_id`)
+fn actor_id(stable_ids: &Query<&StableId>, entity: Entity) -> LogActor {
+    stable_ids.get(entity).copied().unwrap_or_else(|_| CombatLog::unstable_id(entity))
+}
+
+/// System: события боя тика → CombatLog
+///
+/// Порядок внутри tick — по фазам: намерение выстрела → выстрел / отказ → замах →
+/// попадание → парирование → урон → реакция → подъём → смерть.
+#[allow(clippy::too_many_arguments)]
+pub fn record_combat_log(
+    mut fire_intents: EventReader<WeaponFireIntent>,
+    mut fired: EventReader<WeaponFired>,
+    mut denied: EventReader<FireDenied>,
+    mut melee_started: EventReader<MeleeAttackStarted>,
+    mut melee_hits: EventReader<MeleeHit>,
+    mut parries: EventReader<ParrySuccess>,
+    mut damage: EventReader<DamageDealt>,
+    mut flinches: EventReader<FlinchTriggered>,
+    mut get_ups: EventReader<KnockdownGetUp>,
+    mut deaths: EventReader<EntityDied>,
+    stable_ids: Query<&StableId>,
+    tick: Res<SimulationTick>,
+    mut log: ResMut<CombatLog>,
+) {
+    let tick = tick.get();
+    let id = |entity: Entity| actor_id(&stable_ids, entity);
+
+    for intent in fire_intents.read() {
+        log.push(tick, CombatLogEvent::FireIntent {
+            shooter: id(intent.shooter),
+            target: intent.target.map(id),
+        });
+    }
+    for shot in fired.read() {
+        log.push(tick, CombatLogEvent::Fired {
+            shooter: id(shot.shooter),
+            target: shot.target.map(id),
+            damage: shot.damage,
+            pellets: shot.fire_mode.pellets(),
+        });
+    }
+    for denial in denied.read() {
+        log.push(tick, CombatLogEvent::FireDenied {
+            shooter: id(denial.shooter),
+            reason: denial.reason,
+        });
+    }
+    for started in melee_started.read() {
+        log.push(tick, CombatLogEvent::MeleeStarted {
+            attacker: id(started.attacker),
+            attack_type: started.attack_type.clone(),
+        });
+    }
+    for hit in melee_hits.read() {
+        log.push(tick, CombatLogEvent::MeleeHit {
+            attacker: id(hit.attacker),
+            target: id(hit.target),
+            damage: hit.damage,
+            blocked: hit.was_blocked,
+            parried: hit.was_parried,
+        });
+    }
+    for parry in parries.read() {
+        log.push(tick, CombatLogEvent::Parry {
+            attacker: id(parry.attacker),
+            defender: id(parry.defender),
+        });
+    }
+    for dealt in damage.read() {
+        log.push(tick, CombatLogEvent::Damage {
+            attacker: id(dealt.attacker),
+            target: id(dealt.target),
+            damage: dealt.damage,
+            source: dealt.source,
+        });
+    }
+    for flinch in flinches.read() {
+        log.push(tick, CombatLogEvent::Flinch {
+            entity: id(flinch.entity),
+            source: id(flinch.source),
+            kind: flinch.kind,
+        });
+    }
+    for get_up in get_ups.read() {
+        log.push(tick, CombatLogEvent::GetUp { entity: id(get_up.entity) });
+    }
+    for died in deaths.read() {
+        log.push(tick, CombatLogEvent::Died {
+            entity: id(died.entity),
+            killer: died.killer.map(id),
+        });
+    }
+}
+
+/// System: конец матча (arena / extraction / провал run) → файл лога + новый лог
+///
+/// Без каталога экспорта (`CombatLogExport::dir`) лог просто сбрасывается.
+pub fn export_combat_log_on_match_end(
+    mut arena_ended: EventReader<ArenaMatchEnded>,
+    mut extracted: EventReader<RunExtracted>,
+    mut failed: EventReader<RunFailed>,
+    export: Res<CombatLogExport>,
+    tick: Res<SimulationTick>,
+    mut log: ResMut<CombatLog>,
+) {
+    let ended = arena_ended.read().count() + extracted.read().count() + failed.read().count() > 0;
+    if !ended {
+        return;
+    }
+
+    if let Some(path) = export.file_path(log.seed, tick.get()) {
+        match write_combat_log(&path, &log) {
+            Ok(()) => crate::logger::log(&format!("📼 Combat log: {} records → {}", log.len(), path.display())),
+            Err(error) => crate::logger::log_error(&format!("Combat log export failed ({}): {}", path.display(), error)),
+        }
+    }
+
+    let seed = log.seed;
+    *log = CombatLog::new(seed);
+}
@@ -0,0 +1,78 @@
+//! Companion components (спутники игрока, их приказы, RTS выделение).
+
+use bevy::prelude::*;
+
+/// Текущий приказ спутника.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum CompanionOrder {
+    /// Держаться рядом с лидером, драться с замеченными врагами в пределах leash
+    Follow,
+    /// Стоять на точке (стрелять / драться с места)
+    HoldPosition { position: Vec3 },
+    /// Атаковать конкретную цель (до её смерти → Follow)
+    AttackTarget { target: Entity },
+    /// Выйти из боя и вернуться к лидеру (дошёл → Follow)
+    Regroup,
+}
+
+/// Спутник: дружественный NPC, следующий за лидером (игроком).
+///
+/// Приказы — `CompanionCommandIntent` от лидера. Поверх AIState:
+/// FSM выбирает цели как обычно, движение и переопределения — `companion_movement_from_orders`.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Companion {
+    /// Лидер (None → `assign_companion_leaders` привяжет игрока той же фракции)
+    pub leader: Option<Entity>,
+    pub order: CompanionOrder,
+    /// Дистанция, с которой спутник догоняет лидера (метры)
+    pub follow_distance: f32,
+}
+
+impl Default for Companion {
+    fn default() -> Self {
+        Self {
+            leader: None,
+            order: CompanionOrder::Follow,
+            follow_distance: Self::DEFAULT_FOLLOW_DISTANCE,
+        }
+    }
+}
+
+impl Companion {
+    /// Дистанция следования по умолчанию (метры)
+    pub const DEFAULT_FOLLOW_DISTANCE: f32 = 3.0;
+
+    /// Follow: дальше от лидера бой бросается — спутник возвращается (метры)
+    pub const LEASH_DISTANCE: f32 = 15.0;
+
+    /// HoldPosition: допуск до точки (метры)
+    pub const HOLD_TOLERANCE: f32 = 1.0;
+
+    pub fn new(leader: Entity) -> Self {
+        Self {
+            leader: Some(leader),
+            ..Default::default()
+        }
+    }
+
+    /// Отстал от лидера — догонять
+    pub fn should_close_in(&self, distance_to_leader: f32) -> bool {
+        distance_to_leader > self.follow_distance
+    }
+
+    /// Regroup завершён (рядом с лидером)
+    pub fn has_regrouped(&self, distance_to_leader: f32) -> bool {
+        !self.should_close_in(distance_to_leader)
+    }
+
+    /// Follow: слишком далеко от лидера для боя
+    pub fn is_beyond_leash(distance_to_leader: f32) -> bool {
+        distance_to_leader > Self::LEASH_DISTANCE
+    }
+}
+
+/// Маркер: юнит выделен в RTS режиме (Godot: клик / рамка по дружественным NPC)
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct Selected;
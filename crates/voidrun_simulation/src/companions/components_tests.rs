@@ -0,0 +1,33 @@
+//! Tests for companion components.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::*;
+
+    #[test]
+    fn companion_defaults_to_follow_without_leader() {
+        let companion = Companion::default();
+
+        assert_eq!(companion.leader, None);
+        assert_eq!(companion.order, CompanionOrder::Follow);
+
+        let bound = Companion::new(Entity::from_raw(1));
+        assert_eq!(bound.leader, Some(Entity::from_raw(1)));
+    }
+
+    #[test]
+    fn companion_closes_in_only_beyond_follow_distance() {
+        let companion = Companion::default();
+
+        assert!(!companion.should_close_in(Companion::DEFAULT_FOLLOW_DISTANCE));
+        assert!(companion.has_regrouped(1.0));
+        assert!(companion.should_close_in(Companion::DEFAULT_FOLLOW_DISTANCE + 0.5));
+    }
+
+    #[test]
+    fn follow_leash_drops_far_fights() {
+        assert!(!Companion::is_beyond_leash(Companion::LEASH_DISTANCE));
+        assert!(Companion::is_beyond_leash(Companion::LEASH_DISTANCE + 1.0));
+    }
+}
@@ -0,0 +1,52 @@
+//! Companion events (приказы лидера, RTS приказы выделенным юнитам).
+
+use bevy::prelude::*;
+use super::components::CompanionOrder;
+use crate::formation::FormationShape;
+
+/// Приказ спутникам (клавиши игрока → Godot input).
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum CompanionCommand {
+    /// За мной
+    Follow,
+    /// Стоять здесь (точка — текущая позиция спутника)
+    HoldPosition,
+    /// Атаковать цель под прицелом
+    AttackTarget { target: Entity },
+    /// Ко мне (выйти из боя)
+    Regroup,
+}
+
+/// Intent: лидер отдаёт приказ всем своим спутникам
+#[derive(Event, Debug, Clone)]
+pub struct CompanionCommandIntent {
+    pub leader: Entity,
+    pub command: CompanionCommand,
+}
+
+/// Событие: приказ спутника сменился (приказ лидера / цель мертва / вернулся к лидеру)
+#[derive(Event, Debug, Clone)]
+pub struct CompanionOrderChanged {
+    pub companion: Entity,
+    pub order: CompanionOrder,
+}
+
+/// RTS приказ выделенным юнитам (ПКМ в RTS камере).
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum UnitOrder {
+    /// Идти в точку (строем `formation`, лицом по ходу движения) и держать её
+    MoveTo { position: Vec3, formation: FormationShape },
+    /// Атаковать врага под курсором
+    Attack { target: Entity },
+}
+
+/// Intent: приказ выделенным юнитам (Godot RTS input → ECS)
+///
+/// Юниты чужой фракции / мёртвые / без AI — игнор.
+#[derive(Event, Debug, Clone)]
+pub struct UnitOrderIntent {
+    /// Кто приказывает (игрок) — фракция и лидер отряда
+    pub issuer: Entity,
+    pub units: Vec<Entity>,
+    pub order: UnitOrder,
+}
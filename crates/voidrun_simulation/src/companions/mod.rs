@@ -0,0 +1,57 @@
+//! Companions module — спутники игрока и приказы
+//!
+//! # Architecture
+//!
+//! Спутник — обычный AI NPC (AIState FSM) с компонентом `Companion`:
+//!
+//! **Flow:**
+//! - Лидер — игрок той же фракции (`assign_companion_leaders`)
+//! - Клавиши приказов (Godot input) → `CompanionCommandIntent` → `Companion::order`
+//! - После `ai_fsm_transitions`: приказ → AIState override + MovementCommand
+//!   (`ai_movement_from_state` спутников пропускает)
+//!
+//! Приказы: Follow, HoldPosition, AttackTarget, Regroup.
+//!
+//! **RTS:** выделенные (`Selected`, Godot click / box select) юниты по ПКМ →
+//! `UnitOrderIntent` → тот же `Companion::order` (MoveTo → HoldPosition строем, Attack → AttackTarget).
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Companions Plugin
+///
+/// Регистрирует приказы спутников в FixedUpdate (после FSM: поверх выбранного AIState).
+pub struct CompanionsPlugin;
+
+impl Plugin for CompanionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CompanionCommandIntent>()
+            .add_event::<CompanionOrderChanged>()
+            .add_event::<UnitOrderIntent>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    deselect_dead_units,            // 1. Dead → снять Selected
+                    assign_companion_leaders,       // 2. Спутник без лидера → игрок фракции
+                    apply_companion_commands,       // 3. CompanionCommandIntent → Companion::order
+                    apply_unit_orders,              // 4. UnitOrderIntent (RTS) → Companion::order
+                    companion_movement_from_orders, // 5. Приказ → AIState override + MovementCommand
+                )
+                    .chain()
+                    .after(crate::ai::ai_fsm_transitions)
+                    .before(crate::ai::ai_movement_from_state),
+            );
+    }
+}
@@ -0,0 +1,271 @@
+//! Companion systems (привязка к лидеру, приказы / RTS приказы → AIState / MovementCommand).
+
+use bevy::prelude::*;
+use crate::ai::{AIState, SpottedEnemies};
+use crate::combat::{Dead, Downed, KnockdownState};
+use crate::components::{Actor, Health, MovementCommand};
+use crate::player::Player;
+use crate::StrategicPosition;
+use crate::formation::formation_offset;
+use super::components::{Companion, CompanionOrder, Selected};
+use super::events::{CompanionCommand, CompanionCommandIntent, CompanionOrderChanged, UnitOrder, UnitOrderIntent};
+
+/// System: спутник без лидера (или лидер мёртв / despawned) → игрок той же фракции
+pub fn assign_companion_leaders(
+    mut companions: Query<(Entity, &mut Companion, &Actor)>,
+    players: Query<(Entity, &Actor), (With<Player>, Without<Dead>)>,
+) {
+    for (entity, mut companion, actor) in companions.iter_mut() {
+        if companion.leader.is_some_and(|leader| players.contains(leader)) {
+            continue;
+        }
+
+        let leader = players
+            .iter()
+            .find(|(_, player)| player.faction_id == actor.faction_id)
+            .map(|(player, _)| player);
+        if companion.leader != leader {
+            companion.leader = leader;
+            crate::logger::log(&format!("🤝 Companion {:?} now follows {:?}", entity, leader));
+        }
+    }
+}
+
+/// System: CompanionCommandIntent → приказ всем спутникам лидера
+///
+/// AttackTarget по союзнику / мёртвому — игнор. HoldPosition — точка, где спутник стоит сейчас.
+pub fn apply_companion_commands(
+    mut intents: EventReader<CompanionCommandIntent>,
+    mut companions: Query<(Entity, &mut Companion, &Actor, &StrategicPosition)>,
+    targets: Query<(&Actor, &Health)>,
+    mut changed_events: EventWriter<CompanionOrderChanged>,
+) {
+    for intent in intents.read() {
+        for (entity, mut companion, actor, position) in companions.iter_mut() {
+            if companion.leader != Some(intent.leader) {
+                continue;
+            }
+
+            let order = match intent.command {
+                CompanionCommand::Follow => CompanionOrder::Follow,
+                CompanionCommand::HoldPosition => CompanionOrder::HoldPosition {
+                    position: position.to_world_position(0.5),
+                },
+                CompanionCommand::AttackTarget { target } => {
+                    let hostile = targets
+                        .get(target)
+                        .is_ok_and(|(target_actor, health)| target_actor.faction_id != actor.faction_id && health.is_alive());
+                    if !hostile {
+                        continue;
+                    }
+                    CompanionOrder::AttackTarget { target }
+                }
+                CompanionCommand::Regroup => CompanionOrder::Regroup,
+            };
+
+            companion.order = order;
+            changed_events.write(CompanionOrderChanged { companion: entity, order });
+            crate::logger::log(&format!("🤝 Companion {:?} order: {:?}", entity, order));
+        }
+    }
+}
+
+/// System: погибший юнит снимается с выделения (Godot убирает подсветку по RemovedComponents)
+pub fn deselect_dead_units(selected: Query<Entity, (With<Selected>, With<Dead>)>, mut commands: Commands) {
+    for entity in selected.iter() {
+        commands.entity(entity).remove::<Selected>();
+    }
+}
+
+/// System: UnitOrderIntent (RTS) → приказ спутника каждому юниту
+///
+/// Юнит без `Companion` становится спутником приказавшего (дальше — общий pipeline приказов):
+/// - MoveTo → HoldPosition в своей клетке строя (лицом от центра отряда к точке)
+/// - Attack → AttackTarget (цель союзник / мертва — приказ игнор)
+///
+/// Чужая фракция / мёртвые / без AI — пропуск.
+pub fn apply_unit_orders(
+    mut intents: EventReader<UnitOrderIntent>,
+    mut units: Query<(&Actor, &Health, &StrategicPosition, Option<&mut Companion>), With<AIState>>,
+    targets: Query<(&Actor, &Health)>,
+    mut changed_events: EventWriter<CompanionOrderChanged>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((issuer, _)) = targets.get(intent.issuer) else {
+            continue;
+        };
+        let faction_id = issuer.faction_id;
+
+        if let UnitOrder::Attack { target } = intent.order {
+            let hostile = targets
+                .get(target)
+                .is_ok_and(|(target_actor, health)| target_actor.faction_id != faction_id && health.is_alive());
+            if !hostile {
+                continue;
+            }
+        }
+
+        let obedient: Vec<Entity> = intent
+            .units
+            .iter()
+            .copied()
+            .filter(|unit| {
+                *unit != intent.issuer
+                    && units
+                        .get(*unit)
+                        .is_ok_and(|(actor, health, ..)| actor.faction_id == faction_id && health.is_alive())
+            })
+            .collect();
+        if obedient.is_empty() {
+            continue;
+        }
+
+        let centroid = obedient
+            .iter()
+            .filter_map(|unit| units.get(*unit).ok())
+            .map(|(_, _, position, _)| position.to_world_position(0.5))
+            .sum::<Vec3>()
+            / obedient.len() as f32;
+
+        for (index, unit) in obedient.iter().copied().enumerate() {
+            let order = match intent.order {
+                UnitOrder::MoveTo { position, formation } => CompanionOrder::HoldPosition {
+                    position: position + formation_offset(formation, index, obedient.len(), position - centroid),
+                },
+                UnitOrder::Attack { target } => CompanionOrder::AttackTarget { target },
+            };
+
+            let Ok((_, _, _, companion)) = units.get_mut(unit) else {
+                continue;
+            };
+            match companion {
+                Some(mut companion) => {
+                    companion.leader = Some(intent.issuer);
+                    companion.order = order;
+                }
+                None => {
+                    commands.entity(unit).insert(Companion {
+                        order,
+                        ..Companion::new(intent.issuer)
+                    });
+                }
+            }
+
+            changed_events.write(CompanionOrderChanged { companion: unit, order });
+            crate::logger::log(&format!("🎯 Unit {:?} order: {:?}", unit, order));
+        }
+    }
+}
+
+/// System: приказ спутника → AIState override + MovementCommand (вместо `ai_movement_from_state`)
+///
+/// Запускается после `ai_fsm_transitions`:
+/// - Follow: бой с замеченными в пределах leash, иначе держится рядом с лидером
+/// - HoldPosition: бой с места, уведён с точки — возвращается
+/// - AttackTarget: Combat по цели (цель мертва → Follow)
+/// - Regroup: замеченные враги сброшены, идёт к лидеру (дошёл → Follow)
+///
+/// KnockdownState / Downed / Dead — стоим на месте (Idle).
+#[allow(clippy::type_complexity)]
+pub fn companion_movement_from_orders(
+    mut companions: Query<(
+        Entity,
+        &mut Companion,
+        &mut AIState,
+        &mut MovementCommand,
+        &mut SpottedEnemies,
+        &StrategicPosition,
+        Has<KnockdownState>,
+        Has<Downed>,
+    )>,
+    others: Query<(&StrategicPosition, &Health)>,
+    mut changed_events: EventWriter<CompanionOrderChanged>,
+) {
+    for (entity, mut companion, mut state, mut command, mut spotted, position, knocked_down, downed) in companions.iter_mut() {
+        if knocked_down || downed || *state == AIState::Dead {
+            set_command(&mut command, MovementCommand::Idle);
+            continue;
+        }
+
+        let current_pos = position.to_world_position(0.5);
+        let leader_distance = companion
+            .leader
+            .and_then(|leader| others.get(leader).ok())
+            .map(|(leader_pos, _)| leader_pos.to_world_position(0.5).distance(current_pos));
+
+        match companion.order {
+            CompanionOrder::AttackTarget { target } => {
+                let target_alive = others.get(target).is_ok_and(|(_, health)| health.is_alive());
+                if !target_alive {
+                    companion.order = CompanionOrder::Follow;
+                    changed_events.write(CompanionOrderChanged { companion: entity, order: companion.order });
+                    continue;
+                }
+
+                // Цель могла выпасть из зрения — держим её в SpottedEnemies, иначе FSM бросит бой
+                if !spotted.enemies.contains(&target) {
+                    spotted.enemies.push(target);
+                }
+                if *state != (AIState::Combat { target }) {
+                    *state = AIState::Combat { target };
+                }
+                set_command(&mut command, MovementCommand::FollowEntity { target });
+            }
+
+            CompanionOrder::Regroup => {
+                spotted.enemies.clear();
+                if matches!(*state, AIState::Combat { .. } | AIState::Retreat { .. }) {
+                    *state = AIState::Idle;
+                }
+
+                let (Some(leader), Some(distance)) = (companion.leader, leader_distance) else {
+                    set_command(&mut command, MovementCommand::Idle);
+                    continue;
+                };
+                if companion.has_regrouped(distance) {
+                    companion.order = CompanionOrder::Follow;
+                    changed_events.write(CompanionOrderChanged { companion: entity, order: companion.order });
+                    set_command(&mut command, MovementCommand::Idle);
+                } else {
+                    set_command(&mut command, MovementCommand::FollowEntity { target: leader });
+                }
+            }
+
+            CompanionOrder::HoldPosition { position: hold } => {
+                if current_pos.distance(hold) > Companion::HOLD_TOLERANCE {
+                    set_command(&mut command, MovementCommand::MoveToPosition { target: hold });
+                } else {
+                    set_command(&mut command, MovementCommand::Stop);
+                }
+            }
+
+            CompanionOrder::Follow => {
+                let beyond_leash = leader_distance.is_some_and(Companion::is_beyond_leash);
+                let combat_command = match *state {
+                    AIState::Combat { target } if !beyond_leash => Some(MovementCommand::FollowEntity { target }),
+                    AIState::Retreat { from_target: Some(target), .. } => Some(MovementCommand::RetreatFrom { target }),
+                    _ => None,
+                };
+                if let Some(combat_command) = combat_command {
+                    set_command(&mut command, combat_command);
+                    continue;
+                }
+
+                match (companion.leader, leader_distance) {
+                    (Some(leader), Some(distance)) if companion.should_close_in(distance) => {
+                        set_command(&mut command, MovementCommand::FollowEntity { target: leader });
+                    }
+                    _ => set_command(&mut command, MovementCommand::Idle),
+                }
+            }
+        }
+    }
+}
+
+/// Записать команду только при смене (иначе Changed<MovementCommand> спамит Godot)
+fn set_command(command: &mut Mut<MovementCommand>, new_command: MovementCommand) {
+    if **command != new_command {
+        **command = new_command;
+    }
+}
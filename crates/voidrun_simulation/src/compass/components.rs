@@ -0,0 +1,116 @@
+//! Compass components (маркеры мира, курс камеры, данные полосы).
+
+use bevy::prelude::*;
+
+/// Тип маркера (иконка / цвет на полосе)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum MarkerKind {
+    Objective,
+    CapturePoint,
+    Extraction,
+    Waypoint,
+}
+
+/// Точка мира, отслеживаемая компасом.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct WorldMarker {
+    pub label: String,
+    pub kind: MarkerKind,
+    /// World position (обновляется `sync_world_markers` для подвижных источников)
+    pub position: Vec3,
+}
+
+impl WorldMarker {
+    pub fn new(label: impl Into<String>, kind: MarkerKind, position: Vec3) -> Self {
+        Self {
+            label: label.into(),
+            kind,
+            position,
+        }
+    }
+}
+
+/// Yaw камеры игрока (радианы, Godot `rotation.y`; 0 — взгляд в -Z)
+///
+/// Пишет Godot каждый кадр в FPS режиме.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct CameraYaw(pub f32);
+
+/// Сторона света на полосе
+#[derive(Debug, Clone, PartialEq)]
+pub struct CardinalMark {
+    pub label: &'static str,
+    /// Смещение от курса (градусы, -180..180, + = правее центра)
+    pub offset: f32,
+}
+
+/// Пеленг маркера на полосе
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarkerBearing {
+    pub marker: Entity,
+    pub label: String,
+    pub kind: MarkerKind,
+    /// Смещение от курса (градусы, -180..180, + = правее центра)
+    pub offset: f32,
+    /// Дистанция по XZ (метры)
+    pub distance: f32,
+}
+
+/// Данные компасной полосы (resource, пересчитывается `update_compass`)
+///
+/// `active == false` — игрока нет, HUD скрывает полосу.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct Compass {
+    pub active: bool,
+    /// Курс игрока (градусы 0..360, 0 = север)
+    pub heading: f32,
+    pub cardinals: Vec<CardinalMark>,
+    pub markers: Vec<MarkerBearing>,
+}
+
+/// Стороны света (пеленг в градусах)
+const CARDINALS: [(&str, f32); 8] = [
+    ("N", 0.0),
+    ("NE", 45.0),
+    ("E", 90.0),
+    ("SE", 135.0),
+    ("S", 180.0),
+    ("SW", 225.0),
+    ("W", 270.0),
+    ("NW", 315.0),
+];
+
+impl Compass {
+    /// Курс из yaw камеры (градусы 0..360, по часовой от севера)
+    pub fn heading_from_yaw(yaw: f32) -> f32 {
+        (-yaw.to_degrees()).rem_euclid(360.0)
+    }
+
+    /// Пеленг из `from` на `to` (градусы 0..360, высота не учитывается)
+    pub fn bearing(from: Vec3, to: Vec3) -> f32 {
+        let offset = to - from;
+        offset.x.atan2(-offset.z).to_degrees().rem_euclid(360.0)
+    }
+
+    /// Смещение пеленга относительно курса (-180..180)
+    pub fn relative(bearing: f32, heading: f32) -> f32 {
+        let offset = (bearing - heading).rem_euclid(360.0);
+        if offset > 180.0 {
+            offset - 360.0
+        } else {
+            offset
+        }
+    }
+
+    /// Стороны света относительно курса
+    pub fn cardinals_for(heading: f32) -> Vec<CardinalMark> {
+        CARDINALS
+            .iter()
+            .map(|&(label, bearing)| CardinalMark {
+                label,
+                offset: Self::relative(bearing, heading),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,32 @@
+//! Tests for compass math (курс, пеленг, смещение на полосе).
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::*;
+
+    #[test]
+    fn test_heading_from_yaw() {
+        assert!(Compass::heading_from_yaw(0.0).abs() < 1e-4);
+        // Поворот влево (yaw > 0) → запад
+        assert!((Compass::heading_from_yaw(std::f32::consts::FRAC_PI_2) - 270.0).abs() < 1e-3);
+        assert!((Compass::heading_from_yaw(-std::f32::consts::FRAC_PI_2) - 90.0).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_bearing_cardinal_directions() {
+        assert!(Compass::bearing(Vec3::ZERO, Vec3::new(0.0, 5.0, -10.0)).abs() < 1e-4);
+        assert!((Compass::bearing(Vec3::ZERO, Vec3::new(10.0, 0.0, 0.0)) - 90.0).abs() < 1e-4);
+        assert!((Compass::bearing(Vec3::ZERO, Vec3::new(0.0, 0.0, 10.0)) - 180.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_relative_wraps_around_north() {
+        assert!((Compass::relative(10.0, 350.0) - 20.0).abs() < 1e-4);
+        assert!((Compass::relative(350.0, 10.0) + 20.0).abs() < 1e-4);
+
+        let cardinals = Compass::cardinals_for(90.0);
+        let east = cardinals.iter().find(|mark| mark.label == "E").unwrap();
+        assert!(east.offset.abs() < 1e-4);
+    }
+}
@@ -0,0 +1,45 @@
+//! Compass module — данные компасной полосы FPS HUD
+//!
+//! # Architecture
+//!
+//! **Flow:**
+//! - Godot: yaw камеры игрока → `CameraYaw` (каждый кадр, FPS режим)
+//! - ECS `update_compass`: курс, стороны света и пеленги `WorldMarker` → `Compass` resource
+//! - Godot HUD рисует полосу вверху экрана из `Compass` (ECS не знает про UI)
+//!
+//! Курс: 0° = север (-Z, "вперёд" Godot), по часовой стрелке (90° = восток, +X).
+//! Маркеры: объективные предметы, точки эвакуации (+ любые entity с `WorldMarker`).
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use systems::*;
+
+/// Compass Plugin
+///
+/// Регистрирует маркеры и расчёт компаса в FixedUpdate.
+pub struct CompassPlugin;
+
+impl Plugin for CompassPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<CameraYaw>()
+            .init_resource::<Compass>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    attach_world_markers, // 1. Объективы / эвакуация → WorldMarker
+                    sync_world_markers,   // 2. Позиции маркеров за источниками
+                    update_compass,       // 3. Курс + пеленги → Compass
+                )
+                    .chain(),
+            );
+    }
+}
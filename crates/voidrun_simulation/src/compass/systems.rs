@@ -0,0 +1,73 @@
+//! Compass systems (маркеры источников, расчёт полосы).
+
+use bevy::prelude::*;
+use crate::game_mode::ExtractionPoint;
+use crate::objective::ObjectiveItem;
+use crate::player::Player;
+use crate::StrategicPosition;
+use super::components::{CameraYaw, Compass, MarkerBearing, MarkerKind, WorldMarker};
+
+/// System: новые объективные предметы / точки эвакуации → WorldMarker
+///
+/// Зона захвата объектива — отдельный маркер-entity (статичная точка).
+pub fn attach_world_markers(
+    objectives: Query<(Entity, &ObjectiveItem), Added<ObjectiveItem>>,
+    extraction_points: Query<(Entity, &ExtractionPoint), Added<ExtractionPoint>>,
+    mut commands: Commands,
+) {
+    for (entity, objective) in objectives.iter() {
+        commands
+            .entity(entity)
+            .insert(WorldMarker::new("Core", MarkerKind::Objective, objective.position));
+        commands.spawn(WorldMarker::new(
+            "Capture",
+            MarkerKind::CapturePoint,
+            objective.capture_point,
+        ));
+    }
+
+    for (entity, point) in extraction_points.iter() {
+        commands
+            .entity(entity)
+            .insert(WorldMarker::new("Extraction", MarkerKind::Extraction, point.position));
+    }
+}
+
+/// System: подвижные источники (объектив у носителя) → позиция маркера
+pub fn sync_world_markers(mut markers: Query<(&mut WorldMarker, &ObjectiveItem), Changed<ObjectiveItem>>) {
+    for (mut marker, objective) in markers.iter_mut() {
+        marker.position = objective.position;
+    }
+}
+
+/// System: CameraYaw + позиция игрока + WorldMarker → Compass
+pub fn update_compass(
+    player: Query<&StrategicPosition, With<Player>>,
+    markers: Query<(Entity, &WorldMarker)>,
+    yaw: Res<CameraYaw>,
+    mut compass: ResMut<Compass>,
+) {
+    let Ok(position) = player.single() else {
+        if compass.active {
+            *compass = Compass::default();
+        }
+        return;
+    };
+
+    let origin = position.to_world_position(0.0);
+    let heading = Compass::heading_from_yaw(yaw.0);
+
+    compass.active = true;
+    compass.heading = heading;
+    compass.cardinals = Compass::cardinals_for(heading);
+    compass.markers = markers
+        .iter()
+        .map(|(entity, marker)| MarkerBearing {
+            marker: entity,
+            label: marker.label.clone(),
+            kind: marker.kind,
+            offset: Compass::relative(Compass::bearing(origin, marker.position), heading),
+            distance: Vec2::new(marker.position.x - origin.x, marker.position.z - origin.z).length(),
+        })
+        .collect();
+}
@@ -4,7 +4,7 @@
 //! - actor domain: Actor, Health, Stamina, PlayerControlled
 //! - movement domain: MovementCommand, NavigationState, MovementSpeed, JumpIntent
 //! - shooting domain: AimMode, ToggleADSIntent
-//! - shared domain: StrategicPosition, PrefabPath, EquippedWeapons, Armor, EnergyShield, Inventory, CameraMode, ActiveCamera, Attachment
+//! - shared domain: StrategicPosition, PrefabPath, EquippedWeapons, EquippedArmor, EnergyShield, Inventory, CameraMode, ActiveCamera, Attachment
 //! - combat domain: WeaponStats, MeleeAttackState, etc. (уже в combat/)
 //! - ai domain: AIState, AIConfig, etc. (уже в ai/)
 //!
@@ -0,0 +1,24 @@
+//! Content module — проверка данных до запуска игры (`validate-content`)
+//!
+//! # Architecture
+//!
+//! RON файлы `data/` (рецепты, пресеты снаряжения / NPC archetypes, loot tables) разбираются
+//! теми же типами, что и в игре, и сверяются со встроенными `ItemDefinitions`:
+//! - ItemId существует, подходит слоту пресета (размер оружия, слот брони, consumable)
+//! - prefab'ы предметов лежат в Godot проекте (`res://` → `godot/`)
+//! - комплекты брони (`set_id`) зарегистрированы
+//! - количества / веса / время осмысленны
+//!
+//! Проблема = файл + строка + описание (`ContentIssue`). Ловит опечатки до runtime
+//! логов "node not found" / "unknown item".
+//!
+//! Фракции и квесты данными не задаются (faction_id — константы в коде), проверять нечего.
+
+pub mod validation;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod validation_tests;
+
+// Re-exports
+pub use validation::*;
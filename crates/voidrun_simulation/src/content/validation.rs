@@ -0,0 +1,399 @@
+//! Проверка контента: разбор RON + перекрёстные ссылки (ItemId, prefab paths, комплекты брони).
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use crate::combat::CancelTable;
+use crate::crafting::RecipeBook;
+use crate::equipment::LoadoutBook;
+use crate::interaction::LootTable;
+use crate::shared::equipment::ShieldCoverage;
+use crate::item_system::{ArmorSlot, ConsumableEffect, ItemDefinition, ItemDefinitions, ItemId, ItemType, WeaponSize};
+
+/// Файлы данных (относительно каталога `data/`)
+pub const RECIPES_FILE: &str = "recipes.ron";
+pub const LOADOUTS_FILE: &str = "loadouts.ron";
+pub const LOOT_TABLES_FILE: &str = "loot_tables.ron";
+pub const CANCEL_TABLE_FILE: &str = "cancel_table.ron";
+
+/// Найденная проблема: файл, строка (если нашлась), описание
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContentIssue {
+    pub file: String,
+    pub line: Option<usize>,
+    pub message: String,
+}
+
+impl fmt::Display for ContentIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{}:{}: {}", self.file, line, self.message),
+            None => write!(f, "{}: {}", self.file, self.message),
+        }
+    }
+}
+
+/// Итог проверки: проверенные файлы + проблемы
+#[derive(Debug, Clone, Default)]
+pub struct ContentReport {
+    pub checked: Vec<String>,
+    pub issues: Vec<ContentIssue>,
+}
+
+impl ContentReport {
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn push(&mut self, file: &ContentFile, line: Option<usize>, message: String) {
+        self.issues.push(ContentIssue {
+            file: file.path.clone(),
+            line,
+            message,
+        });
+    }
+}
+
+/// Текст файла контента (для поиска строк ссылок)
+#[derive(Debug, Clone)]
+pub struct ContentFile {
+    pub path: String,
+    pub source: String,
+}
+
+impl ContentFile {
+    pub fn new(path: impl Into<String>, source: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            source: source.into(),
+        }
+    }
+
+    pub fn read(path: &Path) -> std::io::Result<Self> {
+        Ok(Self::new(path.display().to_string(), std::fs::read_to_string(path)?))
+    }
+
+    /// Строка (с 1) первого `"needle"` после строки с `"anchor"` (ключ таблицы / рецепта)
+    ///
+    /// Нет anchor — поиск с начала; не нашлось после anchor — строка anchor, без anchor — None.
+    pub fn line_of(&self, anchor: Option<&str>, needle: &str) -> Option<usize> {
+        let quoted = |text: &str| format!("\"{}\"", text);
+        let lines: Vec<&str> = self.source.lines().collect();
+
+        let anchor_line = match anchor {
+            Some(anchor) => Some(lines.iter().position(|line| line.contains(&quoted(anchor)))?),
+            None => None,
+        };
+        let start = anchor_line.unwrap_or(0);
+        let found = lines[start..]
+            .iter()
+            .position(|line| line.contains(&quoted(needle)))
+            .map(|offset| start + offset);
+
+        found.or(anchor_line).map(|line| line + 1)
+    }
+
+    /// Строка anchor (ключ таблицы / рецепта / пресета)
+    fn line_of_key(&self, key: &str) -> Option<usize> {
+        self.line_of(None, key)
+    }
+}
+
+/// Где лежит контент
+#[derive(Debug, Clone)]
+pub struct ContentPaths {
+    /// Каталог RON данных (`data/`)
+    pub data_dir: PathBuf,
+    /// Исходник встроенных ItemDefinitions (строки для ошибок предметов)
+    pub item_source: Option<PathBuf>,
+    /// Godot проект (`res://` пути prefab'ов); None — prefab'ы не проверяются
+    pub godot_project: Option<PathBuf>,
+}
+
+impl ContentPaths {
+    /// Раскладка репозитория: `<crate>/data`, `<crate>/src/item_system.rs`, `<crate>/../../godot`
+    pub fn from_crate_dir(crate_dir: &Path) -> Self {
+        Self {
+            data_dir: crate_dir.join("data"),
+            item_source: Some(crate_dir.join("src").join("item_system.rs")),
+            godot_project: Some(crate_dir.join("..").join("..").join("godot")),
+        }
+    }
+}
+
+/// Проверить весь контент (RON файлы `data_dir` + встроенные ItemDefinitions)
+pub fn validate_content(paths: &ContentPaths) -> ContentReport {
+    let mut report = ContentReport::default();
+    let definitions = ItemDefinitions::default();
+
+    let item_file = paths
+        .item_source
+        .as_deref()
+        .and_then(|path| ContentFile::read(path).ok())
+        .unwrap_or_else(|| ContentFile::new("item_system.rs", ""));
+    let godot_project = paths.godot_project.as_deref();
+    check_items(&item_file, &definitions, &|prefab| prefab_exists(godot_project, prefab), &mut report);
+
+    let checks: [(&str, fn(&ContentFile, &ItemDefinitions, &mut ContentReport)); 4] = [
+        (RECIPES_FILE, check_recipes),
+        (LOADOUTS_FILE, check_loadouts),
+        (LOOT_TABLES_FILE, check_loot_tables),
+        (CANCEL_TABLE_FILE, check_cancel_table),
+    ];
+    for (name, check) in checks {
+        let path = paths.data_dir.join(name);
+        match ContentFile::read(&path) {
+            Ok(file) => {
+                check(&file, &definitions, &mut report);
+                report.checked.push(file.path);
+            }
+            Err(error) => report.issues.push(ContentIssue {
+                file: path.display().to_string(),
+                line: None,
+                message: format!("cannot read: {}", error),
+            }),
+        }
+    }
+
+    report
+}
+
+/// `res://` путь существует в Godot проекте (проект не задан — считаем, что есть)
+fn prefab_exists(godot_project: Option<&Path>, prefab: &str) -> bool {
+    let Some(project) = godot_project else {
+        return true;
+    };
+    let Some(relative) = prefab.strip_prefix("res://") else {
+        return false;
+    };
+    project.join(relative).is_file()
+}
+
+/// Ошибка разбора RON → проблема со строкой
+fn parse_issue(file: &ContentFile, error: ron::error::SpannedError, report: &mut ContentReport) {
+    report.push(file, Some(error.position.line), format!("parse error: {}", error.code));
+}
+
+fn definition<'a>(definitions: &'a ItemDefinitions, item: &str) -> Option<&'a ItemDefinition> {
+    definitions.get(&ItemId::from(item))
+}
+
+/// Встроенные предметы: prefab'ы в Godot проекте, weapon template, комплекты брони, генераторы щита
+pub fn check_items(
+    file: &ContentFile,
+    definitions: &ItemDefinitions,
+    prefab_exists: &dyn Fn(&str) -> bool,
+    report: &mut ContentReport,
+) {
+    let mut ids: Vec<&ItemId> = definitions.all_ids();
+    ids.sort_by(|a, b| a.0.cmp(&b.0));
+
+    for id in ids {
+        let Some(item) = definitions.get(id) else {
+            continue;
+        };
+        let line = file.line_of_key(&id.0);
+
+        if let Some(prefab) = &item.prefab_path {
+            if !prefab_exists(prefab) {
+                report.push(file, file.line_of(Some(&id.0), prefab), format!("item '{}': prefab '{}' not found", id.0, prefab));
+            }
+        }
+        if let Some(ConsumableEffect::SpawnProjectile { prefab_path, .. }) = &item.consumable_effect {
+            if !prefab_exists(prefab_path) {
+                report.push(
+                    file,
+                    file.line_of(Some(&id.0), prefab_path),
+                    format!("item '{}': projectile prefab '{}' not found", id.0, prefab_path),
+                );
+            }
+        }
+        if matches!(item.item_type, ItemType::Weapon { .. }) && item.weapon_template.is_none() {
+            report.push(file, line, format!("item '{}': weapon without weapon_template", id.0));
+        }
+        if let Some(set_id) = item.armor_stats.as_ref().and_then(|armor| armor.set_id.as_deref()) {
+            if definitions.armor_set(set_id).is_none() {
+                report.push(file, line, format!("item '{}': unknown armor set '{}'", id.0, set_id));
+            }
+        }
+        if let Some(shield) = item.armor_stats.as_ref().and_then(|armor| armor.shield.as_ref()) {
+            if shield.max_energy <= 0.0 || shield.recharge_rate < 0.0 || shield.recharge_delay < 0.0 {
+                report.push(file, line, format!("item '{}': shield generator needs max_energy > 0 and non-negative recharge", id.0));
+            }
+            if let ShieldCoverage::FrontArc { half_angle_degrees } = shield.coverage {
+                if half_angle_degrees <= 0.0 || half_angle_degrees > 180.0 {
+                    report.push(file, line, format!("item '{}': shield arc {} must be in (0, 180] degrees", id.0, half_angle_degrees));
+                }
+            }
+        }
+    }
+}
+
+/// data/recipes.ron: ItemId ингредиентов и результата, количества, время
+pub fn check_recipes(file: &ContentFile, definitions: &ItemDefinitions, report: &mut ContentReport) {
+    let book = match RecipeBook::from_ron(&file.source) {
+        Ok(book) => book,
+        Err(error) => return parse_issue(file, error, report),
+    };
+
+    let mut recipes: Vec<_> = book.recipes.iter().collect();
+    recipes.sort_by_key(|(id, _)| id.as_str());
+
+    for (id, recipe) in recipes {
+        let line = file.line_of_key(id);
+        if recipe.inputs.is_empty() {
+            report.push(file, line, format!("recipe '{}': no inputs", id));
+        }
+        if recipe.duration <= 0.0 {
+            report.push(file, line, format!("recipe '{}': duration must be > 0", id));
+        }
+
+        for input in recipe.inputs.iter() {
+            if definition(definitions, &input.item).is_none() {
+                report.push(file, file.line_of(Some(id), &input.item), format!("recipe '{}': unknown item '{}'", id, input.item));
+            }
+            if input.count == 0 {
+                report.push(file, file.line_of(Some(id), &input.item), format!("recipe '{}': input '{}' count is 0", id, input.item));
+            }
+        }
+
+        let output = &recipe.output;
+        if definition(definitions, &output.item).is_none() {
+            report.push(file, file.line_of(Some(id), &output.item), format!("recipe '{}': unknown output item '{}'", id, output.item));
+        } else if output.count > definitions.max_stack(&ItemId::from(output.item.as_str())) {
+            report.push(file, line, format!("recipe '{}': output count {} exceeds max stack", id, output.count));
+        }
+    }
+}
+
+/// data/loadouts.ron (пресеты игрока / NPC archetypes): ItemId и тип предмета по слоту
+pub fn check_loadouts(file: &ContentFile, definitions: &ItemDefinitions, report: &mut ContentReport) {
+    let book = match LoadoutBook::from_ron(&file.source) {
+        Ok(book) => book,
+        Err(error) => return parse_issue(file, error, report),
+    };
+
+    let mut loadouts: Vec<_> = book.loadouts.iter().collect();
+    loadouts.sort_by_key(|(name, _)| name.as_str());
+
+    for (name, loadout) in loadouts {
+        for (slot, item) in loadout.weapons.iter().enumerate() {
+            let Some(item) = item else {
+                continue;
+            };
+            let Some(found) = loadout_item(file, definitions, name, item, report) else {
+                continue;
+            };
+            // Слоты 1-2 — большое оружие, 3-4 — малое
+            let expected = if slot < 2 { WeaponSize::Large } else { WeaponSize::Small };
+            match &found.item_type {
+                ItemType::Weapon { size } if *size == expected => {}
+                ItemType::Weapon { size } => report.push(
+                    file,
+                    file.line_of(Some(name), item),
+                    format!("loadout '{}': {:?} weapon '{}' in slot {}", name, size, item, slot + 1),
+                ),
+                _ => report.push(
+                    file,
+                    file.line_of(Some(name), item),
+                    format!("loadout '{}': '{}' in weapon slot {} is not a weapon", name, item, slot + 1),
+                ),
+            }
+        }
+
+        for (index, item) in loadout.armor.iter().enumerate() {
+            let Some(item) = item else {
+                continue;
+            };
+            let Some(found) = loadout_item(file, definitions, name, item, report) else {
+                continue;
+            };
+            let expected = ArmorSlot::ALL[index];
+            if found.armor_stats.as_ref().map(|armor| armor.slot) != Some(expected) {
+                report.push(
+                    file,
+                    file.line_of(Some(name), item),
+                    format!("loadout '{}': '{}' does not fit armor slot {:?}", name, item, expected),
+                );
+            }
+        }
+
+        for consumable in loadout.consumables.iter() {
+            let Some(found) = loadout_item(file, definitions, name, &consumable.item, report) else {
+                continue;
+            };
+            if !matches!(found.item_type, ItemType::Consumable | ItemType::Throwable) {
+                report.push(
+                    file,
+                    file.line_of(Some(name), &consumable.item),
+                    format!("loadout '{}': '{}' is not a consumable", name, consumable.item),
+                );
+            }
+            if consumable.count == 0 || consumable.count > definitions.max_stack(&found.id) {
+                report.push(
+                    file,
+                    file.line_of(Some(name), &consumable.item),
+                    format!("loadout '{}': '{}' count {} outside 1..={}", name, consumable.item, consumable.count, definitions.max_stack(&found.id)),
+                );
+            }
+        }
+    }
+}
+
+/// Предмет пресета (нет в ItemDefinitions — проблема в отчёт)
+fn loadout_item<'a>(
+    file: &ContentFile,
+    definitions: &'a ItemDefinitions,
+    loadout: &str,
+    item: &str,
+    report: &mut ContentReport,
+) -> Option<&'a ItemDefinition> {
+    let found = definition(definitions, item);
+    if found.is_none() {
+        report.push(file, file.line_of(Some(loadout), item), format!("loadout '{}': unknown item '{}'", loadout, item));
+    }
+    found
+}
+
+/// data/loot_tables.ron: ItemId, веса, диапазоны стаков
+pub fn check_loot_tables(file: &ContentFile, definitions: &ItemDefinitions, report: &mut ContentReport) {
+    let tables = match LootTable::from_ron(&file.source) {
+        Ok(tables) => tables,
+        Err(error) => return parse_issue(file, error, report),
+    };
+
+    let mut pools: Vec<_> = tables.tables.iter().collect();
+    pools.sort_by_key(|(name, _)| name.as_str());
+
+    for (name, pool) in pools {
+        let line = file.line_of_key(name);
+        if pool.rolls == 0 {
+            report.push(file, line, format!("loot table '{}': rolls is 0", name));
+        }
+        if pool.entries.iter().all(|entry| entry.weight == 0) {
+            report.push(file, line, format!("loot table '{}': no entry can drop (all weights 0)", name));
+        }
+
+        for entry in pool.entries.iter() {
+            let entry_line = file.line_of(Some(name), &entry.item);
+            if definition(definitions, &entry.item).is_none() {
+                report.push(file, entry_line, format!("loot table '{}': unknown item '{}'", name, entry.item));
+            }
+            let (min, max) = entry.count;
+            if min > max {
+                report.push(file, entry_line, format!("loot table '{}': '{}' count ({}, {}) min > max", name, entry.item, min, max));
+            }
+        }
+    }
+}
+
+/// Cancel таблица: повторные пары (from, phase), пустые списки, принудительные реакции в into
+pub fn check_cancel_table(file: &ContentFile, _definitions: &ItemDefinitions, report: &mut ContentReport) {
+    let table = match CancelTable::from_ron(&file.source) {
+        Ok(table) => table,
+        Err(error) => return parse_issue(file, error, report),
+    };
+
+    for issue in table.issues() {
+        report.push(file, None, issue);
+    }
+}
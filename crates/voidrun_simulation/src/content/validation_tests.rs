@@ -0,0 +1,114 @@
+//! Tests for content validation.
+
+#[cfg(test)]
+mod tests {
+    use super::super::validation::*;
+    use crate::item_system::ItemDefinitions;
+
+    fn run(check: fn(&ContentFile, &ItemDefinitions, &mut ContentReport), source: &str) -> Vec<ContentIssue> {
+        let mut report = ContentReport::default();
+        check(&ContentFile::new("test.ron", source), &ItemDefinitions::default(), &mut report);
+        report.issues
+    }
+
+    #[test]
+    fn test_shipped_data_is_valid() {
+        let data = concat!(env!("CARGO_MANIFEST_DIR"), "/data/");
+        for (name, check) in [
+            (RECIPES_FILE, check_recipes as fn(&ContentFile, &ItemDefinitions, &mut ContentReport)),
+            (LOADOUTS_FILE, check_loadouts),
+            (LOOT_TABLES_FILE, check_loot_tables),
+            (CANCEL_TABLE_FILE, check_cancel_table),
+        ] {
+            let file = ContentFile::read(std::path::Path::new(&format!("{}{}", data, name))).unwrap();
+            let mut report = ContentReport::default();
+            check(&file, &ItemDefinitions::default(), &mut report);
+            assert!(report.is_ok(), "{:?}", report.issues);
+        }
+    }
+
+    #[test]
+    fn test_unknown_recipe_item_reported_with_line() {
+        let issues = run(
+            check_recipes,
+            r#"(
+    recipes: {
+        "medkit": (
+            inputs: [(item: "chem_vial", count: 1)],
+            output: (item: "health_kti"),
+            duration: 3.0,
+        ),
+    },
+)"#,
+        );
+
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].line, Some(5));
+        assert_eq!(issues[0].to_string(), "test.ron:5: recipe 'medkit': unknown output item 'health_kti'");
+    }
+
+    #[test]
+    fn test_parse_error_reports_position() {
+        let issues = run(check_loot_tables, "(\n    tables: {\n        \"crate\": (rolls: 1,\n");
+
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.starts_with("parse error"));
+        assert!(issues[0].line.is_some());
+    }
+
+    #[test]
+    fn test_loadout_slot_mismatches() {
+        let issues = run(
+            check_loadouts,
+            r#"(
+    loadouts: {
+        "broken": (
+            weapons: (Some("pistol_basic"), None, Some("health_kit"), None),
+            armor: (Some("armor_light"), None, None, None),
+            consumables: [(item: "melee_sword")],
+        ),
+    },
+)"#,
+        );
+
+        let messages: Vec<&str> = issues.iter().map(|issue| issue.message.as_str()).collect();
+        assert_eq!(issues.len(), 4, "{:?}", messages);
+        assert!(messages.iter().any(|message| message.contains("Small weapon 'pistol_basic' in slot 1")));
+        assert!(messages.iter().any(|message| message.contains("'health_kit' in weapon slot 3 is not a weapon")));
+        assert!(messages.iter().any(|message| message.contains("'armor_light' does not fit armor slot Helmet")));
+        assert!(messages.iter().any(|message| message.contains("'melee_sword' is not a consumable")));
+    }
+
+    #[test]
+    fn test_loot_table_weights_and_counts() {
+        let issues = run(
+            check_loot_tables,
+            r#"(
+    tables: {
+        "empty": (
+            rolls: 0,
+            entries: [(item: "scrap_metal", weight: 0, count: (3, 1))],
+        ),
+    },
+)"#,
+        );
+
+        assert_eq!(issues.len(), 3, "{:?}", issues);
+        assert_eq!(issues[2].line, Some(5));
+    }
+
+    #[test]
+    fn test_missing_prefab_reported() {
+        let mut report = ContentReport::default();
+        let file = ContentFile::new("item_system.rs", "id: \"pistol_basic\".into(),\nprefab_path: Some(\"res://actors/test_pistol.tscn\"),\n");
+
+        check_items(&file, &ItemDefinitions::default(), &|prefab| !prefab.contains("test_pistol"), &mut report);
+
+        let pistol = report
+            .issues
+            .iter()
+            .find(|issue| issue.message.starts_with("item 'pistol_basic'"))
+            .expect("pistol prefab not reported");
+        assert_eq!(pistol.line, Some(2));
+    }
+}
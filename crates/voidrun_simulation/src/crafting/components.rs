@@ -0,0 +1,123 @@
+//! Crafting components (рецепты из RON, книга рецептов, крафт в процессе).
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use crate::item_system::{ItemDefinitions, ItemId, ItemInstance};
+use crate::components::Inventory;
+
+/// Ошибка разбора рецептов из RON
+pub type RecipeParseError = ron::error::SpannedError;
+
+/// Встроенные рецепты (data/recipes.ron)
+const DEFAULT_RECIPES: &str = include_str!("../../data/recipes.ron");
+
+/// Ингредиент рецепта
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RecipeInput {
+    /// ItemId (ItemDefinitions)
+    pub item: String,
+    pub count: u32,
+}
+
+/// Результат рецепта
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RecipeOutput {
+    /// ItemId (ItemDefinitions)
+    pub item: String,
+    #[serde(default = "RecipeOutput::single")]
+    pub count: u32,
+}
+
+impl RecipeOutput {
+    fn single() -> u32 {
+        1
+    }
+
+    /// Готовый ItemInstance (count > 1 → стак)
+    pub fn instantiate(&self) -> ItemInstance {
+        if self.count > 1 {
+            ItemInstance::consumable_stack(self.item.as_str(), self.count)
+        } else {
+            ItemInstance::new(self.item.as_str())
+        }
+    }
+}
+
+/// Рецепт: ингредиенты → результат за `duration` секунд
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Recipe {
+    pub inputs: Vec<RecipeInput>,
+    pub output: RecipeOutput,
+    /// Время крафта (секунды, interruptible Channeling)
+    pub duration: f32,
+}
+
+impl Recipe {
+    /// Хватает ли ингредиентов в Inventory
+    pub fn can_craft(&self, inventory: &Inventory) -> bool {
+        self.inputs
+            .iter()
+            .all(|input| inventory.count_item(&ItemId::from(input.item.as_str())) >= input.count)
+    }
+
+    /// Списать ингредиенты. false — не хватает, inventory не изменён.
+    pub fn consume_inputs(&self, inventory: &mut Inventory) -> bool {
+        if !self.can_craft(inventory) {
+            return false;
+        }
+        for input in self.inputs.iter() {
+            inventory.consume_item(&ItemId::from(input.item.as_str()), input.count);
+        }
+        true
+    }
+}
+
+/// Книга рецептов по id (resource)
+///
+/// По умолчанию — `data/recipes.ron`, замена — `from_ron`.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+pub struct RecipeBook {
+    pub recipes: HashMap<String, Recipe>,
+}
+
+impl Default for RecipeBook {
+    fn default() -> Self {
+        Self::from_ron(DEFAULT_RECIPES).unwrap_or_else(|error| {
+            crate::logger::log_error(&format!("Default recipes failed to parse: {}", error));
+            Self { recipes: HashMap::new() }
+        })
+    }
+}
+
+impl RecipeBook {
+    pub fn from_ron(source: &str) -> Result<Self, RecipeParseError> {
+        ron::from_str(source)
+    }
+
+    pub fn get(&self, recipe: &str) -> Option<&Recipe> {
+        self.recipes.get(recipe)
+    }
+
+    /// ItemId рецептов, которых нет в ItemDefinitions ("recipe: item")
+    pub fn unknown_items(&self, definitions: &ItemDefinitions) -> Vec<String> {
+        let mut unknown = Vec::new();
+        for (id, recipe) in self.recipes.iter() {
+            let items = recipe.inputs.iter().map(|input| &input.item).chain([&recipe.output.item]);
+            for item in items {
+                if definitions.get(&ItemId::from(item.as_str())).is_none() {
+                    unknown.push(format!("{}: {}", id, item));
+                }
+            }
+        }
+        unknown.sort();
+        unknown
+    }
+}
+
+/// Актор крафтит (пока идёт Channeling::Craft)
+#[derive(Component, Debug, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Crafting {
+    pub recipe: String,
+}
@@ -0,0 +1,45 @@
+//! Tests for recipes (RON, ItemId, списание ингредиентов).
+
+#[cfg(test)]
+mod tests {
+    use super::super::components::*;
+    use crate::components::Inventory;
+    use crate::item_system::{ItemDefinitions, ItemId, ItemInstance};
+
+    #[test]
+    fn test_default_recipes_reference_known_items() {
+        let book = RecipeBook::default();
+        let definitions = ItemDefinitions::default();
+
+        assert!(book.get("health_kit").is_some());
+        assert!(book.unknown_items(&definitions).is_empty(), "{:?}", book.unknown_items(&definitions));
+    }
+
+    #[test]
+    fn test_craft_consumes_inputs_and_instantiates_output() {
+        let book = RecipeBook::from_ron(
+            r#"(recipes: {
+                "kit": (inputs: [(item: "scrap_metal", count: 2), (item: "chem_vial", count: 1)],
+                        output: (item: "health_kit"), duration: 2.0),
+                "smokes": (inputs: [], output: (item: "grenade_smoke", count: 2), duration: 1.0),
+            })"#,
+        )
+        .unwrap();
+        let recipe = book.get("kit").unwrap();
+
+        let mut inventory = Inventory::default();
+        inventory.add_item(ItemInstance::consumable_stack("scrap_metal", 1));
+        inventory.add_item(ItemInstance::new("chem_vial"));
+        assert!(!recipe.can_craft(&inventory));
+        assert!(!recipe.consume_inputs(&mut inventory));
+        assert_eq!(inventory.count_item(&ItemId::from("scrap_metal")), 1);
+
+        inventory.add_item(ItemInstance::consumable_stack("scrap_metal", 3));
+        assert!(recipe.consume_inputs(&mut inventory));
+        assert_eq!(inventory.count_item(&ItemId::from("scrap_metal")), 2);
+        assert_eq!(inventory.count_item(&ItemId::from("chem_vial")), 0);
+
+        assert_eq!(recipe.output.instantiate().stack_size, 1);
+        assert_eq!(book.get("smokes").unwrap().output.instantiate().stack_size, 2);
+    }
+}
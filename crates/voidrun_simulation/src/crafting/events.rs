@@ -0,0 +1,51 @@
+//! Crafting events.
+
+use bevy::prelude::*;
+use crate::item_system::ItemInstance;
+
+/// Intent: скрафтить по рецепту (UI крафта)
+///
+/// Обрабатывается `start_crafting` → проверка Inventory → Channeling(Craft).
+#[derive(Event, Debug, Clone)]
+pub struct CraftItemIntent {
+    pub actor: Entity,
+    pub recipe: String,
+}
+
+/// Крафт начат (UI: прогресс)
+#[derive(Event, Debug, Clone)]
+pub struct CraftingStarted {
+    pub actor: Entity,
+    pub recipe: String,
+    /// Длительность (секунды)
+    pub duration: f32,
+}
+
+/// Предмет скрафчен и добавлен в Inventory
+#[derive(Event, Debug, Clone)]
+pub struct ItemCrafted {
+    pub actor: Entity,
+    pub recipe: String,
+    pub item: ItemInstance,
+}
+
+/// Причина отказа / срыва крафта
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CraftFailure {
+    /// Рецепта нет в RecipeBook
+    UnknownRecipe,
+    /// Не хватает ингредиентов
+    MissingIngredients,
+    /// Актор занят (другое действие / крафт)
+    Busy,
+    /// Channel прерван уроном
+    Interrupted,
+}
+
+/// Крафт не начат или сорван (UI подсказка)
+#[derive(Event, Debug, Clone)]
+pub struct CraftingFailed {
+    pub actor: Entity,
+    pub recipe: String,
+    pub reason: CraftFailure,
+}
@@ -0,0 +1,51 @@
+//! Crafting module — рецепты из RON: ингредиенты из Inventory → новый ItemInstance
+//!
+//! # Architecture
+//!
+//! **Flow:**
+//! - UI крафта → `CraftItemIntent { actor, recipe }`
+//! - ECS `start_crafting` → рецепт из `RecipeBook`, проверка Inventory → Channeling(Craft)
+//!   (прерывается уроном, как любой channel) + `CraftingStarted`
+//! - ChannelCompleted → ингредиенты списаны, результат в Inventory + `ItemCrafted`
+//! - Отказ / срыв → `CraftingFailed { reason }`
+//!
+//! Рецепты (`data/recipes.ron`) ссылаются на ItemId из `ItemDefinitions`.
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Crafting Plugin
+///
+/// Регистрирует книгу рецептов и канал крафта в FixedUpdate.
+pub struct CraftingPlugin;
+
+impl Plugin for CraftingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CraftItemIntent>()
+            .add_event::<CraftingStarted>()
+            .add_event::<ItemCrafted>()
+            .add_event::<CraftingFailed>()
+            .init_resource::<RecipeBook>()
+            .add_systems(Startup, validate_recipe_book)
+            .add_systems(
+                FixedUpdate,
+                (
+                    start_crafting,    // 1. CraftItemIntent → Channeling(Craft)
+                    complete_crafting, // 2. ChannelCompleted(Craft) → результат в Inventory
+                )
+                    .chain(),
+            );
+    }
+}
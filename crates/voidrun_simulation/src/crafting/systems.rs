@@ -0,0 +1,146 @@
+//! Crafting systems (CraftItemIntent → Channeling(Craft) → предмет в Inventory).
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use crate::combat::{
+    ActionKind, ActionLock, ActionPhase, CancelTable, ChannelCompleted, ChannelInterrupted, ChannelKind, Channeling,
+};
+use crate::components::Inventory;
+use crate::item_system::ItemDefinitions;
+use crate::SimulationTick;
+use super::components::{Crafting, RecipeBook};
+use super::events::{CraftFailure, CraftItemIntent, CraftingFailed, CraftingStarted, ItemCrafted};
+
+/// Startup: рецепты ссылаются на существующие ItemId (иначе warning в лог)
+pub fn validate_recipe_book(book: Res<RecipeBook>, definitions: Res<ItemDefinitions>) {
+    for unknown in book.unknown_items(&definitions) {
+        crate::logger::log_warning(&format!("⚒️ Recipe references unknown item {}", unknown));
+    }
+}
+
+/// Крафтер: ингредиенты + занятость (ActionLock / активный канал)
+pub type CraftActors<'w, 's> = Query<'w, 's, (&'static Inventory, Option<&'static ActionLock>, Has<Channeling>)>;
+
+/// SystemParam: CraftItemIntent на входе → CraftingStarted / CraftingFailed на выходе
+#[derive(SystemParam)]
+pub struct CraftIntentEvents<'w, 's> {
+    intents: EventReader<'w, 's, CraftItemIntent>,
+    started: EventWriter<'w, CraftingStarted>,
+    failed: EventWriter<'w, CraftingFailed>,
+}
+
+/// System: CraftItemIntent → Channeling(Craft)
+///
+/// - Рецепт из RecipeBook, ингредиенты проверяются по Inventory (списываются на завершении)
+/// - ActionLock + CancelTable должны разрешать Craft
+pub fn start_crafting(
+    mut events: CraftIntentEvents,
+    actors: CraftActors,
+    book: Res<RecipeBook>,
+    cancel_table: Res<CancelTable>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    for intent in events.intents.read() {
+        let Ok((inventory, lock, channeling)) = actors.get(intent.actor) else {
+            continue;
+        };
+
+        let failure = match book.get(&intent.recipe) {
+            None => Some(CraftFailure::UnknownRecipe),
+            Some(_) if channeling || !ActionLock::permits(lock, ActionKind::Craft, &cancel_table) => {
+                Some(CraftFailure::Busy)
+            }
+            Some(recipe) if !recipe.can_craft(inventory) => Some(CraftFailure::MissingIngredients),
+            Some(_) => None,
+        };
+        if let Some(reason) = failure {
+            events.failed.write(CraftingFailed {
+                actor: intent.actor,
+                recipe: intent.recipe.clone(),
+                reason,
+            });
+            continue;
+        }
+        let Some(recipe) = book.get(&intent.recipe) else {
+            continue;
+        };
+
+        commands.entity(intent.actor).insert((
+            Channeling::new(ChannelKind::Craft, tick.after_secs(recipe.duration)),
+            ActionLock::new(ActionKind::Craft, ActionPhase::Active),
+            Crafting { recipe: intent.recipe.clone() },
+        ));
+        events.started.write(CraftingStarted {
+            actor: intent.actor,
+            recipe: intent.recipe.clone(),
+            duration: recipe.duration,
+        });
+
+        crate::logger::log(&format!("⚒️ {:?} crafting {}", intent.actor, intent.recipe));
+    }
+}
+
+/// System: завершённый Craft channel → ингредиенты списаны, результат в Inventory;
+/// прерванный → CraftingFailed(Interrupted)
+#[allow(clippy::too_many_arguments)]
+pub fn complete_crafting(
+    mut completed_events: EventReader<ChannelCompleted>,
+    mut interrupted_events: EventReader<ChannelInterrupted>,
+    mut crafters: Query<(&Crafting, &mut Inventory)>,
+    book: Res<RecipeBook>,
+    mut crafted_events: EventWriter<ItemCrafted>,
+    mut failed_events: EventWriter<CraftingFailed>,
+    definitions: Res<ItemDefinitions>,
+    mut commands: Commands,
+) {
+    for completed in completed_events.read() {
+        if completed.kind != ChannelKind::Craft {
+            continue;
+        }
+        let Ok((crafting, mut inventory)) = crafters.get_mut(completed.entity) else {
+            continue;
+        };
+
+        commands.entity(completed.entity).remove::<Crafting>();
+
+        // Inventory мог измениться за время канала (выброс, использование)
+        let Some(recipe) = book.get(&crafting.recipe) else {
+            continue;
+        };
+        if !recipe.consume_inputs(&mut inventory) {
+            failed_events.write(CraftingFailed {
+                actor: completed.entity,
+                recipe: crafting.recipe.clone(),
+                reason: CraftFailure::MissingIngredients,
+            });
+            continue;
+        }
+
+        let item = recipe.output.instantiate();
+        inventory.add_stacked(item.clone(), &definitions);
+
+        crate::logger::log(&format!("⚒️ {:?} crafted {}", completed.entity, crafting.recipe));
+        crafted_events.write(ItemCrafted {
+            actor: completed.entity,
+            recipe: crafting.recipe.clone(),
+            item,
+        });
+    }
+
+    for interrupted in interrupted_events.read() {
+        if interrupted.kind != ChannelKind::Craft {
+            continue;
+        }
+        let Ok((crafting, _)) = crafters.get(interrupted.entity) else {
+            continue;
+        };
+
+        commands.entity(interrupted.entity).remove::<Crafting>();
+        failed_events.write(CraftingFailed {
+            actor: interrupted.entity,
+            recipe: crafting.recipe.clone(),
+            reason: CraftFailure::Interrupted,
+        });
+    }
+}
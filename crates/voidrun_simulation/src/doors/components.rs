@@ -0,0 +1,153 @@
+//! Door components (закрытые / запертые двери, выбивание).
+
+use bevy::prelude::*;
+use crate::item_system::ItemId;
+
+/// Состояние двери.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum DoorState {
+    /// Закрыта (блокирует проход и обзор)
+    Closed,
+    /// Заперта (выбить или открыть ключом `Door::key`)
+    Locked,
+    /// Открыта
+    Open,
+    /// Выбита (integrity = 0), проход свободен навсегда
+    Breached,
+}
+
+/// Дверь уровня (destructible): выбивается channelled ударом, запертая — открывается ключом.
+///
+/// Позиция и ориентация — из Godot ноды (группа `breachable_doors`).
+/// "За дверью" — сторона, противоположная выбивающему (по нормали `facing`).
+/// Навигация: Godot NavigationLink3D через проём (заперта → выключен).
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Door {
+    /// Центр проёма (world coordinates)
+    pub position: Vec3,
+    /// Нормаль полотна (XZ, нормализованная)
+    pub facing: Vec3,
+    pub state: DoorState,
+    /// Прочность (0 → Breached)
+    pub integrity: u32,
+    pub max_integrity: u32,
+    /// Ключ (ItemId в Inventory), отпирающий Locked дверь. None — только выбить
+    pub key: Option<ItemId>,
+}
+
+impl Door {
+    /// Дистанция, с которой можно выбивать (метры)
+    pub const BREACH_RANGE: f32 = 1.8;
+    /// Длительность удара/рывка (секунды, interruptible Channeling)
+    pub const BREACH_DURATION: f32 = 1.2;
+    /// Урон двери за удар
+    pub const BREACH_DAMAGE: u32 = 50;
+    /// Радиус, в котором слышен удар (метры)
+    pub const BREACH_NOISE_RADIUS: f32 = 15.0;
+    /// Зона stagger за дверью: глубина и полуширина (метры)
+    pub const STAGGER_DEPTH: f32 = 2.0;
+    pub const STAGGER_HALF_WIDTH: f32 = 1.0;
+    /// Длительность stagger у стоящих за дверью (секунды)
+    pub const STAGGER_DURATION: f32 = 0.8;
+    /// Дистанция, с которой AI открывает дверь на пути (метры, внутри `Interactable::range`)
+    pub const AI_OPEN_RANGE: f32 = 1.5;
+
+    pub fn new(position: Vec3, facing: Vec3, max_integrity: u32, locked: bool) -> Self {
+        Self {
+            position,
+            facing: Vec3::new(facing.x, 0.0, facing.z).normalize_or(Vec3::Z),
+            state: if locked { DoorState::Locked } else { DoorState::Closed },
+            integrity: max_integrity,
+            max_integrity,
+            key: None,
+        }
+    }
+
+    /// Запертая дверь отпирается ключом `key`
+    pub fn with_key(mut self, key: impl Into<ItemId>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+
+    /// Отпереть (Locked → Closed). false — не заперта.
+    pub fn unlock(&mut self) -> bool {
+        if self.state != DoorState::Locked {
+            return false;
+        }
+        self.state = DoorState::Closed;
+        true
+    }
+
+    /// Проходима для навигации (открыта / выбита; закрытую незапертую AI открывает сам)
+    pub fn is_pathable(&self) -> bool {
+        self.state != DoorState::Locked
+    }
+
+    /// Блокирует проход (Closed / Locked)
+    pub fn is_blocking(&self) -> bool {
+        matches!(self.state, DoorState::Closed | DoorState::Locked)
+    }
+
+    /// Открыть / закрыть (interact). Some(open) — новое состояние, None — заперта или выбита.
+    pub fn toggle(&mut self) -> Option<bool> {
+        self.state = match self.state {
+            DoorState::Closed => DoorState::Open,
+            DoorState::Open => DoorState::Closed,
+            DoorState::Locked | DoorState::Breached => return None,
+        };
+
+        Some(self.state == DoorState::Open)
+    }
+
+    /// Можно ли выбивать с позиции `position`
+    pub fn can_be_breached_from(&self, position: Vec3) -> bool {
+        self.is_blocking() && self.position.distance(position) <= Self::BREACH_RANGE
+    }
+
+    /// Знаковая дистанция от плоскости двери (по нормали, XZ)
+    pub fn side_of(&self, position: Vec3) -> f32 {
+        let offset = position - self.position;
+        Vec3::new(offset.x, 0.0, offset.z).dot(self.facing)
+    }
+
+    /// `a` и `b` по разные стороны двери
+    pub fn separates(&self, a: Vec3, b: Vec3) -> bool {
+        self.side_of(a) * self.side_of(b) < 0.0
+    }
+
+    /// Стоит ли `position` вплотную за дверью относительно выбивающего в `breacher`
+    pub fn is_directly_behind(&self, breacher: Vec3, position: Vec3) -> bool {
+        if !self.separates(breacher, position) {
+            return false;
+        }
+
+        let depth = self.side_of(position).abs();
+        let offset = position - self.position;
+        let lateral = (Vec3::new(offset.x, 0.0, offset.z) - self.facing * self.side_of(position)).length();
+
+        depth <= Self::STAGGER_DEPTH && lateral <= Self::STAGGER_HALF_WIDTH
+    }
+
+    /// Урон двери. true — дверь только что выбита.
+    pub fn apply_damage(&mut self, amount: u32) -> bool {
+        if !self.is_blocking() {
+            return false;
+        }
+
+        self.integrity = self.integrity.saturating_sub(amount);
+        if self.integrity == 0 {
+            self.state = DoorState::Breached;
+            return true;
+        }
+
+        false
+    }
+}
+
+/// Актор выбивает дверь (пока идёт Channeling::Breach).
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct BreachingDoor {
+    pub door: Entity,
+}
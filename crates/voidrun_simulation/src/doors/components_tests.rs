@@ -0,0 +1,72 @@
+//! Tests for door components (breach geometry, integrity).
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::*;
+
+    fn door() -> Door {
+        // Проём в начале координат, нормаль +Z
+        Door::new(Vec3::ZERO, Vec3::Z, 100, true)
+    }
+
+    #[test]
+    fn test_breach_damage_destroys_door() {
+        let mut door = door();
+        assert_eq!(door.state, DoorState::Locked);
+
+        assert!(!door.apply_damage(Door::BREACH_DAMAGE));
+        assert!(door.apply_damage(Door::BREACH_DAMAGE));
+        assert_eq!(door.state, DoorState::Breached);
+        assert!(!door.is_blocking());
+
+        // Выбитую дверь больше не бьют
+        assert!(!door.apply_damage(Door::BREACH_DAMAGE));
+        assert!(!door.can_be_breached_from(Vec3::new(0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn test_toggle_opens_closed_door_but_not_locked() {
+        let mut locked = door();
+        assert_eq!(locked.toggle(), None);
+        assert_eq!(locked.state, DoorState::Locked);
+
+        let mut door = Door::new(Vec3::ZERO, Vec3::Z, 100, false);
+        assert_eq!(door.toggle(), Some(true));
+        assert!(!door.is_blocking());
+        assert_eq!(door.toggle(), Some(false));
+        assert!(door.is_blocking());
+    }
+
+    #[test]
+    fn test_directly_behind_is_opposite_side_near_door() {
+        let door = door();
+        let breacher = Vec3::new(0.0, 0.0, 1.0);
+
+        assert!(door.is_directly_behind(breacher, Vec3::new(0.3, 0.0, -1.0)));
+        // Та же сторона, что и выбивающий
+        assert!(!door.is_directly_behind(breacher, Vec3::new(0.0, 0.0, 1.5)));
+        // Слишком далеко за дверью / сбоку
+        assert!(!door.is_directly_behind(breacher, Vec3::new(0.0, 0.0, -3.0)));
+        assert!(!door.is_directly_behind(breacher, Vec3::new(2.0, 0.0, -1.0)));
+    }
+
+    #[test]
+    fn test_separates_sides() {
+        let door = door();
+        assert!(door.separates(Vec3::new(0.0, 0.0, 2.0), Vec3::new(5.0, 0.0, -4.0)));
+        assert!(!door.separates(Vec3::new(0.0, 0.0, 2.0), Vec3::new(5.0, 0.0, 4.0)));
+    }
+
+    #[test]
+    fn test_unlock_with_key_then_toggle() {
+        let mut door = door().with_key("keycard_security");
+        assert!(!door.is_pathable());
+
+        assert!(door.unlock());
+        assert_eq!(door.state, DoorState::Closed);
+        assert!(door.is_pathable());
+        assert!(!door.unlock());
+        assert_eq!(door.toggle(), Some(true));
+    }
+}
@@ -0,0 +1,31 @@
+//! Door events.
+
+use bevy::prelude::*;
+
+/// Intent: актор выбивает ближайшую дверь (player input / AI)
+///
+/// Обрабатывается `start_door_breaches` → Channeling(Breach).
+#[derive(Event, Debug, Clone)]
+pub struct BreachDoorIntent {
+    pub actor: Entity,
+}
+
+/// Удар по двери пришёлся (channel завершён)
+///
+/// Godot: звук/тряска полотна. Шум и stagger применяет ECS.
+#[derive(Event, Debug, Clone)]
+pub struct DoorKicked {
+    pub door: Entity,
+    pub breacher: Entity,
+    /// Остаток прочности после удара
+    pub integrity: u32,
+}
+
+/// Дверь выбита (integrity = 0)
+///
+/// Godot: убрать полотно / коллизию.
+#[derive(Event, Debug, Clone)]
+pub struct DoorBreached {
+    pub door: Entity,
+    pub breacher: Entity,
+}
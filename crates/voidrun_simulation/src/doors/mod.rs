@@ -0,0 +1,53 @@
+//! Doors module — закрытые/запертые двери и их выбивание (breach)
+//!
+//! # Architecture
+//!
+//! Связывает interactables, destructibles и combat:
+//!
+//! **Flow:**
+//! - `BreachDoorIntent` (player input / `ai_breach_blocking_doors`) → Channeling(Breach)
+//! - ChannelCompleted → урон двери, шум (ActorSpotted), stagger вплотную за дверью
+//! - integrity = 0 → `DoorBreached` (Godot убирает полотно)
+//! - Запертая дверь с ключом (`Door::key` в Inventory) отпирается через [F] (`interaction`)
+//! - AI открывает незапертые двери на пути (`ai_open_doors_on_path` → InteractIntent),
+//!   запертые — выбивает; Godot NavigationLink3D через проём выключен, пока дверь заперта
+//!
+//! Door entities создаёт Godot из нод группы `breachable_doors` (ECS не знает геометрию).
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Door Plugin
+///
+/// Регистрирует открытие дверей AI и выбивание в FixedUpdate.
+pub struct DoorPlugin;
+
+impl Plugin for DoorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BreachDoorIntent>()
+            .add_event::<DoorKicked>()
+            .add_event::<DoorBreached>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    ai_open_doors_on_path,    // 1. AI: закрытая дверь на пути → InteractIntent
+                    ai_breach_blocking_doors, // 2. AI: цель за запертой дверью → BreachDoorIntent
+                    start_door_breaches,      // 3. BreachDoorIntent → Channeling(Breach)
+                    complete_door_breaches,   // 4. ChannelCompleted(Breach) → урон, шум, stagger
+                )
+                    .chain(),
+            );
+    }
+}
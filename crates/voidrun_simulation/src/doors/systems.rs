@@ -0,0 +1,218 @@
+//! Door systems (breach: channelled удар → урон двери, шум, stagger за дверью; AI открывает двери на пути).
+
+use bevy::prelude::*;
+use crate::ai::{AIState, GodotAIEvent};
+use crate::combat::{
+    ActionKind, ActionLock, ActionPhase, CancelTable, ChannelCompleted, ChannelInterrupted, ChannelKind, Channeling,
+    StaggerState,
+};
+use crate::components::{Actor, Health};
+use crate::interaction::InteractIntent;
+use crate::movement::MovementCommand;
+use crate::{SimulationTick, StrategicPosition};
+use super::components::{BreachingDoor, Door, DoorState};
+use super::events::{BreachDoorIntent, DoorBreached, DoorKicked};
+
+/// System: AI открывает незапертую дверь на пути (InteractIntent, как игрок [F])
+///
+/// Дверь Closed, AI в `AI_OPEN_RANGE`, цель движения / боя по другую сторону.
+/// Путь через дверь строит Godot NavigationLink3D (закрытая незапертая — проходима).
+pub fn ai_open_doors_on_path(
+    actors: Query<(Entity, &AIState, &MovementCommand, &StrategicPosition, &Health), Without<Channeling>>,
+    positions: Query<&StrategicPosition>,
+    doors: Query<(Entity, &Door)>,
+    mut intents: EventWriter<InteractIntent>,
+) {
+    for (entity, state, command, position, health) in actors.iter() {
+        if !health.is_alive() {
+            continue;
+        }
+
+        let destination = match (state, command) {
+            (AIState::Combat { target }, _) => positions.get(*target).ok().map(|pos| pos.to_world_position(0.5)),
+            (_, MovementCommand::MoveToPosition { target }) => Some(*target),
+            (_, MovementCommand::FollowEntity { target }) => {
+                positions.get(*target).ok().map(|pos| pos.to_world_position(0.5))
+            }
+            _ => None,
+        };
+        let Some(destination) = destination else {
+            continue;
+        };
+
+        let own_pos = position.to_world_position(0.5);
+        let door = doors.iter().find(|(_, door)| {
+            door.state == DoorState::Closed
+                && door.position.distance(own_pos) <= Door::AI_OPEN_RANGE
+                && door.separates(own_pos, destination)
+        });
+        if let Some((door_entity, _)) = door {
+            intents.write(InteractIntent {
+                actor: entity,
+                target: door_entity,
+            });
+        }
+    }
+}
+
+/// System: AI в бою выбивает дверь между собой и целью
+///
+/// Дверь заперта (закрытую AI открывает — `ai_open_doors_on_path`), AI в `BREACH_RANGE`,
+/// цель по другую сторону.
+pub fn ai_breach_blocking_doors(
+    actors: Query<(Entity, &AIState, &StrategicPosition), (Without<Channeling>, Without<BreachingDoor>)>,
+    positions: Query<&StrategicPosition>,
+    doors: Query<&Door>,
+    mut intents: EventWriter<BreachDoorIntent>,
+) {
+    for (entity, state, position) in actors.iter() {
+        let AIState::Combat { target } = state else {
+            continue;
+        };
+        let Ok(target_pos) = positions.get(*target) else {
+            continue;
+        };
+
+        let own_pos = position.to_world_position(0.5);
+        let target_pos = target_pos.to_world_position(0.5);
+
+        let blocked = doors.iter().any(|door| {
+            door.state == DoorState::Locked
+                && door.can_be_breached_from(own_pos)
+                && door.separates(own_pos, target_pos)
+        });
+        if blocked {
+            intents.write(BreachDoorIntent { actor: entity });
+        }
+    }
+}
+
+/// System: BreachDoorIntent → Channeling(Breach) у ближайшей двери в `BREACH_RANGE`
+///
+/// ActionLock + CancelTable должны разрешать Breach (не в атаке/парировании/stagger).
+/// Эффект применяется по ChannelCompleted (`complete_door_breaches`).
+pub fn start_door_breaches(
+    mut intents: EventReader<BreachDoorIntent>,
+    breachers: Query<(&StrategicPosition, Option<&ActionLock>), Without<Channeling>>,
+    doors: Query<(Entity, &Door)>,
+    cancel_table: Res<CancelTable>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((position, lock)) = breachers.get(intent.actor) else {
+            continue;
+        };
+
+        let breacher_pos = position.to_world_position(0.5);
+        let nearest = doors
+            .iter()
+            .filter(|(_, door)| door.can_be_breached_from(breacher_pos))
+            .min_by(|(_, a), (_, b)| {
+                a.position.distance(breacher_pos).total_cmp(&b.position.distance(breacher_pos))
+            });
+        let Some((door_entity, _)) = nearest else {
+            continue;
+        };
+
+        if !ActionLock::permits(lock, ActionKind::Breach, &cancel_table) {
+            continue;
+        }
+
+        commands.entity(intent.actor).insert((
+            Channeling::new(ChannelKind::Breach, tick.after_secs(Door::BREACH_DURATION)),
+            ActionLock::new(ActionKind::Breach, ActionPhase::Active),
+            BreachingDoor { door: door_entity },
+        ));
+
+        crate::logger::log(&format!("🚪 {:?} breaching door {:?}", intent.actor, door_entity));
+    }
+}
+
+/// System: завершённый Breach channel → удар по двери
+///
+/// - Урон двери (`BREACH_DAMAGE`), integrity = 0 → DoorBreached
+/// - Шум: враги в `BREACH_NOISE_RADIUS` слышат выбивающего (ActorSpotted)
+/// - Акторы вплотную за дверью → короткий stagger
+#[allow(clippy::too_many_arguments)]
+pub fn complete_door_breaches(
+    mut completed_events: EventReader<ChannelCompleted>,
+    mut interrupted_events: EventReader<ChannelInterrupted>,
+    breachers: Query<(&BreachingDoor, &Actor, &StrategicPosition)>,
+    listeners: Query<(Entity, &Actor, &StrategicPosition, &Health, Has<StaggerState>)>,
+    mut doors: Query<&mut Door>,
+    mut kicked_events: EventWriter<DoorKicked>,
+    mut breached_events: EventWriter<DoorBreached>,
+    mut spotted_events: EventWriter<GodotAIEvent>,
+    mut commands: Commands,
+) {
+    for completed in completed_events.read() {
+        if completed.kind != ChannelKind::Breach {
+            continue;
+        }
+        let Ok((breaching, breacher_actor, breacher_pos)) = breachers.get(completed.entity) else {
+            continue;
+        };
+
+        commands.entity(completed.entity).remove::<BreachingDoor>();
+
+        let Ok(mut door) = doors.get_mut(breaching.door) else {
+            continue;
+        };
+        if !door.is_blocking() {
+            continue; // Дверь открыли/выбили, пока замахивались
+        }
+
+        let breacher_pos = breacher_pos.to_world_position(0.5);
+        let breached = door.apply_damage(Door::BREACH_DAMAGE);
+
+        for (listener, listener_actor, listener_pos, health, staggered) in listeners.iter() {
+            if listener == completed.entity || !health.is_alive() {
+                continue;
+            }
+            let listener_pos = listener_pos.to_world_position(0.5);
+
+            // Вплотную за дверью → сбит с ног ударом (свои тоже)
+            if !staggered && door.is_directly_behind(breacher_pos, listener_pos) {
+                commands
+                    .entity(listener)
+                    .insert(StaggerState::new(Door::STAGGER_DURATION, completed.entity));
+            }
+
+            // Шум: враги рядом слышат выбивающего
+            if listener_actor.faction_id != breacher_actor.faction_id
+                && listener_pos.distance(breacher_pos) <= Door::BREACH_NOISE_RADIUS
+            {
+                spotted_events.write(GodotAIEvent::ActorSpotted {
+                    observer: listener,
+                    target: completed.entity,
+                });
+            }
+        }
+
+        kicked_events.write(DoorKicked {
+            door: breaching.door,
+            breacher: completed.entity,
+            integrity: door.integrity,
+        });
+
+        if breached {
+            crate::logger::log(&format!("💥 Door {:?} breached by {:?}", breaching.door, completed.entity));
+            breached_events.write(DoorBreached {
+                door: breaching.door,
+                breacher: completed.entity,
+            });
+        } else {
+            crate::logger::log(&format!(
+                "🦶 Door {:?} kicked by {:?} ({}/{})",
+                breaching.door, completed.entity, door.integrity, door.max_integrity
+            ));
+        }
+    }
+
+    for interrupted in interrupted_events.read() {
+        if interrupted.kind == ChannelKind::Breach && breachers.contains(interrupted.entity) {
+            commands.entity(interrupted.entity).remove::<BreachingDoor>();
+        }
+    }
+}
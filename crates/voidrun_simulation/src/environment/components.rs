@@ -0,0 +1,616 @@
+//! Environment components (кислород, вакуумные зоны, зоны опасности, статус-эффекты, погода, температура тела).
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Запас кислорода актора (секунды дыхания)
+///
+/// Вне вакуума восстанавливается до `capacity` (база + шлем брони),
+/// в вакууме убывает 1 ед./сек. На нуле — удушье раз в `ASPHYXIATION_INTERVAL`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Oxygen {
+    pub current: f32,
+    /// Запас без шлема (задержка дыхания)
+    pub base_capacity: f32,
+    /// Восстановление в секунду (вне вакуума)
+    pub refill_per_sec: f32,
+    /// Время до следующего тика удушья
+    pub suffocation_timer: f32,
+}
+
+impl Default for Oxygen {
+    fn default() -> Self {
+        Self {
+            current: 30.0,
+            base_capacity: 30.0,
+            refill_per_sec: 10.0,
+            suffocation_timer: 0.0,
+        }
+    }
+}
+
+impl Oxygen {
+    /// Интервал урона удушьем (секунды)
+    pub const ASPHYXIATION_INTERVAL: f32 = 1.0;
+    /// Урон удушьем за тик
+    pub const ASPHYXIATION_DAMAGE: u32 = 8;
+
+    /// Полный запас с учётом шлема (`EquippedArmor::oxygen_bonus`)
+    pub fn capacity(&self, helmet_bonus: f32) -> f32 {
+        self.base_capacity + helmet_bonus.max(0.0)
+    }
+
+    pub fn is_depleted(&self) -> bool {
+        self.current <= 0.0
+    }
+
+    /// Дышать `delta` секунд в вакууме. Возвращает true если кислород кончился именно сейчас.
+    pub fn drain(&mut self, delta: f32) -> bool {
+        if self.is_depleted() {
+            return false;
+        }
+        self.current = (self.current - delta).max(0.0);
+        if self.is_depleted() {
+            // Первый тик удушья — через полный интервал
+            self.suffocation_timer = Self::ASPHYXIATION_INTERVAL;
+            return true;
+        }
+        false
+    }
+
+    /// Восстановить запас за `delta` секунд (не выше `capacity`)
+    pub fn refill(&mut self, delta: f32, capacity: f32) {
+        self.current = (self.current + self.refill_per_sec * delta).min(capacity);
+        self.suffocation_timer = 0.0;
+    }
+
+    /// Тик удушья. Возвращает true когда пора нанести урон.
+    pub fn tick_suffocation(&mut self, delta: f32) -> bool {
+        self.suffocation_timer -= delta;
+        if self.suffocation_timer <= 0.0 {
+            self.suffocation_timer += Self::ASPHYXIATION_INTERVAL;
+            return true;
+        }
+        false
+    }
+}
+
+/// Актор в вакууме (вставляется `apply_vacuum_zone_events`)
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct InVacuum;
+
+/// Разгерметизированный объём (AABB, world coordinates)
+///
+/// Spawn: Godot (ноды группы `vacuum_zones`). Используется AI для выбора маршрутов.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct VacuumZone {
+    pub center: Vec3,
+    pub half_extents: Vec3,
+}
+
+impl VacuumZone {
+    pub fn new(center: Vec3, half_extents: Vec3) -> Self {
+        Self {
+            center,
+            half_extents: half_extents.abs(),
+        }
+    }
+
+    /// Точка внутри зоны
+    pub fn contains(&self, point: Vec3) -> bool {
+        aabb_contains(self.center, self.half_extents, point)
+    }
+}
+
+/// Точка вне всех вакуумных зон (есть воздух)
+pub fn is_pressurized<'a>(zones: impl IntoIterator<Item = &'a VacuumZone>, point: Vec3) -> bool {
+    !zones.into_iter().any(|zone| zone.contains(point))
+}
+
+/// Вид зоны опасности.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum HazardKind {
+    Radiation,
+    Fire,
+    ToxicGas,
+}
+
+impl HazardKind {
+    /// Из meta `hazard` ноды уровня ("radiation" / "fire" / "toxic_gas")
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "radiation" => Some(Self::Radiation),
+            "fire" => Some(Self::Fire),
+            "toxic_gas" => Some(Self::ToxicGas),
+            _ => None,
+        }
+    }
+
+    /// Урон в секунду внутри зоны по умолчанию
+    pub fn default_damage_per_second(self) -> f32 {
+        match self {
+            Self::Radiation => 3.0,
+            Self::Fire => 12.0,
+            Self::ToxicGas => 6.0,
+        }
+    }
+
+    /// Статус-эффект, который зона вешает по умолчанию
+    pub fn default_status(self) -> StatusEffectKind {
+        match self {
+            Self::Radiation => StatusEffectKind::Irradiated,
+            Self::Fire => StatusEffectKind::Burning,
+            Self::ToxicGas => StatusEffectKind::Poisoned,
+        }
+    }
+}
+
+/// Зона опасности (AABB, world coordinates)
+///
+/// Spawn: Godot (ноды группы `hazard_zones`). Внутри — периодический урон
+/// `DamageSource::Environmental` и статус-эффект (обновляется, пока актор в зоне).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct HazardZone {
+    pub kind: HazardKind,
+    pub damage_per_second: f32,
+    pub status: Option<StatusEffectKind>,
+    pub center: Vec3,
+    pub half_extents: Vec3,
+}
+
+impl HazardZone {
+    /// Зона с параметрами вида по умолчанию
+    pub fn new(kind: HazardKind, center: Vec3, half_extents: Vec3) -> Self {
+        Self {
+            kind,
+            damage_per_second: kind.default_damage_per_second(),
+            status: Some(kind.default_status()),
+            center,
+            half_extents: half_extents.abs(),
+        }
+    }
+
+    /// Точка внутри зоны
+    pub fn contains(&self, point: Vec3) -> bool {
+        aabb_contains(self.center, self.half_extents, point)
+    }
+
+    /// Ближайшая точка за краем зоны по XZ (+ `margin`) — куда уходить из опасности
+    pub fn escape_point(&self, point: Vec3, margin: f32) -> Vec3 {
+        let offset = point - self.center;
+        let to_x_edge = self.half_extents.x - offset.x.abs();
+        let to_z_edge = self.half_extents.z - offset.z.abs();
+
+        let mut escape = point;
+        if to_x_edge <= to_z_edge {
+            let sign = if offset.x >= 0.0 { 1.0 } else { -1.0 };
+            escape.x = self.center.x + sign * (self.half_extents.x + margin);
+        } else {
+            let sign = if offset.z >= 0.0 { 1.0 } else { -1.0 };
+            escape.z = self.center.z + sign * (self.half_extents.z + margin);
+        }
+        escape
+    }
+}
+
+/// Актор внутри зон опасности (вставляется `apply_hazard_zone_events`)
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct HazardExposure {
+    /// HazardZone entities, в которых стоит актор
+    pub zones: Vec<Entity>,
+    /// Накопленный дробный урон (применяется целыми единицами)
+    pub pending_damage: f32,
+}
+
+/// Вид статус-эффекта.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum StatusEffectKind {
+    /// Горит: сильный урон, гаснет быстро
+    Burning,
+    /// Облучён: слабый урон, держится долго
+    Irradiated,
+    /// Отравлен: слабый урон + расход stamina
+    Poisoned,
+    /// Переохлаждение (BodyTemperature ниже нормы): расход stamina + слабый урон
+    Freezing,
+    /// Перегрев (BodyTemperature выше нормы): сильный расход stamina
+    Overheating,
+}
+
+impl StatusEffectKind {
+    /// Длительность после выхода из зоны (секунды)
+    pub fn duration(self) -> f32 {
+        match self {
+            Self::Burning => 3.0,
+            Self::Irradiated => 20.0,
+            Self::Poisoned => 6.0,
+            // Обновляется каждый тик, пока температура вне нормы
+            Self::Freezing | Self::Overheating => 2.0,
+        }
+    }
+
+    pub fn damage_per_second(self) -> f32 {
+        match self {
+            Self::Burning => 5.0,
+            Self::Irradiated => 1.0,
+            Self::Poisoned => 2.0,
+            Self::Freezing => 1.0,
+            Self::Overheating => 0.5,
+        }
+    }
+
+    pub fn stamina_drain_per_second(self) -> f32 {
+        match self {
+            Self::Poisoned => 15.0,
+            Self::Freezing => 5.0,
+            Self::Overheating => 10.0,
+            Self::Burning | Self::Irradiated => 0.0,
+        }
+    }
+}
+
+/// Активный статус-эффект
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct ActiveStatusEffect {
+    pub kind: StatusEffectKind,
+    pub remaining: f32,
+}
+
+/// Статус-эффекты актора (горение, облучение, отравление)
+///
+/// Снимается `update_status_effects`, когда все эффекты истекли.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct StatusEffects {
+    pub effects: Vec<ActiveStatusEffect>,
+    /// Накопленный дробный урон (применяется целыми единицами)
+    pub pending_damage: f32,
+}
+
+impl StatusEffects {
+    pub fn has(&self, kind: StatusEffectKind) -> bool {
+        self.effects.iter().any(|effect| effect.kind == kind)
+    }
+
+    /// Повесить / обновить эффект на полную длительность. Возвращает true если эффект новый.
+    pub fn apply(&mut self, kind: StatusEffectKind) -> bool {
+        if let Some(effect) = self.effects.iter_mut().find(|effect| effect.kind == kind) {
+            effect.remaining = kind.duration();
+            return false;
+        }
+        self.effects.push(ActiveStatusEffect {
+            kind,
+            remaining: kind.duration(),
+        });
+        true
+    }
+
+    /// Тик `delta` секунд: истёкшие эффекты снимаются.
+    /// Возвращает (урон целыми единицами, расход stamina).
+    pub fn tick(&mut self, delta: f32) -> (u32, f32) {
+        let mut stamina_drain = 0.0;
+        for effect in self.effects.iter() {
+            let active = delta.min(effect.remaining.max(0.0));
+            self.pending_damage += effect.kind.damage_per_second() * active;
+            stamina_drain += effect.kind.stamina_drain_per_second() * active;
+        }
+        for effect in self.effects.iter_mut() {
+            effect.remaining -= delta;
+        }
+        self.effects.retain(|effect| effect.remaining > 0.0);
+
+        (take_whole_damage(&mut self.pending_damage), stamina_drain)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.effects.is_empty()
+    }
+}
+
+/// Вид погоды
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum WeatherKind {
+    #[default]
+    Clear,
+    Rain,
+    Storm,
+}
+
+/// Погода chunk'а: вид, сила (0..1), ветер (XZ, м/с), температура воздуха (°C)
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct WeatherState {
+    pub kind: WeatherKind,
+    pub intensity: f32,
+    pub wind: Vec2,
+    pub temperature: f32,
+}
+
+impl Default for WeatherState {
+    fn default() -> Self {
+        Self {
+            kind: WeatherKind::Clear,
+            intensity: 0.0,
+            wind: Vec2::ZERO,
+            temperature: Self::MILD_TEMPERATURE,
+        }
+    }
+}
+
+impl WeatherState {
+    /// Потеря заметности на полной силе (дождь / шторм)
+    pub const RAIN_VISIBILITY_LOSS: f32 = 0.25;
+    pub const STORM_VISIBILITY_LOSS: f32 = 0.45;
+    /// Температура по умолчанию (и в помещениях), °C
+    pub const MILD_TEMPERATURE: f32 = 18.0;
+
+    pub fn rain(intensity: f32, wind: Vec2) -> Self {
+        Self {
+            kind: WeatherKind::Rain,
+            intensity: intensity.clamp(0.0, 1.0),
+            wind,
+            ..Default::default()
+        }
+    }
+
+    pub fn storm(intensity: f32, wind: Vec2) -> Self {
+        Self {
+            kind: WeatherKind::Storm,
+            intensity: intensity.clamp(0.0, 1.0),
+            wind,
+            ..Default::default()
+        }
+    }
+
+    /// Builder: температура воздуха
+    pub fn with_temperature(mut self, temperature: f32) -> Self {
+        self.temperature = temperature;
+        self
+    }
+
+    /// Множитель заметности актора под этой погодой (0.55..1)
+    pub fn visibility_multiplier(&self) -> f32 {
+        let loss = match self.kind {
+            WeatherKind::Clear => 0.0,
+            WeatherKind::Rain => Self::RAIN_VISIBILITY_LOSS,
+            WeatherKind::Storm => Self::STORM_VISIBILITY_LOSS,
+        };
+        1.0 - loss * self.intensity
+    }
+}
+
+/// Погода по chunk'ам (resource): `default` + локальные переопределения
+#[derive(Resource, Debug, Clone, Default)]
+pub struct WeatherMap {
+    pub default: WeatherState,
+    pub chunks: HashMap<IVec2, WeatherState>,
+}
+
+impl WeatherMap {
+    /// Погода chunk'а (StrategicPosition::chunk)
+    pub fn at(&self, chunk: IVec2) -> WeatherState {
+        self.chunks.get(&chunk).copied().unwrap_or(self.default)
+    }
+}
+
+/// Актор внутри помещения — дождь и ветер не действуют (маркер)
+///
+/// Вставляется/снимается по `InteriorEntered` / `InteriorExited` (Godot volume detection).
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct Sheltered;
+
+/// Объём помещения (AABB, world coordinates)
+///
+/// Spawn: Godot (ноды группы `interior_volumes` из уровня).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct InteriorVolume {
+    pub center: Vec3,
+    pub half_extents: Vec3,
+}
+
+impl InteriorVolume {
+    pub fn new(center: Vec3, half_extents: Vec3) -> Self {
+        Self {
+            center,
+            half_extents: half_extents.abs(),
+        }
+    }
+
+    /// Точка внутри помещения
+    pub fn contains(&self, point: Vec3) -> bool {
+        aabb_contains(self.center, self.half_extents, point)
+    }
+}
+
+/// Погода, действующая на актора (chunk + Sheltered)
+///
+/// Пишет `update_weather_exposure`; перцепция умножает заметность на `visibility`,
+/// Godot берёт `rain` / `wind` для эффектов, BodyTemperature — `felt_temperature`.
+/// Без компонента — ясно и тепло (`WeatherState::MILD_TEMPERATURE`).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct WeatherExposure {
+    /// Множитель заметности (1 — погода не мешает)
+    pub visibility: f32,
+    /// Сила осадков (0..1)
+    pub rain: f32,
+    pub wind: Vec2,
+    /// Температура воздуха (°C)
+    pub temperature: f32,
+}
+
+impl Default for WeatherExposure {
+    fn default() -> Self {
+        Self {
+            visibility: 1.0,
+            rain: 0.0,
+            wind: Vec2::ZERO,
+            temperature: WeatherState::MILD_TEMPERATURE,
+        }
+    }
+}
+
+impl WeatherExposure {
+    /// Охлаждение ветром (°C на м/с)
+    pub const WIND_CHILL_PER_MS: f32 = 0.7;
+    /// Охлаждение промокшего на полной силе дождя (°C)
+    pub const RAIN_CHILL: f32 = 6.0;
+
+    /// Что доходит до актора: в помещении — как в ясную тёплую погоду
+    pub fn from_weather(weather: WeatherState, sheltered: bool) -> Self {
+        if sheltered {
+            return Self::default();
+        }
+        if weather.kind == WeatherKind::Clear {
+            return Self {
+                temperature: weather.temperature,
+                ..Self::default()
+            };
+        }
+        Self {
+            visibility: weather.visibility_multiplier(),
+            rain: weather.intensity,
+            wind: weather.wind,
+            temperature: weather.temperature,
+        }
+    }
+
+    /// Ощущаемая температура: воздух − ветер − дождь
+    pub fn felt_temperature(&self) -> f32 {
+        self.temperature - self.wind.length() * Self::WIND_CHILL_PER_MS - self.rain * Self::RAIN_CHILL
+    }
+}
+
+/// Источник тепла (костёр, обогреватель)
+///
+/// Spawn: Godot (interactable-рубильник с meta `heat`). С `Switch` греет только включённым.
+/// Тепло падает линейно от `warmth` в центре до 0 на `radius` (XZ).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct HeatSource {
+    pub position: Vec3,
+    /// Прибавка к ощущаемой температуре в центре (°C)
+    pub warmth: f32,
+    pub radius: f32,
+}
+
+impl HeatSource {
+    /// Радиус по умолчанию (метры)
+    pub const DEFAULT_RADIUS: f32 = 4.0;
+
+    pub fn new(position: Vec3, warmth: f32) -> Self {
+        Self {
+            position,
+            warmth,
+            radius: Self::DEFAULT_RADIUS,
+        }
+    }
+
+    /// Прибавка к температуре в точке (°C, 0 за радиусом)
+    pub fn warmth_at(&self, point: Vec3) -> f32 {
+        if self.radius <= 0.0 {
+            return 0.0;
+        }
+        let offset = point - self.position;
+        let distance = Vec2::new(offset.x, offset.z).length();
+        self.warmth * (1.0 - distance / self.radius).max(0.0)
+    }
+}
+
+/// Температура тела актора (°C) — опциональная survival-характеристика
+///
+/// Тянется к `target` (ощущаемая температура + изоляция брони + тепло рядом).
+/// Ниже `FREEZING_BELOW` — статус `Freezing`, выше `OVERHEATING_ABOVE` — `Overheating`.
+/// Без компонента актор к температуре равнодушен (NPC).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct BodyTemperature {
+    pub current: f32,
+}
+
+impl Default for BodyTemperature {
+    fn default() -> Self {
+        Self {
+            current: Self::NORMAL,
+        }
+    }
+}
+
+impl BodyTemperature {
+    pub const NORMAL: f32 = 37.0;
+    pub const FREEZING_BELOW: f32 = 35.0;
+    pub const OVERHEATING_ABOVE: f32 = 39.0;
+    /// Комфортная ощущаемая температура (°C) — тело не остывает и не греется
+    pub const COMFORT_MIN: f32 = 10.0;
+    pub const COMFORT_MAX: f32 = 28.0;
+    /// Сдвиг целевой температуры тела на градус холода/жары снаружи
+    pub const SENSITIVITY: f32 = 0.15;
+    /// Скорость изменения температуры тела (°C/сек)
+    pub const DRIFT_PER_SEC: f32 = 0.05;
+    /// Предел целевой температуры тела
+    pub const MIN: f32 = 30.0;
+    pub const MAX: f32 = 42.0;
+
+    /// Целевая температура тела при ощущаемой `felt` и изоляции брони (0..1)
+    ///
+    /// Изоляция гасит холод, но немного усиливает жару.
+    pub fn target(felt: f32, insulation: f32) -> f32 {
+        let insulation = insulation.clamp(0.0, 1.0);
+        let cold = (Self::COMFORT_MIN - felt).max(0.0) * (1.0 - insulation);
+        let heat = (felt - Self::COMFORT_MAX).max(0.0) * (1.0 + insulation * 0.5);
+        (Self::NORMAL + (heat - cold) * Self::SENSITIVITY).clamp(Self::MIN, Self::MAX)
+    }
+
+    /// Сдвиг к `target` за `delta` секунд (без перелёта)
+    pub fn drift(&mut self, target: f32, delta: f32) {
+        let step = Self::DRIFT_PER_SEC * delta;
+        self.current += (target - self.current).clamp(-step, step);
+    }
+
+    /// Статус при текущей температуре (`None` — в норме)
+    pub fn condition(&self) -> Option<StatusEffectKind> {
+        if self.current < Self::FREEZING_BELOW {
+            Some(StatusEffectKind::Freezing)
+        } else if self.current > Self::OVERHEATING_ABOVE {
+            Some(StatusEffectKind::Overheating)
+        } else {
+            None
+        }
+    }
+}
+
+/// Стоимость вакуума для выбора точек AI (сравнима с DPS зон опасности)
+pub const VACUUM_TRAVERSAL_COST: f32 = 10.0;
+
+/// Стоимость точки для AI: вакуум + DPS всех зон опасности (0 = безопасно)
+pub fn traversal_cost<'a>(
+    vacuum_zones: impl IntoIterator<Item = &'a VacuumZone>,
+    hazard_zones: impl IntoIterator<Item = &'a HazardZone>,
+    point: Vec3,
+) -> f32 {
+    let vacuum = if is_pressurized(vacuum_zones, point) { 0.0 } else { VACUUM_TRAVERSAL_COST };
+    let hazards: f32 = hazard_zones
+        .into_iter()
+        .filter(|zone| zone.contains(point))
+        .map(|zone| zone.damage_per_second)
+        .sum();
+    vacuum + hazards
+}
+
+/// Забрать целую часть накопленного урона
+pub fn take_whole_damage(pending: &mut f32) -> u32 {
+    let whole = pending.floor().max(0.0);
+    *pending -= whole;
+    whole as u32
+}
+
+fn aabb_contains(center: Vec3, half_extents: Vec3, point: Vec3) -> bool {
+    let offset = (point - center).abs();
+    offset.x <= half_extents.x && offset.y <= half_extents.y && offset.z <= half_extents.z
+}
@@ -0,0 +1,157 @@
+//! Tests for environment components (кислород, вакуумные зоны, зоны опасности, статус-эффекты, погода).
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::*;
+
+    #[test]
+    fn helmet_extends_capacity() {
+        let oxygen = Oxygen::default();
+
+        assert_eq!(oxygen.capacity(0.0), oxygen.base_capacity);
+        assert_eq!(oxygen.capacity(90.0), oxygen.base_capacity + 90.0);
+    }
+
+    #[test]
+    fn drain_reports_depletion_once_then_suffocates_each_interval() {
+        let mut oxygen = Oxygen {
+            current: 1.0,
+            ..Default::default()
+        };
+
+        assert!(!oxygen.drain(0.5));
+        assert!(oxygen.drain(0.5));
+        assert!(oxygen.is_depleted());
+        // Повторно OxygenDepleted не шлём
+        assert!(!oxygen.drain(0.5));
+
+        // Первый тик удушья — через полный интервал
+        assert!(!oxygen.tick_suffocation(Oxygen::ASPHYXIATION_INTERVAL * 0.5));
+        assert!(oxygen.tick_suffocation(Oxygen::ASPHYXIATION_INTERVAL * 0.5));
+    }
+
+    #[test]
+    fn refill_caps_at_capacity() {
+        let mut oxygen = Oxygen {
+            current: 0.0,
+            ..Default::default()
+        };
+
+        oxygen.refill(100.0, 40.0);
+
+        assert_eq!(oxygen.current, 40.0);
+        assert_eq!(oxygen.suffocation_timer, 0.0);
+    }
+
+    #[test]
+    fn vacuum_zone_contains_and_pressurized_check() {
+        let zone = VacuumZone::new(Vec3::new(10.0, 0.0, 0.0), Vec3::new(2.0, 3.0, 2.0));
+
+        assert!(zone.contains(Vec3::new(11.0, 1.0, -1.5)));
+        assert!(!zone.contains(Vec3::new(13.0, 0.0, 0.0)));
+        assert!(!is_pressurized([&zone], Vec3::new(10.0, 0.0, 0.0)));
+        assert!(is_pressurized([&zone], Vec3::ZERO));
+    }
+
+    #[test]
+    fn hazard_escape_point_leaves_zone_through_nearest_edge() {
+        let zone = HazardZone::new(HazardKind::Fire, Vec3::ZERO, Vec3::new(5.0, 2.0, 2.0));
+
+        // Ближе к краю по Z → выходим по +Z
+        let escape = zone.escape_point(Vec3::new(1.0, 0.0, 1.5), 1.0);
+        assert_eq!(escape, Vec3::new(1.0, 0.0, 3.0));
+        assert!(!zone.contains(escape));
+    }
+
+    #[test]
+    fn status_effects_refresh_tick_and_expire() {
+        let mut statuses = StatusEffects::default();
+
+        assert!(statuses.apply(StatusEffectKind::Poisoned));
+        assert!(!statuses.apply(StatusEffectKind::Poisoned));
+
+        // 1 секунда отравления: урон 2, stamina 15
+        let (damage, stamina_drain) = statuses.tick(1.0);
+        assert_eq!(damage, 2);
+        assert!((stamina_drain - 15.0).abs() < 1e-4);
+
+        // Истекает — эффект снят, урон только за оставшееся время
+        let remaining = StatusEffectKind::Poisoned.duration() - 1.0;
+        let (damage, _) = statuses.tick(remaining + 10.0);
+        assert_eq!(damage, (remaining * 2.0) as u32);
+        assert!(statuses.is_empty());
+    }
+
+    #[test]
+    fn traversal_cost_sums_vacuum_and_hazards() {
+        let vacuum = VacuumZone::new(Vec3::ZERO, Vec3::splat(2.0));
+        let radiation = HazardZone::new(HazardKind::Radiation, Vec3::ZERO, Vec3::splat(2.0));
+
+        assert_eq!(
+            traversal_cost([&vacuum], [&radiation], Vec3::ZERO),
+            VACUUM_TRAVERSAL_COST + HazardKind::Radiation.default_damage_per_second()
+        );
+        assert_eq!(traversal_cost([&vacuum], [&radiation], Vec3::splat(5.0)), 0.0);
+    }
+
+    #[test]
+    fn shelter_suppresses_chunk_weather() {
+        let mut weather = WeatherMap::default();
+        weather.chunks.insert(IVec2::new(1, 0), WeatherState::storm(1.0, Vec2::new(6.0, 0.0)));
+
+        assert_eq!(weather.at(IVec2::ZERO).kind, WeatherKind::Clear);
+
+        let outside = WeatherExposure::from_weather(weather.at(IVec2::new(1, 0)), false);
+        assert!((outside.visibility - (1.0 - WeatherState::STORM_VISIBILITY_LOSS)).abs() < 1e-5);
+        assert_eq!(outside.wind, Vec2::new(6.0, 0.0));
+
+        let inside = WeatherExposure::from_weather(weather.at(IVec2::new(1, 0)), true);
+        assert_eq!(inside, WeatherExposure::default());
+    }
+
+    #[test]
+    fn wind_and_rain_chill_felt_temperature() {
+        let cold_storm = WeatherState::storm(1.0, Vec2::new(10.0, 0.0)).with_temperature(5.0);
+
+        let outside = WeatherExposure::from_weather(cold_storm, false);
+        let expected = 5.0 - 10.0 * WeatherExposure::WIND_CHILL_PER_MS - WeatherExposure::RAIN_CHILL;
+        assert!((outside.felt_temperature() - expected).abs() < 1e-4);
+
+        // Ясно, но холодно — температура доходит, дождя и ветра нет
+        let clear_cold = WeatherExposure::from_weather(WeatherState::default().with_temperature(-10.0), false);
+        assert_eq!(clear_cold.felt_temperature(), -10.0);
+
+        // В помещении — мягкая температура
+        let inside = WeatherExposure::from_weather(cold_storm, true);
+        assert_eq!(inside.felt_temperature(), WeatherState::MILD_TEMPERATURE);
+    }
+
+    #[test]
+    fn insulation_dampens_cold_and_body_drifts_to_condition() {
+        let bare = BodyTemperature::target(-20.0, 0.0);
+        let suited = BodyTemperature::target(-20.0, 0.8);
+        assert!(bare < suited && suited < BodyTemperature::NORMAL);
+        assert_eq!(BodyTemperature::target(WeatherState::MILD_TEMPERATURE, 0.0), BodyTemperature::NORMAL);
+
+        let mut body = BodyTemperature::default();
+        assert_eq!(body.condition(), None);
+
+        // Дрейф не перелетает цель
+        body.drift(bare, 1000.0);
+        assert_eq!(body.current, bare);
+        assert_eq!(body.condition(), Some(StatusEffectKind::Freezing));
+
+        body.current = BodyTemperature::OVERHEATING_ABOVE + 0.5;
+        assert_eq!(body.condition(), Some(StatusEffectKind::Overheating));
+    }
+
+    #[test]
+    fn heat_source_warmth_falls_off_with_distance() {
+        let campfire = HeatSource::new(Vec3::ZERO, 20.0);
+
+        assert_eq!(campfire.warmth_at(Vec3::new(0.0, 1.0, 0.0)), 20.0);
+        assert!((campfire.warmth_at(Vec3::new(HeatSource::DEFAULT_RADIUS / 2.0, 0.0, 0.0)) - 10.0).abs() < 1e-4);
+        assert_eq!(campfire.warmth_at(Vec3::new(10.0, 0.0, 0.0)), 0.0);
+    }
+}
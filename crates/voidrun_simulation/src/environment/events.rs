@@ -0,0 +1,56 @@
+//! Environment events.
+
+use bevy::prelude::*;
+use super::components::StatusEffectKind;
+
+/// Актор вошёл в вакуумную зону (Godot → ECS, overlap Area3D)
+#[derive(Event, Debug, Clone)]
+pub struct VacuumZoneEntered {
+    pub entity: Entity,
+}
+
+/// Актор покинул все вакуумные зоны (Godot → ECS)
+#[derive(Event, Debug, Clone)]
+pub struct VacuumZoneExited {
+    pub entity: Entity,
+}
+
+/// Кислород кончился — начинается удушье (ECS → UI/audio)
+#[derive(Event, Debug, Clone)]
+pub struct OxygenDepleted {
+    pub entity: Entity,
+}
+
+/// Актор вошёл в зону опасности (Godot → ECS, overlap Area3D)
+#[derive(Event, Debug, Clone)]
+pub struct HazardZoneEntered {
+    pub entity: Entity,
+    /// HazardZone entity
+    pub zone: Entity,
+}
+
+/// Актор покинул зону опасности (Godot → ECS)
+#[derive(Event, Debug, Clone)]
+pub struct HazardZoneExited {
+    pub entity: Entity,
+    pub zone: Entity,
+}
+
+/// На актора повешен новый статус-эффект (ECS → UI/VFX)
+#[derive(Event, Debug, Clone)]
+pub struct StatusEffectApplied {
+    pub entity: Entity,
+    pub kind: StatusEffectKind,
+}
+
+/// Актор вошёл в помещение (Godot → ECS, overlap Area3D `interior_volumes`)
+#[derive(Event, Debug, Clone)]
+pub struct InteriorEntered {
+    pub entity: Entity,
+}
+
+/// Актор покинул все помещения (Godot → ECS)
+#[derive(Event, Debug, Clone)]
+pub struct InteriorExited {
+    pub entity: Entity,
+}
@@ -0,0 +1,75 @@
+//! Environment module — кислород и вакуум (разгерметизированные отсеки), зоны опасности, погода, температура
+//!
+//! # Architecture
+//!
+//! Геометрия вакуумных зон — в уровне (Godot authoritative): Godot регистрирует
+//! зоны как `VacuumZone` entities и по overlap шлёт `VacuumZoneEntered` / `VacuumZoneExited`.
+//!
+//! **Flow:**
+//! - `VacuumZoneEntered` → `InVacuum` (маркер)
+//! - `InVacuum` → `Oxygen` убывает (1 ед. = 1 секунда дыхания)
+//! - Кислород кончился → `OxygenDepleted` + удушье (`DamageSource::Environmental` раз в секунду)
+//! - Вне вакуума → запас восстанавливается до `Oxygen::capacity` (шлем брони добавляет запас)
+//!
+//! Зоны опасности (радиация, огонь, токсичный газ) — тоже из уровня (`HazardZone`):
+//! - `HazardZoneEntered` / `HazardZoneExited` → `HazardExposure` (список зон актора)
+//! - Внутри: урон `damage_per_second` + статус-эффект зоны (`StatusEffects`)
+//! - Статус тикает и после выхода (горение, облучение, отравление)
+//!
+//! AI: вакуум и зоны опасности — дорогие точки (`traversal_cost`) для патруля и отступления.
+//!
+//! Погода — по chunk'ам (`WeatherMap`): дождь / шторм снижают заметность актора (`WeatherExposure`).
+//! Помещения (`interior_volumes` уровня) → `InteriorEntered` / `InteriorExited` → `Sheltered`:
+//! внутри дождь, ветер и штраф перцепции не действуют.
+//!
+//! Температура тела (`BodyTemperature`, опционально — у игрока): ощущаемая температура
+//! (воздух chunk'а − ветер − дождь, в помещении мягкая) + `HeatSource` рядом (костёр, обогреватель),
+//! изоляция брони гасит холод. Вне нормы — статус `Freezing` / `Overheating` (урон, расход stamina).
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Environment Plugin
+///
+/// Регистрирует вакуум, кислород, зоны опасности, погоду и температуру тела в FixedUpdate.
+pub struct EnvironmentPlugin;
+
+impl Plugin for EnvironmentPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<VacuumZoneEntered>()
+            .add_event::<VacuumZoneExited>()
+            .add_event::<OxygenDepleted>()
+            .add_event::<HazardZoneEntered>()
+            .add_event::<HazardZoneExited>()
+            .add_event::<StatusEffectApplied>()
+            .add_event::<InteriorEntered>()
+            .add_event::<InteriorExited>()
+            .init_resource::<WeatherMap>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    apply_vacuum_zone_events, // 1. VacuumZoneEntered/Exited → InVacuum
+                    update_oxygen,            // 2. Расход/восстановление кислорода + удушье
+                    apply_hazard_zone_events, // 3. HazardZoneEntered/Exited → HazardExposure
+                    apply_hazard_damage,      // 4. Урон зон + статус-эффекты зон
+                    update_status_effects,    // 5. Тик статус-эффектов (урон, stamina)
+                    apply_interior_events,    // 6. InteriorEntered/Exited → Sheltered
+                    update_weather_exposure,  // 7. Погода chunk'а + Sheltered → WeatherExposure
+                    update_body_temperature,  // 8. WeatherExposure + броня + HeatSource → BodyTemperature (+ статус)
+                )
+                    .chain(),
+            );
+    }
+}
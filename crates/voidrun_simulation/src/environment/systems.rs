@@ -0,0 +1,369 @@
+//! Environment systems (вакуум → расход кислорода → удушье; зоны опасности → урон + статус-эффекты;
+//! погода chunk'а → WeatherExposure, помещения → Sheltered; погода + броня + тепло → BodyTemperature).
+
+use bevy::prelude::*;
+use crate::combat::{apply_damage, DamageDealt, DamageGate, DamageSource};
+use crate::components::{Actor, EquippedArmor, Health, Stamina};
+use crate::StrategicPosition;
+use crate::interaction::Switch;
+use super::components::{
+    take_whole_damage, BodyTemperature, HazardExposure, HazardZone, HeatSource, InVacuum, Oxygen, Sheltered,
+    StatusEffects, WeatherExposure, WeatherMap,
+};
+use super::events::{
+    HazardZoneEntered, HazardZoneExited, InteriorEntered, InteriorExited, OxygenDepleted, StatusEffectApplied,
+    VacuumZoneEntered, VacuumZoneExited,
+};
+use std::collections::BTreeMap;
+
+/// System: VacuumZoneEntered / VacuumZoneExited → InVacuum
+pub fn apply_vacuum_zone_events(
+    mut entered: EventReader<VacuumZoneEntered>,
+    mut exited: EventReader<VacuumZoneExited>,
+    mut commands: Commands,
+) {
+    for event in entered.read() {
+        let Ok(mut entity_commands) = commands.get_entity(event.entity) else {
+            continue;
+        };
+        entity_commands.insert(InVacuum);
+
+        crate::logger::log(&format!("🫧 {:?} entered vacuum", event.entity));
+    }
+
+    for event in exited.read() {
+        if let Ok(mut entity_commands) = commands.get_entity(event.entity) {
+            entity_commands.remove::<InVacuum>();
+        }
+    }
+}
+
+/// System: расход / восстановление кислорода, удушье
+///
+/// - `InVacuum` → запас убывает; 0 → `OxygenDepleted` и урон `ASPHYXIATION_DAMAGE`
+///   раз в `ASPHYXIATION_INTERVAL` (`DamageSource::Environmental`, щит не защищает)
+/// - Вне вакуума → восстановление до `capacity` (снятый шлем срезает запас)
+/// - Invulnerable блокирует урон (`apply_damage`)
+pub fn update_oxygen(
+    mut actors: Query<(
+        Entity,
+        &mut Oxygen,
+        &mut Health,
+        Option<&EquippedArmor>,
+        Has<InVacuum>,
+        Option<&StrategicPosition>,
+    )>,
+    mut depleted_events: EventWriter<OxygenDepleted>,
+    mut damage_events: EventWriter<DamageDealt>,
+    mut damage_gate: DamageGate,
+    time: Res<Time<Fixed>>,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut oxygen, mut health, armor, in_vacuum, position) in actors.iter_mut() {
+        if !health.is_alive() {
+            continue;
+        }
+
+        let capacity = oxygen.capacity(armor.map_or(0.0, |armor| armor.oxygen_bonus()));
+
+        if !in_vacuum {
+            if oxygen.current != capacity {
+                oxygen.refill(delta, capacity);
+            }
+            continue;
+        }
+
+        oxygen.current = oxygen.current.min(capacity);
+
+        if oxygen.drain(delta) {
+            depleted_events.write(OxygenDepleted { entity });
+            crate::logger::log(&format!("😵 {:?} out of oxygen", entity));
+            continue;
+        }
+
+        if !oxygen.is_depleted() || !oxygen.tick_suffocation(delta) {
+            continue;
+        }
+
+        deal_environmental_damage(
+            entity,
+            &mut health,
+            Oxygen::ASPHYXIATION_DAMAGE,
+            position,
+            &mut damage_gate,
+            &mut damage_events,
+        );
+    }
+}
+
+/// System: HazardZoneEntered / HazardZoneExited → HazardExposure (список зон актора)
+pub fn apply_hazard_zone_events(
+    mut entered: EventReader<HazardZoneEntered>,
+    mut exited: EventReader<HazardZoneExited>,
+    exposures: Query<&HazardExposure>,
+    mut commands: Commands,
+) {
+    // BTreeMap — детерминированный порядок; несколько событий на актора за тик сводим в один insert
+    let mut updated: BTreeMap<Entity, Vec<Entity>> = BTreeMap::new();
+
+    for event in entered.read() {
+        let zones = updated
+            .entry(event.entity)
+            .or_insert_with(|| exposures.get(event.entity).map(|e| e.zones.clone()).unwrap_or_default());
+        if !zones.contains(&event.zone) {
+            zones.push(event.zone);
+        }
+    }
+    for event in exited.read() {
+        let zones = updated
+            .entry(event.entity)
+            .or_insert_with(|| exposures.get(event.entity).map(|e| e.zones.clone()).unwrap_or_default());
+        zones.retain(|&zone| zone != event.zone);
+    }
+
+    for (entity, zones) in updated {
+        let Ok(mut entity_commands) = commands.get_entity(entity) else {
+            continue;
+        };
+
+        if zones.is_empty() {
+            entity_commands.remove::<HazardExposure>();
+            continue;
+        }
+
+        let pending_damage = exposures.get(entity).map_or(0.0, |exposure| exposure.pending_damage);
+        entity_commands.insert(HazardExposure { zones, pending_damage });
+    }
+}
+
+/// System: урон зон опасности + статус-эффекты зон
+///
+/// - Урон: сумма `damage_per_second` всех зон актора, целыми единицами (`DamageSource::Environmental`)
+/// - Статус зоны обновляется на полную длительность каждый тик (после выхода — тикает сам)
+/// - Invulnerable блокирует урон (статус всё равно вешается)
+pub fn apply_hazard_damage(
+    mut actors: Query<(
+        Entity,
+        &mut HazardExposure,
+        &mut Health,
+        Option<&mut StatusEffects>,
+        Option<&StrategicPosition>,
+    )>,
+    zones: Query<&HazardZone>,
+    mut applied_events: EventWriter<StatusEffectApplied>,
+    mut damage_events: EventWriter<DamageDealt>,
+    mut damage_gate: DamageGate,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut exposure, mut health, mut statuses, position) in actors.iter_mut() {
+        if !health.is_alive() {
+            continue;
+        }
+
+        let mut damage_per_second = 0.0;
+        let mut new_statuses = StatusEffects::default();
+        for zone in exposure.zones.iter().filter_map(|zone| zones.get(*zone).ok()) {
+            damage_per_second += zone.damage_per_second;
+
+            let Some(kind) = zone.status else {
+                continue;
+            };
+            let is_new = match statuses.as_deref_mut() {
+                Some(statuses) => statuses.apply(kind),
+                None => new_statuses.apply(kind),
+            };
+            if is_new {
+                applied_events.write(StatusEffectApplied { entity, kind });
+            }
+        }
+        if !new_statuses.is_empty() {
+            commands.entity(entity).insert(new_statuses);
+        }
+
+        exposure.pending_damage += damage_per_second * delta;
+        let damage = take_whole_damage(&mut exposure.pending_damage);
+        if damage == 0 {
+            continue;
+        }
+
+        deal_environmental_damage(entity, &mut health, damage, position, &mut damage_gate, &mut damage_events);
+    }
+}
+
+/// System: тик статус-эффектов (урон, расход stamina), все истекли → компонент снят
+pub fn update_status_effects(
+    mut actors: Query<(
+        Entity,
+        &mut StatusEffects,
+        &mut Health,
+        Option<&mut Stamina>,
+        Option<&StrategicPosition>,
+    )>,
+    mut damage_events: EventWriter<DamageDealt>,
+    mut damage_gate: DamageGate,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut statuses, mut health, stamina, position) in actors.iter_mut() {
+        if !health.is_alive() {
+            commands.entity(entity).remove::<StatusEffects>();
+            continue;
+        }
+
+        let (damage, stamina_drain) = statuses.tick(delta);
+        if statuses.is_empty() {
+            commands.entity(entity).remove::<StatusEffects>();
+        }
+
+        if let Some(mut stamina) = stamina {
+            if stamina_drain > 0.0 {
+                stamina.current = (stamina.current - stamina_drain).max(0.0);
+            }
+        }
+
+        if damage == 0 {
+            continue;
+        }
+
+        deal_environmental_damage(entity, &mut health, damage, position, &mut damage_gate, &mut damage_events);
+    }
+}
+
+/// Урон среды: щит не защищает, attacker = сам актор (как fall damage), Invulnerable — без урона
+fn deal_environmental_damage(
+    entity: Entity,
+    health: &mut Health,
+    damage: u32,
+    position: Option<&StrategicPosition>,
+    damage_gate: &mut DamageGate,
+    damage_events: &mut EventWriter<DamageDealt>,
+) {
+    let Some(applied) =
+        apply_damage(damage_gate, entity, entity, health, None, damage, DamageSource::Environmental)
+    else {
+        return;
+    };
+
+    damage_events.write(DamageDealt {
+        attacker: entity,
+        target: entity,
+        damage,
+        source: DamageSource::Environmental,
+        applied_damage: applied,
+        impact_point: position.map(|pos| pos.to_world_position(0.0)).unwrap_or(Vec3::ZERO),
+        impact_normal: Vec3::Y,
+    });
+}
+
+/// System: InteriorEntered / InteriorExited → Sheltered
+pub fn apply_interior_events(
+    mut entered: EventReader<InteriorEntered>,
+    mut exited: EventReader<InteriorExited>,
+    mut commands: Commands,
+) {
+    for event in entered.read() {
+        if let Ok(mut entity_commands) = commands.get_entity(event.entity) {
+            entity_commands.insert(Sheltered);
+        }
+    }
+
+    for event in exited.read() {
+        if let Ok(mut entity_commands) = commands.get_entity(event.entity) {
+            entity_commands.remove::<Sheltered>();
+        }
+    }
+}
+
+/// System: погода chunk'а актора + Sheltered → WeatherExposure
+///
+/// В помещении дождь, ветер и штраф заметности не действуют.
+/// Компонент вставляется только когда погода реально действует (ясно — без компонента).
+pub fn update_weather_exposure(
+    mut actors: Query<(Entity, &StrategicPosition, Has<Sheltered>, Option<&mut WeatherExposure>), With<Actor>>,
+    weather: Res<WeatherMap>,
+    mut commands: Commands,
+) {
+    for (entity, position, sheltered, exposure) in actors.iter_mut() {
+        let fresh = WeatherExposure::from_weather(weather.at(position.chunk), sheltered);
+
+        match exposure {
+            Some(mut exposure) => {
+                if *exposure != fresh {
+                    *exposure = fresh;
+                }
+            }
+            None => {
+                if fresh != WeatherExposure::default() {
+                    commands.entity(entity).insert(fresh);
+                }
+            }
+        }
+    }
+}
+
+/// System: погода + изоляция брони + источники тепла → BodyTemperature → статус Freezing / Overheating
+///
+/// - Ощущаемая температура: `WeatherExposure::felt_temperature` (без компонента — мягкая погода)
+///   + тепло ближайших `HeatSource` (с `Switch` — только включённые)
+/// - Тело тянется к `BodyTemperature::target` со скоростью `DRIFT_PER_SEC`
+/// - Вне нормы статус обновляется каждый тик (урон / stamina тикает `update_status_effects`)
+#[allow(clippy::too_many_arguments)]
+pub fn update_body_temperature(
+    mut actors: Query<(
+        Entity,
+        &mut BodyTemperature,
+        &Health,
+        &StrategicPosition,
+        Option<&WeatherExposure>,
+        Option<&EquippedArmor>,
+        Option<&mut StatusEffects>,
+    )>,
+    heat_sources: Query<(&HeatSource, Option<&Switch>)>,
+    mut applied_events: EventWriter<StatusEffectApplied>,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut body, health, position, exposure, armor, statuses) in actors.iter_mut() {
+        if !health.is_alive() {
+            continue;
+        }
+
+        let point = position.to_world_position(0.0);
+        let warmth: f32 = heat_sources
+            .iter()
+            .filter(|(_, switch)| switch.is_none_or(|switch| switch.on))
+            .map(|(source, _)| source.warmth_at(point))
+            .sum();
+        let felt = exposure.copied().unwrap_or_default().felt_temperature() + warmth;
+        let target = BodyTemperature::target(felt, armor.map_or(0.0, |armor| armor.insulation()));
+
+        if body.current != target {
+            body.drift(target, delta);
+        }
+
+        let Some(kind) = body.condition() else {
+            continue;
+        };
+        let is_new = match statuses {
+            Some(mut statuses) => statuses.apply(kind),
+            None => {
+                let mut fresh = StatusEffects::default();
+                fresh.apply(kind);
+                commands.entity(entity).insert(fresh);
+                true
+            }
+        };
+        if is_new {
+            applied_events.write(StatusEffectApplied { entity, kind });
+            crate::logger::log(&format!("🌡️ {:?} {:?} ({:.1}°C)", entity, kind, body.current));
+        }
+    }
+}
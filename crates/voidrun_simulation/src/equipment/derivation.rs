@@ -0,0 +1,53 @@
+//! Weapon stats derivation — WeaponStats актора выводится из активного оружия EquippedWeapons
+//!
+//! Единственный источник истины — `EquippedItem` в активном слоте:
+//! ItemDefinition (WeaponStatsTemplate + prefab) + affixes экземпляра → WeaponStats + Attachment.
+//!
+//! **Кэш:** `DerivedWeaponSource` запоминает, из чего выведен текущий WeaponStats.
+//! EquippedWeapons меняется часто (износ, патроны, клин) — пересчёт только при смене
+//! слота / предмета / affixes, runtime state (cooldown, нагрев) при этом не сбрасывается.
+//!
+//! Акторы без EquippedWeapons (тестовые NPC archetypes) — WeaponStats задаётся при спавне.
+
+use bevy::prelude::*;
+
+use crate::components::equipment::{EquippedItem, EquippedWeapons};
+use crate::item_system::{Affix, ItemDefinitions, ItemId};
+use crate::{Attachment, AttachmentType, WeaponStats};
+
+/// Из чего выведен текущий WeaponStats актора (кэш derive_weapon_stats)
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct DerivedWeaponSource {
+    /// Активный слот EquippedWeapons
+    pub slot: u8,
+    /// Предмет в слоте
+    pub definition_id: ItemId,
+    /// Affixes экземпляра
+    pub affixes: Vec<Affix>,
+}
+
+impl DerivedWeaponSource {
+    /// Источник активного оружия (None — руки пустые)
+    pub fn of_active(weapons: &EquippedWeapons) -> Option<Self> {
+        weapons.get_active_weapon().map(|item| Self {
+            slot: weapons.active_slot,
+            definition_id: item.definition_id.clone(),
+            affixes: item.affixes.clone(),
+        })
+    }
+}
+
+/// WeaponStats + Attachment экипированного оружия (None — предмет не оружие / нет definition)
+pub fn derive_weapon_components(item: &EquippedItem, definitions: &ItemDefinitions) -> Option<(WeaponStats, Attachment)> {
+    let def = definitions.get(&item.definition_id)?;
+    let template = def.weapon_template.as_ref()?;
+
+    Some((
+        template.to_weapon_stats_with_affixes(&item.affixes),
+        Attachment {
+            prefab_path: def.prefab_path.clone().unwrap_or_default(),
+            attachment_point: def.attachment_point.clone().unwrap_or_default(),
+            attachment_type: AttachmentType::Weapon,
+        },
+    ))
+}
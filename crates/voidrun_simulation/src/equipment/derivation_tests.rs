@@ -0,0 +1,48 @@
+//! Tests for weapon stats derivation (EquippedWeapons → WeaponStats).
+
+#[cfg(test)]
+mod tests {
+    use super::super::derivation::*;
+    use crate::components::equipment::{EquippedItem, EquippedWeapons};
+    use crate::item_system::{Affix, ItemDefinitions, ItemInstance, WeaponStatsTemplate};
+
+    #[test]
+    fn test_derive_from_definition_with_affixes() {
+        let definitions = ItemDefinitions::default();
+        let mut instance = ItemInstance::new("melee_sword");
+        instance.affixes = vec![Affix::DamagePercent(0.2)];
+
+        let (stats, attachment) = derive_weapon_components(&EquippedItem::from_instance(&instance), &definitions).unwrap();
+
+        let base = WeaponStatsTemplate::melee_sword().stats.base_damage;
+        assert_eq!(stats.base_damage, (base as f32 * 1.2).round() as u32);
+        assert_eq!(attachment.prefab_path, "res://actors/test_sword.tscn");
+        assert_eq!(attachment.attachment_point, "%RightHandAttachment");
+    }
+
+    #[test]
+    fn test_non_weapon_is_not_derived() {
+        let item = EquippedItem::from_instance(&ItemInstance::new("health_kit"));
+        assert!(derive_weapon_components(&item, &ItemDefinitions::default()).is_none());
+    }
+
+    #[test]
+    fn test_source_ignores_runtime_state() {
+        let mut weapons = EquippedWeapons::empty();
+        assert!(DerivedWeaponSource::of_active(&weapons).is_none());
+
+        weapons.set_slot(0, Some(EquippedItem::from_instance(&ItemInstance::new("melee_sword"))));
+        let before = DerivedWeaponSource::of_active(&weapons);
+
+        // Износ / клин — тот же источник (кэш не сбрасывается)
+        let active = weapons.get_active_weapon_mut().unwrap();
+        active.durability = 0.4;
+        active.jammed = true;
+        assert_eq!(DerivedWeaponSource::of_active(&weapons), before);
+
+        // Другой слот — другой источник
+        weapons.set_slot(2, Some(EquippedItem::from_instance(&ItemInstance::new("pistol_basic"))));
+        weapons.active_slot = 2;
+        assert_ne!(DerivedWeaponSource::of_active(&weapons), before);
+    }
+}
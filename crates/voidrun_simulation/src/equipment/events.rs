@@ -8,14 +8,26 @@
 //! - `SwapActiveWeaponIntent` → меняет active slot (smooth transition)
 //!
 //! **Armor lifecycle:**
-//! - `EquipArmorIntent` → equip armor (добавляет Armor + Attachment + unlock consumable slots)
-//! - `UnequipArmorIntent` → unequip armor (удаляет компоненты, lock consumable slots)
+//! - `EquipArmorIntent` → часть брони в свой слот EquippedArmor (пересчёт комплекта + consumable slots)
+//! - `UnequipArmorIntent` → освободить слот брони (часть → Inventory)
 //!
 //! **Consumables:**
 //! - `UseConsumableIntent` → use consumable из слота (instant effect)
+//!
+//! **Throwables:**
+//! - `ThrowIntent` → одна штука метательного оружия из consumable слота → `ThrowableLaunched` (Godot бросок)
+//! - `ThrowableLanded` (Godot) → предмет лежит WorldItem'ом где упал
+//!
+//! **Loadouts:**
+//! - `ApplyLoadoutIntent` → пресет из `LoadoutBook` целиком (через equip intents) или `LoadoutRejected`
+//! - `SaveLoadoutIntent` → текущее снаряжение → пресет в `LoadoutBook`
+//!
+//! **Inventory stacks:**
+//! - `SplitStackIntent` → отделить часть стака в новый слот
+//! - `MergeStackIntent` → пересыпать стак в другой (до `ItemDefinition::max_stack`)
 
 use bevy::prelude::*;
-use crate::item_system::ItemInstance;
+use crate::item_system::{ArmorSlot, ItemId, ItemInstance};
 
 // ============================================================================
 // Weapon Events
@@ -97,28 +109,29 @@ impl WeaponSlot {
 // Armor Events
 // ============================================================================
 
-/// Equip armor
+/// Equip armor (слот — из `ArmorStatsTemplate::slot` предмета)
 ///
 /// # Flow
-/// 1. Unequip старую броню (если есть)
-/// 2. Добавить Armor компонент
-/// 3. Добавить Attachment (визуал на %Body)
-/// 4. Unlock consumable slots (2 + armor bonus)
+/// 1. Старая часть в этом слоте → Inventory
+/// 2. Часть → `EquippedArmor` (компонент создаётся при первой части)
+/// 3. Пересчёт бонуса комплекта
+/// 4. Unlock consumable slots (2 + суммарный armor bonus)
 #[derive(Event, Clone, Debug)]
 pub struct EquipArmorIntent {
     pub entity: Entity,
     pub item: ItemInstance,
 }
 
-/// Unequip armor
+/// Unequip armor из слота
 ///
 /// # Flow
-/// 1. Удалить Armor компонент
-/// 2. Удалить Attachment (визуал)
-/// 3. Lock consumable slots (обратно к базовым 2)
+/// 1. Часть из слота → Inventory
+/// 2. Пересчёт бонуса комплекта
+/// 3. Consumable slots по оставшимся частям
 #[derive(Event, Clone, Debug)]
 pub struct UnequipArmorIntent {
     pub entity: Entity,
+    pub slot: ArmorSlot,
 }
 
 // ============================================================================
@@ -136,3 +149,110 @@ pub struct UseConsumableIntent {
     pub entity: Entity,
     pub slot_index: u8, // 0-4 (hotkeys 5-9)
 }
+
+// ============================================================================
+// Throwable Events
+// ============================================================================
+
+/// Бросить метательное оружие (первый unlocked consumable слот с `ItemType::Throwable`)
+#[derive(Event, Clone, Debug)]
+pub struct ThrowIntent {
+    pub thrower: Entity,
+}
+
+/// Бросок одобрен: штука снята со слота (ECS → Godot)
+///
+/// Godot спавнит летящий предмет по дуге; попадание в актора → `MeleeHit` (урон melee-класса),
+/// приземление / попадание → `ThrowableLanded`.
+#[derive(Event, Clone, Debug)]
+pub struct ThrowableLaunched {
+    pub thrower: Entity,
+    /// Брошенный экземпляр (stack_size = 1)
+    pub item: ItemInstance,
+    pub damage: u32,
+    /// Начальная скорость (м/с)
+    pub speed: f32,
+}
+
+/// Брошенный предмет упал (Godot → ECS) → WorldItem в `position`
+#[derive(Event, Clone, Debug)]
+pub struct ThrowableLanded {
+    pub item: ItemInstance,
+    /// Позиция приземления (world coordinates)
+    pub position: Vec3,
+}
+
+// ============================================================================
+// Inventory Stack Events
+// ============================================================================
+
+/// Отделить `count` штук стака Inventory в новый слот
+///
+/// # Flow
+/// 1. Проверить индекс и что `count` в 1..stack_size
+/// 2. Уменьшить стак, вставить новый сразу после него
+#[derive(Event, Clone, Debug)]
+pub struct SplitStackIntent {
+    pub entity: Entity,
+    pub index: usize,
+    pub count: u32,
+}
+
+/// Пересыпать стак `from` в стак `into` (тот же предмет)
+///
+/// # Flow
+/// 1. Проверить что стаки совместимы и `into` не полон
+/// 2. Перенести до `ItemDefinition::max_stack`, пустой `from` удалить
+#[derive(Event, Clone, Debug)]
+pub struct MergeStackIntent {
+    pub entity: Entity,
+    pub from: usize,
+    pub into: usize,
+}
+
+// ============================================================================
+// Loadout Events
+// ============================================================================
+
+/// Применить пресет снаряжения (оружие, броня, consumables) атомарно
+///
+/// # Flow
+/// 1. Пресет из `LoadoutBook` (нет → `LoadoutRejected(UnknownLoadout)`)
+/// 2. `Loadout::plan`: всё нужное есть в Inventory (+ снимаемое)? Нет → `LoadoutRejected(MissingItems)`, ничего не меняется
+/// 3. Снятое → Inventory, `EquipWeaponIntent` / `EquipArmorIntent` / `UnequipArmorIntent`, consumables в слоты
+/// 4. `LoadoutApplied`
+#[derive(Event, Clone, Debug)]
+pub struct ApplyLoadoutIntent {
+    pub entity: Entity,
+    pub loadout: String,
+}
+
+/// Сохранить текущее снаряжение как пресет (перезаписывает одноимённый)
+#[derive(Event, Clone, Debug)]
+pub struct SaveLoadoutIntent {
+    pub entity: Entity,
+    pub name: String,
+}
+
+/// Пресет применён (ECS → UI)
+#[derive(Event, Clone, Debug)]
+pub struct LoadoutApplied {
+    pub entity: Entity,
+    pub loadout: String,
+}
+
+/// Почему пресет не применён
+#[derive(Clone, Debug, PartialEq)]
+pub enum LoadoutRejection {
+    UnknownLoadout,
+    /// Не хватает предметов в Inventory
+    MissingItems(Vec<ItemId>),
+}
+
+/// Пресет не применён — снаряжение не изменилось (ECS → UI)
+#[derive(Event, Clone, Debug)]
+pub struct LoadoutRejected {
+    pub entity: Entity,
+    pub loadout: String,
+    pub reason: LoadoutRejection,
+}
@@ -0,0 +1,240 @@
+//! Loadouts — пресеты снаряжения (оружие по слотам, броня, consumables) из RON.
+//!
+//! Пресеты игрока (`SaveLoadoutIntent`) и archetypes NPC (`data/loadouts.ron`) лежат в `LoadoutBook`.
+//! `Loadout::plan` проверяет всё заранее: либо план целиком, либо список недостающих предметов.
+
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use crate::components::equipment::{ConsumableSlots, EquippedArmor, EquippedWeapons, Inventory};
+use crate::item_system::{ArmorSlot, ItemDefinitions, ItemId, ItemInstance};
+
+/// Ошибка разбора loadouts из RON
+pub type LoadoutParseError = ron::error::SpannedError;
+
+/// Встроенные пресеты (data/loadouts.ron)
+const DEFAULT_LOADOUTS: &str = include_str!("../../data/loadouts.ron");
+
+/// Consumable слота пресета
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LoadoutConsumable {
+    /// ItemId (ItemDefinitions)
+    pub item: String,
+    #[serde(default = "LoadoutConsumable::single")]
+    pub count: u32,
+}
+
+impl LoadoutConsumable {
+    fn single() -> u32 {
+        1
+    }
+}
+
+/// Пресет снаряжения
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Loadout {
+    /// ItemId по слотам оружия (0-3, hotkeys 1-4)
+    #[serde(default)]
+    pub weapons: [Option<String>; 4],
+    /// ItemId брони по слотам (Helmet, Chest, Legs, Boots)
+    #[serde(default)]
+    pub armor: [Option<String>; 4],
+    /// Слоты 5-9 по порядку (лишние отбрасываются)
+    #[serde(default)]
+    pub consumables: Vec<LoadoutConsumable>,
+}
+
+/// Проверенный план применения пресета
+#[derive(Debug, Clone)]
+pub struct LoadoutPlan {
+    /// Меняющиеся слоты оружия: (слот, новый предмет; None — освободить)
+    pub weapon_changes: Vec<(u8, Option<ItemInstance>)>,
+    /// Меняющиеся слоты брони: (слот, новая часть; None — освободить)
+    pub armor_changes: Vec<(ArmorSlot, Option<ItemInstance>)>,
+    pub consumables: [Option<ItemInstance>; 5],
+    /// Inventory после применения (снятое вернулось, взятое списано); None — актор без Inventory
+    pub inventory: Option<Vec<ItemInstance>>,
+}
+
+impl Loadout {
+    /// Снимок текущего снаряжения актора
+    pub fn capture(weapons: &EquippedWeapons, armor: Option<&EquippedArmor>, consumables: Option<&ConsumableSlots>) -> Self {
+        Self {
+            weapons: std::array::from_fn(|slot| {
+                weapons.get_slot(slot as u8).map(|item| item.definition_id.0.clone())
+            }),
+            armor: std::array::from_fn(|index| {
+                armor
+                    .and_then(|armor| armor.get(ArmorSlot::ALL[index]))
+                    .map(|piece| piece.definition_id.0.clone())
+            }),
+            consumables: consumables
+                .map(|slots| {
+                    slots
+                        .slots
+                        .iter()
+                        .flatten()
+                        .map(|item| LoadoutConsumable {
+                            item: item.definition_id.0.clone(),
+                            count: item.stack_size,
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        }
+    }
+
+    /// Спланировать применение: снятое уходит в Inventory, нужное берётся из него.
+    ///
+    /// - С Inventory: всё нужное должно найтись (Inventory + снимаемое), иначе `Err(недостающие)`
+    /// - Без Inventory (NPC archetype): новые экземпляры, снятое пропадает
+    /// - Слоты, где уже лежит нужный предмет, не трогаются
+    pub fn plan(
+        &self,
+        weapons: &EquippedWeapons,
+        armor: Option<&EquippedArmor>,
+        consumables: Option<&ConsumableSlots>,
+        inventory: Option<&Inventory>,
+    ) -> Result<LoadoutPlan, Vec<ItemId>> {
+        let mut pool = inventory.map(|inventory| Inventory {
+            items: inventory.items.clone(),
+            ..Inventory::empty()
+        });
+
+        // 1. Снимаемое → в пул (до выбора: предмет может переехать в другой слот)
+        let changed_slots: Vec<u8> = (0..4u8)
+            .filter(|&slot| {
+                weapons.get_slot(slot).map(|item| item.definition_id.0.as_str())
+                    != self.weapons[slot as usize].as_deref()
+            })
+            .collect();
+        let changed_armor: Vec<ArmorSlot> = ArmorSlot::ALL
+            .into_iter()
+            .filter(|&slot| {
+                armor.and_then(|armor| armor.get(slot)).map(|piece| piece.definition_id.0.as_str())
+                    != self.armor[slot.index()].as_deref()
+            })
+            .collect();
+
+        if let Some(pool) = pool.as_mut() {
+            for &slot in changed_slots.iter() {
+                if let Some(item) = weapons.get_slot(slot) {
+                    pool.add_item(item.to_instance());
+                }
+            }
+            for &slot in changed_armor.iter() {
+                if let Some(piece) = armor.and_then(|armor| armor.get(slot)) {
+                    pool.add_item(piece.to_instance());
+                }
+            }
+            for item in consumables.into_iter().flat_map(|slots| slots.slots.iter().flatten()) {
+                pool.add_item(item.clone());
+            }
+        }
+
+        // 2. Берём нужное из пула (или создаём для NPC)
+        let mut missing = Vec::new();
+        let mut take = |pool: &mut Option<Inventory>, id: &str| -> Option<ItemInstance> {
+            let id = ItemId::from(id);
+            let Some(pool) = pool.as_mut() else {
+                return Some(ItemInstance::new(id));
+            };
+            let taken = pool.find_item(&id).and_then(|index| pool.remove_item(index));
+            if taken.is_none() {
+                missing.push(id);
+            }
+            taken
+        };
+
+        let weapon_changes: Vec<(u8, Option<ItemInstance>)> = changed_slots
+            .iter()
+            .map(|&slot| {
+                let item = self.weapons[slot as usize].as_deref().and_then(|id| take(&mut pool, id));
+                (slot, item)
+            })
+            .collect();
+
+        let armor_changes: Vec<(ArmorSlot, Option<ItemInstance>)> = changed_armor
+            .iter()
+            .map(|&slot| {
+                let piece = self.armor[slot.index()].as_deref().and_then(|id| take(&mut pool, id));
+                (slot, piece)
+            })
+            .collect();
+
+        let mut consumable_slots: [Option<ItemInstance>; 5] = Default::default();
+        for (slot, entry) in consumable_slots.iter_mut().zip(self.consumables.iter()) {
+            let id = ItemId::from(entry.item.as_str());
+            let available = pool.as_mut().is_none_or(|pool| pool.consume_item(&id, entry.count));
+            if available {
+                *slot = Some(ItemInstance::consumable_stack(id, entry.count));
+            } else {
+                missing.push(id);
+            }
+        }
+
+        if !missing.is_empty() {
+            return Err(missing);
+        }
+
+        Ok(LoadoutPlan {
+            weapon_changes,
+            armor_changes,
+            consumables: consumable_slots,
+            inventory: pool.map(|pool| pool.items),
+        })
+    }
+
+    /// Все ItemId пресета
+    fn item_ids(&self) -> impl Iterator<Item = &str> {
+        self.weapons
+            .iter()
+            .flatten()
+            .chain(self.armor.iter().flatten())
+            .chain(self.consumables.iter().map(|entry| &entry.item))
+            .map(String::as_str)
+    }
+}
+
+/// Пресеты снаряжения по имени (resource)
+///
+/// По умолчанию — встроенные (`data/loadouts.ron`), пресеты игрока добавляет `SaveLoadoutIntent`.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+pub struct LoadoutBook {
+    pub loadouts: HashMap<String, Loadout>,
+}
+
+impl Default for LoadoutBook {
+    fn default() -> Self {
+        Self::from_ron(DEFAULT_LOADOUTS).unwrap_or_else(|error| {
+            crate::logger::log_error(&format!("Default loadouts failed to parse: {}", error));
+            Self { loadouts: HashMap::new() }
+        })
+    }
+}
+
+impl LoadoutBook {
+    pub fn from_ron(source: &str) -> Result<Self, LoadoutParseError> {
+        ron::from_str(source)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Loadout> {
+        self.loadouts.get(name)
+    }
+
+    /// ItemId пресетов, которых нет в ItemDefinitions ("loadout: item")
+    pub fn unknown_items(&self, definitions: &ItemDefinitions) -> Vec<String> {
+        let mut unknown: Vec<String> = self
+            .loadouts
+            .iter()
+            .flat_map(|(name, loadout)| {
+                loadout
+                    .item_ids()
+                    .filter(|item| definitions.get(&ItemId::from(*item)).is_none())
+                    .map(move |item| format!("{}: {}", name, item))
+            })
+            .collect();
+        unknown.sort();
+        unknown
+    }
+}
@@ -0,0 +1,106 @@
+//! Tests for loadouts (RON, план применения, проверка Inventory).
+
+#[cfg(test)]
+mod tests {
+    use super::super::loadout::*;
+    use crate::components::equipment::{ConsumableSlots, EquippedItem, EquippedWeapons, Inventory};
+    use crate::item_system::{ArmorSlot, ItemDefinitions, ItemId, ItemInstance};
+
+    fn sword_only() -> EquippedWeapons {
+        let mut weapons = EquippedWeapons::empty();
+        weapons.set_slot(0, Some(EquippedItem::from_instance(&ItemInstance::new("melee_sword"))));
+        weapons
+    }
+
+    #[test]
+    fn test_default_loadouts_reference_known_items() {
+        let book = LoadoutBook::default();
+
+        assert!(book.get("player_default").is_some());
+        assert!(book.get("npc_elite").is_some());
+        assert!(book.unknown_items(&ItemDefinitions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_plan_rejects_missing_items_atomically() {
+        let loadout = LoadoutBook::default().get("player_default").cloned().unwrap();
+        let mut inventory = Inventory::empty();
+        inventory.add_item(ItemInstance::new("pistol_basic"));
+
+        // Нет health_kit → отказ целиком
+        let missing = loadout
+            .plan(&sword_only(), None, Some(&ConsumableSlots::default()), Some(&inventory))
+            .unwrap_err();
+        assert_eq!(missing, vec![ItemId::from("health_kit")]);
+    }
+
+    #[test]
+    fn test_plan_takes_from_inventory_and_keeps_matching_slots() {
+        let loadout = LoadoutBook::default().get("player_default").cloned().unwrap();
+        let mut inventory = Inventory::empty();
+        inventory.add_item(ItemInstance::new("pistol_basic"));
+        inventory.add_item(ItemInstance::consumable_stack("health_kit", 3));
+
+        let plan = loadout
+            .plan(&sword_only(), None, Some(&ConsumableSlots::default()), Some(&inventory))
+            .unwrap();
+
+        // Меч уже в слоте 0 — меняется только слот 2
+        assert_eq!(plan.weapon_changes.len(), 1);
+        let (slot, item) = &plan.weapon_changes[0];
+        assert_eq!(*slot, 2);
+        assert_eq!(item.as_ref().unwrap().definition_id, ItemId::from("pistol_basic"));
+
+        assert!(plan.armor_changes.is_empty());
+        assert_eq!(plan.consumables[0].as_ref().unwrap().stack_size, 2);
+
+        // Из Inventory ушли пистолет и 2 аптечки
+        let remaining = plan.inventory.unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].stack_size, 1);
+    }
+
+    #[test]
+    fn test_plan_moves_equipped_weapon_between_slots() {
+        let loadout = Loadout {
+            weapons: [None, Some("melee_sword".to_string()), None, None],
+            ..Default::default()
+        };
+
+        let plan = loadout
+            .plan(&sword_only(), None, None, Some(&Inventory::empty()))
+            .unwrap();
+
+        // Слот 0 освобождён, меч переехал в слот 1, Inventory не изменился
+        assert_eq!(plan.weapon_changes.len(), 2);
+        assert!(plan.weapon_changes.iter().any(|(slot, item)| *slot == 0 && item.is_none()));
+        assert!(plan.weapon_changes.iter().any(|(slot, item)| *slot == 1 && item.is_some()));
+        assert!(plan.inventory.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_plan_without_inventory_creates_items() {
+        let loadout = LoadoutBook::default().get("npc_elite").cloned().unwrap();
+
+        let plan = loadout.plan(&EquippedWeapons::empty(), None, None, None).unwrap();
+
+        assert_eq!(plan.weapon_changes.len(), 3);
+        assert_eq!(plan.armor_changes.len(), 4);
+        assert!(plan.armor_changes.iter().any(|(slot, item)| {
+            *slot == ArmorSlot::Chest && item.as_ref().unwrap().definition_id == ItemId::from("armor_military")
+        }));
+        assert!(plan.consumables[1].is_some());
+        assert!(plan.inventory.is_none());
+    }
+
+    #[test]
+    fn test_capture_round_trips_through_plan() {
+        let weapons = sword_only();
+        let captured = Loadout::capture(&weapons, None, None);
+
+        assert_eq!(captured.weapons[0].as_deref(), Some("melee_sword"));
+        let plan = captured.plan(&weapons, None, None, Some(&Inventory::empty())).unwrap();
+        assert!(plan.weapon_changes.is_empty());
+        assert!(plan.armor_changes.is_empty());
+    }
+}
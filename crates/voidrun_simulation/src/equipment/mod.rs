@@ -11,21 +11,53 @@
 //! - Equip → добавить WeaponStats + Attachment
 //! - Unequip → удалить компоненты, вернуть в Inventory
 //! - Swap → smooth transition (detach → attach)
+//! - WeaponStats + Attachment выводятся из активного слота (`derive_weapon_stats`), не ставятся вручную
 //!
 //! **Armor lifecycle:**
-//! - Equip → добавить Armor + Attachment + unlock consumables
-//! - Unequip → удалить компоненты, lock consumables
+//! - Слоты Helmet / Chest / Legs / Boots в `EquippedArmor` (слот — из `ArmorStatsTemplate::slot`)
+//! - Equip / Unequip → снятое в Inventory, пересчёт бонуса комплекта + consumable slots
+//! - Генератор щита (`ArmorStatsTemplate::shield`, пояс в слоте Legs): equip → `EnergyShield`
+//!   по шаблону (доля заряда прежнего щита сохраняется), снятие → щита нет
+//! - Визуал: Godot синхронизирует prefab каждого слота по Changed<EquippedArmor>
+//!
+//! **Loadouts:**
+//! - Пресеты (`LoadoutBook`, `data/loadouts.ron` + сохранённые игроком) → `ApplyLoadoutIntent`
+//! - Применение атомарно: Inventory проверяется заранее, экипировка — через equip intents
+//! - NPC archetypes (без Inventory) получают новые экземпляры
+//!
+//! **Inventory stacks:**
+//! - Split / Merge intents → стаки до `ItemDefinition::max_stack`
+//!
+//! **Encumbrance:**
+//! - Inventory изменился → carry weight > max_weight → `Encumbered` (штраф скорости, без спринта)
 //!
 //! **Consumables:**
 //! - Use → instant effect (restore HP/stamina, spawn grenade)
+//! - AI: Channeling(Consumable) → ChannelCompleted → Use (interruptible уроном)
+//!
+//! **Throwables:**
+//! - Throw → штука из consumable слота летит (Godot дуга) → попадание = melee урон → WorldItem где упал
 
 use bevy::prelude::*;
+use crate::game_state::GameState;
 
+pub mod derivation;
 pub mod events;
+pub mod loadout;
 pub mod systems;
 
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod loadout_tests;
+#[cfg(test)]
+mod derivation_tests;
+#[cfg(test)]
+mod systems_tests;
+
 // Re-exports
+pub use derivation::*;
 pub use events::*;
+pub use loadout::*;
 pub use systems::*;
 
 /// Equipment plugin (lifecycle management)
@@ -41,14 +73,38 @@ impl Plugin for EquipmentPlugin {
             .add_event::<EquipArmorIntent>()
             .add_event::<UnequipArmorIntent>()
             .add_event::<UseConsumableIntent>()
+            .add_event::<ThrowIntent>()
+            .add_event::<ThrowableLaunched>()
+            .add_event::<ThrowableLanded>()
+            .add_event::<SplitStackIntent>()
+            .add_event::<MergeStackIntent>()
+            .add_event::<ApplyLoadoutIntent>()
+            .add_event::<SaveLoadoutIntent>()
+            .add_event::<LoadoutApplied>()
+            .add_event::<LoadoutRejected>()
+            .init_resource::<LoadoutBook>()
+            .add_systems(Startup, validate_loadout_book)
             // Systems (обрабатываем в Update schedule)
             .add_systems(Update, (
+                apply_loadouts.before(process_equip_weapon).before(process_equip_armor).before(process_unequip_armor),
+                save_loadouts,
                 process_equip_weapon,
                 process_unequip_weapon,
                 process_weapon_swap,
+                derive_weapon_stats
+                    .after(apply_loadouts)
+                    .after(process_equip_weapon)
+                    .after(process_unequip_weapon)
+                    .after(process_weapon_swap),
                 process_equip_armor,
                 process_unequip_armor,
+                complete_consumable_channels.before(process_use_consumable),
                 process_use_consumable,
-            ));
+                process_throw_intents,
+                spawn_landed_throwables,
+                process_split_stack,
+                process_merge_stack,
+                update_encumbrance,
+            ).run_if(in_state(GameState::Playing)));
     }
 }
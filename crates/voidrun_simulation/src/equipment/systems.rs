@@ -6,21 +6,42 @@
 //! - `process_equip_weapon` — equip weapon в слот
 //! - `process_unequip_weapon` — unequip weapon из слота
 //! - `process_weapon_swap` — smooth swap активного оружия
+//! - `derive_weapon_stats` — активное оружие → WeaponStats + Attachment (кэш по источнику)
 //!
 //! **Armor lifecycle:**
-//! - `process_equip_armor` — equip armor
-//! - `process_unequip_armor` — unequip armor
+//! - `process_equip_armor` — часть брони в слот EquippedArmor (генератор щита → EnergyShield)
+//! - `process_unequip_armor` — освободить слот брони (снят генератор → без EnergyShield)
+//!
+//! **Loadouts:**
+//! - `validate_loadout_book` — пресеты ссылаются на существующие ItemId
+//! - `apply_loadouts` — пресет целиком через equip intents
+//! - `save_loadouts` — текущее снаряжение → пресет
+//!
+//! **Inventory stacks:**
+//! - `process_split_stack` — разделить стак
+//! - `process_merge_stack` — объединить стаки
+//!
+//! **Encumbrance:**
+//! - `update_encumbrance` — carry weight → Encumbered
 //!
 //! **Consumables:**
 //! - `process_use_consumable` — use consumable из слота
+//! - `complete_consumable_channels` — завершённый "using item" channel → use consumable
+//!
+//! **Throwables:**
+//! - `process_throw_intents` — одна штука из слота → ThrowableLaunched
+//! - `spawn_landed_throwables` — ThrowableLanded → WorldItem
 
 use bevy::prelude::*;
+use std::collections::HashMap;
 use crate::{
     components::equipment::*,
+    equipment::derivation::{derive_weapon_components, DerivedWeaponSource},
     equipment::events::*,
-    item_system::{ItemDefinitions, ItemInstance},
+    equipment::loadout::{Loadout, LoadoutBook},
+    item_system::{ItemDefinitions, ItemInstance, ItemType},
     logger::{log, log_error} ,
-    Attachment, AttachmentType, WeaponStats,
+    Attachment, WeaponStats,
 };
 
 // ============================================================================
@@ -28,8 +49,9 @@ use crate::{
 // ============================================================================
 
 /// Process equip weapon intents
+///
+/// WeaponStats + Attachment выводит `derive_weapon_stats` (по Changed<EquippedWeapons>)
 pub fn process_equip_weapon(
-    mut commands: Commands,
     mut events: EventReader<EquipWeaponIntent>,
     mut equipped: Query<(&mut EquippedWeapons, Option<&mut Inventory>)>,
     definitions: Res<ItemDefinitions>,
@@ -46,19 +68,7 @@ pub fn process_equip_weapon(
         if let Some(old_item) = weapons.get_slot_mut(slot_index).take() {
             // Вернуть в inventory
             if let Some(ref mut inv) = inventory {
-                inv.add_item(ItemInstance {
-                    definition_id: old_item.definition_id.clone(),
-                    stack_size: 1,
-                    durability: Some(old_item.durability),
-                    ammo_count: old_item.ammo_count,
-                });
-            }
-
-            // Если это активный слот → удалить WeaponStats + Attachment
-            if weapons.active_slot == slot_index {
-                commands.entity(intent.entity)
-                    .remove::<WeaponStats>()
-                    .remove::<Attachment>();
+                inv.add_item(old_item.to_instance());
             }
         }
 
@@ -68,30 +78,14 @@ pub fn process_equip_weapon(
             continue;
         };
 
-        weapons.set_slot(slot_index, Some(EquippedItem {
-            definition_id: intent.item.definition_id.clone(),
-            durability: intent.item.durability.unwrap_or(1.0),
-            ammo_count: intent.item.ammo_count,
-        }));
+        if def.weapon_template.is_none() {
+            log_error("Item is not a weapon!");
+            continue;
+        }
 
-        // 3. Если это активный слот → добавить WeaponStats + Attachment
-        if weapons.active_slot == slot_index {
-            let Some(template) = &def.weapon_template else {
-                log_error("Item is not a weapon!");
-                continue;
-            };
-
-            commands.entity(intent.entity).insert((
-                template.to_weapon_stats(),
-                Attachment {
-                    prefab_path: def.prefab_path.clone().unwrap_or_default(),
-                    attachment_point: def.attachment_point.clone().unwrap_or_default(),
-                    attachment_type: AttachmentType::Weapon,
-                },
-            ));
+        weapons.set_slot(slot_index, Some(EquippedItem::from_instance(&intent.item)));
 
-            log(&format!("✅ Equipped weapon {} to slot {:?}", def.name, intent.slot));
-        }
+        log(&format!("✅ Equipped weapon {} to slot {:?}", def.name, intent.slot));
     }
 }
 
@@ -101,7 +95,6 @@ pub fn process_equip_weapon(
 
 /// Process unequip weapon intents
 pub fn process_unequip_weapon(
-    mut commands: Commands,
     mut events: EventReader<UnequipWeaponIntent>,
     mut equipped: Query<(&mut EquippedWeapons, Option<&mut Inventory>)>,
 ) {
@@ -120,22 +113,10 @@ pub fn process_unequip_weapon(
 
         // 2. Вернуть в inventory
         if let Some(ref mut inv) = inventory {
-            inv.add_item(ItemInstance {
-                definition_id: old_item.definition_id.clone(),
-                stack_size: 1,
-                durability: Some(old_item.durability),
-                ammo_count: old_item.ammo_count,
-            });
+            inv.add_item(old_item.to_instance());
         }
 
-        // 3. Если это активный слот → удалить WeaponStats + Attachment
-        if weapons.active_slot == slot_index {
-            commands.entity(intent.entity)
-                .remove::<WeaponStats>()
-                .remove::<Attachment>();
-
-            log(&format!("🗑️ Unequipped weapon from slot {:?}", intent.slot));
-        }
+        log(&format!("🗑️ Unequipped weapon from slot {:?}", intent.slot));
     }
 }
 
@@ -145,7 +126,6 @@ pub fn process_unequip_weapon(
 
 /// Process weapon swap intents (smooth transition)
 pub fn process_weapon_swap(
-    mut commands: Commands,
     mut events: EventReader<SwapActiveWeaponIntent>,
     mut equipped: Query<&mut EquippedWeapons>,
     definitions: Res<ItemDefinitions>,
@@ -169,26 +149,14 @@ pub fn process_weapon_swap(
         let Some(def) = definitions.get(&new_weapon.definition_id) else {
             continue;
         };
-
-        // === Smooth swap flow ===
-
-        // 1. Update active slot
-        weapons.active_slot = intent.target_slot;
-
-        // 2. Update WeaponStats + Attachment
-        // NOTE: attach_prefabs_main_thread автоматически detach старый prefab при Changed<Attachment>
         let Some(template) = &def.weapon_template else {
             continue;
         };
 
-        commands.entity(intent.entity).insert((
-            template.to_weapon_stats(),
-            Attachment {
-                prefab_path: def.prefab_path.clone().unwrap_or_default(),
-                attachment_point: def.attachment_point.clone().unwrap_or_default(),
-                attachment_type: AttachmentType::Weapon,
-            },
-        ));
+        // === Smooth swap flow ===
+        // Active slot → derive_weapon_stats обновит WeaponStats + Attachment
+        // NOTE: attach_prefabs_main_thread автоматически detach старый prefab при Changed<Attachment>
+        weapons.active_slot = intent.target_slot;
 
         log(&format!("✅ Weapon swap → slot {} ({}, {})",
             intent.target_slot,
@@ -198,17 +166,69 @@ pub fn process_weapon_swap(
     }
 }
 
+// ============================================================================
+// Weapon Stats Derivation
+// ============================================================================
+
+/// System: активное оружие EquippedWeapons → WeaponStats + Attachment
+///
+/// Один источник истины (ItemDefinition + affixes экземпляра) для equip / unequip / swap / loadout.
+/// Кэш `DerivedWeaponSource`: износ / патроны / клин меняют EquippedWeapons, но не источник →
+/// WeaponStats не пересобирается (cooldown и нагрев не сбрасываются).
+/// Пустые руки → WeaponStats + Attachment снимаются.
+pub fn derive_weapon_stats(
+    mut commands: Commands,
+    actors: Query<(Entity, &EquippedWeapons, Option<&DerivedWeaponSource>), Changed<EquippedWeapons>>,
+    definitions: Res<ItemDefinitions>,
+) {
+    for (entity, weapons, derived) in actors.iter() {
+        let source = DerivedWeaponSource::of_active(weapons);
+        if source.as_ref() == derived {
+            continue; // Кэш: источник не изменился
+        }
+
+        let components = weapons
+            .get_active_weapon()
+            .and_then(|item| derive_weapon_components(item, &definitions));
+
+        match (source, components) {
+            (Some(source), Some((stats, attachment))) => {
+                log(&format!(
+                    "🔧 WeaponStats derived for {:?}: {} (slot {}, {} affixes)",
+                    entity, source.definition_id.0, source.slot, source.affixes.len()
+                ));
+                commands.entity(entity).insert((stats, attachment, source));
+            }
+            _ => {
+                commands.entity(entity)
+                    .remove::<WeaponStats>()
+                    .remove::<Attachment>()
+                    .remove::<DerivedWeaponSource>();
+            }
+        }
+    }
+}
+
 // ============================================================================
 // Armor Equip
 // ============================================================================
 
 /// Process equip armor intents
+///
+/// Несколько частей за кадр (loadout) → копятся в `pending` для акторов без EquippedArmor.
 pub fn process_equip_armor(
     mut commands: Commands,
     mut events: EventReader<EquipArmorIntent>,
-    mut consumables: Query<&mut ConsumableSlots>,
+    mut actors: Query<(
+        Option<&mut EquippedArmor>,
+        Option<&mut ConsumableSlots>,
+        Option<&mut Inventory>,
+        Option<&EnergyShield>,
+    )>,
     definitions: Res<ItemDefinitions>,
 ) {
+    let mut pending: HashMap<Entity, EquippedArmor> = HashMap::new();
+
     for intent in events.read() {
         let Some(def) = definitions.get(&intent.item.definition_id) else {
             continue;
@@ -219,30 +239,53 @@ pub fn process_equip_armor(
             continue;
         };
 
-        // 1. Add Armor component
-        commands.entity(intent.entity).insert(Armor {
-            definition_id: intent.item.definition_id.clone(),
-            durability: intent.item.durability.unwrap_or(1.0),
-            defense: armor_stats.defense,
-            consumable_slot_bonus: armor_stats.consumable_slot_bonus,
-        });
+        let Ok((mut equipped, consumables, inventory, current_shield)) = actors.get_mut(intent.entity) else {
+            continue;
+        };
 
-        // 2. Add Attachment (визуал)
-        if let Some(prefab_path) = &def.prefab_path {
-            commands.entity(intent.entity).insert(Attachment {
-                prefab_path: prefab_path.clone(),
-                attachment_point: "%Body".to_string(),
-                attachment_type: AttachmentType::Armor,
-            });
+        // 1. Часть в свой слот (template + affixes экземпляра), старая → Inventory
+        let armor = match equipped.as_deref_mut() {
+            Some(armor) => armor,
+            None => pending.entry(intent.entity).or_default(),
+        };
+        let replaced = armor.equip(Armor::from_item(&intent.item, armor_stats));
+        let replaced_generator = replaced.as_ref().is_some_and(|piece| piece.shield.is_some());
+        if let (Some(replaced), Some(mut inventory)) = (replaced, inventory) {
+            inventory.add_item(replaced.to_instance());
+        }
+
+        // 1.5 Генератор щита: новый EnergyShield (доля заряда прежнего щита сохраняется)
+        if let Some(template) = &armor_stats.shield {
+            let mut shield = template.to_energy_shield();
+            if let Some(current) = current_shield {
+                shield.carry_charge_from(current);
+            }
+            log(&format!("🛡️ Shield generator equipped ({}): {:?}", def.name, template.coverage));
+            commands.entity(intent.entity).insert(shield);
+        } else if replaced_generator {
+            commands.entity(intent.entity).remove::<EnergyShield>();
+        }
+
+        // 2. Бонус комплекта
+        armor.refresh_set_bonus(&definitions);
+        if let Some(bonus) = &armor.set_bonus {
+            log(&format!("🛡️ Armor set complete: {}", bonus.name));
         }
 
         // 3. Unlock consumable slots
-        if let Ok(mut slots) = consumables.get_mut(intent.entity) {
-            let unlocked = 2 + armor_stats.consumable_slot_bonus;
+        let unlocked = 2 + armor.consumable_slot_bonus();
+        if let Some(mut slots) = consumables {
             slots.unlock_slots(unlocked);
-
-            log(&format!("✅ Armor equipped - {} consumable slots unlocked", unlocked));
         }
+
+        log(&format!(
+            "✅ {:?} equipped ({}) - {} consumable slots unlocked",
+            armor_stats.slot, def.name, unlocked
+        ));
+    }
+
+    for (entity, armor) in pending {
+        commands.entity(entity).insert(armor);
     }
 }
 
@@ -254,21 +297,31 @@ pub fn process_equip_armor(
 pub fn process_unequip_armor(
     mut commands: Commands,
     mut events: EventReader<UnequipArmorIntent>,
-    mut consumables: Query<&mut ConsumableSlots>,
+    mut actors: Query<(&mut EquippedArmor, Option<&mut ConsumableSlots>, Option<&mut Inventory>)>,
+    definitions: Res<ItemDefinitions>,
 ) {
     for intent in events.read() {
-        // 1. Remove Armor component
-        commands.entity(intent.entity).remove::<Armor>();
-
-        // 2. Remove Attachment (визуал)
-        // NOTE: Attachment для armor может быть shared с другими items
-        // Поэтому удаляем только если attachment_type == Armor
-        // TODO: Implement proper multi-attachment tracking
-
-        // 3. Lock consumable slots (обратно к базовым 2)
-        if let Ok(mut slots) = consumables.get_mut(intent.entity) {
-            slots.unlock_slots(2);
-            log("🗑️ Armor unequipped - consumable slots locked to 2");
+        let Ok((mut armor, consumables, inventory)) = actors.get_mut(intent.entity) else {
+            continue;
+        };
+
+        // 1. Часть → Inventory (слот мог быть освобождён loadout'ом — тогда только пересчёт)
+        let removed = armor.unequip(intent.slot);
+        if removed.as_ref().is_some_and(|piece| piece.shield.is_some()) {
+            commands.entity(intent.entity).remove::<EnergyShield>();
+        }
+        if let (Some(removed), Some(mut inventory)) = (removed, inventory) {
+            inventory.add_item(removed.to_instance());
+        }
+
+        // 2. Бонус комплекта
+        armor.refresh_set_bonus(&definitions);
+
+        // 3. Consumable slots по оставшимся частям
+        if let Some(mut slots) = consumables {
+            let unlocked = 2 + armor.consumable_slot_bonus();
+            slots.unlock_slots(unlocked);
+            log(&format!("🗑️ {:?} unequipped - consumable slots locked to {}", intent.slot, unlocked));
         }
     }
 }
@@ -277,12 +330,38 @@ pub fn process_unequip_armor(
 // Consumable Use
 // ============================================================================
 
+/// Завершённый Channeling(Consumable) → UseConsumableIntent
+///
+/// Channelled использование (AI self-heal): эффект применяется только если
+/// channel не был прерван уроном (ChannelInterrupted → ничего не тратится).
+pub fn complete_consumable_channels(
+    mut completed_events: EventReader<crate::combat::ChannelCompleted>,
+    mut use_events: EventWriter<UseConsumableIntent>,
+) {
+    for completed in completed_events.read() {
+        let crate::combat::ChannelKind::Consumable { slot_index } = completed.kind else {
+            continue;
+        };
+
+        use_events.write(UseConsumableIntent {
+            entity: completed.entity,
+            slot_index,
+        });
+    }
+}
+
 /// Process use consumable intents
+#[allow(clippy::too_many_arguments)]
 pub fn process_use_consumable(
     mut events: EventReader<UseConsumableIntent>,
     mut consumables: Query<&mut ConsumableSlots>,
     mut health: Query<&mut crate::actor::Health>,
     mut stamina: Query<&mut crate::actor::Stamina>,
+    mut weapons: Query<&mut EquippedWeapons>,
+    positions: Query<&crate::StrategicPosition>,
+    mut smoke_events: EventWriter<crate::combat::SmokeDeployed>,
+    mut flash_events: EventWriter<crate::combat::FlashbangDetonated>,
+    mut emp_events: EventWriter<crate::combat::EmpDetonated>,
     definitions: Res<ItemDefinitions>,
 ) {
     for intent in events.read() {
@@ -329,6 +408,332 @@ pub fn process_use_consumable(
                 // TODO: Implement grenade spawn (Phase 5)
                 log(&format!("✅ Использован {} (grenade)", def.name));
             }
+            crate::item_system::ConsumableEffect::DeploySmoke { radius, duration } => {
+                // TODO: бросок (пока облако под ногами — как и SpawnProjectile, без траектории)
+                if let Ok(position) = positions.get(intent.entity) {
+                    smoke_events.write(crate::combat::SmokeDeployed {
+                        center: position.to_world_position(0.5),
+                        radius: *radius,
+                        duration: *duration,
+                    });
+                    log(&format!("✅ Использован {} (smoke)", def.name));
+                }
+            }
+            crate::item_system::ConsumableEffect::Flashbang { radius, max_duration } => {
+                if let Ok(position) = positions.get(intent.entity) {
+                    flash_events.write(crate::combat::FlashbangDetonated {
+                        thrower: intent.entity,
+                        position: position.to_world_position(0.5),
+                        radius: *radius,
+                        max_duration: *max_duration,
+                    });
+                    log(&format!("✅ Использован {} (flash)", def.name));
+                }
+            }
+            crate::item_system::ConsumableEffect::Emp { radius, duration } => {
+                if let Ok(position) = positions.get(intent.entity) {
+                    emp_events.write(crate::combat::EmpDetonated {
+                        source: intent.entity,
+                        position: position.to_world_position(0.5),
+                        radius: *radius,
+                        duration: *duration,
+                    });
+                    log(&format!("✅ Использован {} (EMP)", def.name));
+                }
+            }
+            crate::item_system::ConsumableEffect::RepairWeapon { amount } => {
+                let active = weapons
+                    .get_mut(intent.entity)
+                    .ok()
+                    .and_then(|mut weapons| {
+                        let weapon = weapons.get_active_weapon_mut()?;
+                        weapon.repair(*amount);
+                        Some(weapon.durability)
+                    });
+                match active {
+                    Some(durability) => {
+                        log(&format!("✅ Использован {} (прочность {:.0}%)", def.name, durability * 100.0))
+                    }
+                    None => log_error("⚠️ Нечего чинить - оружие не в руках"),
+                }
+            }
+        }
+    }
+}
+
+// ============================================================================
+// Throwables
+// ============================================================================
+
+/// ThrowIntent → одна штука метательного оружия из consumable слота → ThrowableLaunched
+///
+/// Слот — первый разблокированный с `ItemType::Throwable` (стак уменьшается, последняя штука освобождает слот).
+pub fn process_throw_intents(
+    mut events: EventReader<ThrowIntent>,
+    mut consumables: Query<&mut ConsumableSlots>,
+    mut launched_events: EventWriter<ThrowableLaunched>,
+    definitions: Res<ItemDefinitions>,
+) {
+    for intent in events.read() {
+        let Ok(mut slots) = consumables.get_mut(intent.thrower) else {
+            continue;
+        };
+
+        let throwable = (0..slots.unlocked_count).find_map(|index| {
+            let item = slots.get_slot(index)?;
+            let def = definitions.get(&item.definition_id)?;
+            let stats = def.throwable_stats.as_ref().filter(|_| def.item_type == ItemType::Throwable)?;
+            Some((index, stats.damage, stats.speed))
+        });
+        let Some((slot_index, damage, speed)) = throwable else {
+            log_error("⚠️ Нечего бросить - нет метательного оружия в слотах");
+            continue;
+        };
+
+        let Some(item) = take_one_from_slot(&mut slots, slot_index) else {
+            continue;
+        };
+
+        log(&format!("🗡️ Бросок {:?} (урон {})", item.definition_id, damage));
+        launched_events.write(ThrowableLaunched {
+            thrower: intent.thrower,
+            item,
+            damage,
+            speed,
+        });
+    }
+}
+
+/// Снять одну штуку со стака слота (последняя — слот освобождается)
+fn take_one_from_slot(slots: &mut ConsumableSlots, index: u8) -> Option<ItemInstance> {
+    let stack = slots.get_slot_mut(index)?;
+    if stack.stack_size > 1 {
+        stack.stack_size -= 1;
+        let mut single = stack.clone();
+        single.stack_size = 1;
+        return Some(single);
+    }
+    slots.take_slot(index)
+}
+
+/// ThrowableLanded → WorldItem (подбирается как обычный предмет)
+pub fn spawn_landed_throwables(
+    mut events: EventReader<ThrowableLanded>,
+    definitions: Res<ItemDefinitions>,
+    mut commands: Commands,
+) {
+    for landed in events.read() {
+        let prefab_path = definitions
+            .get(&landed.item.definition_id)
+            .and_then(|def| def.prefab_path.clone());
+
+        commands.spawn(crate::interaction::WorldItem::bundle(landed.item.clone(), landed.position, prefab_path));
+    }
+}
+
+// ============================================================================
+// Loadouts
+// ============================================================================
+
+/// Startup: пресеты ссылаются на существующие ItemId (иначе warning в лог)
+pub fn validate_loadout_book(book: Res<LoadoutBook>, definitions: Res<ItemDefinitions>) {
+    for unknown in book.unknown_items(&definitions) {
+        crate::logger::log_warning(&format!("🎽 Loadout references unknown item {}", unknown));
+    }
+}
+
+/// Process apply loadout intents (атомарно: план целиком или отказ)
+///
+/// Слоты освобождаются здесь (снятое → Inventory), экипировка — существующими intents
+/// (`process_equip_weapon` / `process_equip_armor` в этом же кадре).
+#[allow(clippy::too_many_arguments)]
+pub fn apply_loadouts(
+    mut commands: Commands,
+    mut events: EventReader<ApplyLoadoutIntent>,
+    mut actors: Query<(
+        &mut EquippedWeapons,
+        Option<&mut EquippedArmor>,
+        Option<&mut ConsumableSlots>,
+        Option<&mut Inventory>,
+    )>,
+    book: Res<LoadoutBook>,
+    mut equip_weapon_events: EventWriter<EquipWeaponIntent>,
+    mut equip_armor_events: EventWriter<EquipArmorIntent>,
+    mut unequip_armor_events: EventWriter<UnequipArmorIntent>,
+    mut applied_events: EventWriter<LoadoutApplied>,
+    mut rejected_events: EventWriter<LoadoutRejected>,
+) {
+    for intent in events.read() {
+        let Ok((mut weapons, mut armor, consumables, inventory)) = actors.get_mut(intent.entity) else {
+            log_error(&format!("Entity {:?} missing EquippedWeapons", intent.entity));
+            continue;
+        };
+
+        let Some(loadout) = book.get(&intent.loadout) else {
+            rejected_events.write(LoadoutRejected {
+                entity: intent.entity,
+                loadout: intent.loadout.clone(),
+                reason: LoadoutRejection::UnknownLoadout,
+            });
+            continue;
+        };
+
+        let plan = match loadout.plan(&weapons, armor.as_deref(), consumables.as_deref(), inventory.as_deref()) {
+            Ok(plan) => plan,
+            Err(missing) => {
+                log_error(&format!("Loadout {} rejected for {:?}: missing {:?}", intent.loadout, intent.entity, missing));
+                rejected_events.write(LoadoutRejected {
+                    entity: intent.entity,
+                    loadout: intent.loadout.clone(),
+                    reason: LoadoutRejection::MissingItems(missing),
+                });
+                continue;
+            }
+        };
+
+        // 1. Оружие: слот освобождён здесь (снятое уже в plan.inventory), новое — EquipWeaponIntent
+        for (slot, item) in plan.weapon_changes {
+            weapons.set_slot(slot, None);
+            // Пустой слот: derive_weapon_stats снимет WeaponStats, если он был активным
+            if let (Some(item), Some(weapon_slot)) = (item, WeaponSlot::from_index(slot)) {
+                equip_weapon_events.write(EquipWeaponIntent {
+                    entity: intent.entity,
+                    slot: weapon_slot,
+                    item,
+                });
+            }
+        }
+
+        // 2. Броня: слот освобождён здесь, новая часть — EquipArmorIntent, пустой слот — UnequipArmorIntent (пересчёт)
+        for (slot, piece) in plan.armor_changes {
+            if let Some(armor) = armor.as_deref_mut() {
+                armor.unequip(slot);
+            }
+            match piece {
+                Some(item) => {
+                    equip_armor_events.write(EquipArmorIntent { entity: intent.entity, item });
+                }
+                None => {
+                    unequip_armor_events.write(UnequipArmorIntent { entity: intent.entity, slot });
+                }
+            }
+        }
+
+        // 3. Consumables
+        match consumables {
+            Some(mut slots) => slots.slots = plan.consumables,
+            None => {
+                commands.entity(intent.entity).insert(ConsumableSlots {
+                    slots: plan.consumables,
+                    ..ConsumableSlots::default()
+                });
+            }
+        }
+
+        // 4. Inventory (снятое вернулось, взятое списано)
+        if let (Some(mut inventory), Some(items)) = (inventory, plan.inventory) {
+            inventory.items = items;
+        }
+
+        log(&format!("🎽 Loadout {} applied to {:?}", intent.loadout, intent.entity));
+        applied_events.write(LoadoutApplied {
+            entity: intent.entity,
+            loadout: intent.loadout.clone(),
+        });
+    }
+}
+
+/// Process save loadout intents (текущее снаряжение → LoadoutBook)
+pub fn save_loadouts(
+    mut events: EventReader<SaveLoadoutIntent>,
+    actors: Query<(&EquippedWeapons, Option<&EquippedArmor>, Option<&ConsumableSlots>)>,
+    mut book: ResMut<LoadoutBook>,
+) {
+    for intent in events.read() {
+        let Ok((weapons, armor, consumables)) = actors.get(intent.entity) else {
+            continue;
+        };
+
+        book.loadouts
+            .insert(intent.name.clone(), Loadout::capture(weapons, armor, consumables));
+        log(&format!("🎽 Loadout {} saved from {:?}", intent.name, intent.entity));
+    }
+}
+
+// ============================================================================
+// Inventory Stacks
+// ============================================================================
+
+/// Process split stack intents
+pub fn process_split_stack(mut events: EventReader<SplitStackIntent>, mut inventories: Query<&mut Inventory>) {
+    for intent in events.read() {
+        let Ok(mut inventory) = inventories.get_mut(intent.entity) else {
+            continue;
+        };
+
+        if !inventory.split_stack(intent.index, intent.count) {
+            log_error(&format!(
+                "Cannot split {} from slot {} ({:?})",
+                intent.count, intent.index, intent.entity
+            ));
+        }
+    }
+}
+
+/// Process merge stack intents
+pub fn process_merge_stack(
+    mut events: EventReader<MergeStackIntent>,
+    mut inventories: Query<&mut Inventory>,
+    definitions: Res<ItemDefinitions>,
+) {
+    for intent in events.read() {
+        let Ok(mut inventory) = inventories.get_mut(intent.entity) else {
+            continue;
+        };
+
+        if !inventory.merge_stacks(intent.from, intent.into, &definitions) {
+            log_error(&format!(
+                "Cannot merge slot {} into {} ({:?})",
+                intent.from, intent.into, intent.entity
+            ));
+        }
+    }
+}
+
+// ============================================================================
+// Encumbrance
+// ============================================================================
+
+/// System: изменился Inventory → пересчёт carry weight → вставить/обновить/снять Encumbered
+///
+/// Перегруз сразу сбрасывает спринт (дальше не даёт apply_sprint_intents).
+pub fn update_encumbrance(
+    mut commands: Commands,
+    query: Query<(Entity, &Inventory, Option<&Encumbered>), Changed<Inventory>>,
+    definitions: Res<ItemDefinitions>,
+) {
+    for (entity, inventory, current) in query.iter() {
+        let carry_weight = inventory.carry_weight(&definitions);
+
+        match (Encumbered::from_weight(carry_weight, inventory.max_weight), current) {
+            (Some(encumbered), current) => {
+                if current != Some(&encumbered) {
+                    commands
+                        .entity(entity)
+                        .insert(encumbered)
+                        .remove::<crate::movement::Sprinting>();
+                }
+                if current.is_none() {
+                    log(&format!(
+                        "🎒 {:?} encumbered: {:.1}/{:.1} kg (speed ×{:.2})",
+                        entity, carry_weight, inventory.max_weight, encumbered.speed_multiplier
+                    ));
+                }
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<Encumbered>();
+            }
+            (None, None) => {}
         }
     }
 }
@@ -0,0 +1,96 @@
+//! Tests for throwable systems (ThrowIntent → ThrowableLaunched, ThrowableLanded → WorldItem).
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use super::super::events::{ThrowIntent, ThrowableLanded, ThrowableLaunched};
+    use super::super::systems::{process_throw_intents, spawn_landed_throwables};
+    use crate::components::equipment::ConsumableSlots;
+    use crate::interaction::{Interactable, InteractionKind, Pickup, WorldItem};
+    use crate::item_system::{ItemId, ItemInstance};
+    use crate::{create_test_app, drain_events};
+
+    fn throw(world: &mut World, thrower: Entity) -> Vec<ThrowableLaunched> {
+        world.send_event(ThrowIntent { thrower });
+        world.run_system_once(process_throw_intents).unwrap();
+        drain_events(world)
+    }
+
+    #[test]
+    fn test_throw_takes_one_from_first_throwable_slot() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let mut slots = ConsumableSlots::empty();
+        slots.set_slot(0, Some(ItemInstance::new("health_kit")));
+        slots.set_slot(1, Some(ItemInstance::consumable_stack("throwing_knife", 3)));
+        let thrower = world.spawn(slots).id();
+
+        let launched = throw(world, thrower);
+
+        assert_eq!(launched.len(), 1);
+        assert_eq!(launched[0].thrower, thrower);
+        assert_eq!(launched[0].item.definition_id, ItemId::from("throwing_knife"));
+        assert_eq!(launched[0].item.stack_size, 1);
+        assert_eq!(launched[0].damage, 30);
+        assert_eq!(launched[0].speed, 18.0);
+
+        let slots = world.get::<ConsumableSlots>(thrower).unwrap();
+        assert_eq!(slots.get_slot(1).unwrap().stack_size, 2);
+        // Аптечка не тронута
+        assert!(slots.get_slot(0).is_some());
+    }
+
+    #[test]
+    fn test_last_throwable_frees_slot() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let mut slots = ConsumableSlots::empty();
+        slots.set_slot(0, Some(ItemInstance::new("throwing_spear")));
+        let thrower = world.spawn(slots).id();
+
+        assert_eq!(throw(world, thrower).len(), 1);
+        assert!(world.get::<ConsumableSlots>(thrower).unwrap().get_slot(0).is_none());
+
+        // Слот пуст → бросать нечего
+        assert!(throw(world, thrower).is_empty());
+    }
+
+    #[test]
+    fn test_no_throw_without_unlocked_throwable() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let mut slots = ConsumableSlots::empty();
+        slots.set_slot(0, Some(ItemInstance::new("health_kit")));
+        // Слот 3 заблокирован без брони (unlocked_count = 2)
+        slots.set_slot(3, Some(ItemInstance::consumable_stack("throwing_knife", 2)));
+        let thrower = world.spawn(slots).id();
+
+        assert!(throw(world, thrower).is_empty());
+
+        let slots = world.get::<ConsumableSlots>(thrower).unwrap();
+        assert_eq!(slots.get_slot(3).unwrap().stack_size, 2);
+        assert!(slots.get_slot(0).is_some());
+    }
+
+    #[test]
+    fn test_landed_throwable_becomes_world_item() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let position = Vec3::new(3.0, 0.0, -4.0);
+
+        world.send_event(ThrowableLanded {
+            item: ItemInstance::new("throwing_knife"),
+            position,
+        });
+        world.run_system_once(spawn_landed_throwables).unwrap();
+
+        let mut items = world.query_filtered::<(&Pickup, &Interactable), With<WorldItem>>();
+        let landed: Vec<_> = items.iter(world).collect();
+        assert_eq!(landed.len(), 1);
+        let (pickup, interactable) = landed[0];
+        assert_eq!(pickup.item.definition_id, ItemId::from("throwing_knife"));
+        assert_eq!(interactable.kind, InteractionKind::Pickup);
+        assert_eq!(interactable.position, position);
+    }
+}
@@ -0,0 +1,86 @@
+//! Faction AI components & resources (squads, orders, config).
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Принадлежность актора к отряду внутри фракции.
+///
+/// Без компонента актор считается членом отряда 0 своей фракции.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
+pub struct SquadMember {
+    pub squad_id: u32,
+}
+
+/// Ключ отряда: (faction_id, squad_id)
+pub type SquadKey = (u64, u32);
+
+/// Приказ отряду (squad-level, не per-actor).
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum SquadOrder {
+    /// Атаковать сектор (центр известных врагов)
+    AttackSector { center: Vec3, radius: f32 },
+    /// Держать позицию
+    Defend { position: Vec3, radius: f32 },
+    /// Собраться в точке (отступить и перегруппироваться)
+    Regroup { position: Vec3 },
+}
+
+impl SquadOrder {
+    /// Вид приказа без параметров (для hysteresis и логов)
+    pub fn kind(&self) -> SquadOrderKind {
+        match self {
+            Self::AttackSector { .. } => SquadOrderKind::Attack,
+            Self::Defend { .. } => SquadOrderKind::Defend,
+            Self::Regroup { .. } => SquadOrderKind::Regroup,
+        }
+    }
+}
+
+/// Вид приказа (utility scoring выбирает между ними).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum SquadOrderKind {
+    Attack,
+    Defend,
+    Regroup,
+}
+
+/// Текущие приказы отрядов (resource).
+///
+/// Читается per-actor логикой по желанию — FSM от приказов не зависит.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct SquadOrders {
+    pub orders: HashMap<SquadKey, SquadOrder>,
+    /// Tick следующего пересчёта (evaluation_interval)
+    pub next_evaluation_tick: u64,
+}
+
+impl SquadOrders {
+    pub fn get(&self, faction_id: u64, squad_id: u32) -> Option<&SquadOrder> {
+        self.orders.get(&(faction_id, squad_id))
+    }
+}
+
+/// Параметры faction AI (resource).
+#[derive(Resource, Debug, Clone)]
+pub struct FactionAIConfig {
+    /// Как часто пересчитывать приказы (секунды)
+    pub evaluation_interval: f32,
+    /// Новый приказ должен набрать на столько больше текущего (анти-дребезг)
+    pub switch_margin: f32,
+    /// Радиус сектора атаки / обороны (метры)
+    pub sector_radius: f32,
+    /// Сколько замеченных врагов = максимальное давление на территорию
+    pub pressure_saturation: u32,
+}
+
+impl Default for FactionAIConfig {
+    fn default() -> Self {
+        Self {
+            evaluation_interval: 2.0,
+            switch_margin: 0.1,
+            sector_radius: 10.0,
+            pressure_saturation: 4,
+        }
+    }
+}
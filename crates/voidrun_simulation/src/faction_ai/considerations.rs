@@ -0,0 +1,99 @@
+//! Utility considerations (scored 0..1) для приказов отряда.
+//!
+//! Чистые функции от `SquadSnapshot` — тестируются без World.
+
+use bevy::prelude::*;
+use super::components::SquadOrderKind;
+
+/// Сводка по отряду на момент оценки.
+#[derive(Debug, Clone, Default)]
+pub struct SquadSnapshot {
+    /// Всего членов (включая мёртвых, ещё не despawned)
+    pub members: u32,
+    /// Живых членов
+    pub alive: u32,
+    /// Средняя доля HP живых (0..1)
+    pub average_health: f32,
+    /// Уникальных врагов в SpottedEnemies отряда
+    pub spotted_enemies: u32,
+    /// Центр живых членов (world)
+    pub centroid: Vec3,
+    /// Центр замеченных врагов (world)
+    pub enemy_centroid: Option<Vec3>,
+}
+
+/// Давление на территорию: сколько врагов видит отряд (насыщение на `saturation`)
+pub fn territory_pressure(snapshot: &SquadSnapshot, saturation: u32) -> f32 {
+    if saturation == 0 {
+        return 0.0;
+    }
+    (snapshot.spotted_enemies as f32 / saturation as f32).min(1.0)
+}
+
+/// Потери: доля мёртвых
+pub fn casualties(snapshot: &SquadSnapshot) -> f32 {
+    if snapshot.members == 0 {
+        return 0.0;
+    }
+    1.0 - snapshot.alive as f32 / snapshot.members as f32
+}
+
+/// Ресурсы: средний HP живых (0 если все мертвы)
+pub fn resources(snapshot: &SquadSnapshot) -> f32 {
+    if snapshot.alive == 0 {
+        return 0.0;
+    }
+    snapshot.average_health.clamp(0.0, 1.0)
+}
+
+/// Utility score для каждого вида приказа.
+///
+/// - Attack: есть кого атаковать, отряд цел и здоров, давление умеренное
+/// - Defend: давление высокое, но ресурсов хватает держаться
+/// - Regroup: потери и низкие ресурсы
+pub fn score_orders(snapshot: &SquadSnapshot, pressure_saturation: u32) -> [(SquadOrderKind, f32); 3] {
+    let pressure = territory_pressure(snapshot, pressure_saturation);
+    let losses = casualties(snapshot);
+    let supplies = resources(snapshot);
+    let has_targets = if snapshot.enemy_centroid.is_some() { 1.0 } else { 0.0 };
+
+    let attack = has_targets * (1.0 - losses) * supplies * (1.0 - 0.5 * pressure);
+    let defend = pressure * (0.5 + 0.5 * supplies) * (1.0 - losses * 0.5);
+    let regroup = (losses + (1.0 - supplies)) * 0.5;
+
+    [
+        (SquadOrderKind::Attack, attack),
+        (SquadOrderKind::Defend, defend),
+        (SquadOrderKind::Regroup, regroup),
+    ]
+}
+
+/// Лучший приказ с учётом hysteresis: текущий сохраняется, пока новый не лучше на `switch_margin`.
+pub fn choose_order(
+    scores: &[(SquadOrderKind, f32)],
+    current: Option<SquadOrderKind>,
+    switch_margin: f32,
+) -> SquadOrderKind {
+    let (best_kind, best_score) = scores
+        .iter()
+        .copied()
+        .fold((SquadOrderKind::Defend, f32::MIN), |best, candidate| {
+            if candidate.1 > best.1 { candidate } else { best }
+        });
+
+    let Some(current) = current else {
+        return best_kind;
+    };
+
+    let current_score = scores
+        .iter()
+        .find(|(kind, _)| *kind == current)
+        .map(|(_, score)| *score)
+        .unwrap_or(f32::MIN);
+
+    if best_score > current_score + switch_margin {
+        best_kind
+    } else {
+        current
+    }
+}
@@ -0,0 +1,50 @@
+//! Tests for faction AI utility considerations.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::SquadOrderKind;
+    use super::super::considerations::*;
+
+    fn snapshot(alive: u32, members: u32, health: f32, enemies: u32) -> SquadSnapshot {
+        SquadSnapshot {
+            members,
+            alive,
+            average_health: health,
+            spotted_enemies: enemies,
+            centroid: Vec3::ZERO,
+            enemy_centroid: (enemies > 0).then_some(Vec3::new(10.0, 0.0, 0.0)),
+        }
+    }
+
+    fn best(snapshot: &SquadSnapshot) -> SquadOrderKind {
+        choose_order(&score_orders(snapshot, 4), None, 0.0)
+    }
+
+    #[test]
+    fn test_healthy_squad_attacks_visible_enemy() {
+        assert_eq!(best(&snapshot(4, 4, 1.0, 1)), SquadOrderKind::Attack);
+    }
+
+    #[test]
+    fn test_outnumbered_squad_defends() {
+        assert_eq!(best(&snapshot(4, 4, 0.8, 6)), SquadOrderKind::Defend);
+    }
+
+    #[test]
+    fn test_battered_squad_regroups() {
+        assert_eq!(best(&snapshot(1, 4, 0.3, 1)), SquadOrderKind::Regroup);
+    }
+
+    #[test]
+    fn test_hysteresis_keeps_current_order() {
+        let scores = [
+            (SquadOrderKind::Attack, 0.55),
+            (SquadOrderKind::Defend, 0.5),
+            (SquadOrderKind::Regroup, 0.1),
+        ];
+
+        assert_eq!(choose_order(&scores, Some(SquadOrderKind::Defend), 0.1), SquadOrderKind::Defend);
+        assert_eq!(choose_order(&scores, Some(SquadOrderKind::Regroup), 0.1), SquadOrderKind::Attack);
+    }
+}
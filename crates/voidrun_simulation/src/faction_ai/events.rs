@@ -0,0 +1,12 @@
+//! Faction AI events.
+
+use bevy::prelude::*;
+use super::components::SquadOrder;
+
+/// Отряду выдан новый приказ (смена вида приказа, не каждый пересчёт).
+#[derive(Event, Debug, Clone)]
+pub struct SquadOrderIssued {
+    pub faction_id: u64,
+    pub squad_id: u32,
+    pub order: SquadOrder,
+}
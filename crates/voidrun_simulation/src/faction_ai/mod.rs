@@ -0,0 +1,56 @@
+//! Faction AI module — utility AI для стратегических решений фракций (Фаза 4)
+//!
+//! # Architecture
+//!
+//! Отдельно от per-actor FSM (`ai`): оценивает отряды целиком и выдаёт
+//! squad-level приказы (атаковать сектор, держать позицию, перегруппироваться).
+//!
+//! **Flow:**
+//! - Акторы группируются по (faction_id, `SquadMember::squad_id`)
+//! - Considerations (0..1): territory pressure, casualties, resources
+//! - Utility score на каждый вид приказа → выбор с hysteresis
+//! - Результат: `SquadOrders` resource + `SquadOrderIssued` event
+//!
+//! Per-actor логика читает приказы по желанию — FSM от faction AI не зависит.
+//!
+//! Supply drop (`SupplyDropLanded`): ближайший отряд каждой фракции получает маршрут
+//! к контейнеру (`SupplyDropContester`) и вскрывает его, как только замок откроется.
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod considerations;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod considerations_tests;
+
+// Re-exports
+pub use components::*;
+pub use considerations::{SquadSnapshot, score_orders, choose_order};
+pub use events::*;
+pub use systems::*;
+
+/// Faction AI Plugin
+///
+/// Регистрирует faction AI в FixedUpdate (после per-actor AI, пересчёт раз в `evaluation_interval`).
+pub struct FactionAIPlugin;
+
+impl Plugin for FactionAIPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SquadOrderIssued>()
+            .init_resource::<FactionAIConfig>()
+            .init_resource::<SquadOrders>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    evaluate_squad_orders,          // 1. Пересчёт приказов отрядов
+                    route_squads_to_supply_drops,   // 2. SupplyDropLanded → отряды к контейнеру
+                    release_supply_drop_contesters, // 3. Вскрытие у контейнера / маршрут назад
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,229 @@
+//! Faction AI systems (squad order evaluation, отряды к supply drop).
+
+use bevy::prelude::*;
+use std::collections::{BTreeMap, HashSet};
+use crate::ai::{AIState, PatrolMode, PatrolRoute, SpottedEnemies};
+use crate::components::{Actor, Health};
+use crate::formation::{formation_offset, FormationShape};
+use crate::horde::HORDE_FACTION_ID;
+use crate::player::Player;
+use crate::world_events::{
+    OpenSupplyDropIntent, SupplyDrop, SupplyDropContester, SupplyDropLanded, WorldEventConfig,
+};
+use crate::{SimulationTick, StrategicPosition};
+use super::components::{FactionAIConfig, SquadKey, SquadMember, SquadOrder, SquadOrderKind, SquadOrders};
+use super::considerations::{choose_order, score_orders, SquadSnapshot};
+use super::events::SquadOrderIssued;
+
+/// Накопитель сводки отряда (один проход по акторам)
+#[derive(Default)]
+struct SquadAccumulator {
+    members: u32,
+    alive: u32,
+    health_sum: f32,
+    position_sum: Vec3,
+    enemies: HashSet<Entity>,
+}
+
+/// System: пересчитать приказы отрядов (раз в `evaluation_interval`)
+///
+/// 1. Группируем акторов по (faction_id, squad_id) → SquadSnapshot
+/// 2. Utility scoring (territory pressure, casualties, resources)
+/// 3. Выбор с hysteresis → SquadOrders; смена вида приказа → SquadOrderIssued
+///
+/// Отряды без живых членов удаляются из SquadOrders.
+pub fn evaluate_squad_orders(
+    actors: Query<(&Actor, &Health, &StrategicPosition, Option<&SpottedEnemies>, Option<&SquadMember>)>,
+    positions: Query<&StrategicPosition>,
+    config: Res<FactionAIConfig>,
+    tick: Res<SimulationTick>,
+    mut orders: ResMut<SquadOrders>,
+    mut issued_events: EventWriter<SquadOrderIssued>,
+) {
+    if tick.get() < orders.next_evaluation_tick {
+        return;
+    }
+    orders.next_evaluation_tick = tick.after_secs(config.evaluation_interval);
+
+    // BTreeMap — детерминированный порядок отрядов
+    let mut squads: BTreeMap<SquadKey, SquadAccumulator> = BTreeMap::new();
+
+    for (actor, health, position, spotted, member) in actors.iter() {
+        let key = (actor.faction_id, member.map(|m| m.squad_id).unwrap_or(0));
+        let squad = squads.entry(key).or_default();
+
+        squad.members += 1;
+        if !health.is_alive() {
+            continue;
+        }
+
+        squad.alive += 1;
+        squad.health_sum += health.current as f32 / health.max.max(1) as f32;
+        squad.position_sum += position.to_world_position(0.5);
+        if let Some(spotted) = spotted {
+            squad.enemies.extend(spotted.enemies.iter().copied());
+        }
+    }
+
+    orders.orders.retain(|key, _| squads.get(key).is_some_and(|squad| squad.alive > 0));
+
+    for (key, squad) in squads {
+        if squad.alive == 0 {
+            continue;
+        }
+
+        let enemy_positions: Vec<Vec3> = squad
+            .enemies
+            .iter()
+            .filter_map(|enemy| positions.get(*enemy).ok())
+            .map(|pos| pos.to_world_position(0.5))
+            .collect();
+
+        let snapshot = SquadSnapshot {
+            members: squad.members,
+            alive: squad.alive,
+            average_health: squad.health_sum / squad.alive as f32,
+            spotted_enemies: squad.enemies.len() as u32,
+            centroid: squad.position_sum / squad.alive as f32,
+            enemy_centroid: (!enemy_positions.is_empty())
+                .then(|| enemy_positions.iter().sum::<Vec3>() / enemy_positions.len() as f32),
+        };
+
+        let current_kind = orders.orders.get(&key).map(|order| order.kind());
+        let scores = score_orders(&snapshot, config.pressure_saturation);
+        let kind = choose_order(&scores, current_kind, config.switch_margin);
+        let order = build_order(kind, &snapshot, config.sector_radius);
+
+        if current_kind != Some(kind) {
+            crate::logger::log(&format!(
+                "🎖️ Faction {} squad {}: {:?} → {:?} (scores: {:?})",
+                key.0, key.1, current_kind, kind, scores
+            ));
+            issued_events.write(SquadOrderIssued {
+                faction_id: key.0,
+                squad_id: key.1,
+                order,
+            });
+        }
+
+        orders.orders.insert(key, order);
+    }
+}
+
+/// Параметры приказа из сводки отряда
+fn build_order(kind: SquadOrderKind, snapshot: &SquadSnapshot, sector_radius: f32) -> SquadOrder {
+    match kind {
+        SquadOrderKind::Attack => SquadOrder::AttackSector {
+            center: snapshot.enemy_centroid.unwrap_or(snapshot.centroid),
+            radius: sector_radius,
+        },
+        SquadOrderKind::Defend => SquadOrder::Defend {
+            position: snapshot.centroid,
+            radius: sector_radius,
+        },
+        SquadOrderKind::Regroup => {
+            // Точка сбора — от врагов, на половину радиуса сектора
+            let away = snapshot
+                .enemy_centroid
+                .map(|enemy| (snapshot.centroid - enemy).normalize_or_zero())
+                .unwrap_or(Vec3::ZERO);
+            SquadOrder::Regroup {
+                position: snapshot.centroid + away * sector_radius * 0.5,
+            }
+        }
+    }
+}
+
+/// System: SupplyDropLanded → ближайший отряд каждой фракции идёт оспорить контейнер
+///
+/// - Отряды: живые AI-акторы по (faction_id, squad_id); орда не участвует
+/// - От каждой фракции — один отряд с ближайшим центром в `contest_range`
+/// - Члены отряда: PatrolRoute → клетка клина у контейнера (прежний маршрут — в `SupplyDropContester`)
+pub fn route_squads_to_supply_drops(
+    mut landed_events: EventReader<SupplyDropLanded>,
+    actors: Query<
+        (Entity, &Actor, &Health, &StrategicPosition, Option<&SquadMember>, Option<&PatrolRoute>),
+        (With<AIState>, Without<Player>, Without<SupplyDropContester>),
+    >,
+    config: Res<WorldEventConfig>,
+    mut commands: Commands,
+) {
+    for event in landed_events.read() {
+        // BTreeMap — детерминированный выбор при равных дистанциях
+        let mut squads: BTreeMap<SquadKey, (Vec<Entity>, Vec3)> = BTreeMap::new();
+        for (entity, actor, health, position, member, _) in actors.iter() {
+            if !health.is_alive() || actor.faction_id == HORDE_FACTION_ID {
+                continue;
+            }
+            let key = (actor.faction_id, member.map(|m| m.squad_id).unwrap_or(0));
+            let squad = squads.entry(key).or_default();
+            squad.0.push(entity);
+            squad.1 += position.to_world_position(0.0);
+        }
+
+        let mut chosen: BTreeMap<u64, (u32, f32)> = BTreeMap::new();
+        for (&(faction_id, squad_id), (members, position_sum)) in squads.iter() {
+            let centroid = *position_sum / members.len() as f32;
+            let distance = centroid.distance(event.position);
+            if distance > config.contest_range {
+                continue;
+            }
+            if chosen.get(&faction_id).is_none_or(|&(_, best)| distance < best) {
+                chosen.insert(faction_id, (squad_id, distance));
+            }
+        }
+
+        for (faction_id, (squad_id, distance)) in chosen {
+            let Some((members, position_sum)) = squads.get(&(faction_id, squad_id)) else {
+                continue;
+            };
+
+            // Клин от центра отряда к контейнеру — не толпятся в одной точке
+            let centroid = *position_sum / members.len() as f32;
+            for (index, &member) in members.iter().enumerate() {
+                let previous_route = actors.get(member).ok().and_then(|(.., route)| route.cloned());
+                let slot = event.position
+                    + formation_offset(FormationShape::Wedge, index, members.len(), event.position - centroid);
+                commands.entity(member).insert((
+                    SupplyDropContester {
+                        drop: event.drop,
+                        previous_route,
+                    },
+                    PatrolRoute::new(vec![slot], PatrolMode::Loop),
+                ));
+            }
+
+            crate::logger::log(&format!(
+                "🎖️ Faction {} squad {} ({} members, {:.0}m) → contest supply drop {:?}",
+                faction_id,
+                squad_id,
+                members.len(),
+                distance,
+                event.drop
+            ));
+        }
+    }
+}
+
+/// System: оспаривающие у открытого контейнера вскрывают его; контейнера нет → маршрут назад
+pub fn release_supply_drop_contesters(
+    contesters: Query<(Entity, &SupplyDropContester, &StrategicPosition, &Health)>,
+    drops: Query<&SupplyDrop>,
+    mut open_events: EventWriter<OpenSupplyDropIntent>,
+    mut commands: Commands,
+) {
+    for (entity, contester, position, health) in contesters.iter() {
+        let Ok(drop) = drops.get(contester.drop) else {
+            let mut entity_commands = commands.entity(entity);
+            entity_commands.remove::<(SupplyDropContester, PatrolRoute)>();
+            if let Some(route) = contester.previous_route.clone() {
+                entity_commands.insert(route);
+            }
+            continue;
+        };
+
+        if health.is_alive() && drop.can_be_opened_from(position.to_world_position(0.0)) {
+            open_events.write(OpenSupplyDropIntent { actor: entity });
+        }
+    }
+}
@@ -0,0 +1,143 @@
+//! Forensics components (история урона, посмертные записи, таймлайн матча).
+
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::fmt::Write;
+
+use crate::ai::{AIState, AIStateTransition};
+use crate::combat::{AppliedDamage, DamageSource};
+use crate::StableId;
+
+/// Одно попадание по entity
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct DamageRecord {
+    pub tick: u64,
+    pub attacker: Entity,
+    pub damage: u32,
+    pub source: DamageSource,
+    pub applied: AppliedDamage,
+    /// HP цели после удара
+    pub health_after: u32,
+}
+
+/// Последние попадания по актору (кольцевой буфер, старые вытесняются)
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct DamageHistory {
+    pub records: VecDeque<DamageRecord>,
+}
+
+impl DamageHistory {
+    /// Сколько попаданий хранится
+    pub const CAPACITY: usize = 5;
+
+    pub fn push(&mut self, record: DamageRecord) {
+        if self.records.len() == Self::CAPACITY {
+            self.records.pop_front();
+        }
+        self.records.push_back(record);
+    }
+
+    /// Уникальные атакующие (в порядке последнего удара, свежие первыми)
+    pub fn attackers(&self) -> Vec<Entity> {
+        let mut attackers = Vec::new();
+        for record in self.records.iter().rev() {
+            if !attackers.contains(&record.attacker) {
+                attackers.push(record.attacker);
+            }
+        }
+        attackers
+    }
+}
+
+/// Состояние атакующего в момент смерти цели
+#[derive(Debug, Clone, PartialEq)]
+pub struct AttackerSnapshot {
+    pub entity: Entity,
+    pub stable_id: Option<StableId>,
+    /// None — не AI (игрок / BT) или уже despawned
+    pub ai_state: Option<AIState>,
+    /// None — атакующего уже нет
+    pub health: Option<u32>,
+    pub position: Option<Vec3>,
+}
+
+/// Посмертная запись (снимок на tick смерти)
+#[derive(Debug, Clone, PartialEq)]
+pub struct DeathRecord {
+    pub entity: Entity,
+    pub stable_id: Option<StableId>,
+    pub tick: u64,
+    pub position: Vec3,
+    pub killer: Option<Entity>,
+    /// AI state погибшего (что он делал)
+    pub ai_state: Option<AIState>,
+    /// Последние переходы FSM погибшего (AIStateHistory), старые первыми
+    pub ai_history: Vec<AIStateTransition>,
+    /// Последние попадания, старые первыми
+    pub last_damage: Vec<DamageRecord>,
+    pub attackers: Vec<AttackerSnapshot>,
+}
+
+impl DeathRecord {
+    /// Многострочная сводка (лог / debug overlay)
+    pub fn summary(&self) -> String {
+        let mut text = format!(
+            "☠️ {:?} died at tick {} pos ({:.1}, {:.1}, {:.1}) killer {:?} state {:?}",
+            self.entity, self.tick, self.position.x, self.position.y, self.position.z, self.killer, self.ai_state
+        );
+
+        for transition in &self.ai_history {
+            let _ = write!(
+                text,
+                "\n  t{} fsm {:?} → {:?} ({:?})",
+                transition.tick, transition.from, transition.to, transition.cause
+            );
+        }
+        for record in &self.last_damage {
+            let _ = write!(
+                text,
+                "\n  t{} {:?} → {} {:?} ({:?}) hp {}",
+                record.tick, record.attacker, record.damage, record.source, record.applied, record.health_after
+            );
+        }
+        for attacker in &self.attackers {
+            let _ = write!(
+                text,
+                "\n  attacker {:?} hp {:?} state {:?}",
+                attacker.entity, attacker.health, attacker.ai_state
+            );
+        }
+        text
+    }
+}
+
+/// Таймлайн матча: посмертные записи (resource)
+///
+/// Ищется по Entity (пока жив труп) или StableId (после despawn / между сессиями).
+#[derive(Resource, Debug, Default)]
+pub struct MatchTimeline {
+    pub deaths: VecDeque<DeathRecord>,
+}
+
+impl MatchTimeline {
+    /// Сколько смертей держим (старые вытесняются)
+    pub const MAX_DEATHS: usize = 128;
+
+    pub fn record_death(&mut self, record: DeathRecord) {
+        if self.deaths.len() == Self::MAX_DEATHS {
+            self.deaths.pop_front();
+        }
+        self.deaths.push_back(record);
+    }
+
+    /// Последняя смерть entity
+    pub fn death_of(&self, entity: Entity) -> Option<&DeathRecord> {
+        self.deaths.iter().rev().find(|record| record.entity == entity)
+    }
+
+    /// Смерть по StableId (Entity мог быть переиспользован)
+    pub fn death_of_stable(&self, id: StableId) -> Option<&DeathRecord> {
+        self.deaths.iter().rev().find(|record| record.stable_id == Some(id))
+    }
+}
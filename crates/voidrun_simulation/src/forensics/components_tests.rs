@@ -0,0 +1,78 @@
+//! Tests for forensics components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::components::*;
+    use crate::combat::{AppliedDamage, DamageSource};
+    use crate::StableId;
+    use bevy::prelude::{Entity, Vec3};
+
+    fn hit(tick: u64, attacker: u32) -> DamageRecord {
+        DamageRecord {
+            tick,
+            attacker: Entity::from_raw(attacker),
+            damage: 10,
+            source: DamageSource::Ranged,
+            applied: AppliedDamage::Direct,
+            health_after: 50,
+        }
+    }
+
+    fn death(entity: u32, stable_id: Option<StableId>) -> DeathRecord {
+        DeathRecord {
+            entity: Entity::from_raw(entity),
+            stable_id,
+            tick: 100,
+            position: Vec3::ZERO,
+            killer: None,
+            ai_state: None,
+            ai_history: Vec::new(),
+            last_damage: Vec::new(),
+            attackers: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_history_keeps_last_five() {
+        let mut history = DamageHistory::default();
+        for tick in 0..7 {
+            history.push(hit(tick, 1));
+        }
+
+        assert_eq!(history.records.len(), DamageHistory::CAPACITY);
+        assert_eq!(history.records.front().unwrap().tick, 2);
+        assert_eq!(history.records.back().unwrap().tick, 6);
+    }
+
+    #[test]
+    fn test_attackers_unique_most_recent_first() {
+        let mut history = DamageHistory::default();
+        history.push(hit(1, 1));
+        history.push(hit(2, 2));
+        history.push(hit(3, 1));
+
+        assert_eq!(history.attackers(), vec![Entity::from_raw(1), Entity::from_raw(2)]);
+    }
+
+    #[test]
+    fn test_timeline_lookup_by_entity_and_stable_id() {
+        let mut timeline = MatchTimeline::default();
+        timeline.record_death(death(1, Some(StableId(7))));
+        timeline.record_death(death(2, None));
+
+        assert_eq!(timeline.death_of(Entity::from_raw(2)).unwrap().stable_id, None);
+        assert_eq!(timeline.death_of_stable(StableId(7)).unwrap().entity, Entity::from_raw(1));
+        assert!(timeline.death_of(Entity::from_raw(3)).is_none());
+    }
+
+    #[test]
+    fn test_timeline_capped() {
+        let mut timeline = MatchTimeline::default();
+        for entity in 0..(MatchTimeline::MAX_DEATHS as u32 + 3) {
+            timeline.record_death(death(entity, None));
+        }
+
+        assert_eq!(timeline.deaths.len(), MatchTimeline::MAX_DEATHS);
+        assert!(timeline.death_of(Entity::from_raw(0)).is_none());
+    }
+}
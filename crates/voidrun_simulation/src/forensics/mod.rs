@@ -0,0 +1,49 @@
+//! Forensics module — посмертные записи для диагностики «почему NPC внезапно умер / ничего не делал»
+//!
+//! # Architecture
+//!
+//! Каждый актор держит `DamageHistory` (последние 5 попаданий). На `EntityDied` снимается
+//! `DeathRecord`: история урона, позиция, AI state и последние переходы FSM (`AIStateHistory`)
+//! погибшего, состояние и позиции атакующих — и кладётся в `MatchTimeline`
+//! (ограниченный буфер смертей матча).
+//!
+//! **Flow:**
+//! - `Added<Actor>` → `DamageHistory`
+//! - `DamageDealt` → запись в `DamageHistory` цели
+//! - `EntityDied` → `DeathRecord` → `MatchTimeline` (+ сводка в лог)
+//!
+//! Запись ищется `MatchTimeline::death_of(entity)` / `death_of_stable(id)`.
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use systems::*;
+
+/// Forensics Plugin
+///
+/// Регистрирует историю урона и посмертные записи в FixedUpdate.
+pub struct ForensicsPlugin;
+
+impl Plugin for ForensicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<DamageHistory>()
+            .init_resource::<MatchTimeline>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    attach_damage_history, // 1. Новые акторы → DamageHistory
+                    record_damage_history, // 2. DamageDealt → история цели
+                    capture_death_records, // 3. EntityDied → DeathRecord → MatchTimeline
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,95 @@
+//! Forensics systems (история урона → посмертная запись в MatchTimeline).
+
+use bevy::prelude::*;
+use crate::ai::{AIState, AIStateHistory};
+use crate::combat::{DamageDealt, EntityDied};
+use crate::components::{Actor, Health};
+use crate::{SimulationTick, StableId, StrategicPosition};
+use super::components::{AttackerSnapshot, DamageHistory, DamageRecord, DeathRecord, MatchTimeline};
+
+/// System: новые акторы → DamageHistory
+pub fn attach_damage_history(
+    actors: Query<Entity, (Added<Actor>, Without<DamageHistory>)>,
+    mut commands: Commands,
+) {
+    for entity in actors.iter() {
+        commands.entity(entity).insert(DamageHistory::default());
+    }
+}
+
+/// System: DamageDealt → DamageHistory цели (последние `DamageHistory::CAPACITY`)
+pub fn record_damage_history(
+    mut damage_events: EventReader<DamageDealt>,
+    mut targets: Query<(&mut DamageHistory, &Health)>,
+    tick: Res<SimulationTick>,
+) {
+    for dealt in damage_events.read() {
+        let Ok((mut history, health)) = targets.get_mut(dealt.target) else {
+            continue;
+        };
+
+        history.push(DamageRecord {
+            tick: tick.get(),
+            attacker: dealt.attacker,
+            damage: dealt.damage,
+            source: dealt.source,
+            applied: dealt.applied_damage,
+            health_after: health.current,
+        });
+    }
+}
+
+/// System: EntityDied → DeathRecord (история урона + переходы FSM + позиция + состояние атакующих) → MatchTimeline
+pub fn capture_death_records(
+    mut death_events: EventReader<EntityDied>,
+    actors: Query<(
+        Option<&DamageHistory>,
+        Option<&StrategicPosition>,
+        Option<&AIState>,
+        Option<&Health>,
+        Option<&StableId>,
+        Option<&AIStateHistory>,
+    )>,
+    tick: Res<SimulationTick>,
+    mut timeline: ResMut<MatchTimeline>,
+) {
+    for died in death_events.read() {
+        let Ok((history, position, ai_state, _, stable_id, ai_history)) = actors.get(died.entity) else {
+            continue;
+        };
+
+        let mut attacker_entities = history.map(DamageHistory::attackers).unwrap_or_default();
+        if let Some(killer) = died.killer.filter(|killer| !attacker_entities.contains(killer)) {
+            attacker_entities.insert(0, killer);
+        }
+
+        let attackers = attacker_entities
+            .into_iter()
+            .map(|entity| match actors.get(entity) {
+                Ok((_, position, ai_state, health, stable_id, _)) => AttackerSnapshot {
+                    entity,
+                    stable_id: stable_id.copied(),
+                    ai_state: ai_state.cloned(),
+                    health: health.map(|health| health.current),
+                    position: position.map(|position| position.to_world_position(0.0)),
+                },
+                Err(_) => AttackerSnapshot { entity, stable_id: None, ai_state: None, health: None, position: None },
+            })
+            .collect();
+
+        let record = DeathRecord {
+            entity: died.entity,
+            stable_id: stable_id.copied(),
+            tick: tick.get(),
+            position: position.map_or(Vec3::ZERO, |position| position.to_world_position(0.0)),
+            killer: died.killer,
+            ai_state: ai_state.cloned(),
+            ai_history: ai_history.map(|history| history.transitions.iter().cloned().collect()).unwrap_or_default(),
+            last_damage: history.map(|history| history.records.iter().copied().collect()).unwrap_or_default(),
+            attackers,
+        };
+
+        crate::logger::log(&record.summary());
+        timeline.record_death(record);
+    }
+}
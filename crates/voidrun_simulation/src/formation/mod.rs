@@ -0,0 +1,21 @@
+//! Formation module — строй для групповых приказов движения
+//!
+//! # Architecture
+//!
+//! Чистые функции (без систем и plugin): точка приказа + направление → смещение клетки юнита.
+//! Иначе все юниты идут в одну точку и толкаются на navmesh.
+//!
+//! **Кто использует:**
+//! - RTS приказ MoveTo (`companions::apply_unit_orders`) → HoldPosition каждому в своей клетке
+//! - Отряд к supply drop (`faction_ai::route_squads_to_supply_drops`) → PatrolRoute в клетку
+//!
+//! Строи: Line (шеренга), Wedge (клин), Column (колонна).
+
+pub mod shapes;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod shapes_tests;
+
+// Re-exports
+pub use shapes::*;
@@ -0,0 +1,48 @@
+//! Formation shapes (клетки строя относительно точки приказа).
+
+use bevy::prelude::*;
+
+/// Шаг между клетками строя (метры)
+pub const FORMATION_SPACING: f32 = 1.5;
+
+/// Форма строя.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum FormationShape {
+    /// Шеренга поперёк направления движения (центр — точка приказа)
+    Line,
+    /// Клин: первый — на точке, остальные уступами назад влево / вправо
+    #[default]
+    Wedge,
+    /// Колонна: первый — на точке, остальные друг за другом
+    Column,
+}
+
+impl FormationShape {
+    /// Клетка `index` из `count` в локальных координатах строя: (вправо, назад), метры
+    pub fn local_slot(self, index: usize, count: usize) -> Vec2 {
+        match self {
+            Self::Line => {
+                let center = count.saturating_sub(1) as f32 / 2.0;
+                Vec2::new((index as f32 - center) * FORMATION_SPACING, 0.0)
+            }
+            Self::Wedge => {
+                let rank = index.div_ceil(2) as f32;
+                let side = if index % 2 == 1 { -1.0 } else { 1.0 };
+                Vec2::new(side * rank * FORMATION_SPACING, rank * FORMATION_SPACING)
+            }
+            Self::Column => Vec2::new(0.0, index as f32 * FORMATION_SPACING),
+        }
+    }
+}
+
+/// Смещение юнита `index` из `count` от точки приказа (world, XZ)
+///
+/// `facing` — направление движения группы (обычно центр группы → точка приказа);
+/// вырожденное (ноль / вертикаль) → -Z.
+pub fn formation_offset(shape: FormationShape, index: usize, count: usize, facing: Vec3) -> Vec3 {
+    let forward = Vec3::new(facing.x, 0.0, facing.z).try_normalize().unwrap_or(Vec3::NEG_Z);
+    let right = forward.cross(Vec3::Y);
+    let slot = shape.local_slot(index, count);
+
+    right * slot.x - forward * slot.y
+}
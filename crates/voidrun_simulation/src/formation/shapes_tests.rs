@@ -0,0 +1,61 @@
+//! Tests for formation shapes.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::shapes::*;
+
+    fn offsets(shape: FormationShape, count: usize, facing: Vec3) -> Vec<Vec3> {
+        (0..count).map(|index| formation_offset(shape, index, count, facing)).collect()
+    }
+
+    fn assert_spread(offsets: &[Vec3]) {
+        for (i, a) in offsets.iter().enumerate() {
+            for b in offsets.iter().skip(i + 1) {
+                assert!(a.distance(*b) >= FORMATION_SPACING - 1e-4, "{a:?} / {b:?} overlap");
+            }
+        }
+    }
+
+    #[test]
+    fn single_unit_goes_to_order_point() {
+        for shape in [FormationShape::Line, FormationShape::Wedge, FormationShape::Column] {
+            assert!(formation_offset(shape, 0, 1, Vec3::X).length() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn every_shape_gives_distinct_slots() {
+        for shape in [FormationShape::Line, FormationShape::Wedge, FormationShape::Column] {
+            assert_spread(&offsets(shape, 7, Vec3::new(1.0, 0.0, 1.0)));
+        }
+    }
+
+    #[test]
+    fn line_is_centered_across_facing() {
+        let line = offsets(FormationShape::Line, 4, Vec3::NEG_Z);
+        let center = line.iter().copied().sum::<Vec3>() / 4.0;
+
+        assert!(center.length() < 1e-5);
+        assert!(line.iter().all(|offset| offset.z.abs() < 1e-5));
+    }
+
+    #[test]
+    fn wedge_and_column_trail_behind_the_point() {
+        let facing = Vec3::X;
+
+        for shape in [FormationShape::Wedge, FormationShape::Column] {
+            let slots = offsets(shape, 5, facing);
+            assert!(slots.iter().skip(1).all(|offset| offset.dot(facing) < 0.0));
+        }
+
+        let column = offsets(FormationShape::Column, 3, facing);
+        assert!(column.iter().all(|offset| offset.z.abs() < 1e-5));
+    }
+
+    #[test]
+    fn degenerate_facing_falls_back_to_forward() {
+        let slot = formation_offset(FormationShape::Column, 1, 2, Vec3::Y);
+        assert!((slot - Vec3::new(0.0, 0.0, FORMATION_SPACING)).length() < 1e-5);
+    }
+}
@@ -0,0 +1,338 @@
+//! Game mode components (режим игры, extraction run, точки эвакуации, профиль).
+
+use bevy::prelude::*;
+use crate::actor::Appearance;
+use crate::item_system::{ItemId, ItemInstance};
+use crate::tutorial::TutorialProgress;
+
+/// Текущий режим игры.
+///
+/// `Sandbox` — свободная симуляция без условий победы/поражения.
+#[derive(Resource, Debug, Clone, Default)]
+pub enum GameMode {
+    #[default]
+    Sandbox,
+    /// Extraction: набрать лут и успеть эвакуироваться
+    Extraction(ExtractionRun),
+    /// Arena: дуэль 1v1 best-of-N раундов
+    Arena(ArenaMatch),
+}
+
+impl GameMode {
+    /// Extraction run с параметрами по умолчанию
+    pub fn extraction() -> Self {
+        Self::Extraction(ExtractionRun::default())
+    }
+
+    /// Активный extraction run (если режим Extraction)
+    pub fn extraction_run(&self) -> Option<&ExtractionRun> {
+        match self {
+            Self::Extraction(run) => Some(run),
+            _ => None,
+        }
+    }
+
+    pub fn extraction_run_mut(&mut self) -> Option<&mut ExtractionRun> {
+        match self {
+            Self::Extraction(run) => Some(run),
+            _ => None,
+        }
+    }
+
+    /// Активный arena матч (если режим Arena)
+    pub fn arena_match(&self) -> Option<&ArenaMatch> {
+        match self {
+            Self::Arena(arena) => Some(arena),
+            _ => None,
+        }
+    }
+
+    pub fn arena_match_mut(&mut self) -> Option<&mut ArenaMatch> {
+        match self {
+            Self::Arena(arena) => Some(arena),
+            _ => None,
+        }
+    }
+}
+
+/// Причина провала run (лут потерян)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum RunFailReason {
+    /// Игрок погиб
+    Died,
+    /// Время вышло до эвакуации (MIA)
+    TimeExpired,
+}
+
+/// Фаза extraction run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ExtractionPhase {
+    /// Сбор лута, точки эвакуации закрыты
+    Looting,
+    /// Точки эвакуации открыты (игрок может уйти)
+    ExtractionOpen,
+    /// Эвакуировался — лут в профиле
+    Extracted,
+    /// Провал — лут потерян
+    Failed { reason: RunFailReason },
+}
+
+/// Состояние extraction run (risk/reward: дольше в рейде → больше лута, но ближе таймер).
+///
+/// Точки эвакуации открываются по первому из условий:
+/// - прошло `open_after` секунд
+/// - захвачено `captures_to_open` объективов (`ObjectiveCaptured`)
+#[derive(Debug, Clone, Reflect)]
+pub struct ExtractionRun {
+    /// Время с начала run (секунды)
+    pub elapsed: f32,
+    /// Лимит времени: не успел эвакуироваться → TimeExpired (секунды)
+    pub time_limit: f32,
+    /// Точки эвакуации открываются через (секунды)
+    pub open_after: f32,
+    /// Объективов до досрочного открытия (0 = только по времени)
+    pub captures_to_open: u32,
+    /// Захвачено объективов за run
+    pub captures: u32,
+    pub phase: ExtractionPhase,
+}
+
+impl Default for ExtractionRun {
+    fn default() -> Self {
+        Self::new(600.0, 300.0, 1)
+    }
+}
+
+impl ExtractionRun {
+    pub fn new(time_limit: f32, open_after: f32, captures_to_open: u32) -> Self {
+        Self {
+            elapsed: 0.0,
+            time_limit,
+            open_after,
+            captures_to_open,
+            captures: 0,
+            phase: ExtractionPhase::Looting,
+        }
+    }
+
+    /// Run идёт (не завершён эвакуацией или провалом)
+    pub fn is_active(&self) -> bool {
+        matches!(self.phase, ExtractionPhase::Looting | ExtractionPhase::ExtractionOpen)
+    }
+
+    /// Выполнено условие открытия точек эвакуации
+    pub fn should_open(&self) -> bool {
+        self.elapsed >= self.open_after
+            || (self.captures_to_open > 0 && self.captures >= self.captures_to_open)
+    }
+
+    /// Оставшееся время до провала (секунды)
+    pub fn remaining(&self) -> f32 {
+        (self.time_limit - self.elapsed).max(0.0)
+    }
+}
+
+/// Точка эвакуации (работает только в фазе `ExtractionOpen`).
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct ExtractionPoint {
+    /// Центр зоны, world coordinates
+    pub position: Vec3,
+    /// Радиус зоны (метры, XZ)
+    pub radius: f32,
+}
+
+impl ExtractionPoint {
+    /// Радиус зоны по умолчанию (метры)
+    pub const DEFAULT_RADIUS: f32 = 4.0;
+
+    pub fn new(position: Vec3) -> Self {
+        Self {
+            position,
+            radius: Self::DEFAULT_RADIUS,
+        }
+    }
+
+    /// `position` внутри зоны (XZ)
+    pub fn contains(&self, position: Vec3) -> bool {
+        let offset = position - self.position;
+        Vec3::new(offset.x, 0.0, offset.z).length() <= self.radius
+    }
+}
+
+/// Персистентный профиль игрока (переживает runs).
+///
+/// Эвакуация переносит лут из `Inventory` в `stash`; смерть/таймаут — лут теряется.
+/// NOTE: сохранение на диск — вместе с общим save/load (пока только in-memory);
+/// `appearance` уже сериализуется в RON (`Appearance::to_ron` / `from_ron`).
+#[derive(Resource, Debug, Clone, Default)]
+pub struct PlayerProfile {
+    /// Банк предметов, вынесенных из рейдов
+    pub stash: Vec<ItemInstance>,
+    pub runs_extracted: u32,
+    pub runs_failed: u32,
+    /// Внешность персонажа (применяется к игроку при spawn)
+    pub appearance: Appearance,
+    /// Пройденные шаги туториала первого запуска
+    pub tutorial: TutorialProgress,
+}
+
+impl PlayerProfile {
+    /// Положить вынесенный лут в банк, возвращает количество предметов
+    pub fn bank(&mut self, items: impl IntoIterator<Item = ItemInstance>) -> usize {
+        let before = self.stash.len();
+        self.stash.extend(items);
+        self.runs_extracted += 1;
+        self.stash.len() - before
+    }
+}
+
+/// Участник arena дуэли (слот 0/1 в `ArenaMatch::fighters`).
+///
+/// HP = 0 в раунде — нокаут, а не смерть: Godot не убирает труп,
+/// следующий раунд восстанавливает HP/stamina.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct ArenaFighter {
+    pub slot: usize,
+}
+
+/// Фаза arena матча.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ArenaPhase {
+    /// Позиции сброшены, бойцы неуязвимы до старта
+    Countdown { remaining: f32 },
+    /// Раунд идёт
+    Fighting,
+    /// Пауза между раундами (показ статистики)
+    RoundOver { remaining: f32 },
+    /// Матч завершён (`None` — ничья)
+    MatchOver { winner: Option<Entity> },
+}
+
+/// Статистика раунда (overlay + тюнинг melee).
+///
+/// Массивы индексируются слотом бойца.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RoundStats {
+    pub round: u32,
+    /// Длительность раунда (секунды)
+    pub duration: f32,
+    /// Нанесённый урон
+    pub damage: [u32; 2],
+    /// Попадания
+    pub hits: [u32; 2],
+    /// Парирования (соперник получил StaggerState от бойца)
+    pub parries: [u32; 2],
+    /// Слот победителя (`None` — ничья)
+    pub winner: Option<usize>,
+}
+
+/// Arena дуэль 1v1: best-of-N раундов, сброс позиций, таймер раунда, баны оружия.
+///
+/// Баны: предметы из `bans` снимаются с бойцов при сбросе раунда.
+#[derive(Debug, Clone)]
+pub struct ArenaMatch {
+    pub fighters: [Entity; 2],
+    /// Точки старта раунда (по слоту)
+    pub spawn_points: [Vec3; 2],
+    pub best_of: u32,
+    /// Текущий раунд (с 1)
+    pub round: u32,
+    pub wins: [u32; 2],
+    /// Лимит раунда (секунды), по истечении — победа по % HP
+    pub round_time_limit: f32,
+    pub bans: Vec<ItemId>,
+    pub phase: ArenaPhase,
+    pub current: RoundStats,
+    pub history: Vec<RoundStats>,
+    /// Раунд ещё не сброшен (HP, позиции, баны) — выставляется new / start_next_round
+    pub needs_reset: bool,
+}
+
+impl ArenaMatch {
+    /// Отсчёт перед раундом (секунды)
+    pub const COUNTDOWN_SECS: f32 = 3.0;
+    /// Пауза после раунда (секунды)
+    pub const ROUND_OVER_SECS: f32 = 3.0;
+    /// Лимит раунда по умолчанию (секунды)
+    pub const DEFAULT_ROUND_TIME: f32 = 90.0;
+
+    pub fn new(fighters: [Entity; 2], spawn_points: [Vec3; 2], best_of: u32) -> Self {
+        Self {
+            fighters,
+            spawn_points,
+            best_of: best_of.max(1),
+            round: 1,
+            wins: [0, 0],
+            round_time_limit: Self::DEFAULT_ROUND_TIME,
+            bans: Vec::new(),
+            phase: ArenaPhase::Countdown { remaining: Self::COUNTDOWN_SECS },
+            current: RoundStats { round: 1, ..Default::default() },
+            history: Vec::new(),
+            needs_reset: true,
+        }
+    }
+
+    /// Побед для выигрыша матча
+    pub fn wins_needed(&self) -> u32 {
+        self.best_of / 2 + 1
+    }
+
+    /// Слот бойца (None — не участник)
+    pub fn fighter_slot(&self, entity: Entity) -> Option<usize> {
+        self.fighters.iter().position(|&fighter| fighter == entity)
+    }
+
+    /// Предмет забанен в этом матче
+    pub fn is_banned(&self, item: &ItemId) -> bool {
+        self.bans.contains(item)
+    }
+
+    /// Победитель по таймауту: больший % HP, равный — ничья
+    pub fn timeout_winner(health_fractions: [f32; 2]) -> Option<usize> {
+        match health_fractions[0].total_cmp(&health_fractions[1]) {
+            std::cmp::Ordering::Greater => Some(0),
+            std::cmp::Ordering::Less => Some(1),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Завершить раунд: засчитать победу, статистику в историю.
+    ///
+    /// Возвращает статистику раунда; фаза → RoundOver или MatchOver.
+    pub fn finish_round(&mut self, winner: Option<usize>) -> RoundStats {
+        if let Some(slot) = winner {
+            self.wins[slot] += 1;
+        }
+        self.current.winner = winner;
+        let stats = std::mem::take(&mut self.current);
+        self.history.push(stats.clone());
+
+        let decided = self.wins.iter().any(|&wins| wins >= self.wins_needed());
+        self.phase = if decided || self.round >= self.best_of {
+            ArenaPhase::MatchOver { winner: self.leader() }
+        } else {
+            ArenaPhase::RoundOver { remaining: Self::ROUND_OVER_SECS }
+        };
+
+        stats
+    }
+
+    /// Следующий раунд (после RoundOver)
+    pub fn start_next_round(&mut self) {
+        self.round += 1;
+        self.current = RoundStats { round: self.round, ..Default::default() };
+        self.phase = ArenaPhase::Countdown { remaining: Self::COUNTDOWN_SECS };
+        self.needs_reset = true;
+    }
+
+    /// Лидер по победам (None — поровну)
+    pub fn leader(&self) -> Option<Entity> {
+        match self.wins[0].cmp(&self.wins[1]) {
+            std::cmp::Ordering::Greater => Some(self.fighters[0]),
+            std::cmp::Ordering::Less => Some(self.fighters[1]),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
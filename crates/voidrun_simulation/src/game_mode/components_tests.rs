@@ -0,0 +1,122 @@
+//! Tests for game mode components (extraction run, точки эвакуации, профиль, arena).
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::*;
+    use crate::item_system::ItemInstance;
+
+    #[test]
+    fn test_extraction_opens_by_time_or_capture() {
+        let mut run = ExtractionRun::new(600.0, 300.0, 1);
+        assert!(!run.should_open());
+
+        run.captures = 1;
+        assert!(run.should_open());
+
+        run.captures = 0;
+        run.elapsed = 300.0;
+        assert!(run.should_open());
+    }
+
+    #[test]
+    fn test_extraction_without_capture_condition() {
+        let mut run = ExtractionRun::new(600.0, 300.0, 0);
+
+        // captures_to_open = 0 → только по времени
+        assert!(!run.should_open());
+        run.elapsed = 301.0;
+        assert!(run.should_open());
+        assert_eq!(run.remaining(), 299.0);
+    }
+
+    #[test]
+    fn test_run_inactive_after_outcome() {
+        let mut run = ExtractionRun::default();
+        assert!(run.is_active());
+
+        run.phase = ExtractionPhase::ExtractionOpen;
+        assert!(run.is_active());
+
+        run.phase = ExtractionPhase::Failed { reason: RunFailReason::Died };
+        assert!(!run.is_active());
+    }
+
+    #[test]
+    fn test_extraction_point_ignores_height() {
+        let point = ExtractionPoint::new(Vec3::new(10.0, 0.0, 0.0));
+
+        assert!(point.contains(Vec3::new(12.0, 3.0, 1.0)));
+        assert!(!point.contains(Vec3::new(4.0, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_profile_bank_accumulates() {
+        let mut profile = PlayerProfile::default();
+
+        let banked = profile.bank(vec![ItemInstance::new("pistol_basic"), ItemInstance::new("pistol_basic")]);
+        assert_eq!(banked, 2);
+
+        profile.bank(vec![ItemInstance::new("pistol_basic")]);
+        assert_eq!(profile.stash.len(), 3);
+        assert_eq!(profile.runs_extracted, 2);
+    }
+
+    #[test]
+    fn test_game_mode_default_is_sandbox() {
+        assert!(GameMode::default().extraction_run().is_none());
+        assert!(GameMode::extraction().extraction_run().is_some());
+    }
+
+    fn duel(best_of: u32) -> ArenaMatch {
+        ArenaMatch::new(
+            [Entity::from_raw(1), Entity::from_raw(2)],
+            [Vec3::new(-5.0, 0.0, 0.0), Vec3::new(5.0, 0.0, 0.0)],
+            best_of,
+        )
+    }
+
+    #[test]
+    fn test_arena_best_of_three_ends_early() {
+        let mut arena = duel(3);
+        assert_eq!(arena.wins_needed(), 2);
+
+        arena.finish_round(Some(0));
+        assert!(matches!(arena.phase, ArenaPhase::RoundOver { .. }));
+
+        arena.start_next_round();
+        assert_eq!(arena.round, 2);
+        assert!(arena.needs_reset);
+
+        arena.finish_round(Some(0));
+        assert_eq!(arena.phase, ArenaPhase::MatchOver { winner: Some(Entity::from_raw(1)) });
+        assert_eq!(arena.history.len(), 2);
+    }
+
+    #[test]
+    fn test_arena_draw_after_last_round() {
+        let mut arena = duel(1);
+
+        let stats = arena.finish_round(None);
+        assert_eq!(stats.winner, None);
+        assert_eq!(arena.phase, ArenaPhase::MatchOver { winner: None });
+    }
+
+    #[test]
+    fn test_arena_timeout_winner_by_health_fraction() {
+        assert_eq!(ArenaMatch::timeout_winner([0.6, 0.3]), Some(0));
+        assert_eq!(ArenaMatch::timeout_winner([0.2, 0.9]), Some(1));
+        assert_eq!(ArenaMatch::timeout_winner([0.5, 0.5]), None);
+    }
+
+    #[test]
+    fn test_arena_bans_and_slots() {
+        let mut arena = duel(3);
+        arena.bans.push("pistol_basic".into());
+
+        assert!(arena.is_banned(&"pistol_basic".into()));
+        assert!(!arena.is_banned(&"sword_basic".into()));
+        assert_eq!(arena.fighter_slot(Entity::from_raw(2)), Some(1));
+        assert_eq!(arena.fighter_slot(Entity::from_raw(3)), None);
+    }
+}
@@ -0,0 +1,48 @@
+//! Game mode events (потребители: UI, persistence).
+
+use bevy::prelude::*;
+use super::components::{RoundStats, RunFailReason};
+
+/// Точки эвакуации открылись
+#[derive(Event, Debug, Clone)]
+pub struct ExtractionOpened {
+    /// Оставшееся время run (секунды)
+    pub remaining: f32,
+}
+
+/// Игрок эвакуировался — лут в профиле
+#[derive(Event, Debug, Clone)]
+pub struct RunExtracted {
+    pub player: Entity,
+    pub items_banked: usize,
+}
+
+/// Run провален — лут потерян
+#[derive(Event, Debug, Clone)]
+pub struct RunFailed {
+    pub player: Entity,
+    pub reason: RunFailReason,
+    pub items_lost: usize,
+}
+
+/// Arena: раунд сброшен (Godot телепортирует бойцов на `spawn_points`)
+#[derive(Event, Debug, Clone)]
+pub struct ArenaRoundReset {
+    pub round: u32,
+    pub fighters: [Entity; 2],
+    pub spawn_points: [Vec3; 2],
+}
+
+/// Arena: раунд завершён (нокаут или таймаут)
+#[derive(Event, Debug, Clone)]
+pub struct ArenaRoundEnded {
+    pub winner: Option<Entity>,
+    pub stats: RoundStats,
+}
+
+/// Arena: матч завершён
+#[derive(Event, Debug, Clone)]
+pub struct ArenaMatchEnded {
+    pub winner: Option<Entity>,
+    pub wins: [u32; 2],
+}
@@ -0,0 +1,63 @@
+//! Game mode module — режим игры (Sandbox / Extraction) и его правила
+//!
+//! # Extraction
+//!
+//! **Flow:**
+//! - Run стартует с `GameMode::Extraction` — игрок собирает лут в `Inventory`
+//! - Таймер / захват объектива (`ObjectiveCaptured`) → точки эвакуации открыты (`ExtractionOpened`)
+//! - Игрок в открытой `ExtractionPoint` → лут в `PlayerProfile::stash` (`RunExtracted`)
+//! - Смерть или конец таймера → лут потерян (`RunFailed`)
+//!
+//! Risk/reward: дольше в рейде → больше лута, но ближе конец таймера.
+//!
+//! # Arena
+//!
+//! **Flow:**
+//! - `GameMode::Arena` + `ArenaFighter` на двух бойцах (debug overlay → SimulationBridge)
+//! - Сброс раунда: HP/stamina, баны, неуязвимость на отсчёт → `ArenaRoundReset` (Godot телепорт)
+//! - Нокаут (HP = 0) / таймаут (по % HP) → `ArenaRoundEnded` со статистикой раунда
+//! - `wins_needed` побед → `ArenaMatchEnded`
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Game Mode Plugin
+///
+/// Регистрирует правила режима в FixedUpdate (после ObjectivePlugin — читает `ObjectiveCaptured`).
+pub struct GameModePlugin;
+
+impl Plugin for GameModePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameMode>()
+            .init_resource::<PlayerProfile>()
+            .add_event::<ExtractionOpened>()
+            .add_event::<RunExtracted>()
+            .add_event::<RunFailed>()
+            .add_event::<ArenaRoundReset>()
+            .add_event::<ArenaRoundEnded>()
+            .add_event::<ArenaMatchEnded>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    tick_extraction_run,    // 1. Таймер + захваты → открытие эвакуации
+                    resolve_extraction_run, // 2. Смерть / таймаут / эвакуация
+                    record_arena_stats,     // 3. DamageDealt / StaggerState между бойцами → статистика
+                    tick_arena_match,       // 4. Сброс / отсчёт / нокаут / таймаут раунда
+                )
+                    .chain()
+                    .after(crate::objective::carry_objectives),
+            );
+    }
+}
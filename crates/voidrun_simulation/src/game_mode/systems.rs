@@ -0,0 +1,271 @@
+//! Game mode systems (extraction run: таймер, открытие точек, эвакуация / провал;
+//! arena: раунды, нокаут/таймаут, статистика).
+
+use bevy::prelude::*;
+use crate::components::{EquippedWeapons, Health, Inventory, Stamina};
+use crate::combat::{DamageDealt, Downed, Invulnerable, InvulnerabilityReason, StaggerState};
+use crate::equipment::{UnequipWeaponIntent, WeaponSlot};
+use crate::objective::ObjectiveCaptured;
+use crate::player::Player;
+use crate::{SimulationTick, StrategicPosition};
+use super::components::{
+    ArenaFighter, ArenaMatch, ArenaPhase, ExtractionPhase, ExtractionPoint, GameMode, PlayerProfile,
+    RunFailReason,
+};
+use super::events::{
+    ArenaMatchEnded, ArenaRoundEnded, ArenaRoundReset, ExtractionOpened, RunExtracted, RunFailed,
+};
+
+/// System: таймер run + захваты объективов → открытие точек эвакуации
+pub fn tick_extraction_run(
+    mut game_mode: ResMut<GameMode>,
+    mut captured_events: EventReader<ObjectiveCaptured>,
+    mut opened_events: EventWriter<ExtractionOpened>,
+    time: Res<Time<Fixed>>,
+) {
+    let captures = captured_events.read().count() as u32;
+
+    let Some(run) = game_mode.extraction_run_mut() else {
+        return;
+    };
+    if !run.is_active() {
+        return;
+    }
+
+    run.elapsed += time.delta_secs();
+    run.captures += captures;
+
+    if run.phase == ExtractionPhase::Looting && run.should_open() {
+        run.phase = ExtractionPhase::ExtractionOpen;
+        opened_events.write(ExtractionOpened {
+            remaining: run.remaining(),
+        });
+        crate::logger::log(&format!("🚁 Extraction open ({:.0}s left)", run.remaining()));
+    }
+}
+
+/// System: исход run для игрока
+///
+/// - Смерть → `RunFailed { Died }`, лут из Inventory теряется (Downed — ещё не смерть, ждём поднятия)
+/// - Время вышло → `RunFailed { TimeExpired }`, лут теряется
+/// - Живой игрок в открытой точке эвакуации → `RunExtracted`, лут в `PlayerProfile::stash`
+pub fn resolve_extraction_run(
+    mut game_mode: ResMut<GameMode>,
+    mut profile: ResMut<PlayerProfile>,
+    mut players: Query<(Entity, &StrategicPosition, &Health, Has<Downed>, &mut Inventory), With<Player>>,
+    points: Query<&ExtractionPoint>,
+    mut extracted_events: EventWriter<RunExtracted>,
+    mut failed_events: EventWriter<RunFailed>,
+) {
+    let Some(run) = game_mode.extraction_run_mut() else {
+        return;
+    };
+    if !run.is_active() {
+        return;
+    }
+
+    let Ok((player, position, health, downed, mut inventory)) = players.single_mut() else {
+        return;
+    };
+
+    let fail_reason = if !health.is_alive() && !downed {
+        Some(RunFailReason::Died)
+    } else if run.remaining() <= 0.0 {
+        Some(RunFailReason::TimeExpired)
+    } else {
+        None
+    };
+
+    if let Some(reason) = fail_reason {
+        let items_lost = inventory.len();
+        inventory.items.clear();
+        profile.runs_failed += 1;
+        run.phase = ExtractionPhase::Failed { reason };
+
+        failed_events.write(RunFailed {
+            player,
+            reason,
+            items_lost,
+        });
+        crate::logger::log(&format!("☠️ Run failed ({:?}): {} items lost", reason, items_lost));
+        return;
+    }
+
+    if run.phase != ExtractionPhase::ExtractionOpen || downed {
+        return;
+    }
+
+    let player_pos = position.to_world_position(0.5);
+    if !points.iter().any(|point| point.contains(player_pos)) {
+        return;
+    }
+
+    let items_banked = profile.bank(inventory.items.drain(..));
+    run.phase = ExtractionPhase::Extracted;
+
+    extracted_events.write(RunExtracted {
+        player,
+        items_banked,
+    });
+    crate::logger::log(&format!("🚁 Extracted: {} items banked", items_banked));
+}
+
+/// System: arena матч — сброс раундов, отсчёт, нокаут/таймаут, best-of-N
+///
+/// - Сброс (начало Countdown): HP/stamina восстановлены, баны сняты (UnequipWeaponIntent),
+///   бойцы неуязвимы до конца отсчёта, `ArenaRoundReset` → Godot телепортирует на старт
+/// - Fighting: HP = 0 → нокаут (победа соперника), лимит времени → победа по % HP
+/// - RoundOver → следующий раунд; `wins_needed` побед / последний раунд → `ArenaMatchEnded`
+#[allow(clippy::too_many_arguments)]
+pub fn tick_arena_match(
+    mut game_mode: ResMut<GameMode>,
+    mut fighters: Query<(&mut Health, Option<&mut Stamina>, Option<&EquippedWeapons>), With<ArenaFighter>>,
+    mut reset_events: EventWriter<ArenaRoundReset>,
+    mut round_ended_events: EventWriter<ArenaRoundEnded>,
+    mut match_ended_events: EventWriter<ArenaMatchEnded>,
+    mut unequip_events: EventWriter<UnequipWeaponIntent>,
+    time: Res<Time<Fixed>>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    let Some(arena) = game_mode.arena_match_mut() else {
+        return;
+    };
+    let delta = time.delta_secs();
+
+    if arena.needs_reset {
+        arena.needs_reset = false;
+
+        for fighter in arena.fighters {
+            let Ok((mut health, stamina, weapons)) = fighters.get_mut(fighter) else {
+                continue;
+            };
+            health.current = health.max;
+            if let Some(mut stamina) = stamina {
+                stamina.current = stamina.max;
+            }
+
+            for index in 0..4 {
+                let banned = weapons
+                    .and_then(|weapons| weapons.get_slot(index))
+                    .is_some_and(|item| arena.is_banned(&item.definition_id));
+                if !banned {
+                    continue;
+                }
+                let Some(slot) = WeaponSlot::from_index(index) else {
+                    continue;
+                };
+                unequip_events.write(UnequipWeaponIntent { entity: fighter, slot });
+            }
+
+            commands.entity(fighter).insert(Invulnerable {
+                until_tick: tick.after_secs(ArenaMatch::COUNTDOWN_SECS),
+                reason: InvulnerabilityReason::SpawnProtection,
+            });
+        }
+
+        reset_events.write(ArenaRoundReset {
+            round: arena.round,
+            fighters: arena.fighters,
+            spawn_points: arena.spawn_points,
+        });
+        crate::logger::log(&format!("🏟️ Arena round {} reset", arena.round));
+    }
+
+    match arena.phase {
+        ArenaPhase::Countdown { remaining } => {
+            let remaining = remaining - delta;
+            arena.phase = if remaining <= 0.0 {
+                crate::logger::log(&format!("🏟️ Arena round {} FIGHT!", arena.round));
+                ArenaPhase::Fighting
+            } else {
+                ArenaPhase::Countdown { remaining }
+            };
+        }
+        ArenaPhase::Fighting => {
+            arena.current.duration += delta;
+
+            let health_fractions = arena.fighters.map(|fighter| {
+                fighters
+                    .get(fighter)
+                    .map(|(health, _, _)| health.current as f32 / health.max.max(1) as f32)
+                    .unwrap_or(0.0)
+            });
+            let knocked_out = health_fractions.map(|fraction| fraction <= 0.0);
+
+            let winner = match knocked_out {
+                [true, true] => None,
+                [true, false] => Some(1),
+                [false, true] => Some(0),
+                [false, false] if arena.current.duration >= arena.round_time_limit => {
+                    ArenaMatch::timeout_winner(health_fractions)
+                }
+                [false, false] => return,
+            };
+
+            let stats = arena.finish_round(winner);
+            let winner_entity = winner.map(|slot| arena.fighters[slot]);
+            crate::logger::log(&format!(
+                "🏟️ Arena round {} over: winner {:?} (score {}:{})",
+                stats.round, winner_entity, arena.wins[0], arena.wins[1]
+            ));
+            round_ended_events.write(ArenaRoundEnded {
+                winner: winner_entity,
+                stats,
+            });
+
+            if let ArenaPhase::MatchOver { winner } = arena.phase {
+                match_ended_events.write(ArenaMatchEnded {
+                    winner,
+                    wins: arena.wins,
+                });
+                crate::logger::log(&format!("🏆 Arena match over: winner {:?}", winner));
+            }
+        }
+        ArenaPhase::RoundOver { remaining } => {
+            let remaining = remaining - delta;
+            if remaining <= 0.0 {
+                arena.start_next_round();
+            } else {
+                arena.phase = ArenaPhase::RoundOver { remaining };
+            }
+        }
+        ArenaPhase::MatchOver { .. } => {}
+    }
+}
+
+/// System: статистика текущего раунда (урон, попадания, парирования между бойцами)
+pub fn record_arena_stats(
+    mut game_mode: ResMut<GameMode>,
+    mut damage_events: EventReader<DamageDealt>,
+    staggered: Query<&StaggerState, Added<StaggerState>>,
+) {
+    let Some(arena) = game_mode.arena_match_mut() else {
+        damage_events.clear();
+        return;
+    };
+    if arena.phase != ArenaPhase::Fighting {
+        damage_events.clear();
+        return;
+    }
+
+    for damage in damage_events.read() {
+        let (Some(attacker), Some(target)) = (arena.fighter_slot(damage.attacker), arena.fighter_slot(damage.target)) else {
+            continue;
+        };
+        if attacker == target {
+            continue; // Fall damage и т.п. — не удар соперника
+        }
+        arena.current.damage[attacker] += damage.damage;
+        arena.current.hits[attacker] += 1;
+    }
+
+    for fighter in arena.fighters {
+        let Ok(stagger) = staggered.get(fighter) else {
+            continue;
+        };
+        if let Some(parrier) = arena.fighter_slot(stagger.parried_by).filter(|&slot| arena.fighters[slot] != fighter) {
+            arena.current.parries[parrier] += 1;
+        }
+    }
+}
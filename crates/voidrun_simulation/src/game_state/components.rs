@@ -0,0 +1,56 @@
+//! Game state components (состояния игры и допустимые переходы).
+
+use bevy::prelude::*;
+
+/// Глобальное состояние игры (Bevy States).
+#[derive(States, Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum GameState {
+    /// Главное меню (симуляция мира стоит)
+    MainMenu,
+    /// Загрузка сцены / контента
+    Loading,
+    /// Игра идёт (combat / AI / equipment системы работают)
+    #[default]
+    Playing,
+    /// Меню паузы
+    Paused,
+    /// Конец забега (смерть / проигрыш режима)
+    GameOver,
+}
+
+impl GameState {
+    /// Godot меню: 0 = MainMenu, 1 = Loading, 2 = Playing, 3 = Paused, 4 = GameOver
+    pub fn from_index(index: i64) -> Option<Self> {
+        match index {
+            0 => Some(Self::MainMenu),
+            1 => Some(Self::Loading),
+            2 => Some(Self::Playing),
+            3 => Some(Self::Paused),
+            4 => Some(Self::GameOver),
+            _ => None,
+        }
+    }
+
+    /// Индекс для Godot (обратный `from_index`)
+    pub fn index(&self) -> i64 {
+        match self {
+            Self::MainMenu => 0,
+            Self::Loading => 1,
+            Self::Playing => 2,
+            Self::Paused => 3,
+            Self::GameOver => 4,
+        }
+    }
+
+    /// Допустимый переход (переход в то же состояние — нет)
+    pub fn can_transition_to(&self, next: GameState) -> bool {
+        matches!(
+            (self, next),
+            (Self::MainMenu, Self::Loading)
+                | (Self::Loading, Self::Playing)
+                | (Self::Playing, Self::Paused | Self::GameOver | Self::MainMenu)
+                | (Self::Paused, Self::Playing | Self::MainMenu)
+                | (Self::GameOver, Self::MainMenu | Self::Loading)
+        )
+    }
+}
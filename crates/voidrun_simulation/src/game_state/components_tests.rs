@@ -0,0 +1,34 @@
+//! Tests for game state components.
+
+#[cfg(test)]
+mod tests {
+    use super::super::components::*;
+
+    #[test]
+    fn game_state_index_round_trips() {
+        for index in 0..5 {
+            let state = GameState::from_index(index).unwrap();
+            assert_eq!(state.index(), index);
+        }
+        assert_eq!(GameState::from_index(5), None);
+        assert_eq!(GameState::from_index(-1), None);
+    }
+
+    #[test]
+    fn game_state_follows_menu_flow() {
+        assert!(GameState::MainMenu.can_transition_to(GameState::Loading));
+        assert!(GameState::Loading.can_transition_to(GameState::Playing));
+        assert!(GameState::Playing.can_transition_to(GameState::Paused));
+        assert!(GameState::Paused.can_transition_to(GameState::Playing));
+        assert!(GameState::Playing.can_transition_to(GameState::GameOver));
+        assert!(GameState::GameOver.can_transition_to(GameState::MainMenu));
+    }
+
+    #[test]
+    fn game_state_rejects_skipped_and_repeated_transitions() {
+        assert!(!GameState::MainMenu.can_transition_to(GameState::Playing));
+        assert!(!GameState::Paused.can_transition_to(GameState::GameOver));
+        assert!(!GameState::GameOver.can_transition_to(GameState::Playing));
+        assert!(!GameState::Playing.can_transition_to(GameState::Playing));
+    }
+}
@@ -0,0 +1,19 @@
+//! Game state events (запрос перехода из Godot меню, смена состояния).
+
+use bevy::prelude::*;
+use super::components::GameState;
+
+/// Intent: перейти в состояние (Godot меню → ECS)
+///
+/// Недопустимый из текущего состояния переход — игнор.
+#[derive(Event, Debug, Clone)]
+pub struct GameStateRequest {
+    pub to: GameState,
+}
+
+/// Событие: состояние игры сменилось (UI / музыка / сохранения)
+#[derive(Event, Debug, Clone)]
+pub struct GameStateChanged {
+    pub from: GameState,
+    pub to: GameState,
+}
@@ -0,0 +1,52 @@
+//! Game state module — глобальный state machine игры (Bevy States)
+//!
+//! # Architecture
+//!
+//! `GameState`: MainMenu → Loading → Playing ⇄ Paused, Playing → GameOver → MainMenu / Loading.
+//!
+//! **Flow:**
+//! - Godot меню → `SimulationBridge::request_game_state` → `GameStateRequest`
+//! - `apply_game_state_requests` (PreUpdate): недопустимый переход — отказ + лог, иначе `NextState`
+//! - Bevy `StateTransition` → `emit_game_state_changes` → `GameStateChanged { from, to }`
+//!
+//! Combat / AI / Equipment системы гейтятся `in_state(GameState::Playing)`.
+//! `GameState::Paused` — меню паузы (ввод приказов закрыт); тактическая пауза с очередью
+//! приказов — отдельно (`time_control::SimulationState`).
+//!
+//! NOTE: стартовое состояние — Playing (debug-сцена и headless тесты без меню);
+//! Godot меню переводит в MainMenu при запуске.
+
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Game State Plugin
+///
+/// Регистрирует `GameState` (+ StatesPlugin, если его нет), запросы переходов и события смены состояния.
+pub struct GameStatePlugin;
+
+impl Plugin for GameStatePlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+
+        app.init_state::<GameState>()
+            .add_event::<GameStateRequest>()
+            .add_event::<GameStateChanged>()
+            .add_systems(PreUpdate, apply_game_state_requests)
+            .add_systems(Update, emit_game_state_changes);
+    }
+}
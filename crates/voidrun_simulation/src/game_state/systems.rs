@@ -0,0 +1,46 @@
+//! Game state systems (валидация запросов перехода, события смены состояния).
+
+use bevy::prelude::*;
+use super::components::GameState;
+use super::events::{GameStateChanged, GameStateRequest};
+
+/// System: GameStateRequest → NextState (PreUpdate, переход в StateTransition того же кадра)
+///
+/// Несколько запросов за кадр — проверяются цепочкой (последний допустимый побеждает).
+pub fn apply_game_state_requests(
+    mut requests: EventReader<GameStateRequest>,
+    state: Res<State<GameState>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let mut current = *state.get();
+    for request in requests.read() {
+        if !current.can_transition_to(request.to) {
+            crate::logger::log_warning(&format!(
+                "🚫 GameState transition {:?} → {:?} rejected",
+                current, request.to
+            ));
+            continue;
+        }
+
+        next_state.set(request.to);
+        current = request.to;
+    }
+}
+
+/// System: Bevy StateTransitionEvent → GameStateChanged
+pub fn emit_game_state_changes(
+    mut transitions: EventReader<StateTransitionEvent<GameState>>,
+    mut changed_events: EventWriter<GameStateChanged>,
+) {
+    for transition in transitions.read() {
+        let (Some(from), Some(to)) = (transition.exited, transition.entered) else {
+            continue;
+        };
+        if from == to {
+            continue;
+        }
+
+        crate::logger::log(&format!("🎬 GameState: {:?} → {:?}", from, to));
+        changed_events.write(GameStateChanged { from, to });
+    }
+}
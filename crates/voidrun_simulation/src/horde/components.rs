@@ -0,0 +1,155 @@
+//! Horde components & resources (шум по зонам, состояние орды).
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Фракция орды (враждебна всем)
+pub const HORDE_FACTION_ID: u64 = 66;
+
+/// Шум одной зоны.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct NoiseArea {
+    pub level: f32,
+    /// Последний, кто шумел (цель орды)
+    pub last_source: Entity,
+    /// Где шумели последний раз (world coordinates)
+    pub last_position: Vec3,
+}
+
+/// Накопленный шум по зонам уровня (resource).
+///
+/// Зона — квадрат `AREA_SIZE` × `AREA_SIZE` по XZ (высота игнорируется).
+#[derive(Resource, Debug, Default, Clone)]
+pub struct NoiseMap {
+    pub areas: HashMap<IVec2, NoiseArea>,
+}
+
+impl NoiseMap {
+    /// Сторона зоны (метры)
+    pub const AREA_SIZE: f32 = 20.0;
+
+    /// Зона, в которую попадает позиция
+    pub fn area_of(position: Vec3) -> IVec2 {
+        IVec2::new(
+            (position.x / Self::AREA_SIZE).floor() as i32,
+            (position.z / Self::AREA_SIZE).floor() as i32,
+        )
+    }
+
+    /// Центр зоны (y = 0)
+    pub fn area_center(area: IVec2) -> Vec3 {
+        Vec3::new(
+            (area.x as f32 + 0.5) * Self::AREA_SIZE,
+            0.0,
+            (area.y as f32 + 0.5) * Self::AREA_SIZE,
+        )
+    }
+
+    /// Добавить шум в зону позиции
+    pub fn add(&mut self, position: Vec3, amount: f32, source: Entity) {
+        if amount <= 0.0 {
+            return;
+        }
+
+        let area = self.areas.entry(Self::area_of(position)).or_insert(NoiseArea {
+            level: 0.0,
+            last_source: source,
+            last_position: position,
+        });
+        area.level += amount;
+        area.last_source = source;
+        area.last_position = position;
+    }
+
+    /// Затухание всех зон (затихшие удаляются)
+    pub fn decay(&mut self, amount: f32) {
+        for area in self.areas.values_mut() {
+            area.level -= amount;
+        }
+        self.areas.retain(|_, area| area.level > 0.0);
+    }
+
+    /// Самая шумная зона с уровнем не ниже `threshold`
+    pub fn loudest_above(&self, threshold: f32) -> Option<(IVec2, NoiseArea)> {
+        self.areas
+            .iter()
+            .filter(|(_, area)| area.level >= threshold)
+            .max_by(|(_, a), (_, b)| a.level.total_cmp(&b.level))
+            .map(|(key, area)| (*key, *area))
+    }
+}
+
+/// Настройки шума и орды (resource).
+#[derive(Resource, Debug, Clone)]
+pub struct HordeConfig {
+    /// Шум зоны, вызывающий орду
+    pub threshold: f32,
+    /// Затухание шума (единиц/секунду)
+    pub decay_per_sec: f32,
+    /// Шум выстрела на метр `hearing_range` (100м → 1.0, бесшумное оружие → 0)
+    pub gunshot_noise_per_meter: f32,
+    /// Шум удара по двери
+    pub door_kick_noise: f32,
+    /// Шум выбитой двери
+    pub door_breach_noise: f32,
+    /// Пауза между предупреждением и появлением орды (секунды)
+    pub warning_secs: f32,
+    /// Пауза после орды, пока новая не может начаться (секунды)
+    pub cooldown_secs: f32,
+    /// Размер волны (без лидера)
+    pub wave_size: u32,
+    /// Дистанция спавна от источника шума (метры)
+    pub spawn_distance: f32,
+}
+
+impl Default for HordeConfig {
+    fn default() -> Self {
+        Self {
+            threshold: 30.0,
+            decay_per_sec: 0.25,
+            gunshot_noise_per_meter: 0.01,
+            door_kick_noise: 2.0,
+            door_breach_noise: 4.0,
+            warning_secs: 10.0,
+            cooldown_secs: 120.0,
+            wave_size: 8,
+            spawn_distance: 25.0,
+        }
+    }
+}
+
+impl HordeConfig {
+    /// Точка спавна: `spawn_distance` от источника шума, со стороны центра зоны
+    /// (источник в центре → по +X)
+    pub fn spawn_position(&self, area_center: Vec3, noise_position: Vec3) -> Vec3 {
+        let offset = Vec3::new(area_center.x - noise_position.x, 0.0, area_center.z - noise_position.z);
+        noise_position + offset.try_normalize().unwrap_or(Vec3::X) * self.spawn_distance
+    }
+}
+
+/// Фаза орды.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum HordePhase {
+    /// Ждём, пока кто-нибудь нашумит
+    #[default]
+    Quiet,
+    /// Предупреждение отправлено, орда появится на `arrives_at_tick`
+    Incoming {
+        position: Vec3,
+        target: Entity,
+        arrives_at_tick: u64,
+    },
+    /// Орда была, новая не раньше `until_tick`
+    Cooldown { until_tick: u64 },
+}
+
+/// Состояние орды (resource, одна орда за раз).
+#[derive(Resource, Debug, Default, Clone)]
+pub struct HordeDirector {
+    pub phase: HordePhase,
+}
+
+/// Элитный лидер орды (director вешает на спавне).
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct HordeLeader;
@@ -0,0 +1,60 @@
+//! Tests for horde components (noise map, spawn position).
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::*;
+
+    #[test]
+    fn area_of_floors_negative_coordinates() {
+        assert_eq!(NoiseMap::area_of(Vec3::new(5.0, 3.0, 19.9)), IVec2::new(0, 0));
+        assert_eq!(NoiseMap::area_of(Vec3::new(-0.1, 0.0, 20.0)), IVec2::new(-1, 1));
+        assert_eq!(NoiseMap::area_center(IVec2::new(-1, 1)), Vec3::new(-10.0, 0.0, 30.0));
+    }
+
+    #[test]
+    fn noise_accumulates_per_area_and_decays() {
+        let shooter = Entity::from_raw(1);
+        let mut noise = NoiseMap::default();
+
+        noise.add(Vec3::new(1.0, 0.0, 1.0), 2.0, shooter);
+        noise.add(Vec3::new(2.0, 0.0, 2.0), 1.0, shooter);
+        noise.add(Vec3::new(50.0, 0.0, 0.0), 0.5, shooter);
+        assert_eq!(noise.areas[&IVec2::ZERO].level, 3.0);
+
+        // Тихая зона затухла полностью → удалена
+        noise.decay(1.0);
+        assert_eq!(noise.areas.len(), 1);
+        assert_eq!(noise.areas[&IVec2::ZERO].level, 2.0);
+    }
+
+    #[test]
+    fn loudest_area_above_threshold_tracks_last_source() {
+        let first = Entity::from_raw(1);
+        let second = Entity::from_raw(2);
+        let mut noise = NoiseMap::default();
+
+        noise.add(Vec3::new(1.0, 0.0, 1.0), 5.0, first);
+        noise.add(Vec3::new(3.0, 0.0, 1.0), 5.0, second);
+        noise.add(Vec3::new(45.0, 0.0, 1.0), 8.0, first);
+
+        assert!(noise.loudest_above(11.0).is_none());
+
+        let (area, loudest) = noise.loudest_above(8.0).unwrap();
+        assert_eq!(area, IVec2::ZERO);
+        assert_eq!(loudest.last_source, second);
+        assert_eq!(loudest.last_position, Vec3::new(3.0, 0.0, 1.0));
+    }
+
+    #[test]
+    fn horde_spawns_away_from_noise_source() {
+        let config = HordeConfig::default();
+
+        let position = config.spawn_position(Vec3::new(10.0, 0.0, 10.0), Vec3::new(10.0, 0.0, 5.0));
+        assert_eq!(position, Vec3::new(10.0, 0.0, 5.0 + config.spawn_distance));
+
+        // Источник в центре зоны → fallback по +X
+        let center = Vec3::new(10.0, 0.0, 10.0);
+        assert_eq!(config.spawn_position(center, center), center + Vec3::X * config.spawn_distance);
+    }
+}
@@ -0,0 +1,27 @@
+//! Horde events.
+
+use bevy::prelude::*;
+
+/// Орда идёт (ECS → UI/audio)
+///
+/// Генерируется `update_horde_director` при превышении порога шума зоны.
+/// Через `arrives_in_secs` — `HordeSpawnRequested`.
+#[derive(Event, Debug, Clone)]
+pub struct HordeWarning {
+    /// Центр зоны, где нашумели
+    pub area_center: Vec3,
+    /// Последний источник шума (цель орды)
+    pub target: Entity,
+    pub arrives_in_secs: f32,
+}
+
+/// Запрос орды (ECS → director)
+///
+/// Director (Godot spawn layer) спавнит `count` бойцов фракции `HORDE_FACTION_ID`
+/// вокруг `position` и элитного лидера (`HordeLeader`), сразу нацеленных на `target`.
+#[derive(Event, Debug, Clone)]
+pub struct HordeSpawnRequested {
+    pub position: Vec3,
+    pub target: Entity,
+    pub count: u32,
+}
@@ -0,0 +1,52 @@
+//! Horde module — орда, вызванная накопленным шумом
+//!
+//! # Architecture
+//!
+//! Системное последствие громкой игры:
+//!
+//! **Flow:**
+//! - Выстрелы (`WeaponFired`) и удары по дверям (`DoorKicked`/`DoorBreached`) → шум зоны (`NoiseMap`)
+//! - Шум затухает со временем; зона выше `HordeConfig::threshold` → `HordeWarning`
+//! - Через `warning_secs` → `HordeSpawnRequested` (director спавнит волну + элитного лидера)
+//! - После орды — `cooldown_secs` без новых орд
+//!
+//! Одна орда за раз (`HordeDirector`).
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Horde Plugin
+///
+/// Регистрирует шум и орду в FixedUpdate (после дверей: читает DoorKicked/DoorBreached).
+pub struct HordePlugin;
+
+impl Plugin for HordePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<HordeWarning>()
+            .add_event::<HordeSpawnRequested>()
+            .init_resource::<NoiseMap>()
+            .init_resource::<HordeConfig>()
+            .init_resource::<HordeDirector>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    accumulate_noise,      // 1. Выстрелы/двери → NoiseMap, затухание
+                    update_horde_director, // 2. Порог → HordeWarning → HordeSpawnRequested → cooldown
+                )
+                    .chain()
+                    .after(crate::doors::complete_door_breaches),
+            );
+    }
+}
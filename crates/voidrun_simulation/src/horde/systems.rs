@@ -0,0 +1,124 @@
+//! Horde systems (шум → предупреждение → орда → cooldown).
+
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use crate::combat::WeaponFired;
+use crate::components::Actor;
+use crate::doors::{Door, DoorBreached, DoorKicked};
+use crate::SimulationTick;
+use super::components::{HordeConfig, HordeDirector, HordePhase, NoiseMap, HORDE_FACTION_ID};
+use super::events::{HordeSpawnRequested, HordeWarning};
+
+/// SystemParam: источники шума для орды (выстрелы, удары по дверям)
+#[derive(SystemParam)]
+pub struct NoiseEvents<'w, 's> {
+    fired: EventReader<'w, 's, WeaponFired>,
+    kicked: EventReader<'w, 's, DoorKicked>,
+    breached: EventReader<'w, 's, DoorBreached>,
+}
+
+/// System: выстрелы и удары по дверям → шум зоны, затухание
+///
+/// - Выстрел: `hearing_range × gunshot_noise_per_meter` в точке стрелявшего
+/// - Удар / выбитая дверь: фиксированный шум в точке двери
+/// - Сама орда шума не копит (иначе бой с ней вызывает следующую)
+pub fn accumulate_noise(
+    mut events: NoiseEvents,
+    doors: Query<&Door>,
+    actors: Query<&Actor>,
+    config: Res<HordeConfig>,
+    time: Res<Time<Fixed>>,
+    mut noise: ResMut<NoiseMap>,
+) {
+    for event in events.fired.read() {
+        if actors
+            .get(event.shooter)
+            .is_ok_and(|actor| actor.faction_id == HORDE_FACTION_ID)
+        {
+            continue;
+        }
+
+        noise.add(
+            event.shooter_position,
+            event.hearing_range * config.gunshot_noise_per_meter,
+            event.shooter,
+        );
+    }
+
+    for event in events.kicked.read() {
+        if let Ok(door) = doors.get(event.door) {
+            noise.add(door.position, config.door_kick_noise, event.breacher);
+        }
+    }
+
+    for event in events.breached.read() {
+        if let Ok(door) = doors.get(event.door) {
+            noise.add(door.position, config.door_breach_noise, event.breacher);
+        }
+    }
+
+    noise.decay(config.decay_per_sec * time.delta_secs());
+}
+
+/// System: фазы орды
+///
+/// - Quiet: зона выше порога → HordeWarning, шум зоны сброшен → Incoming
+/// - Incoming: время вышло → HordeSpawnRequested (волна + лидер) → Cooldown
+/// - Cooldown: истёк → Quiet (шум копится и во время cooldown)
+pub fn update_horde_director(
+    mut director: ResMut<HordeDirector>,
+    mut noise: ResMut<NoiseMap>,
+    config: Res<HordeConfig>,
+    tick: Res<SimulationTick>,
+    mut warning_events: EventWriter<HordeWarning>,
+    mut spawn_events: EventWriter<HordeSpawnRequested>,
+) {
+    match director.phase {
+        HordePhase::Quiet => {
+            let Some((area, loudest)) = noise.loudest_above(config.threshold) else {
+                return;
+            };
+            noise.areas.remove(&area);
+
+            let area_center = NoiseMap::area_center(area);
+            director.phase = HordePhase::Incoming {
+                position: config.spawn_position(area_center, loudest.last_position),
+                target: loudest.last_source,
+                arrives_at_tick: tick.after_secs(config.warning_secs),
+            };
+
+            crate::logger::log(&format!(
+                "📯 Horde incoming in {:.0}s: area {:?} too loud (target {:?})",
+                config.warning_secs, area, loudest.last_source
+            ));
+            warning_events.write(HordeWarning {
+                area_center,
+                target: loudest.last_source,
+                arrives_in_secs: config.warning_secs,
+            });
+        }
+        HordePhase::Incoming {
+            position,
+            target,
+            arrives_at_tick,
+        } => {
+            if tick.get() < arrives_at_tick {
+                return;
+            }
+
+            director.phase = HordePhase::Cooldown {
+                until_tick: tick.after_secs(config.cooldown_secs),
+            };
+            spawn_events.write(HordeSpawnRequested {
+                position,
+                target,
+                count: config.wave_size,
+            });
+        }
+        HordePhase::Cooldown { until_tick } => {
+            if tick.get() >= until_tick {
+                director.phase = HordePhase::Quiet;
+            }
+        }
+    }
+}
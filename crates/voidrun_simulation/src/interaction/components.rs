@@ -0,0 +1,144 @@
+//! Interaction components (что можно использовать и откуда).
+
+use bevy::prelude::*;
+use crate::item_system::ItemInstance;
+use crate::shared::{PrefabPath, StrategicPosition};
+
+/// Что происходит при использовании.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum InteractionKind {
+    /// Открыть / закрыть (entity с `Door`)
+    Door,
+    /// Подобрать предмет (entity с `Pickup`)
+    Pickup,
+    /// Переключить (entity с `Switch`)
+    Switch,
+    /// Забрать содержимое (entity с `Container` — труп, ящик)
+    Container,
+}
+
+impl InteractionKind {
+    /// Вид из meta ноды уровня (`interaction`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "door" => Some(InteractionKind::Door),
+            "pickup" => Some(InteractionKind::Pickup),
+            "switch" => Some(InteractionKind::Switch),
+            "container" => Some(InteractionKind::Container),
+            _ => None,
+        }
+    }
+}
+
+/// Объект, который актор может использовать ([F]).
+///
+/// Позиция — из Godot ноды (ECS проверяет только дистанцию).
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Interactable {
+    pub kind: InteractionKind,
+    /// World position (центр объекта)
+    pub position: Vec3,
+    /// Максимальная дистанция использования (метры, XZ)
+    pub range: f32,
+}
+
+impl Interactable {
+    /// Дистанция использования по умолчанию (метры)
+    pub const DEFAULT_RANGE: f32 = 2.5;
+
+    pub fn new(kind: InteractionKind, position: Vec3) -> Self {
+        Self {
+            kind,
+            position,
+            range: Self::DEFAULT_RANGE,
+        }
+    }
+
+    /// Актор в `position` дотягивается (XZ — StrategicPosition без высоты)
+    pub fn in_range(&self, position: Vec3) -> bool {
+        let offset = position - self.position;
+        Vec2::new(offset.x, offset.z).length() <= self.range
+    }
+}
+
+/// Предмет, лежащий в мире (Interactable::Pickup).
+#[derive(Component, Debug, Clone)]
+pub struct Pickup {
+    pub item: ItemInstance,
+}
+
+/// Предмет, выпавший в мир (дроп из Inventory) — Pickup без ноды уровня.
+///
+/// Godot спавнит визуал из `PrefabPath` (пустой путь → placeholder) и убирает при despawn.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+#[require(StrategicPosition, PrefabPath)]
+pub struct WorldItem;
+
+impl WorldItem {
+    /// Entity-тип предмета в мире: WorldItem + Pickup + Interactable + позиция + prefab
+    pub fn bundle(item: ItemInstance, position: Vec3, prefab_path: Option<String>) -> impl Bundle {
+        (
+            WorldItem,
+            Interactable::new(InteractionKind::Pickup, position),
+            Pickup { item },
+            StrategicPosition::from_world_position(position),
+            PrefabPath::new(prefab_path.unwrap_or_default()),
+        )
+    }
+}
+
+/// Лут в контейнере (Interactable::Container) — труп актора, ящик.
+///
+/// [F] открывает (`OpenContainer` на акторе, UI показывает содержимое), `TakeFromContainerIntent`
+/// забирает предметы. Труп с лутом живёт `CORPSE_LIFETIME`, опустошённый — `LOOTED_LIFETIME`
+/// (через `DespawnAfter`). Содержимое ящиков — из `LootTable` (`LootTableRef`).
+#[derive(Component, Debug, Clone, Default)]
+pub struct Container {
+    pub items: Vec<ItemInstance>,
+}
+
+impl Container {
+    /// Сколько лежит труп с лутом (секунды)
+    pub const CORPSE_LIFETIME: f32 = 60.0;
+    /// Сколько лежит обысканный труп (секунды)
+    pub const LOOTED_LIFETIME: f32 = 5.0;
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Забрать всё содержимое
+    pub fn take_all(&mut self) -> Vec<ItemInstance> {
+        std::mem::take(&mut self.items)
+    }
+
+    /// Забрать предмет по индексу
+    pub fn take(&mut self, index: usize) -> Option<ItemInstance> {
+        (index < self.items.len()).then(|| self.items.remove(index))
+    }
+}
+
+/// Актор смотрит содержимое контейнера (UI открыт)
+///
+/// Снимается при опустошении, уходе за `Interactable::range` или исчезновении контейнера.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpenContainer {
+    pub container: Entity,
+}
+
+/// Рубильник / кнопка / терминал (Interactable::Switch).
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct Switch {
+    pub on: bool,
+}
+
+impl Switch {
+    /// Переключить, возвращает новое состояние
+    pub fn toggle(&mut self) -> bool {
+        self.on = !self.on;
+        self.on
+    }
+}
@@ -0,0 +1,39 @@
+//! Tests for interaction components (дистанция, рубильник, контейнер лута).
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::*;
+    use crate::item_system::{ItemId, ItemInstance};
+
+    #[test]
+    fn test_interactable_range_ignores_height() {
+        let switch = Interactable::new(InteractionKind::Switch, Vec3::new(0.0, 1.5, 0.0));
+
+        assert!(switch.in_range(Vec3::new(Interactable::DEFAULT_RANGE, 0.0, 0.0)));
+        assert!(!switch.in_range(Vec3::new(Interactable::DEFAULT_RANGE + 0.1, 0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_switch_toggle_and_kind_names() {
+        let mut switch = Switch::default();
+        assert!(switch.toggle());
+        assert!(!switch.toggle());
+
+        assert_eq!(InteractionKind::from_name("pickup"), Some(InteractionKind::Pickup));
+        assert_eq!(InteractionKind::from_name("terminal"), None);
+    }
+
+    #[test]
+    fn test_loot_container_take_all_empties() {
+        let mut container = Container {
+            items: vec![ItemInstance::new("medkit"), ItemInstance::new("rifle")],
+        };
+
+        let items = container.take_all();
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].definition_id, ItemId::from("medkit"));
+        assert!(container.is_empty());
+        assert_eq!(InteractionKind::from_name("container"), Some(InteractionKind::Container));
+    }
+}
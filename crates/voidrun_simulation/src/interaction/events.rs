@@ -0,0 +1,109 @@
+//! Interaction events.
+
+use bevy::prelude::*;
+use crate::item_system::ItemInstance;
+
+/// Intent: актор использует конкретный объект (Godot raycast по [F] / AI / скрипт)
+///
+/// Обрабатывается `process_interact_intents`.
+#[derive(Event, Debug, Clone)]
+pub struct InteractIntent {
+    pub actor: Entity,
+    pub target: Entity,
+}
+
+/// Дверь открыта / закрыта
+///
+/// Godot: полотно скрыто, коллизия выключена (или наоборот).
+#[derive(Event, Debug, Clone)]
+pub struct DoorToggled {
+    pub door: Entity,
+    pub actor: Entity,
+    pub open: bool,
+}
+
+/// Предмет подобран (уже в Inventory, Pickup entity удалён)
+///
+/// Godot: убрать ноду предмета.
+#[derive(Event, Debug, Clone)]
+pub struct ItemPickedUp {
+    pub pickup: Entity,
+    pub actor: Entity,
+    pub item: ItemInstance,
+}
+
+/// Intent: актор выбрасывает предмет из Inventory (UI / AI)
+///
+/// Обрабатывается `drop_items` → WorldItem у ног актора.
+#[derive(Event, Debug, Clone)]
+pub struct DropItemIntent {
+    pub actor: Entity,
+    /// Индекс в `Inventory::items`
+    pub inventory_index: usize,
+}
+
+/// Предмет выпал в мир (дроп из Inventory)
+#[derive(Event, Debug, Clone)]
+pub struct ItemDropped {
+    pub world_item: Entity,
+    pub actor: Entity,
+    pub item: ItemInstance,
+}
+
+/// Контейнер открыт ([F]) — снимок содержимого для UI
+#[derive(Event, Debug, Clone)]
+pub struct ContainerOpened {
+    pub container: Entity,
+    pub actor: Entity,
+    pub items: Vec<ItemInstance>,
+}
+
+/// Intent: забрать предмет(ы) из открытого контейнера (UI / [F] повторно)
+///
+/// `index: None` — всё содержимое.
+#[derive(Event, Debug, Clone)]
+pub struct TakeFromContainerIntent {
+    pub actor: Entity,
+    pub container: Entity,
+    pub index: Option<usize>,
+}
+
+/// Предметы забраны из контейнера (уже в Inventory актора)
+///
+/// `emptied` — контейнер пуст, Interactable снят (труп исчезнет через `LOOTED_LIFETIME`).
+#[derive(Event, Debug, Clone)]
+pub struct ContainerLooted {
+    pub container: Entity,
+    pub actor: Entity,
+    pub items: Vec<ItemInstance>,
+    pub emptied: bool,
+}
+
+/// Рубильник переключён
+///
+/// Godot: сигнал `switch_toggled(on)` на ноде (скрипты уровня — свет, лифты, терминалы).
+#[derive(Event, Debug, Clone)]
+pub struct SwitchToggled {
+    pub switch: Entity,
+    pub actor: Entity,
+    pub on: bool,
+}
+
+/// Почему использование не сработало.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InteractionDenial {
+    /// Актор слишком далеко
+    OutOfRange,
+    /// Дверь заперта без ключа (только выбить) или уже выбита
+    Locked,
+    /// Без Inventory предмет не подобрать
+    NoInventory,
+}
+
+/// Использование отклонено (UI подсказка / звук)
+#[derive(Event, Debug, Clone)]
+pub struct InteractionDenied {
+    pub actor: Entity,
+    pub target: Entity,
+    pub reason: InteractionDenial,
+}
@@ -0,0 +1,147 @@
+//! Loot tables — взвешенные дропы для контейнеров и трупов (RON).
+//!
+//! Таблица по имени: `rolls` бросков, каждый — предмет по весу (или пусто, `empty_weight`).
+//! Ящики уровня / NPC ссылаются на таблицу через `LootTableRef`.
+
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+use std::collections::HashMap;
+use crate::item_system::{ItemDefinitions, ItemId, ItemInstance};
+
+/// Ошибка разбора loot tables из RON
+pub type LootTableParseError = ron::error::SpannedError;
+
+/// Один вариант броска
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LootEntry {
+    /// ItemId (ItemDefinitions)
+    pub item: String,
+    pub weight: u32,
+    /// Размер стака (min, max включительно)
+    #[serde(default = "LootEntry::single")]
+    pub count: (u32, u32),
+}
+
+impl LootEntry {
+    fn single() -> (u32, u32) {
+        (1, 1)
+    }
+}
+
+/// Набор бросков одной таблицы
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct LootPool {
+    /// Количество бросков
+    pub rolls: u32,
+    /// Вес "ничего не выпало" (на бросок)
+    #[serde(default)]
+    pub empty_weight: u32,
+    pub entries: Vec<LootEntry>,
+}
+
+impl LootPool {
+    /// Бросить таблицу (результат — готовые ItemInstance)
+    pub fn roll(&self, rng: &mut impl Rng) -> Vec<ItemInstance> {
+        let total: u32 = self.empty_weight + self.entries.iter().map(|entry| entry.weight).sum::<u32>();
+        if total == 0 {
+            return Vec::new();
+        }
+
+        let mut items = Vec::new();
+        for _ in 0..self.rolls {
+            let mut pick = rng.gen_range(0..total);
+            if pick < self.empty_weight {
+                continue;
+            }
+            pick -= self.empty_weight;
+
+            let Some(entry) = self.entries.iter().find(|entry| {
+                if pick < entry.weight {
+                    return true;
+                }
+                pick -= entry.weight;
+                false
+            }) else {
+                continue;
+            };
+
+            let (min, max) = entry.count;
+            let count = if max > min { rng.gen_range(min..=max) } else { min };
+            if count == 0 {
+                continue;
+            }
+            items.push(if count > 1 {
+                ItemInstance::consumable_stack(entry.item.as_str(), count)
+            } else {
+                ItemInstance::new(entry.item.as_str())
+            });
+        }
+        items
+    }
+}
+
+/// Loot tables по имени (resource)
+///
+/// По умолчанию — `data/loot_tables.ron`, замена — `from_ron`.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+pub struct LootTable {
+    pub tables: HashMap<String, LootPool>,
+}
+
+/// Встроенные таблицы (data/loot_tables.ron): ящики уровня и дропы NPC
+const DEFAULT_LOOT_TABLES: &str = include_str!("../../data/loot_tables.ron");
+
+impl Default for LootTable {
+    fn default() -> Self {
+        Self::from_ron(DEFAULT_LOOT_TABLES).unwrap_or_else(|error| {
+            crate::logger::log_error(&format!("Default loot tables failed to parse: {}", error));
+            Self { tables: HashMap::new() }
+        })
+    }
+}
+
+impl LootTable {
+    pub fn from_ron(source: &str) -> Result<Self, LootTableParseError> {
+        ron::from_str(source)
+    }
+
+    /// Бросить таблицу по имени (неизвестная — пусто + ошибка в лог)
+    pub fn roll(&self, name: &str, rng: &mut impl Rng) -> Vec<ItemInstance> {
+        let Some(pool) = self.tables.get(name) else {
+            crate::logger::log_error(&format!("Loot table '{}' not found", name));
+            return Vec::new();
+        };
+        pool.roll(rng)
+    }
+
+    /// ItemId таблиц, которых нет в ItemDefinitions ("table: item")
+    pub fn unknown_items(&self, definitions: &ItemDefinitions) -> Vec<String> {
+        let mut unknown: Vec<String> = self
+            .tables
+            .iter()
+            .flat_map(|(name, pool)| {
+                pool.entries
+                    .iter()
+                    .filter(|entry| definitions.get(&ItemId::from(entry.item.as_str())).is_none())
+                    .map(move |entry| format!("{}: {}", name, entry.item))
+            })
+            .collect();
+        unknown.sort();
+        unknown
+    }
+
+    /// Бросок таблицы + редкость / affixes оружия и брони (дроп ящика / трупа)
+    pub fn roll_with_affixes(&self, name: &str, definitions: &ItemDefinitions, rng: &mut impl Rng) -> Vec<ItemInstance> {
+        let mut items = self.roll(name, rng);
+        for item in items.iter_mut() {
+            definitions.roll_affixes(item, rng);
+        }
+        items
+    }
+}
+
+/// Ссылка на loot table (ящик уровня — заполняется при появлении, NPC — дроп при смерти)
+#[derive(Component, Debug, Clone, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct LootTableRef(pub String);
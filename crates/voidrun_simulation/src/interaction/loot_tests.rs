@@ -0,0 +1,53 @@
+//! Tests for loot tables (RON, веса, детерминизм).
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+    use super::super::loot::*;
+    use crate::item_system::{ItemDefinitions, ItemId};
+
+    #[test]
+    fn test_default_tables_reference_known_items() {
+        let tables = LootTable::default();
+        let definitions = ItemDefinitions::default();
+
+        for name in ["supply_crate", "weapon_locker", "npc_common", "npc_elite"] {
+            let pool = tables.tables.get(name).expect("default table missing");
+            for entry in pool.entries.iter() {
+                assert!(
+                    definitions.get(&ItemId::from(entry.item.as_str())).is_some(),
+                    "{}: unknown item {}",
+                    name,
+                    entry.item
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_roll_is_deterministic_and_respects_weights() {
+        let tables = LootTable::from_ron(
+            r#"(tables: {
+                "only_kits": (rolls: 4, entries: [(item: "health_kit", weight: 1, count: (2, 2))]),
+                "nothing": (rolls: 5, empty_weight: 1, entries: []),
+            })"#,
+        )
+        .unwrap();
+
+        let items = tables.roll("only_kits", &mut ChaCha8Rng::seed_from_u64(7));
+        assert_eq!(items.len(), 4);
+        assert!(items.iter().all(|item| item.definition_id == ItemId::from("health_kit") && item.stack_size == 2));
+        assert!(tables.roll("nothing", &mut ChaCha8Rng::seed_from_u64(7)).is_empty());
+
+        let defaults = LootTable::default();
+        let roll = |seed| {
+            defaults
+                .roll("supply_crate", &mut ChaCha8Rng::seed_from_u64(seed))
+                .into_iter()
+                .map(|item| (item.definition_id, item.stack_size))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(roll(42), roll(42));
+    }
+}
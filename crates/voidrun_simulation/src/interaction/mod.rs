@@ -0,0 +1,74 @@
+//! Interaction module — единая клавиша "использовать" (двери, рубильники, предметы)
+//!
+//! # Architecture
+//!
+//! **Flow:**
+//! - Godot: [F] → raycast камеры → ближайший `Interactable` → `InteractIntent { actor, target }`
+//! - ECS `process_interact_intents`: дистанция, затем действие по `InteractionKind`:
+//!   - Door → открыть / закрыть (`DoorToggled`), заперта → `InteractionDenied`
+//!   - Pickup → предмет в Inventory, entity удалён (`ItemPickedUp`)
+//!   - Switch → переключить (`SwitchToggled`)
+//!   - Container → открыть (`OpenContainer` на акторе, `ContainerOpened` с содержимым для UI)
+//! - `TakeFromContainerIntent` → предмет / всё из открытого контейнера в Inventory (`ContainerLooted`),
+//!   опустошённый труп исчезает через `DespawnAfter`
+//! - `DropItemIntent` → `WorldItem` (Pickup + PrefabPath) на земле (`ItemDropped`)
+//! - `EntityDied` → снаряжение и инвентарь трупа в `Container` (+ бросок `LootTableRef`)
+//! - `LootTable` (RON) → содержимое ящиков уровня с `LootTableRef`
+//!
+//! Interactable entities уровня создаёт Godot из нод (ECS не знает геометрию),
+//! WorldItem — ECS, Godot только спавнит визуал.
+//! Основа для дверей, лута и терминалов.
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod loot;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+#[cfg(test)]
+mod loot_tests;
+#[cfg(test)]
+mod systems_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use loot::*;
+pub use systems::*;
+
+/// Interaction Plugin
+///
+/// Регистрирует обработку InteractIntent, контейнеры и дроп предметов в FixedUpdate.
+pub struct InteractionPlugin;
+
+impl Plugin for InteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<InteractIntent>()
+            .add_event::<DoorToggled>()
+            .add_event::<ItemPickedUp>()
+            .add_event::<SwitchToggled>()
+            .add_event::<InteractionDenied>()
+            .add_event::<DropItemIntent>()
+            .add_event::<ItemDropped>()
+            .add_event::<ContainerOpened>()
+            .add_event::<TakeFromContainerIntent>()
+            .add_event::<ContainerLooted>()
+            .init_resource::<LootTable>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    process_interact_intents,         // 1. InteractIntent → дверь / предмет / рубильник / контейнер
+                    take_from_containers,             // 2. TakeFromContainerIntent → Inventory
+                    close_distant_containers,         // 3. Ушёл от контейнера → OpenContainer снят
+                    drop_items,                       // 4. DropItemIntent → WorldItem
+                    fill_containers_from_loot_tables, // 5. Added<LootTableRef> → содержимое ящика
+                    create_corpse_loot,               // 6. EntityDied → Container на трупе
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,367 @@
+//! Interaction systems (InteractIntent → дверь / предмет / рубильник / контейнер, дроп и лут трупов).
+
+use bevy::prelude::*;
+use std::collections::HashSet;
+use crate::combat::{DespawnAfter, EntityDied};
+use crate::components::{Health, Inventory};
+use crate::doors::Door;
+use crate::player::Player;
+use crate::item_system::{ItemDefinitions, ItemInstance};
+use crate::shared::{ConsumableSlots, EquippedWeapons};
+use crate::{DeterministicRng, StrategicPosition};
+use super::components::{Container, Interactable, InteractionKind, OpenContainer, Pickup, Switch, WorldItem};
+use super::events::{
+    ContainerLooted, ContainerOpened, DoorToggled, DropItemIntent, InteractIntent, InteractionDenial,
+    InteractionDenied, ItemDropped, ItemPickedUp, SwitchToggled, TakeFromContainerIntent,
+};
+use super::loot::{LootTable, LootTableRef};
+
+/// System: InteractIntent → действие по `InteractionKind`
+///
+/// - Мёртвый актор / несуществующая цель → intent игнорируется
+/// - Дальше `Interactable::range` → `InteractionDenied(OutOfRange)`
+/// - Запертая дверь с ключом (`Door::key`) в Inventory актора → отпирается
+/// - Несколько intent на один предмет / дверь за тик — срабатывает первый
+/// - Контейнер только открывается (`OpenContainer`), предметы — `take_from_containers`
+#[allow(clippy::too_many_arguments)]
+pub fn process_interact_intents(
+    mut intents: EventReader<InteractIntent>,
+    mut actors: Query<(&StrategicPosition, &Health, Option<&mut Inventory>)>,
+    mut interactables: Query<(
+        &Interactable,
+        Option<&mut Door>,
+        Option<&Pickup>,
+        Option<&mut Switch>,
+        Option<&Container>,
+    )>,
+    mut door_events: EventWriter<DoorToggled>,
+    mut pickup_events: EventWriter<ItemPickedUp>,
+    mut switch_events: EventWriter<SwitchToggled>,
+    mut opened_events: EventWriter<ContainerOpened>,
+    mut denied_events: EventWriter<InteractionDenied>,
+    definitions: Res<ItemDefinitions>,
+    mut commands: Commands,
+) {
+    // Despawn применится в конце тика: подобранные предметы и переключённые двери этого тика пропускаем
+    let mut consumed = HashSet::new();
+
+    for intent in intents.read() {
+        let Ok((position, health, inventory)) = actors.get_mut(intent.actor) else {
+            continue;
+        };
+        if !health.is_alive() || consumed.contains(&intent.target) {
+            continue;
+        }
+        let Ok((interactable, door, pickup, switch, container)) = interactables.get_mut(intent.target) else {
+            continue;
+        };
+
+        let deny = |reason| InteractionDenied {
+            actor: intent.actor,
+            target: intent.target,
+            reason,
+        };
+
+        if !interactable.in_range(position.to_world_position(0.0)) {
+            denied_events.write(deny(InteractionDenial::OutOfRange));
+            continue;
+        }
+
+        match interactable.kind {
+            InteractionKind::Door => {
+                let Some(mut door) = door else {
+                    continue;
+                };
+                // Запертая дверь + ключ в Inventory → отпереть и сразу открыть
+                let has_key = door.key.as_ref().is_some_and(|key| {
+                    inventory.as_ref().is_some_and(|inventory| inventory.find_item(key).is_some())
+                });
+                if has_key && door.unlock() {
+                    crate::logger::log(&format!("🔑 {:?} unlocked door {:?}", intent.actor, intent.target));
+                }
+                match door.toggle() {
+                    Some(open) => {
+                        // Два AI у одной двери не закрывают её обратно
+                        consumed.insert(intent.target);
+                        door_events.write(DoorToggled {
+                            door: intent.target,
+                            actor: intent.actor,
+                            open,
+                        });
+                    }
+                    None => {
+                        denied_events.write(deny(InteractionDenial::Locked));
+                    }
+                }
+            }
+            InteractionKind::Pickup => {
+                let Some(pickup) = pickup else {
+                    continue;
+                };
+                let Some(mut inventory) = inventory else {
+                    denied_events.write(deny(InteractionDenial::NoInventory));
+                    continue;
+                };
+
+                inventory.add_stacked(pickup.item.clone(), &definitions);
+                consumed.insert(intent.target);
+                commands.entity(intent.target).despawn();
+
+                crate::logger::log(&format!(
+                    "🤲 {:?} picked up {:?} ({:?})",
+                    intent.actor, pickup.item.definition_id, intent.target
+                ));
+                pickup_events.write(ItemPickedUp {
+                    pickup: intent.target,
+                    actor: intent.actor,
+                    item: pickup.item.clone(),
+                });
+            }
+            InteractionKind::Container => {
+                let Some(container) = container else {
+                    continue;
+                };
+                if inventory.is_none() {
+                    denied_events.write(deny(InteractionDenial::NoInventory));
+                    continue;
+                }
+
+                commands.entity(intent.actor).insert(OpenContainer {
+                    container: intent.target,
+                });
+                opened_events.write(ContainerOpened {
+                    container: intent.target,
+                    actor: intent.actor,
+                    items: container.items.clone(),
+                });
+            }
+            InteractionKind::Switch => {
+                let Some(mut switch) = switch else {
+                    continue;
+                };
+                let on = switch.toggle();
+                switch_events.write(SwitchToggled {
+                    switch: intent.target,
+                    actor: intent.actor,
+                    on,
+                });
+            }
+        }
+    }
+}
+
+/// System: TakeFromContainerIntent → предметы из открытого контейнера в Inventory
+///
+/// - Нужен `OpenContainer` на этот контейнер и дистанция `Interactable::range`
+/// - Опустошённый контейнер: Interactable снят, труп исчезает через `LOOTED_LIFETIME`
+#[allow(clippy::too_many_arguments)]
+pub fn take_from_containers(
+    mut intents: EventReader<TakeFromContainerIntent>,
+    mut actors: Query<(&StrategicPosition, &Health, &mut Inventory, Option<&OpenContainer>)>,
+    mut containers: Query<(&Interactable, &mut Container, Has<Health>)>,
+    mut looted_events: EventWriter<ContainerLooted>,
+    mut denied_events: EventWriter<InteractionDenied>,
+    definitions: Res<ItemDefinitions>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((position, health, mut inventory, open)) = actors.get_mut(intent.actor) else {
+            continue;
+        };
+        if !health.is_alive() || open.map(|open| open.container) != Some(intent.container) {
+            continue;
+        }
+        let Ok((interactable, mut container, is_corpse)) = containers.get_mut(intent.container) else {
+            continue;
+        };
+        if container.is_empty() {
+            continue;
+        }
+
+        if !interactable.in_range(position.to_world_position(0.0)) {
+            denied_events.write(InteractionDenied {
+                actor: intent.actor,
+                target: intent.container,
+                reason: InteractionDenial::OutOfRange,
+            });
+            continue;
+        }
+
+        let items = match intent.index {
+            Some(index) => container.take(index).into_iter().collect(),
+            None => container.take_all(),
+        };
+        if items.is_empty() {
+            continue;
+        }
+        for item in items.iter() {
+            inventory.add_stacked(item.clone(), &definitions);
+        }
+
+        let emptied = container.is_empty();
+        if emptied {
+            commands.entity(intent.actor).remove::<OpenContainer>();
+            let mut container_commands = commands.entity(intent.container);
+            container_commands.remove::<(Container, Interactable)>();
+            // Обысканный труп исчезает быстро (ящик уровня остаётся нодой в Godot)
+            if is_corpse {
+                container_commands.insert(DespawnAfter {
+                    despawn_time: time.elapsed_secs() + Container::LOOTED_LIFETIME,
+                });
+            }
+        }
+
+        crate::logger::log(&format!(
+            "🎒 {:?} took {} items from {:?}{}",
+            intent.actor,
+            items.len(),
+            intent.container,
+            if emptied { " (emptied)" } else { "" }
+        ));
+        looted_events.write(ContainerLooted {
+            container: intent.container,
+            actor: intent.actor,
+            items,
+            emptied,
+        });
+    }
+}
+
+/// System: OpenContainer снимается, если актор ушёл / контейнер исчез или опустел
+pub fn close_distant_containers(
+    actors: Query<(Entity, &StrategicPosition, &OpenContainer)>,
+    containers: Query<(&Interactable, &Container)>,
+    mut commands: Commands,
+) {
+    for (actor, position, open) in actors.iter() {
+        let still_open = containers.get(open.container).is_ok_and(|(interactable, container)| {
+            !container.is_empty() && interactable.in_range(position.to_world_position(0.0))
+        });
+        if !still_open {
+            commands.entity(actor).remove::<OpenContainer>();
+        }
+    }
+}
+
+/// System: новые ящики с `LootTableRef` → содержимое из LootTable
+pub fn fill_containers_from_loot_tables(
+    mut containers: Query<(Entity, &LootTableRef, &mut Container), Added<LootTableRef>>,
+    loot_table: Res<LootTable>,
+    definitions: Res<ItemDefinitions>,
+    mut rng: ResMut<DeterministicRng>,
+) {
+    for (entity, table, mut container) in containers.iter_mut() {
+        let rolled = loot_table.roll_with_affixes(&table.0, &definitions, &mut rng.rng);
+        crate::logger::log(&format!(
+            "🎲 Container {:?} filled from '{}': {} items",
+            entity,
+            table.0,
+            rolled.len()
+        ));
+        container.items.extend(rolled);
+    }
+}
+
+/// System: DropItemIntent → предмет из Inventory в мир (WorldItem у ног актора)
+pub fn drop_items(
+    mut intents: EventReader<DropItemIntent>,
+    mut actors: Query<(&StrategicPosition, &mut Inventory)>,
+    definitions: Res<ItemDefinitions>,
+    mut dropped_events: EventWriter<ItemDropped>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((position, mut inventory)) = actors.get_mut(intent.actor) else {
+            continue;
+        };
+        let Some(item) = inventory.remove_item(intent.inventory_index) else {
+            continue;
+        };
+
+        let origin = position.to_world_position(0.0);
+        let world_item = spawn_world_item(&mut commands, &definitions, item.clone(), origin);
+        dropped_events.write(ItemDropped {
+            world_item,
+            actor: intent.actor,
+            item,
+        });
+    }
+}
+
+/// System: EntityDied → снаряжение и инвентарь трупа в Container ([F] — обыскать)
+///
+/// - Inventory, все слоты оружия, расходники → `Container` на трупе
+/// - `LootTableRef` актора → дополнительный бросок LootTable (оружие / броня — с редкостью и affixes)
+/// - Труп с лутом: `DespawnAfter` = `CORPSE_LIFETIME` (без лута Godot ставит свой короткий таймер)
+/// - Игрок не лутается (лут теряется в `resolve_extraction_run`)
+pub fn create_corpse_loot(
+    mut deaths: EventReader<EntityDied>,
+    mut actors: Query<
+        (
+            &StrategicPosition,
+            Option<&mut Inventory>,
+            Option<&mut EquippedWeapons>,
+            Option<&mut ConsumableSlots>,
+            Option<&LootTableRef>,
+        ),
+        Without<Player>,
+    >,
+    loot_table: Res<LootTable>,
+    definitions: Res<ItemDefinitions>,
+    mut rng: ResMut<DeterministicRng>,
+    time: Res<Time>,
+    mut commands: Commands,
+) {
+    for death in deaths.read() {
+        let Ok((position, inventory, weapons, consumables, table)) = actors.get_mut(death.entity) else {
+            continue;
+        };
+
+        let mut loot = Vec::new();
+        if let Some(mut inventory) = inventory {
+            loot.append(&mut inventory.items);
+        }
+        if let Some(mut weapons) = weapons {
+            for slot in 0..4 {
+                let Some(weapon) = weapons.get_slot(slot).cloned() else {
+                    continue;
+                };
+                weapons.set_slot(slot, None);
+                loot.push(weapon.to_instance());
+            }
+        }
+        if let Some(mut consumables) = consumables {
+            loot.extend((0..consumables.slots.len() as u8).filter_map(|slot| consumables.take_slot(slot)));
+        }
+        if let Some(table) = table {
+            loot.extend(loot_table.roll_with_affixes(&table.0, &definitions, &mut rng.rng));
+        }
+
+        if loot.is_empty() {
+            continue;
+        }
+
+        crate::logger::log(&format!("💰 {:?} died — corpse holds {} items", death.entity, loot.len()));
+        commands.entity(death.entity).insert((
+            Container { items: loot },
+            Interactable::new(InteractionKind::Container, position.to_world_position(0.0)),
+            DespawnAfter {
+                despawn_time: time.elapsed_secs() + Container::CORPSE_LIFETIME,
+            },
+        ));
+    }
+}
+
+/// WorldItem с prefab'ом из ItemDefinition (нет definition / prefab → placeholder в Godot)
+fn spawn_world_item(
+    commands: &mut Commands,
+    definitions: &ItemDefinitions,
+    item: ItemInstance,
+    position: Vec3,
+) -> Entity {
+    let prefab_path = definitions
+        .get(&item.definition_id)
+        .and_then(|definition| definition.prefab_path.clone());
+    commands.spawn(WorldItem::bundle(item, position, prefab_path)).id()
+}
@@ -0,0 +1,153 @@
+//! Tests for corpse loot containers (лут трупа, открытие, обыск).
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::prelude::*;
+    use crate::combat::{DespawnAfter, EntityDied};
+    use crate::components::{Health, Inventory};
+    use crate::item_system::{ItemId, ItemInstance};
+    use crate::player::Player;
+    use crate::shared::{ConsumableSlots, EquippedItem, EquippedWeapons};
+    use crate::{create_test_app, drain_events, StrategicPosition};
+    use super::super::components::{Container, Interactable, InteractionKind, OpenContainer};
+    use super::super::events::{
+        ContainerLooted, ContainerOpened, InteractIntent, InteractionDenial, InteractionDenied,
+        TakeFromContainerIntent,
+    };
+    use super::super::systems::{create_corpse_loot, process_interact_intents, take_from_containers};
+
+    fn at(x: f32, z: f32) -> StrategicPosition {
+        StrategicPosition::from_world_position(Vec3::new(x, 0.0, z))
+    }
+
+    fn kill(world: &mut World, entity: Entity) {
+        world.send_event(EntityDied { entity, killer: None });
+        world.run_system_once(create_corpse_loot).unwrap();
+    }
+
+    fn spawn_corpse(world: &mut World, items: Vec<ItemInstance>) -> Entity {
+        world
+            .spawn((
+                Health { current: 0, max: 100 },
+                Container { items },
+                Interactable::new(InteractionKind::Container, Vec3::ZERO),
+            ))
+            .id()
+    }
+
+    fn spawn_looter(world: &mut World, x: f32) -> Entity {
+        world.spawn((at(x, 0.0), Health::new(100), Inventory::empty())).id()
+    }
+
+    #[test]
+    fn test_npc_corpse_collects_inventory_weapons_and_consumables() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let mut inventory = Inventory::empty();
+        inventory.add_item(ItemInstance::new("health_kit"));
+        let mut weapons = EquippedWeapons::empty();
+        weapons.set_slot(0, Some(EquippedItem::from_instance(&ItemInstance::new("melee_sword"))));
+        let mut consumables = ConsumableSlots::empty();
+        consumables.set_slot(1, Some(ItemInstance::new("health_kit")));
+        let npc = world.spawn((at(4.0, 2.0), inventory, weapons, consumables)).id();
+
+        kill(world, npc);
+
+        let container = world.get::<Container>(npc).expect("corpse container");
+        assert_eq!(container.items.len(), 3);
+        assert!(container.items.iter().any(|item| item.definition_id == ItemId::from("melee_sword")));
+
+        let interactable = world.get::<Interactable>(npc).unwrap();
+        assert_eq!(interactable.kind, InteractionKind::Container);
+        assert_eq!(interactable.position, Vec3::new(4.0, 0.0, 2.0));
+        assert_eq!(world.get::<DespawnAfter>(npc).unwrap().despawn_time, Container::CORPSE_LIFETIME);
+
+        // Предметы перенесены, а не скопированы
+        assert!(world.get::<Inventory>(npc).unwrap().items.is_empty());
+        assert!(world.get::<EquippedWeapons>(npc).unwrap().get_slot(0).is_none());
+        assert!(world.get::<ConsumableSlots>(npc).unwrap().get_slot(1).is_none());
+    }
+
+    #[test]
+    fn test_no_container_for_player_or_empty_handed_death() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let mut inventory = Inventory::empty();
+        inventory.add_item(ItemInstance::new("health_kit"));
+        let player = world.spawn((Player, at(0.0, 0.0), inventory)).id();
+        let empty_handed = world.spawn((at(1.0, 0.0), Inventory::empty(), EquippedWeapons::empty())).id();
+
+        kill(world, player);
+        kill(world, empty_handed);
+
+        assert!(world.get::<Container>(player).is_none());
+        assert_eq!(world.get::<Inventory>(player).unwrap().items.len(), 1);
+        assert!(world.get::<Container>(empty_handed).is_none());
+        assert!(world.get::<DespawnAfter>(empty_handed).is_none());
+    }
+
+    #[test]
+    fn test_open_then_take_one_then_empty_corpse() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let corpse = spawn_corpse(world, vec![ItemInstance::new("melee_sword"), ItemInstance::new("pistol_basic")]);
+        let looter = spawn_looter(world, 1.0);
+
+        world.send_event(InteractIntent { actor: looter, target: corpse });
+        world.run_system_once(process_interact_intents).unwrap();
+        assert_eq!(world.get::<OpenContainer>(looter).unwrap().container, corpse);
+        let opened = drain_events::<ContainerOpened>(world);
+        assert_eq!(opened.len(), 1);
+        assert_eq!(opened[0].items.len(), 2);
+        // Открытие ничего не забирает
+        assert_eq!(world.get::<Container>(corpse).unwrap().items.len(), 2);
+
+        world.send_event(TakeFromContainerIntent { actor: looter, container: corpse, index: Some(0) });
+        world.run_system_once(take_from_containers).unwrap();
+        let looted = drain_events::<ContainerLooted>(world);
+        assert_eq!(looted.len(), 1);
+        assert!(!looted[0].emptied);
+        assert_eq!(looted[0].items[0].definition_id, ItemId::from("melee_sword"));
+        assert_eq!(world.get::<Container>(corpse).unwrap().items.len(), 1);
+        assert!(world.get::<OpenContainer>(looter).is_some());
+
+        world.send_event(TakeFromContainerIntent { actor: looter, container: corpse, index: None });
+        world.run_system_once(take_from_containers).unwrap();
+        let looted = drain_events::<ContainerLooted>(world);
+        assert_eq!(looted.len(), 1);
+        assert!(looted[0].emptied);
+
+        assert_eq!(world.get::<Inventory>(looter).unwrap().items.len(), 2);
+        assert!(world.get::<OpenContainer>(looter).is_none());
+        assert!(world.get::<Container>(corpse).is_none());
+        assert!(world.get::<Interactable>(corpse).is_none());
+        assert_eq!(world.get::<DespawnAfter>(corpse).unwrap().despawn_time, Container::LOOTED_LIFETIME);
+    }
+
+    #[test]
+    fn test_take_requires_open_container_and_range() {
+        let mut app = create_test_app();
+        let world = app.world_mut();
+        let corpse = spawn_corpse(world, vec![ItemInstance::new("health_kit")]);
+        let stranger = spawn_looter(world, 1.0);
+        let walked_away = spawn_looter(world, 1.0);
+
+        // Без OpenContainer intent игнорируется
+        world.send_event(TakeFromContainerIntent { actor: stranger, container: corpse, index: None });
+        world.run_system_once(take_from_containers).unwrap();
+        assert!(drain_events::<ContainerLooted>(world).is_empty());
+        assert!(drain_events::<InteractionDenied>(world).is_empty());
+
+        // Открыл и отошёл → OutOfRange, контейнер не тронут
+        world.entity_mut(walked_away).insert((OpenContainer { container: corpse }, at(10.0, 0.0)));
+        world.send_event(TakeFromContainerIntent { actor: walked_away, container: corpse, index: None });
+        world.run_system_once(take_from_containers).unwrap();
+        assert!(drain_events::<ContainerLooted>(world).is_empty());
+        let denied = drain_events::<InteractionDenied>(world);
+        assert_eq!(denied.len(), 1);
+        assert_eq!(denied[0].reason, InteractionDenial::OutOfRange);
+        assert_eq!(world.get::<Container>(corpse).unwrap().items.len(), 1);
+        assert!(world.get::<Inventory>(walked_away).unwrap().items.is_empty());
+    }
+}
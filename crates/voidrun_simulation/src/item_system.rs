@@ -10,18 +10,19 @@
 //! **ItemInstance** — runtime конкретный предмет:
 //! - Ссылается на `ItemDefinition` через `ItemId`
 //! - Mutable state (durability, ammo_count, stack_size)
+//! - Редкость + affixes (бросок при дропе из DeterministicRng, применяются при equip)
 //! - Хранится в `Inventory`, `EquippedWeapons`, `ConsumableSlots`
 //!
 //! **ItemType** — категории предметов:
 //! - Weapon (Large/Small) → EquippedWeapons (slots 1-4)
 //! - Consumable → ConsumableSlots (slots 5-9)
-//! - Armor → Armor компонент
+//! - Armor → EquippedArmor (слоты Helmet / Chest / Legs / Boots, бонус полного комплекта)
 //! - Shield → физический щит (not EnergyShield!)
 //!
 //! # Пример использования
 //!
 //! ```rust
-//! use voidrun_simulation::{ItemDefinitions, ItemInstance, ItemId};
+//! use voidrun_simulation::{ItemDefinitions, ItemInstance, ItemId, Rarity};
 //!
 //! // Lookup definition
 //! let definitions = ItemDefinitions::default();
@@ -33,6 +34,8 @@
 //!     stack_size: 1,
 //!     durability: Some(0.8), // 80% durability
 //!     ammo_count: None,
+//!     rarity: Rarity::Common,
+//!     affixes: Vec::new(),
 //! };
 //!
 //! // Get weapon template
@@ -42,8 +45,10 @@
 //! ```
 
 use bevy::prelude::*;
+use rand::Rng;
 use std::collections::HashMap;
-use crate::combat::{WeaponStats, WeaponType};
+use crate::combat::{DamageFalloff, FireMode, ProjectileBallistics, RecoilPattern, AdsConfig, MeleeCleave, WeaponHeat, WeaponStats, WeaponType};
+use crate::shared::equipment::{EnergyShield, ShieldCoverage};
 
 // ============================================================================
 // ItemId
@@ -80,6 +85,8 @@ pub enum ItemType {
     Shield,
     /// Consumable (health kit, grenade, etc.)
     Consumable,
+    /// Метательное оружие (нож, копьё): из consumable слота, после броска — WorldItem где упал
+    Throwable,
     /// Craft material (для крафта)
     CraftMaterial,
     /// Quest item
@@ -95,6 +102,106 @@ pub enum WeaponSize {
     Small,
 }
 
+// ============================================================================
+// Rarity + Affixes
+// ============================================================================
+
+/// Редкость предмета (число affixes при дропе)
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+pub enum Rarity {
+    #[default]
+    Common,
+    Uncommon,
+    Rare,
+    Epic,
+}
+
+impl Rarity {
+    /// Веса броска (Common..Epic)
+    const WEIGHTS: [u32; 4] = [70, 20, 8, 2];
+
+    /// Число affixes предмета этой редкости
+    pub fn affix_count(self) -> usize {
+        match self {
+            Self::Common => 0,
+            Self::Uncommon => 1,
+            Self::Rare => 2,
+            Self::Epic => 3,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Common => "Common",
+            Self::Uncommon => "Uncommon",
+            Self::Rare => "Rare",
+            Self::Epic => "Epic",
+        }
+    }
+
+    /// Бросок редкости не ниже `floor`
+    pub fn roll(floor: Rarity, rng: &mut impl Rng) -> Self {
+        let total: u32 = Self::WEIGHTS.iter().sum();
+        let mut pick = rng.gen_range(0..total);
+        let mut rolled = Self::Common;
+        for (rarity, weight) in [Self::Common, Self::Uncommon, Self::Rare, Self::Epic].into_iter().zip(Self::WEIGHTS) {
+            if pick < weight {
+                rolled = rarity;
+                break;
+            }
+            pick -= weight;
+        }
+        rolled.max(floor)
+    }
+}
+
+/// Процедурный модификатор характеристик (бросается при дропе)
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+pub enum Affix {
+    /// +% урона оружия
+    DamagePercent(f32),
+    /// −% cooldown атаки оружия
+    AttackSpeedPercent(f32),
+    /// +% регенерации stamina (броня)
+    StaminaRegenPercent(f32),
+    /// +defense брони
+    Defense(u32),
+}
+
+impl Affix {
+    /// Бросок affix для типа предмета (`None` — тип без affixes)
+    pub fn roll(item_type: &ItemType, rng: &mut impl Rng) -> Option<Self> {
+        match item_type {
+            ItemType::Weapon { .. } => Some(if rng.gen_bool(0.5) {
+                Self::DamagePercent(rng.gen_range(5..=20) as f32 / 100.0)
+            } else {
+                Self::AttackSpeedPercent(rng.gen_range(5..=15) as f32 / 100.0)
+            }),
+            ItemType::Armor => Some(if rng.gen_bool(0.5) {
+                Self::StaminaRegenPercent(rng.gen_range(10..=30) as f32 / 100.0)
+            } else {
+                Self::Defense(rng.gen_range(3..=10))
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Применить weapon affixes к WeaponStats (урон, cooldown)
+pub fn apply_weapon_affixes(stats: &mut WeaponStats, affixes: &[Affix]) {
+    for affix in affixes {
+        match *affix {
+            Affix::DamagePercent(bonus) => {
+                stats.base_damage = (stats.base_damage as f32 * (1.0 + bonus)).round() as u32;
+            }
+            Affix::AttackSpeedPercent(bonus) => {
+                stats.attack_cooldown *= (1.0 - bonus).max(0.1);
+            }
+            Affix::StaminaRegenPercent(_) | Affix::Defense(_) => {}
+        }
+    }
+}
+
 // ============================================================================
 // ItemDefinition (статические данные)
 // ============================================================================
@@ -111,6 +218,12 @@ pub struct ItemDefinition {
     pub name: String,
     /// Тип предмета
     pub item_type: ItemType,
+    /// Минимальная редкость при дропе (бросок не опускает ниже)
+    pub rarity: Rarity,
+    /// Вес одной штуки (кг, стак — × stack_size)
+    pub weight: f32,
+    /// Максимум штук в одном слоте Inventory (1 — не стакается)
+    pub max_stack: u32,
 
     // === Weapon-specific ===
     /// Weapon stats template (для создания WeaponStats компонента)
@@ -124,6 +237,10 @@ pub struct ItemDefinition {
     /// Armor stats template
     pub armor_stats: Option<ArmorStatsTemplate>,
 
+    // === Throwable-specific ===
+    /// Параметры броска (урон, скорость)
+    pub throwable_stats: Option<ThrowableStats>,
+
     // === Consumable-specific ===
     /// Consumable effect
     pub consumable_effect: Option<ConsumableEffect>,
@@ -141,6 +258,7 @@ pub struct ItemDefinition {
 /// - Композиция через `WeaponStats` (избегаем дублирования 15 полей)
 /// - Template = base stats (immutable)
 /// - WeaponStats = base + runtime state (cooldown_timer)
+/// - Режим огня (`stats.fire_mode`): Single / Burst / Auto / Shotgun
 #[derive(Clone, Debug, Reflect)]
 pub struct WeaponStatsTemplate {
     /// Base weapon stats (immutable)
@@ -155,6 +273,28 @@ impl WeaponStatsTemplate {
         stats
     }
 
+    /// WeaponStats с affixes экземпляра (урон, скорость атаки)
+    pub fn to_weapon_stats_with_affixes(&self, affixes: &[Affix]) -> WeaponStats {
+        let mut stats = self.to_weapon_stats();
+        apply_weapon_affixes(&mut stats, affixes);
+        stats
+    }
+
+    /// Режим огня оружия
+    pub fn fire_mode(&self) -> FireMode {
+        self.stats.fire_mode
+    }
+
+    /// Паттерн отдачи оружия
+    pub fn recoil(&self) -> RecoilPattern {
+        self.stats.recoil
+    }
+
+    /// Прицел оружия (ADS зум / вскидка / чувствительность)
+    pub fn ads(&self) -> AdsConfig {
+        self.stats.ads
+    }
+
     /// Melee sword preset
     pub fn melee_sword() -> Self {
         Self {
@@ -183,6 +323,13 @@ impl WeaponStatsTemplate {
                 range: 0.0,
                 projectile_speed: 0.0,
                 hearing_range: 0.0,
+                heat: WeaponHeat::none(),
+                ballistics: ProjectileBallistics::none(),
+                falloff: DamageFalloff::none(),
+                fire_mode: FireMode::Single,
+                recoil: RecoilPattern::none(),
+                ads: AdsConfig::iron_sights(),
+                cleave: MeleeCleave::single_target(), // Укол — одно тело
             },
         }
     }
@@ -212,22 +359,163 @@ impl WeaponStatsTemplate {
                 range: 50.0,
                 projectile_speed: 500.0,
                 hearing_range: 200.0,
+                heat: WeaponHeat::none(),
+                ballistics: ProjectileBallistics::new(1.5, 20.0).with_drop(9.8, 0.1), // Винтовка прошивает тело насквозь
+                falloff: DamageFalloff::new(30.0, 50.0, 0.6),
+                fire_mode: FireMode::Burst { shots: 3, interval: 0.12 }, // Очередь по 3, cooldown между очередями
+                recoil: RecoilPattern::new(1.2, 0.6, 0.8, 3.0, 4.0), // Третий выстрел очереди — уже выше и шире
+                ads: AdsConfig { fov: 55.0, transition_time: 0.35, sensitivity_multiplier: 0.65 },
+                cleave: MeleeCleave::new(2, 0.5),
             },
         }
     }
+
+    /// Shotgun preset (дробь: 8 дробин, сильный урон вблизи)
+    pub fn ranged_shotgun() -> Self {
+        Self {
+            stats: WeaponStats {
+                weapon_type: WeaponType::Ranged,
+                base_damage: 6, // На дробину
+                attack_cooldown: 0.9,
+                cooldown_timer: 0.0,
+                attack_radius: 0.0,
+                windup_duration: 0.0,
+                attack_duration: 0.0,
+                recovery_duration: 0.0,
+                parry_window: 0.0,
+                parry_active_duration: 0.0,
+                stagger_duration: 0.0,
+                range: 15.0,
+                projectile_speed: 60.0,
+                hearing_range: 150.0,
+                heat: WeaponHeat::none(),
+                ballistics: ProjectileBallistics::new(0.0, 10.0).with_drop(2.0, 0.3), // Дробь не пробивает, быстро тормозит
+                falloff: DamageFalloff::new(5.0, 15.0, 0.25),
+                fire_mode: FireMode::Shotgun { pellets: 8, spread_degrees: 6.0 },
+                recoil: RecoilPattern::new(5.0, 1.0, 2.0, 4.0, 3.0), // Сильный толчок, но выстрел редкий
+                ads: AdsConfig { fov: 75.0, transition_time: 0.3, sensitivity_multiplier: 0.9 }, // Мушка, зум почти не нужен
+                cleave: MeleeCleave::new(2, 0.5),
+            },
+        }
+    }
+
+    /// Sniper rifle preset (оптика с сильным зумом, мощный редкий выстрел)
+    pub fn ranged_sniper() -> Self {
+        Self {
+            stats: WeaponStats {
+                base_damage: 60,
+                attack_cooldown: 1.6,
+                range: 120.0,
+                projectile_speed: 800.0,
+                hearing_range: 300.0,
+                ballistics: ProjectileBallistics::new(2.5, 10.0).with_drop(9.8, 0.02), // Прошивает укрытия, почти не тормозит
+                falloff: DamageFalloff::none(),
+                fire_mode: FireMode::Single,
+                recoil: RecoilPattern::new(6.0, 0.8, 3.0, 6.0, 3.0), // Каждый выстрел сбивает прицел
+                ads: AdsConfig::scope(18.0), // ×5 оптика
+                ..Self::ranged_rifle().stats
+            },
+        }
+    }
+
+    /// Plasma rifle preset (энергетическое, перегревается)
+    pub fn plasma_rifle() -> Self {
+        Self {
+            stats: WeaponStats::energy_rifle(),
+        }
+    }
 }
 
 // ============================================================================
 // ArmorStatsTemplate
 // ============================================================================
 
+/// Слот брони (часть тела)
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Reflect)]
+pub enum ArmorSlot {
+    Helmet,
+    Chest,
+    Legs,
+    Boots,
+}
+
+impl ArmorSlot {
+    pub const ALL: [ArmorSlot; 4] = [ArmorSlot::Helmet, ArmorSlot::Chest, ArmorSlot::Legs, ArmorSlot::Boots];
+
+    /// Индекс в `EquippedArmor::pieces`
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Attachment point визуала на actor prefab
+    pub fn attachment_point(self) -> &'static str {
+        match self {
+            ArmorSlot::Helmet => "%HelmetAttachment",
+            ArmorSlot::Chest => "%ChestAttachment",
+            ArmorSlot::Legs => "%LegsAttachment",
+            ArmorSlot::Boots => "%BootsAttachment",
+        }
+    }
+}
+
+/// Бонус полного комплекта (все 4 слота с одним `set_id`)
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+pub struct ArmorSetBonus {
+    pub name: String,
+    pub defense: u32,
+    pub stamina_regen_bonus: f32,
+    pub oxygen_bonus: f32,
+    pub insulation: f32,
+}
+
 /// Armor stats template
 #[derive(Clone, Debug, Reflect)]
 pub struct ArmorStatsTemplate {
+    /// Слот (часть тела)
+    pub slot: ArmorSlot,
+    /// Комплект (None — одиночный предмет)
+    pub set_id: Option<String>,
     /// Defense rating (damage reduction)
     pub defense: u32,
     /// Consumable slot bonus (0-3 доп слота)
     pub consumable_slot_bonus: u8,
+    /// Запас кислорода шлема (секунды сверх базового Oxygen)
+    pub oxygen_bonus: f32,
+    /// Теплоизоляция (0..1): доля холода, которую броня гасит (BodyTemperature)
+    pub insulation: f32,
+    /// Генератор энергощита (None — обычная броня)
+    pub shield: Option<ShieldTemplate>,
+}
+
+/// Генератор энергощита (часть брони): ёмкость, перезарядка, покрытие
+#[derive(Clone, Debug, PartialEq, Reflect)]
+pub struct ShieldTemplate {
+    pub max_energy: f32,
+    /// Энергия/сек вне боя
+    pub recharge_rate: f32,
+    /// Секунды после попадания до начала перезарядки
+    pub recharge_delay: f32,
+    pub coverage: ShieldCoverage,
+}
+
+impl ShieldTemplate {
+    /// Новый EnergyShield (полный заряд)
+    pub fn to_energy_shield(&self) -> EnergyShield {
+        EnergyShield::new(self.max_energy, self.recharge_rate, self.recharge_delay).with_coverage(self.coverage)
+    }
+}
+
+// ============================================================================
+// ThrowableStats
+// ============================================================================
+
+/// Параметры метательного оружия (ThrowIntent)
+#[derive(Clone, Debug, Reflect)]
+pub struct ThrowableStats {
+    /// Урон при попадании (melee-класс: броня, добивание, flinch — как у удара)
+    pub damage: u32,
+    /// Начальная скорость броска (м/с, дальше — баллистика)
+    pub speed: f32,
 }
 
 // ============================================================================
@@ -243,6 +531,14 @@ pub enum ConsumableEffect {
     RestoreStamina { amount: u32 },
     /// Spawn projectile (grenade)
     SpawnProjectile { prefab_path: String, damage: u32 },
+    /// Дымовое облако (блокирует LOS на время)
+    DeploySmoke { radius: f32, duration: f32 },
+    /// Светошумовая вспышка (ослепляет смотрящих на неё)
+    Flashbang { radius: f32, max_duration: f32 },
+    /// EMP импульс (обнуляет энергощиты и блокирует recharge, оглушает электронику)
+    Emp { radius: f32, duration: f32 },
+    /// Ремонт активного оружия (+прочность, клин не устраняет)
+    RepairWeapon { amount: f32 },
 }
 
 // ============================================================================
@@ -253,7 +549,7 @@ pub enum ConsumableEffect {
 ///
 /// Хранится в `Inventory`, `EquippedWeapons`, `ConsumableSlots`.
 /// Mutable state (durability, ammo, stack size).
-#[derive(Clone, Debug, Reflect)]
+#[derive(Clone, Debug, PartialEq, Reflect)]
 pub struct ItemInstance {
     /// Ссылка на definition
     pub definition_id: ItemId,
@@ -263,6 +559,10 @@ pub struct ItemInstance {
     pub durability: Option<f32>,
     /// Ammo count (для ranged weapons)
     pub ammo_count: Option<u32>,
+    /// Редкость (бросается при дропе)
+    pub rarity: Rarity,
+    /// Rolled affixes (применяются при equip)
+    pub affixes: Vec<Affix>,
 }
 
 impl ItemInstance {
@@ -273,6 +573,8 @@ impl ItemInstance {
             stack_size: 1,
             durability: Some(1.0), // Полная прочность
             ammo_count: None,
+            rarity: Rarity::Common,
+            affixes: Vec::new(),
         }
     }
 
@@ -283,9 +585,22 @@ impl ItemInstance {
             stack_size: 1,
             durability: Some(1.0),
             ammo_count: Some(ammo),
+            rarity: Rarity::Common,
+            affixes: Vec::new(),
         }
     }
 
+    /// Можно сложить в один стак: тот же предмет, без прочности/патронов, одинаковые редкость и affixes
+    pub fn can_stack_with(&self, other: &ItemInstance) -> bool {
+        self.definition_id == other.definition_id
+            && self.durability.is_none()
+            && other.durability.is_none()
+            && self.ammo_count.is_none()
+            && other.ammo_count.is_none()
+            && self.rarity == other.rarity
+            && self.affixes == other.affixes
+    }
+
     /// Создать consumable stack
     pub fn consumable_stack(definition_id: impl Into<ItemId>, count: u32) -> Self {
         Self {
@@ -293,6 +608,8 @@ impl ItemInstance {
             stack_size: count,
             durability: None,
             ammo_count: None,
+            rarity: Rarity::Common,
+            affixes: Vec::new(),
         }
     }
 }
@@ -308,6 +625,8 @@ impl ItemInstance {
 #[derive(Resource, Clone, Debug)]
 pub struct ItemDefinitions {
     definitions: HashMap<ItemId, ItemDefinition>,
+    /// Бонусы комплектов брони по set_id
+    armor_sets: HashMap<String, ArmorSetBonus>,
 }
 
 impl ItemDefinitions {
@@ -315,6 +634,7 @@ impl ItemDefinitions {
     pub fn new() -> Self {
         Self {
             definitions: HashMap::new(),
+            armor_sets: HashMap::new(),
         }
     }
 
@@ -328,10 +648,40 @@ impl ItemDefinitions {
         self.definitions.insert(definition.id.clone(), definition);
     }
 
+    /// Бонус комплекта брони
+    pub fn armor_set(&self, set_id: &str) -> Option<&ArmorSetBonus> {
+        self.armor_sets.get(set_id)
+    }
+
+    /// Добавить комплект брони
+    pub fn add_armor_set(&mut self, set_id: impl Into<String>, bonus: ArmorSetBonus) {
+        self.armor_sets.insert(set_id.into(), bonus);
+    }
+
+    /// Размер стака предмета (неизвестный — 1)
+    pub fn max_stack(&self, id: &ItemId) -> u32 {
+        self.get(id).map_or(1, |definition| definition.max_stack.max(1))
+    }
+
     /// Получить все IDs
     pub fn all_ids(&self) -> Vec<&ItemId> {
         self.definitions.keys().collect()
     }
+
+    /// Бросить редкость + affixes свежему дропу (оружие / броня, не ниже `ItemDefinition::rarity`)
+    pub fn roll_affixes(&self, item: &mut ItemInstance, rng: &mut impl Rng) {
+        let Some(definition) = self.get(&item.definition_id) else {
+            return;
+        };
+        if !matches!(definition.item_type, ItemType::Weapon { .. } | ItemType::Armor) {
+            return;
+        }
+
+        item.rarity = Rarity::roll(definition.rarity, rng);
+        item.affixes = (0..item.rarity.affix_count())
+            .filter_map(|_| Affix::roll(&definition.item_type, rng))
+            .collect();
+    }
 }
 
 impl Default for ItemDefinitions {
@@ -348,10 +698,14 @@ impl Default for ItemDefinitions {
             item_type: ItemType::Weapon {
                 size: WeaponSize::Large,
             },
+            rarity: Rarity::Common,
+            weight: 3.0,
+            max_stack: 1,
             weapon_template: Some(WeaponStatsTemplate::melee_sword()),
             prefab_path: Some("res://actors/test_sword.tscn".to_string()),
             attachment_point: Some("%RightHandAttachment".to_string()),
             armor_stats: None,
+            throwable_stats: None,
             consumable_effect: None,
         });
 
@@ -362,10 +716,14 @@ impl Default for ItemDefinitions {
             item_type: ItemType::Weapon {
                 size: WeaponSize::Small,
             },
+            rarity: Rarity::Common,
+            weight: 0.8,
+            max_stack: 1,
             weapon_template: Some(WeaponStatsTemplate::dagger()),
             prefab_path: Some("res://actors/test_sword.tscn".to_string()), // Временно используем sword model
             attachment_point: Some("%RightHandAttachment".to_string()),
             armor_stats: None,
+            throwable_stats: None,
             consumable_effect: None,
         });
 
@@ -376,10 +734,14 @@ impl Default for ItemDefinitions {
             item_type: ItemType::Weapon {
                 size: WeaponSize::Small,
             },
+            rarity: Rarity::Common,
+            weight: 1.2,
+            max_stack: 1,
             weapon_template: Some(WeaponStatsTemplate::ranged_pistol()),
             prefab_path: Some("res://actors/test_pistol.tscn".to_string()),
             attachment_point: Some("%RightHandAttachment".to_string()),
             armor_stats: None,
+            throwable_stats: None,
             consumable_effect: None,
         });
 
@@ -390,10 +752,68 @@ impl Default for ItemDefinitions {
             item_type: ItemType::Weapon {
                 size: WeaponSize::Large,
             },
+            rarity: Rarity::Common,
+            weight: 4.0,
+            max_stack: 1,
             weapon_template: Some(WeaponStatsTemplate::ranged_rifle()),
             prefab_path: Some("res://actors/test_pistol.tscn".to_string()), // Временно используем pistol model
             attachment_point: Some("%RightHandAttachment".to_string()),
             armor_stats: None,
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // Shotgun (large, дробь)
+        defs.add(ItemDefinition {
+            id: "shotgun_basic".into(),
+            name: "Pump Shotgun".to_string(),
+            item_type: ItemType::Weapon {
+                size: WeaponSize::Large,
+            },
+            rarity: Rarity::Common,
+            weight: 3.5,
+            max_stack: 1,
+            weapon_template: Some(WeaponStatsTemplate::ranged_shotgun()),
+            prefab_path: Some("res://actors/test_pistol.tscn".to_string()), // Временно используем pistol model
+            attachment_point: Some("%RightHandAttachment".to_string()),
+            armor_stats: None,
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // Sniper rifle (large, оптика — сильный ADS зум)
+        defs.add(ItemDefinition {
+            id: "sniper_basic".into(),
+            name: "Marksman Rifle".to_string(),
+            item_type: ItemType::Weapon {
+                size: WeaponSize::Large,
+            },
+            rarity: Rarity::Uncommon,
+            weight: 5.5,
+            max_stack: 1,
+            weapon_template: Some(WeaponStatsTemplate::ranged_sniper()),
+            prefab_path: Some("res://actors/test_pistol.tscn".to_string()), // Временно используем pistol model
+            attachment_point: Some("%RightHandAttachment".to_string()),
+            armor_stats: None,
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // Plasma rifle (large, энергетическое — нагрев вместо патронов)
+        defs.add(ItemDefinition {
+            id: "plasma_rifle".into(),
+            name: "Plasma Rifle".to_string(),
+            item_type: ItemType::Weapon {
+                size: WeaponSize::Large,
+            },
+            rarity: Rarity::Uncommon,
+            weight: 4.5,
+            max_stack: 1,
+            weapon_template: Some(WeaponStatsTemplate::plasma_rifle()),
+            prefab_path: Some("res://actors/test_pistol.tscn".to_string()), // Временно используем pistol model
+            attachment_point: Some("%RightHandAttachment".to_string()),
+            armor_stats: None,
+            throwable_stats: None,
             consumable_effect: None,
         });
 
@@ -404,13 +824,22 @@ impl Default for ItemDefinitions {
             id: "armor_military".into(),
             name: "Military Combat Armor".to_string(),
             item_type: ItemType::Armor,
+            rarity: Rarity::Rare,
+            weight: 12.0,
+            max_stack: 1,
             weapon_template: None,
             prefab_path: None, // TODO: armor prefab
-            attachment_point: Some("%Body".to_string()),
+            attachment_point: Some(ArmorSlot::Chest.attachment_point().to_string()),
             armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Chest,
+                set_id: Some("military".to_string()),
                 defense: 50,
                 consumable_slot_bonus: 3, // Unlock все 5 слотов (2 базовых + 3 бонуса)
+                oxygen_bonus: 30.0, // Закрытый шлем
+                insulation: 0.4,
+                shield: None,
             }),
+            throwable_stats: None,
             consumable_effect: None,
         });
 
@@ -419,13 +848,22 @@ impl Default for ItemDefinitions {
             id: "armor_tactical".into(),
             name: "Tactical Vest".to_string(),
             item_type: ItemType::Armor,
+            rarity: Rarity::Common,
+            weight: 8.0,
+            max_stack: 1,
             weapon_template: None,
             prefab_path: None, // TODO: armor prefab
-            attachment_point: Some("%Body".to_string()),
+            attachment_point: Some(ArmorSlot::Chest.attachment_point().to_string()),
             armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Chest,
+                set_id: None,
                 defense: 30,
                 consumable_slot_bonus: 2, // Unlock 4 слота (2 + 2)
+                oxygen_bonus: 0.0,
+                insulation: 0.2,
+                shield: None,
             }),
+            throwable_stats: None,
             consumable_effect: None,
         });
 
@@ -434,13 +872,22 @@ impl Default for ItemDefinitions {
             id: "armor_light".into(),
             name: "Light Armor".to_string(),
             item_type: ItemType::Armor,
+            rarity: Rarity::Common,
+            weight: 4.0,
+            max_stack: 1,
             weapon_template: None,
             prefab_path: None, // TODO: armor prefab
-            attachment_point: Some("%Body".to_string()),
+            attachment_point: Some(ArmorSlot::Chest.attachment_point().to_string()),
             armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Chest,
+                set_id: None,
                 defense: 15,
                 consumable_slot_bonus: 1, // Unlock 3 слота (2 + 1)
+                oxygen_bonus: 0.0,
+                insulation: 0.1,
+                shield: None,
             }),
+            throwable_stats: None,
             consumable_effect: None,
         });
 
@@ -449,16 +896,270 @@ impl Default for ItemDefinitions {
             id: "armor_scrap".into(),
             name: "Scrap Armor".to_string(),
             item_type: ItemType::Armor,
+            rarity: Rarity::Common,
+            weight: 6.0,
+            max_stack: 1,
             weapon_template: None,
             prefab_path: None, // TODO: armor prefab
-            attachment_point: Some("%Body".to_string()),
+            attachment_point: Some(ArmorSlot::Chest.attachment_point().to_string()),
             armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Chest,
+                set_id: None,
                 defense: 5,
                 consumable_slot_bonus: 0, // Только базовые 2 слота
+                oxygen_bonus: 0.0,
+                insulation: 0.15,
+                shield: None,
             }),
+            throwable_stats: None,
             consumable_effect: None,
         });
 
+        // EVA suit (герметичный шлем — работа в вакууме)
+        defs.add(ItemDefinition {
+            id: "armor_eva".into(),
+            name: "EVA Suit".to_string(),
+            item_type: ItemType::Armor,
+            rarity: Rarity::Common,
+            weight: 15.0,
+            max_stack: 1,
+            weapon_template: None,
+            prefab_path: None, // TODO: armor prefab
+            attachment_point: Some(ArmorSlot::Chest.attachment_point().to_string()),
+            armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Chest,
+                set_id: Some("eva".to_string()),
+                defense: 10,
+                consumable_slot_bonus: 1, // Unlock 3 слота (2 + 1)
+                oxygen_bonus: 90.0, // Баллоны скафандра
+                insulation: 0.8,
+                shield: None,
+            }),
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // === ARMOR SETS (шлем / поножи / ботинки к нагрудникам) ===
+
+        // Military helmet
+        defs.add(ItemDefinition {
+            id: "helmet_military".into(),
+            name: "Military Helmet".to_string(),
+            item_type: ItemType::Armor,
+            rarity: Rarity::Rare,
+            weight: 2.5,
+            max_stack: 1,
+            weapon_template: None,
+            prefab_path: None, // TODO: armor prefab
+            attachment_point: Some(ArmorSlot::Helmet.attachment_point().to_string()),
+            armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Helmet,
+                set_id: Some("military".to_string()),
+                defense: 15,
+                consumable_slot_bonus: 0,
+                oxygen_bonus: 0.0,
+                insulation: 0.1,
+                shield: None,
+            }),
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // Military legs
+        defs.add(ItemDefinition {
+            id: "legs_military".into(),
+            name: "Military Leg Guards".to_string(),
+            item_type: ItemType::Armor,
+            rarity: Rarity::Rare,
+            weight: 4.0,
+            max_stack: 1,
+            weapon_template: None,
+            prefab_path: None, // TODO: armor prefab
+            attachment_point: Some(ArmorSlot::Legs.attachment_point().to_string()),
+            armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Legs,
+                set_id: Some("military".to_string()),
+                defense: 15,
+                consumable_slot_bonus: 0,
+                oxygen_bonus: 0.0,
+                insulation: 0.1,
+                shield: None,
+            }),
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // Military boots
+        defs.add(ItemDefinition {
+            id: "boots_military".into(),
+            name: "Military Boots".to_string(),
+            item_type: ItemType::Armor,
+            rarity: Rarity::Rare,
+            weight: 2.0,
+            max_stack: 1,
+            weapon_template: None,
+            prefab_path: None, // TODO: armor prefab
+            attachment_point: Some(ArmorSlot::Boots.attachment_point().to_string()),
+            armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Boots,
+                set_id: Some("military".to_string()),
+                defense: 10,
+                consumable_slot_bonus: 0,
+                oxygen_bonus: 0.0,
+                insulation: 0.05,
+                shield: None,
+            }),
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // EVA helmet (герметичный)
+        defs.add(ItemDefinition {
+            id: "helmet_eva".into(),
+            name: "EVA Helmet".to_string(),
+            item_type: ItemType::Armor,
+            rarity: Rarity::Common,
+            weight: 3.0,
+            max_stack: 1,
+            weapon_template: None,
+            prefab_path: None, // TODO: armor prefab
+            attachment_point: Some(ArmorSlot::Helmet.attachment_point().to_string()),
+            armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Helmet,
+                set_id: Some("eva".to_string()),
+                defense: 5,
+                consumable_slot_bonus: 0,
+                oxygen_bonus: 30.0,
+                insulation: 0.1,
+                shield: None,
+            }),
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // EVA legs
+        defs.add(ItemDefinition {
+            id: "legs_eva".into(),
+            name: "EVA Suit Legs".to_string(),
+            item_type: ItemType::Armor,
+            rarity: Rarity::Common,
+            weight: 4.0,
+            max_stack: 1,
+            weapon_template: None,
+            prefab_path: None, // TODO: armor prefab
+            attachment_point: Some(ArmorSlot::Legs.attachment_point().to_string()),
+            armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Legs,
+                set_id: Some("eva".to_string()),
+                defense: 5,
+                consumable_slot_bonus: 0,
+                oxygen_bonus: 0.0,
+                insulation: 0.05,
+                shield: None,
+            }),
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // EVA boots (магнитные подошвы)
+        defs.add(ItemDefinition {
+            id: "boots_eva".into(),
+            name: "EVA Boots".to_string(),
+            item_type: ItemType::Armor,
+            rarity: Rarity::Common,
+            weight: 3.0,
+            max_stack: 1,
+            weapon_template: None,
+            prefab_path: None, // TODO: armor prefab
+            attachment_point: Some(ArmorSlot::Boots.attachment_point().to_string()),
+            armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Boots,
+                set_id: Some("eva".to_string()),
+                defense: 5,
+                consumable_slot_bonus: 0,
+                oxygen_bonus: 0.0,
+                insulation: 0.05,
+                shield: None,
+            }),
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // === SHIELD GENERATORS (броня слота Legs — пояс-генератор вместо поножей) ===
+
+        // Shield belt (сфера)
+        defs.add(ItemDefinition {
+            id: "shield_belt".into(),
+            name: "Shield Belt".to_string(),
+            item_type: ItemType::Armor,
+            rarity: Rarity::Uncommon,
+            weight: 2.0,
+            max_stack: 1,
+            weapon_template: None,
+            prefab_path: None, // TODO: generator prefab
+            attachment_point: Some(ArmorSlot::Legs.attachment_point().to_string()),
+            armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Legs,
+                set_id: None,
+                defense: 0,
+                consumable_slot_bonus: 0,
+                oxygen_bonus: 0.0,
+                insulation: 0.0,
+                shield: Some(ShieldTemplate {
+                    max_energy: 200.0,
+                    recharge_rate: 10.0,
+                    recharge_delay: 3.0,
+                    coverage: ShieldCoverage::Sphere,
+                }),
+            }),
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // Aegis belt (фронтальная дуга: вдвое ёмче, спина открыта)
+        defs.add(ItemDefinition {
+            id: "shield_belt_aegis".into(),
+            name: "Aegis Belt".to_string(),
+            item_type: ItemType::Armor,
+            rarity: Rarity::Rare,
+            weight: 2.5,
+            max_stack: 1,
+            weapon_template: None,
+            prefab_path: None, // TODO: generator prefab
+            attachment_point: Some(ArmorSlot::Legs.attachment_point().to_string()),
+            armor_stats: Some(ArmorStatsTemplate {
+                slot: ArmorSlot::Legs,
+                set_id: None,
+                defense: 0,
+                consumable_slot_bonus: 0,
+                oxygen_bonus: 0.0,
+                insulation: 0.0,
+                shield: Some(ShieldTemplate {
+                    max_energy: 400.0,
+                    recharge_rate: 25.0,
+                    recharge_delay: 2.0,
+                    coverage: ShieldCoverage::FrontArc { half_angle_degrees: 60.0 },
+                }),
+            }),
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        defs.add_armor_set("military", ArmorSetBonus {
+            name: "Military Kit".to_string(),
+            defense: 25,
+            stamina_regen_bonus: 0.15,
+            oxygen_bonus: 0.0,
+            insulation: 0.0,
+        });
+        defs.add_armor_set("eva", ArmorSetBonus {
+            name: "Sealed EVA Suit".to_string(),
+            defense: 0,
+            stamina_regen_bonus: 0.0,
+            oxygen_bonus: 120.0, // Герметичный контур
+            insulation: 0.2,
+        });
+
         // === CONSUMABLES ===
 
         // Health kit
@@ -466,10 +1167,14 @@ impl Default for ItemDefinitions {
             id: "health_kit".into(),
             name: "Health Kit".to_string(),
             item_type: ItemType::Consumable,
+            rarity: Rarity::Common,
+            weight: 0.5,
+            max_stack: 5,
             weapon_template: None,
             prefab_path: None,
             attachment_point: None,
             armor_stats: None,
+            throwable_stats: None,
             consumable_effect: Some(ConsumableEffect::RestoreHealth { amount: 50 }),
         });
 
@@ -478,10 +1183,14 @@ impl Default for ItemDefinitions {
             id: "stamina_boost".into(),
             name: "Stamina Boost".to_string(),
             item_type: ItemType::Consumable,
+            rarity: Rarity::Common,
+            weight: 0.3,
+            max_stack: 5,
             weapon_template: None,
             prefab_path: None,
             attachment_point: None,
             armor_stats: None,
+            throwable_stats: None,
             consumable_effect: Some(ConsumableEffect::RestoreStamina { amount: 100 }),
         });
 
@@ -490,16 +1199,195 @@ impl Default for ItemDefinitions {
             id: "grenade_frag".into(),
             name: "Frag Grenade".to_string(),
             item_type: ItemType::Consumable,
+            rarity: Rarity::Common,
+            weight: 0.6,
+            max_stack: 3,
             weapon_template: None,
             prefab_path: None,
             attachment_point: None,
             armor_stats: None,
+            throwable_stats: None,
             consumable_effect: Some(ConsumableEffect::SpawnProjectile {
                 prefab_path: "res://actors/test_projectile.tscn".to_string(),
                 damage: 75,
             }),
         });
 
+        // Smoke grenade
+        defs.add(ItemDefinition {
+            id: "grenade_smoke".into(),
+            name: "Smoke Grenade".to_string(),
+            item_type: ItemType::Consumable,
+            rarity: Rarity::Common,
+            weight: 0.5,
+            max_stack: 3,
+            weapon_template: None,
+            prefab_path: None,
+            attachment_point: None,
+            armor_stats: None,
+            throwable_stats: None,
+            consumable_effect: Some(ConsumableEffect::DeploySmoke {
+                radius: 4.0,
+                duration: 12.0,
+            }),
+        });
+
+        // Flashbang
+        defs.add(ItemDefinition {
+            id: "grenade_flash".into(),
+            name: "Flashbang".to_string(),
+            item_type: ItemType::Consumable,
+            rarity: Rarity::Common,
+            weight: 0.4,
+            max_stack: 3,
+            weapon_template: None,
+            prefab_path: None,
+            attachment_point: None,
+            armor_stats: None,
+            throwable_stats: None,
+            consumable_effect: Some(ConsumableEffect::Flashbang {
+                radius: 12.0,
+                max_duration: 4.0,
+            }),
+        });
+
+        // EMP граната
+        defs.add(ItemDefinition {
+            id: "grenade_emp".into(),
+            name: "EMP Grenade".to_string(),
+            item_type: ItemType::Consumable,
+            rarity: Rarity::Uncommon,
+            weight: 0.5,
+            max_stack: 3,
+            weapon_template: None,
+            prefab_path: None,
+            attachment_point: None,
+            armor_stats: None,
+            throwable_stats: None,
+            consumable_effect: Some(ConsumableEffect::Emp {
+                radius: 8.0,
+                duration: 6.0,
+            }),
+        });
+
+        // Ремкомплект оружия
+        defs.add(ItemDefinition {
+            id: "repair_kit".into(),
+            name: "Weapon Repair Kit".to_string(),
+            item_type: ItemType::Consumable,
+            rarity: Rarity::Common,
+            weight: 0.8,
+            max_stack: 3,
+            weapon_template: None,
+            prefab_path: None,
+            attachment_point: None,
+            armor_stats: None,
+            throwable_stats: None,
+            consumable_effect: Some(ConsumableEffect::RepairWeapon { amount: 0.5 }),
+        });
+
+        // === THROWABLES ===
+
+        // Метательный нож
+        defs.add(ItemDefinition {
+            id: "throwing_knife".into(),
+            name: "Throwing Knife".to_string(),
+            item_type: ItemType::Throwable,
+            rarity: Rarity::Common,
+            weight: 0.2,
+            max_stack: 5,
+            weapon_template: None,
+            prefab_path: None,
+            attachment_point: None,
+            armor_stats: None,
+            throwable_stats: Some(ThrowableStats { damage: 30, speed: 18.0 }),
+            consumable_effect: None,
+        });
+
+        // Метательное копьё
+        defs.add(ItemDefinition {
+            id: "throwing_spear".into(),
+            name: "Throwing Spear".to_string(),
+            item_type: ItemType::Throwable,
+            rarity: Rarity::Common,
+            weight: 1.5,
+            max_stack: 1,
+            weapon_template: None,
+            prefab_path: None,
+            attachment_point: None,
+            armor_stats: None,
+            throwable_stats: Some(ThrowableStats { damage: 55, speed: 14.0 }),
+            consumable_effect: None,
+        });
+
+        // === CRAFT MATERIALS ===
+
+        // Металлолом (гранаты, броня)
+        defs.add(ItemDefinition {
+            id: "scrap_metal".into(),
+            name: "Scrap Metal".to_string(),
+            item_type: ItemType::CraftMaterial,
+            rarity: Rarity::Common,
+            weight: 1.0,
+            max_stack: 50,
+            weapon_template: None,
+            prefab_path: None,
+            attachment_point: None,
+            armor_stats: None,
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // Химреагент (аптечки, дымовые)
+        defs.add(ItemDefinition {
+            id: "chem_vial".into(),
+            name: "Chem Vial".to_string(),
+            item_type: ItemType::CraftMaterial,
+            rarity: Rarity::Common,
+            weight: 0.2,
+            max_stack: 20,
+            weapon_template: None,
+            prefab_path: None,
+            attachment_point: None,
+            armor_stats: None,
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // Плата (электроника: флешки, ключ-карты)
+        defs.add(ItemDefinition {
+            id: "circuit_board".into(),
+            name: "Circuit Board".to_string(),
+            item_type: ItemType::CraftMaterial,
+            rarity: Rarity::Common,
+            weight: 0.3,
+            max_stack: 20,
+            weapon_template: None,
+            prefab_path: None,
+            attachment_point: None,
+            armor_stats: None,
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
+        // === KEYS ===
+
+        // Ключ-карта охраны (запертые двери с meta `key`)
+        defs.add(ItemDefinition {
+            id: "keycard_security".into(),
+            name: "Security Keycard".to_string(),
+            item_type: ItemType::Quest,
+            rarity: Rarity::Common,
+            weight: 0.05,
+            max_stack: 1,
+            weapon_template: None,
+            prefab_path: None,
+            attachment_point: None,
+            armor_stats: None,
+            throwable_stats: None,
+            consumable_effect: None,
+        });
+
         defs
     }
 }
@@ -532,6 +1420,22 @@ mod tests {
         assert!(defs.get(&"health_kit".into()).is_some());
         assert!(defs.get(&"stamina_boost".into()).is_some());
         assert!(defs.get(&"grenade_frag".into()).is_some());
+        assert!(defs.get(&"grenade_smoke".into()).is_some());
+        assert!(defs.get(&"grenade_flash".into()).is_some());
+        assert!(defs.get(&"grenade_emp".into()).is_some());
+
+        // Craft materials + keys
+        assert!(defs.get(&"scrap_metal".into()).is_some());
+        assert!(defs.get(&"chem_vial".into()).is_some());
+        assert!(defs.get(&"circuit_board".into()).is_some());
+        assert!(defs.get(&"keycard_security".into()).is_some());
+
+        // Throwables
+        for id in ["throwing_knife", "throwing_spear"] {
+            let def = defs.get(&id.into()).unwrap();
+            assert_eq!(def.item_type, ItemType::Throwable);
+            assert!(def.throwable_stats.as_ref().is_some_and(|stats| stats.damage > 0 && stats.speed > 0.0));
+        }
     }
 
     #[test]
@@ -545,6 +1449,18 @@ mod tests {
         assert!(stats.is_melee());
     }
 
+    #[test]
+    fn test_sniper_scope_zooms_harder_than_rifle() {
+        let defs = ItemDefinitions::default();
+        let sniper = defs.get(&"sniper_basic".into()).and_then(|def| def.weapon_template.as_ref()).unwrap();
+        let rifle = WeaponStatsTemplate::ranged_rifle();
+
+        assert!(sniper.to_weapon_stats().is_ranged());
+        assert!(sniper.ads().fov < rifle.ads().fov);
+        assert!(sniper.ads().sensitivity_multiplier < rifle.ads().sensitivity_multiplier);
+        assert!(sniper.ads().transition_time > rifle.ads().transition_time);
+    }
+
     #[test]
     fn test_item_instance_new() {
         let item = ItemInstance::new("melee_sword");
@@ -561,4 +1477,53 @@ mod tests {
         assert_eq!(item.stack_size, 5);
         assert_eq!(item.durability, None);
     }
+
+    #[test]
+    fn test_rarity_roll_respects_floor() {
+        use rand::SeedableRng;
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(7);
+        for _ in 0..100 {
+            assert!(Rarity::roll(Rarity::Rare, &mut rng) >= Rarity::Rare);
+        }
+    }
+
+    #[test]
+    fn test_roll_affixes_matches_rarity() {
+        use rand::SeedableRng;
+        let defs = ItemDefinitions::default();
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(42);
+
+        for _ in 0..50 {
+            let mut sword = ItemInstance::new("melee_sword");
+            defs.roll_affixes(&mut sword, &mut rng);
+            assert_eq!(sword.affixes.len(), sword.rarity.affix_count());
+        }
+
+        // armor_military — floor Rare
+        let mut armor = ItemInstance::new("armor_military");
+        defs.roll_affixes(&mut armor, &mut rng);
+        assert!(armor.rarity >= Rarity::Rare);
+        assert!(armor.affixes.len() >= 2);
+
+        // Consumables без affixes
+        let mut kit = ItemInstance::consumable_stack("health_kit", 1);
+        defs.roll_affixes(&mut kit, &mut rng);
+        assert_eq!(kit.rarity, Rarity::Common);
+        assert!(kit.affixes.is_empty());
+    }
+
+    #[test]
+    fn test_apply_weapon_affixes() {
+        let mut stats = WeaponStats::melee_sword();
+        let base_damage = stats.base_damage;
+        let base_cooldown = stats.attack_cooldown;
+
+        apply_weapon_affixes(
+            &mut stats,
+            &[Affix::DamagePercent(0.5), Affix::AttackSpeedPercent(0.2), Affix::Defense(5)],
+        );
+
+        assert_eq!(stats.base_damage, (base_damage as f32 * 1.5).round() as u32);
+        assert!((stats.attack_cooldown - base_cooldown * 0.8).abs() < 1e-5);
+    }
 }
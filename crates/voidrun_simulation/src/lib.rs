@@ -8,16 +8,41 @@
 //! - Godot = tactical layer (physics, rendering, pathfinding)
 
 use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
 use rand::SeedableRng;
 use rand_chacha::ChaCha8Rng;
 
 // Публичные модули (domains)
 pub mod ai;
+pub mod faction_ai;
 pub mod logger;
 pub mod combat;
 pub mod equipment;
 pub mod item_system;
 pub mod player;
+pub mod security;
+pub mod doors;
+pub mod interaction;
+pub mod compass;
+pub mod scan;
+pub mod crafting;
+pub mod tutorial;
+pub mod session;
+pub mod trading;
+pub mod objective;
+pub mod game_mode;
+pub mod horde;
+pub mod world_events;
+pub mod environment;
+pub mod battlefield;
+pub mod forensics;
+pub mod combat_log;
+pub mod content;
+pub mod companions;
+pub mod formation;
+pub mod time_control;
+pub mod game_state;
 
 // New domains (Phase 1 refactoring)
 pub mod actor;
@@ -30,18 +55,41 @@ pub mod components;
 
 // Re-export базовых компонентов для удобства
 pub use ai::{AIConfig, AIPlugin, AIState};
+pub use faction_ai::FactionAIPlugin;
+pub use security::SecurityPlugin;
+pub use doors::DoorPlugin;
+pub use interaction::InteractionPlugin;
+pub use compass::CompassPlugin;
+pub use scan::ScanPlugin;
+pub use crafting::CraftingPlugin;
+pub use tutorial::TutorialPlugin;
+pub use session::SessionPlugin;
+pub use trading::TradingPlugin;
+pub use objective::ObjectivePlugin;
+pub use game_mode::GameModePlugin;
+pub use horde::HordePlugin;
+pub use world_events::WorldEventsPlugin;
+pub use environment::EnvironmentPlugin;
+pub use battlefield::BattlefieldPlugin;
+pub use forensics::ForensicsPlugin;
+pub use combat_log::CombatLogPlugin;
+pub use companions::CompanionsPlugin;
+pub use time_control::TimeControlPlugin;
+pub use game_state::{GameState, GameStatePlugin};
+pub use movement::MovementPlugin;
 pub use combat::{
     calculate_damage, update_weapon_cooldowns, WeaponStats, WeaponType, CombatPlugin, DamageDealt, Dead, EntityDied,
     Exhausted, ATTACK_COST, BLOCK_COST, DODGE_COST,
 };
 pub use components::*;
 pub use item_system::{
-    ArmorStatsTemplate, ConsumableEffect, ItemDefinition, ItemDefinitions, ItemId, ItemInstance,
-    ItemType, WeaponSize, WeaponStatsTemplate,
+    Affix, ArmorSetBonus, ArmorSlot, ArmorStatsTemplate, ConsumableEffect, ItemDefinition, ItemDefinitions, ItemId, ItemInstance,
+    ItemType, Rarity, ShieldTemplate, ThrowableStats, WeaponSize, WeaponStatsTemplate,
 };
 pub use equipment::{
     EquipWeaponIntent, UnequipWeaponIntent, SwapActiveWeaponIntent, WeaponSlot,
-    EquipArmorIntent, UnequipArmorIntent, UseConsumableIntent, EquipmentPlugin,
+    EquipArmorIntent, UnequipArmorIntent, UseConsumableIntent, SplitStackIntent, MergeStackIntent, EquipmentPlugin,
+    ApplyLoadoutIntent, SaveLoadoutIntent, LoadoutBook, ThrowIntent, ThrowableLaunched, ThrowableLanded,
 };
 
 // Re-export events
@@ -58,10 +106,23 @@ impl Plugin for SimulationPlugin {
             .insert_resource(Time::<Fixed>::from_hz(60.0))
             // Детерминистичный RNG (seed по умолчанию)
             .insert_resource(DeterministicRng::new(42))
+            // Глобальный tick counter (FixedFirst, до всех FixedUpdate систем)
+            .init_resource::<SimulationTick>()
+            .add_systems(FixedFirst, advance_simulation_tick)
+            // Промахи Godot lookup'ов (ExpectationFailed → ExpectationLog, дедуп на кадр)
+            .add_event::<ExpectationFailed>()
+            .init_resource::<ExpectationLog>()
+            .add_systems(Last, record_expectation_failures)
+            // Stable IDs (StableId ↔ Entity для сохранений / реплеев / сети / квестов)
+            .register_type::<StableId>()
+            .init_resource::<StableIdRegistry>()
+            .add_systems(PostUpdate, (assign_stable_ids, register_stable_ids, release_stable_ids).chain())
             // Item definitions (hardcoded базовые items)
             .insert_resource(ItemDefinitions::default())
             // Подсистемы (ECS strategic layer)
-            .add_plugins((CombatPlugin, AIPlugin, EquipmentPlugin));
+            .add_plugins((CombatPlugin, AIPlugin, FactionAIPlugin, SecurityPlugin, DoorPlugin, InteractionPlugin, CompassPlugin, ScanPlugin, BattlefieldPlugin, ForensicsPlugin, GameStatePlugin))
+            // Bevy: кортеж плагинов ≤ 15 элементов
+            .add_plugins((CraftingPlugin, ObjectivePlugin, GameModePlugin, TutorialPlugin, SessionPlugin, TradingPlugin, HordePlugin, WorldEventsPlugin, EnvironmentPlugin, MovementPlugin, EquipmentPlugin, CombatLogPlugin, CompanionsPlugin, TimeControlPlugin));
     }
 }
 
@@ -81,17 +142,70 @@ impl DeterministicRng {
     }
 }
 
+/// Глобальный simulation tick (детерминистичный, инкрементируется в FixedFirst)
+///
+/// Используется для tick-based таймеров (invulnerability windows и т.п.),
+/// где float accumulation дрейфует между прогонами.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct SimulationTick(pub u64);
+
+impl SimulationTick {
+    /// Частота fixed timestep (должна совпадать с Time::<Fixed>::from_hz)
+    pub const HZ: f32 = 60.0;
+
+    /// Текущий tick
+    pub fn get(&self) -> u64 {
+        self.0
+    }
+
+    /// Конвертировать секунды → ticks (округление вверх)
+    pub fn ticks_for_secs(secs: f32) -> u64 {
+        (secs.max(0.0) * Self::HZ).ceil() as u64
+    }
+
+    /// Tick через `secs` секунд от текущего
+    pub fn after_secs(&self, secs: f32) -> u64 {
+        self.0 + Self::ticks_for_secs(secs)
+    }
+}
+
+/// Система: инкремент SimulationTick (FixedFirst)
+pub fn advance_simulation_tick(mut tick: ResMut<SimulationTick>) {
+    tick.0 = tick.0.wrapping_add(1);
+}
+
 /// Создаёт minimal Bevy App для headless симуляции
+///
+/// Часы зафиксированы: каждый `app.update()` = ровно один fixed tick (1/60 s),
+/// число тиков не зависит от wall-clock (детерминизм тестов).
 pub fn create_headless_app(seed: u64) -> App {
     let mut app = App::new();
     logger::init_logger();
     app.add_plugins(MinimalPlugins)
         .insert_resource(DeterministicRng::new(seed))
-        .insert_resource(Time::<Fixed>::from_hz(60.0)); // 60Hz FixedUpdate
+        .insert_resource(Time::<Fixed>::from_hz(60.0)) // 60Hz FixedUpdate
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(1.0 / 60.0)));
+
+    app
+}
 
+/// App для unit тестов систем: headless app + SimulationPlugin
+///
+/// События и ресурсы регистрируют сами plugins (как в игре) — тест их не перечисляет.
+/// Schedule'ы не запускаются: системы гоняются через `run_system_once`.
+#[cfg(test)]
+pub(crate) fn create_test_app() -> App {
+    let mut app = create_headless_app(42);
+    app.add_plugins(SimulationPlugin);
     app
 }
 
+/// Накопленные события `E` (очередь очищается)
+#[cfg(test)]
+pub(crate) fn drain_events<E: Event>(world: &mut World) -> Vec<E> {
+    world.resource_mut::<Events<E>>().drain().collect()
+}
+
 /// Snapshot мира для сравнения детерминизма
 /// (упрощённая версия, полная в bevy_save будет позже)
 pub fn world_snapshot<T: Component>(world: &mut World) -> Vec<u8>
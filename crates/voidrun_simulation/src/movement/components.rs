@@ -33,6 +33,367 @@ impl Default for MovementCommand {
     }
 }
 
+/// Стойка актора: скорость, высота коллизии (Godot capsule), заметность (stealth)
+///
+/// Без компонента актор стоит.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub enum Stance {
+    #[default]
+    Standing,
+    Crouched,
+    Prone,
+}
+
+impl Stance {
+    /// Множитель скорости движения
+    pub fn speed_multiplier(&self) -> f32 {
+        match self {
+            Self::Standing => 1.0,
+            Self::Crouched => 0.5,
+            Self::Prone => 0.25,
+        }
+    }
+
+    /// Доля высоты капсулы коллизии от стоячей
+    pub fn height_factor(&self) -> f32 {
+        match self {
+            Self::Standing => 1.0,
+            Self::Crouched => 0.6,
+            Self::Prone => 0.3,
+        }
+    }
+
+    /// Множитель заметности (Visibility)
+    pub fn visibility_factor(&self) -> f32 {
+        match self {
+            Self::Standing => 1.0,
+            Self::Crouched => 0.6,
+            Self::Prone => 0.35,
+        }
+    }
+
+    /// Множитель покачивания оружия (с упора / лёжа ствол спокойнее)
+    pub fn sway_factor(&self) -> f32 {
+        match self {
+            Self::Standing => 1.0,
+            Self::Crouched => 0.7,
+            Self::Prone => 0.4,
+        }
+    }
+
+    /// Спринт только стоя
+    pub fn can_sprint(&self) -> bool {
+        *self == Self::Standing
+    }
+
+    /// Переключение стойки: повторное нажатие той же → встать
+    pub fn toggled(self, target: Stance) -> Stance {
+        if self == target { Self::Standing } else { target }
+    }
+}
+
+/// Параметры спринта актора (per-actor тюнинг)
+///
+/// Без компонента используется `Sprint::default()`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Sprint {
+    /// Множитель скорости при спринте (поверх базовой скорости и Stance)
+    pub speed_multiplier: f32,
+    /// Расход stamina в секунду
+    pub stamina_drain_per_sec: f32,
+}
+
+impl Default for Sprint {
+    fn default() -> Self {
+        Self {
+            speed_multiplier: 2.0,
+            stamina_drain_per_sec: 15.0,
+        }
+    }
+}
+
+/// Актор спринтует (вставляется `apply_sprint_intents`, снимается при отпускании / 0 stamina)
+///
+/// Пока висит: stamina тратится (`Sprint::stamina_drain_per_sec`), regen стоит,
+/// ActionLock(Sprint) блокирует атаки; ADS и стрельба тоже запрещены.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct Sprinting;
+
+/// Реактивный ранец: тяга вверх, пока зажат Space в воздухе (и в невесомости)
+///
+/// Топливо тратится, пока висит `JetpackThrusting`; без тяги — восстанавливается.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Jetpack {
+    pub fuel: f32,
+    pub max_fuel: f32,
+    /// Ускорение тяги (м/с², больше гравитации — иначе не взлететь)
+    pub thrust: f32,
+    /// Расход топлива в секунду
+    pub burn_per_sec: f32,
+    /// Восстановление топлива в секунду (без тяги)
+    pub regen_per_sec: f32,
+    /// Максимальная скорость подъёма (м/с)
+    pub max_rise_speed: f32,
+}
+
+impl Default for Jetpack {
+    fn default() -> Self {
+        Self {
+            fuel: 100.0,
+            max_fuel: 100.0,
+            thrust: 14.0,
+            burn_per_sec: 25.0,
+            regen_per_sec: 10.0,
+            max_rise_speed: 5.0,
+        }
+    }
+}
+
+impl Jetpack {
+    pub fn has_fuel(&self) -> bool {
+        self.fuel > 0.0
+    }
+
+    /// Сжечь топливо за `delta` секунд. Возвращает true если топливо кончилось.
+    pub fn burn(&mut self, delta: f32) -> bool {
+        self.fuel = (self.fuel - self.burn_per_sec * delta).max(0.0);
+        self.fuel <= 0.0
+    }
+
+    /// Восстановить топливо за `delta` секунд
+    pub fn regenerate(&mut self, delta: f32) {
+        self.fuel = (self.fuel + self.regen_per_sec * delta).min(self.max_fuel);
+    }
+}
+
+/// Jetpack работает (вставляется `apply_jetpack_intents`, снимается при отпускании / 0 топлива)
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct JetpackThrusting;
+
+/// Актора отбросило (ударная волна и т.п.)
+///
+/// Пока висит: Godot ведёт тело горизонтально по `velocity` (input / навигация игнорируются),
+/// скорость гаснет линейно к концу `remaining`. Снимается `update_shoves`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Shoved {
+    /// Горизонтальная скорость отброса в начале (м/с, world)
+    pub velocity: Vec3,
+    pub remaining: f32,
+    pub duration: f32,
+}
+
+impl Shoved {
+    /// Длительность отброса по умолчанию (секунды)
+    pub const DEFAULT_DURATION: f32 = 0.4;
+
+    /// Отброс от `origin` к `target` со скоростью `speed` (точно в центре — по +X)
+    pub fn away_from(origin: Vec3, target: Vec3, speed: f32) -> Self {
+        let direction = Vec3::new(target.x - origin.x, 0.0, target.z - origin.z)
+            .try_normalize()
+            .unwrap_or(Vec3::X);
+        Self {
+            velocity: direction * speed,
+            remaining: Self::DEFAULT_DURATION,
+            duration: Self::DEFAULT_DURATION,
+        }
+    }
+
+    /// Текущая скорость (линейное затухание)
+    pub fn current_velocity(&self) -> Vec3 {
+        if self.duration <= 0.0 {
+            return Vec3::ZERO;
+        }
+        self.velocity * (self.remaining / self.duration).clamp(0.0, 1.0)
+    }
+}
+
+/// Наклон из-за укрытия (peek, Q/E)
+///
+/// `target` задаётся `apply_lean_intents` (-1 влево, 0 прямо, 1 вправо), `amount` плавно
+/// догоняет его в `update_lean_states`. Godot наклоняет камеру и верх корпуса вокруг
+/// CameraPivot, LOS/зрение берут глаза со сдвигом `lateral_offset` (выглянуть из-за угла).
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct LeanState {
+    /// Желаемый наклон (-1..1, + вправо)
+    pub target: f32,
+    /// Текущий наклон (-1..1, + вправо)
+    pub amount: f32,
+}
+
+impl LeanState {
+    /// Угол крена на полном наклоне (градусы)
+    pub const MAX_ANGLE_DEGREES: f32 = 15.0;
+    /// Боковой сдвиг корпуса на полном наклоне (м)
+    pub const SLIDE: f32 = 0.25;
+    /// Скорость наклона (доля полного наклона в секунду)
+    pub const SPEED: f32 = 5.0;
+
+    /// Крен вокруг оси Z (Godot: вправо — отрицательный)
+    pub fn roll_radians(&self) -> f32 {
+        -self.amount * Self::MAX_ANGLE_DEGREES.to_radians()
+    }
+
+    /// Боковой сдвиг точки на высоте `height` над опорой (м, + вправо)
+    pub fn lateral_offset(&self, height: f32) -> f32 {
+        self.amount * Self::SLIDE + height * (self.amount * Self::MAX_ANGLE_DEGREES.to_radians()).sin()
+    }
+
+    /// Приблизить `amount` к `goal` за `delta` секунд (`goal` — `target` или 0, если наклон запрещён)
+    pub fn step_towards(&mut self, goal: f32, delta: f32) {
+        let max_step = Self::SPEED * delta;
+        self.amount += (goal - self.amount).clamp(-max_step, max_step);
+    }
+
+    pub fn is_leaning(&self) -> bool {
+        self.amount.abs() > f32::EPSILON
+    }
+}
+
+/// Актор перелезает через препятствие (mantle/vault)
+///
+/// Вставляется `start_mantles` из `MantleIntent`, снимается `update_mantle_states`.
+/// Пока висит: ActionLock(Mantle) блокирует атаки, player input не двигает тело,
+/// Godot позиционирует актора по `position()` (гравитация не применяется).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct MantleState {
+    /// Позиция в момент старта (ноги), world coordinates
+    pub start: Vec3,
+    /// Точка приземления на/за препятствием (ноги), world coordinates
+    pub end: Vec3,
+    pub elapsed: f32,
+    pub duration: f32,
+}
+
+impl MantleState {
+    /// Длительность перелезания по умолчанию (секунды)
+    pub const DEFAULT_DURATION: f32 = 0.6;
+    /// Доля длительности на подъём (остаток — перенос вперёд)
+    pub const RISE_FRACTION: f32 = 0.5;
+
+    pub fn new(start: Vec3, end: Vec3) -> Self {
+        Self {
+            start,
+            end,
+            elapsed: 0.0,
+            duration: Self::DEFAULT_DURATION,
+        }
+    }
+
+    /// Прогресс 0.0-1.0
+    pub fn progress(&self) -> f32 {
+        if self.duration <= 0.0 {
+            return 1.0;
+        }
+        (self.elapsed / self.duration).clamp(0.0, 1.0)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+
+    /// Позиция на траектории: сначала подъём на месте, затем перенос вперёд
+    /// (иначе капсула режет угол препятствия)
+    pub fn position(&self) -> Vec3 {
+        let t = self.progress();
+        let top = self.start.y.max(self.end.y);
+
+        if t < Self::RISE_FRACTION {
+            let rise = t / Self::RISE_FRACTION;
+            Vec3::new(self.start.x, self.start.y + (top - self.start.y) * rise, self.start.z)
+        } else {
+            let forward = (t - Self::RISE_FRACTION) / (1.0 - Self::RISE_FRACTION);
+            let xz = self.start.lerp(self.end, forward);
+            Vec3::new(xz.x, top + (self.end.y - top) * forward, xz.z)
+        }
+    }
+}
+
+/// Актор на лестнице (внутри ladder volume Godot)
+///
+/// Вставляется по `LadderEntered`, снимается по `LadderExited`.
+/// Пока компонент есть, Godot не применяет гравитацию:
+/// вертикальная скорость = `direction × speed` (W/S у игрока, цель MovementCommand у NPC).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct ClimbingState {
+    /// Направление подъёма: 1.0 вверх, -1.0 вниз, 0.0 висим на месте
+    pub direction: f32,
+    /// Скорость подъёма (м/с)
+    pub speed: f32,
+}
+
+impl ClimbingState {
+    /// Скорость подъёма по умолчанию (м/с)
+    pub const DEFAULT_SPEED: f32 = 2.0;
+
+    /// Вертикальная скорость (м/с), direction зажат в -1.0..1.0
+    pub fn vertical_velocity(&self) -> f32 {
+        self.direction.clamp(-1.0, 1.0) * self.speed
+    }
+}
+
+impl Default for ClimbingState {
+    fn default() -> Self {
+        Self {
+            direction: 0.0,
+            speed: Self::DEFAULT_SPEED,
+        }
+    }
+}
+
+/// Гравитация вокруг актора (внутри gravity zone Godot)
+///
+/// Вставляется по `GravityZoneEntered`, снимается по `GravityZoneExited`.
+/// Без компонента — обычная гравитация (scale 1.0).
+/// `scale` ниже `ZERO_G_THRESHOLD` → невесомость: без пола, инерционный дрейф,
+/// движение только импульсами двигателей (`drift_velocity`).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct GravityState {
+    /// Множитель стандартной гравитации (0.0 = невесомость, 0.16 = Луна)
+    pub scale: f32,
+}
+
+impl GravityState {
+    /// Ниже — невесомость
+    pub const ZERO_G_THRESHOLD: f32 = 0.05;
+    /// Ускорение двигателей в невесомости (м/с²)
+    pub const THRUST_ACCELERATION: f32 = 4.0;
+    /// Максимальная скорость дрейфа (м/с)
+    pub const MAX_DRIFT_SPEED: f32 = 6.0;
+
+    pub fn new(scale: f32) -> Self {
+        Self { scale: scale.max(0.0) }
+    }
+
+    pub fn is_zero_g(&self) -> bool {
+        self.scale < Self::ZERO_G_THRESHOLD
+    }
+
+    /// Скорость после импульса двигателей за `delta` секунд
+    ///
+    /// Без тяги скорость сохраняется (инерция). Длина `thrust_direction`
+    /// зажата до 1.0, итоговая скорость — до `MAX_DRIFT_SPEED`.
+    pub fn drift_velocity(velocity: Vec3, thrust_direction: Vec3, delta: f32) -> Vec3 {
+        let thrust = thrust_direction.clamp_length_max(1.0) * Self::THRUST_ACCELERATION * delta;
+        (velocity + thrust).clamp_length_max(Self::MAX_DRIFT_SPEED)
+    }
+}
+
+impl Default for GravityState {
+    fn default() -> Self {
+        Self { scale: 1.0 }
+    }
+}
+
 /// Состояние навигации актора (для избежания спама PositionChanged events)
 ///
 /// Проблема:
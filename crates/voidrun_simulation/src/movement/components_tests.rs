@@ -0,0 +1,124 @@
+//! Tests for movement components (stance, mantle, ladder climbing, gravity zones, jetpack, shove, lean).
+
+#[cfg(test)]
+mod tests {
+    use super::super::components::*;
+
+    #[test]
+    fn test_stance_toggle_returns_to_standing() {
+        assert_eq!(Stance::Standing.toggled(Stance::Crouched), Stance::Crouched);
+        assert_eq!(Stance::Crouched.toggled(Stance::Crouched), Stance::Standing);
+        assert_eq!(Stance::Crouched.toggled(Stance::Prone), Stance::Prone);
+        assert_eq!(Stance::Prone.toggled(Stance::Prone), Stance::Standing);
+    }
+
+    #[test]
+    fn test_lower_stance_is_slower_and_shorter() {
+        assert!(Stance::Crouched.speed_multiplier() < Stance::Standing.speed_multiplier());
+        assert!(Stance::Prone.speed_multiplier() < Stance::Crouched.speed_multiplier());
+        assert!(Stance::Prone.height_factor() < Stance::Crouched.height_factor());
+        assert!(Stance::Standing.can_sprint());
+        assert!(!Stance::Crouched.can_sprint());
+    }
+
+    #[test]
+    fn test_mantle_rises_before_moving_forward() {
+        use bevy::prelude::Vec3;
+
+        let start = Vec3::new(0.0, 0.0, 0.0);
+        let end = Vec3::new(0.0, 1.0, -1.2);
+        let mut mantle = MantleState::new(start, end);
+
+        // Середина подъёма — ещё над стартом (не режем угол препятствия)
+        mantle.elapsed = mantle.duration * MantleState::RISE_FRACTION * 0.5;
+        let rising = mantle.position();
+        assert_eq!(rising.z, start.z);
+        assert!((rising.y - 0.5).abs() < 1e-5);
+
+        mantle.elapsed = mantle.duration;
+        assert!(mantle.is_finished());
+        assert!(mantle.position().distance(end) < 1e-5);
+    }
+
+    #[test]
+    fn test_climbing_velocity_clamps_direction() {
+        let mut climbing = ClimbingState::default();
+        assert_eq!(climbing.vertical_velocity(), 0.0);
+
+        climbing.direction = 1.0;
+        assert_eq!(climbing.vertical_velocity(), ClimbingState::DEFAULT_SPEED);
+
+        // Диагональный input не ускоряет спуск
+        climbing.direction = -3.0;
+        assert_eq!(climbing.vertical_velocity(), -ClimbingState::DEFAULT_SPEED);
+    }
+
+    #[test]
+    fn test_zero_g_drift_keeps_momentum_and_caps_speed() {
+        use bevy::prelude::Vec3;
+
+        assert!(GravityState::new(0.0).is_zero_g());
+        assert!(!GravityState::new(0.16).is_zero_g());
+
+        // Без тяги — инерция
+        let drifting = Vec3::new(1.0, 0.5, 0.0);
+        assert_eq!(GravityState::drift_velocity(drifting, Vec3::ZERO, 0.1), drifting);
+
+        // Тяга разгоняет, но не быстрее MAX_DRIFT_SPEED
+        let boosted = GravityState::drift_velocity(Vec3::X * 5.9, Vec3::X * 10.0, 1.0);
+        assert!((boosted.length() - GravityState::MAX_DRIFT_SPEED).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_jetpack_burns_out_and_regenerates() {
+        let mut jetpack = Jetpack::default();
+        let burn_time = jetpack.max_fuel / jetpack.burn_per_sec;
+
+        assert!(!jetpack.burn(burn_time * 0.5));
+        assert!(jetpack.burn(burn_time));
+        assert_eq!(jetpack.fuel, 0.0);
+        assert!(!jetpack.has_fuel());
+
+        // Восстановление не выше max_fuel
+        jetpack.regenerate(1000.0);
+        assert_eq!(jetpack.fuel, jetpack.max_fuel);
+    }
+
+    #[test]
+    fn test_shove_points_away_and_decays() {
+        use bevy::prelude::Vec3;
+
+        let shoved = Shoved::away_from(Vec3::ZERO, Vec3::new(0.0, 5.0, 2.0), 8.0);
+        assert_eq!(shoved.velocity, Vec3::Z * 8.0);
+
+        let half = Shoved {
+            remaining: shoved.duration * 0.5,
+            ..shoved
+        };
+        assert_eq!(half.current_velocity(), Vec3::Z * 4.0);
+
+        // Точно в центре — отброс по +X (не NaN)
+        assert_eq!(Shoved::away_from(Vec3::ONE, Vec3::ONE, 2.0).velocity, Vec3::X * 2.0);
+    }
+
+    #[test]
+    fn test_lean_eases_to_target_and_offsets_eyes() {
+        let mut lean = LeanState { target: 1.0, amount: 0.0 };
+
+        lean.step_towards(lean.target, 0.1);
+        assert!((lean.amount - 0.5).abs() < 1e-5);
+        lean.step_towards(lean.target, 1.0);
+        assert_eq!(lean.amount, 1.0);
+
+        // Вправо: крен отрицательный, глаза сдвинуты вправо сильнее, чем корпус
+        assert!(lean.roll_radians() < 0.0);
+        assert!(lean.lateral_offset(0.8) > LeanState::SLIDE);
+
+        // Влево — зеркально
+        let left = LeanState { target: -1.0, amount: -1.0 };
+        assert_eq!(left.lateral_offset(0.8), -lean.lateral_offset(0.8));
+
+        lean.step_towards(0.0, 1.0);
+        assert!(!lean.is_leaning());
+    }
+}
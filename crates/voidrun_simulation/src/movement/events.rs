@@ -14,3 +14,138 @@ use bevy::prelude::*;
 pub struct JumpIntent {
     pub entity: Entity,
 }
+
+/// Event: перелезть через препятствие (mantle/vault)
+///
+/// Генерируется:
+/// - Player input system (Space лицом к препятствию по пояс — Godot raycast)
+///
+/// Обрабатывается:
+/// - start_mantles (ECS): проверяет ActionLock, жив ли актор → MantleState
+#[derive(Event, Debug, Clone)]
+pub struct MantleIntent {
+    pub entity: Entity,
+    /// Позиция ног в момент нажатия
+    pub start: Vec3,
+    /// Точка приземления на/за препятствием
+    pub end: Vec3,
+}
+
+/// Event: начать/закончить спринт
+///
+/// Генерируется:
+/// - Player input system (Shift зажат + есть движение)
+/// - AI system (для NPC, если нужно)
+///
+/// Обрабатывается:
+/// - apply_sprint_intents (ECS): проверяет stamina, Exhausted, Stance, ActionLock
+#[derive(Event, Debug, Clone)]
+pub struct SprintIntent {
+    pub entity: Entity,
+    /// true — начать спринт, false — закончить
+    pub active: bool,
+}
+
+/// Event: наклон из-за укрытия (peek)
+///
+/// Генерируется:
+/// - Player input system (Q/E зажаты; отпускание → direction 0)
+///
+/// Обрабатывается:
+/// - apply_lean_intents (ECS): проверяет спринт, Stance, mantle/лестницу → LeanState.target
+#[derive(Event, Debug, Clone)]
+pub struct LeanIntent {
+    pub entity: Entity,
+    /// -1 влево, 0 выпрямиться, 1 вправо
+    pub direction: f32,
+}
+
+/// Event: включить/выключить jetpack
+///
+/// Генерируется:
+/// - Player input system (Space зажат в воздухе / в невесомости)
+///
+/// Обрабатывается:
+/// - apply_jetpack_intents (ECS): проверяет топливо, mantle/лестницу
+#[derive(Event, Debug, Clone)]
+pub struct JetpackIntent {
+    pub entity: Entity,
+    /// true — включить тягу, false — выключить
+    pub active: bool,
+}
+
+/// Event: jetpack включился (ECS → Godot VFX/SFX)
+#[derive(Event, Debug, Clone)]
+pub struct JetpackIgnited {
+    pub entity: Entity,
+}
+
+/// Event: jetpack выключился (ECS → Godot VFX/SFX)
+#[derive(Event, Debug, Clone)]
+pub struct JetpackCutOff {
+    pub entity: Entity,
+    /// true — кончилось топливо (а не отпустили Space)
+    pub out_of_fuel: bool,
+}
+
+/// Event: актор приземлился после падения/прыжка
+///
+/// Генерируется:
+/// - apply_gravity_to_all_actors (Godot layer): переход воздух → пол
+///
+/// Обрабатывается:
+/// - apply_fall_damage (ECS): impact_speed выше порога → урон, жёсткое приземление → StaggerState
+#[derive(Event, Debug, Clone)]
+pub struct Landed {
+    pub entity: Entity,
+    /// Вертикальная скорость в момент касания (м/с, положительная)
+    pub impact_speed: f32,
+}
+
+/// Event: актор вошёл в ladder volume
+///
+/// Генерируется:
+/// - detect_ladder_overlaps_main_thread (Godot layer): новое пересечение Area3D группы "ladders"
+///
+/// Обрабатывается:
+/// - apply_ladder_events (ECS): → ClimbingState
+#[derive(Event, Debug, Clone)]
+pub struct LadderEntered {
+    pub entity: Entity,
+}
+
+/// Event: актор покинул ladder volume (или спрыгнул — Space на лестнице)
+///
+/// Генерируется:
+/// - detect_ladder_overlaps_main_thread (Godot layer): пересечение закончилось
+/// - Player input system (Space → отпустить лестницу)
+///
+/// Обрабатывается:
+/// - apply_ladder_events (ECS): снимает ClimbingState
+#[derive(Event, Debug, Clone)]
+pub struct LadderExited {
+    pub entity: Entity,
+}
+
+/// Event: актор вошёл в gravity zone (или сменилась гравитация зоны)
+///
+/// Генерируется:
+/// - detect_gravity_zones_main_thread (Godot layer): Area3D группы "gravity_zones", meta `gravity_scale`
+///
+/// Обрабатывается:
+/// - apply_gravity_zone_events (ECS): → GravityState
+#[derive(Event, Debug, Clone)]
+pub struct GravityZoneEntered {
+    pub entity: Entity,
+    /// Множитель гравитации зоны (несколько зон — минимальный)
+    pub scale: f32,
+}
+
+/// Event: актор покинул все gravity zones
+///
+/// Обрабатывается:
+/// - apply_gravity_zone_events (ECS): снимает GravityState (обычная гравитация)
+#[derive(Event, Debug, Clone)]
+pub struct GravityZoneExited {
+    pub entity: Entity,
+}
@@ -4,11 +4,67 @@
 //! - MovementCommand (high-level intent для Godot NavigationAgent)
 //! - NavigationState (состояние навигации)
 //! - MovementSpeed (скорость движения)
-//! - JumpIntent (event для прыжка)
+//! - Stance (стойка: скорость, высота коллизии, заметность)
+//! - Sprint / Sprinting (спринт: множитель скорости, расход stamina)
+//! - MantleState (перелезание через препятствие по пояс)
+//! - ClimbingState (актор на лестнице: без гравитации, подъём/спуск)
+//! - GravityState (gravity zones: множитель гравитации, невесомость с дрейфом)
+//! - Jetpack / JetpackThrusting (реактивный ранец: топливо, тяга)
+//! - Shoved (отброс ударной волной: горизонтальная скорость с затуханием)
+//! - LeanState (наклон из-за укрытия: крен камеры/корпуса, сдвиг глаз для LOS)
+//! - JumpIntent / SprintIntent / MantleIntent (events для прыжка, спринта и перелезания)
+//! - LadderEntered / LadderExited (events ladder volume из Godot)
+//! - GravityZoneEntered / GravityZoneExited (events gravity zone из Godot)
+//! - JetpackIntent / JetpackIgnited / JetpackCutOff (events jetpack: input → ECS → VFX/SFX)
+//! - LeanIntent (event наклона Q/E)
+//! - Landed (event приземления → fall damage)
+
+use bevy::prelude::*;
 
 pub mod components;
 pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
 
 // Re-export all components and events
 pub use components::*;
 pub use events::*;
+pub use systems::*;
+
+/// Movement Plugin
+///
+/// Регистрирует mantle, лестницы, gravity zones, jetpack и наклон в FixedUpdate (после пересчёта ActionLock — start читает lock).
+pub struct MovementPlugin;
+
+impl Plugin for MovementPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<MantleIntent>()
+            .add_event::<LadderEntered>()
+            .add_event::<LadderExited>()
+            .add_event::<GravityZoneEntered>()
+            .add_event::<GravityZoneExited>()
+            .add_event::<JetpackIntent>()
+            .add_event::<JetpackIgnited>()
+            .add_event::<JetpackCutOff>()
+            .add_event::<LeanIntent>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    start_mantles,             // 1. MantleIntent → MantleState (+ ActionLock(Mantle))
+                    update_mantle_states,      // 2. Тик → снятие по завершении
+                    apply_ladder_events,       // 3. LadderEntered/Exited → ClimbingState
+                    apply_gravity_zone_events, // 4. GravityZoneEntered/Exited → GravityState
+                    apply_jetpack_intents,     // 5. JetpackIntent → JetpackThrusting (+ JetpackIgnited)
+                    update_jetpack_fuel,       // 6. Расход/восстановление топлива, 0 → JetpackCutOff
+                    update_shoves,             // 7. Тик Shoved → снятие
+                    apply_lean_intents,        // 8. LeanIntent → LeanState.target
+                    update_lean_states,        // 9. Наклон догоняет target (спринт/mantle/лестница → прямо)
+                )
+                    .chain()
+                    .after(crate::combat::update_action_locks),
+            );
+    }
+}
@@ -0,0 +1,253 @@
+//! Movement systems (mantle/vault: intent → MantleState → завершение; лестницы → ClimbingState;
+//! gravity zones → GravityState; jetpack: intent → JetpackThrusting → топливо; Shoved → затухание;
+//! наклон: LeanIntent → LeanState).
+
+use bevy::prelude::*;
+use crate::components::Health;
+use crate::combat::{ActionKind, ActionLock, ActionPhase, CancelTable};
+use super::components::{
+    ClimbingState, GravityState, Jetpack, JetpackThrusting, LeanState, MantleState, Shoved, Sprinting, Stance,
+};
+use super::events::{
+    GravityZoneEntered, GravityZoneExited, JetpackCutOff, JetpackIgnited, JetpackIntent, LadderEntered, LadderExited,
+    LeanIntent, MantleIntent,
+};
+
+/// System: MantleIntent → MantleState
+///
+/// - Мёртвые / уже перелезающие игнорируются
+/// - ActionLock должен разрешать Mantle (атака, stagger, channel и т.п. — нельзя)
+/// - Спринт сбрасывается (ActionLock(Mantle) приоритетнее)
+pub fn start_mantles(
+    mut intents: EventReader<MantleIntent>,
+    actors: Query<(&Health, Option<&ActionLock>), Without<MantleState>>,
+    cancel_table: Res<CancelTable>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((health, lock)) = actors.get(intent.entity) else {
+            continue;
+        };
+        if !health.is_alive() {
+            continue;
+        }
+        if !ActionLock::permits(lock, ActionKind::Mantle, &cancel_table) {
+            continue;
+        }
+
+        commands
+            .entity(intent.entity)
+            .insert((
+                MantleState::new(intent.start, intent.end),
+                ActionLock::new(ActionKind::Mantle, ActionPhase::Active),
+            ))
+            .remove::<Sprinting>();
+
+        crate::logger::log(&format!(
+            "🧗 {:?} mantling {:?} → {:?}",
+            intent.entity, intent.start, intent.end
+        ));
+    }
+}
+
+/// System: тик MantleState → удаление по завершении
+pub fn update_mantle_states(
+    mut query: Query<(Entity, &mut MantleState)>,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut mantle) in query.iter_mut() {
+        mantle.elapsed += delta;
+
+        if mantle.is_finished() {
+            commands.entity(entity).remove::<MantleState>();
+        }
+    }
+}
+
+/// System: LadderEntered / LadderExited → ClimbingState
+///
+/// - Мёртвые и перелезающие (MantleState) на лестницу не цепляются
+/// - Спринт сбрасывается
+/// - Exit после Enter в том же тике побеждает (commands применяются по порядку)
+pub fn apply_ladder_events(
+    mut entered: EventReader<LadderEntered>,
+    mut exited: EventReader<LadderExited>,
+    actors: Query<&Health, (Without<MantleState>, Without<ClimbingState>)>,
+    mut commands: Commands,
+) {
+    for event in entered.read() {
+        let Ok(health) = actors.get(event.entity) else {
+            continue;
+        };
+        if !health.is_alive() {
+            continue;
+        }
+
+        commands
+            .entity(event.entity)
+            .insert(ClimbingState::default())
+            .remove::<Sprinting>();
+
+        crate::logger::log(&format!("🪜 {:?} grabbed ladder", event.entity));
+    }
+
+    for event in exited.read() {
+        if let Ok(mut entity_commands) = commands.get_entity(event.entity) {
+            entity_commands.remove::<ClimbingState>();
+        }
+    }
+}
+
+/// System: GravityZoneEntered / GravityZoneExited → GravityState
+///
+/// Невесомость сбрасывает спринт и лестницу (отталкиваться не от чего).
+pub fn apply_gravity_zone_events(
+    mut entered: EventReader<GravityZoneEntered>,
+    mut exited: EventReader<GravityZoneExited>,
+    mut commands: Commands,
+) {
+    for event in entered.read() {
+        let Ok(mut entity_commands) = commands.get_entity(event.entity) else {
+            continue;
+        };
+
+        let gravity = GravityState::new(event.scale);
+        entity_commands.insert(gravity);
+        if gravity.is_zero_g() {
+            entity_commands.remove::<(Sprinting, ClimbingState)>();
+        }
+
+        crate::logger::log(&format!("🌌 {:?} gravity × {:.2}", event.entity, gravity.scale));
+    }
+
+    for event in exited.read() {
+        if let Ok(mut entity_commands) = commands.get_entity(event.entity) {
+            entity_commands.remove::<GravityState>();
+        }
+    }
+}
+
+/// System: JetpackIntent → вставить/снять JetpackThrusting
+///
+/// Старт разрешён если есть топливо, актор жив, не перелезает и не на лестнице.
+pub fn apply_jetpack_intents(
+    mut intents: EventReader<JetpackIntent>,
+    actors: Query<(&Jetpack, &Health, Has<JetpackThrusting>, Has<MantleState>, Has<ClimbingState>)>,
+    mut ignited_events: EventWriter<JetpackIgnited>,
+    mut cut_off_events: EventWriter<JetpackCutOff>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((jetpack, health, thrusting, mantling, climbing)) = actors.get(intent.entity) else {
+            continue;
+        };
+
+        if !intent.active {
+            if thrusting {
+                commands.entity(intent.entity).remove::<JetpackThrusting>();
+                cut_off_events.write(JetpackCutOff {
+                    entity: intent.entity,
+                    out_of_fuel: false,
+                });
+            }
+            continue;
+        }
+
+        if thrusting || mantling || climbing || !health.is_alive() || !jetpack.has_fuel() {
+            continue;
+        }
+
+        commands.entity(intent.entity).insert(JetpackThrusting);
+        ignited_events.write(JetpackIgnited {
+            entity: intent.entity,
+        });
+    }
+}
+
+/// System: топливо jetpack
+///
+/// - Тяга → расход `burn_per_sec`, 0 → тяга снята (JetpackCutOff { out_of_fuel: true })
+/// - Без тяги → восстановление `regen_per_sec`
+pub fn update_jetpack_fuel(
+    mut query: Query<(Entity, &mut Jetpack, Has<JetpackThrusting>)>,
+    time: Res<Time<Fixed>>,
+    mut cut_off_events: EventWriter<JetpackCutOff>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut jetpack, thrusting) in query.iter_mut() {
+        if !thrusting {
+            if jetpack.fuel < jetpack.max_fuel {
+                jetpack.regenerate(delta);
+            }
+            continue;
+        }
+
+        if jetpack.burn(delta) {
+            commands.entity(entity).remove::<JetpackThrusting>();
+            cut_off_events.write(JetpackCutOff {
+                entity,
+                out_of_fuel: true,
+            });
+        }
+    }
+}
+
+/// System: тик Shoved → снятие по истечении
+pub fn update_shoves(
+    mut query: Query<(Entity, &mut Shoved)>,
+    time: Res<Time<Fixed>>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (entity, mut shoved) in query.iter_mut() {
+        shoved.remaining -= delta;
+        if shoved.remaining <= 0.0 {
+            commands.entity(entity).remove::<Shoved>();
+        }
+    }
+}
+
+/// System: LeanIntent → LeanState.target
+pub fn apply_lean_intents(mut intents: EventReader<LeanIntent>, mut actors: Query<&mut LeanState>) {
+    for intent in intents.read() {
+        if let Ok(mut lean) = actors.get_mut(intent.entity) {
+            lean.target = intent.direction.clamp(-1.0, 1.0);
+        }
+    }
+}
+
+/// System: LeanState.amount → target
+///
+/// Наклон невозможен (выпрямляется) при спринте, лёжа, во время mantle, на лестнице и мёртвым.
+pub fn update_lean_states(
+    mut query: Query<(
+        &mut LeanState,
+        &Health,
+        Option<&Stance>,
+        Has<Sprinting>,
+        Has<MantleState>,
+        Has<ClimbingState>,
+    )>,
+    time: Res<Time<Fixed>>,
+) {
+    let delta = time.delta_secs();
+
+    for (mut lean, health, stance, sprinting, mantling, climbing) in query.iter_mut() {
+        let blocked = sprinting
+            || mantling
+            || climbing
+            || !health.is_alive()
+            || stance.copied() == Some(Stance::Prone);
+        let goal = if blocked { 0.0 } else { lean.target };
+
+        if lean.amount != goal {
+            lean.step_towards(goal, delta);
+        }
+    }
+}
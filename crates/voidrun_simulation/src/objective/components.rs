@@ -0,0 +1,83 @@
+//! Objective components (переносимые цели: ядро, флаг — escort / capture-the-flag режимы).
+
+use bevy::prelude::*;
+
+/// Состояние объективного предмета.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum ObjectiveState {
+    /// Лежит на земле (можно подобрать)
+    Dropped,
+    /// Несёт актор
+    Carried { carrier: Entity },
+    /// Доставлен в зону захвата (больше не участвует)
+    Captured,
+}
+
+/// Переносимый объективный предмет (ядро / флаг).
+///
+/// Носитель получает ограничения (`CarryingObjective`): медленнее движение, нет ADS.
+/// Смерть носителя → предмет падает на месте смерти.
+/// Доставка в `capture_point` (радиус `capture_radius`) → `ObjectiveCaptured`.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct ObjectiveItem {
+    /// Текущая позиция (на земле / у носителя), world coordinates
+    pub position: Vec3,
+    /// Центр зоны захвата
+    pub capture_point: Vec3,
+    /// Радиус зоны захвата (метры, XZ)
+    pub capture_radius: f32,
+    /// Множитель скорости носителя
+    pub carry_speed_multiplier: f32,
+    pub state: ObjectiveState,
+}
+
+impl ObjectiveItem {
+    /// Дистанция подбора (метры)
+    pub const PICKUP_RANGE: f32 = 1.5;
+    /// Радиус зоны захвата по умолчанию (метры)
+    pub const DEFAULT_CAPTURE_RADIUS: f32 = 3.0;
+    /// Множитель скорости носителя по умолчанию (тяжёлое ядро)
+    pub const DEFAULT_CARRY_SPEED_MULTIPLIER: f32 = 0.6;
+
+    pub fn new(position: Vec3, capture_point: Vec3, capture_radius: f32) -> Self {
+        Self {
+            position,
+            capture_point,
+            capture_radius,
+            carry_speed_multiplier: Self::DEFAULT_CARRY_SPEED_MULTIPLIER,
+            state: ObjectiveState::Dropped,
+        }
+    }
+
+    /// Текущий носитель
+    pub fn carrier(&self) -> Option<Entity> {
+        match self.state {
+            ObjectiveState::Carried { carrier } => Some(carrier),
+            _ => None,
+        }
+    }
+
+    /// Можно ли подобрать с позиции `position`
+    pub fn can_be_picked_up_from(&self, position: Vec3) -> bool {
+        self.state == ObjectiveState::Dropped && self.position.distance(position) <= Self::PICKUP_RANGE
+    }
+
+    /// `position` внутри зоны захвата (XZ)
+    pub fn is_in_capture_zone(&self, position: Vec3) -> bool {
+        let offset = position - self.capture_point;
+        Vec3::new(offset.x, 0.0, offset.z).length() <= self.capture_radius
+    }
+}
+
+/// Актор несёт объективный предмет.
+///
+/// Ограничения носителя:
+/// - скорость × `speed_multiplier` (player input / NavigationAgent velocity)
+/// - нет ADS (Godot input)
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct CarryingObjective {
+    pub item: Entity,
+    pub speed_multiplier: f32,
+}
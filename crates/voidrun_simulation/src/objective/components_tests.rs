@@ -0,0 +1,38 @@
+//! Tests for objective components (pickup range, capture zone).
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::*;
+
+    fn core() -> ObjectiveItem {
+        ObjectiveItem::new(Vec3::ZERO, Vec3::new(20.0, 0.0, 0.0), ObjectiveItem::DEFAULT_CAPTURE_RADIUS)
+    }
+
+    #[test]
+    fn test_pickup_only_when_dropped_and_in_range() {
+        let mut item = core();
+
+        assert!(item.can_be_picked_up_from(Vec3::new(1.0, 0.5, 0.0)));
+        assert!(!item.can_be_picked_up_from(Vec3::new(3.0, 0.0, 0.0)));
+
+        // Уже несут → второй актор не подберёт
+        let carrier = Entity::from_raw(7);
+        item.state = ObjectiveState::Carried { carrier };
+        assert_eq!(item.carrier(), Some(carrier));
+        assert!(!item.can_be_picked_up_from(Vec3::ZERO));
+
+        // Захваченный предмет не подбирается
+        item.state = ObjectiveState::Captured;
+        assert_eq!(item.carrier(), None);
+        assert!(!item.can_be_picked_up_from(Vec3::ZERO));
+    }
+
+    #[test]
+    fn test_capture_zone_ignores_height() {
+        let item = core();
+
+        assert!(item.is_in_capture_zone(Vec3::new(18.0, 5.0, 1.0)));
+        assert!(!item.is_in_capture_zone(Vec3::new(15.0, 0.0, 0.0)));
+    }
+}
@@ -0,0 +1,49 @@
+//! Objective events (потребители: game mode + UI).
+
+use bevy::prelude::*;
+
+/// Intent: актор подбирает ближайший объективный предмет (player input)
+///
+/// Обрабатывается `pick_up_objectives` (ищет предмет в `PICKUP_RANGE`).
+#[derive(Event, Debug, Clone)]
+pub struct PickUpObjectiveIntent {
+    pub actor: Entity,
+}
+
+/// Intent: носитель бросает предмет на месте
+#[derive(Event, Debug, Clone)]
+pub struct DropObjectiveIntent {
+    pub actor: Entity,
+}
+
+/// Предмет подобран
+#[derive(Event, Debug, Clone)]
+pub struct ObjectivePickedUp {
+    pub item: Entity,
+    pub carrier: Entity,
+}
+
+/// Причина падения предмета
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ObjectiveDropReason {
+    /// Носитель бросил сам
+    Manual,
+    /// Носитель погиб / исчез
+    CarrierDied,
+}
+
+/// Предмет упал на землю
+#[derive(Event, Debug, Clone)]
+pub struct ObjectiveDropped {
+    pub item: Entity,
+    pub carrier: Entity,
+    pub position: Vec3,
+    pub reason: ObjectiveDropReason,
+}
+
+/// Предмет доставлен в зону захвата
+#[derive(Event, Debug, Clone)]
+pub struct ObjectiveCaptured {
+    pub item: Entity,
+    pub carrier: Entity,
+}
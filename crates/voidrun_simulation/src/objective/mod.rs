@@ -0,0 +1,51 @@
+//! Objective module — переносимые объективные предметы (escort-the-core / capture-the-flag)
+//!
+//! # Architecture
+//!
+//! **Flow:**
+//! - `PickUpObjectiveIntent` (player input) → `CarryingObjective` на носителе → `ObjectivePickedUp`
+//! - Носитель: медленнее движение, нет ADS (ограничения применяет Godot layer)
+//! - `DropObjectiveIntent` / смерть носителя → предмет на земле → `ObjectiveDropped`
+//! - Носитель в зоне захвата → `ObjectiveCaptured`
+//!
+//! Events потребляют game mode (счёт / победа) и UI (метка носителя).
+//! ObjectiveItem entities создаёт Godot из нод группы `objective_items`.
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Objective Plugin
+///
+/// Регистрирует перенос объективных предметов в FixedUpdate.
+pub struct ObjectivePlugin;
+
+impl Plugin for ObjectivePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PickUpObjectiveIntent>()
+            .add_event::<DropObjectiveIntent>()
+            .add_event::<ObjectivePickedUp>()
+            .add_event::<ObjectiveDropped>()
+            .add_event::<ObjectiveCaptured>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    pick_up_objectives, // 1. PickUpObjectiveIntent → CarryingObjective
+                    drop_objectives,    // 2. DropObjectiveIntent → предмет на землю
+                    carry_objectives,   // 3. Следование за носителем, смерть → drop, захват
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,139 @@
+//! Objective systems (подбор, сброс, перенос, захват).
+
+use bevy::prelude::*;
+use crate::components::Health;
+use crate::StrategicPosition;
+use super::components::{CarryingObjective, ObjectiveItem, ObjectiveState};
+use super::events::{
+    DropObjectiveIntent, ObjectiveCaptured, ObjectiveDropReason, ObjectiveDropped, ObjectivePickedUp,
+    PickUpObjectiveIntent,
+};
+
+/// System: PickUpObjectiveIntent → ближайший лежащий предмет в `PICKUP_RANGE`
+///
+/// Носитель получает `CarryingObjective` (один предмет на актора).
+pub fn pick_up_objectives(
+    mut intents: EventReader<PickUpObjectiveIntent>,
+    actors: Query<(&StrategicPosition, &Health), Without<CarryingObjective>>,
+    mut items: Query<(Entity, &mut ObjectiveItem)>,
+    mut picked_up_events: EventWriter<ObjectivePickedUp>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((position, health)) = actors.get(intent.actor) else {
+            continue;
+        };
+        if !health.is_alive() {
+            continue;
+        }
+
+        let actor_pos = position.to_world_position(0.5);
+        let nearest = items
+            .iter_mut()
+            .filter(|(_, item)| item.can_be_picked_up_from(actor_pos))
+            .min_by(|(_, a), (_, b)| {
+                a.position.distance(actor_pos).total_cmp(&b.position.distance(actor_pos))
+            });
+        let Some((item_entity, mut item)) = nearest else {
+            continue;
+        };
+
+        item.state = ObjectiveState::Carried { carrier: intent.actor };
+        commands.entity(intent.actor).insert(CarryingObjective {
+            item: item_entity,
+            speed_multiplier: item.carry_speed_multiplier,
+        });
+
+        picked_up_events.write(ObjectivePickedUp {
+            item: item_entity,
+            carrier: intent.actor,
+        });
+
+        crate::logger::log(&format!("📦 {:?} picked up objective {:?}", intent.actor, item_entity));
+    }
+}
+
+/// System: DropObjectiveIntent → предмет падает у носителя
+pub fn drop_objectives(
+    mut intents: EventReader<DropObjectiveIntent>,
+    carriers: Query<(&CarryingObjective, &StrategicPosition)>,
+    mut items: Query<&mut ObjectiveItem>,
+    mut dropped_events: EventWriter<ObjectiveDropped>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((carrying, position)) = carriers.get(intent.actor) else {
+            continue;
+        };
+
+        commands.entity(intent.actor).remove::<CarryingObjective>();
+
+        let Ok(mut item) = items.get_mut(carrying.item) else {
+            continue;
+        };
+
+        item.position = position.to_world_position(0.5);
+        item.state = ObjectiveState::Dropped;
+
+        dropped_events.write(ObjectiveDropped {
+            item: carrying.item,
+            carrier: intent.actor,
+            position: item.position,
+            reason: ObjectiveDropReason::Manual,
+        });
+    }
+}
+
+/// System: перенос предметов за носителями + захват
+///
+/// - Носитель жив → предмет следует за ним; в зоне захвата → `ObjectiveCaptured`
+/// - Носитель погиб / исчез → предмет падает на последней позиции (`CarrierDied`)
+pub fn carry_objectives(
+    mut items: Query<(Entity, &mut ObjectiveItem)>,
+    carriers: Query<(&StrategicPosition, &Health), With<CarryingObjective>>,
+    mut dropped_events: EventWriter<ObjectiveDropped>,
+    mut captured_events: EventWriter<ObjectiveCaptured>,
+    mut commands: Commands,
+) {
+    for (item_entity, mut item) in items.iter_mut() {
+        let Some(carrier) = item.carrier() else {
+            continue;
+        };
+
+        let alive_position = carriers
+            .get(carrier)
+            .ok()
+            .filter(|(_, health)| health.is_alive())
+            .map(|(position, _)| position.to_world_position(0.5));
+
+        let Some(position) = alive_position else {
+            // Погиб (или despawn) → предмет падает там, где его видели последним
+            if let Ok(mut carrier_commands) = commands.get_entity(carrier) {
+                carrier_commands.remove::<CarryingObjective>();
+            }
+            item.state = ObjectiveState::Dropped;
+
+            dropped_events.write(ObjectiveDropped {
+                item: item_entity,
+                carrier,
+                position: item.position,
+                reason: ObjectiveDropReason::CarrierDied,
+            });
+            crate::logger::log(&format!("💀 Objective {:?} dropped: carrier {:?} died", item_entity, carrier));
+            continue;
+        };
+
+        item.position = position;
+
+        if item.is_in_capture_zone(position) {
+            item.state = ObjectiveState::Captured;
+            commands.entity(carrier).remove::<CarryingObjective>();
+
+            captured_events.write(ObjectiveCaptured {
+                item: item_entity,
+                carrier,
+            });
+            crate::logger::log(&format!("🏁 Objective {:?} captured by {:?}", item_entity, carrier));
+        }
+    }
+}
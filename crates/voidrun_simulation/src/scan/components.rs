@@ -0,0 +1,142 @@
+//! Scan components (канал сканирования, отчёт о цели, кэш сессии).
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::combat::{DamageSource, WeaponStats, WeaponType};
+use crate::components::{Actor, EnergyShield, EquippedArmor, Health};
+
+/// Актор сканирует цель (пока идёт Channeling::Scan)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct Scanning {
+    pub target: Entity,
+}
+
+impl Scanning {
+    /// Длительность канала (секунды, interruptible)
+    pub const DURATION: f32 = 1.5;
+    /// Дальность сканирования (метры) — дальше канал срывается
+    pub const RANGE: f32 = 25.0;
+}
+
+/// Боевой архетип цели (по оружию)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum CombatArchetype {
+    /// Melee оружие
+    Brawler,
+    /// Ranged оружие
+    Gunner,
+    /// Hybrid (штык-нож)
+    Hybrid,
+    /// Без оружия
+    Unarmed,
+}
+
+impl CombatArchetype {
+    pub fn from_weapon(weapon: Option<&WeaponStats>) -> Self {
+        match weapon.map(|weapon| &weapon.weapon_type) {
+            Some(WeaponType::Melee { .. }) => Self::Brawler,
+            Some(WeaponType::Ranged) => Self::Gunner,
+            Some(WeaponType::Hybrid) => Self::Hybrid,
+            None => Self::Unarmed,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Brawler => "Brawler",
+            Self::Gunner => "Gunner",
+            Self::Hybrid => "Hybrid",
+            Self::Unarmed => "Unarmed",
+        }
+    }
+}
+
+/// Сопротивление источнику урона
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum Resistance {
+    /// Урон идёт прямо в HP
+    None,
+    /// Активный щит поглощает (только Ranged)
+    Shielded,
+    /// Броня (defense rating)
+    Armored(u32),
+}
+
+/// Состояние щита на момент отчёта
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct ShieldReadout {
+    pub energy: f32,
+    pub max_energy: f32,
+    pub active: bool,
+}
+
+/// Результат сканирования цели (обновляется, пока цель в кэше)
+#[derive(Debug, Clone, PartialEq, Reflect)]
+pub struct ScanReport {
+    pub faction_id: u64,
+    pub archetype: CombatArchetype,
+    pub health: u32,
+    pub max_health: u32,
+    pub shield: Option<ShieldReadout>,
+    /// Defense rating брони (0 — без брони)
+    pub armor_defense: u32,
+}
+
+impl ScanReport {
+    /// Снять отчёт с компонентов цели
+    pub fn inspect(
+        actor: &Actor,
+        health: &Health,
+        weapon: Option<&WeaponStats>,
+        shield: Option<&EnergyShield>,
+        armor: Option<&EquippedArmor>,
+    ) -> Self {
+        Self {
+            faction_id: actor.faction_id,
+            archetype: CombatArchetype::from_weapon(weapon),
+            health: health.current,
+            max_health: health.max,
+            shield: shield.map(|shield| ShieldReadout {
+                energy: shield.current_energy,
+                max_energy: shield.max_energy,
+                active: shield.is_active(),
+            }),
+            armor_defense: armor.map_or(0, |armor| armor.defense()),
+        }
+    }
+
+    /// Сопротивление источнику (правила `apply_damage`: щит держит только Ranged)
+    pub fn resistance(&self, source: DamageSource) -> Resistance {
+        let shielded = self.shield.is_some_and(|shield| shield.active);
+        match source {
+            DamageSource::Ranged if shielded => Resistance::Shielded,
+            DamageSource::Environmental => Resistance::None,
+            _ if self.armor_defense > 0 => Resistance::Armored(self.armor_defense),
+            _ => Resistance::None,
+        }
+    }
+}
+
+/// Отсканированные цели за сессию (resource)
+///
+/// Повторный скан цели из кэша — мгновенный (без канала).
+/// Despawn цели → запись удаляется.
+#[derive(Resource, Debug, Default)]
+pub struct ScanCache {
+    pub reports: HashMap<Entity, ScanReport>,
+    /// Последняя отсканированная цель (HUD панель)
+    pub focus: Option<Entity>,
+}
+
+impl ScanCache {
+    pub fn is_scanned(&self, target: Entity) -> bool {
+        self.reports.contains_key(&target)
+    }
+
+    /// Отчёт цели в фокусе
+    pub fn focused(&self) -> Option<(Entity, &ScanReport)> {
+        let target = self.focus?;
+        self.reports.get(&target).map(|report| (target, report))
+    }
+}
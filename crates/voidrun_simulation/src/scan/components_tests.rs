@@ -0,0 +1,30 @@
+//! Tests for scan reports (архетип, сопротивления по источнику урона).
+
+#[cfg(test)]
+mod tests {
+    use super::super::components::*;
+    use crate::combat::{DamageSource, WeaponStats};
+    use crate::components::{Actor, EnergyShield, Health};
+
+    #[test]
+    fn test_archetype_from_weapon() {
+        assert_eq!(CombatArchetype::from_weapon(Some(&WeaponStats::melee_sword())), CombatArchetype::Brawler);
+        assert_eq!(CombatArchetype::from_weapon(Some(&WeaponStats::ranged_pistol())), CombatArchetype::Gunner);
+        assert_eq!(CombatArchetype::from_weapon(None), CombatArchetype::Unarmed);
+    }
+
+    #[test]
+    fn test_active_shield_resists_only_ranged() {
+        let actor = Actor { faction_id: 2 };
+        let health = Health { current: 40, max: 60 };
+        let shield = EnergyShield::basic();
+
+        let report = ScanReport::inspect(&actor, &health, None, Some(&shield), None);
+        assert_eq!(report.health, 40);
+        assert_eq!(report.resistance(DamageSource::Ranged), Resistance::Shielded);
+        assert_eq!(report.resistance(DamageSource::Melee), Resistance::None);
+
+        let unshielded = ScanReport::inspect(&actor, &health, None, None, None);
+        assert_eq!(unshielded.resistance(DamageSource::Ranged), Resistance::None);
+    }
+}
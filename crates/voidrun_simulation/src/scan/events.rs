@@ -0,0 +1,25 @@
+//! Scan events.
+
+use bevy::prelude::*;
+
+/// Intent: начать скан цели (игрок удерживает клавишу скана на цели)
+///
+/// Обрабатывается `start_scans` → Channeling(Scan); цель уже в `ScanCache` → сразу в фокус.
+#[derive(Event, Debug, Clone)]
+pub struct ScanIntent {
+    pub actor: Entity,
+    pub target: Entity,
+}
+
+/// Intent: прервать скан (клавиша отпущена)
+#[derive(Event, Debug, Clone)]
+pub struct CancelScanIntent {
+    pub actor: Entity,
+}
+
+/// Скан завершён — отчёт цели в `ScanCache`
+#[derive(Event, Debug, Clone)]
+pub struct ScanCompleted {
+    pub actor: Entity,
+    pub target: Entity,
+}
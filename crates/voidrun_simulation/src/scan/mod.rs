@@ -0,0 +1,52 @@
+//! Scan module — удержание клавиши на цели → HP, сопротивления и архетип в HUD
+//!
+//! # Architecture
+//!
+//! **Flow:**
+//! - Godot: клавиша скана удерживается, луч камеры на актора → `ScanIntent { actor, target }`;
+//!   отпущена → `CancelScanIntent`
+//! - ECS `start_scans` → Channeling(Scan) (прерывается уроном, как любой channel)
+//! - ChannelCompleted → `ScanReport` в `ScanCache` (на всю сессию) + `ScanCompleted`
+//! - Отчёты кэша обновляются каждый тик (живое HP / щит); повторный скан — мгновенный
+//!
+//! Godot HUD показывает отчёт цели в фокусе (`ScanCache::focused`).
+//! Тот же отчёт — отладочный снимок боевых компонентов актора (лог при скане).
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Scan Plugin
+///
+/// Регистрирует канал скана и кэш отчётов в FixedUpdate.
+pub struct ScanPlugin;
+
+impl Plugin for ScanPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ScanIntent>()
+            .add_event::<CancelScanIntent>()
+            .add_event::<ScanCompleted>()
+            .init_resource::<ScanCache>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    start_scans,          // 1. ScanIntent → Channeling(Scan) / фокус кэша
+                    cancel_scans,         // 2. Отпущена клавиша / цель потеряна → канал снят
+                    complete_scans,       // 3. ChannelCompleted(Scan) → ScanReport в ScanCache
+                    refresh_scan_reports, // 4. Живые значения отчётов, despawn → из кэша
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,147 @@
+//! Scan systems (канал скана → отчёт о цели в ScanCache).
+
+use bevy::prelude::*;
+use crate::combat::{
+    ActionKind, ActionLock, ActionPhase, CancelTable, ChannelCompleted, ChannelInterrupted, ChannelKind, Channeling,
+    WeaponStats,
+};
+use crate::components::{Actor, EnergyShield, EquippedArmor, Health};
+use crate::{SimulationTick, StrategicPosition};
+use super::components::{ScanCache, ScanReport, Scanning};
+use super::events::{CancelScanIntent, ScanCompleted, ScanIntent};
+
+/// System: ScanIntent → Channeling(Scan) на цель в `Scanning::RANGE`
+///
+/// - Цель уже отсканирована → только фокус HUD (без канала)
+/// - ActionLock + CancelTable должны разрешать Scan
+pub fn start_scans(
+    mut intents: EventReader<ScanIntent>,
+    scanners: Query<(&StrategicPosition, Option<&ActionLock>), Without<Channeling>>,
+    targets: Query<(&StrategicPosition, &Health), With<Actor>>,
+    mut cache: ResMut<ScanCache>,
+    cancel_table: Res<CancelTable>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        if cache.is_scanned(intent.target) {
+            cache.focus = Some(intent.target);
+            continue;
+        }
+        let Ok((position, lock)) = scanners.get(intent.actor) else {
+            continue;
+        };
+        let Ok((target_pos, health)) = targets.get(intent.target) else {
+            continue;
+        };
+        if !health.is_alive() || !in_scan_range(position, target_pos) {
+            continue;
+        }
+        if !ActionLock::permits(lock, ActionKind::Scan, &cancel_table) {
+            continue;
+        }
+
+        commands.entity(intent.actor).insert((
+            Channeling::new(ChannelKind::Scan, tick.after_secs(Scanning::DURATION)),
+            ActionLock::new(ActionKind::Scan, ActionPhase::Active),
+            Scanning { target: intent.target },
+        ));
+
+        crate::logger::log(&format!("📡 {:?} scanning {:?}", intent.actor, intent.target));
+    }
+}
+
+/// System: скан срывается — клавиша отпущена, цель умерла / исчезла / вне `Scanning::RANGE`
+pub fn cancel_scans(
+    mut intents: EventReader<CancelScanIntent>,
+    scanners: Query<(Entity, &Scanning, &StrategicPosition)>,
+    targets: Query<(&StrategicPosition, &Health)>,
+    mut commands: Commands,
+) {
+    let released: Vec<Entity> = intents.read().map(|intent| intent.actor).collect();
+
+    for (entity, scanning, position) in scanners.iter() {
+        let target_lost = targets
+            .get(scanning.target)
+            .ok()
+            .is_none_or(|(target_pos, health)| !health.is_alive() || !in_scan_range(position, target_pos));
+        if !target_lost && !released.contains(&entity) {
+            continue;
+        }
+
+        commands.entity(entity).remove::<(Scanning, Channeling)>();
+        crate::logger::log(&format!("📡 {:?} scan of {:?} cancelled", entity, scanning.target));
+    }
+}
+
+/// System: завершённый Scan channel → отчёт в ScanCache + фокус; прерванный → Scanning снят
+#[allow(clippy::too_many_arguments)]
+pub fn complete_scans(
+    mut completed_events: EventReader<ChannelCompleted>,
+    mut interrupted_events: EventReader<ChannelInterrupted>,
+    scanners: Query<&Scanning>,
+    targets: Query<(&Actor, &Health, Option<&WeaponStats>, Option<&EnergyShield>, Option<&EquippedArmor>)>,
+    mut cache: ResMut<ScanCache>,
+    mut scan_events: EventWriter<ScanCompleted>,
+    mut commands: Commands,
+) {
+    for completed in completed_events.read() {
+        if completed.kind != ChannelKind::Scan {
+            continue;
+        }
+        let Ok(scanning) = scanners.get(completed.entity) else {
+            continue;
+        };
+
+        commands.entity(completed.entity).remove::<Scanning>();
+
+        let Ok((actor, health, weapon, shield, armor)) = targets.get(scanning.target) else {
+            continue;
+        };
+        let report = ScanReport::inspect(actor, health, weapon, shield, armor);
+
+        crate::logger::log(&format!("📡 Scan {:?}: {:?}", scanning.target, report));
+        cache.reports.insert(scanning.target, report);
+        cache.focus = Some(scanning.target);
+        scan_events.write(ScanCompleted {
+            actor: completed.entity,
+            target: scanning.target,
+        });
+    }
+
+    for interrupted in interrupted_events.read() {
+        if interrupted.kind == ChannelKind::Scan && scanners.contains(interrupted.entity) {
+            commands.entity(interrupted.entity).remove::<Scanning>();
+        }
+    }
+}
+
+/// System: отчёты кэша следят за целями (HP, щит, смена оружия); despawn → запись удалена
+pub fn refresh_scan_reports(
+    targets: Query<(&Actor, &Health, Option<&WeaponStats>, Option<&EnergyShield>, Option<&EquippedArmor>)>,
+    mut cache: ResMut<ScanCache>,
+) {
+    if cache.reports.is_empty() {
+        return;
+    }
+
+    let cache = &mut *cache;
+    cache.reports.retain(|&target, report| {
+        let Ok((actor, health, weapon, shield, armor)) = targets.get(target) else {
+            return false;
+        };
+        let fresh = ScanReport::inspect(actor, health, weapon, shield, armor);
+        if *report != fresh {
+            *report = fresh;
+        }
+        true
+    });
+
+    if cache.focus.is_some_and(|target| !cache.reports.contains_key(&target)) {
+        cache.focus = None;
+    }
+}
+
+fn in_scan_range(scanner: &StrategicPosition, target: &StrategicPosition) -> bool {
+    scanner.to_world_position(0.0).distance(target.to_world_position(0.0)) <= Scanning::RANGE
+}
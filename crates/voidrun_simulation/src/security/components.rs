@@ -0,0 +1,129 @@
+//! Security components & resources (alarm panels, alerted territories).
+
+use bevy::prelude::*;
+
+/// Состояние тревожной панели.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum AlarmPanelState {
+    /// Ждёт обнаружения нарушителя
+    Armed,
+    /// Тревога поднята; панель снова взводится на `rearm_at_tick`
+    Triggered { rearm_at_tick: u64 },
+    /// Взломана — больше не срабатывает
+    Disabled,
+}
+
+/// Тревожная панель: охраняет территорию фракции.
+///
+/// - Член фракции на территории заметил врага на территории → тревога
+///   (`AlarmTriggered`): вся территория Alerted + запрос подкреплений
+/// - Взлом (Channeling::Hack в `HACK_RANGE`) → Disabled навсегда
+///
+/// Позиция панели — `position` (не актор, без StrategicPosition/визуала).
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct AlarmPanel {
+    /// Фракция-владелец
+    pub faction_id: u64,
+    /// Позиция панели (world coordinates)
+    pub position: Vec3,
+    /// Центр охраняемой территории
+    pub territory_center: Vec3,
+    /// Радиус территории (метры, XZ)
+    pub territory_radius: f32,
+    /// Точка появления подкреплений
+    pub reinforcement_point: Vec3,
+    /// Сколько бойцов вызывает тревога (0 = без подкреплений)
+    pub reinforcement_count: u32,
+    pub state: AlarmPanelState,
+}
+
+impl AlarmPanel {
+    /// Дистанция взлома (метры)
+    pub const HACK_RANGE: f32 = 2.0;
+    /// Длительность взлома (секунды, interruptible Channeling)
+    pub const HACK_DURATION: f32 = 3.0;
+    /// Сколько территория остаётся Alerted после тревоги (секунды)
+    pub const ALERT_DURATION: f32 = 30.0;
+
+    /// Панель в центре своей территории, подкрепления появляются у панели
+    pub fn new(faction_id: u64, position: Vec3, territory_radius: f32, reinforcement_count: u32) -> Self {
+        Self {
+            faction_id,
+            position,
+            territory_center: position,
+            territory_radius,
+            reinforcement_point: position,
+            reinforcement_count,
+            state: AlarmPanelState::Armed,
+        }
+    }
+
+    /// Позиция внутри территории (XZ, высота игнорируется)
+    pub fn covers(&self, position: Vec3) -> bool {
+        Vec2::new(position.x - self.territory_center.x, position.z - self.territory_center.z).length()
+            <= self.territory_radius
+    }
+
+    /// Можно ли поднять тревогу
+    pub fn is_armed(&self) -> bool {
+        self.state == AlarmPanelState::Armed
+    }
+
+    /// Можно ли взломать (в пределах `HACK_RANGE`, ещё не Disabled)
+    pub fn can_be_hacked_from(&self, position: Vec3) -> bool {
+        self.state != AlarmPanelState::Disabled && self.position.distance(position) <= Self::HACK_RANGE
+    }
+}
+
+/// Актор взламывает панель (пока идёт Channeling::Hack).
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct HackingPanel {
+    pub panel: Entity,
+}
+
+/// Территория фракции в состоянии тревоги.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct AlertedTerritory {
+    pub faction_id: u64,
+    pub panel: Entity,
+    pub center: Vec3,
+    pub radius: f32,
+    /// Нарушитель, вызвавший тревогу
+    pub intruder: Entity,
+    /// Tick окончания тревоги (exclusive)
+    pub until_tick: u64,
+}
+
+/// Активные тревоги по территориям фракций (resource).
+///
+/// Per-actor логика читает по желанию (`is_alerted`) — FSM от тревог не зависит.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct FactionAlerts {
+    pub territories: Vec<AlertedTerritory>,
+}
+
+impl FactionAlerts {
+    /// Позиция на территории фракции, находящейся в тревоге (XZ)
+    pub fn is_alerted(&self, faction_id: u64, position: Vec3) -> bool {
+        self.territories.iter().any(|territory| {
+            territory.faction_id == faction_id
+                && Vec2::new(position.x - territory.center.x, position.z - territory.center.z).length()
+                    <= territory.radius
+        })
+    }
+
+    /// Поднять (или продлить) тревогу территории панели
+    pub fn raise(&mut self, territory: AlertedTerritory) {
+        match self.territories.iter_mut().find(|t| t.panel == territory.panel) {
+            Some(existing) => *existing = territory,
+            None => self.territories.push(territory),
+        }
+    }
+
+    /// Удалить истёкшие тревоги
+    pub fn expire(&mut self, tick: u64) {
+        self.territories.retain(|territory| tick < territory.until_tick);
+    }
+}
@@ -0,0 +1,62 @@
+//! Tests for security components (alarm panels, faction alerts).
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::*;
+
+    fn territory(faction_id: u64, panel: Entity, until_tick: u64) -> AlertedTerritory {
+        AlertedTerritory {
+            faction_id,
+            panel,
+            center: Vec3::ZERO,
+            radius: 10.0,
+            intruder: Entity::from_raw(99),
+            until_tick,
+        }
+    }
+
+    #[test]
+    fn panel_covers_territory_ignoring_height() {
+        let panel = AlarmPanel::new(1, Vec3::ZERO, 10.0, 2);
+
+        assert!(panel.covers(Vec3::new(6.0, 5.0, 6.0)));
+        assert!(!panel.covers(Vec3::new(8.0, 0.0, 8.0)));
+    }
+
+    #[test]
+    fn disabled_panel_cannot_be_hacked() {
+        let mut panel = AlarmPanel::new(1, Vec3::ZERO, 10.0, 2);
+        assert!(panel.can_be_hacked_from(Vec3::new(1.5, 0.0, 0.0)));
+        assert!(!panel.can_be_hacked_from(Vec3::new(3.0, 0.0, 0.0)));
+
+        panel.state = AlarmPanelState::Disabled;
+        assert!(!panel.can_be_hacked_from(Vec3::ZERO));
+        assert!(!panel.is_armed());
+    }
+
+    #[test]
+    fn alerts_are_per_faction_and_expire() {
+        let mut alerts = FactionAlerts::default();
+        alerts.raise(territory(1, Entity::from_raw(1), 100));
+
+        assert!(alerts.is_alerted(1, Vec3::new(5.0, 0.0, 0.0)));
+        assert!(!alerts.is_alerted(2, Vec3::new(5.0, 0.0, 0.0)));
+        assert!(!alerts.is_alerted(1, Vec3::new(20.0, 0.0, 0.0)));
+
+        alerts.expire(100);
+        assert!(!alerts.is_alerted(1, Vec3::ZERO));
+    }
+
+    #[test]
+    fn raising_same_panel_extends_alert() {
+        let mut alerts = FactionAlerts::default();
+        let panel = Entity::from_raw(1);
+        alerts.raise(territory(1, panel, 100));
+        alerts.raise(territory(1, panel, 200));
+
+        assert_eq!(alerts.territories.len(), 1);
+        alerts.expire(150);
+        assert!(alerts.is_alerted(1, Vec3::ZERO));
+    }
+}
@@ -0,0 +1,42 @@
+//! Security events.
+
+use bevy::prelude::*;
+
+/// Тревожная панель сработала (ECS → ECS)
+///
+/// Генерируется `trigger_alarm_panels`. Обрабатывается `alert_faction_territories`.
+#[derive(Event, Debug, Clone)]
+pub struct AlarmTriggered {
+    pub panel: Entity,
+    pub faction_id: u64,
+    /// Член фракции, заметивший нарушителя
+    pub observer: Entity,
+    pub intruder: Entity,
+}
+
+/// Запрос подкреплений (ECS → director)
+///
+/// Director (Godot spawn layer) спавнит `count` бойцов фракции в `position`,
+/// сразу нацеленных на `intruder`.
+#[derive(Event, Debug, Clone)]
+pub struct ReinforcementsRequested {
+    pub faction_id: u64,
+    pub position: Vec3,
+    pub count: u32,
+    pub intruder: Entity,
+}
+
+/// Intent: актор начинает взлом ближайшей панели (player interact / AI / скрипт)
+///
+/// Обрабатывается `start_alarm_panel_hacks` → Channeling(Hack).
+#[derive(Event, Debug, Clone)]
+pub struct HackAlarmPanelIntent {
+    pub actor: Entity,
+}
+
+/// Панель взломана (Disabled)
+#[derive(Event, Debug, Clone)]
+pub struct AlarmPanelDisabled {
+    pub panel: Entity,
+    pub hacker: Entity,
+}
@@ -0,0 +1,54 @@
+//! Security module — тревожные панели и территории фракций
+//!
+//! # Architecture
+//!
+//! Связывает stealth (обнаружение), фракции и спавн:
+//!
+//! **Flow:**
+//! - `GodotAIEvent::ActorSpotted` на территории панели → `AlarmTriggered`
+//! - Тревога: территория фракции Alerted (`FactionAlerts`), члены фракции на
+//!   территории получают нарушителя, director получает `ReinforcementsRequested`
+//! - `HackAlarmPanelIntent` → Channeling(Hack) → ChannelCompleted → панель Disabled
+//!
+//! Сработавшая панель взводится снова через `AlarmPanel::ALERT_DURATION`.
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Security Plugin
+///
+/// Регистрирует тревожные панели в FixedUpdate (после per-actor AI: читает ActorSpotted).
+pub struct SecurityPlugin;
+
+impl Plugin for SecurityPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<AlarmTriggered>()
+            .add_event::<ReinforcementsRequested>()
+            .add_event::<HackAlarmPanelIntent>()
+            .add_event::<AlarmPanelDisabled>()
+            .init_resource::<FactionAlerts>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    update_alarm_panels,         // 1. Истечение тревог, повторное взведение
+                    trigger_alarm_panels,        // 2. ActorSpotted на территории → AlarmTriggered
+                    alert_faction_territories,   // 3. Территория Alerted + подкрепления
+                    start_alarm_panel_hacks,     // 4. HackAlarmPanelIntent → Channeling(Hack)
+                    complete_alarm_panel_hacks,  // 5. ChannelCompleted(Hack) → Disabled
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,237 @@
+//! Security systems (alarm panels, territory alerts, hacking).
+
+use bevy::prelude::*;
+use crate::ai::{AIState, GodotAIEvent};
+use crate::combat::{
+    ActionKind, ActionLock, ActionPhase, CancelTable, ChannelCompleted, ChannelInterrupted, ChannelKind, Channeling,
+};
+use crate::components::Actor;
+use crate::{SimulationTick, StrategicPosition};
+use super::components::{AlarmPanel, AlarmPanelState, AlertedTerritory, FactionAlerts, HackingPanel};
+use super::events::{AlarmPanelDisabled, AlarmTriggered, HackAlarmPanelIntent, ReinforcementsRequested};
+
+/// System: обнаружение нарушителя на территории → тревожная панель срабатывает
+///
+/// ActorSpotted, где наблюдатель — член фракции панели, и оба (наблюдатель и
+/// нарушитель) на территории. Одна тревога на панель, пока она не взведена снова.
+pub fn trigger_alarm_panels(
+    mut ai_events: EventReader<GodotAIEvent>,
+    mut panels: Query<(Entity, &mut AlarmPanel)>,
+    actors: Query<(&Actor, &StrategicPosition)>,
+    tick: Res<SimulationTick>,
+    mut triggered_events: EventWriter<AlarmTriggered>,
+) {
+    for event in ai_events.read() {
+        let GodotAIEvent::ActorSpotted { observer, target } = event else {
+            continue;
+        };
+
+        let Ok((observer_actor, observer_pos)) = actors.get(*observer) else {
+            continue;
+        };
+        let Ok((target_actor, target_pos)) = actors.get(*target) else {
+            continue;
+        };
+        if observer_actor.faction_id == target_actor.faction_id {
+            continue;
+        }
+
+        let observer_pos = observer_pos.to_world_position(0.5);
+        let target_pos = target_pos.to_world_position(0.5);
+
+        for (panel_entity, mut panel) in panels.iter_mut() {
+            if panel.faction_id != observer_actor.faction_id || !panel.is_armed() {
+                continue;
+            }
+            if !panel.covers(observer_pos) || !panel.covers(target_pos) {
+                continue;
+            }
+
+            panel.state = AlarmPanelState::Triggered {
+                rearm_at_tick: tick.after_secs(AlarmPanel::ALERT_DURATION),
+            };
+
+            crate::logger::log(&format!(
+                "🚨 Alarm panel {:?} (faction {}): {:?} spotted intruder {:?}",
+                panel_entity, panel.faction_id, observer, target
+            ));
+            triggered_events.write(AlarmTriggered {
+                panel: panel_entity,
+                faction_id: panel.faction_id,
+                observer: *observer,
+                intruder: *target,
+            });
+        }
+    }
+}
+
+/// System: тревога → вся территория фракции Alerted + подкрепления
+///
+/// - FactionAlerts: территория панели в тревоге на `ALERT_DURATION`
+/// - Члены фракции на территории (не в бою, живые) получают ActorSpotted нарушителя
+/// - `reinforcement_count > 0` → ReinforcementsRequested (director спавнит бойцов)
+pub fn alert_faction_territories(
+    mut triggered_events: EventReader<AlarmTriggered>,
+    panels: Query<&AlarmPanel>,
+    actors: Query<(Entity, &Actor, &StrategicPosition, &AIState)>,
+    tick: Res<SimulationTick>,
+    mut alerts: ResMut<FactionAlerts>,
+    mut spotted_events: EventWriter<GodotAIEvent>,
+    mut reinforcement_events: EventWriter<ReinforcementsRequested>,
+) {
+    for alarm in triggered_events.read() {
+        let Ok(panel) = panels.get(alarm.panel) else {
+            continue;
+        };
+
+        alerts.raise(AlertedTerritory {
+            faction_id: panel.faction_id,
+            panel: alarm.panel,
+            center: panel.territory_center,
+            radius: panel.territory_radius,
+            intruder: alarm.intruder,
+            until_tick: tick.after_secs(AlarmPanel::ALERT_DURATION),
+        });
+
+        let mut responders = 0;
+        for (entity, actor, position, state) in actors.iter() {
+            if entity == alarm.observer || actor.faction_id != panel.faction_id {
+                continue;
+            }
+
+            // Занятые боем и мёртвые тревогу игнорируют
+            if matches!(state, AIState::Combat { .. } | AIState::Dead) {
+                continue;
+            }
+
+            if !panel.covers(position.to_world_position(0.5)) {
+                continue;
+            }
+
+            spotted_events.write(GodotAIEvent::ActorSpotted {
+                observer: entity,
+                target: alarm.intruder,
+            });
+            responders += 1;
+        }
+
+        if panel.reinforcement_count > 0 {
+            reinforcement_events.write(ReinforcementsRequested {
+                faction_id: panel.faction_id,
+                position: panel.reinforcement_point,
+                count: panel.reinforcement_count,
+                intruder: alarm.intruder,
+            });
+        }
+
+        crate::logger::log(&format!(
+            "📢 Faction {} territory alerted by panel {:?}: {} responders, {} reinforcements",
+            panel.faction_id, alarm.panel, responders, panel.reinforcement_count
+        ));
+    }
+}
+
+/// System: истечение тревог + повторное взведение сработавших панелей
+pub fn update_alarm_panels(
+    mut panels: Query<&mut AlarmPanel>,
+    tick: Res<SimulationTick>,
+    mut alerts: ResMut<FactionAlerts>,
+) {
+    let now = tick.get();
+
+    alerts.expire(now);
+
+    for mut panel in panels.iter_mut() {
+        if let AlarmPanelState::Triggered { rearm_at_tick } = panel.state {
+            if now >= rearm_at_tick {
+                panel.state = AlarmPanelState::Armed;
+            }
+        }
+    }
+}
+
+/// System: HackAlarmPanelIntent → Channeling(Hack) у ближайшей панели в `HACK_RANGE`
+///
+/// ActionLock + CancelTable должны разрешать Hack (не в атаке/парировании/stagger).
+/// Эффект применяется по ChannelCompleted (`complete_alarm_panel_hacks`).
+pub fn start_alarm_panel_hacks(
+    mut intents: EventReader<HackAlarmPanelIntent>,
+    hackers: Query<(&StrategicPosition, Option<&ActionLock>), Without<Channeling>>,
+    panels: Query<(Entity, &AlarmPanel)>,
+    cancel_table: Res<CancelTable>,
+    tick: Res<SimulationTick>,
+    mut commands: Commands,
+) {
+    for intent in intents.read() {
+        let Ok((position, lock)) = hackers.get(intent.actor) else {
+            continue;
+        };
+
+        let hacker_pos = position.to_world_position(0.5);
+        let nearest = panels
+            .iter()
+            .filter(|(_, panel)| panel.can_be_hacked_from(hacker_pos))
+            .min_by(|(_, a), (_, b)| {
+                a.position.distance(hacker_pos).total_cmp(&b.position.distance(hacker_pos))
+            });
+        let Some((panel_entity, _)) = nearest else {
+            continue;
+        };
+
+        if !ActionLock::permits(lock, ActionKind::Hack, &cancel_table) {
+            continue;
+        }
+
+        commands.entity(intent.actor).insert((
+            Channeling::new(ChannelKind::Hack, tick.after_secs(AlarmPanel::HACK_DURATION)),
+            ActionLock::new(ActionKind::Hack, ActionPhase::Active),
+            HackingPanel { panel: panel_entity },
+        ));
+
+        crate::logger::log(&format!(
+            "💻 {:?} started hacking alarm panel {:?}",
+            intent.actor, panel_entity
+        ));
+    }
+}
+
+/// System: завершённый Hack channel → панель Disabled; прерванный → взлом сброшен
+pub fn complete_alarm_panel_hacks(
+    mut completed_events: EventReader<ChannelCompleted>,
+    mut interrupted_events: EventReader<ChannelInterrupted>,
+    hackers: Query<&HackingPanel>,
+    mut panels: Query<&mut AlarmPanel>,
+    mut disabled_events: EventWriter<AlarmPanelDisabled>,
+    mut commands: Commands,
+) {
+    for completed in completed_events.read() {
+        if completed.kind != ChannelKind::Hack {
+            continue;
+        }
+        let Ok(hacking) = hackers.get(completed.entity) else {
+            continue;
+        };
+
+        commands.entity(completed.entity).remove::<HackingPanel>();
+
+        let Ok(mut panel) = panels.get_mut(hacking.panel) else {
+            continue;
+        };
+        panel.state = AlarmPanelState::Disabled;
+
+        crate::logger::log(&format!(
+            "🔓 Alarm panel {:?} disabled by {:?}",
+            hacking.panel, completed.entity
+        ));
+        disabled_events.write(AlarmPanelDisabled {
+            panel: hacking.panel,
+            hacker: completed.entity,
+        });
+    }
+
+    for interrupted in interrupted_events.read() {
+        if interrupted.kind == ChannelKind::Hack && hackers.contains(interrupted.entity) {
+            commands.entity(interrupted.entity).remove::<HackingPanel>();
+        }
+    }
+}
@@ -0,0 +1,124 @@
+//! Session components (отпечаток баланс-данных, handshake пиров).
+
+use bevy::prelude::*;
+use std::fmt::Debug;
+use crate::crafting::RecipeBook;
+use crate::interaction::LootTable;
+use crate::item_system::ItemDefinitions;
+
+/// Набор баланс-данных, входящий в отпечаток
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum DataSet {
+    /// ItemDefinitions (оружие, броня, consumables)
+    Items,
+    /// LootTable (data/… RON)
+    LootTables,
+    /// RecipeBook (data/recipes.ron)
+    Recipes,
+}
+
+/// Отпечаток баланс-данных (FNV-1a 64 по каждому набору)
+///
+/// Стабилен между машинами: ключи HashMap сортируются, хэш не зависит от RandomState.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub struct DataFingerprint {
+    pub items: u64,
+    pub loot_tables: u64,
+    pub recipes: u64,
+}
+
+impl DataFingerprint {
+    pub fn compute(items: &ItemDefinitions, loot: &LootTable, recipes: &RecipeBook) -> Self {
+        Self {
+            items: hash_entries(items.all_ids().into_iter().filter_map(|id| items.get(id).map(|item| (&id.0, item)))),
+            loot_tables: hash_entries(loot.tables.iter()),
+            recipes: hash_entries(recipes.recipes.iter()),
+        }
+    }
+
+    /// Один хэш на все наборы (лог / UI)
+    pub fn combined(&self) -> u64 {
+        let mut hash = FNV_OFFSET;
+        for part in [self.items, self.loot_tables, self.recipes] {
+            hash = fnv1a(hash, &part.to_le_bytes());
+        }
+        hash
+    }
+
+    /// Наборы, отличающиеся от `other`
+    pub fn mismatches(&self, other: &Self) -> Vec<DataSet> {
+        [
+            (DataSet::Items, self.items != other.items),
+            (DataSet::LootTables, self.loot_tables != other.loot_tables),
+            (DataSet::Recipes, self.recipes != other.recipes),
+        ]
+        .into_iter()
+        .filter_map(|(set, differs)| differs.then_some(set))
+        .collect()
+    }
+}
+
+/// Handshake сессии (отправляется пиру при подключении)
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub struct SessionHandshake {
+    pub protocol_version: u32,
+    pub fingerprint: DataFingerprint,
+}
+
+/// Причина отказа пиру
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandshakeRejection {
+    /// Разные версии протокола
+    ProtocolVersion { local: u32, remote: u32 },
+    /// Локально изменённые data-файлы (какие наборы расходятся)
+    DataMismatch(Vec<DataSet>),
+}
+
+impl SessionHandshake {
+    /// Версия протокола сессии (поднимать при изменении формата handshake)
+    pub const PROTOCOL_VERSION: u32 = 1;
+
+    pub fn new(fingerprint: DataFingerprint) -> Self {
+        Self {
+            protocol_version: Self::PROTOCOL_VERSION,
+            fingerprint,
+        }
+    }
+
+    /// Проверить handshake пира против локального
+    pub fn verify(&self, remote: &SessionHandshake) -> Result<(), HandshakeRejection> {
+        if self.protocol_version != remote.protocol_version {
+            return Err(HandshakeRejection::ProtocolVersion {
+                local: self.protocol_version,
+                remote: remote.protocol_version,
+            });
+        }
+        let mismatches = self.fingerprint.mismatches(&remote.fingerprint);
+        if !mismatches.is_empty() {
+            return Err(HandshakeRejection::DataMismatch(mismatches));
+        }
+        Ok(())
+    }
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+/// Хэш записей `(ключ, значение)` в порядке ключей (Debug — каноничное представление)
+fn hash_entries<'a, V: Debug + 'a>(entries: impl Iterator<Item = (&'a String, &'a V)>) -> u64 {
+    let mut entries: Vec<_> = entries.collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+
+    entries.into_iter().fold(FNV_OFFSET, |hash, (key, value)| {
+        let hash = fnv1a(hash, key.as_bytes());
+        fnv1a(hash, format!("{:?}", value).as_bytes())
+    })
+}
@@ -0,0 +1,44 @@
+//! Tests for session handshake (стабильность отпечатка, отказ при расхождении).
+
+#[cfg(test)]
+mod tests {
+    use super::super::components::*;
+    use crate::crafting::RecipeBook;
+    use crate::interaction::LootTable;
+    use crate::item_system::ItemDefinitions;
+
+    fn default_fingerprint() -> DataFingerprint {
+        DataFingerprint::compute(&ItemDefinitions::default(), &LootTable::default(), &RecipeBook::default())
+    }
+
+    #[test]
+    fn test_fingerprint_is_stable_for_same_data() {
+        assert_eq!(default_fingerprint(), default_fingerprint());
+        assert_ne!(default_fingerprint().combined(), 0);
+    }
+
+    #[test]
+    fn test_edited_data_is_rejected() {
+        let local = SessionHandshake::new(default_fingerprint());
+
+        let mut recipes = RecipeBook::default();
+        recipes.recipes.get_mut("health_kit").unwrap().duration = 0.1;
+        let edited = SessionHandshake::new(DataFingerprint::compute(
+            &ItemDefinitions::default(),
+            &LootTable::default(),
+            &recipes,
+        ));
+
+        assert_eq!(local.verify(&local), Ok(()));
+        assert_eq!(local.verify(&edited), Err(HandshakeRejection::DataMismatch(vec![DataSet::Recipes])));
+
+        let outdated = SessionHandshake {
+            protocol_version: 0,
+            ..local
+        };
+        assert_eq!(
+            local.verify(&outdated),
+            Err(HandshakeRejection::ProtocolVersion { local: 1, remote: 0 })
+        );
+    }
+}
@@ -0,0 +1,25 @@
+//! Session events.
+
+use bevy::prelude::*;
+use super::components::{HandshakeRejection, SessionHandshake};
+
+/// Handshake от пира получен (сетевой слой, когда появятся networked режимы)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PeerHandshakeReceived {
+    /// ID пира в сетевом слое
+    pub peer: u64,
+    pub handshake: SessionHandshake,
+}
+
+/// Пир принят (данные совпадают)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct PeerAccepted {
+    pub peer: u64,
+}
+
+/// Пир отклонён — диагностика для UI / лога (сетевой слой разрывает соединение)
+#[derive(Event, Debug, Clone)]
+pub struct PeerRejected {
+    pub peer: u64,
+    pub reason: HandshakeRejection,
+}
@@ -0,0 +1,49 @@
+//! Session module — handshake сетевой сессии с отпечатком баланс-данных
+//!
+//! # Architecture
+//!
+//! **Flow:**
+//! - `refresh_session_handshake`: ItemDefinitions / LootTable / RecipeBook → `DataFingerprint`
+//!   в `SessionHandshake` (пересчёт при изменении resources)
+//! - Сетевой слой отправляет `SessionHandshake` пиру и пишет ответный `PeerHandshakeReceived`
+//! - `verify_peer_handshakes` → `PeerAccepted` / `PeerRejected { reason }` (какие наборы расходятся)
+//!
+//! Защита от тихого рассинхрона: локально изменённые data-файлы не дают подключиться.
+//! NOTE: networked режимов пока нет — модуль готовит handshake, транспорт подключится позже.
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Session Plugin
+///
+/// Регистрирует локальный handshake и проверку пиров в FixedUpdate.
+pub struct SessionPlugin;
+
+impl Plugin for SessionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PeerHandshakeReceived>()
+            .add_event::<PeerAccepted>()
+            .add_event::<PeerRejected>()
+            .init_resource::<SessionHandshake>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    refresh_session_handshake, // 1. Баланс-данные → отпечаток локального handshake
+                    verify_peer_handshakes,    // 2. Handshake пира → принят / отклонён
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,61 @@
+//! Session systems (локальный отпечаток данных, проверка handshake пиров).
+
+use bevy::prelude::*;
+use crate::crafting::RecipeBook;
+use crate::interaction::LootTable;
+use crate::item_system::ItemDefinitions;
+use super::components::{DataFingerprint, SessionHandshake};
+use super::events::{PeerAccepted, PeerHandshakeReceived, PeerRejected};
+
+/// System: баланс-данные изменились (загрузка / подмена resource) → пересчёт локального handshake
+pub fn refresh_session_handshake(
+    items: Res<ItemDefinitions>,
+    loot: Res<LootTable>,
+    recipes: Res<RecipeBook>,
+    mut handshake: ResMut<SessionHandshake>,
+) {
+    // Default (version 0) — отпечаток ещё не посчитан
+    let first_run = handshake.protocol_version == 0;
+    if !first_run && !items.is_changed() && !loot.is_changed() && !recipes.is_changed() {
+        return;
+    }
+
+    let fresh = SessionHandshake::new(DataFingerprint::compute(&items, &loot, &recipes));
+    if *handshake != fresh {
+        *handshake = fresh;
+        crate::logger::log(&format!(
+            "🔐 Session data fingerprint {:016x} ({:?})",
+            fresh.fingerprint.combined(),
+            fresh.fingerprint
+        ));
+    }
+}
+
+/// System: PeerHandshakeReceived → PeerAccepted / PeerRejected (версия протокола, расхождение данных)
+pub fn verify_peer_handshakes(
+    mut received_events: EventReader<PeerHandshakeReceived>,
+    handshake: Res<SessionHandshake>,
+    mut accepted_events: EventWriter<PeerAccepted>,
+    mut rejected_events: EventWriter<PeerRejected>,
+) {
+    for received in received_events.read() {
+        match handshake.verify(&received.handshake) {
+            Ok(()) => {
+                accepted_events.write(PeerAccepted { peer: received.peer });
+            }
+            Err(reason) => {
+                crate::logger::log_warning(&format!(
+                    "🔐 Peer {} rejected: {:?} (local {:016x}, remote {:016x})",
+                    received.peer,
+                    reason,
+                    handshake.fingerprint.combined(),
+                    received.handshake.fingerprint.combined()
+                ));
+                rejected_events.write(PeerRejected {
+                    peer: received.peer,
+                    reason,
+                });
+            }
+        }
+    }
+}
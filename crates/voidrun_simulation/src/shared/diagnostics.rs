@@ -0,0 +1,182 @@
+//! Diagnostics — ошибки моста ECS ↔ Godot и «expectation failed» события
+//!
+//! Godot-слой раньше молча делал `continue`, если node не нашлась — баги сетапа (нет сокета,
+//! prefab без `Hitbox`) проявлялись как «ничего не происходит». Теперь lookup возвращает
+//! `BridgeError`, система пишет `ExpectationFailed`, `ExpectationLog` схлопывает повторы
+//! (один и тот же промах каждый кадр = одна запись со счётчиком) → debug overlay.
+
+use bevy::prelude::*;
+use std::collections::HashSet;
+use std::fmt;
+
+/// Ошибка моста ECS ↔ Godot
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BridgeError {
+    /// Entity нет в VisualRegistry (визуал не заспавнен / уже удалён)
+    VisualMissing,
+    /// Node по пути не найдена
+    NodeMissing { path: String },
+    /// Node найдена, но другого типа
+    NodeTypeMismatch { path: String, expected: String },
+    /// Prefab (PackedScene) не загрузился
+    PrefabLoadFailed { path: String },
+}
+
+impl BridgeError {
+    /// Путь node / ресурса, к которому относится ошибка ("" — без пути)
+    pub fn node_path(&self) -> &str {
+        match self {
+            BridgeError::VisualMissing => "",
+            BridgeError::NodeMissing { path }
+            | BridgeError::NodeTypeMismatch { path, .. }
+            | BridgeError::PrefabLoadFailed { path } => path,
+        }
+    }
+
+    /// Событие для системы `system` (entity — чей node искали)
+    pub fn expectation(&self, entity: Option<Entity>, system: &'static str) -> ExpectationFailed {
+        ExpectationFailed {
+            entity,
+            node_path: self.node_path().to_string(),
+            system,
+            reason: self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for BridgeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BridgeError::VisualMissing => write!(f, "entity has no visual node"),
+            BridgeError::NodeMissing { path } => write!(f, "node '{}' not found", path),
+            BridgeError::NodeTypeMismatch { path, expected } => {
+                write!(f, "node '{}' is not {}", path, expected)
+            }
+            BridgeError::PrefabLoadFailed { path } => write!(f, "failed to load prefab '{}'", path),
+        }
+    }
+}
+
+impl std::error::Error for BridgeError {}
+
+/// Событие: ожидание сетапа не выполнено (нет node / prefab'а) — поведение пропущено
+#[derive(Event, Debug, Clone)]
+pub struct ExpectationFailed {
+    pub entity: Option<Entity>,
+    pub node_path: String,
+    /// Имя системы, которая не смогла выполниться
+    pub system: &'static str,
+    pub reason: String,
+}
+
+/// Запись журнала (повторы того же system + path схлопнуты)
+#[derive(Debug, Clone)]
+pub struct ExpectationRecord {
+    pub system: &'static str,
+    pub node_path: String,
+    pub reason: String,
+    /// Последний entity с этим промахом
+    pub last_entity: Option<Entity>,
+    /// Сколько кадров промах повторялся
+    pub count: u32,
+}
+
+/// Журнал промахов (resource, читает debug overlay)
+///
+/// Дедуп на кадр: одно (entity, path, system) за кадр считается один раз.
+#[derive(Resource, Debug, Default)]
+pub struct ExpectationLog {
+    pub records: Vec<ExpectationRecord>,
+    frame_seen: HashSet<(Option<Entity>, String, &'static str)>,
+}
+
+impl ExpectationLog {
+    /// Сколько разных промахов держим (старые вытесняются)
+    pub const MAX_RECORDS: usize = 16;
+
+    /// Новый кадр — сбросить дедуп
+    pub fn begin_frame(&mut self) {
+        self.frame_seen.clear();
+    }
+
+    /// Учесть промах. true — запись новая (стоит залогировать)
+    pub fn record(&mut self, failed: &ExpectationFailed) -> bool {
+        let key = (failed.entity, failed.node_path.clone(), failed.system);
+        if !self.frame_seen.insert(key) {
+            return false;
+        }
+
+        if let Some(record) = self
+            .records
+            .iter_mut()
+            .find(|record| record.system == failed.system && record.node_path == failed.node_path)
+        {
+            record.count += 1;
+            record.last_entity = failed.entity;
+            return false;
+        }
+
+        self.records.push(ExpectationRecord {
+            system: failed.system,
+            node_path: failed.node_path.clone(),
+            reason: failed.reason.clone(),
+            last_entity: failed.entity,
+            count: 1,
+        });
+        if self.records.len() > Self::MAX_RECORDS {
+            self.records.remove(0);
+        }
+        true
+    }
+}
+
+/// System: ExpectationFailed → ExpectationLog (Last — после всех Godot систем кадра)
+pub fn record_expectation_failures(
+    mut events: EventReader<ExpectationFailed>,
+    mut log: ResMut<ExpectationLog>,
+) {
+    log.begin_frame();
+
+    for failed in events.read() {
+        if log.record(failed) {
+            crate::logger::log_error(&format!(
+                "⚠️ Expectation failed in {}: {} (entity {:?})",
+                failed.system, failed.reason, failed.entity
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn missing(entity: u32, path: &str) -> ExpectationFailed {
+        BridgeError::NodeMissing { path: path.to_string() }.expectation(Some(Entity::from_raw(entity)), "test_system")
+    }
+
+    #[test]
+    fn test_same_failure_deduped_within_frame() {
+        let mut log = ExpectationLog::default();
+        log.begin_frame();
+
+        assert!(log.record(&missing(1, "%BulletSpawn")));
+        assert!(!log.record(&missing(1, "%BulletSpawn")));
+        assert_eq!(log.records[0].count, 1);
+
+        log.begin_frame();
+        assert!(!log.record(&missing(1, "%BulletSpawn")));
+        assert_eq!(log.records[0].count, 2);
+    }
+
+    #[test]
+    fn test_records_capped_oldest_first() {
+        let mut log = ExpectationLog::default();
+        for index in 0..(ExpectationLog::MAX_RECORDS + 2) {
+            log.record(&missing(1, &format!("Node{}", index)));
+        }
+
+        assert_eq!(log.records.len(), ExpectationLog::MAX_RECORDS);
+        assert_eq!(log.records[0].node_path, "Node2");
+    }
+}
@@ -13,22 +13,28 @@
 //! - Слоты 3-5 unlock через armor bonus
 //! - Instant use (no equip/unequip)
 //!
-//! **Armor** — пассивная защита + визуал:
-//! - Defense rating (damage reduction)
+//! **EquippedArmor** — пассивная защита по слотам (Helmet / Chest / Legs / Boots):
+//! - Defense частей суммируется → damage reduction
 //! - Consumable slot bonus (unlock 7-9 hotkeys)
-//! - При equip → добавляем `Attachment` для визуала
+//! - Все 4 части одного комплекта → `ArmorSetBonus`
+//! - Визуал — prefab на attachment point слота
 //!
 //! **EnergyShield** — энергобарьер:
 //! - Блокирует только ranged урон (velocity > threshold)
 //! - Melee проходит сквозь щит (slow kinetic)
 //! - Recharge delay после получения урона
+//! - Покрытие (`ShieldCoverage`): сфера или фронтальная дуга
+//! - Генератор щита — броня с `ArmorStatsTemplate::shield` (смена части = смена щита)
 //!
 //! **Inventory** — общая свалка:
 //! - Unlimited capacity (пока)
-//! - Weight/volume limits позже
+//! - Стаки до `ItemDefinition::max_stack` (add_stacked / split_stack / merge_stacks)
+//! - Carry weight (ItemDefinition::weight) сверх `max_weight` → `Encumbered`
 
 use bevy::prelude::*;
-use crate::item_system::{ItemId, ItemInstance};
+use crate::item_system::{
+    Affix, ArmorSetBonus, ArmorSlot, ArmorStatsTemplate, ItemDefinitions, ItemId, ItemInstance, Rarity, ShieldTemplate,
+};
 
 // ============================================================================
 // EquippedWeapons (slots 1-4)
@@ -143,6 +149,80 @@ pub struct EquippedItem {
     pub durability: f32,
     /// Runtime ammo count (для ranged weapons)
     pub ammo_count: Option<u32>,
+    /// Редкость экземпляра
+    pub rarity: Rarity,
+    /// Rolled affixes экземпляра (применяются к WeaponStats)
+    pub affixes: Vec<Affix>,
+    /// Оружие заклинило (стрельба заблокирована до ClearJamIntent)
+    pub jammed: bool,
+}
+
+impl EquippedItem {
+    /// Экипировать экземпляр из инвентаря (прочность, патроны, affixes сохраняются)
+    pub fn from_instance(item: &ItemInstance) -> Self {
+        Self {
+            definition_id: item.definition_id.clone(),
+            durability: item.durability.unwrap_or(1.0),
+            ammo_count: item.ammo_count,
+            rarity: item.rarity,
+            affixes: item.affixes.clone(),
+            jammed: false,
+        }
+    }
+
+    /// Вернуть в инвентарь / дроп (клин при снятии устраняется)
+    pub fn to_instance(&self) -> ItemInstance {
+        ItemInstance {
+            definition_id: self.definition_id.clone(),
+            stack_size: 1,
+            durability: Some(self.durability),
+            ammo_count: self.ammo_count,
+            rarity: self.rarity,
+            affixes: self.affixes.clone(),
+        }
+    }
+
+    /// Визуальное состояние по прочности
+    pub fn condition(&self) -> ConditionTier {
+        ConditionTier::from_durability(self.durability)
+    }
+
+    /// Износ (прочность не уходит ниже 0)
+    pub fn wear(&mut self, amount: f32) {
+        self.durability = (self.durability - amount).max(0.0);
+    }
+
+    /// Ремонт (прочность не выше 1)
+    pub fn repair(&mut self, amount: f32) {
+        self.durability = (self.durability + amount).min(1.0);
+    }
+}
+
+/// Визуальное состояние снаряжения (оружие, броня) по прочности
+///
+/// Godot меняет параметры материала prefab'а по тиру — износ читается с первого взгляда.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+pub enum ConditionTier {
+    Pristine,
+    Worn,
+    Damaged,
+}
+
+impl ConditionTier {
+    /// Прочность ≥ этого порога — Pristine
+    pub const PRISTINE_THRESHOLD: f32 = 0.7;
+    /// Прочность ≥ этого порога — Worn, ниже — Damaged
+    pub const WORN_THRESHOLD: f32 = 0.3;
+
+    pub fn from_durability(durability: f32) -> Self {
+        if durability >= Self::PRISTINE_THRESHOLD {
+            Self::Pristine
+        } else if durability >= Self::WORN_THRESHOLD {
+            Self::Worn
+        } else {
+            Self::Damaged
+        }
+    }
 }
 
 // ============================================================================
@@ -221,24 +301,179 @@ impl ConsumableSlots {
 // Armor
 // ============================================================================
 
-/// Armor component (пассивная защита)
+/// Надетая часть брони (один слот `EquippedArmor`)
 ///
-/// # Lifecycle
-/// - При equip: добавляем Armor компонент + Attachment (визуал)
-/// - При unequip: удаляем оба компонента
-/// - Defense влияет на damage calculation
-/// - Consumable slot bonus unlock слоты 7-9
-#[derive(Component, Debug, Reflect)]
-#[reflect(Component)]
+/// Stats = template + affixes экземпляра; rarity/affixes сохраняются для возврата в Inventory.
+#[derive(Debug, Clone, Reflect)]
 pub struct Armor {
     /// Ссылка на definition
     pub definition_id: ItemId,
+    /// Слот (часть тела)
+    pub slot: ArmorSlot,
+    /// Комплект (бонус, когда все 4 слота из одного set)
+    pub set_id: Option<String>,
     /// Runtime durability (0.0-1.0)
     pub durability: f32,
+    pub rarity: Rarity,
+    pub affixes: Vec<Affix>,
     /// Defense rating (damage reduction)
     pub defense: u32,
     /// Consumable slot bonus (0-3 доп слота)
     pub consumable_slot_bonus: u8,
+    /// Запас кислорода шлема (секунды, см. Oxygen::capacity)
+    pub oxygen_bonus: f32,
+    /// Бонус регенерации stamina (доля, из affixes)
+    pub stamina_regen_bonus: f32,
+    /// Теплоизоляция (0..1, см. BodyTemperature)
+    pub insulation: f32,
+    /// Генератор энергощита (часть ставит `EnergyShield`)
+    pub shield: Option<ShieldTemplate>,
+}
+
+impl Armor {
+    /// Armor из template + affixes экземпляра (defense, регенерация stamina)
+    pub fn from_item(item: &ItemInstance, template: &ArmorStatsTemplate) -> Self {
+        let mut armor = Self {
+            definition_id: item.definition_id.clone(),
+            slot: template.slot,
+            set_id: template.set_id.clone(),
+            durability: item.durability.unwrap_or(1.0),
+            rarity: item.rarity,
+            affixes: item.affixes.clone(),
+            defense: template.defense,
+            consumable_slot_bonus: template.consumable_slot_bonus,
+            oxygen_bonus: template.oxygen_bonus,
+            stamina_regen_bonus: 0.0,
+            insulation: template.insulation,
+            shield: template.shield.clone(),
+        };
+        for affix in item.affixes.iter() {
+            match *affix {
+                Affix::Defense(bonus) => armor.defense += bonus,
+                Affix::StaminaRegenPercent(bonus) => armor.stamina_regen_bonus += bonus,
+                Affix::DamagePercent(_) | Affix::AttackSpeedPercent(_) => {}
+            }
+        }
+        armor
+    }
+
+    /// Вернуть в инвентарь / дроп
+    pub fn to_instance(&self) -> ItemInstance {
+        ItemInstance {
+            durability: Some(self.durability),
+            rarity: self.rarity,
+            affixes: self.affixes.clone(),
+            ..ItemInstance::new(self.definition_id.clone())
+        }
+    }
+
+    /// Визуальное состояние по прочности
+    pub fn condition(&self) -> ConditionTier {
+        ConditionTier::from_durability(self.durability)
+    }
+}
+
+/// Надетая броня по слотам (Helmet / Chest / Legs / Boots)
+///
+/// # Lifecycle
+/// - EquipArmorIntent → часть в слот из `ArmorStatsTemplate::slot` (старая → Inventory)
+/// - UnequipArmorIntent → слот освобождается (часть → Inventory)
+/// - Stats суммируются по частям + бонус полного комплекта (`set_bonus`)
+/// - Defense → снижение урона (`reduce_damage`), consumable bonus unlock слоты 7-9
+/// - Визуал — per-slot attachment points (`ArmorSlot::attachment_point`)
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct EquippedArmor {
+    pub pieces: [Option<Armor>; 4],
+    /// Бонус комплекта (пересчитывается `refresh_set_bonus` при смене частей)
+    pub set_bonus: Option<ArmorSetBonus>,
+}
+
+impl EquippedArmor {
+    /// Defense, при которой урон снижается вдвое
+    pub const HALF_REDUCTION_DEFENSE: f32 = 100.0;
+    /// Максимум доп. consumable слотов (2 базовых + 3 = 5)
+    pub const MAX_CONSUMABLE_SLOT_BONUS: u8 = 3;
+
+    pub fn get(&self, slot: ArmorSlot) -> Option<&Armor> {
+        self.pieces[slot.index()].as_ref()
+    }
+
+    /// Надеть часть в её слот, вернуть снятую
+    pub fn equip(&mut self, armor: Armor) -> Option<Armor> {
+        self.pieces[armor.slot.index()].replace(armor)
+    }
+
+    /// Освободить слот
+    pub fn unequip(&mut self, slot: ArmorSlot) -> Option<Armor> {
+        self.pieces[slot.index()].take()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pieces.iter().all(Option::is_none)
+    }
+
+    /// Надетые части
+    pub fn iter(&self) -> impl Iterator<Item = &Armor> {
+        self.pieces.iter().flatten()
+    }
+
+    /// set_id, если все 4 слота заняты частями одного комплекта
+    pub fn full_set_id(&self) -> Option<&str> {
+        let first = self.pieces[0].as_ref()?.set_id.as_deref()?;
+        self.pieces
+            .iter()
+            .all(|piece| piece.as_ref().and_then(|piece| piece.set_id.as_deref()) == Some(first))
+            .then_some(first)
+    }
+
+    /// Пересчитать бонус комплекта после смены частей
+    pub fn refresh_set_bonus(&mut self, definitions: &ItemDefinitions) {
+        self.set_bonus = self.full_set_id().and_then(|set_id| definitions.armor_set(set_id)).cloned();
+    }
+
+    /// Суммарная defense (части + комплект)
+    pub fn defense(&self) -> u32 {
+        self.iter().map(|piece| piece.defense).sum::<u32>() + self.set_bonus.as_ref().map_or(0, |bonus| bonus.defense)
+    }
+
+    /// Доля поглощаемого урона: defense / (defense + HALF_REDUCTION_DEFENSE)
+    pub fn damage_reduction(&self) -> f32 {
+        let defense = self.defense() as f32;
+        defense / (defense + Self::HALF_REDUCTION_DEFENSE)
+    }
+
+    /// Урон после брони (попадание не обнуляется)
+    pub fn reduce_damage(&self, damage: u32) -> u32 {
+        if damage == 0 {
+            return 0;
+        }
+        ((damage as f32 * (1.0 - self.damage_reduction())).round() as u32).max(1)
+    }
+
+    pub fn consumable_slot_bonus(&self) -> u8 {
+        self.iter()
+            .map(|piece| piece.consumable_slot_bonus)
+            .sum::<u8>()
+            .min(Self::MAX_CONSUMABLE_SLOT_BONUS)
+    }
+
+    pub fn oxygen_bonus(&self) -> f32 {
+        self.iter().map(|piece| piece.oxygen_bonus).sum::<f32>()
+            + self.set_bonus.as_ref().map_or(0.0, |bonus| bonus.oxygen_bonus)
+    }
+
+    pub fn stamina_regen_bonus(&self) -> f32 {
+        self.iter().map(|piece| piece.stamina_regen_bonus).sum::<f32>()
+            + self.set_bonus.as_ref().map_or(0.0, |bonus| bonus.stamina_regen_bonus)
+    }
+
+    /// Теплоизоляция (0..1)
+    pub fn insulation(&self) -> f32 {
+        (self.iter().map(|piece| piece.insulation).sum::<f32>()
+            + self.set_bonus.as_ref().map_or(0.0, |bonus| bonus.insulation))
+        .min(1.0)
+    }
 }
 
 // ============================================================================
@@ -256,8 +491,8 @@ pub struct Armor {
 ///
 /// # Usage
 /// - Всегда активен (пассивный компонент)
-/// - No equip/unequip (not item)
-/// - Faction-based stats (military = лучший щит)
+/// - Faction-based stats (military = лучший щит) или генератор в слоте брони
+///   (`ShieldTemplate` — equip части ставит щит, снятие убирает)
 #[derive(Component, Debug, Reflect)]
 #[reflect(Component)]
 pub struct EnergyShield {
@@ -283,6 +518,8 @@ pub struct EnergyShield {
     pub is_active: bool,
     /// Activation threshold (0.0-1.0, обычно 0.5 = 50%)
     pub activation_threshold: f32,
+    /// Покрытие: сфера или фронтальная дуга (направленный щит)
+    pub coverage: ShieldCoverage,
 }
 
 impl Default for EnergyShield {
@@ -296,6 +533,7 @@ impl Default for EnergyShield {
             recharge_timer: 0.0,
             is_active: true,           // Начинаем с активного щита (full energy)
             activation_threshold: 0.5, // 50% для активации (hysteresis)
+            coverage: ShieldCoverage::Sphere,
         }
     }
 }
@@ -312,9 +550,25 @@ impl EnergyShield {
             recharge_timer: 0.0,
             is_active: true,           // Full energy = active
             activation_threshold: 0.5, // 50% threshold
+            coverage: ShieldCoverage::Sphere,
         }
     }
 
+    /// Направленный щит (фронтальная дуга)
+    pub fn with_coverage(mut self, coverage: ShieldCoverage) -> Self {
+        self.coverage = coverage;
+        self
+    }
+
+    /// Принять энергию предыдущего щита (смена генератора): доля заряда и recharge delay сохраняются
+    pub fn carry_charge_from(&mut self, previous: &EnergyShield) {
+        let fraction = (previous.current_energy / previous.max_energy).clamp(0.0, 1.0);
+        self.current_energy = self.max_energy * fraction;
+        self.recharge_timer = previous.recharge_timer;
+        self.is_active = previous.is_active;
+        self.update_active_state();
+    }
+
     /// Military shield preset (лучший)
     pub fn military() -> Self {
         Self::new(500.0, 20.0, 2.0)
@@ -354,6 +608,11 @@ impl EnergyShield {
         // Иначе сохраняем текущее состояние (hysteresis)
     }
 
+    /// Хватает ли энергии активного щита на расход `cost` (толчок щитом)
+    pub fn can_spend(&self, cost: f32) -> bool {
+        self.is_active && self.current_energy >= cost
+    }
+
     /// Получить урон (уменьшить energy)
     pub fn take_damage(&mut self, damage: f32) {
         self.current_energy -= damage;
@@ -380,6 +639,49 @@ impl EnergyShield {
     }
 }
 
+/// Покрытие энергощита
+#[derive(Clone, Copy, Debug, Default, PartialEq, Reflect)]
+pub enum ShieldCoverage {
+    /// Полная сфера (любое направление)
+    #[default]
+    Sphere,
+    /// Фронтальная дуга: попадания в пределах `half_angle_degrees` от взгляда актора
+    FrontArc { half_angle_degrees: f32 },
+}
+
+impl ShieldCoverage {
+    /// Cos половины угла дуги (сфера = -1.0, покрывает всё) — для проверки и шейдера
+    pub fn arc_cos(&self) -> f32 {
+        match *self {
+            ShieldCoverage::Sphere => -1.0,
+            ShieldCoverage::FrontArc { half_angle_degrees } => half_angle_degrees.to_radians().cos(),
+        }
+    }
+
+    /// Щит закрывает не все направления
+    pub fn is_directional(&self) -> bool {
+        *self != ShieldCoverage::Sphere
+    }
+
+    /// Прикрывает ли щит точку попадания
+    ///
+    /// `forward` — взгляд актора, `to_impact` — от центра актора к точке попадания.
+    /// Сравнение в горизонтальной плоскости (попадание сверху/снизу по центру — в щит).
+    pub fn covers(&self, forward: Vec3, to_impact: Vec3) -> bool {
+        if !self.is_directional() {
+            return true;
+        }
+
+        let forward = Vec3::new(forward.x, 0.0, forward.z).normalize_or_zero();
+        let to_impact = Vec3::new(to_impact.x, 0.0, to_impact.z).normalize_or_zero();
+        if forward == Vec3::ZERO || to_impact == Vec3::ZERO {
+            return true;
+        }
+
+        forward.dot(to_impact) >= self.arc_cos()
+    }
+}
+
 // ============================================================================
 // Inventory (общая свалка)
 // ============================================================================
@@ -388,7 +690,7 @@ impl EnergyShield {
 ///
 /// # Architecture
 /// - Vec<ItemInstance> для хранения items
-/// - Unlimited capacity пока (weight/volume позже)
+/// - Unlimited capacity пока (по числу слотов), вес — `max_weight`
 /// - Используется для:
 ///   - Loot pickup
 ///   - Crafting materials
@@ -401,6 +703,10 @@ pub struct Inventory {
     pub items: Vec<ItemInstance>,
     /// Capacity (unlimited пока)
     pub capacity: usize,
+    /// Кредиты (торговля с Merchant)
+    pub currency: u32,
+    /// Максимальный вес без штрафов (кг), сверх — `Encumbered`
+    pub max_weight: f32,
 }
 
 impl Default for Inventory {
@@ -410,12 +716,38 @@ impl Default for Inventory {
 }
 
 impl Inventory {
+    /// Грузоподъёмность по умолчанию (кг)
+    pub const DEFAULT_MAX_WEIGHT: f32 = 30.0;
+
     /// Создать пустой inventory
     pub fn empty() -> Self {
         Self {
             items: Vec::new(),
             capacity: usize::MAX, // Unlimited пока
+            currency: 0,
+            max_weight: Self::DEFAULT_MAX_WEIGHT,
+        }
+    }
+
+    /// Builder: грузоподъёмность
+    pub fn with_max_weight(mut self, max_weight: f32) -> Self {
+        self.max_weight = max_weight;
+        self
+    }
+
+    /// Builder: стартовые кредиты
+    pub fn with_currency(mut self, currency: u32) -> Self {
+        self.currency = currency;
+        self
+    }
+
+    /// Списать кредиты. false — не хватает, баланс не изменён.
+    pub fn spend_currency(&mut self, amount: u32) -> bool {
+        if self.currency < amount {
+            return false;
         }
+        self.currency -= amount;
+        true
     }
 
     /// Добавить item
@@ -423,6 +755,75 @@ impl Inventory {
         self.items.push(item);
     }
 
+    /// Добавить item, досыпая в неполные стаки того же предмета (до `ItemDefinition::max_stack`).
+    /// Остаток — новыми стаками.
+    pub fn add_stacked(&mut self, mut item: ItemInstance, definitions: &ItemDefinitions) {
+        let max_stack = definitions.max_stack(&item.definition_id);
+        let template = item.clone();
+
+        for stack in self.items.iter_mut().filter(|stack| stack.can_stack_with(&template)) {
+            if item.stack_size == 0 {
+                return;
+            }
+            let moved = max_stack.saturating_sub(stack.stack_size).min(item.stack_size);
+            stack.stack_size += moved;
+            item.stack_size -= moved;
+        }
+
+        while item.stack_size > max_stack {
+            let mut chunk = item.clone();
+            chunk.stack_size = max_stack;
+            item.stack_size -= max_stack;
+            self.items.push(chunk);
+        }
+        if item.stack_size > 0 {
+            self.items.push(item);
+        }
+    }
+
+    /// Отделить `count` штук стака `index` в новый слот (сразу после исходного).
+    /// false — нет слота или `count` не в 1..stack_size.
+    pub fn split_stack(&mut self, index: usize, count: u32) -> bool {
+        let Some(stack) = self.items.get_mut(index) else {
+            return false;
+        };
+        if count == 0 || count >= stack.stack_size {
+            return false;
+        }
+
+        stack.stack_size -= count;
+        let mut split = stack.clone();
+        split.stack_size = count;
+        self.items.insert(index + 1, split);
+        true
+    }
+
+    /// Пересыпать стак `from` в `into` (до `ItemDefinition::max_stack`), опустевший `from` удаляется.
+    /// false — те же/несуществующие слоты, разные предметы или `into` полон.
+    pub fn merge_stacks(&mut self, from: usize, into: usize, definitions: &ItemDefinitions) -> bool {
+        if from == into || from >= self.items.len() || into >= self.items.len() {
+            return false;
+        }
+        if !self.items[from].can_stack_with(&self.items[into]) {
+            return false;
+        }
+
+        let max_stack = definitions.max_stack(&self.items[into].definition_id);
+        let moved = max_stack
+            .saturating_sub(self.items[into].stack_size)
+            .min(self.items[from].stack_size);
+        if moved == 0 {
+            return false;
+        }
+
+        self.items[into].stack_size += moved;
+        self.items[from].stack_size -= moved;
+        if self.items[from].stack_size == 0 {
+            self.items.remove(from);
+        }
+        true
+    }
+
     /// Удалить item по индексу
     pub fn remove_item(&mut self, index: usize) -> Option<ItemInstance> {
         if index < self.items.len() {
@@ -439,6 +840,48 @@ impl Inventory {
             .position(|item| item.definition_id == *definition_id)
     }
 
+    /// Суммарное количество предмета (с учётом stack_size)
+    pub fn count_item(&self, definition_id: &ItemId) -> u32 {
+        self.items
+            .iter()
+            .filter(|item| item.definition_id == *definition_id)
+            .map(|item| item.stack_size)
+            .sum()
+    }
+
+    /// Списать `count` штук предмета (стаки уменьшаются, пустые удаляются).
+    /// false — не хватает, inventory не изменён.
+    pub fn consume_item(&mut self, definition_id: &ItemId, count: u32) -> bool {
+        if self.count_item(definition_id) < count {
+            return false;
+        }
+
+        let mut remaining = count;
+        for item in self.items.iter_mut().filter(|item| item.definition_id == *definition_id) {
+            let taken = item.stack_size.min(remaining);
+            item.stack_size -= taken;
+            remaining -= taken;
+            if remaining == 0 {
+                break;
+            }
+        }
+        self.items
+            .retain(|item| item.definition_id != *definition_id || item.stack_size > 0);
+        true
+    }
+
+    /// Суммарный вес (неизвестные definitions — 0)
+    pub fn carry_weight(&self, definitions: &ItemDefinitions) -> f32 {
+        self.items
+            .iter()
+            .filter_map(|item| {
+                definitions
+                    .get(&item.definition_id)
+                    .map(|definition| definition.weight * item.stack_size as f32)
+            })
+            .sum()
+    }
+
     /// Проверить что inventory пустой
     pub fn is_empty(&self) -> bool {
         self.items.is_empty()
@@ -450,6 +893,51 @@ impl Inventory {
     }
 }
 
+// ============================================================================
+// Encumbered (перегруз)
+// ============================================================================
+
+/// Актор перегружен: carry weight сверх `Inventory::max_weight`
+///
+/// Вставляется/снимается `update_encumbrance` при изменении Inventory.
+/// Пока висит:
+/// - скорость × `speed_multiplier` (player input / NavigationAgent velocity)
+/// - спринт запрещён (apply_sprint_intents, Godot input)
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Encumbered {
+    pub carry_weight: f32,
+    pub max_weight: f32,
+    pub speed_multiplier: f32,
+}
+
+impl Encumbered {
+    /// Штраф скорости при минимальном перегрузе
+    pub const BASE_SPEED_MULTIPLIER: f32 = 0.75;
+    /// Нижняя граница множителя скорости
+    pub const MIN_SPEED_MULTIPLIER: f32 = 0.3;
+
+    /// `None` — вес в пределах грузоподъёмности
+    ///
+    /// Множитель падает линейно с долей перегруза: +100% сверх max → MIN_SPEED_MULTIPLIER.
+    pub fn from_weight(carry_weight: f32, max_weight: f32) -> Option<Self> {
+        if carry_weight <= max_weight {
+            return None;
+        }
+
+        let overload = (carry_weight - max_weight) / max_weight.max(f32::EPSILON);
+        let speed_multiplier = (Self::BASE_SPEED_MULTIPLIER
+            - overload * (Self::BASE_SPEED_MULTIPLIER - Self::MIN_SPEED_MULTIPLIER))
+            .max(Self::MIN_SPEED_MULTIPLIER);
+
+        Some(Self {
+            carry_weight,
+            max_weight,
+            speed_multiplier,
+        })
+    }
+}
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -470,17 +958,56 @@ mod tests {
     fn test_equipped_weapons_set_get_slot() {
         let mut weapons = EquippedWeapons::empty();
 
-        let sword = EquippedItem {
-            definition_id: "melee_sword".into(),
-            durability: 1.0,
-            ammo_count: None,
-        };
+        let sword = EquippedItem::from_instance(&ItemInstance::new("melee_sword"));
 
         weapons.set_slot(0, Some(sword.clone()));
         assert!(!weapons.is_slot_empty(0));
         assert_eq!(weapons.get_slot(0).unwrap().definition_id, "melee_sword".into());
     }
 
+    #[test]
+    fn test_inventory_count_and_consume_stacks() {
+        let mut inventory = Inventory::empty();
+        inventory.add_item(ItemInstance::consumable_stack("scrap_metal", 2));
+        inventory.add_item(ItemInstance::new("health_kit"));
+        inventory.add_item(ItemInstance::consumable_stack("scrap_metal", 3));
+
+        let scrap = ItemId::from("scrap_metal");
+        assert_eq!(inventory.count_item(&scrap), 5);
+
+        assert!(!inventory.consume_item(&scrap, 6));
+        assert_eq!(inventory.count_item(&scrap), 5);
+
+        assert!(inventory.consume_item(&scrap, 3));
+        assert_eq!(inventory.count_item(&scrap), 2);
+        // Первый стак израсходован и удалён
+        assert_eq!(inventory.len(), 2);
+    }
+
+    #[test]
+    fn test_condition_tier_from_durability() {
+        assert_eq!(ConditionTier::from_durability(1.0), ConditionTier::Pristine);
+        assert_eq!(ConditionTier::from_durability(0.7), ConditionTier::Pristine);
+        assert_eq!(ConditionTier::from_durability(0.69), ConditionTier::Worn);
+        assert_eq!(ConditionTier::from_durability(0.3), ConditionTier::Worn);
+        assert_eq!(ConditionTier::from_durability(0.29), ConditionTier::Damaged);
+        assert_eq!(ConditionTier::from_durability(0.0), ConditionTier::Damaged);
+    }
+
+    #[test]
+    fn test_equipped_item_wear_and_repair_clamped() {
+        let mut pistol = EquippedItem::from_instance(&ItemInstance::new("pistol_basic"));
+
+        pistol.wear(0.4);
+        assert!((pistol.durability - 0.6).abs() < 1e-5);
+        pistol.wear(2.0);
+        assert_eq!(pistol.durability, 0.0);
+
+        pistol.repair(0.5);
+        pistol.repair(0.8);
+        assert_eq!(pistol.durability, 1.0);
+    }
+
     #[test]
     fn test_consumable_slots_unlock() {
         let mut slots = ConsumableSlots::empty();
@@ -581,4 +1108,139 @@ mod tests {
         let index = inv.find_item(&"unknown".into());
         assert_eq!(index, None);
     }
+
+    #[test]
+    fn test_inventory_carry_weight_and_encumbrance() {
+        let definitions = ItemDefinitions::default();
+        let mut inventory = Inventory::empty().with_max_weight(10.0);
+        inventory.add_item(ItemInstance::new("rifle_basic"));
+        inventory.add_item(ItemInstance::consumable_stack("scrap_metal", 3));
+
+        let weight = inventory.carry_weight(&definitions);
+        assert!((weight - 7.0).abs() < 1e-5);
+        assert!(Encumbered::from_weight(weight, inventory.max_weight).is_none());
+
+        inventory.add_item(ItemInstance::new("armor_military"));
+        let weight = inventory.carry_weight(&definitions);
+        let encumbered = Encumbered::from_weight(weight, inventory.max_weight).unwrap();
+        assert!(encumbered.speed_multiplier < Encumbered::BASE_SPEED_MULTIPLIER);
+        assert!(encumbered.speed_multiplier >= Encumbered::MIN_SPEED_MULTIPLIER);
+
+        // Сильный перегруз — множитель упирается в минимум
+        let heavy = Encumbered::from_weight(100.0, 10.0).unwrap();
+        assert_eq!(heavy.speed_multiplier, Encumbered::MIN_SPEED_MULTIPLIER);
+    }
+
+    #[test]
+    fn test_inventory_stacking_split_and_merge() {
+        let definitions = ItemDefinitions::default();
+        let max = definitions.max_stack(&"health_kit".into());
+        let mut inventory = Inventory::empty();
+
+        // Досыпаем в существующий стак, остаток — новым стаком
+        inventory.add_stacked(ItemInstance::consumable_stack("health_kit", max - 1), &definitions);
+        inventory.add_stacked(ItemInstance::consumable_stack("health_kit", 3), &definitions);
+        assert_eq!(inventory.len(), 2);
+        assert_eq!(inventory.items[0].stack_size, max);
+        assert_eq!(inventory.items[1].stack_size, 2);
+
+        // Оружие не стакается
+        inventory.add_stacked(ItemInstance::new("dagger"), &definitions);
+        inventory.add_stacked(ItemInstance::new("dagger"), &definitions);
+        assert_eq!(inventory.len(), 4);
+
+        // Split: 1..stack_size
+        assert!(!inventory.split_stack(1, 2));
+        assert!(inventory.split_stack(1, 1));
+        assert_eq!(inventory.items[1].stack_size, 1);
+        assert_eq!(inventory.items[2].stack_size, 1);
+
+        // Merge обратно, опустевший слот удалён
+        assert!(inventory.merge_stacks(2, 1, &definitions));
+        assert_eq!(inventory.items[1].stack_size, 2);
+        assert_eq!(inventory.count_item(&"health_kit".into()), max + 2);
+
+        // Полный стак не принимает, разные предметы не смешиваются
+        assert!(!inventory.merge_stacks(1, 0, &definitions));
+        assert!(!inventory.merge_stacks(2, 3, &definitions));
+    }
+
+    #[test]
+    fn test_shield_coverage_front_arc() {
+        let forward = Vec3::NEG_Z;
+        let arc = ShieldCoverage::FrontArc { half_angle_degrees: 60.0 };
+
+        assert!(arc.covers(forward, Vec3::new(0.0, 0.3, -1.0)));
+        assert!(arc.covers(forward, Vec3::new(1.0, 0.0, -1.0))); // 45° — внутри дуги
+        assert!(!arc.covers(forward, Vec3::X)); // Бок
+        assert!(!arc.covers(forward, Vec3::Z)); // Спина
+        assert!(arc.covers(forward, Vec3::Y)); // Сверху по центру — в щит
+        assert!(ShieldCoverage::Sphere.covers(forward, Vec3::Z));
+    }
+
+    #[test]
+    fn test_shield_generator_swap_keeps_charge_fraction() {
+        let definitions = ItemDefinitions::default();
+        let belt = armor_piece(&definitions, "shield_belt");
+        let aegis = armor_piece(&definitions, "shield_belt_aegis");
+        assert_eq!(belt.slot, ArmorSlot::Legs);
+
+        let mut current = belt.shield.unwrap().to_energy_shield();
+        assert_eq!(current.coverage, ShieldCoverage::Sphere);
+        current.take_damage(current.max_energy * 0.75);
+
+        let mut swapped = aegis.shield.unwrap().to_energy_shield();
+        swapped.carry_charge_from(&current);
+        assert!(matches!(swapped.coverage, ShieldCoverage::FrontArc { .. }));
+        assert!((swapped.current_energy - swapped.max_energy * 0.25).abs() < 1e-3);
+        assert_eq!(swapped.recharge_timer, current.recharge_timer);
+    }
+
+    fn armor_piece(definitions: &ItemDefinitions, id: &str) -> Armor {
+        let template = definitions.get(&id.into()).and_then(|def| def.armor_stats.as_ref()).unwrap();
+        Armor::from_item(&ItemInstance::new(id), template)
+    }
+
+    #[test]
+    fn test_equipped_armor_slots_aggregate_defense() {
+        let definitions = ItemDefinitions::default();
+        let mut armor = EquippedArmor::default();
+        assert_eq!(armor.reduce_damage(40), 40);
+
+        assert!(armor.equip(armor_piece(&definitions, "armor_tactical")).is_none());
+        assert!(armor.equip(armor_piece(&definitions, "helmet_military")).is_none());
+        assert_eq!(armor.defense(), 45);
+        assert!(armor.reduce_damage(40) < 40);
+
+        // Тот же слот — старая часть возвращается
+        let replaced = armor.equip(armor_piece(&definitions, "armor_military")).unwrap();
+        assert_eq!(replaced.definition_id, ItemId::from("armor_tactical"));
+        assert_eq!(armor.get(ArmorSlot::Chest).unwrap().definition_id, ItemId::from("armor_military"));
+
+        // Слабое попадание не обнуляется
+        assert_eq!(armor.reduce_damage(1), 1);
+    }
+
+    #[test]
+    fn test_equipped_armor_full_set_bonus() {
+        let definitions = ItemDefinitions::default();
+        let mut armor = EquippedArmor::default();
+        for id in ["helmet_military", "armor_military", "legs_military"] {
+            armor.equip(armor_piece(&definitions, id));
+        }
+        armor.refresh_set_bonus(&definitions);
+        assert!(armor.set_bonus.is_none());
+        let partial_defense = armor.defense();
+
+        armor.equip(armor_piece(&definitions, "boots_military"));
+        armor.refresh_set_bonus(&definitions);
+        assert_eq!(armor.full_set_id(), Some("military"));
+        let bonus = armor.set_bonus.clone().unwrap();
+        assert_eq!(armor.defense(), partial_defense + 10 + bonus.defense);
+
+        // Чужая часть ломает комплект
+        armor.equip(armor_piece(&definitions, "helmet_eva"));
+        armor.refresh_set_bonus(&definitions);
+        assert!(armor.set_bonus.is_none());
+    }
 }
@@ -0,0 +1,33 @@
+//! Tests for shared equipment components (стаки Inventory).
+
+#[cfg(test)]
+mod tests {
+    use super::super::equipment::*;
+    use crate::item_system::{ItemDefinitions, ItemInstance};
+
+    #[test]
+    fn test_add_stacked_fills_partial_stack_and_spills_into_new_stacks() {
+        let definitions = ItemDefinitions::default();
+        let mut inventory = Inventory::empty();
+        inventory.add_item(ItemInstance::consumable_stack("health_kit", 3));
+
+        // max_stack health_kit = 5: 3 + 2 в существующий, остаток 7 → 5 + 2
+        inventory.add_stacked(ItemInstance::consumable_stack("health_kit", 9), &definitions);
+
+        let sizes: Vec<u32> = inventory.items.iter().map(|item| item.stack_size).collect();
+        assert_eq!(sizes, vec![5, 5, 2]);
+    }
+
+    #[test]
+    fn test_add_stacked_keeps_different_items_apart() {
+        let definitions = ItemDefinitions::default();
+        let mut inventory = Inventory::empty();
+        inventory.add_item(ItemInstance::consumable_stack("stamina_boost", 1));
+
+        inventory.add_stacked(ItemInstance::consumable_stack("health_kit", 2), &definitions);
+
+        assert_eq!(inventory.items.len(), 2);
+        assert_eq!(inventory.items[0].stack_size, 1);
+        assert_eq!(inventory.items[1].stack_size, 2);
+    }
+}
@@ -2,17 +2,27 @@
 //!
 //! Содержит компоненты используемые в нескольких доменах:
 //! - World positioning (StrategicPosition, PrefabPath)
-//! - Equipment (EquippedWeapons, Armor, EnergyShield, Inventory)
+//! - Equipment (EquippedWeapons, EquippedArmor, EnergyShield, Inventory)
 //! - Camera (CameraMode, ActiveCamera)
 //! - Attachments (Attachment, AttachmentType, DetachAttachment)
+//! - Diagnostics (BridgeError, ExpectationFailed, ExpectationLog)
+//! - Stable IDs (StableId, StableIdRegistry)
 
 pub mod world;
 pub mod equipment;
 pub mod camera;
 pub mod attachment;
+pub mod diagnostics;
+pub mod stable_id;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod equipment_tests;
 
 // Re-export all components
 pub use world::*;
 pub use equipment::*;
 pub use camera::*;
 pub use attachment::*;
+pub use diagnostics::*;
+pub use stable_id::*;
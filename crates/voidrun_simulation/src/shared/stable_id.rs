@@ -0,0 +1,217 @@
+//! Stable IDs — идентификатор entity, переживающий сессию
+//!
+//! `Entity` (index + generation) переиспользуется и не совпадает между запусками / пирами.
+//! Сохранения, реплеи, сеть и ссылки квестов адресуют entity через `StableId`,
+//! а `StableIdRegistry` переводит его в текущий `Entity` (и обратно).
+//!
+//! **Flow:**
+//! - Spawn актора / предмета / объективного предмета / контейнера → `assign_stable_ids` выдаёт id
+//! - Entity пришёл с готовым `StableId` (загрузка, сеть) → `register_stable_ids` (счётчик сдвигается за него)
+//! - Despawn → `release_stable_ids` убирает маппинг
+
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::components::Actor;
+use crate::interaction::{Container, WorldItem};
+use crate::objective::ObjectiveItem;
+
+/// Стабильный GUID entity: старшие 16 бит — namespace (пир / сессия), младшие 48 — счётчик
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Reflect)]
+#[reflect(Component)]
+pub struct StableId(pub u64);
+
+impl StableId {
+    const COUNTER_BITS: u32 = 48;
+    const COUNTER_MASK: u64 = (1 << Self::COUNTER_BITS) - 1;
+
+    pub fn new(namespace: u16, counter: u64) -> Self {
+        Self(((namespace as u64) << Self::COUNTER_BITS) | (counter & Self::COUNTER_MASK))
+    }
+
+    pub fn namespace(self) -> u16 {
+        (self.0 >> Self::COUNTER_BITS) as u16
+    }
+
+    pub fn counter(self) -> u64 {
+        self.0 & Self::COUNTER_MASK
+    }
+}
+
+impl fmt::Display for StableId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:016x}", self.0)
+    }
+}
+
+/// Маппинг StableId ↔ Entity + выдача новых id (resource)
+///
+/// Счётчик детерминирован (реплей того же seed даёт те же id), namespace разводит пиров.
+#[derive(Resource, Debug)]
+pub struct StableIdRegistry {
+    pub namespace: u16,
+    next_counter: u64,
+    by_id: HashMap<StableId, Entity>,
+    by_entity: HashMap<Entity, StableId>,
+}
+
+impl Default for StableIdRegistry {
+    fn default() -> Self {
+        Self::with_namespace(0)
+    }
+}
+
+impl StableIdRegistry {
+    pub fn with_namespace(namespace: u16) -> Self {
+        Self {
+            namespace,
+            next_counter: 1,
+            by_id: HashMap::new(),
+            by_entity: HashMap::new(),
+        }
+    }
+
+    /// Новый id в своём namespace
+    pub fn allocate(&mut self) -> StableId {
+        let id = StableId::new(self.namespace, self.next_counter);
+        self.next_counter += 1;
+        id
+    }
+
+    /// Связать id с entity (повторная регистрация перезаписывает старую связь)
+    pub fn register(&mut self, id: StableId, entity: Entity) {
+        if let Some(old_entity) = self.by_id.insert(id, entity) {
+            if old_entity != entity {
+                self.by_entity.remove(&old_entity);
+            }
+        }
+        if let Some(old_id) = self.by_entity.insert(entity, id) {
+            if old_id != id {
+                self.by_id.remove(&old_id);
+            }
+        }
+
+        // Загруженный id своего namespace — новые выдаются после него
+        if id.namespace() == self.namespace {
+            self.next_counter = self.next_counter.max(id.counter() + 1);
+        }
+    }
+
+    /// Entity ушёл — id освобождается (не переиспользуется)
+    pub fn release(&mut self, entity: Entity) -> Option<StableId> {
+        let id = self.by_entity.remove(&entity)?;
+        self.by_id.remove(&id);
+        Some(id)
+    }
+
+    pub fn entity(&self, id: StableId) -> Option<Entity> {
+        self.by_id.get(&id).copied()
+    }
+
+    pub fn id(&self, entity: Entity) -> Option<StableId> {
+        self.by_entity.get(&entity).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.by_id.is_empty()
+    }
+}
+
+/// System: новые акторы / предметы / объективные предметы / контейнеры → StableId
+pub fn assign_stable_ids(
+    spawned: Query<
+        Entity,
+        (
+            Or<(Added<Actor>, Added<WorldItem>, Added<ObjectiveItem>, Added<Container>)>,
+            Without<StableId>,
+        ),
+    >,
+    mut registry: ResMut<StableIdRegistry>,
+    mut commands: Commands,
+) {
+    for entity in spawned.iter() {
+        let id = registry.allocate();
+        registry.register(id, entity);
+        commands.entity(entity).insert(id);
+    }
+}
+
+/// System: entity с готовым StableId (загрузка / сеть) → регистрация
+pub fn register_stable_ids(
+    added: Query<(Entity, &StableId), Added<StableId>>,
+    mut registry: ResMut<StableIdRegistry>,
+) {
+    for (entity, id) in added.iter() {
+        if registry.entity(*id) != Some(entity) {
+            registry.register(*id, entity);
+        }
+    }
+}
+
+/// System: despawn / снятие StableId → маппинг удаляется
+pub fn release_stable_ids(mut removed: RemovedComponents<StableId>, mut registry: ResMut<StableIdRegistry>) {
+    for entity in removed.read() {
+        registry.release(entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allocate_is_sequential_in_namespace() {
+        let mut registry = StableIdRegistry::with_namespace(7);
+        let first = registry.allocate();
+        let second = registry.allocate();
+
+        assert_eq!(first.namespace(), 7);
+        assert_eq!(first.counter(), 1);
+        assert_eq!(second.counter(), 2);
+    }
+
+    #[test]
+    fn test_register_lookup_and_release() {
+        let mut registry = StableIdRegistry::default();
+        let entity = Entity::from_raw(3);
+        let id = registry.allocate();
+
+        registry.register(id, entity);
+        assert_eq!(registry.entity(id), Some(entity));
+        assert_eq!(registry.id(entity), Some(id));
+
+        assert_eq!(registry.release(entity), Some(id));
+        assert_eq!(registry.entity(id), None);
+        assert!(registry.is_empty());
+    }
+
+    #[test]
+    fn test_loaded_id_advances_counter() {
+        let mut registry = StableIdRegistry::default();
+        registry.register(StableId::new(0, 40), Entity::from_raw(1));
+        // Чужой namespace счётчик не трогает
+        registry.register(StableId::new(2, 900), Entity::from_raw(2));
+
+        assert_eq!(registry.allocate().counter(), 41);
+    }
+
+    #[test]
+    fn test_reregister_moves_mapping() {
+        let mut registry = StableIdRegistry::default();
+        let id = registry.allocate();
+        let old = Entity::from_raw(1);
+        let new = Entity::from_raw(2);
+
+        registry.register(id, old);
+        registry.register(id, new);
+
+        assert_eq!(registry.entity(id), Some(new));
+        assert_eq!(registry.id(old), None);
+        assert_eq!(registry.len(), 1);
+    }
+}
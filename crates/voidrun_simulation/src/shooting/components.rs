@@ -9,8 +9,16 @@
 //! 1. Player presses RMB → ToggleADSIntent event
 //! 2. System processes intent → update AimMode
 //! 3. Godot systems read AimMode → position RightHand procedurally
+//!
+//! Механика (не только визуал):
+//! - Разброс выстрела: HIP_FIRE_SPREAD_DEGREES × spread_multiplier (ADS точнее)
+//! - Скорость движения × movement_multiplier (в ADS медленнее, без спринта)
+//! - Подброс камеры от отдачи × recoil_multiplier (в ADS оружие упёрто в плечо)
+//! - Игрок в ADS навёлся на актора → GodotAIEvent::AimedAt (угроза для AI)
+//! - Покачивание оружия (WeaponSway) от движения / stamina; Shift в ADS → HoldingBreath гасит его
 
 use bevy::prelude::*;
+use crate::movement::Stance;
 
 /// Player aiming mode state
 ///
@@ -75,9 +83,22 @@ impl Default for AimMode {
 impl AimMode {
     /// Transition duration (seconds)
     ///
-    /// 300ms - fast enough to feel responsive, slow enough to see animation
+    /// 300ms - fast enough to feel responsive, slow enough to see animation.
+    /// Fallback: у оружия своё время вскидки (`AdsConfig::transition_time`)
     pub const TRANSITION_DURATION: f32 = 0.3;
 
+    /// Базовый разброс стрельбы от бедра (градусы)
+    pub const HIP_FIRE_SPREAD_DEGREES: f32 = 4.0;
+
+    /// Множитель разброса в ADS (0.25 → 1° вместо 4°)
+    pub const ADS_SPREAD_MULTIPLIER: f32 = 0.25;
+
+    /// Множитель скорости движения в ADS
+    pub const ADS_MOVEMENT_MULTIPLIER: f32 = 0.5;
+
+    /// Множитель подброса камеры от отдачи в ADS
+    pub const ADS_RECOIL_MULTIPLIER: f32 = 0.6;
+
     /// Can player shoot in this mode?
     ///
     /// Blocked during transitions (prevent spam, tactical cost)
@@ -97,6 +118,43 @@ impl AimMode {
     pub fn is_fully_ads(&self) -> bool {
         matches!(self, AimMode::ADS)
     }
+
+    /// Насколько оружие поднято к глазам (0.0 = hip fire, 1.0 = ADS)
+    ///
+    /// Transitions интерполируются линейно по progress.
+    pub fn ads_weight(&self) -> f32 {
+        match self {
+            AimMode::HipFire => 0.0,
+            AimMode::EnteringADS { progress, .. } => progress.clamp(0.0, 1.0),
+            AimMode::ADS => 1.0,
+            AimMode::ExitingADS { progress, .. } => 1.0 - progress.clamp(0.0, 1.0),
+        }
+    }
+
+    /// Множитель разброса (1.0 от бедра → ADS_SPREAD_MULTIPLIER в ADS)
+    pub fn spread_multiplier(&self) -> f32 {
+        1.0 + (Self::ADS_SPREAD_MULTIPLIER - 1.0) * self.ads_weight()
+    }
+
+    /// Разброс выстрела в текущем режиме (градусы)
+    pub fn spread_degrees(&self) -> f32 {
+        Self::HIP_FIRE_SPREAD_DEGREES * self.spread_multiplier()
+    }
+
+    /// Множитель скорости движения (1.0 от бедра → ADS_MOVEMENT_MULTIPLIER в ADS)
+    pub fn movement_multiplier(&self) -> f32 {
+        1.0 + (Self::ADS_MOVEMENT_MULTIPLIER - 1.0) * self.ads_weight()
+    }
+
+    /// Множитель подброса камеры от отдачи (1.0 от бедра → ADS_RECOIL_MULTIPLIER в ADS)
+    pub fn recoil_multiplier(&self) -> f32 {
+        1.0 + (Self::ADS_RECOIL_MULTIPLIER - 1.0) * self.ads_weight()
+    }
+
+    /// Можно ли спринтовать (только от бедра — спринт сбивает прицел)
+    pub fn allows_sprint(&self) -> bool {
+        matches!(self, AimMode::HipFire)
+    }
 }
 
 /// Event: Toggle ADS mode (RMB input)
@@ -114,6 +172,104 @@ pub struct ToggleADSIntent {
     pub entity: Entity,
 }
 
+/// Event: задержать / отпустить дыхание (Shift в ADS)
+///
+/// ECS (`apply_hold_breath_intents`) разрешает только в полном ADS, без Exhausted и при stamina > 0.
+#[derive(Event, Debug, Clone)]
+pub struct HoldBreathIntent {
+    /// Player entity
+    pub entity: Entity,
+    /// true = задержать, false = отпустить
+    pub active: bool,
+}
+
+/// Дыхание задержано (вставляется `apply_hold_breath_intents`, снимается при отпускании / выходе из ADS / 0 stamina)
+///
+/// Пока висит: покачивание оружия × SWAY_MULTIPLIER, stamina тратится, regen стоит.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct HoldingBreath;
+
+impl HoldingBreath {
+    /// Расход stamina в секунду (полная stamina → 5 секунд)
+    pub const STAMINA_DRAIN_PER_SEC: f32 = 20.0;
+
+    /// Множитель покачивания с задержанным дыханием
+    pub const SWAY_MULTIPLIER: f32 = 0.15;
+}
+
+/// Процедурное покачивание оружия (Godot доворачивает RightHand → выстрел идёт по стволу)
+///
+/// Амплитуда растёт от движения, спринта и одышки (низкая stamina), гасится стойкой,
+/// ADS и задержкой дыхания. Траектория — восьмёрка: yaw = sin(phase), pitch = sin(2·phase) / 2.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeaponSway {
+    /// Амплитуда по yaw (градусы)
+    pub amplitude_degrees: f32,
+    /// Частота восьмёрки (Гц)
+    pub frequency: f32,
+}
+
+impl WeaponSway {
+    /// Покачивание стоя на месте с полной stamina (градусы)
+    pub const BASE_AMPLITUDE_DEGREES: f32 = 0.4;
+
+    /// Добавка на полной скорости ходьбы (градусы)
+    pub const MOVING_AMPLITUDE_DEGREES: f32 = 1.0;
+
+    /// Добавка при спринте (градусы)
+    pub const SPRINT_AMPLITUDE_DEGREES: f32 = 2.0;
+
+    /// Добавка при пустой stamina — одышка (градусы)
+    pub const WINDED_AMPLITUDE_DEGREES: f32 = 1.2;
+
+    /// Множитель амплитуды в ADS (оружие упёрто в плечо)
+    pub const ADS_MULTIPLIER: f32 = 0.6;
+
+    /// Частота в покое / при пустой stamina (Гц)
+    pub const CALM_FREQUENCY: f32 = 0.4;
+    pub const WINDED_FREQUENCY: f32 = 1.1;
+
+    /// Покачивание по состоянию игрока
+    ///
+    /// `move_fraction` — доля скорости ходьбы (0..1), `stamina_fraction` — current / max.
+    pub fn new(
+        move_fraction: f32,
+        sprinting: bool,
+        stamina_fraction: f32,
+        stance: Stance,
+        aim_mode: &AimMode,
+        holding_breath: bool,
+    ) -> Self {
+        let winded = 1.0 - stamina_fraction.clamp(0.0, 1.0);
+
+        let mut amplitude_degrees = Self::BASE_AMPLITUDE_DEGREES
+            + Self::MOVING_AMPLITUDE_DEGREES * move_fraction.clamp(0.0, 1.0)
+            + Self::WINDED_AMPLITUDE_DEGREES * winded;
+        if sprinting {
+            amplitude_degrees += Self::SPRINT_AMPLITUDE_DEGREES;
+        }
+
+        amplitude_degrees *= stance.sway_factor();
+        amplitude_degrees *= 1.0 + (Self::ADS_MULTIPLIER - 1.0) * aim_mode.ads_weight();
+        if holding_breath {
+            amplitude_degrees *= HoldingBreath::SWAY_MULTIPLIER;
+        }
+
+        Self {
+            amplitude_degrees,
+            frequency: Self::CALM_FREQUENCY + (Self::WINDED_FREQUENCY - Self::CALM_FREQUENCY) * winded,
+        }
+    }
+
+    /// Смещение прицела (x = yaw, y = pitch, градусы) в фазе восьмёрки (радианы)
+    ///
+    /// Фазу копит вызывающий (`phase += dt · frequency · TAU`) — смена частоты без рывков.
+    pub fn offset(&self, phase: f32) -> Vec2 {
+        Vec2::new(phase.sin(), (2.0 * phase).sin() * 0.5) * self.amplitude_degrees
+    }
+}
+
 /// Helper: Ease-out cubic curve
 ///
 /// Smooth deceleration: fast start, slow finish
@@ -173,6 +329,79 @@ mod tests {
         .is_ads_or_entering());
     }
 
+    #[test]
+    fn test_ads_reduces_spread() {
+        assert_eq!(AimMode::HipFire.spread_degrees(), AimMode::HIP_FIRE_SPREAD_DEGREES);
+        assert_eq!(
+            AimMode::ADS.spread_degrees(),
+            AimMode::HIP_FIRE_SPREAD_DEGREES * AimMode::ADS_SPREAD_MULTIPLIER
+        );
+    }
+
+    #[test]
+    fn test_ads_slows_movement() {
+        assert_eq!(AimMode::HipFire.movement_multiplier(), 1.0);
+        assert_eq!(AimMode::ADS.movement_multiplier(), AimMode::ADS_MOVEMENT_MULTIPLIER);
+        assert!(AimMode::HipFire.allows_sprint());
+        assert!(!AimMode::ADS.allows_sprint());
+    }
+
+    #[test]
+    fn test_ads_reduces_recoil_kick() {
+        assert_eq!(AimMode::HipFire.recoil_multiplier(), 1.0);
+        assert_eq!(AimMode::ADS.recoil_multiplier(), AimMode::ADS_RECOIL_MULTIPLIER);
+    }
+
+    #[test]
+    fn test_transition_interpolates_penalties() {
+        let entering = AimMode::EnteringADS {
+            start_position: Vec3::ZERO,
+            progress: 0.5,
+        };
+        let exiting = AimMode::ExitingADS {
+            start_position: Vec3::ZERO,
+            progress: 0.25,
+        };
+
+        assert_eq!(entering.ads_weight(), 0.5);
+        assert_eq!(exiting.ads_weight(), 0.75);
+
+        let movement = entering.movement_multiplier();
+        assert!(movement < 1.0 && movement > AimMode::ADS_MOVEMENT_MULTIPLIER);
+        assert!(!entering.allows_sprint());
+    }
+
+    #[test]
+    fn test_weapon_sway_grows_with_movement_and_fatigue() {
+        let calm = WeaponSway::new(0.0, false, 1.0, Stance::Standing, &AimMode::HipFire, false);
+        let walking = WeaponSway::new(1.0, false, 1.0, Stance::Standing, &AimMode::HipFire, false);
+        let winded = WeaponSway::new(0.0, false, 0.0, Stance::Standing, &AimMode::HipFire, false);
+        let sprinting = WeaponSway::new(1.0, true, 1.0, Stance::Standing, &AimMode::HipFire, false);
+
+        assert_eq!(calm.amplitude_degrees, WeaponSway::BASE_AMPLITUDE_DEGREES);
+        assert!(walking.amplitude_degrees > calm.amplitude_degrees);
+        assert!(sprinting.amplitude_degrees > walking.amplitude_degrees);
+        assert!(winded.amplitude_degrees > calm.amplitude_degrees);
+        assert!(winded.frequency > calm.frequency);
+    }
+
+    #[test]
+    fn test_weapon_sway_steadied_by_stance_ads_and_breath() {
+        let standing = WeaponSway::new(0.0, false, 0.5, Stance::Standing, &AimMode::HipFire, false);
+        let prone = WeaponSway::new(0.0, false, 0.5, Stance::Prone, &AimMode::HipFire, false);
+        let ads = WeaponSway::new(0.0, false, 0.5, Stance::Standing, &AimMode::ADS, false);
+        let breath = WeaponSway::new(0.0, false, 0.5, Stance::Standing, &AimMode::ADS, true);
+
+        assert!(prone.amplitude_degrees < standing.amplitude_degrees);
+        assert!(ads.amplitude_degrees < standing.amplitude_degrees);
+        assert!((breath.amplitude_degrees - ads.amplitude_degrees * HoldingBreath::SWAY_MULTIPLIER).abs() < 1e-6);
+
+        // Восьмёрка не выходит за амплитуду
+        let offset = breath.offset(std::f32::consts::FRAC_PI_2);
+        assert!((offset.x - breath.amplitude_degrees).abs() < 1e-6);
+        assert!(offset.y.abs() <= breath.amplitude_degrees * 0.5);
+    }
+
     #[test]
     fn test_ease_out_cubic() {
         assert_eq!(ease_out_cubic(0.0), 0.0);
@@ -3,6 +3,8 @@
 //! Содержит:
 //! - AimMode (Hip Fire / ADS состояния + transitions)
 //! - ToggleADSIntent (event для переключения режима прицеливания)
+//! - Штрафы/бонусы AimMode (разброс, скорость движения)
+//! - WeaponSway (покачивание от движения / stamina) + HoldingBreath / HoldBreathIntent (Shift в ADS)
 //! - ease_out_cubic (easing function)
 
 pub mod components;
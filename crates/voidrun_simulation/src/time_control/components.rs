@@ -0,0 +1,93 @@
+//! Time control components (состояние симуляции, буфер приказов на паузе).
+
+use bevy::prelude::*;
+use crate::companions::{CompanionCommandIntent, UnitOrderIntent};
+
+/// Состояние симуляции: идёт / тактическая пауза.
+///
+/// Меняется из Godot (`SimulationBridge`); применяет `apply_simulation_state`.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SimulationState {
+    #[default]
+    Running,
+    /// FixedUpdate стоит, приказы копятся в `OrderBuffer`
+    Paused,
+}
+
+impl SimulationState {
+    pub fn is_paused(&self) -> bool {
+        *self == Self::Paused
+    }
+
+    /// Противоположное состояние (хоткей паузы)
+    pub fn toggled(&self) -> Self {
+        match self {
+            Self::Running => Self::Paused,
+            Self::Paused => Self::Running,
+        }
+    }
+}
+
+/// Множитель скорости симуляции (bullet-time 0.25x … fast-forward 4x).
+///
+/// Шаг `Time<Fixed>` не меняется — меняется только частота тиков на реальную секунду
+/// (`Time<Virtual>::relative_speed`), поэтому тик-логика детерминистична при любом масштабе.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct TimeScale(f32);
+
+impl TimeScale {
+    pub const MIN: f32 = 0.25;
+    pub const MAX: f32 = 4.0;
+
+    /// Масштаб с clamp в [MIN, MAX] (NaN → 1x)
+    pub fn new(scale: f32) -> Self {
+        if scale.is_nan() {
+            return Self::default();
+        }
+        Self(scale.clamp(Self::MIN, Self::MAX))
+    }
+
+    pub fn get(&self) -> f32 {
+        self.0
+    }
+}
+
+impl Default for TimeScale {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// Приказ, отданный на паузе.
+#[derive(Debug, Clone)]
+pub enum BufferedOrder {
+    /// RTS приказ выделенным юнитам
+    Unit(UnitOrderIntent),
+    /// Приказ лидера спутникам
+    Companion(CompanionCommandIntent),
+}
+
+/// Буфер приказов тактической паузы (порядок отдачи сохраняется).
+#[derive(Resource, Debug, Default)]
+pub struct OrderBuffer {
+    orders: Vec<BufferedOrder>,
+}
+
+impl OrderBuffer {
+    pub fn push(&mut self, order: BufferedOrder) {
+        self.orders.push(order);
+    }
+
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    /// Забрать все приказы (буфер пустеет)
+    pub fn take_all(&mut self) -> Vec<BufferedOrder> {
+        std::mem::take(&mut self.orders)
+    }
+}
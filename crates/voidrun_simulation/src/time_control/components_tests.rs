@@ -0,0 +1,49 @@
+//! Tests for time control components.
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::*;
+    use crate::companions::{CompanionCommand, CompanionCommandIntent, UnitOrder, UnitOrderIntent};
+
+    #[test]
+    fn simulation_state_defaults_to_running_and_toggles() {
+        let state = SimulationState::default();
+
+        assert!(!state.is_paused());
+        assert_eq!(state.toggled(), SimulationState::Paused);
+        assert!(state.toggled().is_paused());
+        assert_eq!(state.toggled().toggled(), SimulationState::Running);
+    }
+
+    #[test]
+    fn time_scale_clamps_to_bullet_time_and_fast_forward() {
+        assert_eq!(TimeScale::default().get(), 1.0);
+        assert_eq!(TimeScale::new(2.0).get(), 2.0);
+        assert_eq!(TimeScale::new(0.01).get(), TimeScale::MIN);
+        assert_eq!(TimeScale::new(10.0).get(), TimeScale::MAX);
+        assert_eq!(TimeScale::new(f32::NAN).get(), 1.0);
+    }
+
+    #[test]
+    fn order_buffer_keeps_issue_order_and_empties_on_take() {
+        let mut buffer = OrderBuffer::default();
+        assert!(buffer.is_empty());
+
+        buffer.push(BufferedOrder::Companion(CompanionCommandIntent {
+            leader: Entity::from_raw(1),
+            command: CompanionCommand::Regroup,
+        }));
+        buffer.push(BufferedOrder::Unit(UnitOrderIntent {
+            issuer: Entity::from_raw(1),
+            units: vec![Entity::from_raw(2)],
+            order: UnitOrder::Attack { target: Entity::from_raw(3) },
+        }));
+        assert_eq!(buffer.len(), 2);
+
+        let orders = buffer.take_all();
+        assert!(matches!(orders[0], BufferedOrder::Companion(_)));
+        assert!(matches!(orders[1], BufferedOrder::Unit(_)));
+        assert!(buffer.is_empty());
+    }
+}
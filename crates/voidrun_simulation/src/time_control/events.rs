@@ -0,0 +1,14 @@
+//! Time control events (пауза / возобновление симуляции).
+
+use bevy::prelude::*;
+
+/// Событие: симуляция поставлена на тактическую паузу
+#[derive(Event, Debug, Clone)]
+pub struct SimulationPaused;
+
+/// Событие: симуляция возобновлена
+#[derive(Event, Debug, Clone)]
+pub struct SimulationResumed {
+    /// Сколько приказов с паузы отправлено в FixedUpdate
+    pub flushed_orders: usize,
+}
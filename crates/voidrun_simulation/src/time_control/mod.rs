@@ -0,0 +1,52 @@
+//! Time control module — тактическая пауза и масштаб времени симуляции
+//!
+//! # Architecture
+//!
+//! **Flow:**
+//! - Godot меню / хоткей → `SimulationBridge::pause_simulation` / `resume_simulation` → `SimulationState`
+//! - `apply_simulation_state` (First, до `TimeSystem`): Paused → `Time<Virtual>` на паузе →
+//!   `Time<Fixed>` не копит время → FixedUpdate (AI, бой, приказы) стоит
+//! - Godot слой (Update) продолжает рендер, камеру, RTS выделение и ввод приказов
+//! - `buffer_orders_while_paused` (Last): `UnitOrderIntent` / `CompanionCommandIntent` → `OrderBuffer`
+//! - Resume → буфер отправляется обратно событиями (в порядке отдачи), FixedUpdate применяет их первым тиком
+//!
+//! **Time scale:** `SimulationBridge::set_time_scale` → `TimeScale` (0.25x … 4x) →
+//! `Time<Virtual>::relative_speed`. Шаг FixedUpdate прежний — масштабируется число тиков,
+//! а не delta (детерминизм сохраняется).
+//!
+//! Godot tactical системы (движение, бой, снаряды) гейтятся `simulation_running`.
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Time Control Plugin
+///
+/// Регистрирует переключение паузы / масштаб времени (First) и буфер приказов на паузе (Last).
+pub struct TimeControlPlugin;
+
+impl Plugin for TimeControlPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SimulationPaused>()
+            .add_event::<SimulationResumed>()
+            .init_resource::<SimulationState>()
+            .init_resource::<OrderBuffer>()
+            .init_resource::<TimeScale>()
+            .add_systems(
+                First,
+                (apply_simulation_state, apply_time_scale).before(bevy::time::TimeSystem),
+            )
+            .add_systems(Last, buffer_orders_while_paused.run_if(simulation_paused));
+    }
+}
@@ -0,0 +1,87 @@
+//! Time control systems (пауза `Time<Virtual>`, буфер приказов, run conditions).
+
+use bevy::prelude::*;
+use crate::companions::{CompanionCommandIntent, UnitOrderIntent};
+use super::components::{BufferedOrder, OrderBuffer, SimulationState, TimeScale};
+use super::events::{SimulationPaused, SimulationResumed};
+
+/// Run condition: симуляция идёт (Godot tactical системы)
+pub fn simulation_running(state: Res<SimulationState>) -> bool {
+    !state.is_paused()
+}
+
+/// Run condition: тактическая пауза
+pub fn simulation_paused(state: Res<SimulationState>) -> bool {
+    state.is_paused()
+}
+
+/// System: SimulationState → пауза `Time<Virtual>` (First, до `TimeSystem`)
+///
+/// Fixed timestep копит время из Virtual → на паузе FixedUpdate не тикает.
+/// Resume → буфер приказов отправляется событиями до первого тика.
+pub fn apply_simulation_state(
+    state: Res<SimulationState>,
+    mut time: ResMut<Time<Virtual>>,
+    mut buffer: ResMut<OrderBuffer>,
+    mut unit_orders: EventWriter<UnitOrderIntent>,
+    mut companion_commands: EventWriter<CompanionCommandIntent>,
+    mut paused_events: EventWriter<SimulationPaused>,
+    mut resumed_events: EventWriter<SimulationResumed>,
+) {
+    if state.is_paused() == time.is_paused() {
+        return;
+    }
+
+    if state.is_paused() {
+        time.pause();
+        paused_events.write(SimulationPaused);
+        crate::logger::log("⏸️ Simulation paused (orders are queued)");
+        return;
+    }
+
+    time.unpause();
+    let orders = buffer.take_all();
+    let flushed_orders = orders.len();
+    for order in orders {
+        match order {
+            BufferedOrder::Unit(intent) => {
+                unit_orders.write(intent);
+            }
+            BufferedOrder::Companion(intent) => {
+                companion_commands.write(intent);
+            }
+        }
+    }
+
+    resumed_events.write(SimulationResumed { flushed_orders });
+    crate::logger::log(&format!("▶️ Simulation resumed ({} queued orders flushed)", flushed_orders));
+}
+
+/// System: приказы на паузе → OrderBuffer (Last, только Paused)
+///
+/// Events забираются целиком: читатели FixedUpdate их не увидят до resume.
+pub fn buffer_orders_while_paused(
+    mut unit_orders: ResMut<Events<UnitOrderIntent>>,
+    mut companion_commands: ResMut<Events<CompanionCommandIntent>>,
+    mut buffer: ResMut<OrderBuffer>,
+) {
+    for intent in unit_orders.drain() {
+        buffer.push(BufferedOrder::Unit(intent));
+    }
+    for intent in companion_commands.drain() {
+        buffer.push(BufferedOrder::Companion(intent));
+    }
+}
+
+/// System: TimeScale → скорость `Time<Virtual>` (First, до `TimeSystem`)
+///
+/// Fixed timestep копит масштабированное время → 4x = в 4 раза больше тиков за кадр,
+/// delta каждого тика прежняя (1/60 s).
+pub fn apply_time_scale(scale: Res<TimeScale>, mut time: ResMut<Time<Virtual>>) {
+    if !scale.is_changed() || time.relative_speed() == scale.get() {
+        return;
+    }
+
+    time.set_relative_speed(scale.get());
+    crate::logger::log(&format!("⏩ Simulation time scale: {:.2}x", scale.get()));
+}
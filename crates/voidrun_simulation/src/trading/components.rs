@@ -0,0 +1,114 @@
+//! Trading components (торговец, ассортимент, цены, листинги для UI).
+
+use bevy::prelude::*;
+use crate::item_system::{ItemDefinitions, ItemId, ItemInstance};
+
+/// Позиция ассортимента торговца
+#[derive(Debug, Clone, Reflect)]
+pub struct StockEntry {
+    /// Что получает покупатель (stack_size — штук за покупку)
+    pub item: ItemInstance,
+    /// Цена покупки (кредиты)
+    pub price: u32,
+    /// Осталось в наличии
+    pub quantity: u32,
+    /// Наличие после restock
+    pub max_quantity: u32,
+}
+
+impl StockEntry {
+    pub fn new(item: ItemInstance, price: u32, max_quantity: u32) -> Self {
+        Self {
+            item,
+            price,
+            quantity: max_quantity,
+            max_quantity,
+        }
+    }
+}
+
+/// Торговец: ассортимент + выкуп + restock по таймеру
+///
+/// Выкупает только то, что сам продаёт (`sell_ratio` от цены, за штуку стака).
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct Merchant {
+    pub stock: Vec<StockEntry>,
+    /// Доля цены при выкупе у игрока (0..1)
+    pub sell_ratio: f32,
+    /// Интервал restock (секунды)
+    pub restock_interval: f32,
+    /// Tick следующего restock (`None` — ещё не запланирован)
+    pub next_restock_tick: Option<u64>,
+}
+
+impl Merchant {
+    /// Дальность торговли (метры)
+    pub const TRADE_RANGE: f32 = 3.0;
+    /// Restock по умолчанию (секунды)
+    pub const DEFAULT_RESTOCK_INTERVAL: f32 = 120.0;
+
+    pub fn new(stock: Vec<StockEntry>) -> Self {
+        Self {
+            stock,
+            sell_ratio: 0.5,
+            restock_interval: Self::DEFAULT_RESTOCK_INTERVAL,
+            next_restock_tick: None,
+        }
+    }
+
+    /// Торговец расходников (health kit, стимуляторы, гранаты, материалы)
+    pub fn supply_vendor() -> Self {
+        Self::new(vec![
+            StockEntry::new(ItemInstance::new("health_kit"), 40, 3),
+            StockEntry::new(ItemInstance::new("stamina_boost"), 25, 3),
+            StockEntry::new(ItemInstance::new("grenade_frag"), 60, 2),
+            StockEntry::new(ItemInstance::new("grenade_smoke"), 30, 2),
+            StockEntry::new(ItemInstance::consumable_stack("scrap_metal", 3), 15, 5),
+        ])
+    }
+
+    /// Цена выкупа одной штуки (`None` — торговец такое не берёт)
+    pub fn buyback_price(&self, item: &ItemId) -> Option<u32> {
+        self.stock
+            .iter()
+            .find(|entry| entry.item.definition_id == *item)
+            .map(|entry| (entry.price as f32 * self.sell_ratio / entry.item.stack_size.max(1) as f32).floor() as u32)
+    }
+
+    /// Вернуть наличие к `max_quantity`
+    pub fn restock(&mut self) {
+        for entry in self.stock.iter_mut() {
+            entry.quantity = entry.max_quantity;
+        }
+    }
+
+    /// Ассортимент для UI (имена из ItemDefinitions)
+    pub fn listings(&self, definitions: &ItemDefinitions) -> Vec<ShopListing> {
+        self.stock
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| ShopListing {
+                index,
+                item: entry.item.definition_id.clone(),
+                name: definitions
+                    .get(&entry.item.definition_id)
+                    .map_or_else(|| entry.item.definition_id.0.clone(), |definition| definition.name.clone()),
+                stack_size: entry.item.stack_size,
+                price: entry.price,
+                quantity: entry.quantity,
+            })
+            .collect()
+    }
+}
+
+/// Строка магазина для UI (`BuyIntent::index` = `ShopListing::index`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShopListing {
+    pub index: usize,
+    pub item: ItemId,
+    pub name: String,
+    pub stack_size: u32,
+    pub price: u32,
+    pub quantity: u32,
+}
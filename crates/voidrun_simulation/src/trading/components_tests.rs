@@ -0,0 +1,42 @@
+//! Tests for merchants (выкуп, restock, листинги).
+
+#[cfg(test)]
+mod tests {
+    use super::super::components::*;
+    use crate::components::Inventory;
+    use crate::item_system::{ItemDefinitions, ItemId};
+
+    #[test]
+    fn test_buyback_price_per_unit() {
+        let merchant = Merchant::supply_vendor();
+
+        assert_eq!(merchant.buyback_price(&ItemId::from("health_kit")), Some(20));
+        // Стак из 3 за 15 → 2.5 за штуку по 50% → 2
+        assert_eq!(merchant.buyback_price(&ItemId::from("scrap_metal")), Some(2));
+        assert_eq!(merchant.buyback_price(&ItemId::from("pistol_basic")), None);
+    }
+
+    #[test]
+    fn test_restock_and_listings() {
+        let mut merchant = Merchant::supply_vendor();
+        merchant.stock[0].quantity = 0;
+
+        let listings = merchant.listings(&ItemDefinitions::default());
+        assert_eq!(listings.len(), merchant.stock.len());
+        assert_eq!(listings[0].quantity, 0);
+        assert_ne!(listings[0].name, "health_kit", "name from ItemDefinitions");
+
+        merchant.restock();
+        assert_eq!(merchant.stock[0].quantity, merchant.stock[0].max_quantity);
+    }
+
+    #[test]
+    fn test_spend_currency_is_atomic() {
+        let mut inventory = Inventory::empty().with_currency(30);
+
+        assert!(!inventory.spend_currency(40));
+        assert_eq!(inventory.currency, 30);
+        assert!(inventory.spend_currency(30));
+        assert_eq!(inventory.currency, 0);
+    }
+}
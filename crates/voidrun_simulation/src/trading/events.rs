@@ -0,0 +1,67 @@
+//! Trading events.
+
+use bevy::prelude::*;
+use crate::item_system::ItemInstance;
+
+/// Intent: купить позицию ассортимента (`index` в `Merchant::stock`)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct BuyIntent {
+    pub buyer: Entity,
+    pub merchant: Entity,
+    pub index: usize,
+}
+
+/// Intent: продать предмет из Inventory (`index` в `Inventory::items`, весь стак)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SellIntent {
+    pub seller: Entity,
+    pub merchant: Entity,
+    pub index: usize,
+}
+
+/// Направление сделки
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeKind {
+    Bought,
+    Sold,
+}
+
+/// Сделка прошла (UI: обновить магазин и баланс)
+#[derive(Event, Debug, Clone)]
+pub struct TradeCompleted {
+    pub actor: Entity,
+    pub merchant: Entity,
+    pub kind: TradeKind,
+    pub item: ItemInstance,
+    /// Кредиты (списано при покупке / начислено при продаже)
+    pub price: u32,
+}
+
+/// Причина отказа в сделке
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeFailure {
+    /// Дальше `Merchant::TRADE_RANGE`
+    OutOfRange,
+    /// Позиция закончилась (ждать restock)
+    OutOfStock,
+    /// Не хватает кредитов
+    InsufficientFunds,
+    /// Торговец такое не выкупает
+    NotBuyable,
+    /// Нет такой позиции / предмета
+    InvalidIndex,
+}
+
+/// Сделка отклонена (UI подсказка)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TradeFailed {
+    pub actor: Entity,
+    pub merchant: Entity,
+    pub reason: TradeFailure,
+}
+
+/// Ассортимент восстановлен
+#[derive(Event, Debug, Clone, Copy)]
+pub struct MerchantRestocked {
+    pub merchant: Entity,
+}
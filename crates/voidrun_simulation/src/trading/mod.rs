@@ -0,0 +1,49 @@
+//! Trading module — торговцы: покупка / продажа за кредиты, restock по таймеру
+//!
+//! # Architecture
+//!
+//! **Flow:**
+//! - `Merchant` на entity (ассортимент `StockEntry`: ItemInstance + цена + наличие)
+//! - UI магазина читает `Merchant::listings(&ItemDefinitions)` → `BuyIntent { index }`
+//!   / `SellIntent { index в Inventory }`
+//! - ECS проверяет дистанцию, наличие, кредиты (`Inventory::currency`) → `TradeCompleted` / `TradeFailed`
+//! - `restock_merchants` раз в `restock_interval` возвращает наличие (`MerchantRestocked`)
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Trading Plugin
+///
+/// Регистрирует сделки и restock в FixedUpdate.
+pub struct TradingPlugin;
+
+impl Plugin for TradingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BuyIntent>()
+            .add_event::<SellIntent>()
+            .add_event::<TradeCompleted>()
+            .add_event::<TradeFailed>()
+            .add_event::<MerchantRestocked>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    process_buy_intents,  // 1. BuyIntent → кредиты → предмет в Inventory
+                    process_sell_intents, // 2. SellIntent → предмет торговцу → кредиты
+                    restock_merchants,    // 3. Таймер → наличие восстановлено
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,142 @@
+//! Trading systems (Buy/Sell intents → Inventory + currency, restock по таймеру).
+
+use bevy::prelude::*;
+use crate::components::Inventory;
+use crate::item_system::ItemDefinitions;
+use crate::{SimulationTick, StrategicPosition};
+use super::components::Merchant;
+use super::events::{BuyIntent, MerchantRestocked, SellIntent, TradeCompleted, TradeFailed, TradeFailure, TradeKind};
+
+/// System: BuyIntent → кредиты списаны, предмет в Inventory, наличие −1
+pub fn process_buy_intents(
+    mut intents: EventReader<BuyIntent>,
+    mut buyers: Query<(&mut Inventory, &StrategicPosition), Without<Merchant>>,
+    mut merchants: Query<(&mut Merchant, &StrategicPosition)>,
+    mut completed_events: EventWriter<TradeCompleted>,
+    mut failed_events: EventWriter<TradeFailed>,
+    definitions: Res<ItemDefinitions>,
+) {
+    for intent in intents.read() {
+        let (Ok((mut inventory, buyer_pos)), Ok((mut merchant, merchant_pos))) =
+            (buyers.get_mut(intent.buyer), merchants.get_mut(intent.merchant))
+        else {
+            continue;
+        };
+
+        let result = if !in_trade_range(buyer_pos, merchant_pos) {
+            Err(TradeFailure::OutOfRange)
+        } else {
+            match merchant.stock.get_mut(intent.index) {
+                None => Err(TradeFailure::InvalidIndex),
+                Some(entry) if entry.quantity == 0 => Err(TradeFailure::OutOfStock),
+                Some(entry) if inventory.currency < entry.price => Err(TradeFailure::InsufficientFunds),
+                Some(entry) => {
+                    inventory.spend_currency(entry.price);
+                    entry.quantity -= 1;
+                    Ok((entry.item.clone(), entry.price))
+                }
+            }
+        };
+
+        match result {
+            Ok((item, price)) => {
+                inventory.add_stacked(item.clone(), &definitions);
+                crate::logger::log(&format!(
+                    "💰 {:?} bought {} for {} (balance {})",
+                    intent.buyer, item.definition_id.0, price, inventory.currency
+                ));
+                completed_events.write(TradeCompleted {
+                    actor: intent.buyer,
+                    merchant: intent.merchant,
+                    kind: TradeKind::Bought,
+                    item,
+                    price,
+                });
+            }
+            Err(reason) => {
+                failed_events.write(TradeFailed {
+                    actor: intent.buyer,
+                    merchant: intent.merchant,
+                    reason,
+                });
+            }
+        }
+    }
+}
+
+/// System: SellIntent → предмет (весь стак) торговцу, кредиты по `Merchant::buyback_price`
+pub fn process_sell_intents(
+    mut intents: EventReader<SellIntent>,
+    mut sellers: Query<(&mut Inventory, &StrategicPosition), Without<Merchant>>,
+    merchants: Query<(&Merchant, &StrategicPosition)>,
+    mut completed_events: EventWriter<TradeCompleted>,
+    mut failed_events: EventWriter<TradeFailed>,
+) {
+    for intent in intents.read() {
+        let (Ok((mut inventory, seller_pos)), Ok((merchant, merchant_pos))) =
+            (sellers.get_mut(intent.seller), merchants.get(intent.merchant))
+        else {
+            continue;
+        };
+
+        let fail = |reason| TradeFailed {
+            actor: intent.seller,
+            merchant: intent.merchant,
+            reason,
+        };
+        if !in_trade_range(seller_pos, merchant_pos) {
+            failed_events.write(fail(TradeFailure::OutOfRange));
+            continue;
+        }
+        let Some(item) = inventory.items.get(intent.index) else {
+            failed_events.write(fail(TradeFailure::InvalidIndex));
+            continue;
+        };
+        let Some(unit_price) = merchant.buyback_price(&item.definition_id) else {
+            failed_events.write(fail(TradeFailure::NotBuyable));
+            continue;
+        };
+        let Some(item) = inventory.remove_item(intent.index) else {
+            continue;
+        };
+
+        let price = unit_price * item.stack_size;
+        inventory.currency = inventory.currency.saturating_add(price);
+        crate::logger::log(&format!(
+            "💰 {:?} sold {} ×{} for {} (balance {})",
+            intent.seller, item.definition_id.0, item.stack_size, price, inventory.currency
+        ));
+        completed_events.write(TradeCompleted {
+            actor: intent.seller,
+            merchant: intent.merchant,
+            kind: TradeKind::Sold,
+            item,
+            price,
+        });
+    }
+}
+
+/// System: restock торговцев раз в `Merchant::restock_interval`
+pub fn restock_merchants(
+    mut merchants: Query<(Entity, &mut Merchant)>,
+    tick: Res<SimulationTick>,
+    mut restocked_events: EventWriter<MerchantRestocked>,
+) {
+    for (entity, mut merchant) in merchants.iter_mut() {
+        let Some(next_restock) = merchant.next_restock_tick else {
+            merchant.next_restock_tick = Some(tick.after_secs(merchant.restock_interval));
+            continue;
+        };
+        if tick.get() < next_restock {
+            continue;
+        }
+
+        merchant.restock();
+        merchant.next_restock_tick = Some(tick.after_secs(merchant.restock_interval));
+        restocked_events.write(MerchantRestocked { merchant: entity });
+    }
+}
+
+fn in_trade_range(actor: &StrategicPosition, merchant: &StrategicPosition) -> bool {
+    actor.to_world_position(0.0).distance(merchant.to_world_position(0.0)) <= Merchant::TRADE_RANGE
+}
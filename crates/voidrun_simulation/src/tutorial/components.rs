@@ -0,0 +1,164 @@
+//! Tutorial components (шаги, условия, прогресс в профиле).
+
+use bevy::prelude::*;
+
+/// Условие завершения шага (предикат над ECS событиями игрока)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TutorialCondition {
+    /// Пройти `meters` (XZ, по StrategicPosition)
+    Moved { meters: f32 },
+    /// Спринтовать `secs` секунд суммарно (Sprinting)
+    Sprinted { secs: f32 },
+    /// Успешных парирований (ParrySuccess)
+    Parried { count: u32 },
+    /// Убитых тренировочных манекенов (EntityDied + TrainingDummy)
+    KilledDummy { count: u32 },
+}
+
+impl TutorialCondition {
+    /// Порог прогресса шага
+    pub fn target(&self) -> f32 {
+        match *self {
+            Self::Moved { meters } => meters,
+            Self::Sprinted { secs } => secs,
+            Self::Parried { count } | Self::KilledDummy { count } => count as f32,
+        }
+    }
+
+    /// Вклад сигнала в прогресс (0 — сигнал не относится к условию)
+    pub fn progress_for(&self, signal: TutorialSignal) -> f32 {
+        match (self, signal) {
+            (Self::Moved { .. }, TutorialSignal::Moved(meters)) => meters,
+            (Self::Sprinted { .. }, TutorialSignal::Sprinted(secs)) => secs,
+            (Self::Parried { .. }, TutorialSignal::Parried) => 1.0,
+            (Self::KilledDummy { .. }, TutorialSignal::KilledDummy) => 1.0,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Действие игрока за тик (собирается `track_tutorial_signals` из событий / компонентов)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TutorialSignal {
+    /// Пройдено метров
+    Moved(f32),
+    /// Секунд спринта
+    Sprinted(f32),
+    Parried,
+    KilledDummy,
+}
+
+/// Шаг туториала: подсказка HUD + условие перехода
+#[derive(Debug, Clone, PartialEq)]
+pub struct TutorialStep {
+    pub id: &'static str,
+    pub prompt: &'static str,
+    pub condition: TutorialCondition,
+}
+
+/// Прогресс туториала в `PlayerProfile` (переживает runs)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TutorialProgress {
+    /// Индекс следующего незавершённого шага
+    pub next_step: usize,
+    pub completed: bool,
+}
+
+/// Сценарий туториала + активный шаг (resource)
+///
+/// Подсказки гейтятся: следующая показывается только после условия текущей.
+/// HUD читает `prompt()` / `progress_fraction()`.
+#[derive(Resource, Debug, Clone)]
+pub struct TutorialState {
+    pub steps: Vec<TutorialStep>,
+    /// Активный шаг (`None` — туториал не идёт)
+    pub current: Option<usize>,
+    /// Прогресс активного шага (единицы условия)
+    pub progress: f32,
+}
+
+impl Default for TutorialState {
+    fn default() -> Self {
+        Self::new(Self::first_run_steps())
+    }
+}
+
+impl TutorialState {
+    pub fn new(steps: Vec<TutorialStep>) -> Self {
+        Self {
+            steps,
+            current: None,
+            progress: 0.0,
+        }
+    }
+
+    /// Первый запуск: ходьба → спринт → парирование → манекен
+    pub fn first_run_steps() -> Vec<TutorialStep> {
+        vec![
+            TutorialStep {
+                id: "move",
+                prompt: "Move with [W][A][S][D]",
+                condition: TutorialCondition::Moved { meters: 5.0 },
+            },
+            TutorialStep {
+                id: "sprint",
+                prompt: "Hold [Shift] to sprint",
+                condition: TutorialCondition::Sprinted { secs: 1.5 },
+            },
+            TutorialStep {
+                id: "parry",
+                prompt: "Parry an attack with [RMB] as it lands",
+                condition: TutorialCondition::Parried { count: 1 },
+            },
+            TutorialStep {
+                id: "kill_dummy",
+                prompt: "Take down the training dummy",
+                condition: TutorialCondition::KilledDummy { count: 1 },
+            },
+        ]
+    }
+
+    /// Запустить с шага `step` (прогресс шага сброшен)
+    pub fn start_at(&mut self, step: usize) {
+        self.current = (step < self.steps.len()).then_some(step);
+        self.progress = 0.0;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.current.is_some()
+    }
+
+    pub fn current_step(&self) -> Option<&TutorialStep> {
+        self.current.and_then(|index| self.steps.get(index))
+    }
+
+    /// Подсказка активного шага (HUD)
+    pub fn prompt(&self) -> Option<&'static str> {
+        self.current_step().map(|step| step.prompt)
+    }
+
+    /// Прогресс активного шага 0..1
+    pub fn progress_fraction(&self) -> f32 {
+        self.current_step()
+            .map_or(0.0, |step| (self.progress / step.condition.target().max(f32::EPSILON)).min(1.0))
+    }
+
+    /// Учесть сигнал. Возвращает индекс завершённого шага (активным становится следующий).
+    pub fn observe(&mut self, signal: TutorialSignal) -> Option<usize> {
+        let index = self.current?;
+        let condition = self.steps.get(index)?.condition;
+
+        self.progress += condition.progress_for(signal);
+        if self.progress < condition.target() {
+            return None;
+        }
+
+        self.start_at(index + 1);
+        Some(index)
+    }
+}
+
+/// Тренировочный манекен (цель шага `KilledDummy`)
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct TrainingDummy;
@@ -0,0 +1,37 @@
+//! Tests for tutorial steps (гейтинг подсказок, прогресс условий).
+
+#[cfg(test)]
+mod tests {
+    use super::super::components::*;
+
+    #[test]
+    fn test_steps_advance_only_on_matching_signals() {
+        let mut state = TutorialState::default();
+        assert!(state.prompt().is_none());
+
+        state.start_at(0);
+        assert_eq!(state.current_step().unwrap().id, "move");
+
+        // Парирование не засчитывается шагу ходьбы — подсказка не меняется
+        assert_eq!(state.observe(TutorialSignal::Parried), None);
+        assert_eq!(state.observe(TutorialSignal::Moved(3.0)), None);
+        assert!((state.progress_fraction() - 0.6).abs() < 1e-4);
+
+        assert_eq!(state.observe(TutorialSignal::Moved(2.5)), Some(0));
+        assert_eq!(state.current_step().unwrap().id, "sprint");
+        assert_eq!(state.progress, 0.0);
+    }
+
+    #[test]
+    fn test_last_step_finishes_tutorial() {
+        let mut state = TutorialState::default();
+        state.start_at(3);
+
+        assert_eq!(state.observe(TutorialSignal::KilledDummy), Some(3));
+        assert!(!state.is_active());
+        assert_eq!(state.observe(TutorialSignal::KilledDummy), None);
+
+        state.start_at(state.steps.len());
+        assert!(!state.is_active());
+    }
+}
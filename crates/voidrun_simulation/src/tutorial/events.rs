@@ -0,0 +1,30 @@
+//! Tutorial events.
+
+use bevy::prelude::*;
+
+/// Запустить туториал (меню / отладка)
+///
+/// `from_beginning: false` — продолжить с `PlayerProfile::tutorial.next_step`.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct StartTutorial {
+    pub from_beginning: bool,
+}
+
+/// Шаг туториала стал активным (HUD: новая подсказка)
+#[derive(Event, Debug, Clone)]
+pub struct TutorialStepStarted {
+    pub index: usize,
+    pub id: &'static str,
+    pub prompt: &'static str,
+}
+
+/// Условие шага выполнено
+#[derive(Event, Debug, Clone)]
+pub struct TutorialStepCompleted {
+    pub index: usize,
+    pub id: &'static str,
+}
+
+/// Все шаги пройдены (профиль помечен completed)
+#[derive(Event, Debug, Clone, Copy)]
+pub struct TutorialFinished;
@@ -0,0 +1,50 @@
+//! Tutorial module — сценарий первого запуска с подсказками в HUD
+//!
+//! # Architecture
+//!
+//! **Flow:**
+//! - Первый spawn игрока (профиль без `tutorial.completed`) / `StartTutorial` → активный шаг
+//! - Каждый тик действия игрока → `TutorialSignal` (ходьба, спринт, ParrySuccess, смерть `TrainingDummy`)
+//! - Условие шага выполнено → `TutorialStepCompleted`, следующая подсказка (`TutorialStepStarted`)
+//! - Последний шаг → `TutorialFinished`
+//!
+//! Прогресс сохраняется в `PlayerProfile::tutorial` (перезапуск продолжает с незавершённого шага).
+//! Godot HUD показывает `TutorialState::prompt()`; манекен спавнит Godot (SimulationBridge).
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// Tutorial Plugin
+///
+/// Регистрирует сценарий и трекинг шагов в FixedUpdate.
+pub struct TutorialPlugin;
+
+impl Plugin for TutorialPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<StartTutorial>()
+            .add_event::<TutorialStepStarted>()
+            .add_event::<TutorialStepCompleted>()
+            .add_event::<TutorialFinished>()
+            .init_resource::<TutorialState>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    start_tutorial,         // 1. Первый spawn игрока / StartTutorial → активный шаг
+                    track_tutorial_signals, // 2. Действия игрока → прогресс шага → профиль
+                )
+                    .chain(),
+            );
+    }
+}
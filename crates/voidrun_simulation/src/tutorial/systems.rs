@@ -0,0 +1,126 @@
+//! Tutorial systems (сигналы игрока → шаги → прогресс в PlayerProfile).
+
+use bevy::prelude::*;
+use crate::combat::{EntityDied, ParrySuccess};
+use crate::game_mode::PlayerProfile;
+use crate::movement::Sprinting;
+use crate::player::Player;
+use crate::StrategicPosition;
+use super::components::{TrainingDummy, TutorialSignal, TutorialState};
+use super::events::{StartTutorial, TutorialFinished, TutorialStepCompleted, TutorialStepStarted};
+
+/// Сдвиг за тик больше этого — телепорт (spawn, arena reset), не ходьба
+const MAX_STEP_PER_TICK: f32 = 2.0;
+
+/// System: первый spawn игрока (профиль без completed) / StartTutorial → активный шаг
+pub fn start_tutorial(
+    mut start_events: EventReader<StartTutorial>,
+    spawned: Query<(), Added<Player>>,
+    mut state: ResMut<TutorialState>,
+    mut profile: ResMut<PlayerProfile>,
+    mut started_events: EventWriter<TutorialStepStarted>,
+) {
+    let restart = start_events.read().fold(None, |restart: Option<bool>, event| {
+        Some(restart.unwrap_or(false) || event.from_beginning)
+    });
+    let first_run = !spawned.is_empty() && !profile.tutorial.completed && !state.is_active();
+
+    let step = match restart {
+        Some(true) => 0,
+        Some(false) if profile.tutorial.completed => return,
+        Some(false) => profile.tutorial.next_step,
+        None if first_run => profile.tutorial.next_step,
+        None => return,
+    };
+
+    state.start_at(step);
+    profile.tutorial.next_step = step;
+    profile.tutorial.completed = false;
+
+    if let (Some(index), Some(step)) = (state.current, state.current_step()) {
+        crate::logger::log(&format!("🎓 Tutorial step {}: {}", index, step.id));
+        started_events.write(TutorialStepStarted {
+            index,
+            id: step.id,
+            prompt: step.prompt,
+        });
+    }
+}
+
+/// System: действия игрока за тик (ходьба, спринт, ParrySuccess, смерть манекена) → прогресс шага
+///
+/// Завершённый шаг сохраняется в `PlayerProfile::tutorial` (продолжение после перезапуска).
+#[allow(clippy::too_many_arguments)]
+pub fn track_tutorial_signals(
+    player: Query<(Entity, &StrategicPosition, Has<Sprinting>), With<Player>>,
+    dummies: Query<(), With<TrainingDummy>>,
+    mut parry_events: EventReader<ParrySuccess>,
+    mut death_events: EventReader<EntityDied>,
+    mut last_position: Local<Option<Vec3>>,
+    time: Res<Time<Fixed>>,
+    mut state: ResMut<TutorialState>,
+    mut profile: ResMut<PlayerProfile>,
+    mut started_events: EventWriter<TutorialStepStarted>,
+    mut completed_events: EventWriter<TutorialStepCompleted>,
+    mut finished_events: EventWriter<TutorialFinished>,
+) {
+    let Ok((player, position, sprinting)) = player.single() else {
+        parry_events.clear();
+        death_events.clear();
+        *last_position = None;
+        return;
+    };
+
+    let world_pos = position.to_world_position(0.0);
+    let travelled = last_position
+        .replace(world_pos)
+        .map_or(0.0, |last| Vec3::new(world_pos.x - last.x, 0.0, world_pos.z - last.z).length());
+
+    let mut signals = Vec::new();
+    if travelled > 0.0 && travelled <= MAX_STEP_PER_TICK {
+        signals.push(TutorialSignal::Moved(travelled));
+    }
+    if sprinting {
+        signals.push(TutorialSignal::Sprinted(time.delta_secs()));
+    }
+    for parry in parry_events.read() {
+        if parry.defender == player {
+            signals.push(TutorialSignal::Parried);
+        }
+    }
+    for died in death_events.read() {
+        if died.killer == Some(player) && dummies.contains(died.entity) {
+            signals.push(TutorialSignal::KilledDummy);
+        }
+    }
+
+    if !state.is_active() {
+        return;
+    }
+
+    for signal in signals {
+        let Some(index) = state.observe(signal) else {
+            continue;
+        };
+        let id = state.steps[index].id;
+        crate::logger::log(&format!("🎓 Tutorial step {} done: {}", index, id));
+        completed_events.write(TutorialStepCompleted { index, id });
+        profile.tutorial.next_step = index + 1;
+
+        match state.current_step() {
+            Some(step) => {
+                started_events.write(TutorialStepStarted {
+                    index: index + 1,
+                    id: step.id,
+                    prompt: step.prompt,
+                });
+            }
+            None => {
+                profile.tutorial.completed = true;
+                crate::logger::log("🎓 Tutorial finished");
+                finished_events.write(TutorialFinished);
+                break;
+            }
+        }
+    }
+}
@@ -0,0 +1,210 @@
+//! World event components & resources (виды событий, планировщик, supply drop, захват контейнера).
+
+use bevy::prelude::*;
+use crate::ai::PatrolRoute;
+use crate::item_system::ItemInstance;
+
+/// Фракция элитных патрулей (враждебна всем)
+pub const ELITE_PATROL_FACTION_ID: u64 = 70;
+
+/// Лут supply drop (равновероятно, `supply_loot_count` бросков)
+pub const SUPPLY_DROP_LOOT_TABLE: &[&str] = &[
+    "health_kit",
+    "stamina_boost",
+    "grenade_frag",
+    "grenade_smoke",
+    "rifle_basic",
+    "armor_tactical",
+];
+
+/// Вид события мира.
+#[derive(Debug, Clone, Copy, PartialEq, Reflect)]
+pub enum WorldEventKind {
+    /// Контейнер с лутом в пустом chunk
+    SupplyDrop,
+    /// Отряд элитных бойцов (`ELITE_PATROL_FACTION_ID`) патрулирует рядом с игроком
+    ElitePatrol,
+    /// Враждебная фракция идёт рейдом на chunk игрока
+    FactionRaid { faction_id: u64, target: Vec3 },
+}
+
+/// Запланированное событие (ждёт `starts_at_tick`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScheduledWorldEvent {
+    pub id: u32,
+    pub kind: WorldEventKind,
+    /// Chunk события (StrategicPosition grid)
+    pub chunk: IVec2,
+    pub position: Vec3,
+    pub starts_at_tick: u64,
+}
+
+/// Планировщик событий мира (resource).
+#[derive(Resource, Debug, Default, Clone)]
+pub struct WorldEventScheduler {
+    pub pending: Vec<ScheduledWorldEvent>,
+    /// Tick следующего броска (0 = первый бросок через `roll_interval`)
+    pub next_roll_tick: u64,
+    pub next_id: u32,
+}
+
+/// Параметры событий мира (resource).
+#[derive(Resource, Debug, Clone)]
+pub struct WorldEventConfig {
+    /// Как часто бросаем (секунды world time)
+    pub roll_interval: f32,
+    /// Шанс события на бросок (0..1)
+    pub roll_chance: f32,
+    /// За сколько секунд до начала объявляем
+    pub lead_time: f32,
+    /// Максимум запланированных одновременно
+    pub max_pending: usize,
+    /// Веса видов событий (рейд — только если есть враждебная фракция)
+    pub supply_drop_weight: f32,
+    pub elite_patrol_weight: f32,
+    pub faction_raid_weight: f32,
+    /// Дистанция события от игрока (метры)
+    pub min_distance: f32,
+    pub max_distance: f32,
+    /// Сколько предметов в supply drop
+    pub supply_loot_count: usize,
+    /// Высота сброса контейнера (метры) и скорость падения (м/с)
+    pub drop_altitude: f32,
+    pub drop_fall_speed: f32,
+    /// Ударная волна приземления: радиус (метры), скорость отброса (м/с), stagger (секунды)
+    pub landing_radius: f32,
+    pub landing_shove_speed: f32,
+    pub landing_stagger: f32,
+    /// Сколько контейнер заперт после приземления (секунды)
+    pub lock_duration: f32,
+    /// Отряды фракций дальше этого от точки приземления не идут (метры)
+    pub contest_range: f32,
+}
+
+impl Default for WorldEventConfig {
+    fn default() -> Self {
+        Self {
+            roll_interval: 90.0,
+            roll_chance: 0.6,
+            lead_time: 20.0,
+            max_pending: 2,
+            supply_drop_weight: 0.5,
+            elite_patrol_weight: 0.3,
+            faction_raid_weight: 0.2,
+            min_distance: 20.0,
+            max_distance: 40.0,
+            supply_loot_count: 3,
+            drop_altitude: 60.0,
+            drop_fall_speed: 15.0,
+            landing_radius: 4.0,
+            landing_shove_speed: 8.0,
+            landing_stagger: 0.6,
+            lock_duration: 15.0,
+            contest_range: 60.0,
+        }
+    }
+}
+
+impl WorldEventConfig {
+    /// Вид события по броску `roll` (0..1) с учётом весов
+    ///
+    /// `raid_faction = None` (враждебных фракций нет) → рейд исключён, веса перенормируются.
+    pub fn pick_kind(&self, roll: f32, raid_faction: Option<u64>, raid_target: Vec3) -> WorldEventKind {
+        let raid_weight = if raid_faction.is_some() { self.faction_raid_weight } else { 0.0 };
+        let total = self.supply_drop_weight + self.elite_patrol_weight + raid_weight;
+        let point = roll.clamp(0.0, 1.0) * total;
+
+        if point < self.supply_drop_weight {
+            WorldEventKind::SupplyDrop
+        } else if point < self.supply_drop_weight + self.elite_patrol_weight {
+            WorldEventKind::ElitePatrol
+        } else {
+            match raid_faction {
+                Some(faction_id) => WorldEventKind::FactionRaid {
+                    faction_id,
+                    target: raid_target,
+                },
+                None => WorldEventKind::ElitePatrol,
+            }
+        }
+    }
+}
+
+/// Состояние контейнера supply drop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum SupplyDropState {
+    /// Падает (высота — `SupplyDrop::altitude`)
+    Descending,
+    /// Приземлился, замок ещё закрыт
+    Locked { unlocks_at_tick: u64 },
+    /// Можно вскрыть (`OpenSupplyDropIntent`)
+    Open,
+}
+
+/// Контейнер supply drop с лутом.
+///
+/// Падает с `altitude` до точки `landing_position`, при ударе отбрасывает всех рядом,
+/// затем заперт на `WorldEventConfig::lock_duration` — фракции успевают прийти его оспорить.
+/// StrategicPosition на том же entity — точка приземления (chunk).
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct SupplyDrop {
+    /// Событие, создавшее контейнер
+    pub event_id: u32,
+    pub loot: Vec<ItemInstance>,
+    /// Точка приземления (world)
+    pub landing_position: Vec3,
+    /// Высота над точкой приземления (0 после удара)
+    pub altitude: f32,
+    pub state: SupplyDropState,
+}
+
+impl SupplyDrop {
+    /// Дистанция вскрытия (метры, XZ)
+    pub const INTERACT_RANGE: f32 = 2.0;
+
+    pub fn new(event_id: u32, loot: Vec<ItemInstance>, landing_position: Vec3, altitude: f32) -> Self {
+        Self {
+            event_id,
+            loot,
+            landing_position,
+            altitude,
+            state: SupplyDropState::Descending,
+        }
+    }
+
+    /// Текущая позиция контейнера (world)
+    pub fn position(&self) -> Vec3 {
+        self.landing_position + Vec3::Y * self.altitude
+    }
+
+    /// Опустить на `distance`. Возвращает true в момент касания земли.
+    pub fn descend(&mut self, distance: f32) -> bool {
+        if self.state != SupplyDropState::Descending {
+            return false;
+        }
+        self.altitude = (self.altitude - distance).max(0.0);
+        self.altitude <= 0.0
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.state == SupplyDropState::Open
+    }
+
+    /// Актор может вскрыть (открыт и в `INTERACT_RANGE` по XZ)
+    pub fn can_be_opened_from(&self, actor_position: Vec3) -> bool {
+        let offset = actor_position - self.landing_position;
+        self.is_open() && Vec2::new(offset.x, offset.z).length() <= Self::INTERACT_RANGE
+    }
+}
+
+/// Актор идёт оспаривать supply drop (маршрут faction AI)
+///
+/// На время захвата PatrolRoute заменён точкой контейнера; прежний маршрут
+/// возвращается, когда контейнер вскрыт (или исчез).
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct SupplyDropContester {
+    pub drop: Entity,
+    pub previous_route: Option<PatrolRoute>,
+}
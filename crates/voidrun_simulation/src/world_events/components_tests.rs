@@ -0,0 +1,49 @@
+//! Tests for world event components (выбор вида события, supply drop).
+
+#[cfg(test)]
+mod tests {
+    use bevy::prelude::*;
+    use super::super::components::*;
+
+    #[test]
+    fn pick_kind_follows_weights() {
+        let config = WorldEventConfig::default();
+        let target = Vec3::new(5.0, 0.0, 5.0);
+
+        assert_eq!(config.pick_kind(0.0, Some(2), target), WorldEventKind::SupplyDrop);
+        assert_eq!(config.pick_kind(0.6, Some(2), target), WorldEventKind::ElitePatrol);
+        assert_eq!(
+            config.pick_kind(0.95, Some(2), target),
+            WorldEventKind::FactionRaid { faction_id: 2, target }
+        );
+    }
+
+    #[test]
+    fn pick_kind_without_hostile_faction_never_raids() {
+        let config = WorldEventConfig::default();
+
+        // Без рейда веса перенормированы: верх диапазона — патруль
+        assert_eq!(config.pick_kind(1.0, None, Vec3::ZERO), WorldEventKind::ElitePatrol);
+        assert_eq!(config.pick_kind(0.5, None, Vec3::ZERO), WorldEventKind::SupplyDrop);
+    }
+
+    #[test]
+    fn supply_drop_descends_then_opens_only_when_unlocked() {
+        let landing = Vec3::new(10.0, 0.0, -4.0);
+        let mut drop = SupplyDrop::new(0, Vec::new(), landing, 20.0);
+
+        assert!(!drop.descend(15.0));
+        assert_eq!(drop.position(), landing + Vec3::Y * 5.0);
+        assert!(drop.descend(15.0));
+        assert_eq!(drop.position(), landing);
+
+        // Заперт — вскрыть нельзя даже вплотную
+        drop.state = SupplyDropState::Locked { unlocks_at_tick: 100 };
+        assert!(!drop.can_be_opened_from(landing));
+        assert!(!drop.descend(1.0));
+
+        drop.state = SupplyDropState::Open;
+        assert!(drop.can_be_opened_from(landing + Vec3::new(1.5, 1.0, 0.0)));
+        assert!(!drop.can_be_opened_from(landing + Vec3::X * (SupplyDrop::INTERACT_RANGE + 0.5)));
+    }
+}
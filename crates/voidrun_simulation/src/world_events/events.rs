@@ -0,0 +1,58 @@
+//! World events (announce → start; supply drop: приземление → замок → вскрытие).
+
+use bevy::prelude::*;
+use crate::item_system::ItemInstance;
+use super::components::WorldEventKind;
+
+/// Событие мира запланировано (ECS → UI)
+///
+/// Генерируется `roll_world_events` за `lead_time` до начала.
+#[derive(Event, Debug, Clone)]
+pub struct WorldEventAnnounced {
+    pub id: u32,
+    pub kind: WorldEventKind,
+    pub position: Vec3,
+    pub starts_in_secs: f32,
+}
+
+/// Событие мира началось (ECS → director / UI)
+///
+/// Supply drop уже заспавнен ECS (`SupplyDrop`); патруль и рейд спавнит director.
+#[derive(Event, Debug, Clone)]
+pub struct WorldEventStarted {
+    pub id: u32,
+    pub kind: WorldEventKind,
+    pub position: Vec3,
+}
+
+/// Supply drop коснулся земли (ECS → Godot VFX / faction AI)
+///
+/// Акторы в `landing_radius` уже отброшены (`Shoved` + stagger).
+#[derive(Event, Debug, Clone)]
+pub struct SupplyDropLanded {
+    pub drop: Entity,
+    pub position: Vec3,
+    pub unlocks_in_secs: f32,
+}
+
+/// Замок supply drop открылся — можно вскрывать
+#[derive(Event, Debug, Clone)]
+pub struct SupplyDropUnlocked {
+    pub drop: Entity,
+}
+
+/// Intent: актор вскрывает ближайший открытый supply drop (player input / AI у контейнера)
+///
+/// Обрабатывается `open_supply_drops` (ищет контейнер в `SupplyDrop::INTERACT_RANGE`).
+#[derive(Event, Debug, Clone)]
+pub struct OpenSupplyDropIntent {
+    pub actor: Entity,
+}
+
+/// Supply drop вскрыт (лут у вскрывшего, контейнер удалён)
+#[derive(Event, Debug, Clone)]
+pub struct SupplyDropLooted {
+    pub drop: Entity,
+    pub looter: Entity,
+    pub items: Vec<ItemInstance>,
+}
@@ -0,0 +1,59 @@
+//! World events module — случайные события мира (supply drop, элитный патруль, рейд фракции)
+//!
+//! # Architecture
+//!
+//! Планировщик в ECS решает ЧТО, ГДЕ и КОГДА; director (Godot) спавнит бойцов.
+//!
+//! **Flow:**
+//! - Раз в `roll_interval` (world time = SimulationTick) бросок DeterministicRng (seed)
+//! - Выбор вида события по весам и состоянию chunks (кто где стоит)
+//! - `WorldEventAnnounced` за `lead_time` до начала (UI: предупреждение с таймером)
+//! - `WorldEventStarted`: supply drop → SupplyDrop entity с лутом (ECS);
+//!   патруль / рейд → director спавнит отряд
+//! - Supply drop: падает → `SupplyDropLanded` (ударная волна отбрасывает) → заперт
+//!   `lock_duration` (faction AI ведёт отряды оспорить) → `OpenSupplyDropIntent` → `SupplyDropLooted`
+//!
+//! Одинаковый seed + одинаковые действия игрока → одинаковые события.
+
+use bevy::prelude::*;
+
+pub mod components;
+pub mod events;
+pub mod systems;
+
+// Tests (separate files with _tests suffix)
+#[cfg(test)]
+mod components_tests;
+
+// Re-exports
+pub use components::*;
+pub use events::*;
+pub use systems::*;
+
+/// World Events Plugin
+///
+/// Регистрирует планировщик событий мира в FixedUpdate.
+pub struct WorldEventsPlugin;
+
+impl Plugin for WorldEventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<WorldEventAnnounced>()
+            .add_event::<WorldEventStarted>()
+            .add_event::<SupplyDropLanded>()
+            .add_event::<SupplyDropUnlocked>()
+            .add_event::<OpenSupplyDropIntent>()
+            .add_event::<SupplyDropLooted>()
+            .init_resource::<WorldEventConfig>()
+            .init_resource::<WorldEventScheduler>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    roll_world_events,   // 1. Бросок по расписанию → WorldEventAnnounced
+                    start_world_events,  // 2. Lead time истёк → WorldEventStarted (+ SupplyDrop)
+                    update_supply_drops, // 3. Падение → удар (Shoved) → замок → открыт
+                    open_supply_drops,   // 4. OpenSupplyDropIntent → лут вскрывшему
+                )
+                    .chain(),
+            );
+    }
+}
@@ -0,0 +1,303 @@
+//! World event systems (бросок по расписанию → announce → start; supply drop: падение → удар → замок → вскрытие).
+
+use bevy::prelude::*;
+use rand::Rng;
+use std::collections::HashSet;
+use crate::combat::{MeleeAttackState, StaggerState};
+use crate::components::{Actor, Health, Inventory};
+use crate::horde::HORDE_FACTION_ID;
+use crate::item_system::{ItemDefinitions, ItemInstance};
+use crate::movement::Shoved;
+use crate::player::Player;
+use crate::{DeterministicRng, SimulationTick, StrategicPosition};
+use super::components::{
+    ScheduledWorldEvent, SupplyDrop, SupplyDropState, WorldEventConfig, WorldEventKind, WorldEventScheduler,
+    ELITE_PATROL_FACTION_ID, SUPPLY_DROP_LOOT_TABLE,
+};
+use super::events::{
+    OpenSupplyDropIntent, SupplyDropLanded, SupplyDropLooted, SupplyDropUnlocked, WorldEventAnnounced,
+    WorldEventStarted,
+};
+
+/// Попыток найти пустой chunk для supply drop
+const SUPPLY_DROP_PLACEMENT_ATTEMPTS: usize = 4;
+
+/// System: бросок события мира раз в `roll_interval`
+///
+/// - Без игрока / при `max_pending` запланированных — пропуск
+/// - Состояние chunks: где стоят живые NPC, какие враждебные игроку фракции есть в мире
+/// - Позиция: кольцо `min_distance..max_distance` вокруг игрока;
+///   supply drop — только в chunk без NPC (иначе событие не состоится)
+/// - `WorldEventAnnounced` сразу, начало через `lead_time`
+pub fn roll_world_events(
+    mut scheduler: ResMut<WorldEventScheduler>,
+    config: Res<WorldEventConfig>,
+    tick: Res<SimulationTick>,
+    mut rng: ResMut<DeterministicRng>,
+    players: Query<(&Actor, &StrategicPosition), With<Player>>,
+    actors: Query<(&Actor, &StrategicPosition, &Health), Without<Player>>,
+    mut announced_events: EventWriter<WorldEventAnnounced>,
+) {
+    if scheduler.next_roll_tick == 0 {
+        scheduler.next_roll_tick = tick.after_secs(config.roll_interval);
+        return;
+    }
+    if tick.get() < scheduler.next_roll_tick {
+        return;
+    }
+    scheduler.next_roll_tick = tick.after_secs(config.roll_interval);
+
+    if scheduler.pending.len() >= config.max_pending {
+        return;
+    }
+    let Ok((player_actor, player_position)) = players.single() else {
+        return;
+    };
+    if rng.rng.gen::<f32>() >= config.roll_chance {
+        return;
+    }
+
+    let mut occupied_chunks = HashSet::new();
+    let mut hostile_factions = Vec::new();
+    for (actor, position, health) in actors.iter() {
+        if !health.is_alive() {
+            continue;
+        }
+        occupied_chunks.insert(position.chunk);
+        if actor.faction_id != player_actor.faction_id
+            && actor.faction_id != ELITE_PATROL_FACTION_ID
+            && actor.faction_id != HORDE_FACTION_ID
+        {
+            hostile_factions.push(actor.faction_id);
+        }
+    }
+    // Порядок query не часть seed — сортируем для детерминизма
+    hostile_factions.sort_unstable();
+    hostile_factions.dedup();
+
+    let player_world = player_position.to_world_position(0.0);
+    let raid_faction = if hostile_factions.is_empty() {
+        None
+    } else {
+        Some(hostile_factions[rng.rng.gen_range(0..hostile_factions.len())])
+    };
+    let kind = config.pick_kind(rng.rng.gen::<f32>(), raid_faction, player_world);
+
+    let attempts = if kind == WorldEventKind::SupplyDrop {
+        SUPPLY_DROP_PLACEMENT_ATTEMPTS
+    } else {
+        1
+    };
+    let mut placement = None;
+    for _ in 0..attempts {
+        let angle = rng.rng.gen::<f32>() * std::f32::consts::TAU;
+        let distance = config.min_distance + rng.rng.gen::<f32>() * (config.max_distance - config.min_distance).max(0.0);
+        let position = player_world + Vec3::new(angle.cos(), 0.0, angle.sin()) * distance;
+        let chunk = StrategicPosition::from_world_position(position).chunk;
+
+        if kind != WorldEventKind::SupplyDrop || !occupied_chunks.contains(&chunk) {
+            placement = Some((position, chunk));
+            break;
+        }
+    }
+    let Some((position, chunk)) = placement else {
+        return;
+    };
+
+    let id = scheduler.next_id;
+    scheduler.next_id += 1;
+    scheduler.pending.push(ScheduledWorldEvent {
+        id,
+        kind,
+        chunk,
+        position,
+        starts_at_tick: tick.after_secs(config.lead_time),
+    });
+
+    crate::logger::log(&format!(
+        "📡 World event #{} {:?} in chunk {:?} at {:?} (in {:.0}s)",
+        id, kind, chunk, position, config.lead_time
+    ));
+    announced_events.write(WorldEventAnnounced {
+        id,
+        kind,
+        position,
+        starts_in_secs: config.lead_time,
+    });
+}
+
+/// System: запланированные события с истёкшим lead time → WorldEventStarted
+///
+/// Supply drop спавнится здесь (SupplyDrop на высоте `drop_altitude` + StrategicPosition точки
+/// приземления, лут из `SUPPLY_DROP_LOOT_TABLE`).
+pub fn start_world_events(
+    mut scheduler: ResMut<WorldEventScheduler>,
+    config: Res<WorldEventConfig>,
+    tick: Res<SimulationTick>,
+    mut rng: ResMut<DeterministicRng>,
+    mut started_events: EventWriter<WorldEventStarted>,
+    mut commands: Commands,
+) {
+    let now = tick.get();
+    let (due, pending): (Vec<_>, Vec<_>) = scheduler
+        .pending
+        .drain(..)
+        .partition(|event| event.starts_at_tick <= now);
+    scheduler.pending = pending;
+
+    for event in due {
+        if event.kind == WorldEventKind::SupplyDrop {
+            let loot = (0..config.supply_loot_count)
+                .map(|_| {
+                    let index = rng.rng.gen_range(0..SUPPLY_DROP_LOOT_TABLE.len());
+                    ItemInstance::new(SUPPLY_DROP_LOOT_TABLE[index])
+                })
+                .collect();
+
+            commands.spawn((
+                SupplyDrop::new(event.id, loot, event.position, config.drop_altitude),
+                StrategicPosition::from_world_position(event.position),
+            ));
+        }
+
+        crate::logger::log(&format!(
+            "📡 World event #{} {:?} started at {:?}",
+            event.id, event.kind, event.position
+        ));
+        started_events.write(WorldEventStarted {
+            id: event.id,
+            kind: event.kind,
+            position: event.position,
+        });
+    }
+}
+
+/// System: падение supply drop → удар → замок → открыт
+///
+/// - Descending: высота убывает `drop_fall_speed`; касание → `SupplyDropLanded`
+/// - Удар: живые акторы в `landing_radius` отброшены от центра (`Shoved`) и в stagger
+///   (текущая атака прерывается)
+/// - Locked: через `lock_duration` → Open + `SupplyDropUnlocked`
+#[allow(clippy::too_many_arguments)]
+pub fn update_supply_drops(
+    mut drops: Query<(Entity, &mut SupplyDrop)>,
+    actors: Query<(Entity, &StrategicPosition, &Health), With<Actor>>,
+    config: Res<WorldEventConfig>,
+    time: Res<Time<Fixed>>,
+    tick: Res<SimulationTick>,
+    mut landed_events: EventWriter<SupplyDropLanded>,
+    mut unlocked_events: EventWriter<SupplyDropUnlocked>,
+    mut commands: Commands,
+) {
+    let delta = time.delta_secs();
+
+    for (drop_entity, mut drop) in drops.iter_mut() {
+        match drop.state {
+            SupplyDropState::Descending => {
+                if !drop.descend(config.drop_fall_speed * delta) {
+                    continue;
+                }
+
+                drop.state = SupplyDropState::Locked {
+                    unlocks_at_tick: tick.after_secs(config.lock_duration),
+                };
+
+                let landing = drop.landing_position;
+                for (actor_entity, position, health) in actors.iter() {
+                    if !health.is_alive() {
+                        continue;
+                    }
+                    let actor_pos = position.to_world_position(0.0);
+                    if Vec2::new(actor_pos.x - landing.x, actor_pos.z - landing.z).length() > config.landing_radius {
+                        continue;
+                    }
+
+                    commands
+                        .entity(actor_entity)
+                        .insert((
+                            Shoved::away_from(landing, actor_pos, config.landing_shove_speed),
+                            StaggerState::new(config.landing_stagger, drop_entity),
+                        ))
+                        .remove::<MeleeAttackState>();
+                }
+
+                crate::logger::log(&format!(
+                    "📦 Supply drop {:?} landed at {:?} (locked {:.0}s)",
+                    drop_entity, landing, config.lock_duration
+                ));
+                landed_events.write(SupplyDropLanded {
+                    drop: drop_entity,
+                    position: landing,
+                    unlocks_in_secs: config.lock_duration,
+                });
+            }
+            SupplyDropState::Locked { unlocks_at_tick } => {
+                if tick.get() < unlocks_at_tick {
+                    continue;
+                }
+
+                drop.state = SupplyDropState::Open;
+                unlocked_events.write(SupplyDropUnlocked { drop: drop_entity });
+            }
+            SupplyDropState::Open => {}
+        }
+    }
+}
+
+/// System: OpenSupplyDropIntent → лут вскрывшему, контейнер удалён
+///
+/// - Ближайший открытый контейнер в `SupplyDrop::INTERACT_RANGE`
+/// - Лут → Inventory вскрывшего (без Inventory — лут уходит с контейнером: отнят у игрока)
+/// - Несколько intent на один контейнер за тик — достаётся первому
+pub fn open_supply_drops(
+    mut intents: EventReader<OpenSupplyDropIntent>,
+    mut actors: Query<(&StrategicPosition, &Health, Option<&mut Inventory>)>,
+    mut drops: Query<(Entity, &mut SupplyDrop)>,
+    mut looted_events: EventWriter<SupplyDropLooted>,
+    definitions: Res<ItemDefinitions>,
+    mut commands: Commands,
+) {
+    // Despawn применится в конце тика — вскрытые в этом тике пропускаем
+    let mut opened = HashSet::new();
+
+    for intent in intents.read() {
+        let Ok((position, health, inventory)) = actors.get_mut(intent.actor) else {
+            continue;
+        };
+        if !health.is_alive() {
+            continue;
+        }
+
+        let actor_pos = position.to_world_position(0.0);
+        let nearest = drops
+            .iter_mut()
+            .filter(|(entity, drop)| !opened.contains(entity) && drop.can_be_opened_from(actor_pos))
+            .min_by(|(_, a), (_, b)| {
+                a.landing_position
+                    .distance(actor_pos)
+                    .total_cmp(&b.landing_position.distance(actor_pos))
+            });
+        let Some((drop_entity, mut drop)) = nearest else {
+            continue;
+        };
+
+        opened.insert(drop_entity);
+        let items = std::mem::take(&mut drop.loot);
+        if let Some(mut inventory) = inventory {
+            for item in items.iter().cloned() {
+                inventory.add_stacked(item, &definitions);
+            }
+        }
+        commands.entity(drop_entity).despawn();
+
+        crate::logger::log(&format!(
+            "📦 Supply drop {:?} looted by {:?} ({} items)",
+            drop_entity, intent.actor, items.len()
+        ));
+        looted_events.write(SupplyDropLooted {
+            drop: drop_entity,
+            looter: intent.actor,
+            items,
+        });
+    }
+}